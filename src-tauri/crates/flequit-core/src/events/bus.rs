@@ -0,0 +1,160 @@
+//! ドメインイベントの配信
+
+use super::domain_event::{DomainEvent, EventOrigin};
+use std::cell::RefCell;
+use std::future::Future;
+use std::sync::OnceLock;
+use tokio::sync::broadcast;
+
+/// 購読者ごとに保持するイベント数の上限
+///
+/// 購読者の処理が追いつかず上限を超えた場合、古いイベントから失われる。
+const DEFAULT_CAPACITY: usize = 1024;
+
+static GLOBAL_BUS: OnceLock<EventBus> = OnceLock::new();
+
+tokio::task_local! {
+    static DEFERRED_EVENTS: RefCell<Vec<DomainEvent>>;
+    static EVENT_ORIGIN: EventOrigin;
+}
+
+/// ドメインイベントを購読者へ配信するバス
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<DomainEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl EventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// アプリケーション全体で共有するバス
+    pub fn global() -> &'static EventBus {
+        GLOBAL_BUS.get_or_init(EventBus::default)
+    }
+
+    /// 以降に配信されるイベントを受け取る
+    pub fn subscribe(&self) -> broadcast::Receiver<DomainEvent> {
+        self.sender.subscribe()
+    }
+
+    /// イベントを配信する
+    ///
+    /// `defer_events` の中で呼ばれた場合は配信せずに保留する。
+    pub fn publish(&self, event: DomainEvent) {
+        let deferred = DEFERRED_EVENTS.try_with(|events| events.borrow_mut().push(event.clone()));
+        if deferred.is_err() {
+            self.send(event);
+        }
+    }
+
+    /// 保留されていたイベントをまとめて配信する
+    pub fn publish_all(&self, events: impl IntoIterator<Item = DomainEvent>) {
+        for event in events {
+            self.send(event);
+        }
+    }
+
+    fn send(&self, event: DomainEvent) {
+        // 購読者がいない場合のエラーは無視する
+        let _ = self.sender.send(event);
+    }
+}
+
+/// グローバルなバスへイベントを配信する
+pub fn publish(event: DomainEvent) {
+    EventBus::global().publish(event);
+}
+
+/// `work` の中で発行されたイベントを配信せずに集めて返す
+///
+/// 既に保留中であれば外側の保留に含め、空のリストを返す。
+pub async fn defer_events<F: Future>(work: F) -> (F::Output, Vec<DomainEvent>) {
+    if DEFERRED_EVENTS.try_with(|_| ()).is_ok() {
+        return (work.await, Vec::new());
+    }
+
+    let events = RefCell::new(Vec::new());
+    DEFERRED_EVENTS
+        .scope(events, async move {
+            let output = work.await;
+            let events = DEFERRED_EVENTS.with(|events| events.take());
+            (output, events)
+        })
+        .await
+}
+
+/// `work` の中で生成されたイベントの発生元を `origin` にする
+pub async fn with_origin<F: Future>(origin: EventOrigin, work: F) -> F::Output {
+    EVENT_ORIGIN.scope(origin, work).await
+}
+
+/// 現在のタスクでのイベントの発生元
+pub(crate) fn current_origin() -> EventOrigin {
+    EVENT_ORIGIN.try_with(|origin| *origin).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::domain_event::{ChangeKind, EntityKind};
+
+    #[tokio::test]
+    async fn test_publish_reaches_subscribers() {
+        let bus = EventBus::new(8);
+        let mut receiver = bus.subscribe();
+
+        bus.publish(DomainEvent::created(EntityKind::Task, "task-1"));
+
+        let event = receiver.recv().await.unwrap();
+        assert_eq!(event.entity, EntityKind::Task);
+        assert_eq!(event.change, ChangeKind::Created);
+        assert_eq!(event.entity_id, "task-1");
+        assert_eq!(event.origin, EventOrigin::Local);
+    }
+
+    #[tokio::test]
+    async fn test_deferred_events_are_held_until_published() {
+        let bus = EventBus::new(8);
+        let mut receiver = bus.subscribe();
+
+        let ((), events) = defer_events(async {
+            bus.publish(DomainEvent::created(EntityKind::Tag, "tag-1"));
+            // 入れ子の保留は外側に含まれる
+            let ((), inner) = defer_events(async {
+                bus.publish(DomainEvent::deleted(EntityKind::Tag, "tag-2"));
+            })
+            .await;
+            assert!(inner.is_empty());
+        })
+        .await;
+
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(events.len(), 2);
+
+        bus.publish_all(events);
+        assert_eq!(receiver.recv().await.unwrap().entity_id, "tag-1");
+        assert_eq!(receiver.recv().await.unwrap().entity_id, "tag-2");
+    }
+
+    #[tokio::test]
+    async fn test_origin_scope_marks_events() {
+        let event = with_origin(EventOrigin::Sync, async {
+            DomainEvent::updated(EntityKind::Task, "task-1")
+        })
+        .await;
+        assert_eq!(event.origin, EventOrigin::Sync);
+        assert_eq!(
+            DomainEvent::updated(EntityKind::Task, "task-1").origin,
+            EventOrigin::Local
+        );
+    }
+}
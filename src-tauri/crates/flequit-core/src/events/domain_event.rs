@@ -0,0 +1,402 @@
+//! ドメインイベントの型定義
+
+use super::bus::current_origin;
+use chrono::{DateTime, Utc};
+use flequit_model::models::activity::activity_entry::{
+    ActivityAction, ActivitySource, FieldChange, NewActivityEntry,
+};
+use flequit_model::types::id_types::{ProjectId, UserId};
+use partially::Partial;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 変更差分の計算から除外するフィールド
+///
+/// 書き込みのたびに必ず変わるため、変更内容として通知する意味がない。
+const IGNORED_FIELDS: &[&str] = &["updated_at", "updated_by"];
+
+/// 変更の理由を記録する変更項目のフィールド名
+///
+/// エンティティのフィールドではないため、取り消しで戻す値には含めない。
+pub const REASON_FIELD: &str = "reason";
+
+/// 変更されたエンティティの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKind {
+    Account,
+    User,
+    Project,
+    TaskList,
+    Task,
+    SubTask,
+    Tag,
+    TagBookmark,
+    /// タスクとタグの関連（`entity_id` はタスクID、`related_id` はタグID）
+    TaskTag,
+    /// サブタスクとタグの関連（`entity_id` はサブタスクID、`related_id` はタグID）
+    SubTaskTag,
+    /// タスクの担当者（`entity_id` はタスクID、`related_id` はユーザーID）
+    TaskAssignment,
+    /// サブタスクの担当者（`entity_id` はサブタスクID、`related_id` はユーザーID）
+    SubTaskAssignment,
+    RecurrenceRule,
+    /// タスクと繰り返しルールの関連（`related_id` はルールID）
+    TaskRecurrence,
+    /// サブタスクと繰り返しルールの関連（`related_id` はルールID）
+    SubTaskRecurrence,
+    /// タスクの依存関係（`entity_id` は待つ側のタスクID、`related_id` は先行タスクID）
+    TaskDependency,
+    TimeEntry,
+    /// タスク・サブタスクのリマインダー（`related_id` はタスクID）
+    Reminder,
+    /// タスク・サブタスク・リマインダーのスヌーズの記録（`related_id` はタスクID）
+    Snooze,
+    /// タスク・サブタスクへのコメント（`related_id` はタスクID）
+    Comment,
+    StatusTransitionRule,
+    WorkflowStatus,
+}
+
+impl EntityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EntityKind::Account => "account",
+            EntityKind::User => "user",
+            EntityKind::Project => "project",
+            EntityKind::TaskList => "task_list",
+            EntityKind::Task => "task",
+            EntityKind::SubTask => "sub_task",
+            EntityKind::Tag => "tag",
+            EntityKind::TagBookmark => "tag_bookmark",
+            EntityKind::TaskTag => "task_tag",
+            EntityKind::SubTaskTag => "sub_task_tag",
+            EntityKind::TaskAssignment => "task_assignment",
+            EntityKind::SubTaskAssignment => "sub_task_assignment",
+            EntityKind::RecurrenceRule => "recurrence_rule",
+            EntityKind::TaskRecurrence => "task_recurrence",
+            EntityKind::SubTaskRecurrence => "sub_task_recurrence",
+            EntityKind::TaskDependency => "task_dependency",
+            EntityKind::TimeEntry => "time_entry",
+            EntityKind::Reminder => "reminder",
+            EntityKind::Snooze => "snooze",
+            EntityKind::Comment => "comment",
+            EntityKind::StatusTransitionRule => "status_transition_rule",
+            EntityKind::WorkflowStatus => "workflow_status",
+        }
+    }
+}
+
+impl fmt::Display for EntityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 変更の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+    Restored,
+}
+
+impl ChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeKind::Created => "created",
+            ChangeKind::Updated => "updated",
+            ChangeKind::Deleted => "deleted",
+            ChangeKind::Restored => "restored",
+        }
+    }
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 変更の発生元
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventOrigin {
+    /// このデバイスでの操作
+    #[default]
+    Local,
+    /// 同期（CalDAV・バンドル取り込みなど）で取り込まれた変更
+    Sync,
+}
+
+impl EventOrigin {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventOrigin::Local => "local",
+            EventOrigin::Sync => "sync",
+        }
+    }
+}
+
+impl fmt::Display for EventOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// エンティティの変更を表すイベント
+///
+/// 発生元は生成時に実行中の `with_origin` スコープから決まる（スコープ外は `Local`）。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainEvent {
+    pub entity: EntityKind,
+    pub change: ChangeKind,
+    /// エンティティが属するプロジェクト（プロジェクト外のエンティティは `None`）
+    pub project_id: Option<ProjectId>,
+    pub entity_id: String,
+    /// 関連先・親エンティティのID（関連付けの変更時やサブタスクの親タスクなど）
+    pub related_id: Option<String>,
+    /// 変更されたフィールド名（更新時のみ）
+    pub changed_fields: Vec<String>,
+    /// 変更前後の値（分かる場合のみ。`changed_fields` と同じ順）
+    pub changes: Vec<FieldChange>,
+    /// 変更を行ったユーザー（`updated_by`）
+    pub actor: Option<UserId>,
+    /// 削除前の状態（物理削除され、後から参照できないエンティティのみ）
+    pub snapshot: Option<serde_json::Value>,
+    pub origin: EventOrigin,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl DomainEvent {
+    pub fn new(entity: EntityKind, change: ChangeKind, entity_id: impl ToString) -> Self {
+        Self {
+            entity,
+            change,
+            project_id: None,
+            entity_id: entity_id.to_string(),
+            related_id: None,
+            changed_fields: Vec::new(),
+            changes: Vec::new(),
+            actor: None,
+            snapshot: None,
+            origin: current_origin(),
+            occurred_at: Utc::now(),
+        }
+    }
+
+    pub fn created(entity: EntityKind, entity_id: impl ToString) -> Self {
+        Self::new(entity, ChangeKind::Created, entity_id)
+    }
+
+    pub fn updated(entity: EntityKind, entity_id: impl ToString) -> Self {
+        Self::new(entity, ChangeKind::Updated, entity_id)
+    }
+
+    pub fn deleted(entity: EntityKind, entity_id: impl ToString) -> Self {
+        Self::new(entity, ChangeKind::Deleted, entity_id)
+    }
+
+    pub fn restored(entity: EntityKind, entity_id: impl ToString) -> Self {
+        Self::new(entity, ChangeKind::Restored, entity_id)
+    }
+
+    pub fn in_project(mut self, project_id: &ProjectId) -> Self {
+        self.project_id = Some(*project_id);
+        self
+    }
+
+    pub fn related_to(mut self, related_id: impl ToString) -> Self {
+        self.related_id = Some(related_id.to_string());
+        self
+    }
+
+    pub fn with_changed_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.changed_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 変更前後の値を設定する（変更されたフィールド名も合わせて設定される）
+    pub fn with_changes(mut self, changes: Vec<FieldChange>) -> Self {
+        self.changed_fields = changes.iter().map(|change| change.field.clone()).collect();
+        self.changes = changes;
+        self
+    }
+
+    /// 変更の理由を変更項目に加える（理由がない場合は何もしない）
+    pub fn with_reason(mut self, reason: Option<&str>) -> Self {
+        if let Some(reason) = reason {
+            self.changed_fields.push(REASON_FIELD.to_string());
+            self.changes
+                .push(value_change(REASON_FIELD, serde_json::Value::Null, reason));
+        }
+        self
+    }
+
+    pub fn with_snapshot(mut self, entity: &impl Serialize) -> Self {
+        self.snapshot = serde_json::to_value(entity).ok();
+        self
+    }
+
+    pub fn by(mut self, actor: &UserId) -> Self {
+        self.actor = Some(*actor);
+        self
+    }
+
+    pub fn from_origin(mut self, origin: EventOrigin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// アクティビティログに記録するエントリへ変換する
+    ///
+    /// プロジェクトに属さないエンティティと、ユーザーごとの表示設定（タグのブックマーク）は
+    /// 記録の対象外で `None` を返す。
+    pub fn to_activity_entry(&self) -> Option<NewActivityEntry> {
+        let project_id = self.project_id?;
+        if self.entity == EntityKind::TagBookmark {
+            return None;
+        }
+
+        let changes = if self.changes.is_empty() {
+            // 値が分からない変更はフィールド名のみ記録する
+            self.changed_fields
+                .iter()
+                .map(|field| FieldChange {
+                    field: field.clone(),
+                    from: serde_json::Value::Null,
+                    to: serde_json::Value::Null,
+                })
+                .collect()
+        } else {
+            self.changes.clone()
+        };
+
+        Some(NewActivityEntry {
+            project_id,
+            entity: self.entity.as_str().to_string(),
+            entity_id: self.entity_id.clone(),
+            related_id: self.related_id.clone(),
+            action: match self.change {
+                ChangeKind::Created => ActivityAction::Created,
+                ChangeKind::Updated => ActivityAction::Updated,
+                ChangeKind::Deleted => ActivityAction::Deleted,
+                ChangeKind::Restored => ActivityAction::Restored,
+            },
+            changes,
+            actor: self.actor,
+            source: match self.origin {
+                EventOrigin::Local => ActivitySource::Local,
+                EventOrigin::Sync => ActivitySource::Sync,
+            },
+            occurred_at: self.occurred_at,
+        })
+    }
+}
+
+/// 2つの値をJSONとして比較し、値が異なるトップレベルのフィールドと変更前後の値を返す
+///
+/// `updated_at` / `updated_by` は除外する。フィールド名順。
+pub fn field_changes<T: Serialize>(before: &T, after: &T) -> Vec<FieldChange> {
+    let (Ok(serde_json::Value::Object(before)), Ok(serde_json::Value::Object(after))) =
+        (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return Vec::new();
+    };
+
+    let mut fields: Vec<&String> = before
+        .keys()
+        .chain(after.keys().filter(|key| !before.contains_key(*key)))
+        .filter(|key| !IGNORED_FIELDS.contains(&key.as_str()))
+        .filter(|key| before.get(*key) != after.get(*key))
+        .collect();
+    fields.sort();
+    fields
+        .into_iter()
+        .map(|field| FieldChange {
+            field: field.clone(),
+            from: before.get(field).cloned().unwrap_or_default(),
+            to: after.get(field).cloned().unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// 1つのフィールドの変更前後の値
+pub fn value_change(field: &str, from: impl Serialize, to: impl Serialize) -> FieldChange {
+    FieldChange {
+        field: field.to_string(),
+        from: serde_json::to_value(from).unwrap_or_default(),
+        to: serde_json::to_value(to).unwrap_or_default(),
+    }
+}
+
+/// パッチを適用した場合に値が変わるフィールドと変更前後の値を返す
+pub fn patch_changes<T>(before: &T, patch: &T::Item) -> Vec<FieldChange>
+where
+    T: Partial + Clone + Serialize,
+    T::Item: Clone,
+{
+    let mut after = before.clone();
+    after.apply_some(patch.clone());
+    field_changes(before, &after)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Sample {
+        title: String,
+        priority: i32,
+        note: Option<String>,
+        updated_at: i64,
+    }
+
+    #[test]
+    fn test_field_changes_ignores_bookkeeping_fields() {
+        let before = Sample {
+            title: "a".to_string(),
+            priority: 1,
+            note: Some("x".to_string()),
+            updated_at: 1,
+        };
+        let after = Sample {
+            title: "b".to_string(),
+            priority: 1,
+            note: None,
+            updated_at: 2,
+        };
+        let changes = field_changes(&before, &after);
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["note", "title"]);
+        assert_eq!(changes[0].from, serde_json::json!("x"));
+        assert_eq!(changes[0].to, serde_json::Value::Null);
+        assert!(field_changes(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn test_activity_entry_requires_project() {
+        let project_id = ProjectId::new();
+        let event = DomainEvent::updated(EntityKind::Task, "task-1")
+            .in_project(&project_id)
+            .with_changed_fields(["status"]);
+        let entry = event.to_activity_entry().unwrap();
+        assert_eq!(entry.entity, "task");
+        assert_eq!(entry.action, ActivityAction::Updated);
+        assert_eq!(entry.changes[0].field, "status");
+
+        assert!(
+            DomainEvent::updated(EntityKind::User, "user-1")
+                .to_activity_entry()
+                .is_none()
+        );
+    }
+}
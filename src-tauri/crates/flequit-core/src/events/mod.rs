@@ -0,0 +1,17 @@
+//! ドメインイベント
+//!
+//! サービスが書き込みに成功したとき、どのエンティティがどう変わったかを
+//! `DomainEvent` として `EventBus` へ通知する。
+//! 検索インデックス・リマインダー・Webhook・UIの再取得などはこれを購読して動作する。
+//!
+//! 作業単位（Unit of Work）の中で発行されたイベントは保留され、
+//! コミットが成功した時点でまとめて配信される。失敗した作業単位のイベントは破棄される。
+
+pub mod bus;
+pub mod domain_event;
+
+pub use bus::{EventBus, defer_events, publish, with_origin};
+pub use domain_event::{
+    ChangeKind, DomainEvent, EntityKind, EventOrigin, REASON_FIELD, field_changes, patch_changes,
+    value_change,
+};
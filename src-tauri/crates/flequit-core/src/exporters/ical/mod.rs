@@ -0,0 +1,559 @@
+//! iCalendar（RFC 5545）エクスポート
+//!
+//! タスク・サブタスクを VTODO として、期間指定（`is_range_date`）のものは VEVENT として出力する。
+//! UIDはタスクIDから生成するため、再エクスポートしたファイルを取り込むと
+//! カレンダーアプリ側では既存の項目が更新される。
+
+pub mod rrule;
+pub mod writer;
+
+pub use writer::{ICalComponent, ICalendar};
+
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::project::Project;
+use flequit_model::models::task_projects::recurrence_rule::RecurrenceRule;
+use flequit_model::models::task_projects::tag::Tag;
+use flequit_model::models::task_projects::task::TaskTree;
+use flequit_model::models::task_projects::task_list::TaskListTree;
+use flequit_model::types::id_types::{SubTaskId, TagId, TaskId, TaskListId};
+use flequit_model::types::task_types::TaskStatus;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// UIDのドメイン部
+pub const UID_DOMAIN: &str = "flequit.app";
+
+/// タスクのUID（IDが変わらない限り同じ値になる）
+pub fn task_uid(task_id: &TaskId) -> String {
+    format!("task-{task_id}@{UID_DOMAIN}")
+}
+
+/// サブタスクのUID
+pub fn subtask_uid(subtask_id: &SubTaskId) -> String {
+    format!("subtask-{subtask_id}@{UID_DOMAIN}")
+}
+
+/// エクスポート対象の絞り込み条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalendarExportFilter {
+    /// 対象のタスクリスト（未指定は全て）
+    pub task_list_ids: Option<Vec<TaskListId>>,
+    /// いずれかのタグが付いたタスクのみ（未指定は全て）
+    pub tag_ids: Option<Vec<TagId>>,
+    /// 対象のステータス（未指定は全て）
+    pub statuses: Option<Vec<TaskStatus>>,
+    /// この日時以降に予定があるもの
+    pub from: Option<DateTime<Utc>>,
+    /// この日時以前に予定があるもの
+    pub to: Option<DateTime<Utc>>,
+    pub include_subtasks: bool,
+    pub include_archived: bool,
+    /// 日付の無いタスクも出力するか
+    pub include_undated: bool,
+}
+
+impl Default for CalendarExportFilter {
+    fn default() -> Self {
+        Self {
+            task_list_ids: None,
+            tag_ids: None,
+            statuses: None,
+            from: None,
+            to: None,
+            include_subtasks: true,
+            include_archived: false,
+            include_undated: true,
+        }
+    }
+}
+
+impl CalendarExportFilter {
+    fn matches_status(&self, status: &TaskStatus) -> bool {
+        self.statuses
+            .as_ref()
+            .is_none_or(|statuses| statuses.contains(status))
+    }
+
+    /// 予定期間が絞り込み期間と重なるか
+    ///
+    /// 繰り返しタスクは開始後も発生し続けるため、終了側の条件は見ない。
+    fn matches_period(
+        &self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+        recurring: bool,
+    ) -> bool {
+        let (first, last) = match (start, end) {
+            (None, None) => return self.include_undated,
+            (Some(s), None) | (None, Some(s)) => (s, s),
+            (Some(s), Some(e)) => (s.min(e), s.max(e)),
+        };
+        let after_from = recurring || self.from.is_none_or(|from| last >= from);
+        let before_to = self.to.is_none_or(|to| first <= to);
+        after_from && before_to
+    }
+}
+
+/// 変換対象の共通項目（タスク・サブタスク）
+struct CalendarEntry<'a> {
+    uid: String,
+    parent_uid: Option<String>,
+    title: &'a str,
+    description: Option<&'a str>,
+    status: &'a TaskStatus,
+    priority: Option<i32>,
+    plan_start_date: Option<DateTime<Utc>>,
+    plan_end_date: Option<DateTime<Utc>>,
+    do_start_date: Option<DateTime<Utc>>,
+    do_end_date: Option<DateTime<Utc>>,
+    is_range_date: bool,
+    recurrence_rule: Option<&'a RecurrenceRule>,
+    categories: Vec<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl CalendarEntry<'_> {
+    /// 予定日（未設定の場合は実績日）
+    fn schedule(&self) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+        if self.plan_start_date.is_some() || self.plan_end_date.is_some() {
+            (self.plan_start_date, self.plan_end_date)
+        } else {
+            (self.do_start_date, self.do_end_date)
+        }
+    }
+
+    fn to_component(&self) -> ICalComponent {
+        let (start, end) = self.schedule();
+        let is_event = self.is_range_date && matches!((start, end), (Some(s), Some(e)) if s <= e);
+        let rrule = self
+            .recurrence_rule
+            .filter(|rule| !rule.deleted && (start.is_some() || end.is_some()))
+            .and_then(rrule::to_rrule);
+        // RRULEはDTSTARTを基準に展開されるため、DTSTARTを出力した場合のみ設定する
+        let mut has_dtstart = false;
+
+        let mut component = ICalComponent::new(if is_event { "VEVENT" } else { "VTODO" });
+        component
+            .raw("UID", self.uid.clone())
+            .datetime("DTSTAMP", &self.updated_at)
+            .datetime("CREATED", &self.created_at)
+            .datetime("LAST-MODIFIED", &self.updated_at)
+            .text("SUMMARY", self.title);
+        if let Some(description) = self.description.filter(|d| !d.trim().is_empty()) {
+            component.text("DESCRIPTION", description);
+        }
+
+        if is_event {
+            if let (Some(start), Some(end)) = (start, end) {
+                component
+                    .datetime("DTSTART", &start)
+                    .datetime("DTEND", &end);
+                has_dtstart = true;
+            }
+            let status = match self.status {
+                TaskStatus::Cancelled => "CANCELLED",
+                TaskStatus::Waiting => "TENTATIVE",
+                _ => "CONFIRMED",
+            };
+            component.raw("STATUS", status);
+        } else {
+            // DUEはDTSTART以降である必要があるため、逆転している場合は開始日を出力しない
+            let mut dtstart = match (start, end) {
+                (Some(start), Some(due)) if start > due => None,
+                _ => start,
+            };
+            // 期限日のみの繰り返しタスクは、期限日を繰り返しの起点とする
+            if dtstart.is_none() && rrule.is_some() {
+                dtstart = end;
+            }
+            if let Some(start) = dtstart {
+                component.datetime("DTSTART", &start);
+                has_dtstart = true;
+            }
+            if let Some(due) = end {
+                component.datetime("DUE", &due);
+            }
+            let status = match self.status {
+                TaskStatus::NotStarted | TaskStatus::Waiting => "NEEDS-ACTION",
+                TaskStatus::InProgress => "IN-PROCESS",
+                TaskStatus::Completed => "COMPLETED",
+                TaskStatus::Cancelled => "CANCELLED",
+            };
+            component.raw("STATUS", status);
+            if *self.status == TaskStatus::Completed {
+                let completed = self.do_end_date.unwrap_or(self.updated_at);
+                component
+                    .datetime("COMPLETED", &completed)
+                    .raw("PERCENT-COMPLETE", "100");
+            }
+        }
+
+        if let Some(priority) = self.priority.and_then(to_ical_priority) {
+            component.raw("PRIORITY", priority.to_string());
+        }
+        if !self.categories.is_empty() {
+            component.text_list("CATEGORIES", &self.categories);
+        }
+        if let Some(rrule) = rrule.filter(|_| has_dtstart) {
+            component.raw("RRULE", rrule);
+        }
+        if let Some(parent_uid) = &self.parent_uid {
+            component.raw("RELATED-TO;RELTYPE=PARENT", parent_uid.clone());
+        }
+
+        // Flequit固有の情報（再取り込み用）
+        component.raw(
+            "X-FLEQUIT-STATUS",
+            serde_json::to_value(self.status)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_default(),
+        );
+        if self.plan_start_date.is_some() || self.plan_end_date.is_some() {
+            if let Some(do_start) = &self.do_start_date {
+                component.datetime("X-FLEQUIT-DO-START", do_start);
+            }
+            if let Some(do_end) = &self.do_end_date {
+                component.datetime("X-FLEQUIT-DO-END", do_end);
+            }
+        }
+        component
+    }
+}
+
+/// Flequitの優先度（1が最高、4が最低、0は未設定）をiCalendarの優先度（1〜9）へ変換
+fn to_ical_priority(priority: i32) -> Option<i32> {
+    match priority {
+        1 => Some(1),
+        2 => Some(5),
+        3 => Some(7),
+        4 => Some(9),
+        _ => None,
+    }
+}
+
+fn tag_names(tag_ids: &[TagId], tags: &HashMap<TagId, &Tag>) -> Vec<String> {
+    tag_ids
+        .iter()
+        .filter_map(|id| tags.get(id))
+        .map(|tag| tag.name.clone())
+        .collect()
+}
+
+fn task_entry<'a>(task: &'a TaskTree, tags: &HashMap<TagId, &Tag>) -> CalendarEntry<'a> {
+    CalendarEntry {
+        uid: task_uid(&task.id),
+        parent_uid: None,
+        title: &task.title,
+        description: task.description.as_deref(),
+        status: &task.status,
+        priority: Some(task.priority),
+        plan_start_date: task.plan_start_date,
+        plan_end_date: task.plan_end_date,
+        do_start_date: task.do_start_date,
+        do_end_date: task.do_end_date,
+        is_range_date: task.is_range_date.unwrap_or(false),
+        recurrence_rule: task.recurrence_rule.as_ref(),
+        categories: tag_names(&task.tag_ids, tags),
+        created_at: task.created_at,
+        updated_at: task.updated_at,
+    }
+}
+
+/// プロジェクトのタスクをカレンダーに追加する
+pub fn append_project(
+    calendar: &mut ICalendar,
+    task_lists: &[TaskListTree],
+    tags: &[Tag],
+    filter: &CalendarExportFilter,
+) {
+    let tag_map: HashMap<TagId, &Tag> = tags
+        .iter()
+        .filter(|tag| !tag.deleted)
+        .map(|tag| (tag.id, tag))
+        .collect();
+
+    let lists = task_lists.iter().filter(|list| {
+        !list.deleted
+            && (filter.include_archived || !list.is_archived)
+            && filter
+                .task_list_ids
+                .as_ref()
+                .is_none_or(|ids| ids.contains(&list.id))
+    });
+
+    for list in lists {
+        for task in &list.tasks {
+            if task.deleted || (task.is_archived && !filter.include_archived) {
+                continue;
+            }
+            if let Some(tag_ids) = &filter.tag_ids
+                && !task.tag_ids.iter().any(|id| tag_ids.contains(id))
+            {
+                continue;
+            }
+            let entry = task_entry(task, &tag_map);
+            let (start, end) = entry.schedule();
+            if !filter.matches_status(&task.status)
+                || !filter.matches_period(start, end, entry.recurrence_rule.is_some())
+            {
+                continue;
+            }
+            calendar.components.push(entry.to_component());
+
+            if !filter.include_subtasks {
+                continue;
+            }
+            for subtask in &task.sub_tasks {
+                if subtask.deleted || !filter.matches_status(&subtask.status) {
+                    continue;
+                }
+                let entry = CalendarEntry {
+                    uid: subtask_uid(&subtask.id),
+                    parent_uid: Some(task_uid(&task.id)),
+                    title: &subtask.title,
+                    description: subtask.description.as_deref(),
+                    status: &subtask.status,
+                    priority: subtask.priority,
+                    plan_start_date: subtask.plan_start_date,
+                    plan_end_date: subtask.plan_end_date,
+                    do_start_date: subtask.do_start_date,
+                    do_end_date: subtask.do_end_date,
+                    is_range_date: subtask.is_range_date.unwrap_or(false),
+                    recurrence_rule: subtask.recurrence_rule.as_ref(),
+                    categories: tag_names(&subtask.tag_ids, &tag_map),
+                    created_at: subtask.created_at,
+                    updated_at: subtask.updated_at,
+                };
+                calendar.components.push(entry.to_component());
+            }
+        }
+    }
+}
+
+/// 1プロジェクト分のカレンダーを作成する
+pub fn project_calendar(
+    project: &Project,
+    task_lists: &[TaskListTree],
+    tags: &[Tag],
+    filter: &CalendarExportFilter,
+) -> ICalendar {
+    let mut calendar = ICalendar::new(Some(project.name.clone()));
+    append_project(&mut calendar, task_lists, tags, filter);
+    calendar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use flequit_model::models::task_projects::subtask::SubTaskTree;
+    use flequit_model::types::datetime_calendar_types::RecurrenceUnit;
+    use flequit_model::types::id_types::{ProjectId, RecurrenceRuleId, UserId};
+
+    fn date(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 5, day, 9, 0, 0).unwrap()
+    }
+
+    fn task(title: &str, list_id: TaskListId) -> TaskTree {
+        TaskTree {
+            id: TaskId::new(),
+            project_id: ProjectId::new(),
+            list_id,
+            title: title.to_string(),
+            description: None,
+            status: TaskStatus::NotStarted,
+            workflow_status_id: None,
+            priority: 0,
+            plan_start_date: None,
+            plan_end_date: None,
+            do_start_date: None,
+            do_end_date: None,
+            is_range_date: None,
+            recurrence_rule: None,
+            assigned_user_ids: vec![],
+            order_index: 0,
+            is_archived: false,
+            created_at: date(1),
+            updated_at: date(2),
+            deleted: false,
+            updated_by: UserId::new(),
+            sub_tasks: vec![],
+            tag_ids: vec![],
+        }
+    }
+
+    fn fixture() -> (Vec<TaskListTree>, Vec<Tag>) {
+        let list_id = TaskListId::new();
+        let tag = Tag {
+            id: TagId::new(),
+            name: "home, garden".to_string(),
+            color: None,
+            order_index: None,
+            created_at: date(1),
+            updated_at: date(1),
+            deleted: false,
+            updated_by: UserId::new(),
+        };
+
+        let mut todo = task("Mow lawn", list_id);
+        todo.priority = 1;
+        todo.plan_end_date = Some(date(10));
+        todo.tag_ids = vec![tag.id];
+        todo.recurrence_rule = Some(RecurrenceRule {
+            id: RecurrenceRuleId::new(),
+            unit: RecurrenceUnit::Week,
+            interval: 1,
+            days_of_week: None,
+            details: None,
+            adjustment: None,
+            end_date: None,
+            max_occurrences: None,
+            created_at: date(1),
+            updated_at: date(1),
+            deleted: false,
+            updated_by: UserId::new(),
+        });
+        todo.sub_tasks.push(SubTaskTree {
+            id: SubTaskId::new(),
+            task_id: todo.id,
+            title: "Buy fuel".to_string(),
+            description: None,
+            status: TaskStatus::Completed,
+            priority: None,
+            plan_start_date: None,
+            plan_end_date: None,
+            do_start_date: None,
+            do_end_date: Some(date(3)),
+            is_range_date: None,
+            recurrence_rule: None,
+            order_index: 0,
+            completed: true,
+            created_at: date(1),
+            updated_at: date(3),
+            deleted: false,
+            updated_by: UserId::new(),
+            assigned_user_ids: vec![],
+            tag_ids: vec![],
+        });
+
+        let mut event = task("Trip", list_id);
+        event.is_range_date = Some(true);
+        event.plan_start_date = Some(date(20));
+        event.plan_end_date = Some(date(22));
+
+        let undated = task("Someday", list_id);
+
+        let list = TaskListTree {
+            id: list_id,
+            project_id: ProjectId::new(),
+            name: "Home".to_string(),
+            description: None,
+            color: None,
+            order_index: 0,
+            is_archived: false,
+            created_at: date(1),
+            updated_at: date(1),
+            deleted: false,
+            updated_by: UserId::new(),
+            tasks: vec![todo, event, undated],
+        };
+        (vec![list], vec![tag])
+    }
+
+    #[test]
+    fn test_components_and_stable_uids() {
+        let (lists, tags) = fixture();
+        let mut calendar = ICalendar::new(None);
+        append_project(
+            &mut calendar,
+            &lists,
+            &tags,
+            &CalendarExportFilter::default(),
+        );
+
+        let kinds: Vec<&str> = calendar.components.iter().map(|c| c.kind).collect();
+        assert_eq!(kinds, vec!["VTODO", "VTODO", "VEVENT", "VTODO"]);
+
+        let todo = &calendar.components[0];
+        assert_eq!(
+            todo.property("UID").unwrap(),
+            task_uid(&lists[0].tasks[0].id)
+        );
+        assert_eq!(todo.property("PRIORITY"), Some("1"));
+        assert_eq!(todo.property("CATEGORIES"), Some("home\\, garden"));
+        assert_eq!(todo.property("RRULE"), Some("FREQ=WEEKLY"));
+        assert_eq!(todo.property("DUE"), Some("20250510T090000Z"));
+
+        let subtask = &calendar.components[1];
+        assert_eq!(subtask.property("STATUS"), Some("COMPLETED"));
+        assert_eq!(subtask.property("COMPLETED"), Some("20250503T090000Z"));
+        assert_eq!(
+            subtask.property("RELATED-TO").unwrap(),
+            todo.property("UID").unwrap()
+        );
+
+        let event = &calendar.components[2];
+        assert_eq!(event.property("DTEND"), Some("20250522T090000Z"));
+
+        // 同じデータからは同じ出力になる
+        let mut again = ICalendar::new(None);
+        append_project(&mut again, &lists, &tags, &CalendarExportFilter::default());
+        assert_eq!(calendar.to_ics(), again.to_ics());
+    }
+
+    #[test]
+    fn test_filter() {
+        let (lists, tags) = fixture();
+        let filter = CalendarExportFilter {
+            from: Some(date(15)),
+            include_subtasks: false,
+            include_undated: false,
+            ..Default::default()
+        };
+        let mut calendar = ICalendar::new(None);
+        append_project(&mut calendar, &lists, &tags, &filter);
+        // 繰り返しタスクと期間内のイベントのみ
+        let titles: Vec<&str> = calendar
+            .components
+            .iter()
+            .filter_map(|c| c.property("SUMMARY"))
+            .collect();
+        assert_eq!(titles, vec!["Mow lawn", "Trip"]);
+
+        let filter = CalendarExportFilter {
+            tag_ids: Some(vec![tags[0].id]),
+            ..Default::default()
+        };
+        let mut calendar = ICalendar::new(None);
+        append_project(&mut calendar, &lists, &tags, &filter);
+        assert_eq!(calendar.components.len(), 2);
+    }
+
+    #[test]
+    fn test_recurring_task_always_has_dtstart() {
+        let (lists, _) = fixture();
+        let mut recurring = lists[0].tasks[0].clone();
+        recurring.sub_tasks.clear();
+
+        // 期限日のみの繰り返しタスクは期限日をDTSTARTとする
+        let todo = task_entry(&recurring, &HashMap::new()).to_component();
+        assert_eq!(todo.property("DTSTART"), Some("20250510T090000Z"));
+        assert_eq!(todo.property("DUE"), Some("20250510T090000Z"));
+        assert_eq!(todo.property("RRULE"), Some("FREQ=WEEKLY"));
+
+        // 開始日が期限日より後の場合も期限日を起点にする
+        recurring.plan_start_date = Some(date(12));
+        let todo = task_entry(&recurring, &HashMap::new()).to_component();
+        assert_eq!(todo.property("DTSTART"), Some("20250510T090000Z"));
+        assert_eq!(todo.property("RRULE"), Some("FREQ=WEEKLY"));
+
+        // 繰り返しのないタスクは逆転した開始日を出力しない
+        recurring.recurrence_rule = None;
+        let todo = task_entry(&recurring, &HashMap::new()).to_component();
+        assert_eq!(todo.property("DTSTART"), None);
+        assert_eq!(todo.property("RRULE"), None);
+    }
+}
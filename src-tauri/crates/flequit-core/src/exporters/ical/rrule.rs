@@ -0,0 +1,148 @@
+//! `RecurrenceRule` から RRULE への変換
+//!
+//! 補正条件（`RecurrenceAdjustment`）と追加の日付条件はRRULEで表現できないため出力しない。
+
+use super::writer::format_datetime;
+use flequit_model::models::task_projects::recurrence_rule::RecurrenceRule;
+use flequit_model::types::datetime_calendar_types::{DayOfWeek, RecurrenceUnit, WeekOfMonth};
+
+fn weekday_code(day: &DayOfWeek) -> &'static str {
+    match day {
+        DayOfWeek::Sunday => "SU",
+        DayOfWeek::Monday => "MO",
+        DayOfWeek::Tuesday => "TU",
+        DayOfWeek::Wednesday => "WE",
+        DayOfWeek::Thursday => "TH",
+        DayOfWeek::Friday => "FR",
+        DayOfWeek::Saturday => "SA",
+    }
+}
+
+fn week_ordinal(week: &WeekOfMonth) -> i32 {
+    match week {
+        WeekOfMonth::First => 1,
+        WeekOfMonth::Second => 2,
+        WeekOfMonth::Third => 3,
+        WeekOfMonth::Fourth => 4,
+        WeekOfMonth::Last => -1,
+    }
+}
+
+/// RRULEの値（`FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR` の形式）を生成
+///
+/// 四半期・半年は月単位の間隔に換算する。
+/// 終了日と最大回数が両方指定されている場合は、RFC 5545 に従い終了日（UNTIL）のみを出力する。
+pub fn to_rrule(rule: &RecurrenceRule) -> Option<String> {
+    if rule.interval <= 0 {
+        return None;
+    }
+    let (freq, multiplier) = match rule.unit {
+        RecurrenceUnit::Minute => ("MINUTELY", 1),
+        RecurrenceUnit::Hour => ("HOURLY", 1),
+        RecurrenceUnit::Day => ("DAILY", 1),
+        RecurrenceUnit::Week => ("WEEKLY", 1),
+        RecurrenceUnit::Month => ("MONTHLY", 1),
+        RecurrenceUnit::Quarter => ("MONTHLY", 3),
+        RecurrenceUnit::HalfYear => ("MONTHLY", 6),
+        RecurrenceUnit::Year => ("YEARLY", 1),
+    };
+
+    let mut parts = vec![format!("FREQ={freq}")];
+    let interval = rule.interval * multiplier;
+    if interval > 1 {
+        parts.push(format!("INTERVAL={interval}"));
+    }
+
+    let details = rule.details.as_ref().filter(|d| !d.deleted);
+    let nth_weekday =
+        details.and_then(|d| Some((d.week_of_period.as_ref()?, d.weekday_of_week.as_ref()?)));
+    let by_day: Vec<String> = match nth_weekday {
+        Some((week, day)) => vec![format!("{}{}", week_ordinal(week), weekday_code(day))],
+        None => rule
+            .days_of_week
+            .iter()
+            .flatten()
+            .map(|day| weekday_code(day).to_string())
+            .collect(),
+    };
+    if !by_day.is_empty() {
+        parts.push(format!("BYDAY={}", by_day.join(",")));
+    }
+    if let Some(day) = details
+        .and_then(|d| d.specific_date)
+        .filter(|d| (1..=31).contains(d))
+    {
+        parts.push(format!("BYMONTHDAY={day}"));
+    }
+
+    if let Some(end_date) = &rule.end_date {
+        parts.push(format!("UNTIL={}", format_datetime(end_date)));
+    } else if let Some(count) = rule.max_occurrences.filter(|c| *c > 0) {
+        parts.push(format!("COUNT={count}"));
+    }
+    Some(parts.join(";"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use flequit_model::models::task_projects::recurrence_details::RecurrenceDetails;
+    use flequit_model::types::id_types::{RecurrenceRuleId, UserId};
+
+    fn rule(unit: RecurrenceUnit, interval: i32) -> RecurrenceRule {
+        let now = Utc::now();
+        RecurrenceRule {
+            id: RecurrenceRuleId::new(),
+            unit,
+            interval,
+            days_of_week: None,
+            details: None,
+            adjustment: None,
+            end_date: None,
+            max_occurrences: None,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        }
+    }
+
+    #[test]
+    fn test_basic_rules() {
+        let mut weekly = rule(RecurrenceUnit::Week, 2);
+        weekly.days_of_week = Some(vec![DayOfWeek::Monday, DayOfWeek::Friday]);
+        weekly.max_occurrences = Some(5);
+        assert_eq!(
+            to_rrule(&weekly).unwrap(),
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;COUNT=5"
+        );
+
+        let mut quarterly = rule(RecurrenceUnit::Quarter, 1);
+        quarterly.end_date = Some(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+        quarterly.max_occurrences = Some(3);
+        assert_eq!(
+            to_rrule(&quarterly).unwrap(),
+            "FREQ=MONTHLY;INTERVAL=3;UNTIL=20260101T000000Z"
+        );
+
+        assert!(to_rrule(&rule(RecurrenceUnit::Day, 0)).is_none());
+    }
+
+    #[test]
+    fn test_monthly_details() {
+        let now = Utc::now();
+        let mut monthly = rule(RecurrenceUnit::Month, 1);
+        monthly.details = Some(RecurrenceDetails {
+            specific_date: None,
+            week_of_period: Some(WeekOfMonth::Last),
+            weekday_of_week: Some(DayOfWeek::Friday),
+            date_conditions: None,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        });
+        assert_eq!(to_rrule(&monthly).unwrap(), "FREQ=MONTHLY;BYDAY=-1FR");
+    }
+}
@@ -0,0 +1,163 @@
+//! RFC 5545 形式の書き出し
+//!
+//! テキストのエスケープ、75オクテットでの行の折り返し、CRLF改行を扱う。
+
+use chrono::{DateTime, Utc};
+
+const PRODID: &str = "-//Flequit//Flequit//EN";
+const MAX_LINE_OCTETS: usize = 75;
+
+/// VTODO / VEVENT などのコンポーネント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ICalComponent {
+    pub kind: &'static str,
+    /// (プロパティ名とパラメータ, エスケープ済みの値)
+    properties: Vec<(String, String)>,
+}
+
+impl ICalComponent {
+    pub fn new(kind: &'static str) -> Self {
+        Self {
+            kind,
+            properties: Vec::new(),
+        }
+    }
+
+    /// 値をそのまま設定（RRULEなど構造化された値用）
+    pub fn raw(&mut self, name: &str, value: impl Into<String>) -> &mut Self {
+        self.properties.push((name.to_string(), value.into()));
+        self
+    }
+
+    /// テキスト値をエスケープして設定
+    pub fn text(&mut self, name: &str, value: &str) -> &mut Self {
+        self.raw(name, escape_text(value))
+    }
+
+    /// UTC日時を設定
+    pub fn datetime(&mut self, name: &str, value: &DateTime<Utc>) -> &mut Self {
+        self.raw(name, format_datetime(value))
+    }
+
+    /// テキストのリスト（CATEGORIESなど）を設定
+    pub fn text_list(&mut self, name: &str, values: &[String]) -> &mut Self {
+        let joined = values
+            .iter()
+            .map(|v| escape_text(v))
+            .collect::<Vec<_>>()
+            .join(",");
+        self.raw(name, joined)
+    }
+
+    /// 指定したプロパティの値（エスケープ済み）を取得
+    pub fn property(&self, name: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(n, _)| n == name || n.starts_with(&format!("{name};")))
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn write(&self, out: &mut String) {
+        write_line(out, &format!("BEGIN:{}", self.kind));
+        for (name, value) in &self.properties {
+            write_line(out, &format!("{name}:{value}"));
+        }
+        write_line(out, &format!("END:{}", self.kind));
+    }
+}
+
+/// VCALENDAR
+#[derive(Debug, Clone, Default)]
+pub struct ICalendar {
+    /// カレンダー名（X-WR-CALNAME）
+    pub name: Option<String>,
+    pub components: Vec<ICalComponent>,
+}
+
+impl ICalendar {
+    pub fn new(name: Option<String>) -> Self {
+        Self {
+            name,
+            components: Vec::new(),
+        }
+    }
+
+    /// RFC 5545 形式の文字列へ変換
+    pub fn to_ics(&self) -> String {
+        let mut out = String::new();
+        write_line(&mut out, "BEGIN:VCALENDAR");
+        write_line(&mut out, "VERSION:2.0");
+        write_line(&mut out, &format!("PRODID:{PRODID}"));
+        write_line(&mut out, "CALSCALE:GREGORIAN");
+        write_line(&mut out, "METHOD:PUBLISH");
+        if let Some(name) = &self.name {
+            write_line(&mut out, &format!("X-WR-CALNAME:{}", escape_text(name)));
+        }
+        for component in &self.components {
+            component.write(&mut out);
+        }
+        write_line(&mut out, "END:VCALENDAR");
+        out
+    }
+}
+
+/// TEXT値のエスケープ（RFC 5545 3.3.11）
+pub fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// UTC日時の書式（`20250101T090000Z`）
+pub fn format_datetime(value: &DateTime<Utc>) -> String {
+    value.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// 1行を75オクテットで折り返して書き出す（UTF-8の文字境界は分割しない）
+pub fn write_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            // 継続行の先頭の空白も1オクテットとして数える
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_and_fold() {
+        assert_eq!(escape_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+
+        let mut component = ICalComponent::new("VTODO");
+        component.text("SUMMARY", &"あ".repeat(40));
+        let mut calendar = ICalendar::new(None);
+        calendar.components.push(component);
+        let ics = calendar.to_ics();
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        for line in ics.split("\r\n") {
+            assert!(line.len() <= MAX_LINE_OCTETS, "line too long: {line}");
+        }
+        let unfolded = ics.replace("\r\n ", "");
+        assert!(unfolded.contains(&format!("SUMMARY:{}", "あ".repeat(40))));
+    }
+}
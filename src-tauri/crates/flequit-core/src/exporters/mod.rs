@@ -0,0 +1,5 @@
+//! 外部形式へのエクスポート
+//!
+//! Flequitのプロジェクト・タスクを他のアプリケーションで扱える形式へ変換する。
+
+pub mod ical;
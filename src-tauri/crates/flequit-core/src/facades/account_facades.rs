@@ -1,5 +1,5 @@
-use crate::services::account_service;
 use crate::InfrastructureRepositoriesTrait;
+use crate::services::account_service;
 use flequit_model::models::accounts::account::{Account, PartialAccount};
 use flequit_model::types::id_types::{AccountId, UserId};
 use flequit_types::errors::service_error::ServiceError;
@@ -12,7 +12,14 @@ pub async fn create_account<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match account_service::create_account(repositories, account, user_id).await {
+    match repositories
+        .run_in_unit_of_work(account_service::create_account(
+            repositories,
+            account,
+            user_id,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to create account: {:?}", e)),
@@ -39,7 +46,14 @@ pub async fn update_account<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match account_service::update_account(repositories, account_id, patch).await {
+    match repositories
+        .run_in_unit_of_work(account_service::update_account(
+            repositories,
+            account_id,
+            patch,
+        ))
+        .await
+    {
         Ok(changed) => Ok(changed),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to update account: {:?}", e)),
@@ -50,7 +64,10 @@ pub async fn delete_account<R>(repositories: &R, id: &AccountId) -> Result<bool,
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match account_service::delete_account(repositories, id).await {
+    match repositories
+        .run_in_unit_of_work(account_service::delete_account(repositories, id))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to delete account: {:?}", e)),
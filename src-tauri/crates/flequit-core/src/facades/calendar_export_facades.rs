@@ -0,0 +1,38 @@
+use crate::InfrastructureRepositoriesTrait;
+use crate::exporters::ical::CalendarExportFilter;
+use crate::services::calendar_export_service;
+use flequit_model::types::id_types::ProjectId;
+use flequit_types::errors::service_error::ServiceError;
+
+pub async fn export_project_calendar<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    filter: &CalendarExportFilter,
+) -> Result<String, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match calendar_export_service::export_project_calendar(repositories, project_id, filter).await {
+        Ok(ics) => Ok(ics),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to export calendar: {:?}", e)),
+    }
+}
+
+pub async fn export_calendar<R>(
+    repositories: &R,
+    project_ids: &[ProjectId],
+    filter: &CalendarExportFilter,
+    calendar_name: Option<String>,
+) -> Result<String, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match calendar_export_service::export_calendar(repositories, project_ids, filter, calendar_name)
+        .await
+    {
+        Ok(ics) => Ok(ics),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to export calendar: {:?}", e)),
+    }
+}
@@ -0,0 +1,137 @@
+use crate::InfrastructureRepositoriesTrait;
+use crate::services::comment_service;
+use flequit_model::models::task_projects::comment::Comment;
+use flequit_model::types::id_types::{CommentId, ProjectId, SubTaskId, TaskId, UserId};
+use flequit_types::errors::service_error::ServiceError;
+
+pub async fn get_comment<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    comment_id: &CommentId,
+) -> Result<Option<Comment>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match comment_service::get_comment(repositories, project_id, comment_id).await {
+        Ok(comment) => Ok(comment),
+        Err(e) => Err(format!("Failed to get comment: {:?}", e)),
+    }
+}
+
+pub async fn list_comments<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: Option<&TaskId>,
+    subtask_id: Option<&SubTaskId>,
+) -> Result<Vec<Comment>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match comment_service::list_comments(repositories, project_id, task_id, subtask_id).await {
+        Ok(comments) => Ok(comments),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to list comments: {:?}", e)),
+    }
+}
+
+pub async fn add_comment<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    subtask_id: Option<&SubTaskId>,
+    body: &str,
+    user_id: &UserId,
+) -> Result<Comment, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(comment_service::add_comment(
+            repositories,
+            project_id,
+            task_id,
+            subtask_id,
+            body,
+            user_id,
+        ))
+        .await
+    {
+        Ok(comment) => Ok(comment),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to add comment: {:?}", e)),
+    }
+}
+
+pub async fn edit_comment<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    comment_id: &CommentId,
+    body: &str,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(comment_service::edit_comment(
+            repositories,
+            project_id,
+            comment_id,
+            body,
+            user_id,
+        ))
+        .await
+    {
+        Ok(edited) => Ok(edited),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to edit comment: {:?}", e)),
+    }
+}
+
+pub async fn delete_comment<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    comment_id: &CommentId,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(comment_service::delete_comment(
+            repositories,
+            project_id,
+            comment_id,
+            user_id,
+        ))
+        .await
+    {
+        Ok(deleted) => Ok(deleted),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to delete comment: {:?}", e)),
+    }
+}
+
+pub async fn restore_comment<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    comment_id: &CommentId,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(comment_service::restore_comment(
+            repositories,
+            project_id,
+            comment_id,
+            user_id,
+        ))
+        .await
+    {
+        Ok(restored) => Ok(restored),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to restore comment: {:?}", e)),
+    }
+}
@@ -2,8 +2,8 @@
 //!
 //! このモジュールは日付条件、曜日条件のService層とのインターフェースを提供します。
 
-use crate::services::datetime_service;
 use crate::InfrastructureRepositoriesTrait;
+use crate::services::datetime_service;
 use chrono::{DateTime, Utc};
 use flequit_model::models::task_projects::date_condition::DateCondition;
 use flequit_model::models::task_projects::weekday_condition::WeekdayCondition;
@@ -22,7 +22,13 @@ pub async fn create_date_condition<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match datetime_service::create_date_condition(repositories, condition).await {
+    match repositories
+        .run_in_unit_of_work(datetime_service::create_date_condition(
+            repositories,
+            condition,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to create date condition: {:?}", e)),
@@ -59,7 +65,13 @@ pub async fn update_date_condition<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match datetime_service::update_date_condition(repositories, condition).await {
+    match repositories
+        .run_in_unit_of_work(datetime_service::update_date_condition(
+            repositories,
+            condition,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to update date condition: {:?}", e)),
@@ -73,7 +85,13 @@ pub async fn delete_date_condition<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match datetime_service::delete_date_condition(repositories, &condition_id).await {
+    match repositories
+        .run_in_unit_of_work(datetime_service::delete_date_condition(
+            repositories,
+            &condition_id,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(e) => Err(format!("Failed to delete date condition: {:?}", e)),
     }
@@ -105,7 +123,13 @@ pub async fn create_weekday_condition<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match datetime_service::create_weekday_condition(repositories, condition).await {
+    match repositories
+        .run_in_unit_of_work(datetime_service::create_weekday_condition(
+            repositories,
+            condition,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to create weekday condition: {:?}", e)),
@@ -144,7 +168,13 @@ pub async fn update_weekday_condition<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match datetime_service::update_weekday_condition(repositories, condition).await {
+    match repositories
+        .run_in_unit_of_work(datetime_service::update_weekday_condition(
+            repositories,
+            condition,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to update weekday condition: {:?}", e)),
@@ -158,7 +188,13 @@ pub async fn delete_weekday_condition<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match datetime_service::delete_weekday_condition(repositories, &condition_id).await {
+    match repositories
+        .run_in_unit_of_work(datetime_service::delete_weekday_condition(
+            repositories,
+            &condition_id,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(e) => Err(format!("Failed to delete weekday condition: {:?}", e)),
     }
@@ -0,0 +1,71 @@
+use crate::InfrastructureRepositoriesTrait;
+use crate::importers::{self, ImportCounts, ImportFormat, ImportOptions, UnmappedField};
+use crate::services::import_service;
+use flequit_model::types::id_types::{ProjectId, UserId};
+use flequit_types::errors::service_error::ServiceError;
+
+/// インポート（またはドライラン）の結果
+#[derive(Debug, Clone)]
+pub struct ImportResult {
+    /// 作成された（ドライランでは作成される）エンティティ数
+    pub counts: ImportCounts,
+    /// 取り込めなかったフィールド
+    pub unmapped_fields: Vec<UnmappedField>,
+    /// スキップしたレコードなどの警告
+    pub warnings: Vec<String>,
+    /// 作成したプロジェクトID（ドライランでは空）
+    pub project_ids: Vec<ProjectId>,
+    pub dry_run: bool,
+}
+
+/// ファイル内容を解析し、保存せずに結果をプレビューする
+pub fn preview_import(
+    format: &ImportFormat,
+    content: &str,
+    options: &ImportOptions,
+) -> Result<ImportResult, String> {
+    let plan = importers::parse_import(format, content, options).map_err(|e| e.to_string())?;
+    Ok(ImportResult {
+        counts: plan.counts(),
+        unmapped_fields: plan.report.unmapped_fields(),
+        warnings: plan.report.warnings.clone(),
+        project_ids: Vec::new(),
+        dry_run: true,
+    })
+}
+
+pub async fn import_tasks<R>(
+    repositories: &R,
+    format: &ImportFormat,
+    content: &str,
+    options: &ImportOptions,
+    user_id: &UserId,
+    dry_run: bool,
+) -> Result<ImportResult, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    if dry_run {
+        return preview_import(format, content, options);
+    }
+
+    let plan = importers::parse_import(format, content, options).map_err(|e| e.to_string())?;
+    match repositories
+        .run_in_unit_of_work(import_service::apply_import_plan(
+            repositories,
+            &plan,
+            user_id,
+        ))
+        .await
+    {
+        Ok(project_ids) => Ok(ImportResult {
+            counts: plan.counts(),
+            unmapped_fields: plan.report.unmapped_fields(),
+            warnings: plan.report.warnings.clone(),
+            project_ids,
+            dry_run: false,
+        }),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to import tasks: {:?}", e)),
+    }
+}
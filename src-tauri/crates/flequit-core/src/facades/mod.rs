@@ -1,13 +1,52 @@
 pub mod account_facades;
+pub mod calendar_export_facades;
+pub mod comment_facades;
 pub mod datetime_facades;
+pub mod import_facades;
 pub mod initialization_facades;
 pub mod project_facades;
 pub mod recurrence_facades;
+pub mod reminder_facades;
+pub mod schedule_facades;
 pub mod setting_facades;
+pub mod snooze_facades;
+pub mod status_transition_facades;
 pub mod subtask_assignment_facades;
 pub mod subtask_facades;
 pub mod tag_facades;
 pub mod task_assignment_facades;
+pub mod task_dependency_facades;
 pub mod task_facades;
 pub mod task_list_facades;
+pub mod time_entry_facades;
+pub mod undo_facades;
 pub mod user_facades;
+pub mod workflow_status_facades;
+
+use crate::InfrastructureRepositoriesTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use std::future::Future;
+
+/// 文字列エラーを返すFacadeの処理全体を1つの作業単位として実行する
+///
+/// 複数のサービス呼び出しをまとめるFacadeで使用する。
+/// `work` が `Err` を返した場合、その中のすべての書き込みが取り消される。
+pub(crate) async fn in_unit_of_work<R, T, F>(repositories: &R, work: F) -> Result<T, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+    T: Send,
+    F: Future<Output = Result<T, String>> + Send,
+{
+    repositories
+        .run_in_unit_of_work(async { work.await.map_err(FacadeError) })
+        .await
+        .map_err(|FacadeError(message)| message)
+}
+
+struct FacadeError(String);
+
+impl From<RepositoryError> for FacadeError {
+    fn from(e: RepositoryError) -> Self {
+        Self(format!("Failed to run unit of work: {:?}", e))
+    }
+}
@@ -3,8 +3,8 @@
 //! このモジュールは繰り返しルール、調整、詳細、タスク・サブタスク関連付けの
 //! Service層とのインターフェースを提供します。
 
-use crate::services::recurrence_service;
 use crate::InfrastructureRepositoriesTrait;
+use crate::services::recurrence_service;
 use flequit_model::{
     models::task_projects::{
         recurrence_adjustment::RecurrenceAdjustment,
@@ -34,7 +34,14 @@ pub async fn create_recurrence_rule<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::create_recurrence_rule(repositories, project_id, rule, user_id).await
+    match repositories
+        .run_in_unit_of_work(recurrence_service::create_recurrence_rule(
+            repositories,
+            project_id,
+            rule,
+            user_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -83,14 +90,15 @@ pub async fn update_recurrence_rule<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::update_recurrence_rule(
-        repositories,
-        project_id,
-        rule_id,
-        patch,
-        user_id,
-    )
-    .await
+    match repositories
+        .run_in_unit_of_work(recurrence_service::update_recurrence_rule(
+            repositories,
+            project_id,
+            rule_id,
+            patch,
+            user_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -107,7 +115,14 @@ pub async fn delete_recurrence_rule<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::delete_recurrence_rule(repositories, project_id, &rule_id).await {
+    match repositories
+        .run_in_unit_of_work(recurrence_service::delete_recurrence_rule(
+            repositories,
+            project_id,
+            &rule_id,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to delete recurrence rule: {:?}", e)),
@@ -127,7 +142,12 @@ pub async fn create_recurrence_adjustment<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::create_recurrence_adjustment(repositories, project_id, adjustment)
+    match repositories
+        .run_in_unit_of_work(recurrence_service::create_recurrence_adjustment(
+            repositories,
+            project_id,
+            adjustment,
+        ))
         .await
     {
         Ok(_) => Ok(true),
@@ -166,7 +186,12 @@ pub async fn delete_recurrence_adjustment<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::delete_recurrence_adjustment(repositories, project_id, &adjustment_id)
+    match repositories
+        .run_in_unit_of_work(recurrence_service::delete_recurrence_adjustment(
+            repositories,
+            project_id,
+            &adjustment_id,
+        ))
         .await
     {
         Ok(_) => Ok(true),
@@ -188,7 +213,14 @@ pub async fn create_recurrence_details<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::create_recurrence_details(repositories, project_id, details).await {
+    match repositories
+        .run_in_unit_of_work(recurrence_service::create_recurrence_details(
+            repositories,
+            project_id,
+            details,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to create recurrence details: {:?}", e)),
@@ -221,7 +253,14 @@ pub async fn update_recurrence_details<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::update_recurrence_details(repositories, project_id, details).await {
+    match repositories
+        .run_in_unit_of_work(recurrence_service::update_recurrence_details(
+            repositories,
+            project_id,
+            details,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to update recurrence details: {:?}", e)),
@@ -237,7 +276,13 @@ pub async fn delete_recurrence_details<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::delete_recurrence_details(repositories, project_id, details_id).await
+    match repositories
+        .run_in_unit_of_work(recurrence_service::delete_recurrence_details(
+            repositories,
+            project_id,
+            details_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -259,13 +304,14 @@ pub async fn create_task_recurrence<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::create_task_recurrence(
-        repositories,
-        project_id,
-        task_id,
-        recurrence_rule_id,
-    )
-    .await
+    match repositories
+        .run_in_unit_of_work(recurrence_service::create_task_recurrence(
+            repositories,
+            project_id,
+            task_id,
+            recurrence_rule_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -299,7 +345,14 @@ pub async fn delete_task_recurrence<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::delete_task_recurrence(repositories, project_id, task_id).await {
+    match repositories
+        .run_in_unit_of_work(recurrence_service::delete_task_recurrence(
+            repositories,
+            project_id,
+            task_id,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to delete task recurrence: {:?}", e)),
@@ -320,13 +373,14 @@ pub async fn create_subtask_recurrence<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::create_subtask_recurrence(
-        repositories,
-        project_id,
-        subtask_id,
-        recurrence_rule_id,
-    )
-    .await
+    match repositories
+        .run_in_unit_of_work(recurrence_service::create_subtask_recurrence(
+            repositories,
+            project_id,
+            subtask_id,
+            recurrence_rule_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -364,7 +418,13 @@ pub async fn delete_subtask_recurrence<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match recurrence_service::delete_subtask_recurrence(repositories, project_id, subtask_id).await
+    match repositories
+        .run_in_unit_of_work(recurrence_service::delete_subtask_recurrence(
+            repositories,
+            project_id,
+            subtask_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -0,0 +1,179 @@
+use crate::InfrastructureRepositoriesTrait;
+use crate::services::reminder_service::{self, ReminderSweep};
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::reminder::{PartialReminder, Reminder};
+use flequit_model::types::id_types::{ProjectId, ReminderId, SubTaskId, TaskId, UserId};
+use flequit_types::errors::service_error::ServiceError;
+
+pub async fn list_reminders<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: Option<&TaskId>,
+    subtask_id: Option<&SubTaskId>,
+) -> Result<Vec<Reminder>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match reminder_service::list_reminders(repositories, project_id, task_id, subtask_id).await {
+        Ok(reminders) => Ok(reminders),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to list reminders: {:?}", e)),
+    }
+}
+
+pub async fn get_reminder<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    reminder_id: &ReminderId,
+) -> Result<Option<Reminder>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match reminder_service::get_reminder(repositories, project_id, reminder_id).await {
+        Ok(reminder) => Ok(reminder),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to get reminder: {:?}", e)),
+    }
+}
+
+pub async fn create_reminder<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    reminder: &Reminder,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(reminder_service::create_reminder(
+            repositories,
+            project_id,
+            reminder,
+            user_id,
+        ))
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to create reminder: {:?}", e)),
+    }
+}
+
+pub async fn update_reminder<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    reminder_id: &ReminderId,
+    patch: &PartialReminder,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(reminder_service::update_reminder(
+            repositories,
+            project_id,
+            reminder_id,
+            patch,
+            user_id,
+        ))
+        .await
+    {
+        Ok(changed) => Ok(changed),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to update reminder: {:?}", e)),
+    }
+}
+
+pub async fn delete_reminder<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    reminder_id: &ReminderId,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(reminder_service::delete_reminder(
+            repositories,
+            project_id,
+            reminder_id,
+            user_id,
+        ))
+        .await
+    {
+        Ok(deleted) => Ok(deleted),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to delete reminder: {:?}", e)),
+    }
+}
+
+pub async fn carry_over_task_reminders<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    from_task_id: &TaskId,
+    to_task_id: &TaskId,
+    user_id: &UserId,
+) -> Result<Vec<Reminder>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(reminder_service::carry_over_task_reminders(
+            repositories,
+            project_id,
+            from_task_id,
+            to_task_id,
+            user_id,
+        ))
+        .await
+    {
+        Ok(reminders) => Ok(reminders),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to carry over task reminders: {:?}", e)),
+    }
+}
+
+pub async fn carry_over_subtask_reminders<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    from_subtask_id: &SubTaskId,
+    to_subtask_id: &SubTaskId,
+    user_id: &UserId,
+) -> Result<Vec<Reminder>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(reminder_service::carry_over_subtask_reminders(
+            repositories,
+            project_id,
+            from_subtask_id,
+            to_subtask_id,
+            user_id,
+        ))
+        .await
+    {
+        Ok(reminders) => Ok(reminders),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to carry over subtask reminders: {:?}", e)),
+    }
+}
+
+pub async fn fire_due_reminders<R>(
+    repositories: &R,
+    now: DateTime<Utc>,
+) -> Result<ReminderSweep, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(reminder_service::fire_due_reminders(repositories, now))
+        .await
+    {
+        Ok(sweep) => Ok(sweep),
+        Err(e) => Err(format!("Failed to fire due reminders: {:?}", e)),
+    }
+}
@@ -0,0 +1,19 @@
+use crate::InfrastructureRepositoriesTrait;
+use crate::services::schedule_service::{self, Schedule};
+use flequit_model::types::id_types::{ProjectId, TaskListId};
+use flequit_types::errors::service_error::ServiceError;
+
+pub async fn compute_schedule<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    list_id: Option<&TaskListId>,
+) -> Result<Schedule, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match schedule_service::compute_schedule(repositories, project_id, list_id).await {
+        Ok(schedule) => Ok(schedule),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to compute schedule: {:?}", e)),
+    }
+}
@@ -0,0 +1,154 @@
+use crate::InfrastructureRepositoriesTrait;
+use crate::services::snooze_service::{self, SnoozeClock, SnoozeOption};
+use flequit_model::models::task_projects::snooze::Snooze;
+use flequit_model::types::id_types::{ProjectId, ReminderId, SnoozeId, SubTaskId, TaskId, UserId};
+use flequit_types::errors::service_error::ServiceError;
+
+pub async fn list_snoozes<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: Option<&TaskId>,
+) -> Result<Vec<Snooze>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match snooze_service::list_snoozes(repositories, project_id, task_id).await {
+        Ok(snoozes) => Ok(snoozes),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to list snoozes: {:?}", e)),
+    }
+}
+
+pub async fn snooze_task<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    option: &SnoozeOption,
+    clock: &SnoozeClock,
+    user_id: &UserId,
+) -> Result<Snooze, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(snooze_service::snooze_task(
+            repositories,
+            project_id,
+            task_id,
+            option,
+            clock,
+            user_id,
+        ))
+        .await
+    {
+        Ok(snooze) => Ok(snooze),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to snooze task: {:?}", e)),
+    }
+}
+
+pub async fn snooze_subtask<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    subtask_id: &SubTaskId,
+    option: &SnoozeOption,
+    clock: &SnoozeClock,
+    user_id: &UserId,
+) -> Result<Snooze, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(snooze_service::snooze_subtask(
+            repositories,
+            project_id,
+            subtask_id,
+            option,
+            clock,
+            user_id,
+        ))
+        .await
+    {
+        Ok(snooze) => Ok(snooze),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to snooze subtask: {:?}", e)),
+    }
+}
+
+pub async fn snooze_reminder<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    reminder_id: &ReminderId,
+    option: &SnoozeOption,
+    clock: &SnoozeClock,
+    user_id: &UserId,
+) -> Result<Snooze, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(snooze_service::snooze_reminder(
+            repositories,
+            project_id,
+            reminder_id,
+            option,
+            clock,
+            user_id,
+        ))
+        .await
+    {
+        Ok(snooze) => Ok(snooze),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to snooze reminder: {:?}", e)),
+    }
+}
+
+pub async fn cancel_deferral<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    subtask_id: Option<&SubTaskId>,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(snooze_service::cancel_deferral(
+            repositories,
+            project_id,
+            task_id,
+            subtask_id,
+            user_id,
+        ))
+        .await
+    {
+        Ok(changed) => Ok(changed),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to cancel deferral: {:?}", e)),
+    }
+}
+
+pub async fn delete_snooze<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    snooze_id: &SnoozeId,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(snooze_service::delete_snooze(
+            repositories,
+            project_id,
+            snooze_id,
+            user_id,
+        ))
+        .await
+    {
+        Ok(deleted) => Ok(deleted),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to delete snooze: {:?}", e)),
+    }
+}
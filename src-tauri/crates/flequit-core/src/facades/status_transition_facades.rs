@@ -0,0 +1,95 @@
+use crate::InfrastructureRepositoriesTrait;
+use crate::services::status_transition_service;
+use flequit_model::models::task_projects::status_transition_rule::{
+    PartialStatusTransitionRule, StatusTransitionRule,
+};
+use flequit_model::types::id_types::{ProjectId, StatusTransitionRuleId, UserId};
+use flequit_types::errors::service_error::ServiceError;
+
+pub async fn list_status_transition_rules<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+) -> Result<Vec<StatusTransitionRule>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match status_transition_service::list_status_transition_rules(repositories, project_id).await {
+        Ok(rules) => Ok(rules),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to list status transition rules: {:?}", e)),
+    }
+}
+
+pub async fn create_status_transition_rule<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    rule: &StatusTransitionRule,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(status_transition_service::create_status_transition_rule(
+            repositories,
+            project_id,
+            rule,
+            user_id,
+        ))
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to create status transition rule: {:?}", e)),
+    }
+}
+
+pub async fn update_status_transition_rule<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    rule_id: &StatusTransitionRuleId,
+    patch: &PartialStatusTransitionRule,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(status_transition_service::update_status_transition_rule(
+            repositories,
+            project_id,
+            rule_id,
+            patch,
+            user_id,
+        ))
+        .await
+    {
+        Ok(changed) => Ok(changed),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to update status transition rule: {:?}", e)),
+    }
+}
+
+pub async fn delete_status_transition_rule<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    rule_id: &StatusTransitionRuleId,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(status_transition_service::delete_status_transition_rule(
+            repositories,
+            project_id,
+            rule_id,
+            user_id,
+        ))
+        .await
+    {
+        Ok(deleted) => Ok(deleted),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to delete status transition rule: {:?}", e)),
+    }
+}
@@ -1,5 +1,5 @@
-use crate::services::subtask_assignment_service as service;
 use crate::InfrastructureRepositoriesTrait;
+use crate::services::subtask_assignment_service as service;
 use flequit_model::models::task_projects::subtask_assignment::SubTaskAssignment;
 use flequit_model::types::id_types::{ProjectId, SubTaskId, UserId};
 use flequit_types::errors::service_error::ServiceError;
@@ -14,14 +14,15 @@ pub async fn add<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match service::add_subtask_assignment(
-        repositories,
-        project_id,
-        subtask_id,
-        assigned_user_id,
-        user_id,
-    )
-    .await
+    match repositories
+        .run_in_unit_of_work(service::add_subtask_assignment(
+            repositories,
+            project_id,
+            subtask_id,
+            assigned_user_id,
+            user_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -38,7 +39,15 @@ pub async fn remove<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match service::remove_subtask_assignment(repositories, project_id, subtask_id, user_id).await {
+    match repositories
+        .run_in_unit_of_work(service::remove_subtask_assignment(
+            repositories,
+            project_id,
+            subtask_id,
+            user_id,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to remove subtask assignment: {:?}", e)),
@@ -85,14 +94,15 @@ pub async fn update<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match service::update_subtask_assignments(
-        repositories,
-        project_id,
-        subtask_id,
-        user_ids,
-        user_id,
-    )
-    .await
+    match repositories
+        .run_in_unit_of_work(service::update_subtask_assignments(
+            repositories,
+            project_id,
+            subtask_id,
+            user_ids,
+            user_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -108,12 +118,13 @@ pub async fn remove_all_by_subtask_id<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match service::remove_all_subtask_assignments_by_subtask_id(
-        repositories,
-        project_id,
-        subtask_id,
-    )
-    .await
+    match repositories
+        .run_in_unit_of_work(service::remove_all_subtask_assignments_by_subtask_id(
+            repositories,
+            project_id,
+            subtask_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -132,7 +143,12 @@ pub async fn remove_all_by_user_id<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match service::remove_all_subtask_assignments_by_user_id(repositories, project_id, user_id)
+    match repositories
+        .run_in_unit_of_work(service::remove_all_subtask_assignments_by_user_id(
+            repositories,
+            project_id,
+            user_id,
+        ))
         .await
     {
         Ok(_) => Ok(true),
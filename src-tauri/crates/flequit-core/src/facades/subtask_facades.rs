@@ -1,9 +1,11 @@
-use crate::services::{subtask_service, subtask_tag_service, tag_service};
+use super::in_unit_of_work;
 use crate::InfrastructureRepositoriesTrait;
+use crate::services::{subtask_service, subtask_tag_service, tag_service};
 use flequit_model::models::task_projects::subtask::{PartialSubTask, SubTask};
 use flequit_model::models::task_projects::subtask_tag::SubTaskTag;
 use flequit_model::models::task_projects::tag::Tag;
 use flequit_model::types::id_types::{ProjectId, SubTaskId, TagId, UserId};
+use flequit_model::types::task_types::TaskStatus;
 use flequit_types::errors::service_error::ServiceError;
 
 pub async fn create_sub_task<R>(
@@ -15,7 +17,15 @@ pub async fn create_sub_task<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match subtask_service::create_subtask(repositories, project_id, subtask, user_id).await {
+    match repositories
+        .run_in_unit_of_work(subtask_service::create_subtask(
+            repositories,
+            project_id,
+            subtask,
+            user_id,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to create subtask: {:?}", e)),
@@ -62,7 +72,14 @@ pub async fn update_sub_task<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match subtask_service::update_subtask(repositories, project_id, subtask_id, patch, user_id)
+    match repositories
+        .run_in_unit_of_work(subtask_service::update_subtask(
+            repositories,
+            project_id,
+            subtask_id,
+            patch,
+            user_id,
+        ))
         .await
     {
         Ok(changed) => Ok(changed),
@@ -71,6 +88,35 @@ where
     }
 }
 
+/// サブタスクのステータスを変更する（遷移ポリシーの確認と作業日時の連動を含む）
+pub async fn update_sub_task_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    subtask_id: &SubTaskId,
+    status: &TaskStatus,
+    reason: Option<&str>,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(subtask_service::update_subtask_status(
+            repositories,
+            project_id,
+            subtask_id,
+            status,
+            reason,
+            user_id,
+        ))
+        .await
+    {
+        Ok(changed) => Ok(changed),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to update subtask status: {:?}", e)),
+    }
+}
+
 pub async fn delete_sub_task<R>(
     repositories: &R,
     project_id: &ProjectId,
@@ -79,7 +125,14 @@ pub async fn delete_sub_task<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match subtask_service::delete_subtask(repositories, project_id, id).await {
+    match repositories
+        .run_in_unit_of_work(subtask_service::delete_subtask(
+            repositories,
+            project_id,
+            id,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to delete subtask: {:?}", e)),
@@ -97,14 +150,15 @@ pub async fn add_subtask_tag_relation<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match subtask_tag_service::add_subtask_tag_relation(
-        repositories,
-        project_id,
-        subtask_id,
-        tag_id,
-        user_id,
-    )
-    .await
+    match repositories
+        .run_in_unit_of_work(subtask_tag_service::add_subtask_tag_relation(
+            repositories,
+            project_id,
+            subtask_id,
+            tag_id,
+            user_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -122,50 +176,53 @@ pub async fn add_subtask_tag<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    // 1) 既存タグ検索（完全一致）
-    let existing = match tag_service::list_tags(repositories, project_id).await {
-        Ok(all) => all.into_iter().find(|t| t.name == tag_name),
-        Err(ServiceError::ValidationError(msg)) => return Err(msg),
-        Err(e) => return Err(format!("Failed to list tags: {:?}", e)),
-    };
+    in_unit_of_work(repositories, async {
+        // 1) 既存タグ検索（完全一致）
+        let existing = match tag_service::list_tags(repositories, project_id).await {
+            Ok(all) => all.into_iter().find(|t| t.name == tag_name),
+            Err(ServiceError::ValidationError(msg)) => return Err(msg),
+            Err(e) => return Err(format!("Failed to list tags: {:?}", e)),
+        };
 
-    // 2) 無ければ作成
-    let tag: Tag = if let Some(existing_tag) = existing {
-        existing_tag
-    } else {
-        use uuid::Uuid;
-        let now = chrono::Utc::now();
-        let new_tag = Tag {
-            id: TagId::from(Uuid::new_v4()),
-            name: tag_name.to_string(),
-            color: None,
-            order_index: None,
-            created_at: now,
-            updated_at: now,
-            deleted: false,
-            updated_by: *user_id,
+        // 2) 無ければ作成
+        let tag: Tag = if let Some(existing_tag) = existing {
+            existing_tag
+        } else {
+            use uuid::Uuid;
+            let now = chrono::Utc::now();
+            let new_tag = Tag {
+                id: TagId::from(Uuid::new_v4()),
+                name: tag_name.to_string(),
+                color: None,
+                order_index: None,
+                created_at: now,
+                updated_at: now,
+                deleted: false,
+                updated_by: *user_id,
+            };
+            match tag_service::create_tag(repositories, project_id, &new_tag, user_id).await {
+                Ok(_) => new_tag,
+                Err(ServiceError::ValidationError(msg)) => return Err(msg),
+                Err(e) => return Err(format!("Failed to create tag: {:?}", e)),
+            }
         };
-        match tag_service::create_tag(repositories, project_id, &new_tag, user_id).await {
-            Ok(_) => new_tag,
-            Err(ServiceError::ValidationError(msg)) => return Err(msg),
-            Err(e) => return Err(format!("Failed to create tag: {:?}", e)),
-        }
-    };
 
-    // 3) 関連付け
-    match subtask_tag_service::add_subtask_tag_relation(
-        repositories,
-        project_id,
-        subtask_id,
-        &tag.id,
-        user_id,
-    )
+        // 3) 関連付け
+        match subtask_tag_service::add_subtask_tag_relation(
+            repositories,
+            project_id,
+            subtask_id,
+            &tag.id,
+            user_id,
+        )
+        .await
+        {
+            Ok(_) => Ok(tag),
+            Err(ServiceError::ValidationError(msg)) => Err(msg),
+            Err(e) => Err(format!("Failed to add subtask-tag relation: {:?}", e)),
+        }
+    })
     .await
-    {
-        Ok(_) => Ok(tag),
-        Err(ServiceError::ValidationError(msg)) => Err(msg),
-        Err(e) => Err(format!("Failed to add subtask-tag relation: {:?}", e)),
-    }
 }
 
 pub async fn remove_subtask_tag_relation<R>(
@@ -177,13 +234,14 @@ pub async fn remove_subtask_tag_relation<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match subtask_tag_service::remove_subtask_tag_relation(
-        repositories,
-        project_id,
-        subtask_id,
-        tag_id,
-    )
-    .await
+    match repositories
+        .run_in_unit_of_work(subtask_tag_service::remove_subtask_tag_relation(
+            repositories,
+            project_id,
+            subtask_id,
+            tag_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -232,14 +290,15 @@ pub async fn update_subtask_tag_relations<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match subtask_tag_service::update_subtask_tag_relations(
-        repositories,
-        project_id,
-        subtask_id,
-        tag_ids,
-        user_id,
-    )
-    .await
+    match repositories
+        .run_in_unit_of_work(subtask_tag_service::update_subtask_tag_relations(
+            repositories,
+            project_id,
+            subtask_id,
+            tag_ids,
+            user_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -255,12 +314,13 @@ pub async fn remove_all_subtask_tags_by_subtask_id<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match subtask_tag_service::remove_all_subtask_tags_by_subtask_id(
-        repositories,
-        project_id,
-        subtask_id,
-    )
-    .await
+    match repositories
+        .run_in_unit_of_work(subtask_tag_service::remove_all_subtask_tags_by_subtask_id(
+            repositories,
+            project_id,
+            subtask_id,
+        ))
+        .await
     {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
@@ -279,7 +339,12 @@ pub async fn remove_all_subtask_tags_by_tag_id<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match subtask_tag_service::remove_all_subtask_tags_by_tag_id(repositories, project_id, tag_id)
+    match repositories
+        .run_in_unit_of_work(subtask_tag_service::remove_all_subtask_tags_by_tag_id(
+            repositories,
+            project_id,
+            tag_id,
+        ))
         .await
     {
         Ok(_) => Ok(true),
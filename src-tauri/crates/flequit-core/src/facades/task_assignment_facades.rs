@@ -1,5 +1,5 @@
-use crate::services::task_assignment_service as service;
 use crate::InfrastructureRepositoriesTrait;
+use crate::services::task_assignment_service as service;
 use flequit_model::models::task_projects::task_assignment::TaskAssignment;
 use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
 use flequit_types::errors::service_error::ServiceError;
@@ -14,7 +14,14 @@ pub async fn add<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match service::add_task_assignment(repositories, project_id, task_id, assigned_user_id, user_id)
+    match repositories
+        .run_in_unit_of_work(service::add_task_assignment(
+            repositories,
+            project_id,
+            task_id,
+            assigned_user_id,
+            user_id,
+        ))
         .await
     {
         Ok(_) => Ok(true),
@@ -32,7 +39,15 @@ pub async fn remove<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match service::remove_task_assignment(repositories, project_id, task_id, user_id).await {
+    match repositories
+        .run_in_unit_of_work(service::remove_task_assignment(
+            repositories,
+            project_id,
+            task_id,
+            user_id,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to remove task assignment: {:?}", e)),
@@ -79,7 +94,14 @@ pub async fn update<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match service::update_task_assignments(repositories, project_id, task_id, user_ids, user_id)
+    match repositories
+        .run_in_unit_of_work(service::update_task_assignments(
+            repositories,
+            project_id,
+            task_id,
+            user_ids,
+            user_id,
+        ))
         .await
     {
         Ok(_) => Ok(true),
@@ -96,7 +118,14 @@ pub async fn remove_all_by_task_id<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match service::remove_all_task_assignments_by_task_id(repositories, project_id, task_id).await {
+    match repositories
+        .run_in_unit_of_work(service::remove_all_task_assignments_by_task_id(
+            repositories,
+            project_id,
+            task_id,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!(
@@ -114,7 +143,14 @@ pub async fn remove_all_by_user_id<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match service::remove_all_task_assignments_by_user_id(repositories, project_id, user_id).await {
+    match repositories
+        .run_in_unit_of_work(service::remove_all_task_assignments_by_user_id(
+            repositories,
+            project_id,
+            user_id,
+        ))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!(
@@ -0,0 +1,84 @@
+use crate::InfrastructureRepositoriesTrait;
+use crate::services::task_dependency_service as service;
+use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
+use flequit_types::errors::service_error::ServiceError;
+
+pub async fn add<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    depends_on_task_id: &TaskId,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(service::add_task_dependency(
+            repositories,
+            project_id,
+            task_id,
+            depends_on_task_id,
+            user_id,
+        ))
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to add task dependency: {:?}", e)),
+    }
+}
+
+pub async fn remove<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    depends_on_task_id: &TaskId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(service::remove_task_dependency(
+            repositories,
+            project_id,
+            task_id,
+            depends_on_task_id,
+        ))
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to remove task dependency: {:?}", e)),
+    }
+}
+
+pub async fn get_blocking_task_ids<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+) -> Result<Vec<TaskId>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match service::list_blocking_task_ids(repositories, project_id, task_id).await {
+        Ok(task_ids) => Ok(task_ids),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to get blocking task IDs: {:?}", e)),
+    }
+}
+
+pub async fn get_dependent_task_ids<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+) -> Result<Vec<TaskId>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match service::list_dependent_task_ids(repositories, project_id, task_id).await {
+        Ok(task_ids) => Ok(task_ids),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to get dependent task IDs: {:?}", e)),
+    }
+}
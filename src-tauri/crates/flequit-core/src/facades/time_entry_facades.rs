@@ -0,0 +1,179 @@
+use crate::InfrastructureRepositoriesTrait;
+use crate::services::time_entry_service::{self, TimeSummaryCondition, TimeTotal};
+use flequit_model::models::task_projects::time_entry::{PartialTimeEntry, TimeEntry};
+use flequit_model::types::id_types::{ProjectId, SubTaskId, TaskId, TimeEntryId, UserId};
+use flequit_types::errors::service_error::ServiceError;
+
+pub async fn start_timer<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    subtask_id: Option<&SubTaskId>,
+    note: Option<String>,
+    user_id: &UserId,
+) -> Result<TimeEntry, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(time_entry_service::start_timer(
+            repositories,
+            project_id,
+            task_id,
+            subtask_id,
+            note,
+            user_id,
+        ))
+        .await
+    {
+        Ok(entry) => Ok(entry),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to start timer: {:?}", e)),
+    }
+}
+
+pub async fn stop_timer<R>(repositories: &R, user_id: &UserId) -> Result<Option<TimeEntry>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(time_entry_service::stop_timer(repositories, user_id))
+        .await
+    {
+        Ok(entry) => Ok(entry),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to stop timer: {:?}", e)),
+    }
+}
+
+pub async fn get_running_time_entry<R>(
+    repositories: &R,
+    user_id: &UserId,
+) -> Result<Option<TimeEntry>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match time_entry_service::find_running_time_entry(repositories, user_id).await {
+        Ok(entry) => Ok(entry),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to get running time entry: {:?}", e)),
+    }
+}
+
+pub async fn create_time_entry<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    entry: &TimeEntry,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(time_entry_service::create_time_entry(
+            repositories,
+            project_id,
+            entry,
+            user_id,
+        ))
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to create time entry: {:?}", e)),
+    }
+}
+
+pub async fn get_time_entry<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    entry_id: &TimeEntryId,
+) -> Result<Option<TimeEntry>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match time_entry_service::get_time_entry(repositories, project_id, entry_id).await {
+        Ok(entry) => Ok(entry),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to get time entry: {:?}", e)),
+    }
+}
+
+pub async fn list_time_entries<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: Option<&TaskId>,
+) -> Result<Vec<TimeEntry>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match time_entry_service::list_time_entries(repositories, project_id, task_id).await {
+        Ok(entries) => Ok(entries),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to list time entries: {:?}", e)),
+    }
+}
+
+pub async fn update_time_entry<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    entry_id: &TimeEntryId,
+    patch: &PartialTimeEntry,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(time_entry_service::update_time_entry(
+            repositories,
+            project_id,
+            entry_id,
+            patch,
+            user_id,
+        ))
+        .await
+    {
+        Ok(changed) => Ok(changed),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to update time entry: {:?}", e)),
+    }
+}
+
+pub async fn delete_time_entry<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    entry_id: &TimeEntryId,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(time_entry_service::delete_time_entry(
+            repositories,
+            project_id,
+            entry_id,
+            user_id,
+        ))
+        .await
+    {
+        Ok(deleted) => Ok(deleted),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to delete time entry: {:?}", e)),
+    }
+}
+
+pub async fn summarize_time<R>(
+    repositories: &R,
+    condition: &TimeSummaryCondition,
+) -> Result<Vec<TimeTotal>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match time_entry_service::summarize_time(repositories, condition).await {
+        Ok(totals) => Ok(totals),
+        Err(ServiceError::InvalidArgument(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to summarize time: {:?}", e)),
+    }
+}
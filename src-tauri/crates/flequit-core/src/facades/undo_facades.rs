@@ -0,0 +1,967 @@
+//! 取り消し・やり直しファサード
+//!
+//! セッションの履歴からステップを取り出し、その操作を1つの作業単位として実行する。
+//! 実行した操作を打ち消すステップは反対側の履歴（取り消しならやり直し）へ積む。
+
+use super::in_unit_of_work;
+use super::{comment_facades, project_facades, tag_facades, task_facades, task_list_facades};
+use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
+use crate::services::{
+    recurrence_service, reminder_service, snooze_service, status_transition_service,
+    subtask_assignment_service, subtask_service, subtask_tag_service, task_assignment_service,
+    task_dependency_service, task_tag_service, time_entry_service, workflow_status_service,
+};
+use crate::undo::{UndoJournal, UndoOperation, UndoStatus, UndoStep, UndoStepSummary, UndoTarget};
+use chrono::{DateTime, Utc};
+use flequit_model::models::activity::activity_entry::FieldChange;
+use flequit_model::models::task_projects::recurrence_rule::RecurrenceRule;
+use flequit_model::models::task_projects::reminder::Reminder;
+use flequit_model::models::task_projects::snooze::Snooze;
+use flequit_model::models::task_projects::status_transition_rule::StatusTransitionRule;
+use flequit_model::models::task_projects::subtask::SubTask;
+use flequit_model::models::task_projects::time_entry::TimeEntry;
+use flequit_model::models::task_projects::workflow_status::WorkflowStatus;
+use flequit_model::traits::TransactionManager;
+use flequit_model::types::id_types::{
+    CommentId, ProjectId, RecurrenceRuleId, ReminderId, SnoozeId, StatusTransitionRuleId,
+    SubTaskId, TagId, TaskId, TaskListId, TimeEntryId, UserId, WorkflowStatusId,
+};
+use flequit_repository::repositories::base_repository_trait::Repository;
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::service_error::ServiceError;
+use sea_orm::DatabaseTransaction;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+/// セッションで最後に行った操作を取り消す
+///
+/// 取り消した操作の概要を返す。取り消す操作がない場合は `None`。
+/// 適用できなかったステップは、同期などで対象が変わっており再試行しても
+/// 成功しないため履歴から破棄する。
+pub async fn undo<R>(
+    repositories: &R,
+    session: &str,
+    user_id: &UserId,
+) -> Result<Option<UndoStepSummary>, String>
+where
+    R: InfrastructureRepositoriesTrait
+        + TransactionManager<Transaction = DatabaseTransaction>
+        + Send
+        + Sync,
+{
+    let journal = UndoJournal::global();
+    let Some(step) = journal.pop_undo(session) else {
+        return Ok(None);
+    };
+    let summary = step.summary();
+    let inverse = apply_step(repositories, &step, user_id).await?;
+    journal.push_redo(session, inverse);
+    Ok(Some(summary))
+}
+
+/// セッションで最後に取り消した操作をやり直す
+pub async fn redo<R>(
+    repositories: &R,
+    session: &str,
+    user_id: &UserId,
+) -> Result<Option<UndoStepSummary>, String>
+where
+    R: InfrastructureRepositoriesTrait
+        + TransactionManager<Transaction = DatabaseTransaction>
+        + Send
+        + Sync,
+{
+    let journal = UndoJournal::global();
+    let Some(step) = journal.pop_redo(session) else {
+        return Ok(None);
+    };
+    let summary = step.summary();
+    let inverse = apply_step(repositories, &step, user_id).await?;
+    journal.push_undo(session, inverse);
+    Ok(Some(summary))
+}
+
+/// セッションで次に取り消し・やり直しされる操作
+pub fn undo_status(session: &str) -> UndoStatus {
+    UndoJournal::global().status(session)
+}
+
+/// ステップの操作を順に実行し、それを打ち消すステップを返す
+async fn apply_step<R>(
+    repositories: &R,
+    step: &UndoStep,
+    user_id: &UserId,
+) -> Result<UndoStep, String>
+where
+    R: InfrastructureRepositoriesTrait
+        + TransactionManager<Transaction = DatabaseTransaction>
+        + Send
+        + Sync,
+{
+    // 取り消し・やり直し自体は新しい操作として記録しない
+    crate::undo::without_session(in_unit_of_work(repositories, async {
+        let now = Utc::now();
+        let mut inverses = Vec::with_capacity(step.operations.len());
+        for operation in &step.operations {
+            inverses.push(apply_operation(repositories, operation, user_id, &now).await?);
+        }
+        inverses.reverse();
+        Ok(step.with_operations(inverses))
+    }))
+    .await
+}
+
+async fn apply_operation<R>(
+    repositories: &R,
+    operation: &UndoOperation,
+    user_id: &UserId,
+    now: &DateTime<Utc>,
+) -> Result<UndoOperation, String>
+where
+    R: InfrastructureRepositoriesTrait
+        + TransactionManager<Transaction = DatabaseTransaction>
+        + Send
+        + Sync,
+{
+    match operation {
+        UndoOperation::Delete(target) => delete(repositories, target, user_id, now).await,
+        UndoOperation::Restore(target) => {
+            restore(repositories, target, user_id, now).await?;
+            Ok(UndoOperation::Delete(target.clone()))
+        }
+        UndoOperation::Recreate { target, snapshot } => {
+            recreate(repositories, target, snapshot, user_id, now).await?;
+            Ok(UndoOperation::Delete(target.clone()))
+        }
+        UndoOperation::SetFields { target, values } => {
+            let previous = set_fields(repositories, target, values, user_id, now).await?;
+            Ok(UndoOperation::SetFields {
+                target: target.clone(),
+                values: previous,
+            })
+        }
+        UndoOperation::Link { target, related_id } => {
+            link(repositories, target, related_id, user_id).await?;
+            Ok(UndoOperation::Unlink {
+                target: target.clone(),
+                related_id: related_id.clone(),
+            })
+        }
+        UndoOperation::Unlink { target, related_id } => {
+            unlink(repositories, target, related_id).await?;
+            Ok(UndoOperation::Link {
+                target: target.clone(),
+                related_id: related_id.clone(),
+            })
+        }
+        UndoOperation::SetLinks {
+            target,
+            related_ids,
+        } => {
+            let previous = set_links(repositories, target, related_ids, user_id).await?;
+            Ok(UndoOperation::SetLinks {
+                target: target.clone(),
+                related_ids: previous,
+            })
+        }
+    }
+}
+
+async fn delete<R>(
+    repositories: &R,
+    target: &UndoTarget,
+    user_id: &UserId,
+    now: &DateTime<Utc>,
+) -> Result<UndoOperation, String>
+where
+    R: InfrastructureRepositoriesTrait
+        + TransactionManager<Transaction = DatabaseTransaction>
+        + Send
+        + Sync,
+{
+    let project_id = &target.project_id;
+    let id = target.entity_id.as_str();
+    let deleted = match target.entity {
+        EntityKind::Project => {
+            project_facades::delete_project(repositories, &ProjectId::from(id), user_id, now)
+                .await?
+        }
+        EntityKind::TaskList => {
+            task_list_facades::delete_task_list(
+                repositories,
+                project_id,
+                &TaskListId::from(id),
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::Task => {
+            task_facades::delete_task(repositories, project_id, &TaskId::from(id), user_id, now)
+                .await?
+        }
+        EntityKind::Tag => {
+            tag_facades::delete_tag(repositories, project_id, &TagId::from(id), user_id, now)
+                .await?
+        }
+        EntityKind::Comment => {
+            comment_facades::delete_comment(repositories, project_id, &CommentId::from(id), user_id)
+                .await?
+        }
+        // 物理削除は作り直せるよう削除前の状態を控えておく
+        EntityKind::SubTask => {
+            let subtask_id = SubTaskId::from(id);
+            let snapshot = repositories
+                .sub_tasks()
+                .find_by_id(project_id, &subtask_id)
+                .await
+                .map_err(|e| format!("Failed to get subtask: {:?}", e))?
+                .ok_or_else(|| not_found(target))?;
+            subtask_service::delete_subtask(repositories, project_id, &subtask_id)
+                .await
+                .map_err(service_error)?;
+            return recreate_operation(target, &snapshot);
+        }
+        EntityKind::RecurrenceRule => {
+            let snapshot = repositories
+                .recurrence_rules()
+                .find_by_id(project_id, &RecurrenceRuleId::from(id))
+                .await
+                .map_err(|e| format!("Failed to get recurrence rule: {:?}", e))?
+                .ok_or_else(|| not_found(target))?;
+            recurrence_service::delete_recurrence_rule(repositories, project_id, id)
+                .await
+                .map_err(service_error)?;
+            return recreate_operation(target, &snapshot);
+        }
+        EntityKind::TimeEntry => {
+            let entry_id = TimeEntryId::from(id);
+            let snapshot = repositories
+                .time_entries()
+                .find_by_id(project_id, &entry_id)
+                .await
+                .map_err(|e| format!("Failed to get time entry: {:?}", e))?
+                .ok_or_else(|| not_found(target))?;
+            time_entry_service::delete_time_entry(repositories, project_id, &entry_id, user_id)
+                .await
+                .map_err(service_error)?;
+            return recreate_operation(target, &snapshot);
+        }
+        EntityKind::Reminder => {
+            let reminder_id = ReminderId::from(id);
+            let snapshot = repositories
+                .reminders()
+                .find_by_id(project_id, &reminder_id)
+                .await
+                .map_err(|e| format!("Failed to get reminder: {:?}", e))?
+                .ok_or_else(|| not_found(target))?;
+            reminder_service::delete_reminder(repositories, project_id, &reminder_id, user_id)
+                .await
+                .map_err(service_error)?;
+            return recreate_operation(target, &snapshot);
+        }
+        EntityKind::Snooze => {
+            let snooze_id = SnoozeId::from(id);
+            let snapshot = repositories
+                .snoozes()
+                .find_by_id(project_id, &snooze_id)
+                .await
+                .map_err(|e| format!("Failed to get snooze: {:?}", e))?
+                .ok_or_else(|| not_found(target))?;
+            snooze_service::delete_snooze(repositories, project_id, &snooze_id, user_id)
+                .await
+                .map_err(service_error)?;
+            return recreate_operation(target, &snapshot);
+        }
+        EntityKind::WorkflowStatus => {
+            let workflow_status_id = WorkflowStatusId::from(id);
+            let snapshot = repositories
+                .workflow_statuses()
+                .find_by_id(project_id, &workflow_status_id)
+                .await
+                .map_err(|e| format!("Failed to get workflow status: {:?}", e))?
+                .ok_or_else(|| not_found(target))?;
+            workflow_status_service::delete_workflow_status(
+                repositories,
+                project_id,
+                &workflow_status_id,
+                user_id,
+            )
+            .await
+            .map_err(service_error)?;
+            return recreate_operation(target, &snapshot);
+        }
+        EntityKind::StatusTransitionRule => {
+            let rule_id = StatusTransitionRuleId::from(id);
+            let snapshot = repositories
+                .status_transition_rules()
+                .find_by_id(project_id, &rule_id)
+                .await
+                .map_err(|e| format!("Failed to get status transition rule: {:?}", e))?
+                .ok_or_else(|| not_found(target))?;
+            status_transition_service::delete_status_transition_rule(
+                repositories,
+                project_id,
+                &rule_id,
+                user_id,
+            )
+            .await
+            .map_err(service_error)?;
+            return recreate_operation(target, &snapshot);
+        }
+        _ => return Err(unsupported(target)),
+    };
+    if !deleted {
+        return Err(not_found(target));
+    }
+    Ok(UndoOperation::Restore(target.clone()))
+}
+
+async fn restore<R>(
+    repositories: &R,
+    target: &UndoTarget,
+    user_id: &UserId,
+    now: &DateTime<Utc>,
+) -> Result<(), String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let project_id = &target.project_id;
+    let id = target.entity_id.as_str();
+    let restored = match target.entity {
+        EntityKind::Project => {
+            project_facades::restore_project(repositories, &ProjectId::from(id), user_id, now)
+                .await?
+        }
+        EntityKind::TaskList => {
+            task_list_facades::restore_task_list(
+                repositories,
+                project_id,
+                &TaskListId::from(id),
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::Task => {
+            task_facades::restore_task(repositories, project_id, &TaskId::from(id), user_id, now)
+                .await?
+        }
+        EntityKind::Tag => {
+            tag_facades::restore_tag(repositories, project_id, &TagId::from(id), user_id, now)
+                .await?
+        }
+        EntityKind::Comment => {
+            comment_facades::restore_comment(
+                repositories,
+                project_id,
+                &CommentId::from(id),
+                user_id,
+            )
+            .await?
+        }
+        _ => return Err(unsupported(target)),
+    };
+    if !restored {
+        return Err(not_found(target));
+    }
+    Ok(())
+}
+
+/// 物理削除したエンティティを削除前の状態のまま保存し直す
+async fn recreate<R>(
+    repositories: &R,
+    target: &UndoTarget,
+    snapshot: &Value,
+    user_id: &UserId,
+    now: &DateTime<Utc>,
+) -> Result<(), String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let project_id = &target.project_id;
+    let event = DomainEvent::restored(target.entity, &target.entity_id).in_project(project_id);
+    match target.entity {
+        EntityKind::SubTask => {
+            let subtask: SubTask = from_snapshot(snapshot)?;
+            repositories
+                .sub_tasks()
+                .save(project_id, &subtask, user_id, now)
+                .await
+                .map_err(|e| format!("Failed to recreate subtask: {:?}", e))?;
+            events::publish(event.related_to(subtask.task_id).by(user_id));
+        }
+        EntityKind::RecurrenceRule => {
+            let rule: RecurrenceRule = from_snapshot(snapshot)?;
+            repositories
+                .recurrence_rules()
+                .save(project_id, &rule, user_id, now)
+                .await
+                .map_err(|e| format!("Failed to recreate recurrence rule: {:?}", e))?;
+            events::publish(event.by(user_id));
+        }
+        EntityKind::TimeEntry => {
+            let entry: TimeEntry = from_snapshot(snapshot)?;
+            repositories
+                .time_entries()
+                .save(project_id, &entry, user_id, now)
+                .await
+                .map_err(|e| format!("Failed to recreate time entry: {:?}", e))?;
+            events::publish(event.related_to(entry.task_id).by(user_id));
+        }
+        EntityKind::Reminder => {
+            let reminder: Reminder = from_snapshot(snapshot)?;
+            repositories
+                .reminders()
+                .save(project_id, &reminder, user_id, now)
+                .await
+                .map_err(|e| format!("Failed to recreate reminder: {:?}", e))?;
+            events::publish(event.related_to(reminder.task_id).by(user_id));
+        }
+        EntityKind::Snooze => {
+            let snooze: Snooze = from_snapshot(snapshot)?;
+            repositories
+                .snoozes()
+                .save(project_id, &snooze, user_id, now)
+                .await
+                .map_err(|e| format!("Failed to recreate snooze: {:?}", e))?;
+            events::publish(event.related_to(snooze.task_id).by(user_id));
+        }
+        EntityKind::WorkflowStatus => {
+            let workflow_status: WorkflowStatus = from_snapshot(snapshot)?;
+            repositories
+                .workflow_statuses()
+                .save(project_id, &workflow_status, user_id, now)
+                .await
+                .map_err(|e| format!("Failed to recreate workflow status: {:?}", e))?;
+            events::publish(event.by(user_id));
+        }
+        EntityKind::StatusTransitionRule => {
+            let rule: StatusTransitionRule = from_snapshot(snapshot)?;
+            repositories
+                .status_transition_rules()
+                .save(project_id, &rule, user_id, now)
+                .await
+                .map_err(|e| format!("Failed to recreate status transition rule: {:?}", e))?;
+            events::publish(event.by(user_id));
+        }
+        _ => return Err(unsupported(target)),
+    }
+    Ok(())
+}
+
+/// フィールドに値を設定し、設定前の値を返す
+async fn set_fields<R>(
+    repositories: &R,
+    target: &UndoTarget,
+    values: &Map<String, Value>,
+    user_id: &UserId,
+    now: &DateTime<Utc>,
+) -> Result<Map<String, Value>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let project_id = &target.project_id;
+    let id = target.entity_id.as_str();
+    let (changes, previous) = match target.entity {
+        EntityKind::Project => {
+            let projects = repositories.projects();
+            let before = projects
+                .find_by_id(project_id)
+                .await
+                .map_err(|e| format!("Failed to get project: {:?}", e))?
+                .ok_or_else(|| not_found(target))?;
+            let (after, previous) = merge_fields(&before, values, user_id, now)?;
+            projects
+                .save(&after, user_id, now)
+                .await
+                .map_err(|e| format!("Failed to update project: {:?}", e))?;
+            (events::field_changes(&before, &after), previous)
+        }
+        EntityKind::TaskList => {
+            set_project_entity_fields(
+                repositories.task_lists(),
+                target,
+                &TaskListId::from(id),
+                values,
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::Task => {
+            set_project_entity_fields(
+                repositories.tasks(),
+                target,
+                &TaskId::from(id),
+                values,
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::SubTask => {
+            set_project_entity_fields(
+                repositories.sub_tasks(),
+                target,
+                &SubTaskId::from(id),
+                values,
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::Tag => {
+            set_project_entity_fields(
+                repositories.tags(),
+                target,
+                &TagId::from(id),
+                values,
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::RecurrenceRule => {
+            set_project_entity_fields(
+                repositories.recurrence_rules(),
+                target,
+                &RecurrenceRuleId::from(id),
+                values,
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::TimeEntry => {
+            set_project_entity_fields(
+                repositories.time_entries(),
+                target,
+                &TimeEntryId::from(id),
+                values,
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::Reminder => {
+            set_project_entity_fields(
+                repositories.reminders(),
+                target,
+                &ReminderId::from(id),
+                values,
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::Snooze => {
+            set_project_entity_fields(
+                repositories.snoozes(),
+                target,
+                &SnoozeId::from(id),
+                values,
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::Comment => {
+            set_project_entity_fields(
+                repositories.comments(),
+                target,
+                &CommentId::from(id),
+                values,
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::WorkflowStatus => {
+            set_project_entity_fields(
+                repositories.workflow_statuses(),
+                target,
+                &WorkflowStatusId::from(id),
+                values,
+                user_id,
+                now,
+            )
+            .await?
+        }
+        EntityKind::StatusTransitionRule => {
+            set_project_entity_fields(
+                repositories.status_transition_rules(),
+                target,
+                &StatusTransitionRuleId::from(id),
+                values,
+                user_id,
+                now,
+            )
+            .await?
+        }
+        _ => return Err(unsupported(target)),
+    };
+
+    events::publish(
+        DomainEvent::updated(target.entity, id)
+            .in_project(project_id)
+            .with_changes(changes)
+            .by(user_id),
+    );
+    Ok(previous)
+}
+
+async fn set_project_entity_fields<T, Id, Repo>(
+    repository: &Repo,
+    target: &UndoTarget,
+    id: &Id,
+    values: &Map<String, Value>,
+    user_id: &UserId,
+    now: &DateTime<Utc>,
+) -> Result<(Vec<FieldChange>, Map<String, Value>), String>
+where
+    T: Serialize + DeserializeOwned + Send + Sync,
+    Id: Send + Sync,
+    Repo: ProjectRepository<T, Id>,
+{
+    let before = repository
+        .find_by_id(&target.project_id, id)
+        .await
+        .map_err(|e| format!("Failed to get {}: {:?}", target.entity, e))?
+        .ok_or_else(|| not_found(target))?;
+    let (after, previous) = merge_fields(&before, values, user_id, now)?;
+    repository
+        .save(&target.project_id, &after, user_id, now)
+        .await
+        .map_err(|e| format!("Failed to update {}: {:?}", target.entity, e))?;
+    Ok((events::field_changes(&before, &after), previous))
+}
+
+/// エンティティのJSON表現に値を上書きし、上書き後のエンティティと上書き前の値を返す
+fn merge_fields<T>(
+    entity: &T,
+    values: &Map<String, Value>,
+    user_id: &UserId,
+    now: &DateTime<Utc>,
+) -> Result<(T, Map<String, Value>), String>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let Ok(Value::Object(mut fields)) = serde_json::to_value(entity) else {
+        return Err("Entity is not serialized as an object".to_string());
+    };
+    let mut previous = Map::new();
+    for (field, value) in values {
+        let before = fields.insert(field.clone(), value.clone());
+        previous.insert(field.clone(), before.unwrap_or(Value::Null));
+    }
+    for (field, value) in [
+        ("updated_at", serde_json::to_value(now)),
+        ("updated_by", serde_json::to_value(user_id)),
+    ] {
+        if let (Some(current), Ok(value)) = (fields.get_mut(field), value) {
+            *current = value;
+        }
+    }
+    let merged = serde_json::from_value(Value::Object(fields))
+        .map_err(|e| format!("Failed to apply field values: {}", e))?;
+    Ok((merged, previous))
+}
+
+async fn link<R>(
+    repositories: &R,
+    target: &UndoTarget,
+    related_id: &str,
+    user_id: &UserId,
+) -> Result<(), String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let project_id = &target.project_id;
+    let id = target.entity_id.as_str();
+    match target.entity {
+        EntityKind::TaskTag => {
+            task_tag_service::add_task_tag_relation(
+                repositories,
+                project_id,
+                &TaskId::from(id),
+                &TagId::from(related_id),
+                user_id,
+            )
+            .await
+        }
+        EntityKind::SubTaskTag => {
+            subtask_tag_service::add_subtask_tag_relation(
+                repositories,
+                project_id,
+                &SubTaskId::from(id),
+                &TagId::from(related_id),
+                user_id,
+            )
+            .await
+        }
+        EntityKind::TaskAssignment => {
+            task_assignment_service::add_task_assignment(
+                repositories,
+                project_id,
+                &TaskId::from(id),
+                &UserId::from(related_id),
+                user_id,
+            )
+            .await
+        }
+        EntityKind::SubTaskAssignment => {
+            subtask_assignment_service::add_subtask_assignment(
+                repositories,
+                project_id,
+                &SubTaskId::from(id),
+                &UserId::from(related_id),
+                user_id,
+            )
+            .await
+        }
+        EntityKind::TaskDependency => {
+            task_dependency_service::add_task_dependency(
+                repositories,
+                project_id,
+                &TaskId::from(id),
+                &TaskId::from(related_id),
+                user_id,
+            )
+            .await
+        }
+        EntityKind::TaskRecurrence => {
+            recurrence_service::create_task_recurrence(
+                repositories,
+                project_id,
+                &TaskId::from(id),
+                &RecurrenceRuleId::from(related_id),
+            )
+            .await
+        }
+        EntityKind::SubTaskRecurrence => {
+            recurrence_service::create_subtask_recurrence(
+                repositories,
+                project_id,
+                &SubTaskId::from(id),
+                &RecurrenceRuleId::from(related_id),
+            )
+            .await
+        }
+        _ => return Err(unsupported(target)),
+    }
+    .map_err(service_error)
+}
+
+async fn unlink<R>(repositories: &R, target: &UndoTarget, related_id: &str) -> Result<(), String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let project_id = &target.project_id;
+    let id = target.entity_id.as_str();
+    match target.entity {
+        EntityKind::TaskTag => {
+            task_tag_service::remove_task_tag_relation(
+                repositories,
+                project_id,
+                &TaskId::from(id),
+                &TagId::from(related_id),
+            )
+            .await
+        }
+        EntityKind::SubTaskTag => {
+            subtask_tag_service::remove_subtask_tag_relation(
+                repositories,
+                project_id,
+                &SubTaskId::from(id),
+                &TagId::from(related_id),
+            )
+            .await
+        }
+        EntityKind::TaskAssignment => {
+            task_assignment_service::remove_task_assignment(
+                repositories,
+                project_id,
+                &TaskId::from(id),
+                &UserId::from(related_id),
+            )
+            .await
+        }
+        EntityKind::SubTaskAssignment => {
+            subtask_assignment_service::remove_subtask_assignment(
+                repositories,
+                project_id,
+                &SubTaskId::from(id),
+                &UserId::from(related_id),
+            )
+            .await
+        }
+        EntityKind::TaskDependency => {
+            task_dependency_service::remove_task_dependency(
+                repositories,
+                project_id,
+                &TaskId::from(id),
+                &TaskId::from(related_id),
+            )
+            .await
+        }
+        // 繰り返しルールはタスク・サブタスクごとに1つのため、関連付けをすべて外す
+        EntityKind::TaskRecurrence => {
+            recurrence_service::delete_task_recurrence(repositories, project_id, &TaskId::from(id))
+                .await
+        }
+        EntityKind::SubTaskRecurrence => {
+            recurrence_service::delete_subtask_recurrence(
+                repositories,
+                project_id,
+                &SubTaskId::from(id),
+            )
+            .await
+        }
+        _ => return Err(unsupported(target)),
+    }
+    .map_err(service_error)
+}
+
+/// 関連付け先をまとめて置き換え、置き換え前の関連付け先を返す
+async fn set_links<R>(
+    repositories: &R,
+    target: &UndoTarget,
+    related_ids: &[String],
+    user_id: &UserId,
+) -> Result<Vec<String>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let project_id = &target.project_id;
+    let id = target.entity_id.as_str();
+    let tag_ids = || {
+        related_ids
+            .iter()
+            .map(|id| TagId::from(id.as_str()))
+            .collect::<Vec<_>>()
+    };
+    let user_ids = || {
+        related_ids
+            .iter()
+            .map(|id| UserId::from(id.as_str()))
+            .collect::<Vec<_>>()
+    };
+    let previous = match target.entity {
+        EntityKind::TaskTag => {
+            let task_id = TaskId::from(id);
+            let previous =
+                task_tag_service::get_tag_ids_by_task_id(repositories, project_id, &task_id)
+                    .await
+                    .map_err(service_error)?;
+            task_tag_service::update_task_tag_relations(
+                repositories,
+                project_id,
+                &task_id,
+                &tag_ids(),
+                user_id,
+            )
+            .await
+            .map_err(service_error)?;
+            to_strings(previous)
+        }
+        EntityKind::SubTaskTag => {
+            let subtask_id = SubTaskId::from(id);
+            let previous = subtask_tag_service::get_tag_ids_by_subtask_id(
+                repositories,
+                project_id,
+                &subtask_id,
+            )
+            .await
+            .map_err(service_error)?;
+            subtask_tag_service::update_subtask_tag_relations(
+                repositories,
+                project_id,
+                &subtask_id,
+                &tag_ids(),
+                user_id,
+            )
+            .await
+            .map_err(service_error)?;
+            to_strings(previous)
+        }
+        EntityKind::TaskAssignment => {
+            let task_id = TaskId::from(id);
+            let previous = task_assignment_service::get_user_ids_by_task_id(
+                repositories,
+                project_id,
+                &task_id,
+            )
+            .await
+            .map_err(service_error)?;
+            task_assignment_service::update_task_assignments(
+                repositories,
+                project_id,
+                &task_id,
+                &user_ids(),
+                user_id,
+            )
+            .await
+            .map_err(service_error)?;
+            to_strings(previous)
+        }
+        EntityKind::SubTaskAssignment => {
+            let subtask_id = SubTaskId::from(id);
+            let previous = subtask_assignment_service::get_user_ids_by_subtask_id(
+                repositories,
+                project_id,
+                &subtask_id,
+            )
+            .await
+            .map_err(service_error)?;
+            subtask_assignment_service::update_subtask_assignments(
+                repositories,
+                project_id,
+                &subtask_id,
+                &user_ids(),
+                user_id,
+            )
+            .await
+            .map_err(service_error)?;
+            to_strings(previous)
+        }
+        _ => return Err(unsupported(target)),
+    };
+    Ok(previous)
+}
+
+fn recreate_operation(
+    target: &UndoTarget,
+    snapshot: &impl Serialize,
+) -> Result<UndoOperation, String> {
+    Ok(UndoOperation::Recreate {
+        target: target.clone(),
+        snapshot: serde_json::to_value(snapshot)
+            .map_err(|e| format!("Failed to serialize {}: {}", target.entity, e))?,
+    })
+}
+
+fn from_snapshot<T: DeserializeOwned>(snapshot: &Value) -> Result<T, String> {
+    serde_json::from_value(snapshot.clone()).map_err(|e| format!("Invalid snapshot: {}", e))
+}
+
+fn to_strings<T: ToString>(ids: Vec<T>) -> Vec<String> {
+    ids.iter().map(ToString::to_string).collect()
+}
+
+fn service_error(e: ServiceError) -> String {
+    match e {
+        ServiceError::ValidationError(msg) => msg,
+        e => format!("Failed to apply undo operation: {:?}", e),
+    }
+}
+
+fn not_found(target: &UndoTarget) -> String {
+    format!("{} not found: {}", target.entity, target.entity_id)
+}
+
+fn unsupported(target: &UndoTarget) -> String {
+    format!("{} cannot be undone", target.entity)
+}
@@ -1,5 +1,5 @@
-use crate::services::user_service;
 use crate::InfrastructureRepositoriesTrait;
+use crate::services::user_service;
 use flequit_model::models::users::user::User;
 use flequit_model::types::id_types::UserId;
 use flequit_types::errors::service_error::ServiceError;
@@ -8,7 +8,10 @@ pub async fn create_user<R>(repositories: &R, user: &User, user_id: &UserId) ->
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match user_service::create_user(repositories, user, user_id).await {
+    match repositories
+        .run_in_unit_of_work(user_service::create_user(repositories, user, user_id))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to create user: {:?}", e)),
@@ -31,7 +34,10 @@ pub async fn update_user<R>(repositories: &R, user: &User, user_id: &UserId) ->
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match user_service::update_user(repositories, user, user_id).await {
+    match repositories
+        .run_in_unit_of_work(user_service::update_user(repositories, user, user_id))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to update user: {:?}", e)),
@@ -42,7 +48,10 @@ pub async fn delete_user<R>(repositories: &R, id: &UserId) -> Result<bool, Strin
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    match user_service::delete_user(repositories, id).await {
+    match repositories
+        .run_in_unit_of_work(user_service::delete_user(repositories, id))
+        .await
+    {
         Ok(_) => Ok(true),
         Err(ServiceError::ValidationError(msg)) => Err(msg),
         Err(e) => Err(format!("Failed to delete user: {:?}", e)),
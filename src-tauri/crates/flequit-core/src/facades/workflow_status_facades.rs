@@ -0,0 +1,95 @@
+use crate::InfrastructureRepositoriesTrait;
+use crate::services::workflow_status_service;
+use flequit_model::models::task_projects::workflow_status::{
+    PartialWorkflowStatus, WorkflowStatus,
+};
+use flequit_model::types::id_types::{ProjectId, UserId, WorkflowStatusId};
+use flequit_types::errors::service_error::ServiceError;
+
+pub async fn list_workflow_statuses<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+) -> Result<Vec<WorkflowStatus>, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match workflow_status_service::list_workflow_statuses(repositories, project_id).await {
+        Ok(statuses) => Ok(statuses),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to list workflow statuses: {:?}", e)),
+    }
+}
+
+pub async fn create_workflow_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    workflow_status: &WorkflowStatus,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(workflow_status_service::create_workflow_status(
+            repositories,
+            project_id,
+            workflow_status,
+            user_id,
+        ))
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to create workflow status: {:?}", e)),
+    }
+}
+
+pub async fn update_workflow_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    workflow_status_id: &WorkflowStatusId,
+    patch: &PartialWorkflowStatus,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(workflow_status_service::update_workflow_status(
+            repositories,
+            project_id,
+            workflow_status_id,
+            patch,
+            user_id,
+        ))
+        .await
+    {
+        Ok(changed) => Ok(changed),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to update workflow status: {:?}", e)),
+    }
+}
+
+pub async fn delete_workflow_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    workflow_status_id: &WorkflowStatusId,
+    user_id: &UserId,
+) -> Result<bool, String>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match repositories
+        .run_in_unit_of_work(workflow_status_service::delete_workflow_status(
+            repositories,
+            project_id,
+            workflow_status_id,
+            user_id,
+        ))
+        .await
+    {
+        Ok(deleted) => Ok(deleted),
+        Err(ServiceError::ValidationError(msg)) => Err(msg),
+        Err(e) => Err(format!("Failed to delete workflow status: {:?}", e)),
+    }
+}
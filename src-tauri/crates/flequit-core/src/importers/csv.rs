@@ -0,0 +1,123 @@
+//! RFC 4180 形式のCSV読み込み
+//!
+//! ダブルクォートによるエスケープとフィールド内改行に対応する。
+
+use super::error::ImportError;
+
+/// ヘッダー行付きのCSV
+#[derive(Debug, Clone)]
+pub struct CsvTable {
+    pub headers: Vec<String>,
+    /// (元ファイルでの行番号, フィールド)
+    pub rows: Vec<(usize, Vec<String>)>,
+}
+
+impl CsvTable {
+    /// CSV文字列を読み込む（先頭行をヘッダーとして扱う）
+    pub fn parse(content: &str) -> Result<Self, ImportError> {
+        let mut records = parse_records(content.trim_start_matches('\u{feff}'))?.into_iter();
+        let (_, headers) = records.next().ok_or_else(|| ImportError::Csv {
+            line: 1,
+            message: "Header row is missing".to_string(),
+        })?;
+        let headers = headers.into_iter().map(|h| h.trim().to_string()).collect();
+        let rows = records
+            .filter(|(_, fields)| fields.iter().any(|f| !f.trim().is_empty()))
+            .collect();
+        Ok(Self { headers, rows })
+    }
+
+    /// ヘッダー名から列番号を取得（大文字小文字は区別しない）
+    pub fn column(&self, name: &str) -> Option<usize> {
+        self.headers
+            .iter()
+            .position(|h| h.eq_ignore_ascii_case(name))
+    }
+
+    /// 指定列の値を取得（空文字は None）
+    pub fn value(fields: &[String], column: Option<usize>) -> Option<&str> {
+        column
+            .and_then(|i| fields.get(i))
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+    }
+}
+
+fn parse_records(content: &str) -> Result<Vec<(usize, Vec<String>)>, ImportError> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => fields.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                fields.push(std::mem::take(&mut field));
+                records.push((record_line, std::mem::take(&mut fields)));
+                line += 1;
+                record_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(ImportError::Csv {
+            line: record_line,
+            message: "Unterminated quoted field".to_string(),
+        });
+    }
+    if !field.is_empty() || !fields.is_empty() {
+        fields.push(field);
+        records.push((record_line, fields));
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_quoted_fields_and_multiline_values() {
+        let content = "\u{feff}Title,Notes\r\n\"Buy \"\"milk\"\"\",\"line1\nline2\"\n\nPlain,\n";
+        let table = CsvTable::parse(content).unwrap();
+
+        assert_eq!(table.headers, vec!["Title", "Notes"]);
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[0].0, 2);
+        assert_eq!(table.rows[0].1, vec!["Buy \"milk\"", "line1\nline2"]);
+        assert_eq!(table.rows[1].0, 5);
+        assert_eq!(
+            CsvTable::value(&table.rows[1].1, table.column("notes")),
+            None
+        );
+
+        assert!(matches!(
+            CsvTable::parse("a,b\n\"open"),
+            Err(ImportError::Csv { line: 2, .. })
+        ));
+    }
+}
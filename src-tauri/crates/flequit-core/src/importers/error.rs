@@ -0,0 +1,33 @@
+//! インポートのエラー定義
+
+use flequit_types::errors::service_error::ServiceError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportError {
+    #[error("Invalid {format} file: {message}")]
+    InvalidFormat {
+        format: &'static str,
+        message: String,
+    },
+
+    #[error("CSV parse error at line {line}: {message}")]
+    Csv { line: usize, message: String },
+
+    #[error("Missing column: {0}")]
+    MissingColumn(String),
+}
+
+impl ImportError {
+    pub(crate) fn invalid(format: &'static str, message: impl Into<String>) -> Self {
+        ImportError::InvalidFormat {
+            format,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<ImportError> for ServiceError {
+    fn from(err: ImportError) -> Self {
+        ServiceError::ValidationError(err.to_string())
+    }
+}
@@ -0,0 +1,327 @@
+//! 列対応を指定した汎用CSV
+//!
+//! どの列をどの項目として読むかを `CsvColumnMapping` で指定する。
+//! 対応付けられなかった列は、値がある場合に未対応として報告する。
+
+use super::csv::CsvTable;
+use super::error::ImportError;
+use super::plan::{ImportPlan, ImportedSubTask, ImportedTask};
+use super::values::{parse_datetime, parse_recurrence_text};
+use super::{DEFAULT_PROJECT_NAME, DEFAULT_TASK_LIST_NAME, ImportOptions};
+use flequit_model::types::task_types::TaskStatus;
+use serde::{Deserialize, Serialize};
+
+fn default_tag_separator() -> String {
+    ",".to_string()
+}
+
+/// CSVの列とFlequitの項目の対応（値は列名）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvColumnMapping {
+    /// タスク名の列（必須）
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// プロジェクト名の列（未指定時はオプションのプロジェクト名）
+    #[serde(default)]
+    pub project: Option<String>,
+    /// タスクリスト名の列
+    #[serde(default)]
+    pub task_list: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub priority: Option<String>,
+    #[serde(default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub due_date: Option<String>,
+    /// タグの列（`tag_separator` 区切り）
+    #[serde(default)]
+    pub tags: Option<String>,
+    #[serde(default = "default_tag_separator")]
+    pub tag_separator: String,
+    /// 親タスク名の列（値がある行は同じタスクリスト内の親タスクのサブタスクになる）
+    #[serde(default)]
+    pub parent: Option<String>,
+    /// 繰り返し表現の列（`every 2 weeks` / `monthly` など）
+    #[serde(default)]
+    pub recurrence: Option<String>,
+}
+
+impl CsvColumnMapping {
+    /// タスク名の列だけを指定した対応
+    pub fn with_title(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            project: None,
+            task_list: None,
+            status: None,
+            priority: None,
+            start_date: None,
+            due_date: None,
+            tags: None,
+            tag_separator: default_tag_separator(),
+            parent: None,
+            recurrence: None,
+        }
+    }
+
+    fn optional_columns(&self) -> [(&'static str, Option<&String>); 10] {
+        [
+            ("description", self.description.as_ref()),
+            ("project", self.project.as_ref()),
+            ("task_list", self.task_list.as_ref()),
+            ("status", self.status.as_ref()),
+            ("priority", self.priority.as_ref()),
+            ("start_date", self.start_date.as_ref()),
+            ("due_date", self.due_date.as_ref()),
+            ("tags", self.tags.as_ref()),
+            ("parent", self.parent.as_ref()),
+            ("recurrence", self.recurrence.as_ref()),
+        ]
+    }
+}
+
+fn parse_status(value: &str) -> Option<TaskStatus> {
+    let normalized: String = value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+    let status = match normalized.as_str() {
+        "notstarted" | "todo" | "open" | "pending" | "new" | "false" | "0" => {
+            TaskStatus::NotStarted
+        }
+        "inprogress" | "doing" | "started" | "active" => TaskStatus::InProgress,
+        "waiting" | "blocked" | "onhold" | "deferred" => TaskStatus::Waiting,
+        "completed" | "complete" | "done" | "closed" | "x" | "true" | "1" => TaskStatus::Completed,
+        "cancelled" | "canceled" | "wontdo" => TaskStatus::Cancelled,
+        _ => return None,
+    };
+    Some(status)
+}
+
+fn parse_priority(value: &str) -> Option<i32> {
+    if let Ok(number) = value.parse::<i32>() {
+        return (0..=4).contains(&number).then_some(number);
+    }
+    let priority = match value.to_lowercase().as_str() {
+        "high" | "h" | "urgent" => 1,
+        "medium" | "m" | "normal" => 2,
+        "low" | "l" => 3,
+        "lowest" => 4,
+        "none" => 0,
+        _ => return None,
+    };
+    Some(priority)
+}
+
+/// 汎用CSVを解析
+pub fn parse(
+    content: &str,
+    mapping: &CsvColumnMapping,
+    options: &ImportOptions,
+) -> Result<ImportPlan, ImportError> {
+    let table = CsvTable::parse(content)?;
+    let title_col = table
+        .column(&mapping.title)
+        .ok_or_else(|| ImportError::MissingColumn(mapping.title.clone()))?;
+
+    let mut columns = std::collections::HashMap::new();
+    for (key, name) in mapping.optional_columns() {
+        if let Some(name) = name {
+            let index = table
+                .column(name)
+                .ok_or_else(|| ImportError::MissingColumn(name.clone()))?;
+            columns.insert(key, index);
+        }
+    }
+    let col = |key: &str| columns.get(key).copied();
+
+    let mapped: Vec<usize> = columns.values().copied().chain([title_col]).collect();
+    let unmapped_columns: Vec<usize> = (0..table.headers.len())
+        .filter(|i| !mapped.contains(i))
+        .collect();
+
+    let fallback_project = options.project_name_or(DEFAULT_PROJECT_NAME);
+    let mut plan = ImportPlan::default();
+
+    for (line, fields) in &table.rows {
+        for index in &unmapped_columns {
+            if let Some(value) = CsvTable::value(fields, Some(*index)) {
+                plan.report.unmapped(
+                    &table.headers[*index],
+                    Some(value),
+                    "Column is not mapped to any field",
+                );
+            }
+        }
+
+        let Some(title) = CsvTable::value(fields, Some(title_col)) else {
+            plan.report
+                .warn(format!("Line {line}: skipped row without title"));
+            continue;
+        };
+        let project_name = CsvTable::value(fields, col("project"))
+            .map(str::to_string)
+            .unwrap_or_else(|| fallback_project.clone());
+        let list_name = CsvTable::value(fields, col("task_list"))
+            .unwrap_or(DEFAULT_TASK_LIST_NAME)
+            .to_string();
+
+        let status = match CsvTable::value(fields, col("status")) {
+            Some(value) => parse_status(value).unwrap_or_else(|| {
+                plan.report.unmapped(
+                    &table.headers[col("status").unwrap_or_default()],
+                    Some(value),
+                    "Unknown status",
+                );
+                TaskStatus::NotStarted
+            }),
+            None => TaskStatus::NotStarted,
+        };
+        let priority = match CsvTable::value(fields, col("priority")) {
+            Some(value) => parse_priority(value).unwrap_or_else(|| {
+                plan.report.unmapped(
+                    &table.headers[col("priority").unwrap_or_default()],
+                    Some(value),
+                    "Unknown priority",
+                );
+                0
+            }),
+            None => 0,
+        };
+        let mut date_of = |key: &str| {
+            let value = CsvTable::value(fields, col(key))?;
+            let date = parse_datetime(value);
+            if date.is_none() {
+                plan.report.unmapped(
+                    &table.headers[col(key).unwrap_or_default()],
+                    Some(value),
+                    "Unrecognized date format",
+                );
+            }
+            date
+        };
+        let plan_start_date = date_of("start_date");
+        let plan_end_date = date_of("due_date");
+        let tags: Vec<String> = CsvTable::value(fields, col("tags"))
+            .map(|value| {
+                value
+                    .split(mapping.tag_separator.as_str())
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let description = CsvTable::value(fields, col("description")).map(str::to_string);
+
+        if let Some(parent_title) = CsvTable::value(fields, col("parent")) {
+            let list = plan.task_list_mut(&project_name, &list_name);
+            match list.tasks.iter_mut().rev().find(|t| t.title == parent_title) {
+                Some(parent) => {
+                    parent.subtasks.push(ImportedSubTask {
+                        title: title.to_string(),
+                        description,
+                        status,
+                        priority: (priority > 0).then_some(priority),
+                        plan_end_date,
+                        tags,
+                    });
+                }
+                None => plan.report.warn(format!(
+                    "Line {line}: skipped subtask '{title}' because parent '{parent_title}' was not found above it"
+                )),
+            }
+            continue;
+        }
+
+        let recurrence = match CsvTable::value(fields, col("recurrence")) {
+            Some(value) => {
+                let recurrence = parse_recurrence_text(value);
+                if recurrence.is_none() {
+                    plan.report.unmapped(
+                        &table.headers[col("recurrence").unwrap_or_default()],
+                        Some(value),
+                        "Recurrence pattern could not be interpreted",
+                    );
+                }
+                recurrence
+            }
+            None => None,
+        };
+
+        plan.task_list_mut(&project_name, &list_name)
+            .tasks
+            .push(ImportedTask {
+                title: title.to_string(),
+                description,
+                status,
+                priority,
+                plan_start_date,
+                plan_end_date,
+                tags,
+                recurrence,
+                subtasks: Vec::new(),
+            });
+    }
+
+    if plan.projects.is_empty() {
+        plan.project_mut(&fallback_project);
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_with_mapping() {
+        let csv = "Name,Notes,List,State,Prio,Due,Labels,Parent,Owner\n\
+                   Plan trip,,Travel,doing,high,2025-06-01,fun;summer,,Ann\n\
+                   Book hotel,,Travel,done,,,,Plan trip,\n\
+                   ,,Travel,,,,,,\n";
+        let mapping = CsvColumnMapping {
+            description: Some("Notes".to_string()),
+            task_list: Some("List".to_string()),
+            status: Some("State".to_string()),
+            priority: Some("Prio".to_string()),
+            due_date: Some("Due".to_string()),
+            tags: Some("Labels".to_string()),
+            tag_separator: ";".to_string(),
+            parent: Some("Parent".to_string()),
+            ..CsvColumnMapping::with_title("Name")
+        };
+        let options = ImportOptions {
+            project_name: Some("Holidays".to_string()),
+        };
+        let plan = parse(csv, &mapping, &options).unwrap();
+
+        let project = &plan.projects[0];
+        assert_eq!(project.name, "Holidays");
+        let task = &project.task_lists[0].tasks[0];
+        assert_eq!(task.status, TaskStatus::InProgress);
+        assert_eq!(task.priority, 1);
+        assert_eq!(task.tags, vec!["fun", "summer"]);
+        assert_eq!(task.subtasks[0].status, TaskStatus::Completed);
+
+        let unmapped = plan.report.unmapped_fields();
+        assert_eq!(unmapped[0].field, "Owner");
+        assert_eq!(plan.report.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_missing_mapped_column() {
+        let err = parse(
+            "Title\nA\n",
+            &CsvColumnMapping::with_title("Name"),
+            &ImportOptions::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ImportError::MissingColumn(name) if name == "Name"));
+    }
+}
@@ -0,0 +1,321 @@
+//! Microsoft To DoのエクスポートJSON（Graph APIの `todoTaskList` / `todoTask` 形式）
+//!
+//! `{"lists": [{"displayName": ..., "tasks": [...]}]}` またはリストの配列を受け付ける。
+//! すべてのリストは1つのプロジェクトのタスクリストとして取り込む。
+
+use super::error::ImportError;
+use super::plan::{ImportPlan, ImportedRecurrence, ImportedSubTask, ImportedTask};
+use super::values::parse_datetime;
+use super::{DEFAULT_TASK_LIST_NAME, ImportOptions};
+use flequit_model::types::datetime_calendar_types::{DayOfWeek, RecurrenceUnit};
+use flequit_model::types::task_types::TaskStatus;
+use serde_json::Value;
+
+const FORMAT: &str = "Microsoft To Do JSON";
+const DEFAULT_PROJECT_NAME: &str = "Microsoft To Do";
+
+/// 取り込む（または意図的に無視する）タスクのキー
+const KNOWN_TASK_KEYS: &[&str] = &[
+    "id",
+    "@odata.etag",
+    "title",
+    "body",
+    "importance",
+    "status",
+    "dueDateTime",
+    "startDateTime",
+    "completedDateTime",
+    "createdDateTime",
+    "lastModifiedDateTime",
+    "bodyLastModifiedDateTime",
+    "recurrence",
+    "categories",
+    "checklistItems",
+    "isReminderOn",
+    "hasAttachments",
+];
+
+fn str_of<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+/// `{"dateTime": ..., "timeZone": ...}` 形式の日時
+fn date_time_of(value: &Value, key: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    value
+        .get(key)
+        .and_then(|d| str_of(d, "dateTime"))
+        .and_then(parse_datetime)
+}
+
+fn importance_to_priority(value: Option<&str>) -> i32 {
+    match value {
+        Some("high") => 1,
+        Some("low") => 3,
+        _ => 0,
+    }
+}
+
+fn status_of(value: Option<&str>) -> TaskStatus {
+    match value {
+        Some("inProgress") => TaskStatus::InProgress,
+        Some("completed") => TaskStatus::Completed,
+        Some("waitingOnOthers") | Some("deferred") => TaskStatus::Waiting,
+        _ => TaskStatus::NotStarted,
+    }
+}
+
+fn day_of_week(value: &str) -> Option<DayOfWeek> {
+    let day = match value.to_lowercase().as_str() {
+        "sunday" => DayOfWeek::Sunday,
+        "monday" => DayOfWeek::Monday,
+        "tuesday" => DayOfWeek::Tuesday,
+        "wednesday" => DayOfWeek::Wednesday,
+        "thursday" => DayOfWeek::Thursday,
+        "friday" => DayOfWeek::Friday,
+        "saturday" => DayOfWeek::Saturday,
+        _ => return None,
+    };
+    Some(day)
+}
+
+/// `patternedRecurrence` を繰り返し設定へ変換
+fn parse_recurrence(value: &Value, plan: &mut ImportPlan) -> Option<ImportedRecurrence> {
+    let pattern = value.get("pattern")?;
+    let pattern_type = str_of(pattern, "type")?;
+    let unit = match pattern_type {
+        "daily" => RecurrenceUnit::Day,
+        "weekly" => RecurrenceUnit::Week,
+        "absoluteMonthly" | "relativeMonthly" => RecurrenceUnit::Month,
+        "absoluteYearly" | "relativeYearly" => RecurrenceUnit::Year,
+        other => {
+            plan.report.unmapped(
+                "recurrence.pattern.type",
+                Some(other),
+                "Unknown recurrence pattern",
+            );
+            return None;
+        }
+    };
+    if pattern_type.starts_with("relative") {
+        plan.report.unmapped(
+            "recurrence.pattern.index",
+            str_of(pattern, "index"),
+            "Relative monthly/yearly patterns are imported as plain intervals",
+        );
+    }
+
+    let interval = pattern
+        .get("interval")
+        .and_then(Value::as_i64)
+        .filter(|n| *n > 0)
+        .unwrap_or(1) as i32;
+    let mut recurrence = ImportedRecurrence::every(unit, interval);
+
+    if pattern_type == "weekly" {
+        let days: Vec<DayOfWeek> = pattern
+            .get("daysOfWeek")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .filter_map(day_of_week)
+            .collect();
+        if !days.is_empty() {
+            recurrence.days_of_week = Some(days);
+        }
+    }
+
+    if let Some(range) = value.get("range") {
+        match str_of(range, "type") {
+            Some("endDate") => {
+                recurrence.end_date = str_of(range, "endDate").and_then(parse_datetime);
+            }
+            Some("numbered") => {
+                recurrence.max_occurrences = range
+                    .get("numberOfOccurrences")
+                    .and_then(Value::as_i64)
+                    .filter(|n| *n > 0)
+                    .map(|n| n as i32);
+            }
+            _ => {}
+        }
+    }
+    Some(recurrence)
+}
+
+fn parse_task(task: &Value, plan: &mut ImportPlan) -> Option<ImportedTask> {
+    for (key, value) in task.as_object().into_iter().flatten() {
+        if KNOWN_TASK_KEYS.contains(&key.as_str()) || value.is_null() {
+            continue;
+        }
+        if matches!(value, Value::Array(items) if items.is_empty()) {
+            continue;
+        }
+        let reason = match key.as_str() {
+            "reminderDateTime" => "Reminders are not supported",
+            "linkedResources" => "Linked resources are not supported",
+            "attachments" => "Attachments are not supported",
+            _ => "Unsupported Microsoft To Do field",
+        };
+        let sample = value
+            .as_str()
+            .map(str::to_string)
+            .unwrap_or_else(|| value.to_string());
+        plan.report.unmapped(key, Some(&sample), reason);
+    }
+
+    let Some(title) = str_of(task, "title") else {
+        plan.report.warn("Skipped a task without title");
+        return None;
+    };
+
+    let description = task
+        .get("body")
+        .and_then(|body| str_of(body, "content"))
+        .map(str::to_string);
+    if task
+        .get("body")
+        .and_then(|body| str_of(body, "contentType"))
+        .is_some_and(|t| t.eq_ignore_ascii_case("html"))
+    {
+        plan.report.unmapped(
+            "body.contentType",
+            Some("html"),
+            "HTML notes are imported as raw text",
+        );
+    }
+
+    let recurrence = task
+        .get("recurrence")
+        .filter(|r| r.is_object())
+        .and_then(|r| parse_recurrence(r, plan));
+
+    let subtasks = task
+        .get("checklistItems")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|item| {
+            let title = str_of(item, "displayName")?;
+            let checked = item.get("isChecked").and_then(Value::as_bool) == Some(true);
+            Some(ImportedSubTask {
+                title: title.to_string(),
+                status: if checked {
+                    TaskStatus::Completed
+                } else {
+                    TaskStatus::NotStarted
+                },
+                ..Default::default()
+            })
+        })
+        .collect();
+
+    Some(ImportedTask {
+        title: title.to_string(),
+        description,
+        status: status_of(str_of(task, "status")),
+        priority: importance_to_priority(str_of(task, "importance")),
+        plan_start_date: date_time_of(task, "startDateTime"),
+        plan_end_date: date_time_of(task, "dueDateTime"),
+        tags: task
+            .get("categories")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        recurrence,
+        subtasks,
+    })
+}
+
+/// エクスポートJSONを解析
+pub fn parse(content: &str, options: &ImportOptions) -> Result<ImportPlan, ImportError> {
+    let root: Value =
+        serde_json::from_str(content).map_err(|e| ImportError::invalid(FORMAT, e.to_string()))?;
+    let lists = match &root {
+        Value::Array(lists) => lists,
+        Value::Object(_) => root
+            .get("lists")
+            .or_else(|| root.get("value"))
+            .and_then(Value::as_array)
+            .ok_or_else(|| ImportError::invalid(FORMAT, "Expected a 'lists' array"))?,
+        _ => return Err(ImportError::invalid(FORMAT, "Expected an object or array")),
+    };
+
+    let project_name = options.project_name_or(DEFAULT_PROJECT_NAME);
+    let mut plan = ImportPlan::default();
+    plan.project_mut(&project_name);
+
+    for list in lists {
+        let list_name = str_of(list, "displayName").unwrap_or(DEFAULT_TASK_LIST_NAME);
+        plan.task_list_mut(&project_name, list_name);
+        let tasks = list
+            .get("tasks")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for task in tasks {
+            if let Some(task) = parse_task(task, &mut plan) {
+                plan.task_list_mut(&project_name, list_name)
+                    .tasks
+                    .push(task);
+            }
+        }
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lists_and_tasks() {
+        let json = r#"{"lists": [{
+            "displayName": "Groceries",
+            "tasks": [{
+                "title": "Water plants",
+                "body": {"content": "balcony", "contentType": "text"},
+                "importance": "high",
+                "status": "waitingOnOthers",
+                "dueDateTime": {"dateTime": "2025-04-01T00:00:00.0000000", "timeZone": "UTC"},
+                "recurrence": {
+                    "pattern": {"type": "weekly", "interval": 1, "daysOfWeek": ["monday", "thursday"]},
+                    "range": {"type": "numbered", "numberOfOccurrences": 10}
+                },
+                "categories": ["Home"],
+                "checklistItems": [{"displayName": "Fern", "isChecked": true}],
+                "reminderDateTime": {"dateTime": "2025-04-01T08:00:00", "timeZone": "UTC"}
+            }]
+        }]}"#;
+        let plan = parse(json, &ImportOptions::default()).unwrap();
+
+        let list = &plan.projects[0].task_lists[0];
+        assert_eq!(list.name, "Groceries");
+        let task = &list.tasks[0];
+        assert_eq!(task.priority, 1);
+        assert_eq!(task.status, TaskStatus::Waiting);
+        assert!(task.plan_end_date.is_some());
+        assert_eq!(task.tags, vec!["Home"]);
+        assert_eq!(task.subtasks[0].status, TaskStatus::Completed);
+        let recurrence = task.recurrence.as_ref().unwrap();
+        assert_eq!(recurrence.days_of_week.as_ref().unwrap().len(), 2);
+        assert_eq!(recurrence.max_occurrences, Some(10));
+
+        let unmapped = plan.report.unmapped_fields();
+        assert_eq!(unmapped.len(), 1);
+        assert_eq!(unmapped[0].field, "reminderDateTime");
+    }
+
+    #[test]
+    fn test_rejects_unexpected_shape() {
+        assert!(parse(r#"{"tasks": []}"#, &ImportOptions::default()).is_err());
+        assert!(parse("not json", &ImportOptions::default()).is_err());
+    }
+}
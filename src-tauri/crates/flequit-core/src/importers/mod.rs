@@ -0,0 +1,83 @@
+//! 外部タスク管理ツールからのインポート
+//!
+//! Todoist（CSV / JSONバックアップ）、Microsoft To Do（JSON）、
+//! Taskwarrior（`task export` のJSON）、列対応を指定した汎用CSVを読み込み、
+//! プロジェクト・タスクリスト・タスク・サブタスク・タグ・繰り返しルールの
+//! 中間表現（`ImportPlan`）へ変換する。
+//!
+//! 変換できなかった項目は `ImportReport` に集計され、
+//! 保存前のドライラン（プレビュー）で確認できる。
+
+pub mod csv;
+pub mod error;
+pub mod generic_csv;
+pub mod microsoft_todo;
+pub mod plan;
+pub mod taskwarrior;
+pub mod todoist;
+pub mod values;
+
+pub use error::ImportError;
+pub use generic_csv::CsvColumnMapping;
+pub use plan::{
+    ImportCounts, ImportPlan, ImportReport, ImportedProject, ImportedRecurrence, ImportedSubTask,
+    ImportedTask, ImportedTaskList, UnmappedField,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// プロジェクト名が決まらない場合の既定値
+pub const DEFAULT_PROJECT_NAME: &str = "Imported";
+/// タスクリスト名が決まらない場合の既定値
+pub const DEFAULT_TASK_LIST_NAME: &str = "Inbox";
+
+/// インポート元の形式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "mapping")]
+pub enum ImportFormat {
+    /// Todoistのプロジェクトテンプレート（CSV）
+    TodoistCsv,
+    /// TodoistのバックアップJSON（Sync API形式）
+    TodoistJson,
+    /// Microsoft To DoのエクスポートJSON（Graph API形式）
+    MicrosoftTodoJson,
+    /// Taskwarriorの `task export` 出力
+    TaskwarriorJson,
+    /// 列対応を指定した汎用CSV
+    GenericCsv(Box<CsvColumnMapping>),
+}
+
+/// インポートオプション
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportOptions {
+    /// プロジェクト名（ファイルにプロジェクト情報が無い形式で使用）
+    pub project_name: Option<String>,
+}
+
+impl ImportOptions {
+    pub(crate) fn project_name_or(&self, fallback: &str) -> String {
+        self.project_name
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .unwrap_or(fallback)
+            .to_string()
+    }
+}
+
+/// ファイル内容を解析してインポート計画を作成する
+///
+/// データの保存は行わない。
+pub fn parse_import(
+    format: &ImportFormat,
+    content: &str,
+    options: &ImportOptions,
+) -> Result<ImportPlan, ImportError> {
+    match format {
+        ImportFormat::TodoistCsv => todoist::parse_csv(content, options),
+        ImportFormat::TodoistJson => todoist::parse_json(content, options),
+        ImportFormat::MicrosoftTodoJson => microsoft_todo::parse(content, options),
+        ImportFormat::TaskwarriorJson => taskwarrior::parse(content, options),
+        ImportFormat::GenericCsv(mapping) => generic_csv::parse(content, mapping, options),
+    }
+}
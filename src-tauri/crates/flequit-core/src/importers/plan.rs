@@ -0,0 +1,218 @@
+//! インポート内容の中間表現とレポート
+//!
+//! 各形式のパーサーはファイルを `ImportPlan` に変換し、
+//! 保存処理（`import_service`）はこの中間表現だけを扱う。
+
+use chrono::{DateTime, Utc};
+use flequit_model::types::datetime_calendar_types::{DayOfWeek, RecurrenceUnit};
+use flequit_model::types::task_types::TaskStatus;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// インポート対象の全体
+#[derive(Debug, Clone, Default)]
+pub struct ImportPlan {
+    pub projects: Vec<ImportedProject>,
+    pub report: ImportReport,
+}
+
+/// インポートするプロジェクト
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportedProject {
+    pub name: String,
+    pub description: Option<String>,
+    pub color: Option<String>,
+    pub task_lists: Vec<ImportedTaskList>,
+}
+
+/// インポートするタスクリスト
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportedTaskList {
+    pub name: String,
+    pub tasks: Vec<ImportedTask>,
+}
+
+/// インポートするタスク
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportedTask {
+    pub title: String,
+    pub description: Option<String>,
+    pub status: TaskStatus,
+    /// Flequitの優先度（1が最高、0は未設定）
+    pub priority: i32,
+    pub plan_start_date: Option<DateTime<Utc>>,
+    pub plan_end_date: Option<DateTime<Utc>>,
+    /// タグ名（同名のタグはプロジェクト内で共有される）
+    pub tags: Vec<String>,
+    pub recurrence: Option<ImportedRecurrence>,
+    pub subtasks: Vec<ImportedSubTask>,
+}
+
+/// インポートするサブタスク
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportedSubTask {
+    pub title: String,
+    pub description: Option<String>,
+    pub status: TaskStatus,
+    pub priority: Option<i32>,
+    pub plan_end_date: Option<DateTime<Utc>>,
+    pub tags: Vec<String>,
+}
+
+/// インポートする繰り返し設定
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedRecurrence {
+    pub unit: RecurrenceUnit,
+    pub interval: i32,
+    pub days_of_week: Option<Vec<DayOfWeek>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub max_occurrences: Option<i32>,
+}
+
+impl ImportedRecurrence {
+    pub fn every(unit: RecurrenceUnit, interval: i32) -> Self {
+        Self {
+            unit,
+            interval,
+            days_of_week: None,
+            end_date: None,
+            max_occurrences: None,
+        }
+    }
+}
+
+/// 取り込めなかった項目
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnmappedField {
+    /// 元ファイルでのフィールド名（列名・キー名）
+    pub field: String,
+    /// 取り込めなかった理由
+    pub reason: String,
+    /// 出現回数
+    pub occurrences: usize,
+    /// 値の例
+    pub sample: Option<String>,
+}
+
+/// インポート結果のレポート
+///
+/// 取り込めなかったフィールドはフィールド名と理由ごとに集計する。
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    unmapped: BTreeMap<(String, String), UnmappedField>,
+    /// スキップしたレコードなどの警告
+    pub warnings: Vec<String>,
+}
+
+impl ImportReport {
+    /// 取り込めなかったフィールドを記録
+    pub fn unmapped(&mut self, field: &str, value: Option<&str>, reason: &str) {
+        let entry = self
+            .unmapped
+            .entry((field.to_string(), reason.to_string()))
+            .or_insert_with(|| UnmappedField {
+                field: field.to_string(),
+                reason: reason.to_string(),
+                occurrences: 0,
+                sample: None,
+            });
+        entry.occurrences += 1;
+        if entry.sample.is_none() {
+            entry.sample = value
+                .filter(|v| !v.is_empty())
+                .map(|v| v.chars().take(80).collect());
+        }
+    }
+
+    /// 警告を記録
+    pub fn warn(&mut self, message: impl Into<String>) {
+        self.warnings.push(message.into());
+    }
+
+    /// 取り込めなかったフィールドの一覧
+    pub fn unmapped_fields(&self) -> Vec<UnmappedField> {
+        self.unmapped.values().cloned().collect()
+    }
+
+    /// 取り込めなかった項目も警告も無いか
+    pub fn is_clean(&self) -> bool {
+        self.unmapped.is_empty() && self.warnings.is_empty()
+    }
+}
+
+/// 作成されるエンティティ数
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ImportCounts {
+    pub projects: usize,
+    pub task_lists: usize,
+    pub tasks: usize,
+    pub subtasks: usize,
+    pub tags: usize,
+    pub recurrence_rules: usize,
+}
+
+impl ImportPlan {
+    /// 作成されるエンティティ数を集計
+    ///
+    /// タグはプロジェクトごとに名前で重複を除いた数。
+    pub fn counts(&self) -> ImportCounts {
+        let mut counts = ImportCounts {
+            projects: self.projects.len(),
+            ..Default::default()
+        };
+        for project in &self.projects {
+            let mut tags = BTreeSet::new();
+            counts.task_lists += project.task_lists.len();
+            for task in project.task_lists.iter().flat_map(|l| &l.tasks) {
+                counts.tasks += 1;
+                counts.subtasks += task.subtasks.len();
+                counts.recurrence_rules += usize::from(task.recurrence.is_some());
+                tags.extend(task.tags.iter().map(|t| t.to_lowercase()));
+                for subtask in &task.subtasks {
+                    tags.extend(subtask.tags.iter().map(|t| t.to_lowercase()));
+                }
+            }
+            counts.tags += tags.len();
+        }
+        counts
+    }
+}
+
+impl ImportPlan {
+    /// 名前でプロジェクトを取得（無ければ末尾に追加）
+    pub(crate) fn project_mut(&mut self, name: &str) -> &mut ImportedProject {
+        let index = match self.projects.iter().position(|p| p.name == name) {
+            Some(index) => index,
+            None => {
+                self.projects.push(ImportedProject {
+                    name: name.to_string(),
+                    ..Default::default()
+                });
+                self.projects.len() - 1
+            }
+        };
+        &mut self.projects[index]
+    }
+
+    /// 名前でタスクリストを取得（無ければプロジェクト・タスクリストを作成）
+    pub(crate) fn task_list_mut(&mut self, project: &str, list: &str) -> &mut ImportedTaskList {
+        self.project_mut(project).task_list_mut(list)
+    }
+}
+
+impl ImportedProject {
+    /// 名前でタスクリストを取得（無ければ末尾に追加）
+    pub(crate) fn task_list_mut(&mut self, name: &str) -> &mut ImportedTaskList {
+        let index = match self.task_lists.iter().position(|l| l.name == name) {
+            Some(index) => index,
+            None => {
+                self.task_lists.push(ImportedTaskList {
+                    name: name.to_string(),
+                    tasks: Vec::new(),
+                });
+                self.task_lists.len() - 1
+            }
+        };
+        &mut self.task_lists[index]
+    }
+}
@@ -0,0 +1,243 @@
+//! Taskwarriorの `task export` 出力
+//!
+//! JSON配列（2.6以降）と1行1タスクの形式（2.5以前）の両方を受け付ける。
+//! `project` の先頭要素をプロジェクト、残り（`Work.Reports` の `Reports`）をタスクリストとする。
+
+use super::error::ImportError;
+use super::plan::{ImportPlan, ImportedTask};
+use super::values::{parse_datetime, parse_recurrence_text};
+use super::{DEFAULT_TASK_LIST_NAME, ImportOptions};
+use flequit_model::types::task_types::TaskStatus;
+use serde_json::Value;
+
+const FORMAT: &str = "Taskwarrior JSON";
+const DEFAULT_PROJECT_NAME: &str = "Taskwarrior";
+
+/// 取り込むキー、または取り込む必要のない内部管理用のキー
+const KNOWN_KEYS: &[&str] = &[
+    "id",
+    "uuid",
+    "description",
+    "project",
+    "tags",
+    "priority",
+    "status",
+    "due",
+    "scheduled",
+    "start",
+    "until",
+    "recur",
+    "parent",
+    "entry",
+    "modified",
+    "end",
+    "urgency",
+    "mask",
+    "imask",
+    "rtype",
+];
+
+fn str_of<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+fn load_tasks(content: &str) -> Result<Vec<Value>, ImportError> {
+    let trimmed = content.trim_start_matches('\u{feff}').trim();
+    if trimmed.starts_with('[') {
+        return serde_json::from_str(trimmed)
+            .map_err(|e| ImportError::invalid(FORMAT, e.to_string()));
+    }
+    trimmed
+        .lines()
+        .map(|line| line.trim().trim_end_matches(','))
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| ImportError::invalid(FORMAT, e.to_string()))
+        })
+        .collect()
+}
+
+/// `task export` の出力を解析
+pub fn parse(content: &str, options: &ImportOptions) -> Result<ImportPlan, ImportError> {
+    let tasks = load_tasks(content)?;
+    let fallback_project = options.project_name_or(DEFAULT_PROJECT_NAME);
+    let mut plan = ImportPlan::default();
+    let mut skipped_deleted = 0;
+    let mut skipped_instances = 0;
+
+    for task in &tasks {
+        if !task.is_object() {
+            return Err(ImportError::invalid(
+                FORMAT,
+                "Each task must be a JSON object",
+            ));
+        }
+        let status = str_of(task, "status").unwrap_or("pending");
+        if status == "deleted" {
+            skipped_deleted += 1;
+            continue;
+        }
+        // 繰り返しの個別インスタンスはテンプレート（status = recurring）から再生成される
+        if task.get("parent").is_some_and(|p| !p.is_null()) {
+            skipped_instances += 1;
+            continue;
+        }
+
+        for (key, value) in task.as_object().into_iter().flatten() {
+            if KNOWN_KEYS.contains(&key.as_str()) || value.is_null() {
+                continue;
+            }
+            let reason = match key.as_str() {
+                "annotations" => "Annotations are not supported",
+                "depends" => "Task dependencies are not supported",
+                "wait" => "Wait dates are not supported",
+                _ => "User defined attributes are not supported",
+            };
+            let sample = match (key.as_str(), value) {
+                ("annotations", Value::Array(items)) => items
+                    .first()
+                    .and_then(|a| str_of(a, "description"))
+                    .map(str::to_string),
+                (_, Value::String(s)) => Some(s.clone()),
+                _ => Some(value.to_string()),
+            };
+            plan.report.unmapped(key, sample.as_deref(), reason);
+        }
+
+        let Some(title) = str_of(task, "description") else {
+            plan.report.warn("Skipped a task without description");
+            continue;
+        };
+
+        let (project_name, list_name) = match str_of(task, "project") {
+            Some(project) => match project.split_once('.') {
+                Some((project, list)) => (project.to_string(), list.to_string()),
+                None => (project.to_string(), DEFAULT_TASK_LIST_NAME.to_string()),
+            },
+            None => (fallback_project.clone(), DEFAULT_TASK_LIST_NAME.to_string()),
+        };
+
+        let status = match status {
+            "completed" => TaskStatus::Completed,
+            "waiting" => TaskStatus::Waiting,
+            _ if task.get("start").is_some_and(|s| !s.is_null()) => TaskStatus::InProgress,
+            _ => TaskStatus::NotStarted,
+        };
+
+        let mut imported = ImportedTask {
+            title: title.to_string(),
+            status,
+            priority: match str_of(task, "priority") {
+                Some("H") => 1,
+                Some("M") => 2,
+                Some("L") => 3,
+                _ => 0,
+            },
+            plan_start_date: str_of(task, "scheduled").and_then(parse_datetime),
+            plan_end_date: str_of(task, "due").and_then(parse_datetime),
+            tags: task
+                .get("tags")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect(),
+            ..Default::default()
+        };
+
+        if let Some(recur) = str_of(task, "recur") {
+            match parse_recurrence_text(recur) {
+                Some(mut recurrence) => {
+                    recurrence.end_date = str_of(task, "until").and_then(parse_datetime);
+                    imported.recurrence = Some(recurrence);
+                }
+                None => plan.report.unmapped(
+                    "recur",
+                    Some(recur),
+                    "Recurrence period could not be interpreted",
+                ),
+            }
+        } else if let Some(until) = str_of(task, "until") {
+            plan.report.unmapped(
+                "until",
+                Some(until),
+                "Expiry dates are only supported for recurring tasks",
+            );
+        }
+
+        plan.task_list_mut(&project_name, &list_name)
+            .tasks
+            .push(imported);
+    }
+
+    if skipped_deleted > 0 {
+        plan.report
+            .warn(format!("Skipped {skipped_deleted} deleted task(s)"));
+    }
+    if skipped_instances > 0 {
+        plan.report.warn(format!(
+            "Skipped {skipped_instances} recurring instance(s); the recurring templates were imported instead"
+        ));
+    }
+    if plan.projects.is_empty() {
+        plan.project_mut(&fallback_project);
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flequit_model::types::datetime_calendar_types::RecurrenceUnit;
+
+    #[test]
+    fn test_parse_export() {
+        let json = r#"[
+            {"id": 1, "uuid": "a", "description": "Write report", "project": "Work.Reports",
+             "tags": ["office"], "priority": "H", "status": "pending",
+             "due": "20250110T090000Z", "start": "20250101T090000Z", "urgency": 9.1,
+             "annotations": [{"entry": "20250101T000000Z", "description": "ask Bob"}],
+             "estimate": "2h"},
+            {"uuid": "b", "description": "Pay rent", "status": "recurring", "recur": "monthly",
+             "until": "20251231T000000Z", "due": "20250101T000000Z"},
+            {"uuid": "c", "description": "Pay rent", "status": "pending", "parent": "b"},
+            {"uuid": "d", "description": "Old", "status": "deleted"}
+        ]"#;
+        let plan = parse(json, &ImportOptions::default()).unwrap();
+
+        assert_eq!(plan.projects.len(), 2);
+        let work = &plan.projects[0];
+        assert_eq!(work.name, "Work");
+        assert_eq!(work.task_lists[0].name, "Reports");
+        let task = &work.task_lists[0].tasks[0];
+        assert_eq!(task.priority, 1);
+        assert_eq!(task.status, TaskStatus::InProgress);
+        assert!(task.plan_end_date.is_some());
+
+        let rent = &plan.projects[1].task_lists[0].tasks[0];
+        let recurrence = rent.recurrence.as_ref().unwrap();
+        assert!(matches!(recurrence.unit, RecurrenceUnit::Month));
+        assert!(recurrence.end_date.is_some());
+
+        assert_eq!(plan.report.warnings.len(), 2);
+        let fields: Vec<String> = plan
+            .report
+            .unmapped_fields()
+            .into_iter()
+            .map(|f| f.field)
+            .collect();
+        assert_eq!(fields, vec!["annotations", "estimate"]);
+    }
+
+    #[test]
+    fn test_parse_line_delimited_export() {
+        let lines = "{\"description\": \"one\"},\n{\"description\": \"two\"}\n";
+        let plan = parse(lines, &ImportOptions::default()).unwrap();
+        assert_eq!(plan.counts().tasks, 2);
+    }
+}
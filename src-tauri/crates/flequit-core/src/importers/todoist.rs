@@ -0,0 +1,591 @@
+//! Todoistのエクスポート形式
+//!
+//! - CSV: プロジェクトテンプレート（`TYPE,CONTENT,DESCRIPTION,PRIORITY,INDENT,...`）
+//! - JSON: バックアップ（Sync APIの `projects` / `sections` / `items` / `notes`）
+
+use super::csv::CsvTable;
+use super::error::ImportError;
+use super::plan::{ImportPlan, ImportedSubTask, ImportedTask};
+use super::values::{parse_datetime, parse_recurrence_text};
+use super::{DEFAULT_TASK_LIST_NAME, ImportOptions};
+use flequit_model::types::task_types::TaskStatus;
+use serde_json::Value;
+use std::collections::HashMap;
+
+const JSON_FORMAT: &str = "Todoist JSON";
+const DEFAULT_PROJECT_NAME: &str = "Todoist";
+
+/// CSVで取り込む列（これ以外の列は未対応として報告する）
+const CSV_KNOWN_COLUMNS: &[&str] = &[
+    "TYPE",
+    "CONTENT",
+    "DESCRIPTION",
+    "PRIORITY",
+    "INDENT",
+    "DATE",
+    "DATE_LANG",
+    "TIMEZONE",
+];
+
+/// 値があれば未対応として報告するCSV列
+const CSV_UNSUPPORTED_COLUMNS: &[(&str, &str)] = &[
+    ("AUTHOR", "Task authors are not imported"),
+    (
+        "RESPONSIBLE",
+        "Assignees cannot be matched to Flequit users",
+    ),
+    ("DURATION", "Task durations are not supported"),
+    ("DURATION_UNIT", "Task durations are not supported"),
+];
+
+/// 取り込むアイテムのキー（これ以外で値を持つキーは未対応として報告する）
+const JSON_ITEM_KNOWN_KEYS: &[&str] = &[
+    "id",
+    "v2_id",
+    "project_id",
+    "v2_project_id",
+    "section_id",
+    "v2_section_id",
+    "parent_id",
+    "v2_parent_id",
+    "content",
+    "description",
+    "priority",
+    "labels",
+    "due",
+    "checked",
+    "is_deleted",
+    "child_order",
+    "day_order",
+    "collapsed",
+    "user_id",
+    "added_by_uid",
+    "assigned_by_uid",
+    "added_at",
+    "updated_at",
+    "completed_at",
+    "sync_id",
+];
+
+/// CONTENT から `@ラベル` を取り出し、タイトルとラベルに分ける
+fn split_labels(content: &str) -> (String, Vec<String>) {
+    let mut labels = Vec::new();
+    let mut words = Vec::new();
+    for word in content.split_whitespace() {
+        match word.strip_prefix('@') {
+            Some(label) if !label.is_empty() => labels.push(label.to_string()),
+            _ => words.push(word),
+        }
+    }
+    (words.join(" "), labels)
+}
+
+/// CSVテンプレートの優先度（1が最高、4が最低）
+fn csv_priority(value: Option<&str>) -> i32 {
+    value
+        .and_then(|v| v.parse::<i32>().ok())
+        .filter(|p| (1..=3).contains(p))
+        .unwrap_or(0)
+}
+
+/// API形式の優先度（4が最高、1が通常）
+fn api_priority(value: Option<i64>) -> i32 {
+    match value {
+        Some(4) => 1,
+        Some(3) => 2,
+        Some(2) => 3,
+        _ => 0,
+    }
+}
+
+/// CSVのDATE列（日付または繰り返し表現）をタスクへ反映
+fn apply_due(
+    task: &mut ImportedTask,
+    due: &str,
+    is_recurring: bool,
+    plan: &mut ImportPlan,
+    field: &str,
+) {
+    if let Some(date) = parse_datetime(due) {
+        task.plan_end_date = Some(date);
+        return;
+    }
+    match parse_recurrence_text(due) {
+        Some(recurrence) => task.recurrence = Some(recurrence),
+        None if is_recurring => plan.report.unmapped(
+            field,
+            Some(due),
+            "Recurrence pattern could not be interpreted",
+        ),
+        None => plan
+            .report
+            .unmapped(field, Some(due), "Natural language dates are not supported"),
+    }
+}
+
+/// プロジェクトテンプレートCSVを解析
+pub fn parse_csv(content: &str, options: &ImportOptions) -> Result<ImportPlan, ImportError> {
+    let table = CsvTable::parse(content)?;
+    let type_col = table.column("TYPE");
+    let content_col = table
+        .column("CONTENT")
+        .ok_or_else(|| ImportError::MissingColumn("CONTENT".to_string()))?;
+    if type_col.is_none() {
+        return Err(ImportError::MissingColumn("TYPE".to_string()));
+    }
+    let description_col = table.column("DESCRIPTION");
+    let priority_col = table.column("PRIORITY");
+    let indent_col = table.column("INDENT");
+    let date_col = table.column("DATE");
+
+    let project_name = options.project_name_or(DEFAULT_PROJECT_NAME);
+    let mut plan = ImportPlan::default();
+    plan.project_mut(&project_name);
+    let mut list_name = DEFAULT_TASK_LIST_NAME.to_string();
+
+    let unknown_columns: Vec<(usize, &String)> = table
+        .headers
+        .iter()
+        .enumerate()
+        .filter(|(_, h)| {
+            !CSV_KNOWN_COLUMNS.iter().any(|k| h.eq_ignore_ascii_case(k))
+                && !CSV_UNSUPPORTED_COLUMNS
+                    .iter()
+                    .any(|(k, _)| h.eq_ignore_ascii_case(k))
+        })
+        .collect();
+    let unsupported_columns: Vec<(usize, &str)> = CSV_UNSUPPORTED_COLUMNS
+        .iter()
+        .filter_map(|(name, reason)| table.column(name).map(|i| (i, *reason)))
+        .collect();
+
+    for (line, fields) in &table.rows {
+        let row_type = CsvTable::value(fields, type_col)
+            .unwrap_or_default()
+            .to_lowercase();
+        let text = CsvTable::value(fields, Some(content_col)).unwrap_or_default();
+
+        match row_type.as_str() {
+            "section" => {
+                if !text.is_empty() {
+                    list_name = text.to_string();
+                    plan.task_list_mut(&project_name, &list_name);
+                }
+                continue;
+            }
+            "task" => {}
+            "note" => {
+                plan.report
+                    .unmapped("note", Some(text), "Comments are not supported");
+                continue;
+            }
+            "meta" => continue,
+            other => {
+                plan.report.warn(format!(
+                    "Line {line}: skipped row with unknown TYPE '{other}'"
+                ));
+                continue;
+            }
+        }
+
+        for (index, header) in &unknown_columns {
+            if let Some(value) = CsvTable::value(fields, Some(*index)) {
+                plan.report
+                    .unmapped(header, Some(value), "Unknown Todoist column");
+            }
+        }
+        for (index, reason) in &unsupported_columns {
+            if let Some(value) = CsvTable::value(fields, Some(*index)) {
+                plan.report
+                    .unmapped(&table.headers[*index], Some(value), reason);
+            }
+        }
+
+        let (title, labels) = split_labels(text);
+        if title.is_empty() {
+            plan.report
+                .warn(format!("Line {line}: skipped task without content"));
+            continue;
+        }
+        let description = CsvTable::value(fields, description_col).map(str::to_string);
+        let priority = csv_priority(CsvTable::value(fields, priority_col));
+        let indent = CsvTable::value(fields, indent_col)
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(1);
+        let due = CsvTable::value(fields, date_col);
+
+        let list = plan.task_list_mut(&project_name, &list_name);
+        if indent >= 2 && !list.tasks.is_empty() {
+            let mut subtask = ImportedSubTask {
+                title,
+                description,
+                priority: (priority > 0).then_some(priority),
+                tags: labels,
+                ..Default::default()
+            };
+            if let Some(due) = due {
+                match parse_datetime(due) {
+                    Some(date) => subtask.plan_end_date = Some(date),
+                    None => plan.report.unmapped(
+                        "DATE",
+                        Some(due),
+                        "Subtask recurrence and natural language dates are not supported",
+                    ),
+                }
+            }
+            if indent > 2 {
+                plan.report.unmapped(
+                    "INDENT",
+                    Some(&indent.to_string()),
+                    "Nesting deeper than subtasks was flattened",
+                );
+            }
+            let list = plan.task_list_mut(&project_name, &list_name);
+            if let Some(parent) = list.tasks.last_mut() {
+                parent.subtasks.push(subtask);
+            }
+            continue;
+        }
+
+        let mut task = ImportedTask {
+            title,
+            description,
+            priority,
+            tags: labels,
+            ..Default::default()
+        };
+        if let Some(due) = due {
+            apply_due(&mut task, due, false, &mut plan, "DATE");
+        }
+        plan.task_list_mut(&project_name, &list_name)
+            .tasks
+            .push(task);
+    }
+
+    Ok(plan)
+}
+
+/// JSON値をIDとして文字列化（数値・文字列どちらのIDにも対応）
+fn id_of(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn str_of<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+fn is_truthy(value: Option<&Value>) -> bool {
+    match value {
+        Some(Value::Bool(b)) => *b,
+        Some(Value::Number(n)) => n.as_i64().unwrap_or(0) != 0,
+        _ => false,
+    }
+}
+
+/// JSONの配列を取得（キーが無い場合は空）
+fn array_of<'a>(root: &'a Value, key: &str) -> &'a [Value] {
+    root.get(key)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// アイテムのラベル名
+fn item_labels(item: &Value) -> Vec<String> {
+    array_of(item, "labels")
+        .iter()
+        .filter_map(Value::as_str)
+        .map(str::to_string)
+        .collect()
+}
+
+/// バックアップJSONを解析
+pub fn parse_json(content: &str, options: &ImportOptions) -> Result<ImportPlan, ImportError> {
+    let root: Value = serde_json::from_str(content)
+        .map_err(|e| ImportError::invalid(JSON_FORMAT, e.to_string()))?;
+    if !root.is_object() || root.get("items").is_none() {
+        return Err(ImportError::invalid(
+            JSON_FORMAT,
+            "Expected an object with an 'items' array",
+        ));
+    }
+
+    let mut plan = ImportPlan::default();
+    let fallback_project = options.project_name_or(DEFAULT_PROJECT_NAME);
+
+    let mut project_names = HashMap::new();
+    for project in array_of(&root, "projects") {
+        if is_truthy(project.get("is_deleted")) {
+            continue;
+        }
+        let Some(id) = id_of(project.get("id")) else {
+            continue;
+        };
+        let name = str_of(project, "name")
+            .unwrap_or(&fallback_project)
+            .to_string();
+        let imported = plan.project_mut(&name);
+        imported.color = str_of(project, "color").map(str::to_string);
+        project_names.insert(id, name);
+    }
+
+    let mut section_names = HashMap::new();
+    for section in array_of(&root, "sections") {
+        if is_truthy(section.get("is_deleted")) {
+            continue;
+        }
+        if let (Some(id), Some(name)) = (id_of(section.get("id")), str_of(section, "name")) {
+            section_names.insert(id, name.to_string());
+        }
+    }
+
+    let notes = array_of(&root, "notes");
+    for note in notes {
+        plan.report.unmapped(
+            "notes",
+            str_of(note, "content"),
+            "Comments are not supported",
+        );
+    }
+    for reminder in array_of(&root, "reminders") {
+        let due = reminder.get("due").and_then(|d| str_of(d, "date"));
+        plan.report
+            .unmapped("reminders", due, "Reminders are not supported");
+    }
+
+    let mut items: Vec<&Value> = array_of(&root, "items")
+        .iter()
+        .filter(|item| !is_truthy(item.get("is_deleted")))
+        .collect();
+    items.sort_by_key(|item| item.get("child_order").and_then(Value::as_i64).unwrap_or(0));
+
+    let parent_of: HashMap<String, String> = items
+        .iter()
+        .filter_map(|item| Some((id_of(item.get("id"))?, id_of(item.get("parent_id"))?)))
+        .collect();
+    // 親を辿ってトップレベルのアイテムIDを求める（循環は打ち切る）
+    let root_of = |id: &String| -> String {
+        let mut current = id.clone();
+        for _ in 0..parent_of.len() {
+            match parent_of.get(&current) {
+                Some(parent) => current = parent.clone(),
+                None => break,
+            }
+        }
+        current
+    };
+
+    // トップレベルのアイテムID -> (プロジェクト名, タスクリスト名, タスク位置)
+    let mut task_locations: HashMap<String, (String, String, usize)> = HashMap::new();
+
+    for item in items
+        .iter()
+        .filter(|item| item.get("parent_id").is_none_or(Value::is_null))
+    {
+        for (key, value) in item.as_object().into_iter().flatten() {
+            if JSON_ITEM_KNOWN_KEYS.contains(&key.as_str()) || value.is_null() {
+                continue;
+            }
+            let sample = value
+                .as_str()
+                .map(str::to_string)
+                .unwrap_or_else(|| value.to_string());
+            plan.report
+                .unmapped(key, Some(&sample), "Unsupported Todoist field");
+        }
+
+        let Some(title) = str_of(item, "content") else {
+            plan.report.warn("Skipped an item without content");
+            continue;
+        };
+        let project_name = id_of(item.get("project_id"))
+            .and_then(|id| project_names.get(&id).cloned())
+            .unwrap_or_else(|| fallback_project.clone());
+        let list_name = id_of(item.get("section_id"))
+            .and_then(|id| section_names.get(&id).cloned())
+            .unwrap_or_else(|| DEFAULT_TASK_LIST_NAME.to_string());
+
+        let mut task = ImportedTask {
+            title: title.to_string(),
+            description: str_of(item, "description").map(str::to_string),
+            priority: api_priority(item.get("priority").and_then(Value::as_i64)),
+            tags: item_labels(item),
+            status: if is_truthy(item.get("checked")) {
+                TaskStatus::Completed
+            } else {
+                TaskStatus::NotStarted
+            },
+            ..Default::default()
+        };
+        if let Some(due) = item.get("due").filter(|d| d.is_object()) {
+            if let Some(date) = str_of(due, "date").and_then(parse_datetime) {
+                task.plan_end_date = Some(date);
+            }
+            if is_truthy(due.get("is_recurring"))
+                && let Some(text) = str_of(due, "string")
+            {
+                apply_due(&mut task, text, true, &mut plan, "due.string");
+            }
+        }
+
+        let list = plan.task_list_mut(&project_name, &list_name);
+        list.tasks.push(task);
+        if let Some(id) = id_of(item.get("id")) {
+            task_locations.insert(id, (project_name, list_name, list.tasks.len() - 1));
+        }
+    }
+
+    let mut flattened = 0;
+    for item in items
+        .iter()
+        .filter(|item| !item.get("parent_id").is_none_or(Value::is_null))
+    {
+        let Some(id) = id_of(item.get("id")) else {
+            continue;
+        };
+        let Some(title) = str_of(item, "content") else {
+            continue;
+        };
+        let root_id = root_of(&id);
+        let Some((project_name, list_name, index)) = task_locations.get(&root_id).cloned() else {
+            plan.report.warn(format!(
+                "Skipped subtask '{title}' whose parent task was not found"
+            ));
+            continue;
+        };
+        if id_of(item.get("parent_id")).as_ref() != Some(&root_id) {
+            flattened += 1;
+        }
+
+        let priority = api_priority(item.get("priority").and_then(Value::as_i64));
+        let subtask = ImportedSubTask {
+            title: title.to_string(),
+            description: str_of(item, "description").map(str::to_string),
+            status: if is_truthy(item.get("checked")) {
+                TaskStatus::Completed
+            } else {
+                TaskStatus::NotStarted
+            },
+            priority: (priority > 0).then_some(priority),
+            plan_end_date: item
+                .get("due")
+                .and_then(|d| str_of(d, "date"))
+                .and_then(parse_datetime),
+            tags: item_labels(item),
+        };
+        if let Some(task) = plan
+            .task_list_mut(&project_name, &list_name)
+            .tasks
+            .get_mut(index)
+        {
+            task.subtasks.push(subtask);
+        }
+    }
+    if flattened > 0 {
+        plan.report.warn(format!(
+            "{flattened} sub-subtask(s) were flattened into subtasks of their top-level task"
+        ));
+    }
+
+    if plan.projects.is_empty() {
+        plan.project_mut(&fallback_project);
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flequit_model::types::datetime_calendar_types::RecurrenceUnit;
+
+    #[test]
+    fn test_parse_csv_template() {
+        let csv = "TYPE,CONTENT,DESCRIPTION,PRIORITY,INDENT,AUTHOR,RESPONSIBLE,DATE,DATE_LANG,TIMEZONE\n\
+                   task,Inbox task,,4,1,,,,,\n\
+                   section,Errands,,,,,,,,\n\
+                   task,Buy milk @shop @home,2 litres,1,1,Alice (1),,every 2 weeks,en,UTC\n\
+                   task,Check price,,4,2,,,2025-03-01,en,UTC\n\
+                   note,Remember coupons,,,,,,,,\n";
+        let plan = parse_csv(csv, &ImportOptions::default()).unwrap();
+
+        let project = &plan.projects[0];
+        assert_eq!(project.name, "Todoist");
+        assert_eq!(project.task_lists.len(), 2);
+        assert_eq!(project.task_lists[0].tasks[0].priority, 0);
+
+        let task = &project.task_lists[1].tasks[0];
+        assert_eq!(task.title, "Buy milk");
+        assert_eq!(task.tags, vec!["shop", "home"]);
+        assert_eq!(task.priority, 1);
+        let recurrence = task.recurrence.as_ref().unwrap();
+        assert!(matches!(recurrence.unit, RecurrenceUnit::Week));
+        assert_eq!(recurrence.interval, 2);
+        assert_eq!(task.subtasks.len(), 1);
+        assert!(task.subtasks[0].plan_end_date.is_some());
+
+        let fields: Vec<String> = plan
+            .report
+            .unmapped_fields()
+            .into_iter()
+            .map(|f| f.field)
+            .collect();
+        assert!(fields.contains(&"AUTHOR".to_string()));
+        assert!(fields.contains(&"note".to_string()));
+    }
+
+    #[test]
+    fn test_parse_json_backup() {
+        let json = r#"{
+            "projects": [{"id": "p1", "name": "Work", "color": "blue"}],
+            "sections": [{"id": "s1", "name": "Doing", "project_id": "p1"}],
+            "items": [
+                {"id": "i1", "project_id": "p1", "section_id": "s1", "content": "Report",
+                 "priority": 4, "labels": ["office"], "checked": false,
+                 "due": {"date": "2025-02-01", "string": "every monday", "is_recurring": true},
+                 "responsible_uid": "42"},
+                {"id": "i2", "project_id": "p1", "parent_id": "i1", "content": "Draft", "checked": true},
+                {"id": "i3", "project_id": "p1", "parent_id": "i2", "content": "Outline"}
+            ],
+            "notes": [{"item_id": "i1", "content": "see wiki"}]
+        }"#;
+        let plan = parse_json(json, &ImportOptions::default()).unwrap();
+
+        let project = &plan.projects[0];
+        assert_eq!(project.name, "Work");
+        let list = &project.task_lists[0];
+        assert_eq!(list.name, "Doing");
+        let task = &list.tasks[0];
+        assert_eq!(task.priority, 1);
+        assert!(task.plan_end_date.is_some());
+        assert_eq!(
+            task.recurrence
+                .as_ref()
+                .unwrap()
+                .days_of_week
+                .as_ref()
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(task.subtasks.len(), 2);
+        assert_eq!(task.subtasks[0].status, TaskStatus::Completed);
+
+        assert_eq!(plan.report.warnings.len(), 1);
+        let fields: Vec<String> = plan
+            .report
+            .unmapped_fields()
+            .into_iter()
+            .map(|f| f.field)
+            .collect();
+        assert!(fields.contains(&"notes".to_string()));
+        assert!(fields.contains(&"responsible_uid".to_string()));
+    }
+}
@@ -0,0 +1,191 @@
+//! 各形式で共通する値（日時・繰り返し表現）の変換
+
+use super::plan::ImportedRecurrence;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use flequit_model::types::datetime_calendar_types::{DayOfWeek, RecurrenceUnit};
+
+/// 日時文字列を解釈する
+///
+/// RFC 3339、Taskwarrior形式（`20250101T090000Z`）、
+/// `YYYY-MM-DD`、`YYYY-MM-DD HH:MM[:SS]`、`YYYY/MM/DD` に対応する。
+/// タイムゾーンが無い場合はUTCとして扱う。
+pub fn parse_datetime(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    const DATETIME_FORMATS: &[&str] = &[
+        "%Y%m%dT%H%M%SZ",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ];
+    for format in DATETIME_FORMATS {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(naive.and_utc());
+        }
+    }
+
+    const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d", "%Y%m%d"];
+    for format in DATE_FORMATS {
+        if let Ok(date) = NaiveDate::parse_from_str(value, format) {
+            return date.and_hms_opt(0, 0, 0).map(|naive| naive.and_utc());
+        }
+    }
+    None
+}
+
+fn parse_weekday(word: &str) -> Option<DayOfWeek> {
+    let word = word.trim().trim_end_matches('s').to_lowercase();
+    let day = match word.get(..3)? {
+        "sun" => DayOfWeek::Sunday,
+        "mon" => DayOfWeek::Monday,
+        "tue" => DayOfWeek::Tuesday,
+        "wed" => DayOfWeek::Wednesday,
+        "thu" => DayOfWeek::Thursday,
+        "fri" => DayOfWeek::Friday,
+        "sat" => DayOfWeek::Saturday,
+        _ => return None,
+    };
+    Some(day)
+}
+
+fn parse_unit(word: &str) -> Option<RecurrenceUnit> {
+    let unit = match word.trim().to_lowercase().as_str() {
+        "minute" | "minutes" | "min" | "mins" => RecurrenceUnit::Minute,
+        "hour" | "hours" | "h" | "hr" | "hrs" => RecurrenceUnit::Hour,
+        "day" | "days" | "d" => RecurrenceUnit::Day,
+        "week" | "weeks" | "w" | "wk" | "wks" => RecurrenceUnit::Week,
+        "month" | "months" | "mo" | "mos" | "m" => RecurrenceUnit::Month,
+        "quarter" | "quarters" | "q" | "qtr" | "qtrs" => RecurrenceUnit::Quarter,
+        "year" | "years" | "y" | "yr" | "yrs" => RecurrenceUnit::Year,
+        _ => return None,
+    };
+    Some(unit)
+}
+
+fn weekdays() -> Vec<DayOfWeek> {
+    vec![
+        DayOfWeek::Monday,
+        DayOfWeek::Tuesday,
+        DayOfWeek::Wednesday,
+        DayOfWeek::Thursday,
+        DayOfWeek::Friday,
+    ]
+}
+
+/// 英語の繰り返し表現を解釈する
+///
+/// Todoistの `every day` / `every 2 weeks` / `every mon, fri` / `every weekday`、
+/// Taskwarriorの `daily` / `weekly` / `biweekly` / `weekdays` / `3d` / `2wk` などに対応する。
+pub fn parse_recurrence_text(text: &str) -> Option<ImportedRecurrence> {
+    let text = text.trim().to_lowercase();
+    let text = text
+        .strip_prefix("every!")
+        .or_else(|| text.strip_prefix("every "))
+        .or_else(|| text.strip_prefix("each "))
+        .unwrap_or(&text)
+        .trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    let simple = match text {
+        "daily" | "day" => Some((RecurrenceUnit::Day, 1)),
+        "weekly" | "week" => Some((RecurrenceUnit::Week, 1)),
+        "biweekly" | "fortnight" | "fortnightly" | "other week" => Some((RecurrenceUnit::Week, 2)),
+        "monthly" | "month" => Some((RecurrenceUnit::Month, 1)),
+        "bimonthly" | "other month" => Some((RecurrenceUnit::Month, 2)),
+        "quarterly" | "quarter" => Some((RecurrenceUnit::Quarter, 1)),
+        "semiannual" | "semiannually" => Some((RecurrenceUnit::HalfYear, 1)),
+        "yearly" | "annual" | "annually" | "year" => Some((RecurrenceUnit::Year, 1)),
+        "biannual" | "biyearly" | "other year" => Some((RecurrenceUnit::Year, 2)),
+        "hourly" | "hour" => Some((RecurrenceUnit::Hour, 1)),
+        "other day" => Some((RecurrenceUnit::Day, 2)),
+        _ => None,
+    };
+    if let Some((unit, interval)) = simple {
+        return Some(ImportedRecurrence::every(unit, interval));
+    }
+
+    if matches!(text, "weekday" | "weekdays" | "workday" | "workdays") {
+        let mut recurrence = ImportedRecurrence::every(RecurrenceUnit::Week, 1);
+        recurrence.days_of_week = Some(weekdays());
+        return Some(recurrence);
+    }
+
+    // 曜日の列挙: "mon, fri" / "monday and thursday"
+    let day_words: Vec<&str> = text
+        .split([',', ' '])
+        .map(str::trim)
+        .filter(|w| !w.is_empty() && *w != "and")
+        .collect();
+    let days: Option<Vec<DayOfWeek>> = day_words.iter().map(|w| parse_weekday(w)).collect();
+    if let Some(days) = days.filter(|d| !d.is_empty()) {
+        let mut recurrence = ImportedRecurrence::every(RecurrenceUnit::Week, 1);
+        recurrence.days_of_week = Some(days);
+        return Some(recurrence);
+    }
+
+    // 回数と単位: "2 weeks" / "3d" / "2wk"
+    let digits_end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    if digits_end == 0 {
+        return None;
+    }
+    let interval: i32 = text[..digits_end].parse().ok().filter(|n| *n > 0)?;
+    let unit = parse_unit(&text[digits_end..])?;
+    Some(ImportedRecurrence::every(unit, interval))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_datetime_formats() {
+        let expected = Utc.with_ymd_and_hms(2025, 1, 2, 9, 30, 0).unwrap();
+        assert_eq!(parse_datetime("2025-01-02T09:30:00Z"), Some(expected));
+        assert_eq!(parse_datetime("20250102T093000Z"), Some(expected));
+        assert_eq!(parse_datetime("2025-01-02 09:30"), Some(expected));
+        assert_eq!(
+            parse_datetime("2025/01/02"),
+            Some(Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap())
+        );
+        assert_eq!(parse_datetime("tomorrow"), None);
+    }
+
+    #[test]
+    fn test_parse_recurrence_text() {
+        let r = parse_recurrence_text("every 2 weeks").unwrap();
+        assert!(matches!(r.unit, RecurrenceUnit::Week));
+        assert_eq!(r.interval, 2);
+
+        let r = parse_recurrence_text("3d").unwrap();
+        assert!(matches!(r.unit, RecurrenceUnit::Day));
+        assert_eq!(r.interval, 3);
+
+        let r = parse_recurrence_text("every mon, fri").unwrap();
+        let days = r.days_of_week.unwrap();
+        assert_eq!(days.len(), 2);
+        assert!(matches!(days[0], DayOfWeek::Monday));
+        assert!(matches!(days[1], DayOfWeek::Friday));
+
+        let r = parse_recurrence_text("weekdays").unwrap();
+        assert_eq!(r.days_of_week.unwrap().len(), 5);
+
+        assert!(matches!(
+            parse_recurrence_text("quarterly").unwrap().unit,
+            RecurrenceUnit::Quarter
+        ));
+        assert!(parse_recurrence_text("every other full moon").is_none());
+        assert!(parse_recurrence_text("").is_none());
+    }
+}
@@ -3,8 +3,12 @@
 //! このクレートは、Flequitアプリケーションのコアビジネスロジックを提供します。
 //! サービス、ファサード、型定義などを含みます。
 
+pub mod events;
+pub mod exporters;
 pub mod facades;
+pub mod importers;
 pub mod ports;
 pub mod services;
+pub mod undo;
 
 pub use ports::infrastructure_repositories::InfrastructureRepositoriesTrait;
@@ -1,8 +1,12 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use flequit_model::models::accounts::account::Account;
+use flequit_model::models::task_projects::comment::Comment;
 use flequit_model::models::task_projects::project::Project;
 use flequit_model::models::task_projects::recurrence_rule::RecurrenceRule;
+use flequit_model::models::task_projects::reminder::Reminder;
+use flequit_model::models::task_projects::snooze::Snooze;
+use flequit_model::models::task_projects::status_transition_rule::StatusTransitionRule;
 use flequit_model::models::task_projects::subtask::SubTask;
 use flequit_model::models::task_projects::subtask_assignment::SubTaskAssignment;
 use flequit_model::models::task_projects::subtask_recurrence::SubTaskRecurrence;
@@ -10,14 +14,18 @@ use flequit_model::models::task_projects::subtask_tag::SubTaskTag;
 use flequit_model::models::task_projects::tag::Tag;
 use flequit_model::models::task_projects::task::Task;
 use flequit_model::models::task_projects::task_assignment::TaskAssignment;
+use flequit_model::models::task_projects::task_dependency::TaskDependency;
 use flequit_model::models::task_projects::task_list::TaskList;
 use flequit_model::models::task_projects::task_recurrence::TaskRecurrence;
 use flequit_model::models::task_projects::task_tag::TaskTag;
+use flequit_model::models::task_projects::time_entry::TimeEntry;
+use flequit_model::models::task_projects::workflow_status::WorkflowStatus;
 use flequit_model::models::user_preferences::tag_bookmark::TagBookmark;
 use flequit_model::models::users::user::User;
 use flequit_model::types::id_types::{
-    AccountId, ProjectId, RecurrenceRuleId, SubTaskId, TagBookmarkId, TagId, TaskId, TaskListId,
-    UserId,
+    AccountId, CommentId, ProjectId, RecurrenceRuleId, ReminderId, SnoozeId,
+    StatusTransitionRuleId, SubTaskId, TagBookmarkId, TagId, TaskId, TaskListId, TimeEntryId,
+    UserId, WorkflowStatusId,
 };
 use flequit_repository::repositories::base_repository_trait::Repository;
 use flequit_repository::repositories::patchable_trait::Patchable;
@@ -332,7 +340,7 @@ pub trait AutomergeProjectRepositoryPort: Send + Sync {
         project_id: &ProjectId,
     ) -> Result<Option<Project>, RepositoryError>;
     async fn get_deleted_tasks(&self, project_id: &ProjectId)
-        -> Result<Vec<Task>, RepositoryError>;
+    -> Result<Vec<Task>, RepositoryError>;
     async fn get_deleted_tags(&self, project_id: &ProjectId) -> Result<Vec<Tag>, RepositoryError>;
     async fn get_deleted_task_lists(
         &self,
@@ -390,6 +398,19 @@ pub trait InfrastructureRepositoriesTrait: Send + Sync + std::fmt::Debug {
     type SubtaskRecurrencesRepository: ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         + Send
         + Sync;
+    type TaskDependenciesRepository: ProjectRelationRepository<TaskDependency, TaskId, TaskId>
+        + Send
+        + Sync;
+    type TimeEntriesRepository: ProjectRepository<TimeEntry, TimeEntryId> + Send + Sync;
+    type RemindersRepository: ProjectRepository<Reminder, ReminderId> + Send + Sync;
+    type SnoozesRepository: ProjectRepository<Snooze, SnoozeId> + Send + Sync;
+    type CommentsRepository: ProjectRepository<Comment, CommentId> + Send + Sync;
+    type StatusTransitionRulesRepository: ProjectRepository<StatusTransitionRule, StatusTransitionRuleId>
+        + Send
+        + Sync;
+    type WorkflowStatusesRepository: ProjectRepository<WorkflowStatus, WorkflowStatusId>
+        + Send
+        + Sync;
 
     type TagBookmarksSqliteRepository: TagBookmarkSqliteRepositoryPort;
     type TagBookmarksAutomergeRepository: TagBookmarkAutomergeRepositoryPort;
@@ -411,6 +432,13 @@ pub trait InfrastructureRepositoriesTrait: Send + Sync + std::fmt::Debug {
     fn subtask_tags(&self) -> &Self::SubtaskTagsRepository;
     fn task_recurrences(&self) -> &Self::TaskRecurrencesRepository;
     fn subtask_recurrences(&self) -> &Self::SubtaskRecurrencesRepository;
+    fn task_dependencies(&self) -> &Self::TaskDependenciesRepository;
+    fn time_entries(&self) -> &Self::TimeEntriesRepository;
+    fn reminders(&self) -> &Self::RemindersRepository;
+    fn snoozes(&self) -> &Self::SnoozesRepository;
+    fn comments(&self) -> &Self::CommentsRepository;
+    fn status_transition_rules(&self) -> &Self::StatusTransitionRulesRepository;
+    fn workflow_statuses(&self) -> &Self::WorkflowStatusesRepository;
 
     fn tag_bookmarks_sqlite(&self) -> &Self::TagBookmarksSqliteRepository;
     fn tag_bookmarks_automerge(&self) -> &Self::TagBookmarksAutomergeRepository;
@@ -420,4 +448,18 @@ pub trait InfrastructureRepositoriesTrait: Send + Sync + std::fmt::Debug {
 
     async fn initialize(&mut self) -> Result<(), Box<dyn std::error::Error>>;
     async fn cleanup(&mut self) -> Result<(), Box<dyn std::error::Error>>;
+
+    /// `work` を1つの作業単位（Unit of Work）として実行する
+    ///
+    /// `work` が `Err` を返した場合、その中で行ったすべてのバックエンドへの書き込みを
+    /// 取り消す。作業単位の中で呼び出した場合は外側の作業単位に含まれる。
+    /// 既定の実装は `work` をそのまま実行する。
+    async fn run_in_unit_of_work<T, E, F>(&self, work: F) -> Result<T, E>
+    where
+        T: Send,
+        E: From<RepositoryError> + Send,
+        F: std::future::Future<Output = Result<T, E>> + Send,
+    {
+        work.await
+    }
 }
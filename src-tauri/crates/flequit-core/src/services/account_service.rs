@@ -1,6 +1,7 @@
 use chrono::Utc;
 
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
 use flequit_model::models::accounts::account::{Account, PartialAccount};
 use flequit_model::types::id_types::{AccountId, UserId};
 use flequit_repository::repositories::base_repository_trait::Repository;
@@ -24,6 +25,7 @@ where
         .save(&new_data, user_id, &now)
         .await?;
 
+    events::publish(DomainEvent::created(EntityKind::Account, new_data.id).by(user_id));
     Ok(())
 }
 
@@ -56,5 +58,6 @@ where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
     repositories.accounts().delete(account_id).await?;
+    events::publish(DomainEvent::deleted(EntityKind::Account, account_id));
     Ok(())
 }
@@ -0,0 +1,60 @@
+//! カレンダーエクスポートサービス
+//!
+//! プロジェクトのタスクをiCalendar形式へ変換します。
+
+use crate::InfrastructureRepositoriesTrait;
+use crate::exporters::ical::{self, CalendarExportFilter, ICalendar};
+use crate::services::{project_service, tag_service, task_list_service};
+use flequit_model::types::id_types::ProjectId;
+use flequit_types::errors::service_error::ServiceError;
+
+/// 1プロジェクトのタスクをiCalendar文字列として出力します。
+pub async fn export_project_calendar<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    filter: &CalendarExportFilter,
+) -> Result<String, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let project = project_service::get_project(repositories, project_id)
+        .await?
+        .filter(|project| !project.deleted)
+        .ok_or_else(|| ServiceError::NotFound(format!("Project not found: {}", project_id)))?;
+    let task_lists = task_list_service::get_task_lists_with_tasks(repositories, project_id).await?;
+    let tags = tag_service::list_tags(repositories, project_id).await?;
+
+    Ok(ical::project_calendar(&project, &task_lists, &tags, filter).to_ics())
+}
+
+/// 複数プロジェクトのタスクを1つのiCalendar文字列として出力します。
+///
+/// `project_ids` が空の場合は、アーカイブ・削除されていない全プロジェクトを対象とします。
+pub async fn export_calendar<R>(
+    repositories: &R,
+    project_ids: &[ProjectId],
+    filter: &CalendarExportFilter,
+    calendar_name: Option<String>,
+) -> Result<String, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let projects = project_service::list_projects(repositories).await?;
+    let targets = projects.iter().filter(|project| {
+        !project.deleted
+            && if project_ids.is_empty() {
+                filter.include_archived || !project.is_archived
+            } else {
+                project_ids.contains(&project.id)
+            }
+    });
+
+    let mut calendar = ICalendar::new(calendar_name);
+    for project in targets {
+        let task_lists =
+            task_list_service::get_task_lists_with_tasks(repositories, &project.id).await?;
+        let tags = tag_service::list_tags(repositories, &project.id).await?;
+        ical::append_project(&mut calendar, &task_lists, &tags, filter);
+    }
+    Ok(calendar.to_ics())
+}
@@ -0,0 +1,404 @@
+//! コメントサービス
+//!
+//! タスク・サブタスクへのコメントの投稿・編集・削除を提供する。
+//! 本文中の`@ハンドル`はユーザーのハンドル（`handle_id`）と照合し、
+//! 言及したユーザーのIDとしてコメントに記録する。
+//! コメントの編集・削除は投稿者本人のみが行える。
+
+use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
+use chrono::Utc;
+use flequit_model::models::task_projects::comment::Comment;
+use flequit_model::models::users::user::User;
+use flequit_model::types::id_types::{CommentId, ProjectId, SubTaskId, TaskId, UserId};
+use flequit_repository::repositories::base_repository_trait::Repository;
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::service_error::ServiceError;
+
+pub async fn get_comment<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    comment_id: &CommentId,
+) -> Result<Option<Comment>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let comment = repositories
+        .comments()
+        .find_by_id(project_id, comment_id)
+        .await?;
+    Ok(comment.filter(|comment| !comment.deleted))
+}
+
+/// プロジェクト内のコメントを投稿順に取得する
+///
+/// `task_id` を指定した場合はそのタスク（サブタスクへのものを含む）のコメントのみ、
+/// `subtask_id` を指定した場合はそのサブタスクのコメントのみを返す。
+pub async fn list_comments<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: Option<&TaskId>,
+    subtask_id: Option<&SubTaskId>,
+) -> Result<Vec<Comment>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let mut comments: Vec<Comment> = repositories
+        .comments()
+        .find_all(project_id)
+        .await?
+        .into_iter()
+        .filter(|comment| !comment.deleted)
+        .filter(|comment| task_id.is_none_or(|task_id| comment.task_id == *task_id))
+        .filter(|comment| {
+            subtask_id.is_none_or(|subtask_id| comment.subtask_id == Some(*subtask_id))
+        })
+        .collect();
+    comments.sort_by_key(|comment| comment.created_at);
+    Ok(comments)
+}
+
+/// タスク（`subtask_id` を指定した場合はサブタスク）にコメントを投稿する
+pub async fn add_comment<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    subtask_id: Option<&SubTaskId>,
+    body: &str,
+    user_id: &UserId,
+) -> Result<Comment, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    validate_target(repositories, project_id, task_id, subtask_id).await?;
+
+    let now = Utc::now();
+    let mut comment = Comment {
+        id: CommentId::new(),
+        project_id: *project_id,
+        task_id: *task_id,
+        subtask_id: subtask_id.copied(),
+        author_id: *user_id,
+        body: normalize_body(body)?,
+        mentioned_user_ids: vec![],
+        created_at: now,
+        edited_at: None,
+        updated_at: now,
+        deleted: false,
+        updated_by: *user_id,
+    };
+    comment.mentioned_user_ids = resolve_mentions(repositories, &comment).await?;
+
+    repositories
+        .comments()
+        .save(project_id, &comment, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::created(EntityKind::Comment, comment.id)
+            .in_project(project_id)
+            .related_to(comment.task_id)
+            .by(user_id),
+    );
+    Ok(comment)
+}
+
+/// コメントの本文を編集する
+///
+/// 言及したユーザーは編集後の本文から改めて記録する。本文が変わらない場合は何もしない。
+pub async fn edit_comment<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    comment_id: &CommentId,
+    body: &str,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(before) = get_comment(repositories, project_id, comment_id).await? else {
+        return Ok(false);
+    };
+    ensure_author(&before, user_id)?;
+
+    let body = normalize_body(body)?;
+    if body == before.body {
+        return Ok(true);
+    }
+
+    let now = Utc::now();
+    let mut comment = before.clone();
+    comment.body = body;
+    comment.mentioned_user_ids = resolve_mentions(repositories, &comment).await?;
+    comment.edited_at = Some(now);
+    comment.updated_at = now;
+    comment.updated_by = *user_id;
+    repositories
+        .comments()
+        .save(project_id, &comment, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::updated(EntityKind::Comment, comment_id)
+            .in_project(project_id)
+            .related_to(comment.task_id)
+            .with_changes(events::field_changes(&before, &comment))
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+/// コメントを論理削除する
+pub async fn delete_comment<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    comment_id: &CommentId,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(mut comment) = get_comment(repositories, project_id, comment_id).await? else {
+        return Ok(false);
+    };
+    ensure_author(&comment, user_id)?;
+
+    let now = Utc::now();
+    comment.deleted = true;
+    comment.updated_at = now;
+    comment.updated_by = *user_id;
+    repositories
+        .comments()
+        .save(project_id, &comment, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::deleted(EntityKind::Comment, comment_id)
+            .in_project(project_id)
+            .related_to(comment.task_id)
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+/// 論理削除したコメントを元に戻す
+pub async fn restore_comment<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    comment_id: &CommentId,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(mut comment) = repositories
+        .comments()
+        .find_by_id(project_id, comment_id)
+        .await?
+        .filter(|comment| comment.deleted)
+    else {
+        return Ok(false);
+    };
+    ensure_author(&comment, user_id)?;
+
+    let now = Utc::now();
+    comment.deleted = false;
+    comment.updated_at = now;
+    comment.updated_by = *user_id;
+    repositories
+        .comments()
+        .save(project_id, &comment, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::restored(EntityKind::Comment, comment_id)
+            .in_project(project_id)
+            .related_to(comment.task_id)
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+/// 本文の前後の空白を取り除き、空でないことを確認する
+fn normalize_body(body: &str) -> Result<String, ServiceError> {
+    let body = body.trim();
+    if body.is_empty() {
+        return Err(ServiceError::ValidationError(
+            "A comment must not be empty".to_string(),
+        ));
+    }
+    Ok(body.to_string())
+}
+
+fn ensure_author(comment: &Comment, user_id: &UserId) -> Result<(), ServiceError> {
+    if comment.author_id != *user_id {
+        return Err(ServiceError::ValidationError(
+            "Only the author can change a comment".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// 本文中の`@ハンドル`を言及したユーザーのIDに変換する
+async fn resolve_mentions<R>(
+    repositories: &R,
+    comment: &Comment,
+) -> Result<Vec<UserId>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let handles = comment.mentioned_handles();
+    if handles.is_empty() {
+        return Ok(vec![]);
+    }
+    let users = repositories.users().find_all().await?;
+    Ok(match_mentions(&handles, &users))
+}
+
+/// ハンドルに一致するユーザーのIDを言及順に返す（大文字・小文字は区別しない）
+///
+/// 一致するユーザーがいないハンドルは言及として扱わない。
+fn match_mentions(handles: &[String], users: &[User]) -> Vec<UserId> {
+    let mut user_ids: Vec<UserId> = Vec::new();
+    for handle in handles {
+        let found = users
+            .iter()
+            .filter(|user| !user.deleted)
+            .find(|user| user.handle_id.eq_ignore_ascii_case(handle));
+        if let Some(user) = found
+            && !user_ids.contains(&user.id)
+        {
+            user_ids.push(user.id);
+        }
+    }
+    user_ids
+}
+
+/// コメント先のタスク（・サブタスク）がプロジェクトに存在することを確認する
+async fn validate_target<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    subtask_id: Option<&SubTaskId>,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    repositories
+        .tasks()
+        .find_by_id(project_id, task_id)
+        .await?
+        .ok_or_else(|| ServiceError::NotFound(format!("Task not found: {}", task_id)))?;
+    if let Some(subtask_id) = subtask_id {
+        let subtask = repositories
+            .sub_tasks()
+            .find_by_id(project_id, subtask_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(format!("Subtask not found: {}", subtask_id)))?;
+        if subtask.task_id != *task_id {
+            return Err(ServiceError::NotFound(format!(
+                "Subtask {} not found in task {}",
+                subtask_id, task_id
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, TimeZone};
+
+    fn at(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 2, 9, minute, 0).unwrap()
+    }
+
+    fn comment(body: &str) -> Comment {
+        let author_id = UserId::new();
+        Comment {
+            id: CommentId::new(),
+            project_id: ProjectId::new(),
+            task_id: TaskId::new(),
+            subtask_id: None,
+            author_id,
+            body: body.to_string(),
+            mentioned_user_ids: vec![],
+            created_at: at(0),
+            edited_at: None,
+            updated_at: at(0),
+            deleted: false,
+            updated_by: author_id,
+        }
+    }
+
+    fn user(handle_id: &str) -> User {
+        let id = UserId::new();
+        User {
+            id,
+            handle_id: handle_id.to_string(),
+            display_name: handle_id.to_string(),
+            email: None,
+            avatar_url: None,
+            bio: None,
+            timezone: None,
+            is_active: true,
+            created_at: at(0),
+            updated_at: at(0),
+            deleted: false,
+            updated_by: id,
+        }
+    }
+
+    #[test]
+    fn test_mentioned_handles_are_parsed_from_body() {
+        let parsed =
+            comment("@alice と @bob.smith へ。@alice もう一度。連絡先: carol@example.com");
+        assert_eq!(
+            parsed.mentioned_handles(),
+            vec!["alice".to_string(), "bob.smith".to_string()]
+        );
+
+        // 文末の句読点や記号だけの言及は含めない
+        assert_eq!(
+            comment("確認お願いします @dave.").mentioned_handles(),
+            vec!["dave"]
+        );
+        assert!(comment("@ だけ").mentioned_handles().is_empty());
+    }
+
+    #[test]
+    fn test_mentions_are_matched_to_known_users() {
+        let alice = user("Alice");
+        let bob = user("bob");
+        let mut removed = user("carol");
+        removed.deleted = true;
+        let users = vec![alice.clone(), bob.clone(), removed];
+
+        let handles: Vec<String> = ["bob", "alice", "carol", "unknown", "BOB"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        // 大文字・小文字を区別せず、存在しないユーザー・削除済みのユーザーは記録しない
+        assert_eq!(match_mentions(&handles, &users), vec![bob.id, alice.id]);
+    }
+
+    #[test]
+    fn test_body_is_trimmed_and_must_not_be_empty() {
+        assert_eq!(normalize_body("  了解です\n").unwrap(), "了解です");
+        assert!(matches!(
+            normalize_body(" \n\t"),
+            Err(ServiceError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_only_author_can_change_comment() {
+        let comment = comment("メモ");
+        assert!(ensure_author(&comment, &comment.author_id).is_ok());
+        assert!(matches!(
+            ensure_author(&comment, &UserId::new()),
+            Err(ServiceError::ValidationError(_))
+        ));
+    }
+}
@@ -0,0 +1,247 @@
+//! インポートサービス
+//!
+//! 解析済みの `ImportPlan` を既存の各サービスを通して保存します。
+
+use crate::InfrastructureRepositoriesTrait;
+use crate::importers::plan::{ImportPlan, ImportedRecurrence};
+use crate::services::{
+    project_service, recurrence_service, subtask_service, subtask_tag_service, tag_service,
+    task_list_service, task_service, task_tag_service,
+};
+use chrono::Utc;
+use flequit_model::models::task_projects::project::Project;
+use flequit_model::models::task_projects::recurrence_rule::RecurrenceRule;
+use flequit_model::models::task_projects::subtask::SubTask;
+use flequit_model::models::task_projects::tag::Tag;
+use flequit_model::models::task_projects::task::Task;
+use flequit_model::models::task_projects::task_list::TaskList;
+use flequit_model::types::id_types::{
+    ProjectId, RecurrenceRuleId, SubTaskId, TagId, TaskId, TaskListId, UserId,
+};
+use flequit_model::types::task_types::TaskStatus;
+use flequit_types::errors::service_error::ServiceError;
+use std::collections::HashMap;
+
+/// プロジェクト内のタグ名からタグIDを解決し、無ければ作成します。
+async fn resolve_tag<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    tags: &mut HashMap<String, TagId>,
+    name: &str,
+    user_id: &UserId,
+) -> Result<TagId, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let key = name.to_lowercase();
+    if let Some(tag_id) = tags.get(&key) {
+        return Ok(*tag_id);
+    }
+
+    let now = Utc::now();
+    let tag = Tag {
+        id: TagId::new(),
+        name: name.to_string(),
+        color: None,
+        order_index: Some(tags.len() as i32),
+        created_at: now,
+        updated_at: now,
+        deleted: false,
+        updated_by: *user_id,
+    };
+    tag_service::create_tag(repositories, project_id, &tag, user_id).await?;
+    tags.insert(key, tag.id);
+    Ok(tag.id)
+}
+
+fn to_recurrence_rule(recurrence: &ImportedRecurrence, user_id: &UserId) -> RecurrenceRule {
+    let now = Utc::now();
+    RecurrenceRule {
+        id: RecurrenceRuleId::new(),
+        unit: recurrence.unit.clone(),
+        interval: recurrence.interval,
+        days_of_week: recurrence.days_of_week.clone(),
+        details: None,
+        adjustment: None,
+        end_date: recurrence.end_date,
+        max_occurrences: recurrence.max_occurrences,
+        created_at: now,
+        updated_at: now,
+        deleted: false,
+        updated_by: *user_id,
+    }
+}
+
+/// インポート計画を保存し、作成したプロジェクトIDを返します。
+///
+/// プロジェクトは常に新規作成し、タグはプロジェクト内で名前（大文字小文字を区別しない）ごとに1つ作成します。
+pub async fn apply_import_plan<R>(
+    repositories: &R,
+    plan: &ImportPlan,
+    user_id: &UserId,
+) -> Result<Vec<ProjectId>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let mut project_ids = Vec::with_capacity(plan.projects.len());
+    let existing_projects = project_service::list_projects(repositories).await?.len();
+
+    for (project_index, imported_project) in plan.projects.iter().enumerate() {
+        let now = Utc::now();
+        let project = Project {
+            id: ProjectId::new(),
+            name: imported_project.name.clone(),
+            description: imported_project.description.clone(),
+            color: imported_project.color.clone(),
+            order_index: (existing_projects + project_index) as i32,
+            is_archived: false,
+            status: None,
+            owner_id: Some(*user_id),
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: *user_id,
+        };
+        let project = project_service::create_project(repositories, &project, user_id).await?;
+        let project_id = project.id;
+        let mut tags: HashMap<String, TagId> = HashMap::new();
+
+        for (list_index, imported_list) in imported_project.task_lists.iter().enumerate() {
+            let now = Utc::now();
+            let task_list = TaskList {
+                id: TaskListId::new(),
+                project_id,
+                name: imported_list.name.clone(),
+                description: None,
+                color: None,
+                order_index: list_index as i32,
+                is_archived: false,
+                created_at: now,
+                updated_at: now,
+                deleted: false,
+                updated_by: *user_id,
+            };
+            task_list_service::create_task_list(repositories, &project_id, &task_list, user_id)
+                .await?;
+
+            for (task_index, imported_task) in imported_list.tasks.iter().enumerate() {
+                let mut tag_ids = Vec::with_capacity(imported_task.tags.len());
+                for name in &imported_task.tags {
+                    let tag_id =
+                        resolve_tag(repositories, &project_id, &mut tags, name, user_id).await?;
+                    if !tag_ids.contains(&tag_id) {
+                        tag_ids.push(tag_id);
+                    }
+                }
+
+                let now = Utc::now();
+                let task = Task {
+                    id: TaskId::new(),
+                    project_id,
+                    list_id: task_list.id,
+                    title: imported_task.title.clone(),
+                    description: imported_task.description.clone(),
+                    status: imported_task.status.clone(),
+                    workflow_status_id: None,
+                    priority: imported_task.priority,
+                    plan_start_date: imported_task.plan_start_date,
+                    plan_end_date: imported_task.plan_end_date,
+                    do_start_date: None,
+                    do_end_date: None,
+                    is_range_date: Some(imported_task.plan_start_date.is_some()),
+                    recurrence_rule: None,
+                    order_index: task_index as i32,
+                    is_archived: false,
+                    assigned_user_ids: Vec::new(),
+                    tag_ids: tag_ids.clone(),
+                    created_at: now,
+                    updated_at: now,
+                    deleted: false,
+                    updated_by: *user_id,
+                };
+                task_service::create_task(repositories, &project_id, &task, user_id).await?;
+                for tag_id in &tag_ids {
+                    task_tag_service::add_task_tag_relation(
+                        repositories,
+                        &project_id,
+                        &task.id,
+                        tag_id,
+                        user_id,
+                    )
+                    .await?;
+                }
+
+                if let Some(recurrence) = &imported_task.recurrence {
+                    let rule = to_recurrence_rule(recurrence, user_id);
+                    let rule_id = rule.id;
+                    recurrence_service::create_recurrence_rule(
+                        repositories,
+                        &project_id,
+                        rule,
+                        user_id,
+                    )
+                    .await?;
+                    recurrence_service::create_task_recurrence(
+                        repositories,
+                        &project_id,
+                        &task.id,
+                        &rule_id,
+                    )
+                    .await?;
+                }
+
+                for (subtask_index, imported_subtask) in imported_task.subtasks.iter().enumerate() {
+                    let mut subtask_tag_ids = Vec::with_capacity(imported_subtask.tags.len());
+                    for name in &imported_subtask.tags {
+                        let tag_id =
+                            resolve_tag(repositories, &project_id, &mut tags, name, user_id)
+                                .await?;
+                        if !subtask_tag_ids.contains(&tag_id) {
+                            subtask_tag_ids.push(tag_id);
+                        }
+                    }
+
+                    let now = Utc::now();
+                    let subtask = SubTask {
+                        id: SubTaskId::new(),
+                        task_id: task.id,
+                        title: imported_subtask.title.clone(),
+                        description: imported_subtask.description.clone(),
+                        status: imported_subtask.status.clone(),
+                        priority: imported_subtask.priority,
+                        plan_start_date: None,
+                        plan_end_date: imported_subtask.plan_end_date,
+                        do_start_date: None,
+                        do_end_date: None,
+                        is_range_date: None,
+                        recurrence_rule: None,
+                        assigned_user_ids: Vec::new(),
+                        tag_ids: subtask_tag_ids.clone(),
+                        order_index: subtask_index as i32,
+                        completed: imported_subtask.status == TaskStatus::Completed,
+                        created_at: now,
+                        updated_at: now,
+                        deleted: false,
+                        updated_by: *user_id,
+                    };
+                    subtask_service::create_subtask(repositories, &project_id, &subtask, user_id)
+                        .await?;
+                    for tag_id in &subtask_tag_ids {
+                        subtask_tag_service::add_subtask_tag_relation(
+                            repositories,
+                            &project_id,
+                            &subtask.id,
+                            tag_id,
+                            user_id,
+                        )
+                        .await?;
+                    }
+                }
+            }
+        }
+
+        project_ids.push(project_id);
+    }
+
+    Ok(project_ids)
+}
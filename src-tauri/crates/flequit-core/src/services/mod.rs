@@ -1,15 +1,25 @@
 pub mod account_service;
+pub mod calendar_export_service;
+pub mod comment_service;
 pub mod datetime_service;
+pub mod import_service;
 pub mod initialization_service;
 pub mod project_service;
 pub mod recurrence_service;
+pub mod reminder_service;
+pub mod schedule_service;
+pub mod snooze_service;
+pub mod status_transition_service;
 pub mod subtask_assignment_service;
 pub mod subtask_service;
 pub mod subtask_tag_service;
 pub mod tag_bookmark_service;
 pub mod tag_service;
 pub mod task_assignment_service;
+pub mod task_dependency_service;
 pub mod task_list_service;
 pub mod task_service;
 pub mod task_tag_service;
+pub mod time_entry_service;
 pub mod user_service;
+pub mod workflow_status_service;
@@ -1,4 +1,5 @@
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
 use chrono::Utc;
 use flequit_model::models::task_projects::project::{PartialProject, Project};
 use flequit_model::types::id_types::{ProjectId, UserId};
@@ -29,6 +30,11 @@ where
         .save(&new_project, user_id, &now)
         .await?;
 
+    events::publish(
+        DomainEvent::created(EntityKind::Project, new_project.id)
+            .in_project(&new_project.id)
+            .by(user_id),
+    );
     Ok(new_project)
 }
 
@@ -106,12 +112,23 @@ pub async fn update_project<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
+    let before = repositories.projects().find_by_id(project_id).await?;
+
     // パッチによる部分更新を実行
     let now = Utc::now();
     let changed = repositories
         .projects()
         .patch(project_id, patch, user_id, &now)
         .await?;
+
+    if changed && let Some(before) = before {
+        events::publish(
+            DomainEvent::updated(EntityKind::Project, project_id)
+                .in_project(project_id)
+                .with_changes(events::patch_changes(&before, patch))
+                .by(user_id),
+        );
+    }
     Ok(changed)
 }
 
@@ -120,5 +137,6 @@ where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
     repositories.projects().delete(project_id).await?;
+    events::publish(DomainEvent::deleted(EntityKind::Project, project_id).in_project(project_id));
     Ok(())
 }
@@ -4,6 +4,7 @@
 //! ビジネスロジックを処理します。
 
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
 use chrono::Utc;
 use flequit_model::models::task_projects::{
     recurrence_adjustment::RecurrenceAdjustment,
@@ -57,6 +58,11 @@ where
         .await
         .map_err(ServiceError::Repository)?;
 
+    events::publish(
+        DomainEvent::created(EntityKind::RecurrenceRule, rule.id)
+            .in_project(project_id)
+            .by(user_id),
+    );
     Ok(())
 }
 
@@ -107,11 +113,25 @@ pub async fn update_recurrence_rule<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
+    let before = repositories
+        .recurrence_rules()
+        .find_by_id(project_id, rule_id)
+        .await?;
     let now = Utc::now();
-    Ok(repositories
+    let changed = repositories
         .recurrence_rules()
         .patch(project_id, rule_id, patch, user_id, &now)
-        .await?)
+        .await?;
+
+    if changed && let Some(before) = before {
+        events::publish(
+            DomainEvent::updated(EntityKind::RecurrenceRule, rule_id)
+                .in_project(project_id)
+                .with_changes(events::patch_changes(&before, patch))
+                .by(user_id),
+        );
+    }
+    Ok(changed)
 }
 
 /// 繰り返しルールを削除します。
@@ -123,12 +143,25 @@ pub async fn delete_recurrence_rule<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
+    let rule_id = RecurrenceRuleId::from(rule_id.to_string());
+    // 取り消せるよう、削除前の状態を控える
+    let before = repositories
+        .recurrence_rules()
+        .find_by_id(project_id, &rule_id)
+        .await
+        .map_err(ServiceError::Repository)?;
     repositories
         .recurrence_rules()
-        .delete(project_id, &RecurrenceRuleId::from(rule_id.to_string()))
+        .delete(project_id, &rule_id)
         .await
         .map_err(ServiceError::Repository)?;
 
+    let mut event =
+        DomainEvent::deleted(EntityKind::RecurrenceRule, rule_id).in_project(project_id);
+    if let Some(before) = before {
+        event = event.with_snapshot(&before);
+    }
+    events::publish(event);
     Ok(())
 }
 
@@ -259,6 +292,12 @@ where
         .await
         .map_err(ServiceError::Repository)?;
 
+    events::publish(
+        DomainEvent::created(EntityKind::TaskRecurrence, task_id)
+            .in_project(project_id)
+            .related_to(recurrence_rule_id)
+            .by(&user_id),
+    );
     Ok(())
 }
 
@@ -290,12 +329,27 @@ pub async fn delete_task_recurrence<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
+    // 取り消せるよう、削除前の関連付け先を控える
+    let rule_id = repositories
+        .task_recurrences()
+        .find_relations(project_id, task_id)
+        .await
+        .map_err(ServiceError::Repository)?
+        .into_iter()
+        .next()
+        .map(|relation| relation.recurrence_rule_id);
     repositories
         .task_recurrences()
         .remove_all(project_id, task_id)
         .await
         .map_err(ServiceError::Repository)?;
 
+    let mut event =
+        DomainEvent::deleted(EntityKind::TaskRecurrence, task_id).in_project(project_id);
+    if let Some(rule_id) = rule_id {
+        event = event.related_to(rule_id);
+    }
+    events::publish(event);
     Ok(())
 }
 
@@ -323,6 +377,12 @@ where
         .await
         .map_err(ServiceError::Repository)?;
 
+    events::publish(
+        DomainEvent::created(EntityKind::SubTaskRecurrence, subtask_id)
+            .in_project(project_id)
+            .related_to(recurrence_rule_id)
+            .by(&user_id),
+    );
     Ok(())
 }
 
@@ -354,11 +414,26 @@ pub async fn delete_subtask_recurrence<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
+    // 取り消せるよう、削除前の関連付け先を控える
+    let rule_id = repositories
+        .subtask_recurrences()
+        .find_relations(project_id, subtask_id)
+        .await
+        .map_err(ServiceError::Repository)?
+        .into_iter()
+        .next()
+        .map(|relation| relation.recurrence_rule_id);
     repositories
         .subtask_recurrences()
         .remove_all(project_id, subtask_id)
         .await
         .map_err(ServiceError::Repository)?;
 
+    let mut event =
+        DomainEvent::deleted(EntityKind::SubTaskRecurrence, subtask_id).in_project(project_id);
+    if let Some(rule_id) = rule_id {
+        event = event.related_to(rule_id);
+    }
+    events::publish(event);
     Ok(())
 }
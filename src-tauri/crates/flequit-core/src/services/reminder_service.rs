@@ -0,0 +1,649 @@
+//! リマインダーサービス
+//!
+//! タスク・サブタスクへのリマインダーの設定と、通知時刻を迎えたリマインダーの判定を提供する。
+//! 通知そのものはアプリケーション側のスケジューラが行い、このサービスは
+//! 通知すべきリマインダーを選んで通知済みとして記録する。
+
+use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
+use chrono::{DateTime, Duration, Utc};
+use flequit_model::models::task_projects::reminder::{PartialReminder, Reminder};
+use flequit_model::models::task_projects::subtask::SubTask;
+use flequit_model::models::task_projects::task::Task;
+use flequit_model::types::id_types::{ProjectId, ReminderId, SubTaskId, TaskId, UserId};
+use flequit_model::types::task_types::ReminderAnchor;
+use flequit_repository::repositories::base_repository_trait::Repository;
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::service_error::ServiceError;
+use std::collections::HashMap;
+
+/// 通知予定時刻からこの時間以上遅れて通知したものを「見逃し」として扱う
+const MISSED_AFTER_SECONDS: i64 = 60;
+
+/// 通知すべきリマインダー
+#[derive(Debug, Clone)]
+pub struct FiredReminder {
+    pub reminder: Reminder,
+    /// 通知対象（タスクまたはサブタスク）のタイトル
+    pub title: String,
+    /// 本来の通知予定時刻
+    pub scheduled_at: DateTime<Utc>,
+    /// アプリケーションの停止中などで通知予定時刻を過ぎてから通知したかどうか
+    pub missed: bool,
+}
+
+/// 通知時刻を迎えたリマインダーの判定結果
+#[derive(Debug, Clone, Default)]
+pub struct ReminderSweep {
+    pub fired: Vec<FiredReminder>,
+    /// 次に通知時刻を迎えるリマインダーの通知予定時刻（ない場合は`None`）
+    pub next_fire_at: Option<DateTime<Utc>>,
+}
+
+/// 通知対象の予定日時と状態
+#[derive(Debug, Clone)]
+struct ReminderTarget {
+    title: String,
+    plan_start: Option<DateTime<Utc>>,
+    plan_end: Option<DateTime<Utc>>,
+    /// 完了・キャンセル・削除・アーカイブ済みで通知が不要か
+    inactive: bool,
+}
+
+impl ReminderTarget {
+    fn of_task(task: &Task) -> Self {
+        Self {
+            title: task.title.clone(),
+            plan_start: task.plan_start_date,
+            plan_end: task.plan_end_date,
+            inactive: task.deleted || task.is_archived || task.status.is_closed(),
+        }
+    }
+
+    fn of_subtask(subtask: &SubTask) -> Self {
+        Self {
+            title: subtask.title.clone(),
+            plan_start: subtask.plan_start_date,
+            plan_end: subtask.plan_end_date,
+            inactive: subtask.deleted || subtask.status.is_closed(),
+        }
+    }
+}
+
+pub async fn get_reminder<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    reminder_id: &ReminderId,
+) -> Result<Option<Reminder>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    Ok(repositories
+        .reminders()
+        .find_by_id(project_id, reminder_id)
+        .await?)
+}
+
+/// プロジェクト内のリマインダーを取得する
+///
+/// `task_id` を指定した場合はそのタスク（サブタスクへのものを含む）のリマインダーのみ、
+/// `subtask_id` を指定した場合はそのサブタスクのリマインダーのみを返す。
+pub async fn list_reminders<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: Option<&TaskId>,
+    subtask_id: Option<&SubTaskId>,
+) -> Result<Vec<Reminder>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let reminders = repositories.reminders().find_all(project_id).await?;
+    Ok(reminders
+        .into_iter()
+        .filter(|reminder| !reminder.deleted)
+        .filter(|reminder| task_id.is_none_or(|task_id| reminder.task_id == *task_id))
+        .filter(|reminder| {
+            subtask_id.is_none_or(|subtask_id| reminder.subtask_id == Some(*subtask_id))
+        })
+        .collect())
+}
+
+/// リマインダーを追加する
+pub async fn create_reminder<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    reminder: &Reminder,
+    user_id: &UserId,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let now = Utc::now();
+    let mut new_data = reminder.clone();
+    new_data.project_id = *project_id;
+    new_data.last_fired_at = None;
+    new_data.snoozed_until = None;
+    new_data.created_at = now;
+    new_data.updated_at = now;
+    new_data.deleted = false;
+    new_data.updated_by = *user_id;
+    normalize_timing(&mut new_data)?;
+    validate_target(repositories, &new_data).await?;
+
+    repositories
+        .reminders()
+        .save(project_id, &new_data, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::created(EntityKind::Reminder, new_data.id)
+            .in_project(project_id)
+            .related_to(new_data.task_id)
+            .by(user_id),
+    );
+    Ok(())
+}
+
+/// リマインダーの通知日時や繰り返しを更新する
+///
+/// 通知日時に関わる項目を変更した場合は、新しい設定で改めて通知されるよう通知済みの記録とスヌーズを消す。
+pub async fn update_reminder<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    reminder_id: &ReminderId,
+    patch: &PartialReminder,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(before) = repositories
+        .reminders()
+        .find_by_id(project_id, reminder_id)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    let mut reminder = before.clone();
+    if let Some(anchor) = patch.anchor {
+        reminder.anchor = anchor;
+    }
+    if let Some(remind_at) = patch.remind_at {
+        reminder.remind_at = remind_at;
+    }
+    if let Some(offset_minutes) = patch.offset_minutes {
+        reminder.offset_minutes = offset_minutes;
+    }
+    if let Some(repeat_interval_minutes) = patch.repeat_interval_minutes {
+        reminder.repeat_interval_minutes = repeat_interval_minutes;
+    }
+    if let Some(repeat_count) = patch.repeat_count {
+        reminder.repeat_count = repeat_count;
+    }
+    normalize_timing(&mut reminder)?;
+    if reminder.anchor != before.anchor
+        || reminder.remind_at != before.remind_at
+        || reminder.offset_minutes != before.offset_minutes
+        || reminder.repeat_interval_minutes != before.repeat_interval_minutes
+        || reminder.repeat_count != before.repeat_count
+    {
+        reminder.last_fired_at = None;
+        reminder.snoozed_until = None;
+    }
+
+    let now = Utc::now();
+    reminder.updated_at = now;
+    reminder.updated_by = *user_id;
+    repositories
+        .reminders()
+        .save(project_id, &reminder, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::updated(EntityKind::Reminder, reminder_id)
+            .in_project(project_id)
+            .related_to(reminder.task_id)
+            .with_changes(events::field_changes(&before, &reminder))
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+/// リマインダーを削除する
+pub async fn delete_reminder<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    reminder_id: &ReminderId,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    // 取り消せるよう、削除前の状態を控える
+    let Some(before) = repositories
+        .reminders()
+        .find_by_id(project_id, reminder_id)
+        .await?
+    else {
+        return Ok(false);
+    };
+    repositories
+        .reminders()
+        .delete(project_id, reminder_id)
+        .await?;
+
+    events::publish(
+        DomainEvent::deleted(EntityKind::Reminder, reminder_id)
+            .in_project(project_id)
+            .related_to(before.task_id)
+            .with_snapshot(&before)
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+/// 繰り返しタスクの前の回のリマインダーを次の回のタスクへ引き継ぐ
+///
+/// 予定日時からの相対指定のリマインダーはそのまま、日時指定のリマインダーは
+/// 前の回から次の回へ予定日時が進んだ分だけ通知日時をずらして複製する。
+/// 予定日時が進んでいない場合、日時指定のリマインダーは引き継がない。
+pub async fn carry_over_task_reminders<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    from_task_id: &TaskId,
+    to_task_id: &TaskId,
+    user_id: &UserId,
+) -> Result<Vec<Reminder>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let from = find_task(repositories, project_id, from_task_id).await?;
+    let to = find_task(repositories, project_id, to_task_id).await?;
+    let shift = occurrence_shift(
+        (from.plan_start_date, from.plan_end_date),
+        (to.plan_start_date, to.plan_end_date),
+    );
+
+    let sources: Vec<Reminder> = list_reminders(repositories, project_id, Some(from_task_id), None)
+        .await?
+        .into_iter()
+        .filter(|reminder| reminder.subtask_id.is_none())
+        .collect();
+    let mut created = Vec::new();
+    for source in &sources {
+        if let Some(reminder) = carried_over(source, *to_task_id, None, shift) {
+            create_reminder(repositories, project_id, &reminder, user_id).await?;
+            created.push(reminder);
+        }
+    }
+    Ok(created)
+}
+
+/// 繰り返しサブタスクの前の回のリマインダーを次の回のサブタスクへ引き継ぐ
+pub async fn carry_over_subtask_reminders<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    from_subtask_id: &SubTaskId,
+    to_subtask_id: &SubTaskId,
+    user_id: &UserId,
+) -> Result<Vec<Reminder>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let from = find_subtask(repositories, project_id, from_subtask_id).await?;
+    let to = find_subtask(repositories, project_id, to_subtask_id).await?;
+    let shift = occurrence_shift(
+        (from.plan_start_date, from.plan_end_date),
+        (to.plan_start_date, to.plan_end_date),
+    );
+
+    let sources = list_reminders(repositories, project_id, None, Some(from_subtask_id)).await?;
+    let mut created = Vec::new();
+    for source in &sources {
+        if let Some(reminder) = carried_over(source, to.task_id, Some(to.id), shift) {
+            create_reminder(repositories, project_id, &reminder, user_id).await?;
+            created.push(reminder);
+        }
+    }
+    Ok(created)
+}
+
+/// 通知時刻を迎えたリマインダーを全プロジェクトから選び、通知済みとして記録する
+///
+/// アプリケーションの停止中に通知時刻を過ぎたリマインダーも、起動後の最初の呼び出しで
+/// 見逃しとしてまとめて通知する。通知済みの記録はユーザーの操作ではないため、
+/// 変更履歴やドメインイベントには残さない。
+pub async fn fire_due_reminders<R>(
+    repositories: &R,
+    now: DateTime<Utc>,
+) -> Result<ReminderSweep, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let mut sweep = ReminderSweep::default();
+    for project in repositories.projects().find_all().await? {
+        if project.deleted {
+            continue;
+        }
+        let reminders = repositories.reminders().find_all(&project.id).await?;
+        if reminders.iter().all(|reminder| reminder.deleted) {
+            continue;
+        }
+        let targets = load_targets(repositories, &project.id).await?;
+
+        for mut reminder in reminders {
+            if reminder.deleted {
+                continue;
+            }
+            let key = (reminder.task_id, reminder.subtask_id);
+            let Some(target) = targets.get(&key).filter(|target| !target.inactive) else {
+                continue;
+            };
+
+            if let Some(scheduled_at) = reminder.due_at(target.plan_start, target.plan_end, now) {
+                reminder.mark_fired(target.plan_start, target.plan_end, now);
+                repositories
+                    .reminders()
+                    .save(&project.id, &reminder, &reminder.updated_by, &now)
+                    .await?;
+                sweep.fired.push(FiredReminder {
+                    reminder: reminder.clone(),
+                    title: target.title.clone(),
+                    scheduled_at,
+                    missed: now - scheduled_at >= Duration::seconds(MISSED_AFTER_SECONDS),
+                });
+            }
+
+            if let Some(next) = reminder.next_fire_at(target.plan_start, target.plan_end) {
+                sweep.next_fire_at = Some(sweep.next_fire_at.map_or(next, |n| n.min(next)));
+            }
+        }
+    }
+    Ok(sweep)
+}
+
+/// プロジェクト内のタスク・サブタスクを通知対象として読み込む
+async fn load_targets<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+) -> Result<HashMap<(TaskId, Option<SubTaskId>), ReminderTarget>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let tasks = repositories.tasks().find_all(project_id).await?;
+    let subtasks = repositories.sub_tasks().find_all(project_id).await?;
+
+    let mut targets = HashMap::new();
+    for task in &tasks {
+        targets.insert((task.id, None), ReminderTarget::of_task(task));
+    }
+    for subtask in &subtasks {
+        let mut target = ReminderTarget::of_subtask(subtask);
+        // 親タスクが通知不要になった場合はサブタスクへの通知も行わない
+        target.inactive |= targets
+            .get(&(subtask.task_id, None))
+            .is_none_or(|parent| parent.inactive);
+        targets.insert((subtask.task_id, Some(subtask.id)), target);
+    }
+    Ok(targets)
+}
+
+/// 前の回から次の回へ予定日時が進んだ量（予定終了日時を優先する）
+fn occurrence_shift(
+    (from_start, from_end): (Option<DateTime<Utc>>, Option<DateTime<Utc>>),
+    (to_start, to_end): (Option<DateTime<Utc>>, Option<DateTime<Utc>>),
+) -> Option<Duration> {
+    match (from_end, to_end) {
+        (Some(from), Some(to)) => Some(to - from),
+        _ => match (from_start, to_start) {
+            (Some(from), Some(to)) => Some(to - from),
+            _ => None,
+        },
+    }
+}
+
+/// 次の回へ引き継ぐリマインダーを作る
+fn carried_over(
+    source: &Reminder,
+    task_id: TaskId,
+    subtask_id: Option<SubTaskId>,
+    shift: Option<Duration>,
+) -> Option<Reminder> {
+    let remind_at = match source.anchor {
+        ReminderAnchor::Absolute => {
+            let shift = shift.filter(|shift| *shift > Duration::zero())?;
+            Some(source.remind_at? + shift)
+        }
+        ReminderAnchor::PlanStart | ReminderAnchor::PlanEnd => None,
+    };
+    Some(Reminder {
+        id: ReminderId::new(),
+        task_id,
+        subtask_id,
+        remind_at,
+        last_fired_at: None,
+        snoozed_until: None,
+        ..source.clone()
+    })
+}
+
+/// 通知日時の指定を検証し、基準に合わない項目を取り除く
+fn normalize_timing(reminder: &mut Reminder) -> Result<(), ServiceError> {
+    match reminder.anchor {
+        ReminderAnchor::Absolute => {
+            if reminder.remind_at.is_none() {
+                return Err(ServiceError::ValidationError(
+                    "An absolute reminder requires a reminder time".to_string(),
+                ));
+            }
+            reminder.offset_minutes = 0;
+        }
+        ReminderAnchor::PlanStart | ReminderAnchor::PlanEnd => reminder.remind_at = None,
+    }
+    if reminder
+        .repeat_interval_minutes
+        .is_some_and(|minutes| minutes <= 0)
+    {
+        return Err(ServiceError::ValidationError(
+            "The repeat interval must be at least one minute".to_string(),
+        ));
+    }
+    if reminder.repeat_count.is_some_and(|count| count < 0) {
+        return Err(ServiceError::ValidationError(
+            "The repeat count must not be negative".to_string(),
+        ));
+    }
+    if reminder.repeat_interval_minutes.is_none() {
+        reminder.repeat_count = None;
+    }
+    Ok(())
+}
+
+async fn find_task<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+) -> Result<Task, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    repositories
+        .tasks()
+        .find_by_id(project_id, task_id)
+        .await?
+        .ok_or_else(|| ServiceError::NotFound(format!("Task not found: {}", task_id)))
+}
+
+async fn find_subtask<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    subtask_id: &SubTaskId,
+) -> Result<SubTask, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    repositories
+        .sub_tasks()
+        .find_by_id(project_id, subtask_id)
+        .await?
+        .ok_or_else(|| ServiceError::NotFound(format!("Subtask not found: {}", subtask_id)))
+}
+
+/// リマインダーの対象タスク（・サブタスク）がプロジェクトに存在することを確認する
+async fn validate_target<R>(repositories: &R, reminder: &Reminder) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    find_task(repositories, &reminder.project_id, &reminder.task_id).await?;
+    if let Some(subtask_id) = &reminder.subtask_id {
+        let subtask = find_subtask(repositories, &reminder.project_id, subtask_id).await?;
+        if subtask.task_id != reminder.task_id {
+            return Err(ServiceError::NotFound(format!(
+                "Subtask {} not found in task {}",
+                subtask_id, reminder.task_id
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 5, hour, minute, 0).unwrap()
+    }
+
+    fn reminder(anchor: ReminderAnchor, offset_minutes: i64) -> Reminder {
+        let user_id = UserId::new();
+        Reminder {
+            id: ReminderId::new(),
+            project_id: ProjectId::new(),
+            task_id: TaskId::new(),
+            subtask_id: None,
+            anchor,
+            remind_at: None,
+            offset_minutes,
+            repeat_interval_minutes: None,
+            repeat_count: None,
+            last_fired_at: None,
+            snoozed_until: None,
+            created_at: at(0, 0),
+            updated_at: at(0, 0),
+            deleted: false,
+            updated_by: user_id,
+        }
+    }
+
+    #[test]
+    fn test_relative_reminder_follows_plan_dates() {
+        let mut before_due = reminder(ReminderAnchor::PlanEnd, -30);
+        assert_eq!(before_due.due_at(None, Some(at(10, 0)), at(9, 29)), None);
+        assert_eq!(
+            before_due.due_at(None, Some(at(10, 0)), at(9, 30)),
+            Some(at(9, 30))
+        );
+
+        // 通知済みの回は再び通知しない
+        before_due.last_fired_at = Some(at(9, 30));
+        assert_eq!(before_due.due_at(None, Some(at(10, 0)), at(12, 0)), None);
+
+        // 繰り返しタスクの次の回へ予定日時が進むと再び通知する
+        let next_day = Some(at(10, 0) + Duration::days(1));
+        assert_eq!(
+            before_due.next_fire_at(None, next_day),
+            Some(at(9, 30) + Duration::days(1))
+        );
+    }
+
+    #[test]
+    fn test_missed_repeats_are_coalesced() {
+        let mut repeating = reminder(ReminderAnchor::PlanStart, 0);
+        repeating.repeat_interval_minutes = Some(10);
+        repeating.repeat_count = Some(3);
+
+        // 9:00, 9:10, 9:20, 9:30 の4回のうち、停止中に過ぎた回は最新の1回にまとめる
+        assert_eq!(
+            repeating.due_at(Some(at(9, 0)), None, at(9, 25)),
+            Some(at(9, 20))
+        );
+        repeating.last_fired_at = Some(at(9, 20));
+        assert_eq!(
+            repeating.next_fire_at(Some(at(9, 0)), None),
+            Some(at(9, 30))
+        );
+
+        // 繰り返し回数を使い切った後は通知しない
+        repeating.last_fired_at = Some(at(9, 30));
+        assert_eq!(repeating.next_fire_at(Some(at(9, 0)), None), None);
+        assert_eq!(repeating.due_at(Some(at(9, 0)), None, at(23, 0)), None);
+    }
+
+    #[test]
+    fn test_snoozed_reminder_fires_again_at_snooze_time() {
+        let mut before_due = reminder(ReminderAnchor::PlanEnd, -30);
+        before_due.snooze(None, Some(at(10, 0)), at(9, 45));
+
+        // 見送った通知予定は通知しない
+        assert_eq!(before_due.due_at(None, Some(at(10, 0)), at(9, 40)), None);
+        assert_eq!(
+            before_due.next_fire_at(None, Some(at(10, 0))),
+            Some(at(9, 45))
+        );
+        assert_eq!(
+            before_due.due_at(None, Some(at(10, 0)), at(9, 50)),
+            Some(at(9, 45))
+        );
+
+        // スヌーズ先で通知すると以降は通知しない
+        before_due.mark_fired(None, Some(at(10, 0)), at(9, 50));
+        assert_eq!(before_due.snoozed_until, None);
+        assert_eq!(before_due.next_fire_at(None, Some(at(10, 0))), None);
+    }
+
+    #[test]
+    fn test_carry_over_shifts_absolute_reminders() {
+        let mut absolute = reminder(ReminderAnchor::Absolute, 0);
+        absolute.remind_at = Some(at(8, 0));
+        absolute.last_fired_at = Some(at(8, 0));
+        let shift = occurrence_shift(
+            (None, Some(at(10, 0))),
+            (None, Some(at(10, 0) + Duration::days(7))),
+        );
+
+        let next = carried_over(&absolute, TaskId::new(), None, shift).unwrap();
+        assert_eq!(next.remind_at, Some(at(8, 0) + Duration::days(7)));
+        assert_eq!(next.last_fired_at, None);
+        assert_ne!(next.id, absolute.id);
+
+        // 予定日時が進まない場合は日時指定のリマインダーを引き継がない
+        assert!(carried_over(&absolute, TaskId::new(), None, None).is_none());
+
+        let relative = reminder(ReminderAnchor::PlanEnd, -60);
+        let next = carried_over(&relative, TaskId::new(), None, None).unwrap();
+        assert_eq!(next.remind_at, None);
+        assert_eq!(next.offset_minutes, -60);
+    }
+
+    #[test]
+    fn test_normalize_timing_rejects_invalid_settings() {
+        let mut absolute = reminder(ReminderAnchor::Absolute, 15);
+        assert!(normalize_timing(&mut absolute).is_err());
+        absolute.remind_at = Some(at(9, 0));
+        normalize_timing(&mut absolute).unwrap();
+        assert_eq!(absolute.offset_minutes, 0);
+
+        let mut relative = reminder(ReminderAnchor::PlanStart, -10);
+        relative.remind_at = Some(at(9, 0));
+        relative.repeat_count = Some(2);
+        normalize_timing(&mut relative).unwrap();
+        assert_eq!(relative.remind_at, None);
+        assert_eq!(relative.repeat_count, None);
+
+        relative.repeat_interval_minutes = Some(0);
+        assert!(normalize_timing(&mut relative).is_err());
+    }
+}
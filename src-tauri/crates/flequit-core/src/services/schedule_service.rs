@@ -0,0 +1,395 @@
+//! スケジュール計算サービス
+//!
+//! タスクの依存関係と予定日時から、クリティカルパス法（CPM）で
+//! 最早・最遅の開始／終了日時と余裕時間を計算し、クリティカルパスを求める。
+//!
+//! - 所要時間は予定開始日時から予定終了日時までとし、どちらかが未設定なら0（マイルストーン）とみなす
+//! - 予定開始日時（未設定なら予定終了日時）は「この日時より前には始めない」制約として扱う
+//! - 予定開始日時が先行タスクの最早終了日時より前になっている場合は食い違いとして報告する
+//!
+//! タスクリスト単位の計算でも、リストをまたぐ依存関係を反映するためにプロジェクト全体で計算する。
+
+use crate::InfrastructureRepositoriesTrait;
+use chrono::{DateTime, Duration, Utc};
+use flequit_model::models::task_projects::task::Task;
+use flequit_model::models::task_projects::task_dependency::TaskDependency;
+use flequit_model::types::id_types::{ProjectId, TaskId, TaskListId};
+use flequit_repository::repositories::project_relation_repository_trait::ProjectRelationRepository;
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::service_error::ServiceError;
+use std::collections::{HashMap, VecDeque};
+
+/// タスクごとの計算結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledTask {
+    pub task_id: TaskId,
+    pub list_id: TaskListId,
+    /// 所要時間
+    pub duration: Duration,
+    pub earliest_start: DateTime<Utc>,
+    pub earliest_finish: DateTime<Utc>,
+    pub latest_start: DateTime<Utc>,
+    pub latest_finish: DateTime<Utc>,
+    /// 全体の終了を遅らせずに遅らせられる時間（最遅開始 − 最早開始）
+    pub slack: Duration,
+    /// 余裕時間がなく、遅れると全体の終了が遅れるタスク
+    pub is_critical: bool,
+}
+
+/// 予定日時と依存関係の食い違い
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleConflict {
+    /// 予定開始日時が早すぎる（後続の）タスク
+    pub task_id: TaskId,
+    /// まだ終わっていない先行タスク
+    pub depends_on_task_id: TaskId,
+    pub plan_start_date: DateTime<Utc>,
+    /// 先行タスクの最早終了日時（これより前には開始できない）
+    pub earliest_start: DateTime<Utc>,
+}
+
+/// スケジュールの計算結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    /// 全体の開始日時
+    pub start: DateTime<Utc>,
+    /// 全体の終了日時（最も遅い最早終了日時）
+    pub finish: DateTime<Utc>,
+    /// 最早開始日時の順に並べたタスク
+    pub tasks: Vec<ScheduledTask>,
+    /// 余裕時間のないタスクを開始順に並べたもの
+    pub critical_path: Vec<TaskId>,
+    pub conflicts: Vec<ScheduleConflict>,
+}
+
+/// 予定日時から求めたタスクの所要時間
+pub fn planned_duration(task: &Task) -> Duration {
+    match (task.plan_start_date, task.plan_end_date) {
+        (Some(start), Some(end)) if end > start => end - start,
+        _ => Duration::zero(),
+    }
+}
+
+/// タスクを開始できる最も早い日時の制約（予定開始日時、未設定なら予定終了日時）
+fn start_constraint(task: &Task) -> Option<DateTime<Utc>> {
+    task.plan_start_date.or(task.plan_end_date)
+}
+
+/// 削除されていないタスクと依存関係からスケジュールを計算する
+///
+/// 制約のないタスクは全体の開始日時（最も早い制約、制約がなければ`now`）から始まる。
+/// 依存関係が循環している場合は計算できないため`ValidationError`を返す。
+pub fn compute(
+    tasks: &[Task],
+    dependencies: &[TaskDependency],
+    now: DateTime<Utc>,
+) -> Result<Schedule, ServiceError> {
+    let tasks: Vec<&Task> = tasks.iter().filter(|task| !task.deleted).collect();
+    let index: HashMap<TaskId, usize> = tasks
+        .iter()
+        .enumerate()
+        .map(|(i, task)| (task.id, i))
+        .collect();
+
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); tasks.len()];
+    let mut successors: Vec<Vec<usize>> = vec![Vec::new(); tasks.len()];
+    for dependency in dependencies.iter().filter(|dependency| !dependency.deleted) {
+        let (Some(&task), Some(&blocker)) = (
+            index.get(&dependency.task_id),
+            index.get(&dependency.depends_on_task_id),
+        ) else {
+            continue;
+        };
+        if task != blocker && !predecessors[task].contains(&blocker) {
+            predecessors[task].push(blocker);
+            successors[blocker].push(task);
+        }
+    }
+
+    let order = topological_order(&predecessors, &successors).ok_or_else(|| {
+        ServiceError::ValidationError("Task dependencies contain a cycle".to_string())
+    })?;
+
+    let durations: Vec<Duration> = tasks.iter().map(|task| planned_duration(task)).collect();
+    let start = tasks
+        .iter()
+        .filter_map(|task| start_constraint(task))
+        .min()
+        .unwrap_or(now);
+
+    // 前進計算（最早開始・最早終了）
+    let mut earliest_start = vec![start; tasks.len()];
+    let mut earliest_finish = vec![start; tasks.len()];
+    let mut conflicts = Vec::new();
+    for &i in &order {
+        let constraint = start_constraint(tasks[i]);
+        let ready = predecessors[i].iter().map(|&p| earliest_finish[p]).max();
+        earliest_start[i] = match (ready, constraint) {
+            (Some(ready), Some(constraint)) => ready.max(constraint),
+            (Some(ready), None) => ready,
+            (None, Some(constraint)) => constraint,
+            (None, None) => start,
+        };
+        earliest_finish[i] = earliest_start[i] + durations[i];
+
+        if let Some(plan_start_date) = tasks[i].plan_start_date {
+            for &p in &predecessors[i] {
+                if plan_start_date < earliest_finish[p] {
+                    conflicts.push(ScheduleConflict {
+                        task_id: tasks[i].id,
+                        depends_on_task_id: tasks[p].id,
+                        plan_start_date,
+                        earliest_start: earliest_finish[p],
+                    });
+                }
+            }
+        }
+    }
+    let finish = earliest_finish.iter().copied().max().unwrap_or(start);
+
+    // 後退計算（最遅開始・最遅終了）
+    let mut latest_start = vec![finish; tasks.len()];
+    let mut latest_finish = vec![finish; tasks.len()];
+    for &i in order.iter().rev() {
+        latest_finish[i] = successors[i]
+            .iter()
+            .map(|&s| latest_start[s])
+            .min()
+            .unwrap_or(finish);
+        latest_start[i] = latest_finish[i] - durations[i];
+    }
+
+    let mut scheduled: Vec<ScheduledTask> = order
+        .iter()
+        .map(|&i| {
+            let slack = latest_start[i] - earliest_start[i];
+            ScheduledTask {
+                task_id: tasks[i].id,
+                list_id: tasks[i].list_id,
+                duration: durations[i],
+                earliest_start: earliest_start[i],
+                earliest_finish: earliest_finish[i],
+                latest_start: latest_start[i],
+                latest_finish: latest_finish[i],
+                slack,
+                is_critical: slack <= Duration::zero(),
+            }
+        })
+        .collect();
+    // 依存関係の順序を保ったまま開始順に並べる
+    scheduled.sort_by_key(|task| task.earliest_start);
+    let critical_path = scheduled
+        .iter()
+        .filter(|task| task.is_critical)
+        .map(|task| task.task_id)
+        .collect();
+
+    Ok(Schedule {
+        start,
+        finish,
+        tasks: scheduled,
+        critical_path,
+        conflicts,
+    })
+}
+
+/// 依存関係の順序（先行タスクが先）に並べる。循環している場合は`None`
+fn topological_order(predecessors: &[Vec<usize>], successors: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let mut remaining: Vec<usize> = predecessors.iter().map(Vec::len).collect();
+    let mut queue: VecDeque<usize> = (0..remaining.len())
+        .filter(|&i| remaining[i] == 0)
+        .collect();
+    let mut order = Vec::with_capacity(remaining.len());
+    while let Some(i) = queue.pop_front() {
+        order.push(i);
+        for &s in &successors[i] {
+            remaining[s] -= 1;
+            if remaining[s] == 0 {
+                queue.push_back(s);
+            }
+        }
+    }
+    (order.len() == remaining.len()).then_some(order)
+}
+
+/// プロジェクト（`list_id`を指定した場合はそのタスクリスト）のスケジュールを計算する
+pub async fn compute_schedule<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    list_id: Option<&TaskListId>,
+) -> Result<Schedule, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let tasks = repositories.tasks().find_all(project_id).await?;
+    let dependencies = repositories
+        .task_dependencies()
+        .find_all(project_id)
+        .await?;
+    let mut schedule = compute(&tasks, &dependencies, Utc::now())?;
+
+    if let Some(list_id) = list_id {
+        schedule.tasks.retain(|task| task.list_id == *list_id);
+        schedule.critical_path = schedule
+            .tasks
+            .iter()
+            .filter(|task| task.is_critical)
+            .map(|task| task.task_id)
+            .collect();
+        let in_list = |task_id: &TaskId| schedule.tasks.iter().any(|task| task.task_id == *task_id);
+        let conflicts = std::mem::take(&mut schedule.conflicts);
+        schedule.conflicts = conflicts
+            .into_iter()
+            .filter(|conflict| in_list(&conflict.task_id))
+            .collect();
+    }
+    Ok(schedule)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use flequit_model::types::id_types::UserId;
+    use flequit_model::types::task_types::TaskStatus;
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, day, 0, 0, 0).unwrap()
+    }
+
+    fn task(plan: Option<(u32, u32)>) -> Task {
+        let now = Utc::now();
+        Task {
+            id: TaskId::new(),
+            project_id: ProjectId::new(),
+            list_id: TaskListId::new(),
+            title: "task".to_string(),
+            description: None,
+            status: TaskStatus::NotStarted,
+            workflow_status_id: None,
+            priority: 0,
+            plan_start_date: plan.map(|(start, _)| at(start)),
+            plan_end_date: plan.map(|(_, end)| at(end)),
+            do_start_date: None,
+            do_end_date: None,
+            is_range_date: None,
+            recurrence_rule: None,
+            order_index: 0,
+            is_archived: false,
+            assigned_user_ids: vec![],
+            tag_ids: vec![],
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        }
+    }
+
+    fn dependency(task: &Task, depends_on: &Task) -> TaskDependency {
+        let now = Utc::now();
+        TaskDependency {
+            task_id: task.id,
+            depends_on_task_id: depends_on.id,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        }
+    }
+
+    fn find(schedule: &Schedule, task: &Task) -> ScheduledTask {
+        schedule
+            .tasks
+            .iter()
+            .find(|scheduled| scheduled.task_id == task.id)
+            .cloned()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_compute_finds_critical_path_and_slack() {
+        // a(1日) → b(3日) → d(1日)、a → c(1日) → d
+        let a = task(Some((1, 2)));
+        let b = task(Some((2, 5)));
+        let c = task(Some((2, 3)));
+        let d = task(Some((5, 6)));
+        let dependencies = vec![
+            dependency(&b, &a),
+            dependency(&c, &a),
+            dependency(&d, &b),
+            dependency(&d, &c),
+        ];
+        let tasks = vec![d.clone(), c.clone(), b.clone(), a.clone()];
+
+        let schedule = compute(&tasks, &dependencies, at(1)).unwrap();
+
+        assert_eq!(schedule.start, at(1));
+        assert_eq!(schedule.finish, at(6));
+        assert_eq!(schedule.critical_path, vec![a.id, b.id, d.id]);
+        assert!(schedule.conflicts.is_empty());
+
+        let c = find(&schedule, &c);
+        assert_eq!(c.earliest_start, at(2));
+        assert_eq!(c.latest_start, at(4));
+        assert_eq!(c.slack, Duration::days(2));
+        assert!(!c.is_critical);
+    }
+
+    #[test]
+    fn test_compute_reports_plan_dates_violating_dependencies() {
+        let a = task(Some((1, 4)));
+        // a の終了前に始まる予定になっている
+        let b = task(Some((2, 3)));
+        let schedule = compute(&[a.clone(), b.clone()], &[dependency(&b, &a)], at(1)).unwrap();
+
+        assert_eq!(
+            schedule.conflicts,
+            vec![ScheduleConflict {
+                task_id: b.id,
+                depends_on_task_id: a.id,
+                plan_start_date: at(2),
+                earliest_start: at(4),
+            }]
+        );
+        // 依存関係を優先して後ろへずらす
+        let b = find(&schedule, &b);
+        assert_eq!(b.earliest_start, at(4));
+        assert_eq!(b.earliest_finish, at(5));
+    }
+
+    #[test]
+    fn test_compute_treats_unplanned_tasks_as_milestones() {
+        let a = task(None);
+        let b = task(Some((3, 4)));
+        let c = task(None);
+        let schedule = compute(
+            &[a.clone(), b.clone(), c.clone()],
+            &[dependency(&a, &b)],
+            at(1),
+        )
+        .unwrap();
+        assert_eq!(schedule.start, at(3));
+
+        // 先行タスクの終了直後に置く
+        let a = find(&schedule, &a);
+        assert_eq!(a.duration, Duration::zero());
+        assert_eq!(a.earliest_start, at(4));
+        // 制約も依存関係もなければ全体の開始日時に置く
+        assert_eq!(find(&schedule, &c).earliest_start, at(3));
+
+        let empty = compute(&[], &[], at(1)).unwrap();
+        assert_eq!(empty.start, at(1));
+        assert_eq!(empty.finish, at(1));
+    }
+
+    #[test]
+    fn test_compute_rejects_cycles() {
+        let a = task(Some((1, 2)));
+        let b = task(Some((2, 3)));
+        let dependencies = vec![dependency(&a, &b), dependency(&b, &a)];
+        assert!(matches!(
+            compute(&[a, b], &dependencies, at(1)),
+            Err(ServiceError::ValidationError(_))
+        ));
+    }
+}
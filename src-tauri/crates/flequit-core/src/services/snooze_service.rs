@@ -0,0 +1,674 @@
+//! スヌーズサービス
+//!
+//! タスク・サブタスクの予定日時やリマインダーを、プリセット・任意の時間・
+//! 「明日の朝」のいずれかだけ先送りする。先送りした内容は `Snooze` として記録し、
+//! スヌーズ前の予定日時を分析に使えるよう残す。
+//!
+//! タスク・サブタスクは最後のスヌーズの先送り先の日時まで「先送り中」となり、
+//! 検索条件 `is_deferred` でスマートリストから除外できる。
+
+use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
+use crate::services::{subtask_service, task_service};
+use chrono::{DateTime, Duration, FixedOffset, NaiveTime, TimeZone, Utc};
+use flequit_model::models::task_projects::snooze::Snooze;
+use flequit_model::models::task_projects::subtask::{PartialSubTask, SubTask};
+use flequit_model::models::task_projects::task::{PartialTask, Task};
+use flequit_model::types::id_types::{ProjectId, ReminderId, SnoozeId, SubTaskId, TaskId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_settings::models::time_label::TimeLabel;
+use flequit_types::errors::service_error::ServiceError;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// 時間ラベルが1つもない場合の「朝」の時刻
+const DEFAULT_MORNING: (u32, u32) = (9, 0);
+
+/// スヌーズのプリセット
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnoozePreset {
+    FifteenMinutes,
+    OneHour,
+    ThreeHours,
+    OneDay,
+    OneWeek,
+}
+
+impl SnoozePreset {
+    /// 先送りする時間（分）
+    pub fn minutes(self) -> i64 {
+        match self {
+            SnoozePreset::FifteenMinutes => 15,
+            SnoozePreset::OneHour => 60,
+            SnoozePreset::ThreeHours => 3 * 60,
+            SnoozePreset::OneDay => 24 * 60,
+            SnoozePreset::OneWeek => 7 * 24 * 60,
+        }
+    }
+}
+
+/// どれだけ先送りするか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SnoozeOption {
+    /// プリセットの時間だけ先送りする
+    Preset { preset: SnoozePreset },
+    /// 任意の時間（分）だけ先送りする
+    Custom { minutes: i64 },
+    /// 翌日の朝（時間ラベルのうち最も早い時刻）まで先送りする
+    TomorrowMorning,
+}
+
+/// スヌーズ先の日時を決めるための現在時刻と利用者の暦
+#[derive(Debug, Clone, Copy)]
+pub struct SnoozeClock {
+    pub now: DateTime<Utc>,
+    /// 利用者のタイムゾーンのUTCからのずれ
+    pub offset: FixedOffset,
+    /// 「朝」の時刻
+    pub morning: NaiveTime,
+}
+
+impl SnoozeClock {
+    /// 時間ラベルの設定から「朝」の時刻を決める
+    ///
+    /// 時間ラベルのうち最も早い時刻を朝とし、有効な時刻のラベルがなければ9:00とする。
+    pub fn new(now: DateTime<Utc>, offset: FixedOffset, time_labels: &[TimeLabel]) -> Self {
+        let morning = time_labels
+            .iter()
+            .filter_map(|label| NaiveTime::parse_from_str(label.time.trim(), "%H:%M").ok())
+            .min()
+            .unwrap_or_else(|| {
+                NaiveTime::from_hms_opt(DEFAULT_MORNING.0, DEFAULT_MORNING.1, 0).unwrap()
+            });
+        Self {
+            now,
+            offset,
+            morning,
+        }
+    }
+
+    /// 翌日の朝の日時
+    fn tomorrow_morning(&self) -> DateTime<Utc> {
+        let today = self.now.with_timezone(&self.offset).date_naive();
+        let local = (today + Duration::days(1)).and_time(self.morning);
+        self.offset
+            .from_local_datetime(&local)
+            .single()
+            .map(|at| at.with_timezone(&Utc))
+            .unwrap_or(self.now + Duration::days(1))
+    }
+}
+
+/// スヌーズ先の日時を決める
+pub fn resolve_until(
+    option: &SnoozeOption,
+    clock: &SnoozeClock,
+) -> Result<DateTime<Utc>, ServiceError> {
+    match option {
+        SnoozeOption::Preset { preset } => Ok(clock.now + Duration::minutes(preset.minutes())),
+        SnoozeOption::Custom { minutes } => {
+            if *minutes <= 0 {
+                return Err(ServiceError::ValidationError(
+                    "The snooze duration must be at least one minute".to_string(),
+                ));
+            }
+            Duration::try_minutes(*minutes)
+                .and_then(|amount| clock.now.checked_add_signed(amount))
+                .ok_or_else(|| {
+                    ServiceError::ValidationError("The snooze duration is too long".to_string())
+                })
+        }
+        SnoozeOption::TomorrowMorning => Ok(clock.tomorrow_morning()),
+    }
+}
+
+/// 予定日時をずらす量
+///
+/// プリセット・任意の時間はその時間だけずらす。「明日の朝」は予定開始日時
+/// （なければ予定終了日時）がスヌーズ先の日時になるようずらし、既にそれより後なら動かさない。
+fn plan_shift(
+    option: &SnoozeOption,
+    clock: &SnoozeClock,
+    until: DateTime<Utc>,
+    plan_start: Option<DateTime<Utc>>,
+    plan_end: Option<DateTime<Utc>>,
+) -> Duration {
+    match option {
+        SnoozeOption::Preset { .. } | SnoozeOption::Custom { .. } => until - clock.now,
+        SnoozeOption::TomorrowMorning => plan_start
+            .or(plan_end)
+            .filter(|reference| *reference < until)
+            .map_or(Duration::zero(), |reference| until - reference),
+    }
+}
+
+/// スヌーズ前の予定日時を記録したスヌーズを作る
+fn new_snooze(
+    project_id: &ProjectId,
+    task_id: TaskId,
+    subtask_id: Option<SubTaskId>,
+    (plan_start, plan_end): (Option<DateTime<Utc>>, Option<DateTime<Utc>>),
+    until: DateTime<Utc>,
+    clock: &SnoozeClock,
+    user_id: &UserId,
+) -> Snooze {
+    Snooze {
+        id: SnoozeId::new(),
+        project_id: *project_id,
+        task_id,
+        subtask_id,
+        reminder_id: None,
+        original_plan_start_date: plan_start,
+        original_plan_end_date: plan_end,
+        original_remind_at: None,
+        snoozed_until: until,
+        created_at: clock.now,
+        updated_at: clock.now,
+        deleted: false,
+        updated_by: *user_id,
+    }
+}
+
+/// タスクを先送りする
+///
+/// 予定日時をずらし、スヌーズ先の日時までタスクを先送り中にする。
+/// 予定日時が未設定の場合も先送り中にはなる。
+pub async fn snooze_task<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    option: &SnoozeOption,
+    clock: &SnoozeClock,
+    user_id: &UserId,
+) -> Result<Snooze, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let task = find_task(repositories, project_id, task_id).await?;
+    if task.deleted || task.status.is_closed() {
+        return Err(ServiceError::ValidationError(
+            "A closed task cannot be snoozed".to_string(),
+        ));
+    }
+    let until = resolve_until(option, clock)?;
+    let shift = plan_shift(
+        option,
+        clock,
+        until,
+        task.plan_start_date,
+        task.plan_end_date,
+    );
+
+    if shift != Duration::zero() && (task.plan_start_date.is_some() || task.plan_end_date.is_some())
+    {
+        let patch = PartialTask {
+            plan_start_date: task.plan_start_date.map(|date| Some(date + shift)),
+            plan_end_date: task.plan_end_date.map(|date| Some(date + shift)),
+            ..Default::default()
+        };
+        task_service::update_task(repositories, project_id, task_id, &patch, user_id).await?;
+    }
+
+    let snooze = new_snooze(
+        project_id,
+        task.id,
+        None,
+        (task.plan_start_date, task.plan_end_date),
+        until,
+        clock,
+        user_id,
+    );
+    save_new_snooze(repositories, project_id, &snooze, user_id).await?;
+    Ok(snooze)
+}
+
+/// サブタスクを先送りする
+pub async fn snooze_subtask<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    subtask_id: &SubTaskId,
+    option: &SnoozeOption,
+    clock: &SnoozeClock,
+    user_id: &UserId,
+) -> Result<Snooze, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let subtask = find_subtask(repositories, project_id, subtask_id).await?;
+    if subtask.deleted || subtask.status.is_closed() {
+        return Err(ServiceError::ValidationError(
+            "A closed subtask cannot be snoozed".to_string(),
+        ));
+    }
+    let until = resolve_until(option, clock)?;
+    let shift = plan_shift(
+        option,
+        clock,
+        until,
+        subtask.plan_start_date,
+        subtask.plan_end_date,
+    );
+
+    if shift != Duration::zero()
+        && (subtask.plan_start_date.is_some() || subtask.plan_end_date.is_some())
+    {
+        let patch = PartialSubTask {
+            plan_start_date: subtask.plan_start_date.map(|date| Some(date + shift)),
+            plan_end_date: subtask.plan_end_date.map(|date| Some(date + shift)),
+            ..Default::default()
+        };
+        subtask_service::update_subtask(repositories, project_id, subtask_id, &patch, user_id)
+            .await?;
+    }
+
+    let snooze = new_snooze(
+        project_id,
+        subtask.task_id,
+        Some(subtask.id),
+        (subtask.plan_start_date, subtask.plan_end_date),
+        until,
+        clock,
+        user_id,
+    );
+    save_new_snooze(repositories, project_id, &snooze, user_id).await?;
+    Ok(snooze)
+}
+
+/// リマインダーをスヌーズする
+///
+/// スヌーズ先の日時まで通知を見送り、その日時に改めて通知する。
+/// 対象のタスク・サブタスクの予定日時は変えない。
+pub async fn snooze_reminder<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    reminder_id: &ReminderId,
+    option: &SnoozeOption,
+    clock: &SnoozeClock,
+    user_id: &UserId,
+) -> Result<Snooze, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let before = repositories
+        .reminders()
+        .find_by_id(project_id, reminder_id)
+        .await?
+        .ok_or_else(|| ServiceError::NotFound(format!("Reminder not found: {}", reminder_id)))?;
+    let (plan_start, plan_end) = match &before.subtask_id {
+        Some(subtask_id) => {
+            let subtask = find_subtask(repositories, project_id, subtask_id).await?;
+            (subtask.plan_start_date, subtask.plan_end_date)
+        }
+        None => {
+            let task = find_task(repositories, project_id, &before.task_id).await?;
+            (task.plan_start_date, task.plan_end_date)
+        }
+    };
+    let until = resolve_until(option, clock)?;
+
+    // 通知済みの通知を見送る場合はその通知日時、まだなら次の通知予定日時を元の日時とする
+    let original_remind_at = before
+        .due_at(plan_start, plan_end, clock.now)
+        .or(before.last_fired_at)
+        .or_else(|| before.next_fire_at(plan_start, plan_end));
+
+    let mut reminder = before.clone();
+    reminder.snooze(plan_start, plan_end, until);
+    reminder.updated_at = clock.now;
+    reminder.updated_by = *user_id;
+    repositories
+        .reminders()
+        .save(project_id, &reminder, user_id, &clock.now)
+        .await?;
+    events::publish(
+        DomainEvent::updated(EntityKind::Reminder, reminder_id)
+            .in_project(project_id)
+            .related_to(reminder.task_id)
+            .with_changes(events::field_changes(&before, &reminder))
+            .by(user_id),
+    );
+
+    let snooze = Snooze {
+        reminder_id: Some(*reminder_id),
+        original_remind_at,
+        ..new_snooze(
+            project_id,
+            reminder.task_id,
+            reminder.subtask_id,
+            (None, None),
+            until,
+            clock,
+            user_id,
+        )
+    };
+    save_new_snooze(repositories, project_id, &snooze, user_id).await?;
+    Ok(snooze)
+}
+
+/// プロジェクト内のスヌーズの記録を作成日時順に取得する
+///
+/// `task_id` を指定した場合はそのタスク（サブタスク・リマインダーのものを含む）の記録のみを返す。
+pub async fn list_snoozes<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: Option<&TaskId>,
+) -> Result<Vec<Snooze>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let mut snoozes: Vec<Snooze> = repositories
+        .snoozes()
+        .find_all(project_id)
+        .await?
+        .into_iter()
+        .filter(|snooze| !snooze.deleted)
+        .filter(|snooze| task_id.is_none_or(|task_id| snooze.task_id == *task_id))
+        .collect();
+    snoozes.sort_by_key(|snooze| snooze.created_at);
+    Ok(snoozes)
+}
+
+/// `now` の時点で先送り中のタスクのID
+pub async fn get_deferred_task_ids<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    now: DateTime<Utc>,
+) -> Result<HashSet<TaskId>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let snoozes = repositories.snoozes().find_all(project_id).await?;
+    Ok(latest_deferrals(&snoozes)
+        .into_iter()
+        .filter(|((_, subtask_id), snooze)| subtask_id.is_none() && snooze.is_deferring(now))
+        .map(|((task_id, _), _)| task_id)
+        .collect())
+}
+
+/// `now` の時点で先送り中のサブタスクのID
+pub async fn get_deferred_subtask_ids<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    now: DateTime<Utc>,
+) -> Result<HashSet<SubTaskId>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let snoozes = repositories.snoozes().find_all(project_id).await?;
+    Ok(latest_deferrals(&snoozes)
+        .into_iter()
+        .filter(|(_, snooze)| snooze.is_deferring(now))
+        .filter_map(|((_, subtask_id), _)| subtask_id)
+        .collect())
+}
+
+/// タスク・サブタスクの先送りを取りやめ、すぐにスマートリストへ戻す
+///
+/// 最後のスヌーズの先送り先の日時を現在時刻に改める。ずらした予定日時は戻さない。
+/// 先送り中でない場合は `false` を返す。
+pub async fn cancel_deferral<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    subtask_id: Option<&SubTaskId>,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let now = Utc::now();
+    let snoozes = repositories.snoozes().find_all(project_id).await?;
+    let Some(before) = latest_deferrals(&snoozes)
+        .remove(&(*task_id, subtask_id.copied()))
+        .filter(|snooze| snooze.is_deferring(now))
+        .cloned()
+    else {
+        return Ok(false);
+    };
+
+    let mut snooze = before.clone();
+    snooze.snoozed_until = now;
+    snooze.updated_at = now;
+    snooze.updated_by = *user_id;
+    repositories
+        .snoozes()
+        .save(project_id, &snooze, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::updated(EntityKind::Snooze, snooze.id)
+            .in_project(project_id)
+            .related_to(snooze.task_id)
+            .with_changes(events::field_changes(&before, &snooze))
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+/// スヌーズの記録を削除する
+///
+/// 先送り中のタスク・サブタスクは、それより前の記録がなければ先送り中でなくなる。
+/// ずらした予定日時やリマインダーは戻さない。
+pub async fn delete_snooze<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    snooze_id: &SnoozeId,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    // 取り消せるよう、削除前の状態を控える
+    let Some(before) = repositories
+        .snoozes()
+        .find_by_id(project_id, snooze_id)
+        .await?
+    else {
+        return Ok(false);
+    };
+    repositories.snoozes().delete(project_id, snooze_id).await?;
+
+    events::publish(
+        DomainEvent::deleted(EntityKind::Snooze, snooze_id)
+            .in_project(project_id)
+            .related_to(before.task_id)
+            .with_snapshot(&before)
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+async fn save_new_snooze<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    snooze: &Snooze,
+    user_id: &UserId,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    repositories
+        .snoozes()
+        .save(project_id, snooze, user_id, &snooze.created_at)
+        .await?;
+    events::publish(
+        DomainEvent::created(EntityKind::Snooze, snooze.id)
+            .in_project(project_id)
+            .related_to(snooze.task_id)
+            .by(user_id),
+    );
+    Ok(())
+}
+
+/// タスク・サブタスクごとの最後の（リマインダー以外の）スヌーズ
+///
+/// 後から短い時間でスヌーズし直した場合も、最後のスヌーズの先送り先の日時に従う。
+fn latest_deferrals(snoozes: &[Snooze]) -> HashMap<(TaskId, Option<SubTaskId>), &Snooze> {
+    let mut latest: HashMap<(TaskId, Option<SubTaskId>), &Snooze> = HashMap::new();
+    for snooze in snoozes
+        .iter()
+        .filter(|snooze| !snooze.deleted && snooze.reminder_id.is_none())
+    {
+        latest
+            .entry((snooze.task_id, snooze.subtask_id))
+            .and_modify(|current| {
+                if snooze.created_at > current.created_at {
+                    *current = snooze;
+                }
+            })
+            .or_insert(snooze);
+    }
+    latest
+}
+
+async fn find_task<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+) -> Result<Task, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    repositories
+        .tasks()
+        .find_by_id(project_id, task_id)
+        .await?
+        .ok_or_else(|| ServiceError::NotFound(format!("Task not found: {}", task_id)))
+}
+
+async fn find_subtask<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    subtask_id: &SubTaskId,
+) -> Result<SubTask, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    repositories
+        .sub_tasks()
+        .find_by_id(project_id, subtask_id)
+        .await?
+        .ok_or_else(|| ServiceError::NotFound(format!("Subtask not found: {}", subtask_id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, day, hour, minute, 0).unwrap()
+    }
+
+    fn label(time: &str) -> TimeLabel {
+        TimeLabel {
+            id: time.to_string(),
+            name: time.to_string(),
+            time: time.to_string(),
+            color: "#000000".to_string(),
+            order: 0,
+        }
+    }
+
+    fn snooze(task_id: TaskId, created_at: DateTime<Utc>, until: DateTime<Utc>) -> Snooze {
+        let clock = SnoozeClock::new(created_at, FixedOffset::east_opt(0).unwrap(), &[]);
+        new_snooze(
+            &ProjectId::new(),
+            task_id,
+            None,
+            (None, None),
+            until,
+            &clock,
+            &UserId::new(),
+        )
+    }
+
+    #[test]
+    fn test_morning_is_earliest_time_label() {
+        let jst = FixedOffset::east_opt(9 * 3600).unwrap();
+        let clock = SnoozeClock::new(
+            at(5, 12, 0),
+            jst,
+            &[label("18:00"), label("07:30"), label("invalid")],
+        );
+        assert_eq!(clock.morning, NaiveTime::from_hms_opt(7, 30, 0).unwrap());
+
+        let fallback = SnoozeClock::new(at(5, 12, 0), jst, &[]);
+        assert_eq!(fallback.morning, NaiveTime::from_hms_opt(9, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_tomorrow_morning_uses_local_date() {
+        let jst = FixedOffset::east_opt(9 * 3600).unwrap();
+        // UTC 1/5 20:00 は日本時間では 1/6 5:00 なので、翌朝は日本時間 1/7 7:30
+        let clock = SnoozeClock::new(at(5, 20, 0), jst, &[label("07:30")]);
+        assert_eq!(
+            resolve_until(&SnoozeOption::TomorrowMorning, &clock).unwrap(),
+            at(6, 22, 30)
+        );
+    }
+
+    #[test]
+    fn test_resolve_until_presets_and_custom() {
+        let clock = SnoozeClock::new(at(5, 12, 0), FixedOffset::east_opt(0).unwrap(), &[]);
+        let preset = SnoozeOption::Preset {
+            preset: SnoozePreset::ThreeHours,
+        };
+        assert_eq!(resolve_until(&preset, &clock).unwrap(), at(5, 15, 0));
+        assert_eq!(
+            resolve_until(&SnoozeOption::Custom { minutes: 45 }, &clock).unwrap(),
+            at(5, 12, 45)
+        );
+        assert!(resolve_until(&SnoozeOption::Custom { minutes: 0 }, &clock).is_err());
+    }
+
+    #[test]
+    fn test_plan_shift() {
+        let clock = SnoozeClock::new(at(5, 12, 0), FixedOffset::east_opt(0).unwrap(), &[]);
+        let one_day = SnoozeOption::Preset {
+            preset: SnoozePreset::OneDay,
+        };
+        let until = resolve_until(&one_day, &clock).unwrap();
+        assert_eq!(
+            plan_shift(&one_day, &clock, until, None, Some(at(5, 18, 0))),
+            Duration::days(1)
+        );
+
+        // 明日の朝: 予定開始日時を翌朝に合わせる
+        let morning = SnoozeOption::TomorrowMorning;
+        let until = resolve_until(&morning, &clock).unwrap();
+        assert_eq!(until, at(6, 9, 0));
+        assert_eq!(
+            plan_shift(
+                &morning,
+                &clock,
+                until,
+                Some(at(5, 14, 0)),
+                Some(at(5, 18, 0))
+            ),
+            Duration::hours(19)
+        );
+        // 既に翌朝より後の予定は動かさない
+        assert_eq!(
+            plan_shift(&morning, &clock, until, None, Some(at(7, 9, 0))),
+            Duration::zero()
+        );
+    }
+
+    #[test]
+    fn test_latest_snooze_decides_deferral() {
+        let task_id = TaskId::new();
+        let long = snooze(task_id, at(5, 9, 0), at(12, 9, 0));
+        let short = snooze(task_id, at(5, 10, 0), at(5, 11, 0));
+        let mut reminder = snooze(TaskId::new(), at(5, 10, 0), at(12, 9, 0));
+        reminder.reminder_id = Some(ReminderId::new());
+
+        let snoozes = vec![short.clone(), long, reminder];
+        let latest = latest_deferrals(&snoozes);
+        assert_eq!(latest.len(), 1);
+        assert_eq!(latest[&(task_id, None)].id, short.id);
+        assert!(latest[&(task_id, None)].is_deferring(at(5, 10, 30)));
+        assert!(!latest[&(task_id, None)].is_deferring(at(5, 11, 0)));
+    }
+}
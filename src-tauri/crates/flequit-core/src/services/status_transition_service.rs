@@ -0,0 +1,463 @@
+//! ステータス遷移サービス
+//!
+//! タスク・サブタスクのステータス変更に共通する処理を提供する。
+//!
+//! - プロジェクトの遷移ポリシー（ステータス遷移ルールの集合）による遷移の可否の判定
+//! - ステータスに応じた作業開始日時（`do_start_date`）・作業終了日時（`do_end_date`）の自動設定
+//!
+//! ルールが登録されていない遷移はすべて許可する。
+
+use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::status_transition_rule::{
+    PartialStatusTransitionRule, StatusTransitionRule,
+};
+use flequit_model::types::id_types::{ProjectId, StatusTransitionRuleId, UserId};
+use flequit_model::types::task_types::{StatusTransitionRequirement, TaskStatus};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::service_error::ServiceError;
+use partially::Partial;
+
+/// ステータスに連動する作業日時
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WorkDates {
+    pub do_start_date: Option<DateTime<Utc>>,
+    pub do_end_date: Option<DateTime<Utc>>,
+}
+
+/// `from`から`to`へ遷移した後の作業日時を求める
+///
+/// - 完了・中止から未完了のステータスへ戻す（再開する）と、作業日時をどちらも消去する
+/// - 実行中になったとき、作業開始日時が未設定なら`now`を設定する
+/// - 完了になったとき、作業終了日時に`now`を設定する（作業開始日時が未設定なら同じ日時を設定する）
+pub fn work_dates_after_transition(
+    from: &TaskStatus,
+    to: &TaskStatus,
+    current: WorkDates,
+    now: DateTime<Utc>,
+) -> WorkDates {
+    if from == to {
+        return current;
+    }
+    let mut dates = if from.is_closed() && !to.is_closed() {
+        WorkDates::default()
+    } else {
+        current
+    };
+    match to {
+        TaskStatus::InProgress => {
+            dates.do_start_date.get_or_insert(now);
+        }
+        TaskStatus::Completed => {
+            dates.do_start_date.get_or_insert(now);
+            dates.do_end_date = Some(now);
+        }
+        _ => {}
+    }
+    dates
+}
+
+/// 遷移ポリシーに照らして`from`から`to`への遷移が許可されるか判定する
+pub fn check_transition(
+    rules: &[StatusTransitionRule],
+    from: &TaskStatus,
+    to: &TaskStatus,
+    reason: Option<&str>,
+) -> Result<(), ServiceError> {
+    if from == to {
+        return Ok(());
+    }
+    let Some(rule) = rule_for(rules, from, to) else {
+        return Ok(());
+    };
+    match rule.requirement {
+        StatusTransitionRequirement::Forbidden => Err(ServiceError::ValidationError(format!(
+            "Changing status from {} to {} is not allowed in this project",
+            from, to
+        ))),
+        StatusTransitionRequirement::ReasonRequired
+            if reason.is_none_or(|reason| reason.trim().is_empty()) =>
+        {
+            Err(ServiceError::ValidationError(format!(
+                "A reason is required to change status from {} to {}",
+                from, to
+            )))
+        }
+        StatusTransitionRequirement::ReasonRequired => Ok(()),
+    }
+}
+
+/// `from`から`to`への遷移に適用されるルール
+fn rule_for<'a>(
+    rules: &'a [StatusTransitionRule],
+    from: &TaskStatus,
+    to: &TaskStatus,
+) -> Option<&'a StatusTransitionRule> {
+    rules
+        .iter()
+        .find(|rule| !rule.deleted && rule.applies_to(from, to))
+}
+
+/// プロジェクトの遷移ポリシーを読み込み、遷移が許可されるか判定する
+pub async fn validate_transition<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    from: &TaskStatus,
+    to: &TaskStatus,
+    reason: Option<&str>,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    if from == to {
+        return Ok(());
+    }
+    let rules = repositories
+        .status_transition_rules()
+        .find_all(project_id)
+        .await?;
+    check_transition(&rules, from, to, reason)
+}
+
+/// 理由を指定できない変更（フィールドの部分更新）で遷移が許可されるか判定する
+///
+/// 理由の入力が必要な遷移は、理由を指定してステータスを変更するよう求めるエラーにする。
+pub async fn validate_transition_without_reason<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    from: &TaskStatus,
+    to: &TaskStatus,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    if from == to {
+        return Ok(());
+    }
+    let rules = repositories
+        .status_transition_rules()
+        .find_all(project_id)
+        .await?;
+    if rule_for(&rules, from, to)
+        .is_some_and(|rule| rule.requirement == StatusTransitionRequirement::ReasonRequired)
+    {
+        return Err(ServiceError::ValidationError(format!(
+            "A reason is required to change status from {} to {}; change the status with a reason instead of updating the fields",
+            from, to
+        )));
+    }
+    check_transition(&rules, from, to, None)
+}
+
+pub async fn list_status_transition_rules<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+) -> Result<Vec<StatusTransitionRule>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    Ok(repositories
+        .status_transition_rules()
+        .find_all(project_id)
+        .await?)
+}
+
+pub async fn create_status_transition_rule<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    rule: &StatusTransitionRule,
+    user_id: &UserId,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let now = Utc::now();
+    let mut new_data = rule.clone();
+    new_data.project_id = *project_id;
+    new_data.created_at = now;
+    new_data.updated_at = now;
+    new_data.deleted = false;
+    new_data.updated_by = *user_id;
+    validate_rule(repositories, &new_data).await?;
+
+    repositories
+        .status_transition_rules()
+        .save(project_id, &new_data, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::created(EntityKind::StatusTransitionRule, new_data.id)
+            .in_project(project_id)
+            .by(user_id),
+    );
+    Ok(())
+}
+
+pub async fn update_status_transition_rule<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    rule_id: &StatusTransitionRuleId,
+    patch: &PartialStatusTransitionRule,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(before) = repositories
+        .status_transition_rules()
+        .find_by_id(project_id, rule_id)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    let mut rule = before.clone();
+    rule.apply_some(patch.clone());
+    let changes = events::field_changes(&before, &rule);
+    if changes.is_empty() {
+        return Ok(false);
+    }
+    validate_rule(repositories, &rule).await?;
+
+    let now = Utc::now();
+    rule.updated_at = now;
+    rule.updated_by = *user_id;
+    repositories
+        .status_transition_rules()
+        .save(project_id, &rule, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::updated(EntityKind::StatusTransitionRule, rule_id)
+            .in_project(project_id)
+            .with_changes(changes)
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+pub async fn delete_status_transition_rule<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    rule_id: &StatusTransitionRuleId,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(before) = repositories
+        .status_transition_rules()
+        .find_by_id(project_id, rule_id)
+        .await?
+    else {
+        return Ok(false);
+    };
+    repositories
+        .status_transition_rules()
+        .delete(project_id, rule_id)
+        .await?;
+
+    events::publish(
+        DomainEvent::deleted(EntityKind::StatusTransitionRule, rule_id)
+            .in_project(project_id)
+            .with_snapshot(&before)
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+/// 同じステータスへの遷移や、同じ遷移に対する重複したルールを拒否する
+async fn validate_rule<R>(repositories: &R, rule: &StatusTransitionRule) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    if rule.from_status == rule.to_status {
+        return Err(ServiceError::ValidationError(
+            "A status transition rule needs different from and to statuses".to_string(),
+        ));
+    }
+    let duplicated = repositories
+        .status_transition_rules()
+        .find_all(&rule.project_id)
+        .await?
+        .iter()
+        .any(|other| other.id != rule.id && other.applies_to(&rule.from_status, &rule.to_status));
+    if duplicated {
+        return Err(ServiceError::ValidationError(format!(
+            "A rule for changing status from {} to {} already exists",
+            rule.from_status, rule.to_status
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 1, hour, 0, 0).unwrap()
+    }
+
+    fn rule(
+        from: TaskStatus,
+        to: TaskStatus,
+        requirement: StatusTransitionRequirement,
+    ) -> StatusTransitionRule {
+        let user_id = UserId::new();
+        StatusTransitionRule {
+            id: StatusTransitionRuleId::new(),
+            project_id: ProjectId::new(),
+            from_status: from,
+            to_status: to,
+            requirement,
+            created_at: at(0),
+            updated_at: at(0),
+            deleted: false,
+            updated_by: user_id,
+        }
+    }
+
+    #[test]
+    fn test_work_dates_follow_status() {
+        let empty = WorkDates::default();
+
+        // 最初に実行中になったときだけ開始日時を設定する
+        let started = work_dates_after_transition(
+            &TaskStatus::NotStarted,
+            &TaskStatus::InProgress,
+            empty,
+            at(9),
+        );
+        assert_eq!(started.do_start_date, Some(at(9)));
+        assert_eq!(started.do_end_date, None);
+        let resumed = work_dates_after_transition(
+            &TaskStatus::Waiting,
+            &TaskStatus::InProgress,
+            started,
+            at(11),
+        );
+        assert_eq!(resumed.do_start_date, Some(at(9)));
+
+        let completed = work_dates_after_transition(
+            &TaskStatus::InProgress,
+            &TaskStatus::Completed,
+            resumed,
+            at(17),
+        );
+        assert_eq!(completed.do_start_date, Some(at(9)));
+        assert_eq!(completed.do_end_date, Some(at(17)));
+
+        // 着手せずに完了した場合は開始日時も完了日時にそろえる
+        let skipped = work_dates_after_transition(
+            &TaskStatus::NotStarted,
+            &TaskStatus::Completed,
+            empty,
+            at(10),
+        );
+        assert_eq!(skipped.do_start_date, Some(at(10)));
+        assert_eq!(skipped.do_end_date, Some(at(10)));
+
+        // 再開すると消去され、実行中への再開では開始日時を設定し直す
+        let reopened = work_dates_after_transition(
+            &TaskStatus::Completed,
+            &TaskStatus::NotStarted,
+            completed,
+            at(18),
+        );
+        assert_eq!(reopened, WorkDates::default());
+        let restarted = work_dates_after_transition(
+            &TaskStatus::Completed,
+            &TaskStatus::InProgress,
+            completed,
+            at(18),
+        );
+        assert_eq!(restarted.do_start_date, Some(at(18)));
+        assert_eq!(restarted.do_end_date, None);
+
+        // 同じステータスや完了から中止への変更では変わらない
+        assert_eq!(
+            work_dates_after_transition(
+                &TaskStatus::Completed,
+                &TaskStatus::Completed,
+                completed,
+                at(19)
+            ),
+            completed
+        );
+        assert_eq!(
+            work_dates_after_transition(
+                &TaskStatus::Completed,
+                &TaskStatus::Cancelled,
+                completed,
+                at(19)
+            ),
+            completed
+        );
+    }
+
+    #[test]
+    fn test_check_transition_applies_rules() {
+        let rules = vec![
+            rule(
+                TaskStatus::Cancelled,
+                TaskStatus::InProgress,
+                StatusTransitionRequirement::ReasonRequired,
+            ),
+            rule(
+                TaskStatus::Completed,
+                TaskStatus::NotStarted,
+                StatusTransitionRequirement::Forbidden,
+            ),
+        ];
+
+        assert!(
+            check_transition(
+                &rules,
+                &TaskStatus::Cancelled,
+                &TaskStatus::InProgress,
+                None
+            )
+            .is_err()
+        );
+        assert!(
+            check_transition(
+                &rules,
+                &TaskStatus::Cancelled,
+                &TaskStatus::InProgress,
+                Some("  ")
+            )
+            .is_err()
+        );
+        assert!(
+            check_transition(
+                &rules,
+                &TaskStatus::Cancelled,
+                &TaskStatus::InProgress,
+                Some("再依頼があったため")
+            )
+            .is_ok()
+        );
+        assert!(
+            check_transition(
+                &rules,
+                &TaskStatus::Completed,
+                &TaskStatus::NotStarted,
+                Some("理由があっても禁止")
+            )
+            .is_err()
+        );
+        // ルールのない遷移は許可される
+        assert!(
+            check_transition(
+                &rules,
+                &TaskStatus::NotStarted,
+                &TaskStatus::Completed,
+                None
+            )
+            .is_ok()
+        );
+    }
+}
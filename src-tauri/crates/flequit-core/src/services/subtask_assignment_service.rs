@@ -1,4 +1,5 @@
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
 use chrono::Utc;
 use flequit_model::models::task_projects::subtask_assignment::SubTaskAssignment;
 use flequit_model::types::id_types::{ProjectId, SubTaskId, UserId};
@@ -30,7 +31,14 @@ where
             &now,
         )
         .await
-        .map_err(ServiceError::Repository)
+        .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::created(EntityKind::SubTaskAssignment, subtask_id)
+            .in_project(&actual_project_id)
+            .related_to(user_id)
+            .by(updating_user_id),
+    );
+    Ok(())
 }
 
 pub async fn remove_subtask_assignment<R>(
@@ -48,7 +56,13 @@ where
         .subtask_assignments()
         .remove(&actual_project_id, subtask_id, user_id)
         .await
-        .map_err(ServiceError::Repository)
+        .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::deleted(EntityKind::SubTaskAssignment, subtask_id)
+            .in_project(&actual_project_id)
+            .related_to(user_id),
+    );
+    Ok(())
 }
 
 pub async fn get_user_ids_by_subtask_id<R>(
@@ -111,6 +125,7 @@ where
 {
     let actual_project_id =
         find_project_id_by_subtask_id(repositories, project_id, subtask_id).await?;
+    let before = get_user_ids_by_subtask_id(repositories, &actual_project_id, subtask_id).await?;
     repositories
         .subtask_assignments()
         .remove_all(&actual_project_id, subtask_id)
@@ -124,6 +139,16 @@ where
             .await
             .map_err(ServiceError::Repository)?;
     }
+    events::publish(
+        DomainEvent::updated(EntityKind::SubTaskAssignment, subtask_id)
+            .in_project(&actual_project_id)
+            .with_changes(vec![events::value_change(
+                "assigned_user_ids",
+                &before,
+                user_ids,
+            )])
+            .by(updating_user_id),
+    );
     Ok(())
 }
 
@@ -137,11 +162,22 @@ where
 {
     let actual_project_id =
         find_project_id_by_subtask_id(repositories, project_id, subtask_id).await?;
+    let before = get_user_ids_by_subtask_id(repositories, &actual_project_id, subtask_id).await?;
     repositories
         .subtask_assignments()
         .remove_all(&actual_project_id, subtask_id)
         .await
-        .map_err(ServiceError::Repository)
+        .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::deleted(EntityKind::SubTaskAssignment, subtask_id)
+            .in_project(&actual_project_id)
+            .with_changes(vec![events::value_change(
+                "assigned_user_ids",
+                &before,
+                Vec::<UserId>::new(),
+            )]),
+    );
+    Ok(())
 }
 
 pub async fn remove_all_subtask_assignments_by_user_id<R>(
@@ -163,6 +199,13 @@ where
             .remove(project_id, &subtask.id, user_id)
             .await
             .map_err(ServiceError::Repository)?;
+        if subtask.assigned_user_ids.contains(user_id) {
+            events::publish(
+                DomainEvent::deleted(EntityKind::SubTaskAssignment, subtask.id)
+                    .in_project(project_id)
+                    .related_to(user_id),
+            );
+        }
     }
     Ok(())
 }
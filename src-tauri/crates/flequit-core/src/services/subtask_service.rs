@@ -2,6 +2,9 @@ use chrono::Utc;
 use flequit_model::types::task_types::TaskStatus;
 
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
+use crate::services::snooze_service;
+use crate::services::status_transition_service::{self, WorkDates};
 use flequit_model::models::task_projects::subtask::{PartialSubTask, SubTask};
 use flequit_model::types::id_types::{ProjectId, SubTaskId, UserId};
 use flequit_repository::repositories::project_patchable_trait::ProjectPatchable;
@@ -14,6 +17,8 @@ pub struct SubtaskSearchCondition {
     pub title: Option<String>,
     pub status: Option<TaskStatus>,
     pub priority: Option<i32>,
+    /// スヌーズで先送り中のサブタスクに絞り込む（`false` でスマートリストから除外する）
+    pub is_deferred: Option<bool>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
 }
@@ -37,6 +42,12 @@ where
         .save(project_id, &new_data, user_id, &now)
         .await?;
 
+    events::publish(
+        DomainEvent::created(EntityKind::SubTask, new_data.id)
+            .in_project(project_id)
+            .related_to(new_data.task_id)
+            .by(user_id),
+    );
     Ok(())
 }
 
@@ -105,6 +116,12 @@ where
         subtasks.retain(|subtask| subtask.priority == Some(priority));
     }
 
+    if let Some(is_deferred) = condition.is_deferred {
+        let deferred =
+            snooze_service::get_deferred_subtask_ids(repositories, project_id, Utc::now()).await?;
+        subtasks.retain(|subtask| deferred.contains(&subtask.id) == is_deferred);
+    }
+
     let offset = condition.offset.unwrap_or(0).max(0) as usize;
     let limit = condition.limit.unwrap_or(i32::MAX).max(0) as usize;
     let subtasks = subtasks.into_iter().skip(offset).take(limit).collect();
@@ -122,12 +139,57 @@ pub async fn update_subtask<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
-    // プロジェクトスコープでパッチによる部分更新を実行
+    let before = repositories
+        .sub_tasks()
+        .find_by_id(project_id, subtask_id)
+        .await?;
+
     let now = Utc::now();
+
+    // ステータスを変更する場合は遷移ポリシーを確認し、作業日時と完了フラグを連動させる
+    // （理由の入力が必要な遷移は拒否する。理由を指定して update_subtask_status で行う）
+    let mut patch = patch.clone();
+    if let (Some(before), Some(status)) = (&before, &patch.status)
+        && before.status != *status
+    {
+        status_transition_service::validate_transition_without_reason(
+            repositories,
+            project_id,
+            &before.status,
+            status,
+        )
+        .await?;
+        let dates = status_transition_service::work_dates_after_transition(
+            &before.status,
+            status,
+            WorkDates {
+                do_start_date: before.do_start_date,
+                do_end_date: before.do_end_date,
+            },
+            now,
+        );
+        patch.do_start_date.get_or_insert(dates.do_start_date);
+        patch.do_end_date.get_or_insert(dates.do_end_date);
+        patch
+            .completed
+            .get_or_insert(*status == TaskStatus::Completed);
+    }
+
+    // プロジェクトスコープでパッチによる部分更新を実行
     let changed = repositories
         .sub_tasks()
-        .patch(project_id, subtask_id, patch, user_id, &now)
+        .patch(project_id, subtask_id, &patch, user_id, &now)
         .await?;
+
+    if changed && let Some(before) = before {
+        events::publish(
+            DomainEvent::updated(EntityKind::SubTask, subtask_id)
+                .in_project(project_id)
+                .related_to(before.task_id)
+                .with_changes(events::patch_changes(&before, &patch))
+                .by(user_id),
+        );
+    }
     Ok(changed)
 }
 
@@ -139,13 +201,27 @@ pub async fn delete_subtask<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
+    // 親タスクのタイムラインへの記録と取り消しのため、削除前の状態を控える
+    let before = repositories
+        .sub_tasks()
+        .find_by_id(project_id, subtask_id)
+        .await?;
     repositories
         .sub_tasks()
         .delete(project_id, subtask_id)
         .await?;
+
+    let mut event = DomainEvent::deleted(EntityKind::SubTask, subtask_id).in_project(project_id);
+    if let Some(before) = before {
+        event = event.related_to(before.task_id).with_snapshot(&before);
+    }
+    events::publish(event);
     Ok(())
 }
 
+/// サブタスクの完了状態を切り替える
+///
+/// 完了にすると作業終了日時を設定し、未完了に戻すと作業日時を消去する。
 pub async fn toggle_completion<R>(
     repositories: &R,
     project_id: &ProjectId,
@@ -156,31 +232,103 @@ where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
     // 既存のサブタスクを取得
-    if let Some(mut subtask) = repositories
+    if let Some(subtask) = repositories
         .sub_tasks()
         .find_by_id(project_id, subtask_id)
         .await?
     {
-        // 完了状態をトグル
-        subtask.completed = !subtask.completed;
-
-        // ステータスも更新（completedフラグと連動）
-        subtask.status = if subtask.completed {
-            TaskStatus::Completed
-        } else {
+        // 完了状態をトグル（ステータスもcompletedフラグと連動）
+        let status = if subtask.completed {
             TaskStatus::NotStarted
+        } else {
+            TaskStatus::Completed
         };
+        change_status(repositories, project_id, subtask, &status, None, user_id).await?;
+    }
 
-        // 更新日時を設定
-        subtask.updated_at = Utc::now();
+    Ok(())
+}
 
-        // 保存
-        let now = Utc::now();
-        repositories
-            .sub_tasks()
-            .save(project_id, &subtask, user_id, &now)
-            .await?;
-    }
+/// サブタスクのステータスを変更する
+///
+/// プロジェクトの遷移ポリシーで理由の入力が必要な遷移は `reason` を指定する。
+/// 作業開始日時・作業終了日時と完了フラグはステータスに合わせて更新する。
+pub async fn update_subtask_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    subtask_id: &SubTaskId,
+    status: &TaskStatus,
+    reason: Option<&str>,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(subtask) = repositories
+        .sub_tasks()
+        .find_by_id(project_id, subtask_id)
+        .await?
+    else {
+        return Ok(false);
+    };
+    change_status(repositories, project_id, subtask, status, reason, user_id).await?;
+    Ok(true)
+}
+
+async fn change_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    mut subtask: SubTask,
+    status: &TaskStatus,
+    reason: Option<&str>,
+    user_id: &UserId,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let before = subtask.clone();
+
+    // 遷移ポリシーの確認
+    status_transition_service::validate_transition(
+        repositories,
+        project_id,
+        &subtask.status,
+        status,
+        reason,
+    )
+    .await?;
+
+    let now = Utc::now();
+    let dates = status_transition_service::work_dates_after_transition(
+        &subtask.status,
+        status,
+        WorkDates {
+            do_start_date: subtask.do_start_date,
+            do_end_date: subtask.do_end_date,
+        },
+        now,
+    );
+    subtask.status = status.clone();
+    subtask.completed = *status == TaskStatus::Completed;
+    subtask.do_start_date = dates.do_start_date;
+    subtask.do_end_date = dates.do_end_date;
+
+    // 更新日時を設定
+    subtask.updated_at = now;
+
+    // 保存
+    repositories
+        .sub_tasks()
+        .save(project_id, &subtask, user_id, &now)
+        .await?;
 
+    events::publish(
+        DomainEvent::updated(EntityKind::SubTask, subtask.id)
+            .in_project(project_id)
+            .related_to(subtask.task_id)
+            .with_changes(events::field_changes(&before, &subtask))
+            .with_reason(reason)
+            .by(user_id),
+    );
     Ok(())
 }
@@ -1,4 +1,5 @@
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
 use chrono::Utc;
 use flequit_model::models::task_projects::subtask_tag::SubTaskTag;
 use flequit_model::types::id_types::{ProjectId, SubTaskId, TagId, UserId};
@@ -24,6 +25,12 @@ where
         .add(&actual_project_id, subtask_id, tag_id, user_id, &Utc::now())
         .await
         .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::created(EntityKind::SubTaskTag, subtask_id)
+            .in_project(&actual_project_id)
+            .related_to(tag_id)
+            .by(user_id),
+    );
     Ok(())
 }
 
@@ -43,6 +50,11 @@ where
         .remove(&actual_project_id, subtask_id, tag_id)
         .await
         .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::deleted(EntityKind::SubTaskTag, subtask_id)
+            .in_project(&actual_project_id)
+            .related_to(tag_id),
+    );
     Ok(())
 }
 
@@ -105,6 +117,7 @@ where
 {
     let actual_project_id =
         find_project_id_by_subtask_id(repositories, project_id, subtask_id).await?;
+    let before = get_tag_ids_by_subtask_id(repositories, &actual_project_id, subtask_id).await?;
     repositories
         .subtask_tags()
         .remove_all(&actual_project_id, subtask_id)
@@ -118,6 +131,12 @@ where
             .await
             .map_err(ServiceError::Repository)?;
     }
+    events::publish(
+        DomainEvent::updated(EntityKind::SubTaskTag, subtask_id)
+            .in_project(&actual_project_id)
+            .with_changes(vec![events::value_change("tag_ids", &before, tag_ids)])
+            .by(user_id),
+    );
     Ok(())
 }
 
@@ -131,11 +150,21 @@ where
 {
     let actual_project_id =
         find_project_id_by_subtask_id(repositories, project_id, subtask_id).await?;
+    let before = get_tag_ids_by_subtask_id(repositories, &actual_project_id, subtask_id).await?;
     repositories
         .subtask_tags()
         .remove_all(&actual_project_id, subtask_id)
         .await
         .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::deleted(EntityKind::SubTaskTag, subtask_id)
+            .in_project(&actual_project_id)
+            .with_changes(vec![events::value_change(
+                "tag_ids",
+                &before,
+                Vec::<TagId>::new(),
+            )]),
+    );
     Ok(())
 }
 
@@ -165,6 +194,11 @@ where
                     .remove(&project.id, &subtask_tag.subtask_id, tag_id)
                     .await
                     .map_err(ServiceError::Repository)?;
+                events::publish(
+                    DomainEvent::deleted(EntityKind::SubTaskTag, subtask_tag.subtask_id)
+                        .in_project(&project.id)
+                        .related_to(tag_id),
+                );
             }
         }
     }
@@ -1,7 +1,8 @@
+use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
 use crate::ports::infrastructure_repositories::{
     TagBookmarkAutomergeRepositoryPort, TagBookmarkSqliteRepositoryPort,
 };
-use crate::InfrastructureRepositoriesTrait;
 use chrono::Utc;
 use flequit_model::models::user_preferences::tag_bookmark::TagBookmark;
 use flequit_model::types::id_types::{ProjectId, TagBookmarkId, TagId, UserId};
@@ -44,6 +45,12 @@ where
         .create(&new_bookmark)
         .await?;
 
+    events::publish(
+        DomainEvent::created(EntityKind::TagBookmark, new_bookmark.id)
+            .in_project(&new_bookmark.project_id)
+            .related_to(new_bookmark.tag_id)
+            .by(&new_bookmark.user_id),
+    );
     Ok(())
 }
 
@@ -118,6 +125,13 @@ where
         .update(&updated_bookmark)
         .await?;
 
+    events::publish(
+        DomainEvent::updated(EntityKind::TagBookmark, updated_bookmark.id)
+            .in_project(&updated_bookmark.project_id)
+            .related_to(updated_bookmark.tag_id)
+            .with_changed_fields(["order_index"])
+            .by(&updated_bookmark.user_id),
+    );
     Ok(())
 }
 
@@ -151,6 +165,13 @@ where
             .tag_bookmarks_automerge()
             .update(bookmark)
             .await?;
+        events::publish(
+            DomainEvent::updated(EntityKind::TagBookmark, bookmark.id)
+                .in_project(&bookmark.project_id)
+                .related_to(bookmark.tag_id)
+                .with_changed_fields(["order_index"])
+                .by(&bookmark.user_id),
+        );
     }
 
     Ok(())
@@ -179,6 +200,12 @@ where
         .delete(user_id, project_id, tag_id)
         .await?;
 
+    events::publish(
+        DomainEvent::deleted(EntityKind::TagBookmark, bookmark_id)
+            .in_project(project_id)
+            .related_to(tag_id)
+            .by(user_id),
+    );
     Ok(())
 }
 
@@ -282,6 +309,12 @@ where
             .tag_bookmarks_automerge()
             .delete(&bookmark.user_id, &bookmark.project_id, &bookmark.tag_id)
             .await?;
+
+        events::publish(
+            DomainEvent::deleted(EntityKind::TagBookmark, bookmark.id)
+                .in_project(project_id)
+                .related_to(tag_id),
+        );
     }
 
     Ok(())
@@ -1,7 +1,8 @@
 use chrono::Utc;
 
-use crate::ports::infrastructure_repositories::TagRepositoryExt;
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
+use crate::ports::infrastructure_repositories::TagRepositoryExt;
 use flequit_model::models::task_projects::tag::{PartialTag, Tag};
 use flequit_model::types::id_types::{ProjectId, TagId, UserId};
 use flequit_repository::repositories::project_repository_trait::ProjectRepository;
@@ -27,6 +28,11 @@ where
         .save(project_id, &new_data, user_id, &now)
         .await?;
 
+    events::publish(
+        DomainEvent::created(EntityKind::Tag, new_data.id)
+            .in_project(project_id)
+            .by(user_id),
+    );
     Ok(())
 }
 
@@ -88,6 +94,8 @@ where
 {
     // 既存のタグを取得
     if let Some(mut tag) = repositories.tags().find_by_id(project_id, tag_id).await? {
+        let before = tag.clone();
+
         // パッチデータで更新
         if let Some(name) = &patch.name {
             tag.name = name.clone();
@@ -108,6 +116,13 @@ where
             .tags()
             .save(project_id, &tag, user_id, &now)
             .await?;
+
+        events::publish(
+            DomainEvent::updated(EntityKind::Tag, tag_id)
+                .in_project(project_id)
+                .with_changes(events::field_changes(&before, &tag))
+                .by(user_id),
+        );
         Ok(true)
     } else {
         Ok(false)
@@ -130,6 +145,7 @@ where
         .delete_with_relations(project_id, tag_id)
         .await?;
 
+    events::publish(DomainEvent::deleted(EntityKind::Tag, tag_id).in_project(project_id));
     Ok(())
 }
 
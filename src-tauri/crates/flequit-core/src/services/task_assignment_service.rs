@@ -1,4 +1,5 @@
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
 use chrono::Utc;
 use flequit_model::models::task_projects::task_assignment::TaskAssignment;
 use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
@@ -23,7 +24,14 @@ where
         .task_assignments()
         .add(&actual_project_id, task_id, user_id, updating_user_id, &now)
         .await
-        .map_err(ServiceError::Repository)
+        .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::created(EntityKind::TaskAssignment, task_id)
+            .in_project(&actual_project_id)
+            .related_to(user_id)
+            .by(updating_user_id),
+    );
+    Ok(())
 }
 
 pub async fn remove_task_assignment<R>(
@@ -40,7 +48,13 @@ where
         .task_assignments()
         .remove(&actual_project_id, task_id, user_id)
         .await
-        .map_err(ServiceError::Repository)
+        .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::deleted(EntityKind::TaskAssignment, task_id)
+            .in_project(&actual_project_id)
+            .related_to(user_id),
+    );
+    Ok(())
 }
 
 pub async fn get_user_ids_by_task_id<R>(
@@ -101,6 +115,7 @@ where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
     let actual_project_id = find_project_id_by_task_id(repositories, project_id, task_id).await?;
+    let before = get_user_ids_by_task_id(repositories, &actual_project_id, task_id).await?;
     repositories
         .task_assignments()
         .remove_all(&actual_project_id, task_id)
@@ -114,6 +129,16 @@ where
             .await
             .map_err(ServiceError::Repository)?;
     }
+    events::publish(
+        DomainEvent::updated(EntityKind::TaskAssignment, task_id)
+            .in_project(&actual_project_id)
+            .with_changes(vec![events::value_change(
+                "assigned_user_ids",
+                &before,
+                user_ids,
+            )])
+            .by(updating_user_id),
+    );
     Ok(())
 }
 
@@ -126,11 +151,22 @@ where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
     let actual_project_id = find_project_id_by_task_id(repositories, project_id, task_id).await?;
+    let before = get_user_ids_by_task_id(repositories, &actual_project_id, task_id).await?;
     repositories
         .task_assignments()
         .remove_all(&actual_project_id, task_id)
         .await
-        .map_err(ServiceError::Repository)
+        .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::deleted(EntityKind::TaskAssignment, task_id)
+            .in_project(&actual_project_id)
+            .with_changes(vec![events::value_change(
+                "assigned_user_ids",
+                &before,
+                Vec::<UserId>::new(),
+            )]),
+    );
+    Ok(())
 }
 
 pub async fn remove_all_task_assignments_by_user_id<R>(
@@ -152,6 +188,13 @@ where
             .remove(project_id, &task.id, user_id)
             .await
             .map_err(ServiceError::Repository)?;
+        if task.assigned_user_ids.contains(user_id) {
+            events::publish(
+                DomainEvent::deleted(EntityKind::TaskAssignment, task.id)
+                    .in_project(project_id)
+                    .related_to(user_id),
+            );
+        }
     }
     Ok(())
 }
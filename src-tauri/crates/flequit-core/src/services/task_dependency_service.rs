@@ -0,0 +1,316 @@
+//! タスク依存関係サービス
+//!
+//! タスク間の「先行タスクの完了待ち」の関係を扱う。
+//! 同じプロジェクト内であればタスクリストをまたいで設定でき、循環する依存関係は作成できない。
+//!
+//! 先行タスクが1つでも未完了（完了・中止以外）のタスクを「ブロック中」とみなす。
+//! 削除された先行タスクはブロックの対象にしない。
+
+use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
+use chrono::Utc;
+use flequit_model::models::task_projects::task::Task;
+use flequit_model::models::task_projects::task_dependency::TaskDependency;
+use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
+use flequit_repository::repositories::project_relation_repository_trait::ProjectRelationRepository;
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::service_error::ServiceError;
+use std::collections::HashSet;
+
+/// `task_id` が `depends_on_task_id` を待つ依存関係を加えると循環するか
+///
+/// 先行タスクから既存の依存関係をたどって `task_id` に到達できる場合は循環する。
+pub fn creates_cycle(
+    dependencies: &[TaskDependency],
+    task_id: &TaskId,
+    depends_on_task_id: &TaskId,
+) -> bool {
+    let mut visited = HashSet::new();
+    let mut stack = vec![*depends_on_task_id];
+    while let Some(current) = stack.pop() {
+        if current == *task_id {
+            return true;
+        }
+        if !visited.insert(current) {
+            continue;
+        }
+        stack.extend(
+            dependencies
+                .iter()
+                .filter(|dependency| !dependency.deleted && dependency.task_id == current)
+                .map(|dependency| dependency.depends_on_task_id),
+        );
+    }
+    false
+}
+
+/// 先行タスクとして他のタスクをブロックしうる（削除されておらず未完了の）タスク
+pub fn open_task_ids(tasks: &[Task]) -> HashSet<TaskId> {
+    tasks
+        .iter()
+        .filter(|task| !task.deleted && !task.status.is_closed())
+        .map(|task| task.id)
+        .collect()
+}
+
+/// 未完了の先行タスクを持つ（ブロック中の）タスク
+pub fn blocked_task_ids(
+    dependencies: &[TaskDependency],
+    open_task_ids: &HashSet<TaskId>,
+) -> HashSet<TaskId> {
+    dependencies
+        .iter()
+        .filter(|dependency| {
+            !dependency.deleted && open_task_ids.contains(&dependency.depends_on_task_id)
+        })
+        .map(|dependency| dependency.task_id)
+        .collect()
+}
+
+/// 依存関係を追加する
+///
+/// 両方のタスクがプロジェクトに存在すること、自分自身や循環する依存関係でないことを確認する。
+/// 既に同じ依存関係がある場合は何もしない。
+pub async fn add_task_dependency<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    depends_on_task_id: &TaskId,
+    user_id: &UserId,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    if task_id == depends_on_task_id {
+        return Err(ServiceError::ValidationError(
+            "A task cannot depend on itself".to_string(),
+        ));
+    }
+    find_active_task(repositories, project_id, task_id).await?;
+    find_active_task(repositories, project_id, depends_on_task_id).await?;
+
+    let dependencies = repositories
+        .task_dependencies()
+        .find_all(project_id)
+        .await?;
+    if dependencies.iter().any(|dependency| {
+        !dependency.deleted
+            && dependency.task_id == *task_id
+            && dependency.depends_on_task_id == *depends_on_task_id
+    }) {
+        return Ok(());
+    }
+    if creates_cycle(&dependencies, task_id, depends_on_task_id) {
+        return Err(ServiceError::ValidationError(format!(
+            "Task {} cannot depend on {} because it would create a dependency cycle",
+            task_id, depends_on_task_id
+        )));
+    }
+
+    let now = Utc::now();
+    repositories
+        .task_dependencies()
+        .add(project_id, task_id, depends_on_task_id, user_id, &now)
+        .await?;
+    events::publish(
+        DomainEvent::created(EntityKind::TaskDependency, task_id)
+            .in_project(project_id)
+            .related_to(depends_on_task_id)
+            .by(user_id),
+    );
+    Ok(())
+}
+
+pub async fn remove_task_dependency<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    depends_on_task_id: &TaskId,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    repositories
+        .task_dependencies()
+        .remove(project_id, task_id, depends_on_task_id)
+        .await?;
+    events::publish(
+        DomainEvent::deleted(EntityKind::TaskDependency, task_id)
+            .in_project(project_id)
+            .related_to(depends_on_task_id),
+    );
+    Ok(())
+}
+
+/// タスクが待っている先行タスクのID
+pub async fn list_blocking_task_ids<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+) -> Result<Vec<TaskId>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    Ok(repositories
+        .task_dependencies()
+        .find_relations(project_id, task_id)
+        .await?
+        .into_iter()
+        .filter(|dependency| !dependency.deleted)
+        .map(|dependency| dependency.depends_on_task_id)
+        .collect())
+}
+
+/// タスクの完了を待っている後続タスクのID
+pub async fn list_dependent_task_ids<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+) -> Result<Vec<TaskId>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    Ok(repositories
+        .task_dependencies()
+        .find_all(project_id)
+        .await?
+        .into_iter()
+        .filter(|dependency| !dependency.deleted && dependency.depends_on_task_id == *task_id)
+        .map(|dependency| dependency.task_id)
+        .collect())
+}
+
+/// プロジェクト内のブロック中のタスクID
+pub async fn get_blocked_task_ids<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+) -> Result<HashSet<TaskId>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let tasks = repositories.tasks().find_all(project_id).await?;
+    let dependencies = repositories
+        .task_dependencies()
+        .find_all(project_id)
+        .await?;
+    Ok(blocked_task_ids(&dependencies, &open_task_ids(&tasks)))
+}
+
+/// 先行タスクが完了・中止したあと、ブロックが解除された後続タスクを通知する
+///
+/// 他に未完了の先行タスクが残っている後続タスクと、既に完了・中止している後続タスクは対象外。
+pub async fn publish_unblocked_dependents<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    blocker_id: &TaskId,
+    user_id: &UserId,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let dependencies = repositories
+        .task_dependencies()
+        .find_all(project_id)
+        .await?;
+    if !dependencies
+        .iter()
+        .any(|dependency| !dependency.deleted && dependency.depends_on_task_id == *blocker_id)
+    {
+        return Ok(());
+    }
+
+    let tasks = repositories.tasks().find_all(project_id).await?;
+    let open = open_task_ids(&tasks);
+    let blocked = blocked_task_ids(&dependencies, &open);
+    for dependency in dependencies
+        .iter()
+        .filter(|dependency| !dependency.deleted && dependency.depends_on_task_id == *blocker_id)
+    {
+        if !open.contains(&dependency.task_id) || blocked.contains(&dependency.task_id) {
+            continue;
+        }
+        events::publish(
+            DomainEvent::updated(EntityKind::TaskDependency, dependency.task_id)
+                .in_project(project_id)
+                .related_to(blocker_id)
+                .with_changes(vec![events::value_change("blocked", true, false)])
+                .by(user_id),
+        );
+    }
+    Ok(())
+}
+
+/// 依存関係を設定できる（プロジェクト内に存在し削除されていない）タスクを取得する
+async fn find_active_task<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+) -> Result<Task, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    repositories
+        .tasks()
+        .find_by_id(project_id, task_id)
+        .await?
+        .filter(|task| !task.deleted)
+        .ok_or_else(|| ServiceError::ValidationError(format!("Task not found: {}", task_id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependency(task_id: TaskId, depends_on_task_id: TaskId) -> TaskDependency {
+        let now = Utc::now();
+        TaskDependency {
+            task_id,
+            depends_on_task_id,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        }
+    }
+
+    #[test]
+    fn test_creates_cycle_detects_transitive_dependencies() {
+        let (a, b, c, d) = (TaskId::new(), TaskId::new(), TaskId::new(), TaskId::new());
+        // a は b を、b は c を待つ
+        let dependencies = vec![dependency(a, b), dependency(b, c)];
+
+        assert!(creates_cycle(&dependencies, &c, &a));
+        assert!(creates_cycle(&dependencies, &b, &a));
+        assert!(creates_cycle(&dependencies, &a, &a));
+        assert!(!creates_cycle(&dependencies, &a, &c));
+        assert!(!creates_cycle(&dependencies, &d, &a));
+
+        // 削除済みの依存関係はたどらない
+        let mut removed = dependencies.clone();
+        removed[1].deleted = true;
+        assert!(!creates_cycle(&removed, &c, &a));
+    }
+
+    #[test]
+    fn test_blocked_task_ids_only_counts_open_blockers() {
+        let (a, b, c) = (TaskId::new(), TaskId::new(), TaskId::new());
+        // a は b と c を待ち、b は c を待つ
+        let dependencies = vec![dependency(a, b), dependency(a, c), dependency(b, c)];
+
+        let all_open = HashSet::from([a, b, c]);
+        assert_eq!(
+            blocked_task_ids(&dependencies, &all_open),
+            HashSet::from([a, b])
+        );
+
+        // c が完了すると b のブロックは解除されるが、a は b を待ち続ける
+        let c_closed = HashSet::from([a, b]);
+        assert_eq!(
+            blocked_task_ids(&dependencies, &c_closed),
+            HashSet::from([a])
+        );
+
+        let all_closed = HashSet::new();
+        assert!(blocked_task_ids(&dependencies, &all_closed).is_empty());
+    }
+}
@@ -1,8 +1,9 @@
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
 use chrono::Utc;
+use flequit_model::models::task_projects::SubTaskTree;
 use flequit_model::models::task_projects::task::TaskTree;
 use flequit_model::models::task_projects::task_list::{PartialTaskList, TaskList, TaskListTree};
-use flequit_model::models::task_projects::SubTaskTree;
 use flequit_model::types::id_types::{ProjectId, TaskListId, UserId};
 use flequit_repository::project_relation_repository_trait::ProjectRelationRepository;
 use flequit_repository::repositories::project_patchable_trait::ProjectPatchable;
@@ -23,6 +24,11 @@ where
         .task_lists()
         .save(project_id, task_list, user_id, &now)
         .await?;
+    events::publish(
+        DomainEvent::created(EntityKind::TaskList, task_list.id)
+            .in_project(project_id)
+            .by(user_id),
+    );
     Ok(())
 }
 
@@ -50,11 +56,25 @@ pub async fn update_task_list<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
+    let before = repositories
+        .task_lists()
+        .find_by_id(project_id, task_list_id)
+        .await?;
     let now = Utc::now();
-    Ok(repositories
+    let changed = repositories
         .task_lists()
         .patch(project_id, task_list_id, patch, user_id, &now)
-        .await?)
+        .await?;
+
+    if changed && let Some(before) = before {
+        events::publish(
+            DomainEvent::updated(EntityKind::TaskList, task_list_id)
+                .in_project(project_id)
+                .with_changes(events::patch_changes(&before, patch))
+                .by(user_id),
+        );
+    }
+    Ok(changed)
 }
 
 pub async fn delete_task_list<R>(
@@ -66,6 +86,7 @@ where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
     repositories.task_lists().delete(project_id, id).await?;
+    events::publish(DomainEvent::deleted(EntityKind::TaskList, id).in_project(project_id));
     Ok(())
 }
 
@@ -237,6 +258,7 @@ where
                 title: task.title.clone(),
                 description: task.description.clone(),
                 status: task.status.clone(),
+                workflow_status_id: task.workflow_status_id,
                 priority: task.priority,
                 plan_start_date: task.plan_start_date,
                 plan_end_date: task.plan_end_date,
@@ -1,7 +1,12 @@
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
+use crate::services::snooze_service;
+use crate::services::status_transition_service::{self, WorkDates};
+use crate::services::task_dependency_service;
+use crate::services::workflow_status_service;
 use chrono::Utc;
 use flequit_model::models::task_projects::task::{PartialTask, Task};
-use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
+use flequit_model::types::id_types::{ProjectId, TaskId, UserId, WorkflowStatusId};
 use flequit_model::types::task_types::TaskStatus;
 use flequit_repository::repositories::project_patchable_trait::ProjectPatchable;
 use flequit_repository::repositories::project_repository_trait::ProjectRepository;
@@ -15,6 +20,10 @@ pub struct TaskSearchCondition {
     pub tag_id: Option<String>,
     pub title: Option<String>,
     pub is_archived: Option<bool>,
+    /// 未完了の先行タスクを持つ（ブロック中の）タスクに絞り込む
+    pub is_blocked: Option<bool>,
+    /// スヌーズで先送り中のタスクに絞り込む（`false` でスマートリストから除外する）
+    pub is_deferred: Option<bool>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
 }
@@ -28,11 +37,57 @@ pub async fn create_task<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
+    // ワークフローステータスを参照する場合はステータスをその分類に合わせる
+    let mut task = task.clone();
+    task.status = workflow_status_service::status_for_reference(
+        repositories,
+        project_id,
+        task.workflow_status_id.as_ref(),
+        &task.status,
+    )
+    .await?;
+
+    let now = Utc::now();
+    repositories
+        .tasks()
+        .save(project_id, &task, user_id, &now)
+        .await?;
+    events::publish(
+        DomainEvent::created(EntityKind::Task, task.id)
+            .in_project(project_id)
+            .by(user_id),
+    );
+    Ok(())
+}
+
+/// タスク全体を保存する（存在すれば置き換え、なければ作成）
+///
+/// 同期で取り込んだ内容の反映など、部分更新ではなく全体を書き込む場合に使用する。
+pub async fn save_task<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task: &Task,
+    user_id: &UserId,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let before = repositories
+        .tasks()
+        .find_by_id(project_id, &task.id)
+        .await?;
     let now = Utc::now();
     repositories
         .tasks()
         .save(project_id, task, user_id, &now)
         .await?;
+
+    let event = match before {
+        Some(before) => DomainEvent::updated(EntityKind::Task, task.id)
+            .with_changes(events::field_changes(&before, task)),
+        None => DomainEvent::created(EntityKind::Task, task.id),
+    };
+    events::publish(event.in_project(project_id).by(user_id));
     Ok(())
 }
 
@@ -107,6 +162,18 @@ where
         tasks.retain(|task| task.is_archived == is_archived);
     }
 
+    if let Some(is_blocked) = condition.is_blocked {
+        let blocked =
+            task_dependency_service::get_blocked_task_ids(repositories, project_id).await?;
+        tasks.retain(|task| blocked.contains(&task.id) == is_blocked);
+    }
+
+    if let Some(is_deferred) = condition.is_deferred {
+        let deferred =
+            snooze_service::get_deferred_task_ids(repositories, project_id, Utc::now()).await?;
+        tasks.retain(|task| deferred.contains(&task.id) == is_deferred);
+    }
+
     let offset = condition.offset.unwrap_or(0).max(0) as usize;
     let limit = condition.limit.unwrap_or(i32::MAX).max(0) as usize;
     let tasks = tasks.into_iter().skip(offset).take(limit).collect();
@@ -124,11 +191,66 @@ pub async fn update_task<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
+    let before = repositories.tasks().find_by_id(project_id, task_id).await?;
     let now = Utc::now();
-    Ok(repositories
+
+    // ステータスを変更する場合は遷移ポリシーを確認し、作業日時を連動させる
+    // （理由の入力が必要な遷移は拒否する。理由を指定して update_task_status で行う）
+    let mut patch = patch.clone();
+    if let Some(before) = &before {
+        workflow_status_service::reconcile_task_patch(repositories, project_id, before, &mut patch)
+            .await?;
+    }
+    if let (Some(before), Some(status)) = (&before, &patch.status)
+        && before.status != *status
+    {
+        status_transition_service::validate_transition_without_reason(
+            repositories,
+            project_id,
+            &before.status,
+            status,
+        )
+        .await?;
+        let dates = status_transition_service::work_dates_after_transition(
+            &before.status,
+            status,
+            WorkDates {
+                do_start_date: before.do_start_date,
+                do_end_date: before.do_end_date,
+            },
+            now,
+        );
+        patch.do_start_date.get_or_insert(dates.do_start_date);
+        patch.do_end_date.get_or_insert(dates.do_end_date);
+    }
+
+    let changed = repositories
         .tasks()
-        .patch(project_id, task_id, patch, user_id, &now)
-        .await?)
+        .patch(project_id, task_id, &patch, user_id, &now)
+        .await?;
+
+    if changed && let Some(before) = before {
+        events::publish(
+            DomainEvent::updated(EntityKind::Task, task_id)
+                .in_project(project_id)
+                .with_changes(events::patch_changes(&before, &patch))
+                .by(user_id),
+        );
+        if patch
+            .status
+            .as_ref()
+            .is_some_and(|status| status.is_closed() && !before.status.is_closed())
+        {
+            task_dependency_service::publish_unblocked_dependents(
+                repositories,
+                project_id,
+                task_id,
+                user_id,
+            )
+            .await?;
+        }
+    }
+    Ok(changed)
 }
 
 pub async fn delete_task<R>(
@@ -140,6 +262,7 @@ where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
     repositories.tasks().delete(project_id, task_id).await?;
+    events::publish(DomainEvent::deleted(EntityKind::Task, task_id).in_project(project_id));
     Ok(())
 }
 
@@ -206,6 +329,8 @@ where
         .find_by_id(&project_id_typed, &task_id_typed)
         .await?
     {
+        let before = task.clone();
+
         // プロジェクトIDが一致するかチェック
         // project_idチェックをコメントアウト
         /*if task.project_id.to_string() != project_id {
@@ -235,16 +360,29 @@ where
             .tasks()
             .save(&project_id_typed, &task, user_id, &now)
             .await?;
+
+        events::publish(
+            DomainEvent::updated(EntityKind::Task, task_id_typed)
+                .in_project(&project_id_typed)
+                .with_changes(events::field_changes(&before, &task))
+                .by(user_id),
+        );
     }
 
     Ok(())
 }
 
+/// タスクのステータスを変更する
+///
+/// プロジェクトの遷移ポリシーで理由の入力が必要な遷移は `reason` を指定する。
+/// 作業開始日時・作業終了日時はステータスに合わせて設定・消去する。
+/// 参照中のワークフローステータスの分類と合わなくなる場合は参照を外す。
 pub async fn update_task_status<R>(
     repositories: &R,
     project_id: &str,
     task_id: &str,
     status: &TaskStatus,
+    reason: Option<&str>,
     user_id: &UserId,
 ) -> Result<(), ServiceError>
 where
@@ -255,7 +393,7 @@ where
     let task_id_typed = TaskId::from(task_id.to_string());
     let project_id_typed = ProjectId::from(project_id.to_string());
 
-    if let Some(mut task) = repositories
+    if let Some(task) = repositories
         .tasks()
         .find_by_id(&project_id_typed, &task_id_typed)
         .await?
@@ -268,20 +406,134 @@ where
             ));
         }*/
 
-        // ステータス更新
-        task.status = status.clone();
+        let workflow_status_id = workflow_status_service::reference_after_status_change(
+            repositories,
+            &project_id_typed,
+            task.workflow_status_id.as_ref(),
+            status,
+        )
+        .await?;
+        change_status(
+            repositories,
+            &project_id_typed,
+            task,
+            status,
+            workflow_status_id,
+            reason,
+            user_id,
+        )
+        .await?;
+    }
 
-        // 更新日時を設定
-        task.updated_at = Utc::now();
+    Ok(())
+}
 
-        // 保存
-        let now = Utc::now();
-        repositories
-            .tasks()
-            .save(&project_id_typed, &task, user_id, &now)
+/// タスクをワークフローステータスへ移す
+///
+/// ステータスは移動先の分類に変わり、遷移ポリシーと作業日時の連動は
+/// `update_task_status` と同じく分類に対して適用される。
+/// タスクが見つからない場合は `false` を返す。
+pub async fn move_task_to_workflow_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    workflow_status_id: &WorkflowStatusId,
+    reason: Option<&str>,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(task) = repositories.tasks().find_by_id(project_id, task_id).await? else {
+        return Ok(false);
+    };
+    let workflow_status =
+        workflow_status_service::find_active_status(repositories, project_id, workflow_status_id)
             .await?;
+    change_status(
+        repositories,
+        project_id,
+        task,
+        &workflow_status.category,
+        Some(workflow_status.id),
+        reason,
+        user_id,
+    )
+    .await?;
+    Ok(true)
+}
+
+/// 遷移ポリシーを確認してステータスとワークフローステータスの参照を変更し、作業日時を連動させる
+async fn change_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    mut task: Task,
+    status: &TaskStatus,
+    workflow_status_id: Option<WorkflowStatusId>,
+    reason: Option<&str>,
+    user_id: &UserId,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let before = task.clone();
+
+    // 遷移ポリシーの確認
+    status_transition_service::validate_transition(
+        repositories,
+        project_id,
+        &task.status,
+        status,
+        reason,
+    )
+    .await?;
+
+    // ステータス更新（作業日時も連動させる）
+    let now = Utc::now();
+    let dates = status_transition_service::work_dates_after_transition(
+        &task.status,
+        status,
+        WorkDates {
+            do_start_date: task.do_start_date,
+            do_end_date: task.do_end_date,
+        },
+        now,
+    );
+    task.status = status.clone();
+    task.workflow_status_id = workflow_status_id;
+    task.do_start_date = dates.do_start_date;
+    task.do_end_date = dates.do_end_date;
+
+    let changes = events::field_changes(&before, &task);
+    if changes.is_empty() {
+        return Ok(());
     }
 
+    // 更新日時を設定
+    task.updated_at = now;
+
+    // 保存
+    repositories
+        .tasks()
+        .save(project_id, &task, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::updated(EntityKind::Task, task.id)
+            .in_project(project_id)
+            .with_changes(changes)
+            .with_reason(reason)
+            .by(user_id),
+    );
+    if task.status.is_closed() && !before.status.is_closed() {
+        task_dependency_service::publish_unblocked_dependents(
+            repositories,
+            project_id,
+            &task.id,
+            user_id,
+        )
+        .await?;
+    }
     Ok(())
 }
 
@@ -305,6 +557,8 @@ where
         .find_by_id(&project_id_typed, &task_id_typed)
         .await?
     {
+        let before = task.clone();
+
         // プロジェクトIDが一致するかチェック
         // project_idチェックをコメントアウト
         /*if task.project_id.to_string() != project_id {
@@ -325,6 +579,13 @@ where
             .tasks()
             .save(&project_id_typed, &task, user_id, &now)
             .await?;
+
+        events::publish(
+            DomainEvent::updated(EntityKind::Task, task_id_typed)
+                .in_project(&project_id_typed)
+                .with_changes(events::field_changes(&before, &task))
+                .by(user_id),
+        );
     }
 
     Ok(())
@@ -1,4 +1,5 @@
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
 use chrono::Utc;
 use flequit_model::models::task_projects::task_tag::TaskTag;
 use flequit_model::types::id_types::{ProjectId, TagId, TaskId, UserId};
@@ -23,6 +24,12 @@ where
         .add(&actual_project_id, task_id, tag_id, user_id, &Utc::now())
         .await
         .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::created(EntityKind::TaskTag, task_id)
+            .in_project(&actual_project_id)
+            .related_to(tag_id)
+            .by(user_id),
+    );
     Ok(())
 }
 
@@ -41,6 +48,11 @@ where
         .remove(&actual_project_id, task_id, tag_id)
         .await
         .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::deleted(EntityKind::TaskTag, task_id)
+            .in_project(&actual_project_id)
+            .related_to(tag_id),
+    );
     Ok(())
 }
 
@@ -101,6 +113,7 @@ where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
     let actual_project_id = find_project_id_by_task_id(repositories, project_id, task_id).await?;
+    let before = get_tag_ids_by_task_id(repositories, &actual_project_id, task_id).await?;
     repositories
         .task_tags()
         .remove_all(&actual_project_id, task_id)
@@ -114,6 +127,12 @@ where
             .await
             .map_err(ServiceError::Repository)?;
     }
+    events::publish(
+        DomainEvent::updated(EntityKind::TaskTag, task_id)
+            .in_project(&actual_project_id)
+            .with_changes(vec![events::value_change("tag_ids", &before, tag_ids)])
+            .by(user_id),
+    );
     Ok(())
 }
 
@@ -126,11 +145,21 @@ where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
     let actual_project_id = find_project_id_by_task_id(repositories, project_id, task_id).await?;
+    let before = get_tag_ids_by_task_id(repositories, &actual_project_id, task_id).await?;
     repositories
         .task_tags()
         .remove_all(&actual_project_id, task_id)
         .await
         .map_err(ServiceError::Repository)?;
+    events::publish(
+        DomainEvent::deleted(EntityKind::TaskTag, task_id)
+            .in_project(&actual_project_id)
+            .with_changes(vec![events::value_change(
+                "tag_ids",
+                &before,
+                Vec::<TagId>::new(),
+            )]),
+    );
     Ok(())
 }
 
@@ -160,6 +189,11 @@ where
                     .remove(&project.id, &task_tag.task_id, tag_id)
                     .await
                     .map_err(ServiceError::Repository)?;
+                events::publish(
+                    DomainEvent::deleted(EntityKind::TaskTag, task_tag.task_id)
+                        .in_project(&project.id)
+                        .related_to(tag_id),
+                );
             }
         }
     }
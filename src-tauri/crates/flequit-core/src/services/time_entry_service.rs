@@ -0,0 +1,693 @@
+//! タイムエントリ（作業時間の記録）サービス
+//!
+//! タイマーの開始・停止、記録の編集、期間ごとの作業時間の集計を提供する。
+//! 計測中のタイマーはユーザーごとに全プロジェクトを通じて1つまでとする。
+
+use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::subtask::SubTask;
+use flequit_model::models::task_projects::task::Task;
+use flequit_model::models::task_projects::time_entry::{PartialTimeEntry, TimeEntry};
+use flequit_model::types::id_types::{ProjectId, SubTaskId, TaskId, TimeEntryId, UserId};
+use flequit_repository::repositories::base_repository_trait::Repository;
+use flequit_repository::repositories::project_relation_repository_trait::ProjectRelationRepository;
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::service_error::ServiceError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 作業時間を集計する単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeGrouping {
+    Task,
+    TaskList,
+    Project,
+    /// 1つの記録は対象タスク（サブタスクの場合はサブタスク）の全タグに計上される
+    Tag,
+    User,
+}
+
+/// 作業時間の集計条件
+#[derive(Debug, Clone)]
+pub struct TimeSummaryCondition {
+    pub group_by: TimeGrouping,
+    /// 集計期間の開始（この日時を含む）
+    pub from: DateTime<Utc>,
+    /// 集計期間の終了（この日時を含まない）
+    pub to: DateTime<Utc>,
+    /// 指定した場合はそのプロジェクトの記録だけを集計する
+    pub project_id: Option<ProjectId>,
+    /// 指定した場合はそのユーザーの記録だけを集計する
+    pub user_id: Option<UserId>,
+}
+
+/// 集計単位ごとの作業時間
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeTotal {
+    /// 集計単位のID（タスクID・タスクリストID・プロジェクトID・タグID・ユーザーID）
+    pub key: String,
+    /// 期間内の作業時間（秒）
+    pub total_seconds: i64,
+    /// 期間内に作業時間がある記録の数
+    pub entry_count: u32,
+}
+
+/// 記録を集計単位へ振り分けるためのプロジェクト内の情報
+#[derive(Debug, Default)]
+pub struct TimeEntryContext {
+    task_lists: HashMap<TaskId, String>,
+    task_tags: HashMap<TaskId, Vec<String>>,
+    subtask_tags: HashMap<SubTaskId, Vec<String>>,
+}
+
+impl TimeEntryContext {
+    pub fn new(tasks: &[Task], subtasks: &[SubTask]) -> Self {
+        Self {
+            task_lists: tasks
+                .iter()
+                .map(|task| (task.id, task.list_id.to_string()))
+                .collect(),
+            task_tags: tasks
+                .iter()
+                .map(|task| {
+                    (
+                        task.id,
+                        task.tag_ids.iter().map(|id| id.to_string()).collect(),
+                    )
+                })
+                .collect(),
+            subtask_tags: subtasks
+                .iter()
+                .map(|subtask| {
+                    let tag_ids = subtask.tag_ids.iter().map(|id| id.to_string()).collect();
+                    (subtask.id, tag_ids)
+                })
+                .collect(),
+        }
+    }
+
+    /// 記録を計上する集計単位のキー
+    fn keys_of(&self, entry: &TimeEntry, group_by: TimeGrouping) -> Vec<String> {
+        match group_by {
+            TimeGrouping::Task => vec![entry.task_id.to_string()],
+            TimeGrouping::Project => vec![entry.project_id.to_string()],
+            TimeGrouping::User => vec![entry.user_id.to_string()],
+            TimeGrouping::TaskList => self
+                .task_lists
+                .get(&entry.task_id)
+                .cloned()
+                .into_iter()
+                .collect(),
+            TimeGrouping::Tag => {
+                let tags = match entry.subtask_id {
+                    Some(subtask_id) => self.subtask_tags.get(&subtask_id),
+                    None => self.task_tags.get(&entry.task_id),
+                };
+                tags.cloned().unwrap_or_default()
+            }
+        }
+    }
+}
+
+/// 記録を集計単位ごとに合計する
+///
+/// 期間をまたぐ記録は期間内の部分だけを数え、計測中の記録は `now` まで作業しているものとする。
+/// 結果は作業時間の長い順（同じ場合はキー順）に並ぶ。
+pub fn summarize_entries(
+    entries: &[TimeEntry],
+    context: &TimeEntryContext,
+    condition: &TimeSummaryCondition,
+    now: DateTime<Utc>,
+) -> Vec<TimeTotal> {
+    let mut totals: HashMap<String, TimeTotal> = HashMap::new();
+    for entry in entries {
+        if entry.deleted
+            || condition
+                .user_id
+                .is_some_and(|user_id| user_id != entry.user_id)
+        {
+            continue;
+        }
+        let seconds = entry
+            .duration_within(condition.from, condition.to, now)
+            .num_seconds();
+        if seconds <= 0 {
+            continue;
+        }
+        for key in context.keys_of(entry, condition.group_by) {
+            let total = totals.entry(key.clone()).or_insert_with(|| TimeTotal {
+                key,
+                total_seconds: 0,
+                entry_count: 0,
+            });
+            total.total_seconds += seconds;
+            total.entry_count += 1;
+        }
+    }
+
+    let mut totals: Vec<TimeTotal> = totals.into_values().collect();
+    totals.sort_by(|a, b| {
+        b.total_seconds
+            .cmp(&a.total_seconds)
+            .then_with(|| a.key.cmp(&b.key))
+    });
+    totals
+}
+
+pub async fn get_time_entry<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    entry_id: &TimeEntryId,
+) -> Result<Option<TimeEntry>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    Ok(repositories
+        .time_entries()
+        .find_by_id(project_id, entry_id)
+        .await?)
+}
+
+/// プロジェクト内の記録を取得する（`task_id` を指定した場合はそのタスクの記録のみ）
+pub async fn list_time_entries<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: Option<&TaskId>,
+) -> Result<Vec<TimeEntry>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let entries = repositories.time_entries().find_all(project_id).await?;
+    Ok(entries
+        .into_iter()
+        .filter(|entry| task_id.is_none_or(|task_id| entry.task_id == *task_id))
+        .collect())
+}
+
+/// ユーザーの計測中の記録を全プロジェクトから探す
+pub async fn find_running_time_entry<R>(
+    repositories: &R,
+    user_id: &UserId,
+) -> Result<Option<TimeEntry>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    for project in repositories.projects().find_all().await? {
+        let running = repositories
+            .time_entries()
+            .find_all(&project.id)
+            .await?
+            .into_iter()
+            .find(|entry| entry.user_id == *user_id && entry.is_running());
+        if running.is_some() {
+            return Ok(running);
+        }
+    }
+    Ok(None)
+}
+
+/// タイマーを開始し、計測中の記録を作成する
+///
+/// 既に計測中のタイマーがある場合はエラーとする（先に停止する必要がある）。
+pub async fn start_timer<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    task_id: &TaskId,
+    subtask_id: Option<&SubTaskId>,
+    note: Option<String>,
+    user_id: &UserId,
+) -> Result<TimeEntry, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    if let Some(running) = find_running_time_entry(repositories, user_id).await? {
+        return Err(ServiceError::ValidationError(format!(
+            "A timer is already running for task {}",
+            running.task_id
+        )));
+    }
+
+    let now = Utc::now();
+    let entry = TimeEntry {
+        id: TimeEntryId::new(),
+        project_id: *project_id,
+        task_id: *task_id,
+        subtask_id: subtask_id.copied(),
+        user_id: *user_id,
+        started_at: now,
+        ended_at: None,
+        note,
+        created_at: now,
+        updated_at: now,
+        deleted: false,
+        updated_by: *user_id,
+    };
+    validate_target(repositories, &entry).await?;
+    repositories
+        .time_entries()
+        .save(project_id, &entry, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::created(EntityKind::TimeEntry, entry.id)
+            .in_project(project_id)
+            .related_to(entry.task_id)
+            .by(user_id),
+    );
+    Ok(entry)
+}
+
+/// 計測中のタイマーを停止する
+///
+/// 停止した記録を返す。計測中のタイマーがない場合は `None`。
+pub async fn stop_timer<R>(
+    repositories: &R,
+    user_id: &UserId,
+) -> Result<Option<TimeEntry>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(before) = find_running_time_entry(repositories, user_id).await? else {
+        return Ok(None);
+    };
+
+    let now = Utc::now();
+    let mut entry = before.clone();
+    entry.ended_at = Some(now.max(entry.started_at));
+    entry.updated_at = now;
+    entry.updated_by = *user_id;
+    repositories
+        .time_entries()
+        .save(&entry.project_id, &entry, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::updated(EntityKind::TimeEntry, entry.id)
+            .in_project(&entry.project_id)
+            .related_to(entry.task_id)
+            .with_changes(events::field_changes(&before, &entry))
+            .by(user_id),
+    );
+    Ok(Some(entry))
+}
+
+/// 終了済みの記録を手動で追加する
+pub async fn create_time_entry<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    entry: &TimeEntry,
+    user_id: &UserId,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    if entry.is_running() {
+        return Err(ServiceError::ValidationError(
+            "Use start_timer to record a running time entry".to_string(),
+        ));
+    }
+    let now = Utc::now();
+    let mut new_data = entry.clone();
+    new_data.project_id = *project_id;
+    new_data.created_at = now;
+    new_data.updated_at = now;
+    new_data.updated_by = *user_id;
+    validate_period(&new_data)?;
+    validate_target(repositories, &new_data).await?;
+
+    repositories
+        .time_entries()
+        .save(project_id, &new_data, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::created(EntityKind::TimeEntry, new_data.id)
+            .in_project(project_id)
+            .related_to(new_data.task_id)
+            .by(user_id),
+    );
+    Ok(())
+}
+
+/// 記録の開始・終了日時やメモを更新する
+pub async fn update_time_entry<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    entry_id: &TimeEntryId,
+    patch: &PartialTimeEntry,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(before) = repositories
+        .time_entries()
+        .find_by_id(project_id, entry_id)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    let mut entry = before.clone();
+    if let Some(task_id) = patch.task_id {
+        entry.task_id = task_id;
+    }
+    if let Some(subtask_id) = patch.subtask_id {
+        entry.subtask_id = subtask_id;
+    }
+    if let Some(started_at) = patch.started_at {
+        entry.started_at = started_at;
+    }
+    if let Some(ended_at) = patch.ended_at {
+        entry.ended_at = ended_at;
+    }
+    if let Some(note) = &patch.note {
+        entry.note = note.clone();
+    }
+    // 計測中の記録を再開することはできない
+    if entry.is_running() && !before.is_running() {
+        return Err(ServiceError::ValidationError(
+            "A stopped time entry cannot be resumed".to_string(),
+        ));
+    }
+    validate_period(&entry)?;
+    if entry.task_id != before.task_id || entry.subtask_id != before.subtask_id {
+        validate_target(repositories, &entry).await?;
+    }
+
+    let now = Utc::now();
+    entry.updated_at = now;
+    entry.updated_by = *user_id;
+    repositories
+        .time_entries()
+        .save(project_id, &entry, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::updated(EntityKind::TimeEntry, entry_id)
+            .in_project(project_id)
+            .related_to(entry.task_id)
+            .with_changes(events::field_changes(&before, &entry))
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+/// 記録を削除する
+pub async fn delete_time_entry<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    entry_id: &TimeEntryId,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    // 取り消せるよう、削除前の状態を控える
+    let Some(before) = repositories
+        .time_entries()
+        .find_by_id(project_id, entry_id)
+        .await?
+    else {
+        return Ok(false);
+    };
+    repositories
+        .time_entries()
+        .delete(project_id, entry_id)
+        .await?;
+
+    events::publish(
+        DomainEvent::deleted(EntityKind::TimeEntry, entry_id)
+            .in_project(project_id)
+            .related_to(before.task_id)
+            .with_snapshot(&before)
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+/// 期間内の作業時間を集計する
+pub async fn summarize_time<R>(
+    repositories: &R,
+    condition: &TimeSummaryCondition,
+) -> Result<Vec<TimeTotal>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    if condition.from >= condition.to {
+        return Err(ServiceError::InvalidArgument(
+            "The end of the period must be after its start".to_string(),
+        ));
+    }
+
+    let project_ids = match condition.project_id {
+        Some(project_id) => vec![project_id],
+        None => repositories
+            .projects()
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|project| project.id)
+            .collect(),
+    };
+
+    let now = Utc::now();
+    let mut entries = Vec::new();
+    let mut context = TimeEntryContext::default();
+    for project_id in &project_ids {
+        let project_entries = repositories.time_entries().find_all(project_id).await?;
+        if project_entries.is_empty() {
+            continue;
+        }
+        if matches!(
+            condition.group_by,
+            TimeGrouping::TaskList | TimeGrouping::Tag
+        ) {
+            let project_context = load_context(repositories, project_id).await?;
+            context.task_lists.extend(project_context.task_lists);
+            context.task_tags.extend(project_context.task_tags);
+            context.subtask_tags.extend(project_context.subtask_tags);
+        }
+        entries.extend(project_entries);
+    }
+
+    Ok(summarize_entries(&entries, &context, condition, now))
+}
+
+/// プロジェクト内のタスク・サブタスクとタグの関連付けを読み込む
+async fn load_context<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+) -> Result<TimeEntryContext, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let tasks = repositories.tasks().find_all(project_id).await?;
+    let subtasks = repositories.sub_tasks().find_all(project_id).await?;
+    let mut context = TimeEntryContext::new(&tasks, &subtasks);
+
+    // タグはモデルのIDリストではなく関連テーブルを正とする
+    let mut task_tags: HashMap<TaskId, Vec<String>> = HashMap::new();
+    for relation in repositories.task_tags().find_all(project_id).await? {
+        task_tags
+            .entry(relation.task_id)
+            .or_default()
+            .push(relation.tag_id.to_string());
+    }
+    let mut subtask_tags: HashMap<SubTaskId, Vec<String>> = HashMap::new();
+    for relation in repositories.subtask_tags().find_all(project_id).await? {
+        subtask_tags
+            .entry(relation.subtask_id)
+            .or_default()
+            .push(relation.tag_id.to_string());
+    }
+    context.task_tags.extend(task_tags);
+    context.subtask_tags.extend(subtask_tags);
+    Ok(context)
+}
+
+fn validate_period(entry: &TimeEntry) -> Result<(), ServiceError> {
+    match entry.ended_at {
+        Some(ended_at) if ended_at < entry.started_at => Err(ServiceError::ValidationError(
+            "The end of a time entry must not be before its start".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// 記録の対象タスク（・サブタスク）がプロジェクトに存在することを確認する
+async fn validate_target<R>(repositories: &R, entry: &TimeEntry) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    if repositories
+        .tasks()
+        .find_by_id(&entry.project_id, &entry.task_id)
+        .await?
+        .is_none()
+    {
+        return Err(ServiceError::NotFound(format!(
+            "Task not found: {}",
+            entry.task_id
+        )));
+    }
+    if let Some(subtask_id) = &entry.subtask_id {
+        let subtask = repositories
+            .sub_tasks()
+            .find_by_id(&entry.project_id, subtask_id)
+            .await?;
+        if subtask.is_none_or(|subtask| subtask.task_id != entry.task_id) {
+            return Err(ServiceError::NotFound(format!(
+                "Subtask {} not found in task {}",
+                subtask_id, entry.task_id
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use flequit_model::types::id_types::{TagId, TaskListId};
+    use flequit_model::types::task_types::TaskStatus;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 9, 1, hour, minute, 0).unwrap()
+    }
+
+    fn entry(
+        task_id: TaskId,
+        user_id: UserId,
+        started_at: DateTime<Utc>,
+        ended_at: Option<DateTime<Utc>>,
+    ) -> TimeEntry {
+        TimeEntry {
+            id: TimeEntryId::new(),
+            project_id: ProjectId::from("3f1c2b9e-7a4d-4c2e-9b8f-1d2e3f4a5b6c"),
+            task_id,
+            subtask_id: None,
+            user_id,
+            started_at,
+            ended_at,
+            note: None,
+            created_at: started_at,
+            updated_at: started_at,
+            deleted: false,
+            updated_by: user_id,
+        }
+    }
+
+    fn condition(group_by: TimeGrouping) -> TimeSummaryCondition {
+        TimeSummaryCondition {
+            group_by,
+            from: at(9, 0),
+            to: at(18, 0),
+            project_id: None,
+            user_id: None,
+        }
+    }
+
+    #[test]
+    fn test_summarize_clips_entries_to_period() {
+        let task_id = TaskId::new();
+        let user_id = UserId::new();
+        let entries = vec![
+            // 期間の開始前から始まった記録は期間内の30分だけ数える
+            entry(task_id, user_id, at(8, 0), Some(at(9, 30))),
+            entry(task_id, user_id, at(10, 0), Some(at(11, 0))),
+            // 期間外の記録は数えない
+            entry(task_id, user_id, at(19, 0), Some(at(20, 0))),
+            // 計測中の記録は現在時刻まで数える
+            entry(task_id, user_id, at(17, 0), None),
+        ];
+
+        let totals = summarize_entries(
+            &entries,
+            &TimeEntryContext::default(),
+            &condition(TimeGrouping::Task),
+            at(17, 15),
+        );
+        assert_eq!(
+            totals,
+            vec![TimeTotal {
+                key: task_id.to_string(),
+                total_seconds: (30 + 60 + 15) * 60,
+                entry_count: 3,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_summarize_groups_by_list_tag_and_user() {
+        let list_id = TaskListId::new();
+        let tag_a = TagId::new();
+        let tag_b = TagId::new();
+        let (alice, bob) = (UserId::new(), UserId::new());
+
+        let tagged = Task {
+            id: TaskId::new(),
+            project_id: ProjectId::new(),
+            list_id,
+            title: "タグ付きのタスク".to_string(),
+            description: None,
+            status: TaskStatus::InProgress,
+            workflow_status_id: None,
+            priority: 0,
+            plan_start_date: None,
+            plan_end_date: None,
+            do_start_date: None,
+            do_end_date: None,
+            is_range_date: None,
+            recurrence_rule: None,
+            order_index: 0,
+            is_archived: false,
+            assigned_user_ids: vec![],
+            tag_ids: vec![tag_a, tag_b],
+            created_at: at(0, 0),
+            updated_at: at(0, 0),
+            deleted: false,
+            updated_by: alice,
+        };
+        let mut untagged = tagged.clone();
+        untagged.id = TaskId::new();
+        untagged.tag_ids = vec![];
+        let context = TimeEntryContext::new(&[tagged.clone(), untagged.clone()], &[]);
+
+        let entries = vec![
+            entry(tagged.id, alice, at(9, 0), Some(at(10, 0))),
+            entry(untagged.id, bob, at(9, 0), Some(at(9, 30))),
+        ];
+
+        let by_list = summarize_entries(
+            &entries,
+            &context,
+            &condition(TimeGrouping::TaskList),
+            at(18, 0),
+        );
+        assert_eq!(by_list.len(), 1);
+        assert_eq!(by_list[0].key, list_id.to_string());
+        assert_eq!(by_list[0].total_seconds, 90 * 60);
+
+        // 複数のタグが付いた記録はそれぞれのタグに計上され、タグのない記録は計上されない
+        let by_tag =
+            summarize_entries(&entries, &context, &condition(TimeGrouping::Tag), at(18, 0));
+        assert_eq!(by_tag.len(), 2);
+        assert!(by_tag.iter().all(|total| total.total_seconds == 60 * 60));
+
+        let mut only_bob = condition(TimeGrouping::User);
+        only_bob.user_id = Some(bob);
+        let by_user = summarize_entries(&entries, &context, &only_bob, at(18, 0));
+        assert_eq!(
+            by_user,
+            vec![TimeTotal {
+                key: bob.to_string(),
+                total_seconds: 30 * 60,
+                entry_count: 1,
+            }]
+        );
+    }
+}
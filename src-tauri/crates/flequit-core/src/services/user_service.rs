@@ -1,6 +1,7 @@
 use chrono::Utc;
 
 use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
 use flequit_model::models::accounts::account::Account;
 use flequit_model::models::users::user::User;
 use flequit_model::types::id_types::UserId;
@@ -37,6 +38,7 @@ where
 
     repositories.users().save(&new_data, user_id, &now).await?;
 
+    events::publish(DomainEvent::created(EntityKind::User, new_data.id).by(user_id));
     Ok(())
 }
 
@@ -77,8 +79,10 @@ pub async fn update_user<R>(
 where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
+    let before = repositories.users().find_by_id(&user.id).await?;
     let now = Utc::now();
     repositories.users().save(user, user_id, &now).await?;
+    publish_user_saved(before.as_ref(), user, user_id);
     Ok(())
 }
 
@@ -89,6 +93,7 @@ where
     R: InfrastructureRepositoriesTrait + Send + Sync,
 {
     repositories.users().delete(user_id).await?;
+    events::publish(DomainEvent::deleted(EntityKind::User, user_id));
     Ok(())
 }
 
@@ -111,6 +116,7 @@ where
     }
 
     // プロフィール更新
+    let before = repositories.users().find_by_id(&user.id).await?;
     let mut updated_user = user.clone();
     let now = Utc::now();
     updated_user.updated_at = now;
@@ -119,6 +125,7 @@ where
         .users()
         .save(&updated_user, user_id, &now)
         .await?;
+    publish_user_saved(before.as_ref(), &updated_user, user_id);
     Ok(())
 }
 
@@ -191,6 +198,7 @@ where
     }
 
     if let Some(mut user) = repositories.users().find_by_id(&user_id_typed).await? {
+        let before = user.clone();
         if let Some(dn) = input.display_name {
             user.display_name = dn;
         }
@@ -210,6 +218,7 @@ where
             .users()
             .save(&user, updating_user_id, &now)
             .await?;
+        publish_user_saved(Some(&before), &user, updating_user_id);
         Ok(())
     } else {
         Err(ServiceError::NotFound("User not found".to_string()))
@@ -247,3 +256,13 @@ where
         Err(ServiceError::NotFound("User not found".to_string()))
     }
 }
+
+/// ユーザーの保存を通知する（保存前が存在しなければ作成として扱う）
+fn publish_user_saved(before: Option<&User>, after: &User, actor: &UserId) {
+    let event = match before {
+        Some(before) => DomainEvent::updated(EntityKind::User, after.id)
+            .with_changes(events::field_changes(before, after)),
+        None => DomainEvent::created(EntityKind::User, after.id),
+    };
+    events::publish(event.by(actor));
+}
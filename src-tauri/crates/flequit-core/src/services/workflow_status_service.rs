@@ -0,0 +1,380 @@
+//! ワークフローステータスサービス
+//!
+//! プロジェクトごとのワークフローステータス（カンバンの列）の定義と、
+//! タスクからの参照の整合性を扱う。
+//!
+//! タスクがワークフローステータスを参照している間、タスクの`status`は常にその分類と一致させる。
+//! 組み込みのステータスを直接変更して分類が合わなくなった場合は参照を外す。
+
+use crate::InfrastructureRepositoriesTrait;
+use crate::events::{self, DomainEvent, EntityKind};
+use chrono::Utc;
+use flequit_model::models::task_projects::task::{PartialTask, Task};
+use flequit_model::models::task_projects::workflow_status::{
+    PartialWorkflowStatus, WorkflowStatus,
+};
+use flequit_model::types::id_types::{ProjectId, UserId, WorkflowStatusId};
+use flequit_model::types::task_types::TaskStatus;
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::service_error::ServiceError;
+use partially::Partial;
+
+/// ステータスを`status`に変更したあとも、参照中のワークフローステータスを維持できるか
+pub fn keeps_reference(workflow_status: Option<&WorkflowStatus>, status: &TaskStatus) -> bool {
+    workflow_status.is_some_and(|workflow_status| {
+        !workflow_status.deleted && workflow_status.category == *status
+    })
+}
+
+/// 定義の内容を確認する（名前が空でないこと、プロジェクト内で名前が重複しないこと）
+pub fn check_definition(
+    statuses: &[WorkflowStatus],
+    candidate: &WorkflowStatus,
+) -> Result<(), ServiceError> {
+    let name = candidate.name.trim();
+    if name.is_empty() {
+        return Err(ServiceError::ValidationError(
+            "Workflow status name must not be empty".to_string(),
+        ));
+    }
+    let duplicated = statuses.iter().any(|other| {
+        other.id != candidate.id && !other.deleted && other.name.trim().eq_ignore_ascii_case(name)
+    });
+    if duplicated {
+        return Err(ServiceError::ValidationError(format!(
+            "Workflow status \"{}\" already exists",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// 参照先として使えるワークフローステータスを取得する（存在しなければ`ValidationError`）
+pub async fn find_active_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    workflow_status_id: &WorkflowStatusId,
+) -> Result<WorkflowStatus, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    repositories
+        .workflow_statuses()
+        .find_by_id(project_id, workflow_status_id)
+        .await?
+        .filter(|workflow_status| !workflow_status.deleted)
+        .ok_or_else(|| {
+            ServiceError::ValidationError(format!(
+                "Workflow status not found: {}",
+                workflow_status_id
+            ))
+        })
+}
+
+/// タスクの参照に合わせてステータスを決める
+///
+/// 参照がなければ`status`をそのまま使い、参照があればその分類を返す。
+pub async fn status_for_reference<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    workflow_status_id: Option<&WorkflowStatusId>,
+    status: &TaskStatus,
+) -> Result<TaskStatus, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match workflow_status_id {
+        Some(id) => Ok(find_active_status(repositories, project_id, id)
+            .await?
+            .category),
+        None => Ok(status.clone()),
+    }
+}
+
+/// 組み込みのステータスを`status`に変更したあとの参照を求める
+///
+/// 参照中のワークフローステータスの分類が`status`と一致しなくなる場合は参照を外す。
+pub async fn reference_after_status_change<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    current: Option<&WorkflowStatusId>,
+    status: &TaskStatus,
+) -> Result<Option<WorkflowStatusId>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(current) = current else {
+        return Ok(None);
+    };
+    let workflow_status = repositories
+        .workflow_statuses()
+        .find_by_id(project_id, current)
+        .await?;
+    Ok(keeps_reference(workflow_status.as_ref(), status).then_some(*current))
+}
+
+/// タスクの部分更新をワークフローステータスの参照と整合させる
+///
+/// - 参照を設定する場合、ステータスを参照先の分類に合わせる（分類と異なるステータスの同時指定はエラー）
+/// - ステータスだけを変更する場合、分類が合わなくなる参照を外す
+pub async fn reconcile_task_patch<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    before: &Task,
+    patch: &mut PartialTask,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    match &patch.workflow_status_id {
+        Some(Some(id)) => {
+            let workflow_status = find_active_status(repositories, project_id, id).await?;
+            match &patch.status {
+                Some(status) if *status != workflow_status.category => {
+                    return Err(ServiceError::ValidationError(format!(
+                        "Status {} does not match the category {} of workflow status \"{}\"",
+                        status, workflow_status.category, workflow_status.name
+                    )));
+                }
+                _ => patch.status = Some(workflow_status.category),
+            }
+        }
+        Some(None) => {}
+        None => {
+            if let Some(status) = &patch.status {
+                let reference = reference_after_status_change(
+                    repositories,
+                    project_id,
+                    before.workflow_status_id.as_ref(),
+                    status,
+                )
+                .await?;
+                if reference != before.workflow_status_id {
+                    patch.workflow_status_id = Some(reference);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn list_workflow_statuses<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+) -> Result<Vec<WorkflowStatus>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    Ok(repositories
+        .workflow_statuses()
+        .find_all(project_id)
+        .await?)
+}
+
+pub async fn create_workflow_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    workflow_status: &WorkflowStatus,
+    user_id: &UserId,
+) -> Result<(), ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let now = Utc::now();
+    let mut new_data = workflow_status.clone();
+    new_data.project_id = *project_id;
+    new_data.name = new_data.name.trim().to_string();
+    new_data.created_at = now;
+    new_data.updated_at = now;
+    new_data.deleted = false;
+    new_data.updated_by = *user_id;
+
+    let statuses = repositories
+        .workflow_statuses()
+        .find_all(project_id)
+        .await?;
+    check_definition(&statuses, &new_data)?;
+
+    repositories
+        .workflow_statuses()
+        .save(project_id, &new_data, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::created(EntityKind::WorkflowStatus, new_data.id)
+            .in_project(project_id)
+            .by(user_id),
+    );
+    Ok(())
+}
+
+/// ワークフローステータスを更新する
+///
+/// タスクが参照している間は分類を変更できない（参照しているタスクのステータスが食い違うため）。
+pub async fn update_workflow_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    workflow_status_id: &WorkflowStatusId,
+    patch: &PartialWorkflowStatus,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(before) = repositories
+        .workflow_statuses()
+        .find_by_id(project_id, workflow_status_id)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    let mut workflow_status = before.clone();
+    workflow_status.apply_some(patch.clone());
+    workflow_status.name = workflow_status.name.trim().to_string();
+    let changes = events::field_changes(&before, &workflow_status);
+    if changes.is_empty() {
+        return Ok(false);
+    }
+
+    let statuses = repositories
+        .workflow_statuses()
+        .find_all(project_id)
+        .await?;
+    check_definition(&statuses, &workflow_status)?;
+    if workflow_status.category != before.category
+        && !referencing_tasks(repositories, project_id, workflow_status_id)
+            .await?
+            .is_empty()
+    {
+        return Err(ServiceError::ValidationError(format!(
+            "Cannot change the category of workflow status \"{}\" while tasks use it",
+            before.name
+        )));
+    }
+
+    let now = Utc::now();
+    workflow_status.updated_at = now;
+    workflow_status.updated_by = *user_id;
+    repositories
+        .workflow_statuses()
+        .save(project_id, &workflow_status, user_id, &now)
+        .await?;
+
+    events::publish(
+        DomainEvent::updated(EntityKind::WorkflowStatus, workflow_status_id)
+            .in_project(project_id)
+            .with_changes(changes)
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+/// ワークフローステータスを削除する
+///
+/// タスクが参照している間は削除できない（先に別のステータスへ移す）。
+pub async fn delete_workflow_status<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    workflow_status_id: &WorkflowStatusId,
+    user_id: &UserId,
+) -> Result<bool, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    let Some(before) = repositories
+        .workflow_statuses()
+        .find_by_id(project_id, workflow_status_id)
+        .await?
+    else {
+        return Ok(false);
+    };
+    let in_use = referencing_tasks(repositories, project_id, workflow_status_id).await?;
+    if !in_use.is_empty() {
+        return Err(ServiceError::ValidationError(format!(
+            "Cannot delete workflow status \"{}\" while {} task(s) use it",
+            before.name,
+            in_use.len()
+        )));
+    }
+
+    repositories
+        .workflow_statuses()
+        .delete(project_id, workflow_status_id)
+        .await?;
+
+    events::publish(
+        DomainEvent::deleted(EntityKind::WorkflowStatus, workflow_status_id)
+            .in_project(project_id)
+            .with_snapshot(&before)
+            .by(user_id),
+    );
+    Ok(true)
+}
+
+/// ワークフローステータスを参照している（削除されていない）タスク
+async fn referencing_tasks<R>(
+    repositories: &R,
+    project_id: &ProjectId,
+    workflow_status_id: &WorkflowStatusId,
+) -> Result<Vec<Task>, ServiceError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+{
+    Ok(repositories
+        .tasks()
+        .find_all(project_id)
+        .await?
+        .into_iter()
+        .filter(|task| !task.deleted && task.workflow_status_id == Some(*workflow_status_id))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workflow_status(name: &str, category: TaskStatus) -> WorkflowStatus {
+        let now = Utc::now();
+        WorkflowStatus {
+            id: WorkflowStatusId::new(),
+            project_id: ProjectId::new(),
+            name: name.to_string(),
+            color: None,
+            order_index: 0,
+            category,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        }
+    }
+
+    #[test]
+    fn test_keeps_reference_only_for_matching_category() {
+        let review = workflow_status("Review", TaskStatus::InProgress);
+        assert!(keeps_reference(Some(&review), &TaskStatus::InProgress));
+        assert!(!keeps_reference(Some(&review), &TaskStatus::Completed));
+        assert!(!keeps_reference(None, &TaskStatus::InProgress));
+
+        let mut removed = review.clone();
+        removed.deleted = true;
+        assert!(!keeps_reference(Some(&removed), &TaskStatus::InProgress));
+    }
+
+    #[test]
+    fn test_check_definition_rejects_blank_and_duplicate_names() {
+        let review = workflow_status("Review", TaskStatus::InProgress);
+        let statuses = vec![review.clone()];
+
+        assert!(
+            check_definition(&statuses, &workflow_status("QA", TaskStatus::InProgress)).is_ok()
+        );
+        assert!(check_definition(&statuses, &workflow_status("  ", TaskStatus::Waiting)).is_err());
+        assert!(
+            check_definition(&statuses, &workflow_status(" review ", TaskStatus::Waiting)).is_err()
+        );
+        // 自分自身との重複は対象外
+        assert!(check_definition(&statuses, &review).is_ok());
+    }
+}
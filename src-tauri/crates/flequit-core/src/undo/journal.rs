@@ -0,0 +1,324 @@
+//! セッションごとの取り消し・やり直し履歴
+
+use super::operation::UndoOperation;
+use crate::events::{ChangeKind, DomainEvent, EntityKind, EventOrigin};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::{Mutex, OnceLock};
+
+/// セッションごとに保持する取り消し履歴の上限
+///
+/// 上限を超えた場合は古いステップから破棄する。
+const DEFAULT_DEPTH: usize = 100;
+
+static GLOBAL_JOURNAL: OnceLock<UndoJournal> = OnceLock::new();
+
+tokio::task_local! {
+    static UNDO_SESSION: Option<String>;
+}
+
+/// 1回の取り消し・やり直しで実行する操作のまとまり
+#[derive(Debug, Clone, PartialEq)]
+pub struct UndoStep {
+    /// 操作の起点となったエンティティの種類
+    pub entity: EntityKind,
+    /// 操作の起点となった変更の種類
+    pub change: ChangeKind,
+    /// 実行順に並んだ操作
+    pub operations: Vec<UndoOperation>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// 画面に表示するためのステップの概要
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UndoStepSummary {
+    pub entity: EntityKind,
+    pub change: ChangeKind,
+    pub operation_count: usize,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// セッションで次に取り消し・やり直しされるステップ
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UndoStatus {
+    pub undo: Option<UndoStepSummary>,
+    pub redo: Option<UndoStepSummary>,
+}
+
+impl UndoStep {
+    /// 作業単位で発行されたイベントを打ち消すステップを組み立てる
+    ///
+    /// 後に起きた変更から順に戻すため、操作はイベントの逆順に並ぶ。
+    /// 記録対象の変更がない場合は `Ok(None)`、一部でも戻せない変更が含まれる場合は `Err` を返す。
+    pub fn from_events(events: &[DomainEvent]) -> Result<Option<Self>, String> {
+        let mut operations = Vec::new();
+        for event in events.iter().rev() {
+            if let Some(operation) = UndoOperation::inverse_of(event)? {
+                operations.push(operation);
+            }
+        }
+        let Some(first) = events.iter().find(|event| event.project_id.is_some()) else {
+            return Ok(None);
+        };
+        if operations.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            entity: first.entity,
+            change: first.change,
+            operations,
+            recorded_at: Utc::now(),
+        }))
+    }
+
+    /// 同じ起点のまま操作を差し替えたステップ（取り消し後のやり直し用など）
+    pub fn with_operations(&self, operations: Vec<UndoOperation>) -> Self {
+        Self {
+            entity: self.entity,
+            change: self.change,
+            operations,
+            recorded_at: Utc::now(),
+        }
+    }
+
+    pub fn summary(&self) -> UndoStepSummary {
+        UndoStepSummary {
+            entity: self.entity,
+            change: self.change,
+            operation_count: self.operations.len(),
+            recorded_at: self.recorded_at,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct SessionHistory {
+    undo: VecDeque<UndoStep>,
+    redo: Vec<UndoStep>,
+}
+
+/// セッションごとの取り消し・やり直し履歴
+#[derive(Debug)]
+pub struct UndoJournal {
+    depth: usize,
+    sessions: Mutex<HashMap<String, SessionHistory>>,
+}
+
+impl Default for UndoJournal {
+    fn default() -> Self {
+        Self::new(DEFAULT_DEPTH)
+    }
+}
+
+impl UndoJournal {
+    pub fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// アプリケーション全体で共有する履歴
+    pub fn global() -> &'static UndoJournal {
+        GLOBAL_JOURNAL.get_or_init(UndoJournal::default)
+    }
+
+    fn with_history<T>(&self, session: &str, f: impl FnOnce(&mut SessionHistory) -> T) -> T {
+        let mut sessions = self
+            .sessions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(sessions.entry(session.to_string()).or_default())
+    }
+
+    /// 新しい操作を記録する（やり直し履歴は破棄される）
+    pub fn record(&self, session: &str, step: UndoStep) {
+        self.with_history(session, |history| {
+            history.redo.clear();
+            self.push(&mut history.undo, step);
+        });
+    }
+
+    /// 次に取り消すステップを取り出す
+    pub fn pop_undo(&self, session: &str) -> Option<UndoStep> {
+        self.with_history(session, |history| history.undo.pop_back())
+    }
+
+    /// 次にやり直すステップを取り出す
+    pub fn pop_redo(&self, session: &str) -> Option<UndoStep> {
+        self.with_history(session, |history| history.redo.pop())
+    }
+
+    /// 取り消し履歴へステップを戻す（やり直し後、または取り消しに失敗した場合）
+    pub fn push_undo(&self, session: &str, step: UndoStep) {
+        self.with_history(session, |history| self.push(&mut history.undo, step));
+    }
+
+    /// やり直し履歴へステップを積む（取り消し後、またはやり直しに失敗した場合）
+    pub fn push_redo(&self, session: &str, step: UndoStep) {
+        self.with_history(session, |history| history.redo.push(step));
+    }
+
+    pub fn status(&self, session: &str) -> UndoStatus {
+        self.with_history(session, |history| UndoStatus {
+            undo: history.undo.back().map(UndoStep::summary),
+            redo: history.redo.last().map(UndoStep::summary),
+        })
+    }
+
+    /// セッションの履歴を破棄する（ウィンドウを閉じた場合など）
+    pub fn clear_session(&self, session: &str) {
+        self.sessions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(session);
+    }
+
+    fn push(&self, undo: &mut VecDeque<UndoStep>, step: UndoStep) {
+        undo.push_back(step);
+        while undo.len() > self.depth {
+            undo.pop_front();
+        }
+    }
+}
+
+/// `work` の中で行われた書き込みを `session` の履歴に記録する
+pub async fn in_session<F: Future>(session: impl Into<String>, work: F) -> F::Output {
+    UNDO_SESSION.scope(Some(session.into()), work).await
+}
+
+/// `work` の中で行われた書き込みを記録しない（取り消し・やり直しの実行中など）
+pub async fn without_session<F: Future>(work: F) -> F::Output {
+    UNDO_SESSION.scope(None, work).await
+}
+
+/// 現在のタスクで書き込みを記録するセッション
+pub fn current_session() -> Option<String> {
+    UNDO_SESSION.try_with(Clone::clone).ok().flatten()
+}
+
+/// 作業単位で発行されたイベントを現在のセッションの履歴に記録する
+///
+/// セッション外の書き込みや同期による変更は記録しない。
+pub fn record_events(events: &[DomainEvent]) {
+    let Some(session) = current_session() else {
+        return;
+    };
+    if events
+        .iter()
+        .any(|event| event.origin != EventOrigin::Local)
+    {
+        return;
+    }
+    match UndoStep::from_events(events) {
+        Ok(Some(step)) => UndoJournal::global().record(&session, step),
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Operation is not recorded for undo: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::value_change;
+    use flequit_model::types::id_types::ProjectId;
+
+    fn step(entity_id: &str) -> UndoStep {
+        let project_id = ProjectId::new();
+        UndoStep::from_events(&[
+            DomainEvent::created(EntityKind::Task, entity_id).in_project(&project_id)
+        ])
+        .unwrap()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_step_inverts_events_in_reverse_order() {
+        let project_id = ProjectId::new();
+        let events = vec![
+            DomainEvent::deleted(EntityKind::TaskList, "list-1").in_project(&project_id),
+            DomainEvent::deleted(EntityKind::Task, "task-1").in_project(&project_id),
+            DomainEvent::updated(EntityKind::User, "user-1"),
+        ];
+
+        let step = UndoStep::from_events(&events).unwrap().unwrap();
+        assert_eq!(step.entity, EntityKind::TaskList);
+        assert_eq!(step.change, ChangeKind::Deleted);
+        let ids: Vec<_> = step
+            .operations
+            .iter()
+            .map(|operation| operation.target().entity_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["task-1", "list-1"]);
+
+        // 記録対象の変更がなければステップにならない
+        assert_eq!(UndoStep::from_events(&events[2..]).unwrap(), None);
+
+        // 戻せない変更を含む場合はステップ全体を記録しない
+        let mut partial = events.clone();
+        partial.push(DomainEvent::updated(EntityKind::Task, "task-2").in_project(&project_id));
+        assert!(UndoStep::from_events(&partial).is_err());
+        partial.pop();
+        partial.push(
+            DomainEvent::updated(EntityKind::Task, "task-2")
+                .in_project(&project_id)
+                .with_changes(vec![value_change("title", "a", "b")]),
+        );
+        assert_eq!(
+            UndoStep::from_events(&partial)
+                .unwrap()
+                .unwrap()
+                .operations
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_journal_keeps_history_per_session() {
+        let journal = UndoJournal::new(2);
+        journal.record("main", step("task-1"));
+        journal.record("main", step("task-2"));
+        journal.record("main", step("task-3"));
+        journal.record("other", step("task-4"));
+
+        // 上限を超えた古いステップは破棄される
+        let undone = journal.pop_undo("main").unwrap();
+        assert_eq!(undone.operations[0].target().entity_id, "task-3");
+        journal.push_redo("main", undone);
+        assert_eq!(
+            journal.pop_undo("main").unwrap().operations[0]
+                .target()
+                .entity_id,
+            "task-2"
+        );
+        assert_eq!(journal.pop_undo("main"), None);
+
+        let status = journal.status("main");
+        assert_eq!(status.undo, None);
+        assert_eq!(status.redo.unwrap().operation_count, 1);
+
+        // 新しい操作を記録するとやり直し履歴は破棄される
+        journal.record("main", step("task-5"));
+        assert_eq!(journal.pop_redo("main"), None);
+
+        assert!(journal.status("other").undo.is_some());
+        journal.clear_session("other");
+        assert_eq!(journal.status("other"), UndoStatus::default());
+    }
+
+    #[tokio::test]
+    async fn test_session_scope() {
+        assert_eq!(current_session(), None);
+        in_session("main", async {
+            assert_eq!(current_session().as_deref(), Some("main"));
+            without_session(async {
+                assert_eq!(current_session(), None);
+            })
+            .await;
+        })
+        .await;
+    }
+}
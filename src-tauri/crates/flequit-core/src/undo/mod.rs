@@ -0,0 +1,18 @@
+//! 取り消し・やり直し（Undo/Redo）
+//!
+//! Facadeの書き込みは作業単位（Unit of Work）として実行され、その中で発行された
+//! ドメインイベントから逆操作を組み立てて1つのステップとして記録する。
+//! 複数のエンティティにまたがる操作（タスクリストの削除やタグの一括変更など）も
+//! 1回の取り消しでまとめて元に戻る。
+//!
+//! 履歴はセッション（ウィンドウ）ごとに分かれており、`in_session` の中で
+//! 実行された書き込みだけが記録される。同期による変更や取り消し・やり直し自体は記録しない。
+
+mod journal;
+mod operation;
+
+pub use journal::{
+    UndoJournal, UndoStatus, UndoStep, UndoStepSummary, current_session, in_session, record_events,
+    without_session,
+};
+pub use operation::{UndoOperation, UndoTarget};
@@ -0,0 +1,264 @@
+//! ドメインイベントから組み立てる逆操作
+
+use crate::events::{ChangeKind, DomainEvent, EntityKind, REASON_FIELD};
+use flequit_model::types::id_types::ProjectId;
+use serde_json::{Map, Value};
+
+/// 操作の対象となるエンティティ
+#[derive(Debug, Clone, PartialEq)]
+pub struct UndoTarget {
+    pub entity: EntityKind,
+    pub project_id: ProjectId,
+    /// 対象のID（関連付けの場合は関連元のタスク・サブタスクのID）
+    pub entity_id: String,
+}
+
+/// 取り消し・やり直しで実行する操作
+///
+/// 実行すると、その操作を打ち消す操作が得られる（`Delete` なら `Restore` など）。
+#[derive(Debug, Clone, PartialEq)]
+pub enum UndoOperation {
+    /// 削除する（プロジェクト・タスクリスト・タスク・タグは論理削除、それ以外は物理削除）
+    Delete(UndoTarget),
+    /// 論理削除したエンティティを復元する
+    Restore(UndoTarget),
+    /// 物理削除したエンティティを削除前の状態から作り直す
+    Recreate { target: UndoTarget, snapshot: Value },
+    /// フィールドの値を設定する
+    SetFields {
+        target: UndoTarget,
+        values: Map<String, Value>,
+    },
+    /// 関連付けを追加する
+    Link {
+        target: UndoTarget,
+        related_id: String,
+    },
+    /// 関連付けを外す
+    Unlink {
+        target: UndoTarget,
+        related_id: String,
+    },
+    /// 関連付け先をまとめて置き換える
+    SetLinks {
+        target: UndoTarget,
+        related_ids: Vec<String>,
+    },
+}
+
+/// 論理削除・復元ができるエンティティ
+const SOFT_DELETABLE: &[EntityKind] = &[
+    EntityKind::Project,
+    EntityKind::TaskList,
+    EntityKind::Task,
+    EntityKind::Tag,
+    EntityKind::Comment,
+];
+
+/// 物理削除されるエンティティ
+const HARD_DELETABLE: &[EntityKind] = &[
+    EntityKind::SubTask,
+    EntityKind::RecurrenceRule,
+    EntityKind::TimeEntry,
+    EntityKind::Reminder,
+    EntityKind::Snooze,
+    EntityKind::WorkflowStatus,
+    EntityKind::StatusTransitionRule,
+];
+
+/// 関連付け
+const RELATIONS: &[EntityKind] = &[
+    EntityKind::TaskTag,
+    EntityKind::SubTaskTag,
+    EntityKind::TaskAssignment,
+    EntityKind::SubTaskAssignment,
+    EntityKind::TaskRecurrence,
+    EntityKind::SubTaskRecurrence,
+    EntityKind::TaskDependency,
+];
+
+impl UndoOperation {
+    pub fn target(&self) -> &UndoTarget {
+        match self {
+            UndoOperation::Delete(target) | UndoOperation::Restore(target) => target,
+            UndoOperation::Recreate { target, .. }
+            | UndoOperation::SetFields { target, .. }
+            | UndoOperation::Link { target, .. }
+            | UndoOperation::Unlink { target, .. }
+            | UndoOperation::SetLinks { target, .. } => target,
+        }
+    }
+
+    /// イベントが表す変更を打ち消す操作を組み立てる
+    ///
+    /// 取り消しの対象外（プロジェクトに属さないエンティティ・タグのブックマーク）は
+    /// `Ok(None)`、対象だが打ち消す操作を組み立てられない場合は理由を `Err` で返す。
+    pub fn inverse_of(event: &DomainEvent) -> Result<Option<Self>, String> {
+        let Some(project_id) = event.project_id else {
+            return Ok(None);
+        };
+        let target = UndoTarget {
+            entity: event.entity,
+            project_id,
+            entity_id: event.entity_id.clone(),
+        };
+        let unsupported = || {
+            format!(
+                "{} {} of {} cannot be undone",
+                event.entity, event.change, event.entity_id
+            )
+        };
+
+        // 先行タスクの完了によるブロック解除は、完了操作から派生した通知なので打ち消さない
+        if event.entity == EntityKind::TaskDependency && event.change == ChangeKind::Updated {
+            return Ok(None);
+        }
+
+        if RELATIONS.contains(&event.entity) {
+            // 一括変更・一括削除は変更前の関連付け先をまとめて戻す
+            if let Some(related_ids) = event.changes.first().and_then(|change| ids(&change.from)) {
+                return Ok(Some(UndoOperation::SetLinks {
+                    target,
+                    related_ids,
+                }));
+            }
+            let related_id = event.related_id.clone().ok_or_else(unsupported)?;
+            return match event.change {
+                ChangeKind::Created => Ok(Some(UndoOperation::Unlink { target, related_id })),
+                ChangeKind::Deleted => Ok(Some(UndoOperation::Link { target, related_id })),
+                _ => Err(unsupported()),
+            };
+        }
+
+        let soft = SOFT_DELETABLE.contains(&event.entity);
+        if !soft && !HARD_DELETABLE.contains(&event.entity) {
+            return Ok(None);
+        }
+        match event.change {
+            ChangeKind::Created => Ok(Some(UndoOperation::Delete(target))),
+            ChangeKind::Restored if soft => Ok(Some(UndoOperation::Delete(target))),
+            ChangeKind::Deleted if soft => Ok(Some(UndoOperation::Restore(target))),
+            ChangeKind::Deleted => {
+                let snapshot = event.snapshot.clone().ok_or_else(unsupported)?;
+                Ok(Some(UndoOperation::Recreate { target, snapshot }))
+            }
+            ChangeKind::Updated if !event.changes.is_empty() => {
+                Ok(Some(UndoOperation::SetFields {
+                    target,
+                    values: event
+                        .changes
+                        .iter()
+                        .filter(|change| change.field != REASON_FIELD)
+                        .map(|change| (change.field.clone(), change.from.clone()))
+                        .collect(),
+                }))
+            }
+            _ => Err(unsupported()),
+        }
+    }
+}
+
+fn ids(value: &Value) -> Option<Vec<String>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|id| id.as_str().map(str::to_string))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::value_change;
+    use flequit_model::types::id_types::TagId;
+
+    #[test]
+    fn test_inverse_of_entity_changes() {
+        let project_id = ProjectId::new();
+        let target = |entity| UndoTarget {
+            entity,
+            project_id,
+            entity_id: "id-1".to_string(),
+        };
+
+        let created = DomainEvent::created(EntityKind::Task, "id-1").in_project(&project_id);
+        assert_eq!(
+            UndoOperation::inverse_of(&created).unwrap(),
+            Some(UndoOperation::Delete(target(EntityKind::Task)))
+        );
+
+        let deleted = DomainEvent::deleted(EntityKind::TaskList, "id-1").in_project(&project_id);
+        assert_eq!(
+            UndoOperation::inverse_of(&deleted).unwrap(),
+            Some(UndoOperation::Restore(target(EntityKind::TaskList)))
+        );
+
+        // 物理削除は削除前の状態がなければ戻せない
+        let removed = DomainEvent::deleted(EntityKind::SubTask, "id-1").in_project(&project_id);
+        assert!(UndoOperation::inverse_of(&removed).is_err());
+        let removed = removed.with_snapshot(&serde_json::json!({ "id": "id-1" }));
+        assert!(matches!(
+            UndoOperation::inverse_of(&removed).unwrap(),
+            Some(UndoOperation::Recreate { .. })
+        ));
+
+        let updated = DomainEvent::updated(EntityKind::Tag, "id-1")
+            .in_project(&project_id)
+            .with_changes(vec![value_change("name", "before", "after")]);
+        let Some(UndoOperation::SetFields { values, .. }) =
+            UndoOperation::inverse_of(&updated).unwrap()
+        else {
+            panic!("expected SetFields");
+        };
+        assert_eq!(values["name"], serde_json::json!("before"));
+
+        // 変更の理由はフィールドではないため戻さない
+        let changed_status = DomainEvent::updated(EntityKind::Task, "id-1")
+            .in_project(&project_id)
+            .with_changes(vec![value_change("status", "not_started", "completed")])
+            .with_reason(Some("done elsewhere"));
+        let Some(UndoOperation::SetFields { values, .. }) =
+            UndoOperation::inverse_of(&changed_status).unwrap()
+        else {
+            panic!("expected SetFields");
+        };
+        assert_eq!(values.len(), 1);
+        assert_eq!(values["status"], serde_json::json!("not_started"));
+
+        // プロジェクト外のエンティティは対象外
+        let user = DomainEvent::updated(EntityKind::User, "user-1");
+        assert_eq!(UndoOperation::inverse_of(&user).unwrap(), None);
+    }
+
+    #[test]
+    fn test_inverse_of_relation_changes() {
+        let project_id = ProjectId::new();
+        let tag_id = TagId::new();
+
+        let added = DomainEvent::created(EntityKind::TaskTag, "task-1")
+            .in_project(&project_id)
+            .related_to(tag_id);
+        assert!(matches!(
+            UndoOperation::inverse_of(&added).unwrap(),
+            Some(UndoOperation::Unlink { related_id, .. }) if related_id == tag_id.to_string()
+        ));
+
+        let replaced = DomainEvent::updated(EntityKind::TaskTag, "task-1")
+            .in_project(&project_id)
+            .with_changes(vec![value_change("tag_ids", [tag_id], Vec::<TagId>::new())]);
+        assert!(matches!(
+            UndoOperation::inverse_of(&replaced).unwrap(),
+            Some(UndoOperation::SetLinks { related_ids, .. }) if related_ids == vec![tag_id.to_string()]
+        ));
+
+        let cleared =
+            DomainEvent::deleted(EntityKind::TaskRecurrence, "task-1").in_project(&project_id);
+        assert!(UndoOperation::inverse_of(&cleared).is_err());
+
+        let unblocked = DomainEvent::updated(EntityKind::TaskDependency, "task-2")
+            .in_project(&project_id)
+            .related_to("task-1")
+            .with_changes(vec![value_change("blocked", true, false)]);
+        assert_eq!(UndoOperation::inverse_of(&unblocked).unwrap(), None);
+    }
+}
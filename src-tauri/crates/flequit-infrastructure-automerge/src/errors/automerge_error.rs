@@ -53,6 +53,9 @@ pub enum AutomergeError {
 
     #[error("Invalid path: {0}")]
     InvalidPath(String),
+
+    #[error("Encryption error: {0}")]
+    EncryptionError(String),
 }
 
 use flequit_types::errors::repository_error::RepositoryError;
@@ -75,6 +78,7 @@ impl From<RepositoryError> for AutomergeError {
             RepositoryError::Export(msg) => AutomergeError::Export(msg),
             RepositoryError::EmailConflict(msg) => AutomergeError::EmailConflict(msg),
             RepositoryError::UserNotFound(msg) => AutomergeError::NotFound(msg),
+            RepositoryError::EncryptionError(msg) => AutomergeError::EncryptionError(msg),
             _ => panic!("想定しないエラー RepositoryError={}", err),
         }
     }
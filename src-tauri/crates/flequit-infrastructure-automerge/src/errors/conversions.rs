@@ -22,6 +22,7 @@ impl From<AutomergeError> for RepositoryError {
             AutomergeError::AutomergeError(msg) => RepositoryError::AutomergeError(msg),
             AutomergeError::StorageError(msg) => RepositoryError::IOError(msg),
             AutomergeError::InvalidPath(msg) => RepositoryError::InvalidOperation(msg),
+            AutomergeError::EncryptionError(msg) => RepositoryError::EncryptionError(msg),
         }
     }
 }
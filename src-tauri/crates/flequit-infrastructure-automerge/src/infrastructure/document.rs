@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use crate::errors::automerge_error::AutomergeError;
 use crate::infrastructure::document_manager::DocumentType;
+use crate::infrastructure::document_schema::{self, DocumentSchema, MigrationReport};
 use automerge::transaction::Transactable;
 use automerge::{ObjType, ReadDoc, ScalarValue};
 use automerge_repo::DocHandle;
@@ -14,6 +15,14 @@ pub struct Document {
     pub handle: DocHandle,
 }
 
+/// 変更履歴のある時点でのドキュメントの状態
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryState {
+    /// 直前に適用した変更の記録日時（UNIXミリ秒）
+    pub timestamp: i64,
+    pub state: serde_json::Value,
+}
+
 impl Document {
     pub fn new(base_path: PathBuf, doc_type: DocumentType, doc_handle: DocHandle) -> Document {
         Self {
@@ -229,6 +238,155 @@ impl Document {
         })
     }
 
+    /// ルート直下で `prefix` から始まるキーのデータをまとめて読み込み
+    ///
+    /// 1件ずつ別のキーに保存したデータは、複数の端末で同時に追加してもマージ後に
+    /// すべて残る（1つのリストにまとめて保存すると、片方の端末で追加した要素が失われる）。
+    /// 読み込み順はキーの昇順。
+    pub async fn load_entries<T: serde::de::DeserializeOwned>(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<T>, AutomergeError> {
+        self.handle.with_doc(|doc| {
+            let mut entries = Vec::new();
+            for key in doc
+                .keys(automerge::ROOT)
+                .filter(|key| key.starts_with(prefix))
+            {
+                let Ok(Some((value, obj_id))) = doc.get(automerge::ROOT, key.as_str()) else {
+                    continue;
+                };
+                let json_value = self.value_to_json_value_with_objid(doc, &value, &obj_id);
+                if json_value == serde_json::Value::Null {
+                    continue;
+                }
+                let entry: T = serde_json::from_value(json_value)
+                    .map_err(|e| AutomergeError::SerializationError(e.to_string()))?;
+                entries.push(entry);
+            }
+            Ok(entries)
+        })
+    }
+
+    /// ルート直下のキーを削除（存在しなければ何もしない）
+    pub async fn delete_data(&self, key: &str) -> Result<bool, AutomergeError> {
+        self.handle.with_doc_mut(|doc| {
+            if doc
+                .get(automerge::ROOT, key)
+                .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?
+                .is_none()
+            {
+                return Ok(false);
+            }
+            let mut tx = doc.transaction();
+            tx.delete(automerge::ROOT, key)
+                .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?;
+            tx.commit();
+            Ok(true)
+        })
+    }
+
+    /// 変更履歴を含むドキュメント全体をバイナリとして取得
+    pub async fn save_history(&self) -> Vec<u8> {
+        self.handle.with_doc(|doc| doc.save())
+    }
+
+    /// 変更履歴を含むバイナリをこのドキュメントにマージ
+    ///
+    /// 同じ履歴を共有するドキュメント同士であれば、双方の変更がCRDTとして統合される。
+    pub async fn merge_history(&self, data: &[u8]) -> Result<(), AutomergeError> {
+        let mut other = automerge::Automerge::load(data)
+            .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?;
+        // 取り込んだ変更が古いスキーマで書かれていれば、マージ後に移行し直す
+        let incoming_version = document_schema::schema_version(&other);
+        let schema = DocumentSchema::of(&self.doc_type);
+        self.handle.with_doc_mut(|doc| {
+            doc.merge(&mut other)
+                .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?;
+            document_schema::migrate_merged(doc, schema, incoming_version)
+        })?;
+        Ok(())
+    }
+
+    /// ドキュメントを最新のスキーマバージョンへ移行
+    pub async fn migrate(&self) -> Result<Option<MigrationReport>, AutomergeError> {
+        let schema = DocumentSchema::of(&self.doc_type);
+        self.handle
+            .with_doc_mut(|doc| document_schema::migrate(doc, schema))
+    }
+
+    /// 現在の変更履歴の先端（heads）を取得
+    pub async fn heads(&self) -> Vec<automerge::ChangeHash> {
+        self.handle.with_doc(|doc| doc.get_heads())
+    }
+
+    /// ドキュメントの内容を指定したheads時点の状態へ戻す
+    ///
+    /// 履歴は巻き戻さず、過去の状態を再現する変更を新たに追加する。
+    /// 既に同じ状態であれば何もせず `false` を返す。
+    pub async fn revert_to(&self, heads: &[automerge::ChangeHash]) -> Result<bool, AutomergeError> {
+        self.handle.with_doc_mut(|doc| {
+            if doc.get_heads() == heads {
+                return Ok(false);
+            }
+
+            let past = doc
+                .fork_at(heads)
+                .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?;
+            let past_root = match self.read_map_object(&past, &automerge::ROOT) {
+                serde_json::Value::Object(map) => map,
+                _ => serde_json::Map::new(),
+            };
+            let current_keys: Vec<String> = doc.keys(automerge::ROOT).collect();
+
+            let mut tx = doc.transaction();
+            for key in current_keys
+                .iter()
+                .filter(|key| !past_root.contains_key(key.as_str()))
+            {
+                tx.delete(automerge::ROOT, key.as_str())
+                    .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?;
+            }
+            for (key, value) in &past_root {
+                self.put_json_value(&mut tx, &automerge::ROOT, key, value)
+                    .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?;
+            }
+            tx.commit();
+            // 戻した状態が古いスキーマのものであれば移行し直す
+            document_schema::migrate(doc, DocumentSchema::of(&self.doc_type))?;
+            Ok(true)
+        })
+    }
+
+    /// 変更履歴を含むバイナリを読み込み、現在の状態をJSONとして取得
+    pub fn history_to_json(data: &[u8]) -> Result<serde_json::Value, AutomergeError> {
+        let doc = automerge::Automerge::load(data)
+            .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?;
+        serde_json::to_value(automerge::AutoSerde::from(&doc))
+            .map_err(|e| AutomergeError::SerializationError(e.to_string()))
+    }
+
+    /// 変更履歴を含むバイナリを読み込み、変更を1つ適用するごとの状態をJSONとして取得
+    ///
+    /// 変更は因果順に適用される。各状態には適用した変更の記録日時（UNIXミリ秒）を添える。
+    pub fn history_states(data: &[u8]) -> Result<Vec<HistoryState>, AutomergeError> {
+        let doc = automerge::Automerge::load(data)
+            .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?;
+
+        let mut replay = automerge::Automerge::new();
+        let mut states = Vec::new();
+        for change in doc.get_changes(&[]) {
+            let timestamp = change.timestamp();
+            replay
+                .apply_changes([change])
+                .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?;
+            let state = serde_json::to_value(automerge::AutoSerde::from(&replay))
+                .map_err(|e| AutomergeError::SerializationError(e.to_string()))?;
+            states.push(HistoryState { timestamp, state });
+        }
+        Ok(states)
+    }
+
     /// ドキュメントの全データをJSONとして取得
     pub async fn export_document_as_json(&self) -> Result<serde_json::Value, AutomergeError> {
         let doc = self;
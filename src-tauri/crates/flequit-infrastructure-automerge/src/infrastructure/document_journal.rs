@@ -0,0 +1,203 @@
+//! 作業単位（Unit of Work）中に変更されたドキュメントの記録
+//!
+//! `DocumentJournal::scope` で実行したFuture内で `DocumentManager` から取得された
+//! ドキュメントについて、最初に触れた時点のheadsを記録する。
+//! 作業単位が失敗した場合は `rollback` で各ドキュメントをその時点の状態へ戻す。
+
+use crate::errors::automerge_error::AutomergeError;
+use crate::infrastructure::document::Document;
+use automerge::ChangeHash;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+tokio::task_local! {
+    static DOCUMENT_JOURNAL: DocumentJournal;
+}
+
+#[derive(Debug, Clone)]
+struct JournalEntry {
+    document: Document,
+    heads: Vec<ChangeHash>,
+}
+
+/// 作業単位中に触れたドキュメントと、その時点のheads
+#[derive(Debug, Clone, Default)]
+pub struct DocumentJournal {
+    entries: Arc<Mutex<Vec<JournalEntry>>>,
+}
+
+impl DocumentJournal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// このジャーナルに記録しながらFutureを実行
+    ///
+    /// 記録は現在のタスク内に限られ、`tokio::spawn` した別タスクには引き継がれない。
+    pub async fn scope<F: Future>(&self, work: F) -> F::Output {
+        DOCUMENT_JOURNAL.scope(self.clone(), work).await
+    }
+
+    /// 現在のタスクでジャーナルが有効か
+    pub fn is_active() -> bool {
+        DOCUMENT_JOURNAL.try_with(|_| ()).is_ok()
+    }
+
+    /// 有効なジャーナルがあれば、ドキュメントの現在のheadsを記録（初回のみ）
+    pub(crate) async fn record(document: &Document) {
+        let Ok(journal) = DOCUMENT_JOURNAL.try_with(Clone::clone) else {
+            return;
+        };
+        if journal.contains(document) {
+            return;
+        }
+        let heads = document.heads().await;
+        let mut entries = journal.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if !entries
+            .iter()
+            .any(|entry| entry.document.doc_type == document.doc_type)
+        {
+            entries.push(JournalEntry {
+                document: document.clone(),
+                heads,
+            });
+        }
+    }
+
+    fn contains(&self, document: &Document) -> bool {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .any(|entry| entry.document.doc_type == document.doc_type)
+    }
+
+    /// 記録済みのドキュメント数
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 記録したドキュメントを作業単位の開始前の状態へ戻す
+    ///
+    /// 後から触れたドキュメントから順に戻す。途中で失敗しても残りのドキュメントは
+    /// 戻し続け、最初のエラーを返す。成功時は実際に戻したドキュメント数を返す。
+    pub async fn rollback(&self) -> Result<usize, AutomergeError> {
+        let entries: Vec<JournalEntry> = {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            entries.drain(..).collect()
+        };
+
+        let mut reverted = 0;
+        let mut first_error = None;
+        for entry in entries.iter().rev() {
+            match entry.document.revert_to(&entry.heads).await {
+                Ok(true) => reverted += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to revert document {:?}: {}",
+                        entry.document.doc_type,
+                        e
+                    );
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(reverted),
+        }
+    }
+
+    /// 記録を破棄（作業単位の確定時）
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::document_manager::{DocumentManager, DocumentType};
+    use flequit_model::types::id_types::ProjectId;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_rollback_restores_documents_touched_in_scope() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = DocumentManager::new(temp_dir.path()).unwrap();
+        let settings = DocumentType::Settings;
+        let project = DocumentType::Project(ProjectId::new());
+
+        manager
+            .save_data(&settings, "name", &"before")
+            .await
+            .unwrap();
+
+        let journal = DocumentJournal::new();
+        journal
+            .scope(async {
+                assert!(DocumentJournal::is_active());
+                manager
+                    .save_data(&settings, "name", &"after")
+                    .await
+                    .unwrap();
+                manager.save_data(&settings, "extra", &1).await.unwrap();
+                manager.save_data(&project, "name", &"new").await.unwrap();
+            })
+            .await;
+        assert!(!DocumentJournal::is_active());
+        assert_eq!(journal.len(), 2);
+
+        assert_eq!(journal.rollback().await.unwrap(), 2);
+        assert!(journal.is_empty());
+        assert_eq!(
+            manager
+                .load_data::<String>(&settings, "name")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("before")
+        );
+        assert_eq!(
+            manager.load_data::<i32>(&settings, "extra").await.unwrap(),
+            None
+        );
+        assert_eq!(
+            manager.load_data::<String>(&project, "name").await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_documents_outside_scope_are_not_recorded() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = DocumentManager::new(temp_dir.path()).unwrap();
+        let journal = DocumentJournal::new();
+
+        manager
+            .save_data(&DocumentType::Settings, "name", &"value")
+            .await
+            .unwrap();
+        journal
+            .scope(async {
+                manager
+                    .load_data::<String>(&DocumentType::Settings, "name")
+                    .await
+                    .unwrap();
+            })
+            .await;
+
+        // 読み込みのみの場合は戻す変更がない
+        assert_eq!(journal.len(), 1);
+        assert_eq!(journal.rollback().await.unwrap(), 0);
+    }
+}
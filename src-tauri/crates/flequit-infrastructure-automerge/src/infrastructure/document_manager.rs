@@ -1,3 +1,5 @@
+use super::document_journal::DocumentJournal;
+use super::encryption::DocumentCipher;
 use super::file_storage::FileStorage;
 use crate::{errors::automerge_error::AutomergeError, infrastructure::document::Document};
 use automerge_repo::RepoHandle;
@@ -99,8 +101,24 @@ pub struct DocumentManager {
 impl DocumentManager {
     /// 新しいDocumentManagerを作成
     pub fn new<P: AsRef<Path>>(base_path: P) -> Result<Self, AutomergeError> {
-        let base_path = base_path.as_ref().to_path_buf();
+        Self::build(base_path.as_ref().to_path_buf(), None)
+    }
 
+    /// 保存時暗号化を有効にしたDocumentManagerを作成
+    ///
+    /// 暗号化はFileStorage内で完結するため、リポジトリ層からは透過的に扱える。
+    /// 平文で残っているドキュメントは、Automerge-Repoが読み書きを始める前に暗号化形式へ移行する。
+    pub fn new_with_cipher<P: AsRef<Path>>(
+        base_path: P,
+        cipher: std::sync::Arc<dyn DocumentCipher>,
+    ) -> Result<Self, AutomergeError> {
+        Self::build(base_path.as_ref().to_path_buf(), Some(cipher))
+    }
+
+    fn build(
+        base_path: PathBuf,
+        cipher: Option<std::sync::Arc<dyn DocumentCipher>>,
+    ) -> Result<Self, AutomergeError> {
         // ベースディレクトリを作成
         if !base_path.exists() {
             std::fs::create_dir_all(&base_path)
@@ -108,7 +126,14 @@ impl DocumentManager {
         }
 
         // FileStorageを使用してAutomerge-Repoを初期化
-        let file_storage = std::sync::Arc::new(FileStorage::new(base_path.clone())?);
+        let file_storage = std::sync::Arc::new(match cipher {
+            Some(cipher) => {
+                let file_storage = FileStorage::new_with_cipher(base_path.clone(), cipher)?;
+                file_storage.encrypt_existing_documents()?;
+                file_storage
+            }
+            None => FileStorage::new(base_path.clone())?,
+        });
         let file_storage_clone = file_storage.clone();
         let repo = automerge_repo::Repo::new(None, Box::new((*file_storage_clone).clone()));
         let repo_handle = repo.run();
@@ -138,6 +163,7 @@ impl DocumentManager {
         // キャッシュから取得
         if let Some(doc) = self.documents.get(doc_type) {
             tracing::debug!("Document found in cache for type: {:?}", doc_type);
+            DocumentJournal::record(doc).await;
             return Ok(doc.clone());
         }
 
@@ -189,7 +215,10 @@ impl DocumentManager {
         };
 
         let doc = Document::new(self.base_path.clone(), doc_type.clone(), doc_handle);
+        // 作業単位のロールバックで取り消されないよう、記録を始める前に移行する
+        doc.migrate().await?;
         self.documents.insert(doc_type.clone(), doc.clone());
+        DocumentJournal::record(&doc).await;
         Ok(doc)
     }
 
@@ -397,6 +426,37 @@ impl DocumentManager {
         Ok(())
     }
 
+    /// Automerge-Repoを停止する
+    ///
+    /// 保留中の変更をストレージへ書き出してからRepoのスレッドを終了する。
+    /// 停止後は使用できないため、ファイルを差し替える前などに呼び出して破棄する。
+    pub async fn shutdown(&mut self) -> Result<(), AutomergeError> {
+        // DocHandleの破棄はRepoへ通知されるため、停止より前にキャッシュを空にする
+        self.documents.clear();
+
+        let repo_handle = self.repo_handle.clone();
+        tokio::task::spawn_blocking(move || repo_handle.stop())
+            .await
+            .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?
+            .map_err(|e| AutomergeError::AutomergeError(format!("{:?}", e)))?;
+
+        tracing::info!("DocumentManager shut down: {:?}", self.base_path);
+        Ok(())
+    }
+
+    /// 読み込み済みドキュメントのスナップショットをディレクトリへ書き出す
+    ///
+    /// Repoが変更を追記している途中のファイルをコピーしないよう、
+    /// メモリ上のドキュメント全体を保存時と同じ形式で書き出す。書き出した件数を返す。
+    pub async fn snapshot_documents_to(&self, dest: &Path) -> Result<usize, AutomergeError> {
+        for (doc_type, doc) in &self.documents {
+            let full_doc = doc.save_history().await;
+            self.file_storage
+                .write_snapshot(&dest.join(doc_type.filename()), &full_doc)?;
+        }
+        Ok(self.documents.len())
+    }
+
     /// 全てのドキュメントをJSONファイルに出力
     pub async fn export_all_documents_to_directory<P: AsRef<Path>>(
         &mut self,
@@ -0,0 +1,371 @@
+//! Automergeドキュメントのスキーマバージョンと移行
+//!
+//! 各ドキュメントはルートの `schema_version` に現在のレイアウトのバージョンを持つ。
+//! バージョンを持たないドキュメントはバージョン0（バージョン管理導入前）として扱い、
+//! ロード時に `MIGRATIONS` に登録された移行を順に適用して最新のバージョンへ上げる。
+//!
+//! ## 移行の書き方
+//!
+//! 複数の端末が同じドキュメントを同時に移行し、その結果がマージされても壊れないよう、
+//! 移行は次の条件を満たす必要がある。
+//!
+//! - 冪等であること（移行済みの内容に再度適用しても何も変わらない）
+//! - 内容だけから書き込む値が決まること（時刻や乱数を使わない）
+//! - リストへの要素の追加や、既存のオブジェクトの置き換えをしないこと
+//!   （同時に行われるとマージ後に要素が重複したり、他の端末の変更が失われたりする）
+//!
+//! バージョン番号の書き込みが競合してマージ後に古い値が残った場合も、
+//! 次のロードで移行が再度適用されるだけで内容は変わらない。
+//! 古いバージョンのままの端末から取り込んだ変更は `migrate_merged` で移行し直す。
+
+use crate::errors::automerge_error::AutomergeError;
+use crate::infrastructure::document_manager::DocumentType;
+use automerge::transaction::{CommitOptions, Transactable, Transaction};
+use automerge::{Automerge, ObjId, ObjType, ROOT, ReadDoc, ScalarValue, Value};
+
+/// スキーマバージョンを保持するルートのキー
+pub const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// 記録者が分からない変更の `updated_by` に補うユーザーID（全端末で同じ値になるようNil UUIDを使う）
+const UNKNOWN_ACTOR: &str = "00000000-0000-0000-0000-000000000000";
+
+/// スキーマの種類（ドキュメントの種類ごとに独立してバージョンを持つ）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DocumentSchema {
+    Settings,
+    Account,
+    User,
+    Project,
+}
+
+impl DocumentSchema {
+    pub fn of(doc_type: &DocumentType) -> Self {
+        match doc_type {
+            DocumentType::Settings => DocumentSchema::Settings,
+            DocumentType::Account => DocumentSchema::Account,
+            DocumentType::User => DocumentSchema::User,
+            DocumentType::Project(_) => DocumentSchema::Project,
+        }
+    }
+
+    /// このアプリケーションが書き込むスキーマのバージョン
+    pub fn current_version(self) -> u64 {
+        MIGRATIONS
+            .iter()
+            .filter(|migration| migration.schema == self)
+            .map(|migration| migration.version)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// 1つ前のバージョンから `version` へ上げる移行
+pub struct Migration {
+    pub schema: DocumentSchema,
+    /// 適用後のバージョン
+    pub version: u64,
+    pub description: &'static str,
+    pub apply: fn(&mut Transaction<'_>) -> Result<(), automerge::AutomergeError>,
+}
+
+/// 登録済みの移行（スキーマごとにバージョンの昇順）
+pub static MIGRATIONS: &[Migration] = &[
+    Migration {
+        schema: DocumentSchema::Settings,
+        version: 1,
+        description: "backfill deleted/updated_by of projects",
+        apply: |tx| {
+            backfill_tracking_fields(tx, "projects", |tx, item| string_at(tx, item, "owner_id"))
+        },
+    },
+    Migration {
+        schema: DocumentSchema::Account,
+        version: 1,
+        description: "backfill deleted/updated_by of accounts",
+        apply: |tx| {
+            backfill_tracking_fields(tx, "accounts", |tx, item| string_at(tx, item, "user_id"))
+        },
+    },
+    Migration {
+        schema: DocumentSchema::User,
+        version: 1,
+        description: "backfill deleted/updated_by of users",
+        apply: |tx| backfill_tracking_fields(tx, "users", |tx, item| string_at(tx, item, "id")),
+    },
+    Migration {
+        schema: DocumentSchema::Project,
+        version: 1,
+        description: "backfill deleted/updated_by of project and its entities",
+        apply: backfill_project_tracking_fields,
+    },
+    Migration {
+        schema: DocumentSchema::Project,
+        version: 2,
+        description: "add empty workflow status reference to tasks",
+        apply: backfill_task_workflow_status,
+    },
+    Migration {
+        schema: DocumentSchema::Project,
+        version: 3,
+        description: "add empty snooze time to reminders",
+        apply: backfill_reminder_snoozed_until,
+    },
+];
+
+/// 移行の結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationReport {
+    pub schema: DocumentSchema,
+    pub from: u64,
+    pub to: u64,
+}
+
+/// ドキュメントのスキーマバージョン（記録がなければ0）
+pub fn schema_version(doc: &impl ReadDoc) -> u64 {
+    match doc.get(ROOT, SCHEMA_VERSION_KEY) {
+        Ok(Some((Value::Scalar(scalar), _))) => match scalar.as_ref() {
+            ScalarValue::Int(version) => u64::try_from(*version).unwrap_or(0),
+            ScalarValue::Uint(version) => *version,
+            _ => 0,
+        },
+        _ => 0,
+    }
+}
+
+/// ドキュメントを最新のスキーマバージョンへ移行する
+///
+/// 移行した場合はその結果を返す。既に最新であれば `None`。
+/// このアプリケーションより新しいバージョンのドキュメントは変更しない。
+pub fn migrate(
+    doc: &mut Automerge,
+    schema: DocumentSchema,
+) -> Result<Option<MigrationReport>, AutomergeError> {
+    let from = schema_version(doc);
+    migrate_from(doc, schema, from)
+}
+
+/// 他の端末の履歴をマージしたドキュメントを移行する
+///
+/// 取り込んだ履歴が古いスキーマで書かれていれば、ドキュメントのバージョンが
+/// 最新であってもその版以降の移行を適用し直す（移行は冪等なので変更がなければ何も書き込まない）。
+pub fn migrate_merged(
+    doc: &mut Automerge,
+    schema: DocumentSchema,
+    incoming_version: u64,
+) -> Result<Option<MigrationReport>, AutomergeError> {
+    let from = schema_version(doc).min(incoming_version);
+    migrate_from(doc, schema, from)
+}
+
+fn migrate_from(
+    doc: &mut Automerge,
+    schema: DocumentSchema,
+    from: u64,
+) -> Result<Option<MigrationReport>, AutomergeError> {
+    let stored = schema_version(doc);
+    let to = schema.current_version();
+    if stored > to {
+        tracing::warn!(
+            "Document schema {:?} version {} is newer than supported version {}",
+            schema,
+            stored,
+            to
+        );
+        return Ok(None);
+    }
+    if from >= to {
+        return Ok(None);
+    }
+
+    // 移行とバージョンの更新を1つの変更にまとめ、途中の状態が同期されないようにする
+    let mut tx = doc.transaction();
+    for migration in MIGRATIONS
+        .iter()
+        .filter(|migration| migration.schema == schema && migration.version > from)
+    {
+        (migration.apply)(&mut tx).map_err(|e| {
+            AutomergeError::AutomergeError(format!(
+                "Failed to migrate {:?} schema to version {}: {}",
+                schema, migration.version, e
+            ))
+        })?;
+    }
+    if stored < to {
+        tx.put(ROOT, SCHEMA_VERSION_KEY, to as i64)
+            .map_err(|e| AutomergeError::AutomergeError(e.to_string()))?;
+    }
+    if tx.pending_ops() == 0 {
+        tx.rollback();
+        return Ok(None);
+    }
+    tx.commit_with(CommitOptions::default().with_message(format!(
+        "Migrate {:?} schema from version {} to {}",
+        schema, from, to
+    )));
+
+    tracing::info!(
+        "Migrated {:?} document schema from version {} to {}",
+        schema,
+        from,
+        to
+    );
+    Ok(Some(MigrationReport { schema, from, to }))
+}
+
+/// プロジェクト本体とプロジェクト内のエンティティに `deleted` / `updated_by` を補う
+///
+/// 記録者が分からない場合はプロジェクトの最終更新者（なければオーナー）とする。
+fn backfill_project_tracking_fields(
+    tx: &mut Transaction<'_>,
+) -> Result<(), automerge::AutomergeError> {
+    let has_project = tx.get(ROOT, "id")?.is_some();
+    let actor = string_at(tx, &ROOT, "updated_by")
+        .or_else(|| string_at(tx, &ROOT, "owner_id"))
+        .unwrap_or_else(|| UNKNOWN_ACTOR.to_string());
+    if has_project {
+        backfill_object(tx, &ROOT, &actor)?;
+    }
+    for list_key in ["task_lists", "tasks", "subtasks", "tags", "members"] {
+        backfill_tracking_fields(tx, list_key, |_, _| Some(actor.clone()))?;
+    }
+    Ok(())
+}
+
+/// タスクにワークフローステータスへの参照（`workflow_status_id`）を補う
+///
+/// 既存のタスクはワークフローステータスを参照しない（組み込みのステータスのみを使う）ため `null` とする。
+/// ワークフローステータスのリスト（`workflow_statuses`）は同時に作られると
+/// 片方の端末の要素が失われるため、移行では作らず最初の登録時に作る。
+fn backfill_task_workflow_status(
+    tx: &mut Transaction<'_>,
+) -> Result<(), automerge::AutomergeError> {
+    let Some((Value::Object(ObjType::List), tasks)) = tx.get(ROOT, "tasks")? else {
+        return Ok(());
+    };
+    for index in 0..tx.length(&tasks) {
+        let Some((Value::Object(ObjType::Map), task)) = tx.get(&tasks, index)? else {
+            continue;
+        };
+        if tx.get(&task, "workflow_status_id")?.is_none() {
+            tx.put(&task, "workflow_status_id", ScalarValue::Null)?;
+        }
+    }
+    Ok(())
+}
+
+/// リマインダーにスヌーズ先の日時（`snoozed_until`）を補う
+///
+/// 既存のリマインダーはスヌーズしていないため `null` とする。
+fn backfill_reminder_snoozed_until(
+    tx: &mut Transaction<'_>,
+) -> Result<(), automerge::AutomergeError> {
+    let Some((Value::Object(ObjType::List), reminders)) = tx.get(ROOT, "reminders")? else {
+        return Ok(());
+    };
+    for index in 0..tx.length(&reminders) {
+        let Some((Value::Object(ObjType::Map), reminder)) = tx.get(&reminders, index)? else {
+            continue;
+        };
+        if tx.get(&reminder, "snoozed_until")?.is_none() {
+            tx.put(&reminder, "snoozed_until", ScalarValue::Null)?;
+        }
+    }
+    Ok(())
+}
+
+/// ルート直下のリストの各要素に、欠けている `deleted` / `updated_by` を補う
+fn backfill_tracking_fields(
+    tx: &mut Transaction<'_>,
+    list_key: &str,
+    actor_of: impl Fn(&Transaction<'_>, &ObjId) -> Option<String>,
+) -> Result<(), automerge::AutomergeError> {
+    let Some((Value::Object(ObjType::List), list)) = tx.get(ROOT, list_key)? else {
+        return Ok(());
+    };
+    for index in 0..tx.length(&list) {
+        let Some((Value::Object(ObjType::Map), item)) = tx.get(&list, index)? else {
+            continue;
+        };
+        let actor = actor_of(tx, &item).unwrap_or_else(|| UNKNOWN_ACTOR.to_string());
+        backfill_object(tx, &item, &actor)?;
+    }
+    Ok(())
+}
+
+fn backfill_object(
+    tx: &mut Transaction<'_>,
+    obj: &ObjId,
+    actor: &str,
+) -> Result<(), automerge::AutomergeError> {
+    if tx.get(obj, "deleted")?.is_none() {
+        tx.put(obj, "deleted", false)?;
+    }
+    if tx.get(obj, "updated_by")?.is_none() {
+        tx.put(obj, "updated_by", actor)?;
+    }
+    Ok(())
+}
+
+fn string_at(doc: &impl ReadDoc, obj: &ObjId, key: &str) -> Option<String> {
+    match doc.get(obj, key).ok()?? {
+        (Value::Scalar(scalar), _) => match scalar.as_ref() {
+            ScalarValue::Str(value) => Some(value.to_string()),
+            _ => None,
+        },
+        (Value::Object(ObjType::Text), text) => doc.text(&text).ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_ordered_per_schema() {
+        for schema in [
+            DocumentSchema::Settings,
+            DocumentSchema::Account,
+            DocumentSchema::User,
+            DocumentSchema::Project,
+        ] {
+            let versions: Vec<u64> = MIGRATIONS
+                .iter()
+                .filter(|migration| migration.schema == schema)
+                .map(|migration| migration.version)
+                .collect();
+            let expected: Vec<u64> = (1..=schema.current_version()).collect();
+            assert_eq!(versions, expected, "{:?}", schema);
+        }
+    }
+
+    #[test]
+    fn test_migrate_stamps_empty_document_once() {
+        let mut doc = Automerge::new();
+        assert_eq!(schema_version(&doc), 0);
+
+        let report = migrate(&mut doc, DocumentSchema::Project).unwrap().unwrap();
+        assert_eq!(report.from, 0);
+        assert_eq!(report.to, DocumentSchema::Project.current_version());
+        assert_eq!(schema_version(&doc), report.to);
+        // 空のドキュメントにはバージョン以外を書き込まない
+        assert_eq!(doc.keys(ROOT).collect::<Vec<_>>(), vec![SCHEMA_VERSION_KEY]);
+
+        let heads = doc.get_heads();
+        assert_eq!(migrate(&mut doc, DocumentSchema::Project).unwrap(), None);
+        assert_eq!(doc.get_heads(), heads);
+    }
+
+    #[test]
+    fn test_newer_document_is_left_untouched() {
+        let mut doc = Automerge::new();
+        let mut tx = doc.transaction();
+        tx.put(ROOT, SCHEMA_VERSION_KEY, 99_i64).unwrap();
+        tx.commit();
+        let heads = doc.get_heads();
+
+        assert_eq!(migrate(&mut doc, DocumentSchema::User).unwrap(), None);
+        assert_eq!(doc.get_heads(), heads);
+        assert_eq!(schema_version(&doc), 99);
+    }
+}
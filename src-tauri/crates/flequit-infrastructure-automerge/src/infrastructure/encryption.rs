@@ -0,0 +1,494 @@
+//! Automergeドキュメントの保存時暗号化
+//!
+//! `FileStorage` が `.automerge` ファイルを読み書きする際に使用する暗号化層。
+//! ドキュメントはRAM上でのみ平文として扱い、ディスクには暗号化済みバイト列を保存する。
+//!
+//! ## 鍵の階層
+//!
+//! - **データ鍵**: プロファイルごとに1つ生成されるランダムな256bit鍵（AES-256-GCM）
+//! - **鍵暗号化鍵**: パスフレーズからArgon2idで導出した鍵。データ鍵をラップする
+//!
+//! ラップ済みデータ鍵は `encryption.key.json` としてプロファイルのAutomergeディレクトリに保存される。
+//! パスフレーズ変更時はデータ鍵を再ラップするだけで、ドキュメントの再暗号化は不要。
+//!
+//! ## ファイル形式
+//!
+//! 暗号化の単位となるブロックと、ブロックを長さ付きフレームとして連ねたファイルの2形式がある。
+//!
+//! ```text
+//! ブロック:   [magic "FLQENC01" (8 bytes)][nonce (12 bytes)][ciphertext + tag]
+//! フレーム形式: [magic "FLQENC02" (8 bytes)]([ブロック長 u32 BE][ブロック])*
+//! ```
+//!
+//! Automerge-Repoの追記（変更チャンク）はフレームを1つ追加するだけで済むため、
+//! 追記のたびにドキュメント全体を復号・再暗号化する必要がない。
+//! 旧形式（ファイル全体が1ブロック）のファイルもそのまま読み込める。
+//!
+//! 先頭のマジックで暗号化済みファイルと平文ファイルを判別するため、
+//! 暗号化ストアと平文ストアが混在していても読み込みが可能（移行用）。
+
+use crate::errors::automerge_error::AutomergeError;
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::{Algorithm, Argon2, Params, Version};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// 暗号化ブロックの先頭に付与するマジックバイト
+pub const ENCRYPTED_FILE_MAGIC: &[u8; 8] = b"FLQENC01";
+
+/// フレーム形式の暗号化ファイルの先頭に付与するマジックバイト
+pub const ENCRYPTED_STREAM_MAGIC: &[u8; 8] = b"FLQENC02";
+
+/// プロファイル鍵ファイル名
+pub const PROFILE_KEY_FILENAME: &str = "encryption.key.json";
+
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 16;
+const KEY_FILE_VERSION: u32 = 1;
+const FRAME_LEN_SIZE: usize = 4;
+
+/// バイト列が暗号化済みファイル形式（ブロック・フレーム形式のいずれか）かどうかを判定
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(ENCRYPTED_FILE_MAGIC) || data.starts_with(ENCRYPTED_STREAM_MAGIC)
+}
+
+/// 暗号化ブロックを長さ付きフレームに変換
+pub(crate) fn encode_frame(block: &[u8]) -> Result<Vec<u8>, AutomergeError> {
+    let len = u32::try_from(block.len())
+        .map_err(|_| AutomergeError::EncryptionError("Encrypted chunk is too large".to_string()))?;
+    let mut frame = Vec::with_capacity(FRAME_LEN_SIZE + block.len());
+    frame.extend_from_slice(&len.to_be_bytes());
+    frame.extend_from_slice(block);
+    Ok(frame)
+}
+
+/// 平文を1フレームだけ含むフレーム形式のファイル内容を作成
+pub(crate) fn encrypt_stream(
+    cipher: &dyn DocumentCipher,
+    plaintext: &[u8],
+) -> Result<Vec<u8>, AutomergeError> {
+    let mut out = ENCRYPTED_STREAM_MAGIC.to_vec();
+    out.extend_from_slice(&encode_frame(&cipher.encrypt(plaintext)?)?);
+    Ok(out)
+}
+
+/// 暗号化済みファイルの内容を復号
+///
+/// フレーム形式の場合は各フレームを復号して連結する。
+/// 末尾のフレームが書き込み途中で途切れている場合は、そのフレームを除いて読み込む。
+pub(crate) fn decrypt_file(
+    cipher: &dyn DocumentCipher,
+    data: &[u8],
+) -> Result<Vec<u8>, AutomergeError> {
+    let Some(mut rest) = data.strip_prefix(ENCRYPTED_STREAM_MAGIC.as_slice()) else {
+        return cipher.decrypt(data);
+    };
+
+    let mut plaintext = Vec::new();
+    while !rest.is_empty() {
+        let Some((len, body)) = rest
+            .split_first_chunk::<FRAME_LEN_SIZE>()
+            .map(|(len, body)| (u32::from_be_bytes(*len) as usize, body))
+            .filter(|(len, body)| *len <= body.len())
+        else {
+            tracing::warn!("Ignoring truncated frame at the end of an encrypted document");
+            break;
+        };
+        let (block, next) = body.split_at(len);
+        plaintext.extend_from_slice(&cipher.decrypt(block)?);
+        rest = next;
+    }
+    Ok(plaintext)
+}
+
+/// ドキュメント暗号化の抽象
+///
+/// `FileStorage` に差し込む暗号化実装。
+/// 実装は暗号化済みバイト列に `ENCRYPTED_FILE_MAGIC` を付与すること。
+pub trait DocumentCipher: Send + Sync + fmt::Debug {
+    /// 平文を暗号化する
+    fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, AutomergeError>;
+
+    /// 暗号化済みバイト列を復号する
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, AutomergeError>;
+}
+
+/// AES-256-GCMによるドキュメント暗号化
+#[derive(Clone)]
+pub struct AesGcmDocumentCipher {
+    key: [u8; KEY_LEN],
+}
+
+impl fmt::Debug for AesGcmDocumentCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 鍵の内容はログに出力しない
+        f.debug_struct("AesGcmDocumentCipher")
+            .finish_non_exhaustive()
+    }
+}
+
+impl AesGcmDocumentCipher {
+    /// 256bitの生鍵から作成
+    pub fn from_key_bytes(key: [u8; KEY_LEN]) -> Self {
+        Self { key }
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.key))
+    }
+}
+
+impl DocumentCipher for AesGcmDocumentCipher {
+    fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, AutomergeError> {
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce_bytes);
+
+        let ciphertext = self
+            .cipher()
+            .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+            .map_err(|e| AutomergeError::EncryptionError(format!("Failed to encrypt: {}", e)))?;
+
+        let mut out = Vec::with_capacity(ENCRYPTED_FILE_MAGIC.len() + NONCE_LEN + ciphertext.len());
+        out.extend_from_slice(ENCRYPTED_FILE_MAGIC);
+        out.extend_from_slice(&nonce_bytes);
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, AutomergeError> {
+        if !data.starts_with(ENCRYPTED_FILE_MAGIC) {
+            return Err(AutomergeError::EncryptionError(
+                "Data is not in encrypted format".to_string(),
+            ));
+        }
+        let body = &data[ENCRYPTED_FILE_MAGIC.len()..];
+        if body.len() < NONCE_LEN {
+            return Err(AutomergeError::EncryptionError(
+                "Encrypted data is truncated".to_string(),
+            ));
+        }
+        let (nonce_bytes, ciphertext) = body.split_at(NONCE_LEN);
+
+        self.cipher()
+            .decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+            .map_err(|_| {
+                AutomergeError::EncryptionError(
+                    "Failed to decrypt document (wrong key or corrupted data)".to_string(),
+                )
+            })
+    }
+}
+
+/// Argon2idのパラメータ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    /// メモリコスト（KiB）
+    pub m_cost: u32,
+    /// 反復回数
+    pub t_cost: u32,
+    /// 並列度
+    pub p_cost: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            m_cost: Params::DEFAULT_M_COST,
+            t_cost: Params::DEFAULT_T_COST,
+            p_cost: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+impl KdfParams {
+    /// パスフレーズとソルトから鍵暗号化鍵を導出
+    fn derive_key(&self, passphrase: &str, salt: &[u8]) -> Result<[u8; KEY_LEN], AutomergeError> {
+        let params = Params::new(self.m_cost, self.t_cost, self.p_cost, Some(KEY_LEN))
+            .map_err(|e| AutomergeError::EncryptionError(format!("Invalid KDF params: {}", e)))?;
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+        let mut key = [0u8; KEY_LEN];
+        argon2
+            .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+            .map_err(|e| {
+                AutomergeError::EncryptionError(format!("Key derivation failed: {}", e))
+            })?;
+        Ok(key)
+    }
+}
+
+/// `encryption.key.json` の内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ProfileKeyFile {
+    version: u32,
+    kdf: String,
+    kdf_params: KdfParams,
+    /// ソルト（hex）
+    salt: String,
+    /// ラップ用ノンス（hex）
+    nonce: String,
+    /// ラップ済みデータ鍵（hex）
+    wrapped_key: String,
+}
+
+/// パスフレーズで保護されたプロファイル鍵の管理
+///
+/// プロファイル（Automergeデータディレクトリ）ごとに1つのデータ鍵を保持する。
+#[derive(Debug, Clone)]
+pub struct ProfileKeyStore {
+    key_path: PathBuf,
+    kdf_params: KdfParams,
+}
+
+impl ProfileKeyStore {
+    /// プロファイルディレクトリを指定して作成
+    pub fn new<P: AsRef<Path>>(profile_dir: P) -> Self {
+        Self {
+            key_path: profile_dir.as_ref().join(PROFILE_KEY_FILENAME),
+            kdf_params: KdfParams::default(),
+        }
+    }
+
+    /// 新規作成時に使用するKDFパラメータを指定
+    pub fn with_kdf_params(mut self, kdf_params: KdfParams) -> Self {
+        self.kdf_params = kdf_params;
+        self
+    }
+
+    /// 鍵ファイルのパス
+    pub fn key_path(&self) -> &Path {
+        &self.key_path
+    }
+
+    /// 鍵ファイルが存在するか（＝プロファイルが暗号化済みか）
+    pub fn exists(&self) -> bool {
+        self.key_path.exists()
+    }
+
+    /// 新しいデータ鍵を生成してパスフレーズで保護し保存
+    ///
+    /// 既に鍵ファイルが存在する場合はエラー（既存データを読めなくなるため上書きしない）。
+    pub fn create(&self, passphrase: &str) -> Result<AesGcmDocumentCipher, AutomergeError> {
+        if self.exists() {
+            return Err(AutomergeError::EncryptionError(format!(
+                "Profile key already exists: {:?}",
+                self.key_path
+            )));
+        }
+
+        let mut data_key = [0u8; KEY_LEN];
+        rand::thread_rng().fill_bytes(&mut data_key);
+
+        self.write_wrapped(passphrase, &data_key, &self.kdf_params)?;
+        tracing::info!("Created profile encryption key at {:?}", self.key_path);
+        Ok(AesGcmDocumentCipher::from_key_bytes(data_key))
+    }
+
+    /// パスフレーズでデータ鍵をアンラップ
+    pub fn unlock(&self, passphrase: &str) -> Result<AesGcmDocumentCipher, AutomergeError> {
+        let data_key = self.unwrap_data_key(passphrase)?;
+        Ok(AesGcmDocumentCipher::from_key_bytes(data_key))
+    }
+
+    /// 鍵ファイルがあればアンラップし、無ければ新規作成
+    pub fn unlock_or_create(
+        &self,
+        passphrase: &str,
+    ) -> Result<AesGcmDocumentCipher, AutomergeError> {
+        if self.exists() {
+            self.unlock(passphrase)
+        } else {
+            self.create(passphrase)
+        }
+    }
+
+    /// パスフレーズを変更（データ鍵は維持し再ラップのみ行う）
+    pub fn change_passphrase(
+        &self,
+        old_passphrase: &str,
+        new_passphrase: &str,
+    ) -> Result<(), AutomergeError> {
+        let data_key = self.unwrap_data_key(old_passphrase)?;
+        self.write_wrapped(new_passphrase, &data_key, &self.kdf_params)?;
+        tracing::info!("Changed profile passphrase at {:?}", self.key_path);
+        Ok(())
+    }
+
+    /// 鍵ファイルを削除（平文ストアへ移行した後に使用）
+    pub fn remove(&self) -> Result<(), AutomergeError> {
+        if self.exists() {
+            std::fs::remove_file(&self.key_path).map_err(|e| {
+                AutomergeError::IOError(format!("Failed to remove profile key: {}", e))
+            })?;
+        }
+        Ok(())
+    }
+
+    fn unwrap_data_key(&self, passphrase: &str) -> Result<[u8; KEY_LEN], AutomergeError> {
+        let content = std::fs::read_to_string(&self.key_path).map_err(|e| {
+            AutomergeError::IOError(format!(
+                "Failed to read profile key {:?}: {}",
+                self.key_path, e
+            ))
+        })?;
+        let key_file: ProfileKeyFile = serde_json::from_str(&content)?;
+
+        if key_file.version != KEY_FILE_VERSION {
+            return Err(AutomergeError::EncryptionError(format!(
+                "Unsupported profile key version: {}",
+                key_file.version
+            )));
+        }
+
+        let salt = decode_hex(&key_file.salt)?;
+        let nonce = decode_hex(&key_file.nonce)?;
+        let wrapped = decode_hex(&key_file.wrapped_key)?;
+        if nonce.len() != NONCE_LEN {
+            return Err(AutomergeError::EncryptionError(
+                "Invalid profile key nonce".to_string(),
+            ));
+        }
+
+        let kek = key_file.kdf_params.derive_key(passphrase, &salt)?;
+        let data_key = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&kek))
+            .decrypt(Nonce::from_slice(&nonce), wrapped.as_slice())
+            .map_err(|_| AutomergeError::EncryptionError("Invalid passphrase".to_string()))?;
+
+        data_key
+            .try_into()
+            .map_err(|_| AutomergeError::EncryptionError("Invalid profile key length".to_string()))
+    }
+
+    fn write_wrapped(
+        &self,
+        passphrase: &str,
+        data_key: &[u8; KEY_LEN],
+        kdf_params: &KdfParams,
+    ) -> Result<(), AutomergeError> {
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let kek = kdf_params.derive_key(passphrase, &salt)?;
+        let wrapped = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&kek))
+            .encrypt(Nonce::from_slice(&nonce), data_key.as_slice())
+            .map_err(|e| {
+                AutomergeError::EncryptionError(format!("Failed to wrap data key: {}", e))
+            })?;
+
+        let key_file = ProfileKeyFile {
+            version: KEY_FILE_VERSION,
+            kdf: "argon2id".to_string(),
+            kdf_params: kdf_params.clone(),
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            wrapped_key: hex::encode(wrapped),
+        };
+
+        if let Some(parent) = self.key_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                AutomergeError::IOError(format!("Failed to create directory: {}", e))
+            })?;
+        }
+        let json = serde_json::to_string_pretty(&key_file)?;
+        write_atomic(&self.key_path, json.as_bytes())
+    }
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>, AutomergeError> {
+    hex::decode(value)
+        .map_err(|e| AutomergeError::EncryptionError(format!("Invalid profile key file: {}", e)))
+}
+
+/// 一時ファイル経由でアトミックに書き込む
+pub(crate) fn write_atomic(path: &Path, data: &[u8]) -> Result<(), AutomergeError> {
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, data)
+        .map_err(|e| AutomergeError::IOError(format!("Failed to write {:?}: {}", tmp_path, e)))?;
+    std::fs::rename(&tmp_path, path)
+        .map_err(|e| AutomergeError::IOError(format!("Failed to replace {:?}: {}", path, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn fast_params() -> KdfParams {
+        KdfParams {
+            m_cost: 64,
+            t_cost: 1,
+            p_cost: 1,
+        }
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let cipher = AesGcmDocumentCipher::from_key_bytes([7u8; KEY_LEN]);
+        let plaintext = b"automerge document bytes";
+
+        let encrypted = cipher.encrypt(plaintext).unwrap();
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.windows(plaintext.len()).any(|w| w == plaintext));
+
+        let decrypted = cipher.decrypt(&encrypted).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn test_stream_frames_are_decrypted_in_order() {
+        let cipher = AesGcmDocumentCipher::from_key_bytes([7u8; KEY_LEN]);
+
+        let mut file = encrypt_stream(&cipher, b"first").unwrap();
+        file.extend_from_slice(&encode_frame(&cipher.encrypt(b"|second").unwrap()).unwrap());
+        assert!(is_encrypted(&file));
+        assert_eq!(decrypt_file(&cipher, &file).unwrap(), b"first|second");
+
+        // 書き込み途中で途切れた末尾のフレームは無視する
+        let complete_len = file.len();
+        file.extend_from_slice(&encode_frame(&cipher.encrypt(b"|third").unwrap()).unwrap());
+        file.truncate(complete_len + 10);
+        assert_eq!(decrypt_file(&cipher, &file).unwrap(), b"first|second");
+
+        // 旧形式（ファイル全体が1ブロック）も読み込める
+        let legacy = cipher.encrypt(b"legacy").unwrap();
+        assert_eq!(decrypt_file(&cipher, &legacy).unwrap(), b"legacy");
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_key_fails() {
+        let cipher = AesGcmDocumentCipher::from_key_bytes([1u8; KEY_LEN]);
+        let other = AesGcmDocumentCipher::from_key_bytes([2u8; KEY_LEN]);
+
+        let encrypted = cipher.encrypt(b"secret").unwrap();
+        assert!(matches!(
+            other.decrypt(&encrypted),
+            Err(AutomergeError::EncryptionError(_))
+        ));
+    }
+
+    #[test]
+    fn test_profile_key_store_unlock_and_change_passphrase() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = ProfileKeyStore::new(temp_dir.path()).with_kdf_params(fast_params());
+
+        let created = store.create("correct horse").unwrap();
+        let encrypted = created.encrypt(b"payload").unwrap();
+
+        assert!(store.unlock("wrong").is_err());
+        assert!(store.create("again").is_err());
+
+        store
+            .change_passphrase("correct horse", "battery staple")
+            .unwrap();
+        assert!(store.unlock("correct horse").is_err());
+
+        let unlocked = store.unlock("battery staple").unwrap();
+        assert_eq!(unlocked.decrypt(&encrypted).unwrap(), b"payload");
+    }
+}
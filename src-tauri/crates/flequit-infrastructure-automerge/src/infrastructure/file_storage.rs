@@ -1,4 +1,8 @@
 use crate::errors::automerge_error::AutomergeError;
+use crate::infrastructure::encryption::{
+    DocumentCipher, ENCRYPTED_STREAM_MAGIC, decrypt_file, encode_frame, encrypt_stream,
+    is_encrypted, write_atomic,
+};
 use automerge_repo::{DocumentId, Storage, StorageError};
 use std::collections::HashMap;
 use std::future::Future;
@@ -41,11 +45,17 @@ impl FileNameMapping {
 }
 
 /// ファイルシステムベースのAutomergeストレージ実装
+///
+/// `cipher` が設定されている場合、ディスク上のファイルは暗号化して保存される。
+/// 読み込み時はファイル先頭のマジックで暗号化の有無を判別するため、
+/// 平文ファイルが残っていても透過的に読み込める。
 #[derive(Clone, Debug)]
 pub struct FileStorage {
     base_path: PathBuf,
     /// メモリ内のマッピング（永続化しない）
     mapping: Arc<RwLock<FileNameMapping>>,
+    /// 保存時暗号化（Noneの場合は平文で保存）
+    cipher: Option<Arc<dyn DocumentCipher>>,
 }
 
 impl FileStorage {
@@ -54,8 +64,21 @@ impl FileStorage {
     /// 起動時に既存の.automergeファイルをスキャンし、
     /// ファイル内容からDocumentIdを読み取ってメモリ内マッピングを構築する
     pub fn new<P: AsRef<Path>>(base_path: P) -> Result<Self, AutomergeError> {
-        let base_path = base_path.as_ref().to_path_buf();
+        Self::build(base_path.as_ref().to_path_buf(), None)
+    }
+
+    /// 暗号化を有効にしたFileStorageを作成
+    pub fn new_with_cipher<P: AsRef<Path>>(
+        base_path: P,
+        cipher: Arc<dyn DocumentCipher>,
+    ) -> Result<Self, AutomergeError> {
+        Self::build(base_path.as_ref().to_path_buf(), Some(cipher))
+    }
 
+    fn build(
+        base_path: PathBuf,
+        cipher: Option<Arc<dyn DocumentCipher>>,
+    ) -> Result<Self, AutomergeError> {
         // ベースディレクトリを作成
         if !base_path.exists() {
             std::fs::create_dir_all(&base_path).map_err(|e| {
@@ -94,7 +117,14 @@ impl FileStorage {
                         let filename_without_ext = filename.replace(".automerge", "");
 
                         // まずファイル内容からDocumentIdを抽出を試みる
-                        let doc_id = match std::fs::read(&path) {
+                        let contents =
+                            std::fs::read(&path)
+                                .map_err(|e| e.to_string())
+                                .and_then(|data| {
+                                    Self::decode_contents(cipher.as_deref(), data)
+                                        .map_err(|e| e.to_string())
+                                });
+                        let doc_id = match contents {
                             Ok(data) => {
                                 if let Some(id) = Self::extract_document_id_from_file(&data) {
                                     tracing::info!(
@@ -149,9 +179,207 @@ impl FileStorage {
         Ok(Self {
             base_path,
             mapping: Arc::new(RwLock::new(mapping)),
+            cipher,
         })
     }
 
+    /// 暗号化が有効かどうか
+    pub fn is_encryption_enabled(&self) -> bool {
+        self.cipher.is_some()
+    }
+
+    /// ディスクから読み込んだバイト列を平文に変換
+    ///
+    /// 暗号化済みファイルは復号し、平文ファイルはそのまま返す（移行期間の互換性のため）。
+    fn decode_contents(
+        cipher: Option<&dyn DocumentCipher>,
+        data: Vec<u8>,
+    ) -> Result<Vec<u8>, AutomergeError> {
+        if !is_encrypted(&data) {
+            return Ok(data);
+        }
+        match cipher {
+            Some(cipher) => decrypt_file(cipher, &data),
+            None => Err(AutomergeError::EncryptionError(
+                "Document is encrypted but no key is unlocked".to_string(),
+            )),
+        }
+    }
+
+    /// ドキュメントファイルを読み込み、平文を返す
+    fn read_document_file(&self, path: &Path) -> std::io::Result<Result<Vec<u8>, AutomergeError>> {
+        let data = std::fs::read(path)?;
+        Ok(Self::decode_contents(self.cipher.as_deref(), data))
+    }
+
+    /// ドキュメント全体を書き込む（暗号化有効時は1フレームのファイルとしてアトミックに置換）
+    fn write_document_file(&self, path: &Path, full_doc: &[u8]) -> Result<(), AutomergeError> {
+        match &self.cipher {
+            Some(cipher) => write_atomic(path, &encrypt_stream(cipher.as_ref(), full_doc)?),
+            None => {
+                if Self::file_is_encrypted(path)? {
+                    return Err(AutomergeError::EncryptionError(format!(
+                        "Refusing to overwrite encrypted document without key: {:?}",
+                        path
+                    )));
+                }
+                std::fs::write(path, full_doc)
+                    .map_err(|e| AutomergeError::StorageError(format!("{:?}: {}", path, e)))
+            }
+        }
+    }
+
+    /// ドキュメントに変更チャンクを追記する
+    ///
+    /// 暗号化有効時は変更チャンクを暗号化し、フレームとして末尾に追記する。
+    /// フレーム形式でないファイル（旧形式・平文）は、最初の追記時にフレーム形式へ書き換える。
+    fn append_document_file(&self, path: &Path, changes: &[u8]) -> Result<(), AutomergeError> {
+        if let Some(cipher) = &self.cipher {
+            let header = Self::read_header(path)?;
+            if header
+                .as_ref()
+                .is_some_and(|header| header.as_slice() != ENCRYPTED_STREAM_MAGIC)
+            {
+                let mut full_doc = match self.read_document_file(path) {
+                    Ok(contents) => contents?,
+                    Err(e) => {
+                        return Err(AutomergeError::StorageError(format!("{:?}: {}", path, e)));
+                    }
+                };
+                full_doc.extend_from_slice(changes);
+                return self.write_document_file(path, &full_doc);
+            }
+
+            let frame = encode_frame(&cipher.encrypt(changes)?)?;
+            return match header {
+                Some(_) => Self::append_bytes(path, &frame),
+                None => {
+                    let mut data = ENCRYPTED_STREAM_MAGIC.to_vec();
+                    data.extend_from_slice(&frame);
+                    write_atomic(path, &data)
+                }
+            };
+        }
+
+        if Self::file_is_encrypted(path)? {
+            return Err(AutomergeError::EncryptionError(format!(
+                "Refusing to append to encrypted document without key: {:?}",
+                path
+            )));
+        }
+        Self::append_bytes(path, changes)
+    }
+
+    /// ファイル末尾にバイト列を追記する
+    fn append_bytes(path: &Path, data: &[u8]) -> Result<(), AutomergeError> {
+        use std::io::Write;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| {
+                AutomergeError::StorageError(format!("Failed to open {:?}: {}", path, e))
+            })?;
+        file.write_all(data).map_err(|e| {
+            AutomergeError::StorageError(format!("Failed to write {:?}: {}", path, e))
+        })?;
+        file.flush()
+            .map_err(|e| AutomergeError::StorageError(format!("Failed to flush {:?}: {}", path, e)))
+    }
+
+    /// ファイルが暗号化形式かどうかを先頭バイトで判定
+    fn file_is_encrypted(path: &Path) -> Result<bool, AutomergeError> {
+        Ok(Self::read_header(path)?.is_some_and(|header| is_encrypted(&header)))
+    }
+
+    /// ファイル先頭の最大8バイトを読み込む（ファイルが無い場合はNone）
+    fn read_header(path: &Path) -> Result<Option<Vec<u8>>, AutomergeError> {
+        use std::io::Read;
+        let mut file = match std::fs::File::open(path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(AutomergeError::StorageError(format!("{:?}: {}", path, e)));
+            }
+        };
+        let mut header = Vec::with_capacity(ENCRYPTED_STREAM_MAGIC.len());
+        file.by_ref()
+            .take(ENCRYPTED_STREAM_MAGIC.len() as u64)
+            .read_to_end(&mut header)
+            .map_err(|e| AutomergeError::StorageError(format!("{:?}: {}", path, e)))?;
+        Ok(Some(header))
+    }
+
+    /// 保存済みの.automergeファイル（.deleted/ を含む）を列挙
+    fn stored_document_files(&self) -> Result<Vec<PathBuf>, AutomergeError> {
+        let mut files = Vec::new();
+        for dir in [self.base_path.clone(), self.base_path.join(".deleted")] {
+            let entries = match std::fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => {
+                    return Err(AutomergeError::IOError(format!(
+                        "Failed to read directory {:?}: {}",
+                        dir, e
+                    )));
+                }
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_file()
+                    && path.extension().and_then(|ext| ext.to_str()) == Some("automerge")
+                {
+                    files.push(path);
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    /// 既存の平文ドキュメントを暗号化形式に移行
+    ///
+    /// 暗号化済みのファイルはスキップする。処理したファイル数を返す。
+    pub fn encrypt_existing_documents(&self) -> Result<usize, AutomergeError> {
+        let cipher = self.cipher.as_ref().ok_or_else(|| {
+            AutomergeError::EncryptionError("Encryption is not enabled".to_string())
+        })?;
+
+        let mut migrated = 0;
+        for path in self.stored_document_files()? {
+            let data = std::fs::read(&path)
+                .map_err(|e| AutomergeError::IOError(format!("{:?}: {}", path, e)))?;
+            if is_encrypted(&data) {
+                continue;
+            }
+            write_atomic(&path, &encrypt_stream(cipher.as_ref(), &data)?)?;
+            migrated += 1;
+        }
+        tracing::info!("Encrypted {} existing automerge documents", migrated);
+        Ok(migrated)
+    }
+
+    /// 暗号化済みドキュメントを平文形式に戻す（暗号化の無効化用）
+    ///
+    /// 平文のファイルはスキップする。処理したファイル数を返す。
+    pub fn decrypt_existing_documents(&self) -> Result<usize, AutomergeError> {
+        let cipher = self.cipher.as_ref().ok_or_else(|| {
+            AutomergeError::EncryptionError("Encryption is not enabled".to_string())
+        })?;
+
+        let mut migrated = 0;
+        for path in self.stored_document_files()? {
+            let data = std::fs::read(&path)
+                .map_err(|e| AutomergeError::IOError(format!("{:?}: {}", path, e)))?;
+            if !is_encrypted(&data) {
+                continue;
+            }
+            write_atomic(&path, &decrypt_file(cipher.as_ref(), &data)?)?;
+            migrated += 1;
+        }
+        tracing::info!("Decrypted {} automerge documents", migrated);
+        Ok(migrated)
+    }
+
     /// ファイルの内容からDocumentIdを抽出
     ///
     /// automerge-repoのファイルフォーマットから DocumentId を読み取る
@@ -309,8 +537,9 @@ impl FileStorage {
     pub async fn get(&self, id: DocumentId) -> Result<Option<Vec<u8>>, AutomergeError> {
         let path = self.document_path(&id);
 
-        match std::fs::read(&path) {
-            Ok(data) => {
+        match self.read_document_file(&path) {
+            Ok(contents) => {
+                let data = contents?;
                 tracing::debug!(
                     "Successfully read document {} ({} bytes)",
                     id.as_uuid_str(),
@@ -369,6 +598,13 @@ impl FileStorage {
         }
     }
 
+    /// ドキュメント全体を指定したパスへ書き出す（バックアップのスナップショット用）
+    ///
+    /// 暗号化有効時は保存時と同じ形式で暗号化する。
+    pub fn write_snapshot(&self, path: &Path, full_doc: &[u8]) -> Result<(), AutomergeError> {
+        self.write_document_file(path, full_doc)
+    }
+
     /// ドキュメントに変更を追記
     pub async fn append(&self, id: DocumentId, changes: Vec<u8>) -> Result<(), AutomergeError> {
         let path = self.document_path(&id);
@@ -382,57 +618,18 @@ impl FileStorage {
         // ファイル名からマッピングを確保（ファイル書き込み時に必ずマッピングを保持）
         self.ensure_mapping_from_path(&path, id.clone());
 
-        match std::fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)
-        {
-            Ok(mut file) => {
-                use std::io::Write;
-                match file.write_all(&changes) {
-                    Ok(_) => {
-                        tracing::debug!(
-                            "Successfully appended {} bytes to document {}",
-                            changes.len(),
-                            id.as_uuid_str()
-                        );
-                        match file.flush() {
-                            Ok(_) => Ok(()),
-                            Err(e) => {
-                                tracing::error!(
-                                    "Failed to flush document {}: {}",
-                                    id.as_uuid_str(),
-                                    e
-                                );
-                                Err(AutomergeError::StorageError(format!(
-                                    "Failed to flush document {}: {}",
-                                    id.as_uuid_str(),
-                                    e
-                                )))
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        tracing::error!("Failed to write to document {}: {}", id.as_uuid_str(), e);
-                        Err(AutomergeError::StorageError(format!(
-                            "Failed to write to document {}: {}",
-                            id.as_uuid_str(),
-                            e
-                        )))
-                    }
-                }
+        match self.append_document_file(&path, &changes) {
+            Ok(()) => {
+                tracing::debug!(
+                    "Successfully appended {} bytes to document {}",
+                    changes.len(),
+                    id.as_uuid_str()
+                );
+                Ok(())
             }
             Err(e) => {
-                tracing::error!(
-                    "Failed to open document {} for append: {}",
-                    id.as_uuid_str(),
-                    e
-                );
-                Err(AutomergeError::StorageError(format!(
-                    "Failed to open document {} for append: {}",
-                    id.as_uuid_str(),
-                    e
-                )))
+                tracing::error!("Failed to append to document {}: {}", id.as_uuid_str(), e);
+                Err(e)
             }
         }
     }
@@ -450,7 +647,7 @@ impl FileStorage {
         // ファイル名からマッピングを確保（ファイル書き込み時に必ずマッピングを保持）
         self.ensure_mapping_from_path(&path, id.clone());
 
-        match std::fs::write(&path, &full_doc) {
+        match self.write_document_file(&path, &full_doc) {
             Ok(_) => {
                 tracing::debug!(
                     "Successfully compacted document {} ({} bytes)",
@@ -461,11 +658,7 @@ impl FileStorage {
             }
             Err(e) => {
                 tracing::error!("Failed to compact document {}: {}", id.as_uuid_str(), e);
-                Err(AutomergeError::StorageError(format!(
-                    "Failed to compact document {}: {}",
-                    id.as_uuid_str(),
-                    e
-                )))
+                Err(e)
             }
         }
     }
@@ -478,9 +671,10 @@ impl Storage for FileStorage {
         id: DocumentId,
     ) -> Pin<Box<dyn Future<Output = Result<Option<Vec<u8>>, StorageError>> + Send + 'static>> {
         let path = self.document_path(&id);
+        let file_storage = self.clone();
         Box::pin(async move {
-            match std::fs::read(&path) {
-                Ok(data) => {
+            match file_storage.read_document_file(&path) {
+                Ok(Ok(data)) => {
                     tracing::debug!(
                         "Successfully read document {} ({} bytes)",
                         id.as_uuid_str(),
@@ -488,6 +682,10 @@ impl Storage for FileStorage {
                     );
                     Ok(Some(data))
                 }
+                Ok(Err(e)) => {
+                    tracing::error!("Failed to decode document {}: {}", id.as_uuid_str(), e);
+                    Err(StorageError::Error)
+                }
                 Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
                     tracing::debug!("Document {} not found", id.as_uuid_str());
                     Err(StorageError::Error)
@@ -551,48 +749,17 @@ impl Storage for FileStorage {
             // ファイル名からマッピングを確保
             file_storage.ensure_mapping_from_path(&path, id.clone());
 
-            match std::fs::OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(&path)
-            {
-                Ok(mut file) => {
-                    use std::io::Write;
-                    match file.write_all(&changes) {
-                        Ok(_) => {
-                            tracing::debug!(
-                                "Successfully appended {} bytes to document {}",
-                                changes.len(),
-                                id.as_uuid_str()
-                            );
-                            match file.flush() {
-                                Ok(_) => Ok(()),
-                                Err(e) => {
-                                    tracing::error!(
-                                        "Failed to flush document {}: {}",
-                                        id.as_uuid_str(),
-                                        e
-                                    );
-                                    Err(StorageError::Error)
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            tracing::error!(
-                                "Failed to write to document {}: {}",
-                                id.as_uuid_str(),
-                                e
-                            );
-                            Err(StorageError::Error)
-                        }
-                    }
+            match file_storage.append_document_file(&path, &changes) {
+                Ok(()) => {
+                    tracing::debug!(
+                        "Successfully appended {} bytes to document {}",
+                        changes.len(),
+                        id.as_uuid_str()
+                    );
+                    Ok(())
                 }
                 Err(e) => {
-                    tracing::error!(
-                        "Failed to open document {} for append: {}",
-                        id.as_uuid_str(),
-                        e
-                    );
+                    tracing::error!("Failed to append to document {}: {}", id.as_uuid_str(), e);
                     Err(StorageError::Error)
                 }
             }
@@ -610,7 +777,7 @@ impl Storage for FileStorage {
             // ファイル名からマッピングを確保
             file_storage.ensure_mapping_from_path(&path, id.clone());
 
-            match std::fs::write(&path, &full_doc) {
+            match file_storage.write_document_file(&path, &full_doc) {
                 Ok(_) => {
                     tracing::debug!(
                         "Successfully compacted document {} ({} bytes)",
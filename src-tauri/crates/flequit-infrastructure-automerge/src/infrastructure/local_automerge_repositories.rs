@@ -6,15 +6,22 @@
 use crate::errors::automerge_error::AutomergeError;
 use crate::infrastructure::{
     accounts::account::AccountLocalAutomergeRepository, document_manager::DocumentManager,
+    task_projects::comment::CommentLocalAutomergeRepository,
     task_projects::project::ProjectLocalAutomergeRepository,
+    task_projects::reminder::ReminderLocalAutomergeRepository,
+    task_projects::snooze::SnoozeLocalAutomergeRepository,
+    task_projects::status_transition_rule::StatusTransitionRuleLocalAutomergeRepository,
     task_projects::subtask::SubTaskLocalAutomergeRepository,
     task_projects::subtask_assignments::SubtaskAssignmentLocalAutomergeRepository,
     task_projects::subtask_tag::SubtaskTagLocalAutomergeRepository,
     task_projects::tag::TagLocalAutomergeRepository,
     task_projects::task::TaskLocalAutomergeRepository,
     task_projects::task_assignments::TaskAssignmentLocalAutomergeRepository,
+    task_projects::task_dependency::TaskDependencyLocalAutomergeRepository,
     task_projects::task_list::TaskListLocalAutomergeRepository,
     task_projects::task_tag::TaskTagLocalAutomergeRepository,
+    task_projects::time_entry::TimeEntryLocalAutomergeRepository,
+    task_projects::workflow_status::WorkflowStatusLocalAutomergeRepository,
     user_preferences::tag_bookmark::TagBookmarkLocalAutomergeRepository,
     users::user::UserLocalAutomergeRepository,
 };
@@ -34,8 +41,15 @@ pub struct LocalAutomergeRepositories {
     pub tags: TagLocalAutomergeRepository,
     pub task_tags: TaskTagLocalAutomergeRepository,
     pub task_assignments: TaskAssignmentLocalAutomergeRepository,
+    pub task_dependencies: TaskDependencyLocalAutomergeRepository,
     pub subtask_tags: SubtaskTagLocalAutomergeRepository,
     pub subtask_assignments: SubtaskAssignmentLocalAutomergeRepository,
+    pub time_entries: TimeEntryLocalAutomergeRepository,
+    pub reminders: ReminderLocalAutomergeRepository,
+    pub snoozes: SnoozeLocalAutomergeRepository,
+    pub comments: CommentLocalAutomergeRepository,
+    pub status_transition_rules: StatusTransitionRuleLocalAutomergeRepository,
+    pub workflow_statuses: WorkflowStatusLocalAutomergeRepository,
     pub accounts: AccountLocalAutomergeRepository,
     pub users: UserLocalAutomergeRepository,
     pub tag_bookmarks: TagBookmarkLocalAutomergeRepository,
@@ -53,9 +67,21 @@ impl LocalAutomergeRepositories {
             task_tags: TaskTagLocalAutomergeRepository::new(base_path.clone()).await?,
             task_assignments: TaskAssignmentLocalAutomergeRepository::new(base_path.clone())
                 .await?,
+            task_dependencies: TaskDependencyLocalAutomergeRepository::new(base_path.clone())
+                .await?,
             subtask_tags: SubtaskTagLocalAutomergeRepository::new(base_path.clone()).await?,
             subtask_assignments: SubtaskAssignmentLocalAutomergeRepository::new(base_path.clone())
                 .await?,
+            time_entries: TimeEntryLocalAutomergeRepository::new(base_path.clone()).await?,
+            reminders: ReminderLocalAutomergeRepository::new(base_path.clone()).await?,
+            snoozes: SnoozeLocalAutomergeRepository::new(base_path.clone()).await?,
+            comments: CommentLocalAutomergeRepository::new(base_path.clone()).await?,
+            status_transition_rules: StatusTransitionRuleLocalAutomergeRepository::new(
+                base_path.clone(),
+            )
+            .await?,
+            workflow_statuses: WorkflowStatusLocalAutomergeRepository::new(base_path.clone())
+                .await?,
             accounts: AccountLocalAutomergeRepository::new(base_path.clone()).await?,
             users: UserLocalAutomergeRepository::new(base_path.clone()).await?,
             tag_bookmarks: TagBookmarkLocalAutomergeRepository::new(base_path).await?,
@@ -90,6 +116,10 @@ impl LocalAutomergeRepositories {
                 document_manager.clone(),
             )
             .await?,
+            task_dependencies: TaskDependencyLocalAutomergeRepository::new_with_manager(
+                document_manager.clone(),
+            )
+            .await?,
             subtask_tags: SubtaskTagLocalAutomergeRepository::new_with_manager(
                 document_manager.clone(),
             )
@@ -98,6 +128,25 @@ impl LocalAutomergeRepositories {
                 document_manager.clone(),
             )
             .await?,
+            time_entries: TimeEntryLocalAutomergeRepository::new_with_manager(
+                document_manager.clone(),
+            )
+            .await?,
+            reminders: ReminderLocalAutomergeRepository::new_with_manager(document_manager.clone())
+                .await?,
+            snoozes: SnoozeLocalAutomergeRepository::new_with_manager(document_manager.clone())
+                .await?,
+            comments: CommentLocalAutomergeRepository::new_with_manager(document_manager.clone())
+                .await?,
+            status_transition_rules:
+                StatusTransitionRuleLocalAutomergeRepository::new_with_manager(
+                    document_manager.clone(),
+                )
+                .await?,
+            workflow_statuses: WorkflowStatusLocalAutomergeRepository::new_with_manager(
+                document_manager.clone(),
+            )
+            .await?,
             accounts: AccountLocalAutomergeRepository::new_with_manager(document_manager.clone())
                 .await?,
             users: UserLocalAutomergeRepository::new_with_manager(document_manager.clone()).await?,
@@ -146,11 +195,46 @@ impl LocalAutomergeRepositories {
         &self.task_assignments
     }
 
+    /// タスク依存関係リポジトリへのアクセス
+    pub fn task_dependencies(&self) -> &TaskDependencyLocalAutomergeRepository {
+        &self.task_dependencies
+    }
+
     /// サブタスクアサインリポジトリへのアクセス
     pub fn subtask_assignments(&self) -> &SubtaskAssignmentLocalAutomergeRepository {
         &self.subtask_assignments
     }
 
+    /// タイムエントリリポジトリへのアクセス
+    pub fn time_entries(&self) -> &TimeEntryLocalAutomergeRepository {
+        &self.time_entries
+    }
+
+    /// リマインダーリポジトリへのアクセス
+    pub fn reminders(&self) -> &ReminderLocalAutomergeRepository {
+        &self.reminders
+    }
+
+    /// スヌーズリポジトリへのアクセス
+    pub fn snoozes(&self) -> &SnoozeLocalAutomergeRepository {
+        &self.snoozes
+    }
+
+    /// コメントリポジトリへのアクセス
+    pub fn comments(&self) -> &CommentLocalAutomergeRepository {
+        &self.comments
+    }
+
+    /// ステータス遷移ルールリポジトリへのアクセス
+    pub fn status_transition_rules(&self) -> &StatusTransitionRuleLocalAutomergeRepository {
+        &self.status_transition_rules
+    }
+
+    /// ワークフローステータスリポジトリへのアクセス
+    pub fn workflow_statuses(&self) -> &WorkflowStatusLocalAutomergeRepository {
+        &self.workflow_statuses
+    }
+
     /// タグブックマークリポジトリへのアクセス
     pub fn tag_bookmarks(&self) -> &TagBookmarkLocalAutomergeRepository {
         &self.tag_bookmarks
@@ -1,6 +1,9 @@
 pub mod accounts;
 pub mod document;
+pub mod document_journal;
 pub mod document_manager;
+pub mod document_schema;
+pub mod encryption;
 pub mod file_storage;
 pub mod local_automerge_repositories;
 pub mod task_projects;
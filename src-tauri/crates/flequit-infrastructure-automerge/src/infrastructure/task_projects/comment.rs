@@ -0,0 +1,159 @@
+use crate::infrastructure::document::Document;
+
+use super::super::document_manager::{DocumentManager, DocumentType};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::comment::Comment;
+use flequit_model::traits::Trackable;
+use flequit_model::types::id_types::{CommentId, ProjectId, TaskId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::comment_repository_trait::CommentRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// プロジェクトドキュメント内でコメントを保持するキーの接頭辞
+pub const COMMENT_KEY_PREFIX: &str = "comment:";
+
+/// コメントを保持するキー
+fn comment_key(id: &CommentId) -> String {
+    format!("{}{}", COMMENT_KEY_PREFIX, id)
+}
+
+/// Automerge実装のコメントリポジトリ
+///
+/// コメントはプロジェクトドキュメントのルート直下に `comment:{id}` のキーで1件ずつ保存する。
+/// 他のエンティティのように1つのリストにまとめると、複数のメンバーがオフラインで
+/// コメントを追加した場合にマージ後にどちらかのリストしか残らないため、
+/// コメントごとにキーを分けてすべてのコメントが残るようにしている。
+#[derive(Debug)]
+pub struct CommentLocalAutomergeRepository {
+    document_manager: Arc<Mutex<DocumentManager>>,
+}
+
+impl CommentLocalAutomergeRepository {
+    pub async fn new(base_path: PathBuf) -> Result<Self, RepositoryError> {
+        let document_manager = DocumentManager::new(base_path)?;
+        Ok(Self {
+            document_manager: Arc::new(Mutex::new(document_manager)),
+        })
+    }
+
+    /// 共有DocumentManagerを使用して新しいインスタンスを作成
+    pub async fn new_with_manager(
+        document_manager: Arc<Mutex<DocumentManager>>,
+    ) -> Result<Self, RepositoryError> {
+        Ok(Self { document_manager })
+    }
+
+    /// 指定されたプロジェクトのDocumentを取得または作成
+    async fn get_or_create_document(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Document, RepositoryError> {
+        let doc_type = DocumentType::Project(*project_id);
+        let mut manager = self.document_manager.lock().await;
+        manager
+            .get_or_create(&doc_type)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    /// 指定されたプロジェクトの全コメントを投稿順に取得（論理削除済みを含む）
+    async fn list_all_comments_raw(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Comment>, RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        let mut comments = document.load_entries::<Comment>(COMMENT_KEY_PREFIX).await?;
+        comments.sort_by(|a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.id.to_string().cmp(&b.id.to_string()))
+        });
+        Ok(comments)
+    }
+
+    pub async fn list_comments(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Comment>, RepositoryError> {
+        let comments = self.list_all_comments_raw(project_id).await?;
+        Ok(comments.into_iter().filter(|c| !c.is_deleted()).collect())
+    }
+
+    /// 指定タスク（サブタスクへのものを含む）のコメントを投稿順に取得
+    pub async fn find_by_task(
+        &self,
+        project_id: &ProjectId,
+        task_id: &TaskId,
+    ) -> Result<Vec<Comment>, RepositoryError> {
+        let comments = self.list_comments(project_id).await?;
+        Ok(comments
+            .into_iter()
+            .filter(|c| c.task_id == *task_id)
+            .collect())
+    }
+}
+
+#[async_trait]
+impl CommentRepositoryTrait for CommentLocalAutomergeRepository {}
+
+#[async_trait]
+impl ProjectRepository<Comment, CommentId> for CommentLocalAutomergeRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &Comment,
+        _user_id: &UserId,
+        _timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        document
+            .save_data(&comment_key(&entity.id), entity)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    /// 論理削除済みのコメントも返す（削除の取り消しに使う）
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &CommentId,
+    ) -> Result<Option<Comment>, RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        Ok(document.load_data::<Comment>(&comment_key(id)).await?)
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Comment>, RepositoryError> {
+        self.list_comments(project_id).await
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &CommentId) -> Result<(), RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        let deleted = document
+            .delete_data(&comment_key(id))
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))?;
+        if !deleted {
+            return Err(RepositoryError::NotFound(format!(
+                "Comment not found: {}",
+                id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &CommentId,
+    ) -> Result<bool, RepositoryError> {
+        Ok(self.find_by_id(project_id, id).await?.is_some())
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        Ok(self.list_comments(project_id).await?.len() as u64)
+    }
+}
@@ -653,6 +653,59 @@ impl ProjectLocalAutomergeRepository {
         self.save_project_document(project_id, snapshot).await
     }
 
+    // ========== プロジェクトバンドル ==========
+
+    /// プロジェクトドキュメントを変更履歴込みのバイナリとして取得
+    ///
+    /// # 戻り値
+    /// Automergeの保存形式のバイナリ。プロジェクトが存在しない場合はエラー。
+    pub async fn export_project_history(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<u8>, RepositoryError> {
+        if self.get_project_document(project_id).await?.is_none() {
+            return Err(RepositoryError::NotFound(format!(
+                "Project not found: {}",
+                project_id
+            )));
+        }
+        let document = self.get_or_create_document(project_id).await?;
+        Ok(document.save_history().await)
+    }
+
+    /// 変更履歴込みのバイナリをプロジェクトドキュメントにマージ
+    ///
+    /// IDを保持したままインポートする場合に使用する。
+    /// 既存のプロジェクトであれば双方の変更が統合され、存在しなければ履歴ごと作成される。
+    pub async fn merge_project_history(
+        &self,
+        project_id: &ProjectId,
+        data: &[u8],
+    ) -> Result<(), RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        document.merge_history(data).await?;
+        Ok(())
+    }
+
+    /// JSON化したプロジェクトドキュメントを新しいプロジェクトとして書き込む
+    ///
+    /// IDを振り直したコピーを作成する場合に使用する。履歴は引き継がれない。
+    pub async fn import_project_json(
+        &self,
+        project_id: &ProjectId,
+        project_json: &serde_json::Value,
+    ) -> Result<(), RepositoryError> {
+        let fields = project_json.as_object().ok_or_else(|| {
+            RepositoryError::ValidationError("Project document must be a JSON object".to_string())
+        })?;
+
+        let document = self.get_or_create_document(project_id).await?;
+        for (key, value) in fields {
+            document.save_data(key, value).await?;
+        }
+        Ok(())
+    }
+
     // ========== クエリフィルタ（Phase 3） ==========
 
     /// 削除済みプロジェクトを取得
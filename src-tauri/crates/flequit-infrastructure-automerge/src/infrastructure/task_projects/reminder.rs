@@ -0,0 +1,145 @@
+use crate::infrastructure::document::Document;
+
+use super::super::document_manager::{DocumentManager, DocumentType};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::reminder::Reminder;
+use flequit_model::traits::Trackable;
+use flequit_model::types::id_types::{ProjectId, ReminderId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::reminder_repository_trait::ReminderRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// プロジェクトドキュメント内でリマインダーを保持するキー
+const REMINDERS_KEY: &str = "reminders";
+
+/// Automerge実装のリマインダーリポジトリ
+///
+/// リマインダーはプロジェクトドキュメントのルート直下 `reminders` に
+/// リストとして保存され、プロジェクトのメンバー間で同期される。
+#[derive(Debug)]
+pub struct ReminderLocalAutomergeRepository {
+    document_manager: Arc<Mutex<DocumentManager>>,
+}
+
+impl ReminderLocalAutomergeRepository {
+    pub async fn new(base_path: PathBuf) -> Result<Self, RepositoryError> {
+        let document_manager = DocumentManager::new(base_path)?;
+        Ok(Self {
+            document_manager: Arc::new(Mutex::new(document_manager)),
+        })
+    }
+
+    /// 共有DocumentManagerを使用して新しいインスタンスを作成
+    pub async fn new_with_manager(
+        document_manager: Arc<Mutex<DocumentManager>>,
+    ) -> Result<Self, RepositoryError> {
+        Ok(Self { document_manager })
+    }
+
+    /// 指定されたプロジェクトのDocumentを取得または作成
+    async fn get_or_create_document(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Document, RepositoryError> {
+        let doc_type = DocumentType::Project(*project_id);
+        let mut manager = self.document_manager.lock().await;
+        manager
+            .get_or_create(&doc_type)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    /// 指定されたプロジェクトの全リマインダーを取得（論理削除済みを含む）
+    async fn list_all_reminders_raw(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Reminder>, RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        let reminders = document.load_data::<Vec<Reminder>>(REMINDERS_KEY).await?;
+        Ok(reminders.unwrap_or_default())
+    }
+
+    async fn save_reminders(
+        &self,
+        project_id: &ProjectId,
+        reminders: &Vec<Reminder>,
+    ) -> Result<(), RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        document
+            .save_data(REMINDERS_KEY, reminders)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    pub async fn list_reminders(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Reminder>, RepositoryError> {
+        let reminders = self.list_all_reminders_raw(project_id).await?;
+        Ok(reminders.into_iter().filter(|r| !r.is_deleted()).collect())
+    }
+}
+
+#[async_trait]
+impl ReminderRepositoryTrait for ReminderLocalAutomergeRepository {}
+
+#[async_trait]
+impl ProjectRepository<Reminder, ReminderId> for ReminderLocalAutomergeRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &Reminder,
+        _user_id: &UserId,
+        _timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let mut reminders = self.list_all_reminders_raw(project_id).await?;
+        if let Some(existing) = reminders.iter_mut().find(|r| r.id == entity.id) {
+            *existing = entity.clone();
+        } else {
+            reminders.push(entity.clone());
+        }
+        self.save_reminders(project_id, &reminders).await
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &ReminderId,
+    ) -> Result<Option<Reminder>, RepositoryError> {
+        let reminders = self.list_reminders(project_id).await?;
+        Ok(reminders.into_iter().find(|r| r.id == *id))
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Reminder>, RepositoryError> {
+        self.list_reminders(project_id).await
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &ReminderId) -> Result<(), RepositoryError> {
+        let mut reminders = self.list_all_reminders_raw(project_id).await?;
+        let initial_len = reminders.len();
+        reminders.retain(|r| r.id != *id);
+        if reminders.len() == initial_len {
+            return Err(RepositoryError::NotFound(format!(
+                "Reminder not found: {}",
+                id
+            )));
+        }
+        self.save_reminders(project_id, &reminders).await
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &ReminderId,
+    ) -> Result<bool, RepositoryError> {
+        Ok(self.find_by_id(project_id, id).await?.is_some())
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        Ok(self.list_reminders(project_id).await?.len() as u64)
+    }
+}
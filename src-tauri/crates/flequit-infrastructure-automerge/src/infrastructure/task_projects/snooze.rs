@@ -0,0 +1,141 @@
+use crate::infrastructure::document::Document;
+
+use super::super::document_manager::{DocumentManager, DocumentType};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::snooze::Snooze;
+use flequit_model::traits::Trackable;
+use flequit_model::types::id_types::{ProjectId, SnoozeId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::snooze_repository_trait::SnoozeRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// プロジェクトドキュメント内でスヌーズを保持するキー
+const SNOOZES_KEY: &str = "snoozes";
+
+/// Automerge実装のスヌーズリポジトリ
+///
+/// スヌーズはプロジェクトドキュメントのルート直下 `snoozes` に
+/// リストとして保存され、プロジェクトのメンバー間で同期される。
+#[derive(Debug)]
+pub struct SnoozeLocalAutomergeRepository {
+    document_manager: Arc<Mutex<DocumentManager>>,
+}
+
+impl SnoozeLocalAutomergeRepository {
+    pub async fn new(base_path: PathBuf) -> Result<Self, RepositoryError> {
+        let document_manager = DocumentManager::new(base_path)?;
+        Ok(Self {
+            document_manager: Arc::new(Mutex::new(document_manager)),
+        })
+    }
+
+    /// 共有DocumentManagerを使用して新しいインスタンスを作成
+    pub async fn new_with_manager(
+        document_manager: Arc<Mutex<DocumentManager>>,
+    ) -> Result<Self, RepositoryError> {
+        Ok(Self { document_manager })
+    }
+
+    /// 指定されたプロジェクトのDocumentを取得または作成
+    async fn get_or_create_document(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Document, RepositoryError> {
+        let doc_type = DocumentType::Project(*project_id);
+        let mut manager = self.document_manager.lock().await;
+        manager
+            .get_or_create(&doc_type)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    /// 指定されたプロジェクトの全スヌーズの記録を取得（論理削除済みを含む）
+    async fn list_all_snoozes_raw(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Snooze>, RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        let snoozes = document.load_data::<Vec<Snooze>>(SNOOZES_KEY).await?;
+        Ok(snoozes.unwrap_or_default())
+    }
+
+    async fn save_snoozes(
+        &self,
+        project_id: &ProjectId,
+        snoozes: &Vec<Snooze>,
+    ) -> Result<(), RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        document
+            .save_data(SNOOZES_KEY, snoozes)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    pub async fn list_snoozes(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Snooze>, RepositoryError> {
+        let snoozes = self.list_all_snoozes_raw(project_id).await?;
+        Ok(snoozes.into_iter().filter(|s| !s.is_deleted()).collect())
+    }
+}
+
+#[async_trait]
+impl SnoozeRepositoryTrait for SnoozeLocalAutomergeRepository {}
+
+#[async_trait]
+impl ProjectRepository<Snooze, SnoozeId> for SnoozeLocalAutomergeRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &Snooze,
+        _user_id: &UserId,
+        _timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let mut snoozes = self.list_all_snoozes_raw(project_id).await?;
+        if let Some(existing) = snoozes.iter_mut().find(|s| s.id == entity.id) {
+            *existing = entity.clone();
+        } else {
+            snoozes.push(entity.clone());
+        }
+        self.save_snoozes(project_id, &snoozes).await
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &SnoozeId,
+    ) -> Result<Option<Snooze>, RepositoryError> {
+        let snoozes = self.list_snoozes(project_id).await?;
+        Ok(snoozes.into_iter().find(|s| s.id == *id))
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Snooze>, RepositoryError> {
+        self.list_snoozes(project_id).await
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &SnoozeId) -> Result<(), RepositoryError> {
+        let mut snoozes = self.list_all_snoozes_raw(project_id).await?;
+        let initial_len = snoozes.len();
+        snoozes.retain(|s| s.id != *id);
+        if snoozes.len() == initial_len {
+            return Err(RepositoryError::NotFound(format!(
+                "Snooze not found: {}",
+                id
+            )));
+        }
+        self.save_snoozes(project_id, &snoozes).await
+    }
+
+    async fn exists(&self, project_id: &ProjectId, id: &SnoozeId) -> Result<bool, RepositoryError> {
+        Ok(self.find_by_id(project_id, id).await?.is_some())
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        Ok(self.list_snoozes(project_id).await?.len() as u64)
+    }
+}
@@ -0,0 +1,156 @@
+use crate::infrastructure::document::Document;
+
+use super::super::document_manager::{DocumentManager, DocumentType};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::status_transition_rule::StatusTransitionRule;
+use flequit_model::traits::Trackable;
+use flequit_model::types::id_types::{ProjectId, StatusTransitionRuleId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::status_transition_rule_repository_trait::StatusTransitionRuleRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// プロジェクトドキュメント内でステータス遷移ルールを保持するキー
+const STATUS_TRANSITION_RULES_KEY: &str = "status_transition_rules";
+
+/// Automerge実装のステータス遷移ルールリポジトリ
+///
+/// ルールはプロジェクトドキュメントのルート直下 `status_transition_rules` に
+/// リストとして保存され、プロジェクトのメンバー間で同期される。
+#[derive(Debug)]
+pub struct StatusTransitionRuleLocalAutomergeRepository {
+    document_manager: Arc<Mutex<DocumentManager>>,
+}
+
+impl StatusTransitionRuleLocalAutomergeRepository {
+    pub async fn new(base_path: PathBuf) -> Result<Self, RepositoryError> {
+        let document_manager = DocumentManager::new(base_path)?;
+        Ok(Self {
+            document_manager: Arc::new(Mutex::new(document_manager)),
+        })
+    }
+
+    /// 共有DocumentManagerを使用して新しいインスタンスを作成
+    pub async fn new_with_manager(
+        document_manager: Arc<Mutex<DocumentManager>>,
+    ) -> Result<Self, RepositoryError> {
+        Ok(Self { document_manager })
+    }
+
+    /// 指定されたプロジェクトのDocumentを取得または作成
+    async fn get_or_create_document(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Document, RepositoryError> {
+        let doc_type = DocumentType::Project(*project_id);
+        let mut manager = self.document_manager.lock().await;
+        manager
+            .get_or_create(&doc_type)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    /// 指定されたプロジェクトの全ルールを取得（論理削除済みを含む）
+    async fn list_all_rules_raw(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<StatusTransitionRule>, RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        let rules = document
+            .load_data::<Vec<StatusTransitionRule>>(STATUS_TRANSITION_RULES_KEY)
+            .await?;
+        Ok(rules.unwrap_or_default())
+    }
+
+    async fn save_rules(
+        &self,
+        project_id: &ProjectId,
+        rules: &Vec<StatusTransitionRule>,
+    ) -> Result<(), RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        document
+            .save_data(STATUS_TRANSITION_RULES_KEY, rules)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    pub async fn list_status_transition_rules(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<StatusTransitionRule>, RepositoryError> {
+        let rules = self.list_all_rules_raw(project_id).await?;
+        Ok(rules.into_iter().filter(|e| !e.is_deleted()).collect())
+    }
+}
+
+#[async_trait]
+impl StatusTransitionRuleRepositoryTrait for StatusTransitionRuleLocalAutomergeRepository {}
+
+#[async_trait]
+impl ProjectRepository<StatusTransitionRule, StatusTransitionRuleId>
+    for StatusTransitionRuleLocalAutomergeRepository
+{
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &StatusTransitionRule,
+        _user_id: &UserId,
+        _timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let mut rules = self.list_all_rules_raw(project_id).await?;
+        if let Some(existing) = rules.iter_mut().find(|e| e.id == entity.id) {
+            *existing = entity.clone();
+        } else {
+            rules.push(entity.clone());
+        }
+        self.save_rules(project_id, &rules).await
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<Option<StatusTransitionRule>, RepositoryError> {
+        let rules = self.list_status_transition_rules(project_id).await?;
+        Ok(rules.into_iter().find(|e| e.id == *id))
+    }
+
+    async fn find_all(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<StatusTransitionRule>, RepositoryError> {
+        self.list_status_transition_rules(project_id).await
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<(), RepositoryError> {
+        let mut rules = self.list_all_rules_raw(project_id).await?;
+        let initial_len = rules.len();
+        rules.retain(|e| e.id != *id);
+        if rules.len() == initial_len {
+            return Err(RepositoryError::NotFound(format!(
+                "StatusTransitionRule not found: {}",
+                id
+            )));
+        }
+        self.save_rules(project_id, &rules).await
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<bool, RepositoryError> {
+        Ok(self.find_by_id(project_id, id).await?.is_some())
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        Ok(self.list_status_transition_rules(project_id).await?.len() as u64)
+    }
+}
@@ -0,0 +1,203 @@
+//! TaskDependency用Automergeリポジトリ
+
+use crate::infrastructure::document::Document;
+
+use super::super::document_manager::{DocumentManager, DocumentType};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::task_dependency::TaskDependency;
+use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
+use flequit_repository::repositories::project_relation_repository_trait::ProjectRelationRepository;
+use flequit_repository::repositories::task_projects::task_dependency_repository_trait::TaskDependencyRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// プロジェクトドキュメント内でタスク依存関係を保持するキー
+const TASK_DEPENDENCIES_KEY: &str = "task_dependencies";
+
+/// Automerge実装のタスク依存関係リポジトリ
+///
+/// 依存関係はプロジェクトドキュメントのルート直下 `task_dependencies` に
+/// リストとして保存され、プロジェクトのメンバー間で同期される。
+#[derive(Debug)]
+pub struct TaskDependencyLocalAutomergeRepository {
+    document_manager: Arc<Mutex<DocumentManager>>,
+}
+
+impl TaskDependencyLocalAutomergeRepository {
+    /// 新しいTaskDependencyRepositoryを作成
+    pub async fn new(base_path: PathBuf) -> Result<Self, RepositoryError> {
+        let document_manager = DocumentManager::new(base_path)?;
+        Ok(Self {
+            document_manager: Arc::new(Mutex::new(document_manager)),
+        })
+    }
+
+    /// 共有DocumentManagerを使用して新しいインスタンスを作成
+    pub async fn new_with_manager(
+        document_manager: Arc<Mutex<DocumentManager>>,
+    ) -> Result<Self, RepositoryError> {
+        Ok(Self { document_manager })
+    }
+
+    /// 指定されたプロジェクトのDocumentを取得または作成
+    async fn get_or_create_document(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Document, RepositoryError> {
+        let document_type = DocumentType::Project(*project_id);
+        let mut manager = self.document_manager.lock().await;
+        manager
+            .get_or_create(&document_type)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    async fn load_dependencies(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<TaskDependency>, RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        Ok(document
+            .load_data::<Vec<TaskDependency>>(TASK_DEPENDENCIES_KEY)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn save_dependencies(
+        &self,
+        project_id: &ProjectId,
+        dependencies: &Vec<TaskDependency>,
+    ) -> Result<(), RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        document
+            .save_data(TASK_DEPENDENCIES_KEY, dependencies)
+            .await?;
+        Ok(())
+    }
+
+    /// 指定タスクを先行タスクとする依存関係（後続タスク側）を取得
+    pub async fn find_dependents(
+        &self,
+        project_id: &ProjectId,
+        depends_on_task_id: &TaskId,
+    ) -> Result<Vec<TaskDependency>, RepositoryError> {
+        let mut dependencies = self.load_dependencies(project_id).await?;
+        dependencies.retain(|d| d.depends_on_task_id == *depends_on_task_id);
+        Ok(dependencies)
+    }
+
+    /// 指定タスクが関わる依存関係（先行・後続の両方）をすべて削除
+    pub async fn remove_all_by_task_id(
+        &self,
+        project_id: &ProjectId,
+        task_id: &TaskId,
+    ) -> Result<(), RepositoryError> {
+        let mut dependencies = self.load_dependencies(project_id).await?;
+        dependencies.retain(|d| d.task_id != *task_id && d.depends_on_task_id != *task_id);
+        self.save_dependencies(project_id, &dependencies).await
+    }
+}
+
+impl TaskDependencyRepositoryTrait for TaskDependencyLocalAutomergeRepository {}
+
+#[async_trait]
+impl ProjectRelationRepository<TaskDependency, TaskId, TaskId>
+    for TaskDependencyLocalAutomergeRepository
+{
+    async fn add(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let mut dependencies = self.load_dependencies(project_id).await?;
+        let exists = dependencies
+            .iter()
+            .any(|d| d.task_id == *parent_id && d.depends_on_task_id == *child_id);
+        if exists {
+            return Ok(());
+        }
+
+        dependencies.push(TaskDependency {
+            task_id: *parent_id,
+            depends_on_task_id: *child_id,
+            created_at: *timestamp,
+            updated_at: *timestamp,
+            deleted: false,
+            updated_by: *user_id,
+        });
+        self.save_dependencies(project_id, &dependencies).await
+    }
+
+    async fn remove(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+    ) -> Result<(), RepositoryError> {
+        let mut dependencies = self.load_dependencies(project_id).await?;
+        dependencies.retain(|d| !(d.task_id == *parent_id && d.depends_on_task_id == *child_id));
+        self.save_dependencies(project_id, &dependencies).await
+    }
+
+    async fn remove_all(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<(), RepositoryError> {
+        let mut dependencies = self.load_dependencies(project_id).await?;
+        dependencies.retain(|d| d.task_id != *parent_id);
+        self.save_dependencies(project_id, &dependencies).await
+    }
+
+    async fn find_relations(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<Vec<TaskDependency>, RepositoryError> {
+        let mut dependencies = self.load_dependencies(project_id).await?;
+        dependencies.retain(|d| d.task_id == *parent_id);
+        Ok(dependencies)
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<bool, RepositoryError> {
+        Ok(self.count(project_id, parent_id).await? > 0)
+    }
+
+    async fn count(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<u64, RepositoryError> {
+        Ok(self.find_relations(project_id, parent_id).await?.len() as u64)
+    }
+
+    async fn find_all(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<TaskDependency>, RepositoryError> {
+        self.load_dependencies(project_id).await
+    }
+
+    async fn find_relation(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+    ) -> Result<Option<TaskDependency>, RepositoryError> {
+        Ok(self
+            .load_dependencies(project_id)
+            .await?
+            .into_iter()
+            .find(|d| d.task_id == *parent_id && d.depends_on_task_id == *child_id))
+    }
+}
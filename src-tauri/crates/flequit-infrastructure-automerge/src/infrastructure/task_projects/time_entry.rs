@@ -0,0 +1,151 @@
+use crate::infrastructure::document::Document;
+
+use super::super::document_manager::{DocumentManager, DocumentType};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::time_entry::TimeEntry;
+use flequit_model::traits::Trackable;
+use flequit_model::types::id_types::{ProjectId, TimeEntryId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::time_entry_repository_trait::TimeEntryRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// プロジェクトドキュメント内でタイムエントリを保持するキー
+const TIME_ENTRIES_KEY: &str = "time_entries";
+
+/// Automerge実装のタイムエントリリポジトリ
+///
+/// タイムエントリはプロジェクトドキュメントのルート直下 `time_entries` に
+/// リストとして保存され、プロジェクトのメンバー間で同期される。
+#[derive(Debug)]
+pub struct TimeEntryLocalAutomergeRepository {
+    document_manager: Arc<Mutex<DocumentManager>>,
+}
+
+impl TimeEntryLocalAutomergeRepository {
+    pub async fn new(base_path: PathBuf) -> Result<Self, RepositoryError> {
+        let document_manager = DocumentManager::new(base_path)?;
+        Ok(Self {
+            document_manager: Arc::new(Mutex::new(document_manager)),
+        })
+    }
+
+    /// 共有DocumentManagerを使用して新しいインスタンスを作成
+    pub async fn new_with_manager(
+        document_manager: Arc<Mutex<DocumentManager>>,
+    ) -> Result<Self, RepositoryError> {
+        Ok(Self { document_manager })
+    }
+
+    /// 指定されたプロジェクトのDocumentを取得または作成
+    async fn get_or_create_document(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Document, RepositoryError> {
+        let doc_type = DocumentType::Project(*project_id);
+        let mut manager = self.document_manager.lock().await;
+        manager
+            .get_or_create(&doc_type)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    /// 指定されたプロジェクトの全タイムエントリを取得（論理削除済みを含む）
+    async fn list_all_time_entries_raw(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<TimeEntry>, RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        let entries = document
+            .load_data::<Vec<TimeEntry>>(TIME_ENTRIES_KEY)
+            .await?;
+        Ok(entries.unwrap_or_default())
+    }
+
+    async fn save_time_entries(
+        &self,
+        project_id: &ProjectId,
+        entries: &Vec<TimeEntry>,
+    ) -> Result<(), RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        document
+            .save_data(TIME_ENTRIES_KEY, entries)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    pub async fn list_time_entries(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<TimeEntry>, RepositoryError> {
+        let entries = self.list_all_time_entries_raw(project_id).await?;
+        Ok(entries.into_iter().filter(|e| !e.is_deleted()).collect())
+    }
+}
+
+#[async_trait]
+impl TimeEntryRepositoryTrait for TimeEntryLocalAutomergeRepository {}
+
+#[async_trait]
+impl ProjectRepository<TimeEntry, TimeEntryId> for TimeEntryLocalAutomergeRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &TimeEntry,
+        _user_id: &UserId,
+        _timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let mut entries = self.list_all_time_entries_raw(project_id).await?;
+        if let Some(existing) = entries.iter_mut().find(|e| e.id == entity.id) {
+            *existing = entity.clone();
+        } else {
+            entries.push(entity.clone());
+        }
+        self.save_time_entries(project_id, &entries).await
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<Option<TimeEntry>, RepositoryError> {
+        let entries = self.list_time_entries(project_id).await?;
+        Ok(entries.into_iter().find(|e| e.id == *id))
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<TimeEntry>, RepositoryError> {
+        self.list_time_entries(project_id).await
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<(), RepositoryError> {
+        let mut entries = self.list_all_time_entries_raw(project_id).await?;
+        let initial_len = entries.len();
+        entries.retain(|e| e.id != *id);
+        if entries.len() == initial_len {
+            return Err(RepositoryError::NotFound(format!(
+                "TimeEntry not found: {}",
+                id
+            )));
+        }
+        self.save_time_entries(project_id, &entries).await
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<bool, RepositoryError> {
+        Ok(self.find_by_id(project_id, id).await?.is_some())
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        Ok(self.list_time_entries(project_id).await?.len() as u64)
+    }
+}
@@ -0,0 +1,163 @@
+use crate::infrastructure::document::Document;
+
+use super::super::document_manager::{DocumentManager, DocumentType};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::workflow_status::WorkflowStatus;
+use flequit_model::traits::Trackable;
+use flequit_model::types::id_types::{ProjectId, UserId, WorkflowStatusId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::workflow_status_repository_trait::WorkflowStatusRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// プロジェクトドキュメント内でワークフローステータスを保持するキー
+const WORKFLOW_STATUSES_KEY: &str = "workflow_statuses";
+
+/// Automerge実装のワークフローステータスリポジトリ
+///
+/// ステータスはプロジェクトドキュメントのルート直下 `workflow_statuses` に
+/// リストとして保存され、プロジェクトのメンバー間で同期される。
+#[derive(Debug)]
+pub struct WorkflowStatusLocalAutomergeRepository {
+    document_manager: Arc<Mutex<DocumentManager>>,
+}
+
+impl WorkflowStatusLocalAutomergeRepository {
+    pub async fn new(base_path: PathBuf) -> Result<Self, RepositoryError> {
+        let document_manager = DocumentManager::new(base_path)?;
+        Ok(Self {
+            document_manager: Arc::new(Mutex::new(document_manager)),
+        })
+    }
+
+    /// 共有DocumentManagerを使用して新しいインスタンスを作成
+    pub async fn new_with_manager(
+        document_manager: Arc<Mutex<DocumentManager>>,
+    ) -> Result<Self, RepositoryError> {
+        Ok(Self { document_manager })
+    }
+
+    /// 指定されたプロジェクトのDocumentを取得または作成
+    async fn get_or_create_document(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Document, RepositoryError> {
+        let doc_type = DocumentType::Project(*project_id);
+        let mut manager = self.document_manager.lock().await;
+        manager
+            .get_or_create(&doc_type)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    /// 指定されたプロジェクトの全ステータスを取得（論理削除済みを含む）
+    async fn list_all_statuses_raw(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<WorkflowStatus>, RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        let statuses = document
+            .load_data::<Vec<WorkflowStatus>>(WORKFLOW_STATUSES_KEY)
+            .await?;
+        Ok(statuses.unwrap_or_default())
+    }
+
+    async fn save_statuses(
+        &self,
+        project_id: &ProjectId,
+        statuses: &Vec<WorkflowStatus>,
+    ) -> Result<(), RepositoryError> {
+        let document = self.get_or_create_document(project_id).await?;
+        document
+            .save_data(WORKFLOW_STATUSES_KEY, statuses)
+            .await
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))
+    }
+
+    /// 表示順（同じ場合は作成順）に並べて返す
+    pub async fn list_workflow_statuses(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<WorkflowStatus>, RepositoryError> {
+        let mut statuses: Vec<WorkflowStatus> = self
+            .list_all_statuses_raw(project_id)
+            .await?
+            .into_iter()
+            .filter(|e| !e.is_deleted())
+            .collect();
+        statuses.sort_by_key(|e| (e.order_index, e.created_at));
+        Ok(statuses)
+    }
+}
+
+#[async_trait]
+impl WorkflowStatusRepositoryTrait for WorkflowStatusLocalAutomergeRepository {}
+
+#[async_trait]
+impl ProjectRepository<WorkflowStatus, WorkflowStatusId>
+    for WorkflowStatusLocalAutomergeRepository
+{
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &WorkflowStatus,
+        _user_id: &UserId,
+        _timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let mut statuses = self.list_all_statuses_raw(project_id).await?;
+        if let Some(existing) = statuses.iter_mut().find(|e| e.id == entity.id) {
+            *existing = entity.clone();
+        } else {
+            statuses.push(entity.clone());
+        }
+        self.save_statuses(project_id, &statuses).await
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &WorkflowStatusId,
+    ) -> Result<Option<WorkflowStatus>, RepositoryError> {
+        let statuses = self.list_workflow_statuses(project_id).await?;
+        Ok(statuses.into_iter().find(|e| e.id == *id))
+    }
+
+    async fn find_all(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<WorkflowStatus>, RepositoryError> {
+        self.list_workflow_statuses(project_id).await
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        id: &WorkflowStatusId,
+    ) -> Result<(), RepositoryError> {
+        let mut statuses = self.list_all_statuses_raw(project_id).await?;
+        let initial_len = statuses.len();
+        statuses.retain(|e| e.id != *id);
+        if statuses.len() == initial_len {
+            return Err(RepositoryError::NotFound(format!(
+                "WorkflowStatus not found: {}",
+                id
+            )));
+        }
+        self.save_statuses(project_id, &statuses).await
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &WorkflowStatusId,
+    ) -> Result<bool, RepositoryError> {
+        Ok(self.find_by_id(project_id, id).await?.is_some())
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        Ok(self.list_workflow_statuses(project_id).await?.len() as u64)
+    }
+}
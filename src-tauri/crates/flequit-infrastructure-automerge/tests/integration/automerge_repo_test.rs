@@ -896,6 +896,34 @@ async fn test_cache_clear() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Repo停止テスト
+#[tokio::test]
+async fn test_shutdown_stops_repo() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir_path = TestPathGenerator::generate_test_dir(file!(), "test_temp");
+    std::fs::create_dir_all(&temp_dir_path)?;
+    let mut manager = DocumentManager::new(&temp_dir_path)?;
+
+    let doc_type = DocumentType::Settings;
+    manager
+        .save_data(&doc_type, "test_key", &"test_value")
+        .await?;
+
+    // 停止時にキャッシュしていたドキュメントも破棄される
+    manager.shutdown().await?;
+    assert!(!manager.exists(&doc_type));
+    drop(manager);
+
+    // 停止後は同じディレクトリで新しいRepoを起動できる
+    let mut manager = DocumentManager::new(&temp_dir_path)?;
+    manager
+        .save_data(&doc_type, "test_key", &"updated_value")
+        .await?;
+    let loaded_value: Option<String> = manager.load_data(&doc_type, "test_key").await?;
+    assert_eq!(loaded_value, Some("updated_value".to_string()));
+
+    Ok(())
+}
+
 /// パス指定でのデータ保存・読み込みテスト
 #[tokio::test]
 async fn test_path_based_data_operations() -> Result<(), Box<dyn std::error::Error>> {
@@ -0,0 +1,200 @@
+//! コメントの同時編集 結合テスト
+//!
+//! 複数のメンバーがオフラインでコメントを追加・編集した後に
+//! 変更履歴を交換しても、すべてのコメントが残ることを検証する
+
+use chrono::{DateTime, Duration, Utc};
+use flequit_infrastructure_automerge::infrastructure::document::Document;
+use flequit_infrastructure_automerge::infrastructure::document_manager::{
+    DocumentManager, DocumentType,
+};
+use flequit_infrastructure_automerge::infrastructure::task_projects::comment::CommentLocalAutomergeRepository;
+use flequit_model::models::task_projects::comment::Comment;
+use flequit_model::types::id_types::{CommentId, ProjectId, TaskId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use std::sync::Arc;
+use tempfile::TempDir;
+use tokio::sync::Mutex;
+
+/// 1台の端末（DocumentManagerとコメントリポジトリ）
+struct Peer {
+    _dir: TempDir,
+    manager: Arc<Mutex<DocumentManager>>,
+    comments: CommentLocalAutomergeRepository,
+}
+
+impl Peer {
+    async fn new() -> Self {
+        let dir = TempDir::new().unwrap();
+        let manager = Arc::new(Mutex::new(DocumentManager::new(dir.path()).unwrap()));
+        let comments = CommentLocalAutomergeRepository::new_with_manager(manager.clone())
+            .await
+            .unwrap();
+        Self {
+            _dir: dir,
+            manager,
+            comments,
+        }
+    }
+
+    async fn document(&self, project_id: &ProjectId) -> Document {
+        self.manager
+            .lock()
+            .await
+            .get_or_create(&DocumentType::Project(*project_id))
+            .await
+            .unwrap()
+    }
+
+    /// 相手の端末の変更履歴を取り込む
+    async fn pull_from(&self, other: &Peer, project_id: &ProjectId) {
+        let history = other.document(project_id).await.save_history().await;
+        self.document(project_id)
+            .await
+            .merge_history(&history)
+            .await
+            .unwrap();
+    }
+}
+
+fn new_comment(
+    project_id: ProjectId,
+    task_id: TaskId,
+    author_id: UserId,
+    body: &str,
+    created_at: DateTime<Utc>,
+) -> Comment {
+    Comment {
+        id: CommentId::new(),
+        project_id,
+        task_id,
+        subtask_id: None,
+        author_id,
+        body: body.to_string(),
+        mentioned_user_ids: vec![],
+        created_at,
+        edited_at: None,
+        updated_at: created_at,
+        deleted: false,
+        updated_by: author_id,
+    }
+}
+
+#[tokio::test]
+async fn test_offline_comments_from_two_members_are_merged() {
+    let project_id = ProjectId::new();
+    let task_id = TaskId::new();
+    let alice = UserId::new();
+    let bob = UserId::new();
+    let base = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+
+    // 共有済みのスレッド
+    let peer_a = Peer::new().await;
+    let peer_b = Peer::new().await;
+    let mut opening = new_comment(project_id, task_id, alice, "仕様を確認しました", base);
+    peer_a
+        .comments
+        .save(&project_id, &opening, &alice, &base)
+        .await
+        .unwrap();
+    peer_b.pull_from(&peer_a, &project_id).await;
+
+    // それぞれオフラインでコメントを追加し、Aは最初のコメントも編集する
+    let from_a = new_comment(
+        project_id,
+        task_id,
+        alice,
+        "@bob 見積もりをお願いします",
+        base + Duration::minutes(10),
+    );
+    let from_b = new_comment(
+        project_id,
+        task_id,
+        bob,
+        "明日までに対応します",
+        base + Duration::minutes(5),
+    );
+    let edited_at = base + Duration::minutes(20);
+    opening.body = "仕様を確認しました（修正版）".to_string();
+    opening.edited_at = Some(edited_at);
+    opening.updated_at = edited_at;
+    for comment in [&from_a, &opening] {
+        peer_a
+            .comments
+            .save(&project_id, comment, &alice, &edited_at)
+            .await
+            .unwrap();
+    }
+    peer_b
+        .comments
+        .save(&project_id, &from_b, &bob, &from_b.created_at)
+        .await
+        .unwrap();
+
+    // 変更履歴を交換する
+    peer_a.pull_from(&peer_b, &project_id).await;
+    peer_b.pull_from(&peer_a, &project_id).await;
+
+    // どちらの端末でもすべてのコメントが投稿順に並ぶ
+    for peer in [&peer_a, &peer_b] {
+        let thread = peer
+            .comments
+            .find_by_task(&project_id, &task_id)
+            .await
+            .unwrap();
+        assert_eq!(
+            thread.iter().map(|c| c.id).collect::<Vec<_>>(),
+            vec![opening.id, from_b.id, from_a.id]
+        );
+        assert_eq!(thread[0].body, "仕様を確認しました（修正版）");
+        assert_eq!(thread[0].edited_at, Some(edited_at));
+    }
+}
+
+#[tokio::test]
+async fn test_soft_deleted_comment_is_hidden_but_restorable() {
+    let peer = Peer::new().await;
+    let project_id = ProjectId::new();
+    let task_id = TaskId::new();
+    let author_id = UserId::new();
+    let now = Utc::now();
+
+    let mut comment = new_comment(project_id, task_id, author_id, "誤投稿", now);
+    peer.comments
+        .save(&project_id, &comment, &author_id, &now)
+        .await
+        .unwrap();
+    comment.deleted = true;
+    peer.comments
+        .save(&project_id, &comment, &author_id, &now)
+        .await
+        .unwrap();
+
+    assert!(
+        peer.comments
+            .find_by_task(&project_id, &task_id)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    let stored = peer
+        .comments
+        .find_by_id(&project_id, &comment.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(stored.deleted);
+
+    // 物理削除するとキーごと消える
+    peer.comments
+        .delete(&project_id, &comment.id)
+        .await
+        .unwrap();
+    assert!(
+        peer.comments
+            .find_by_id(&project_id, &comment.id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
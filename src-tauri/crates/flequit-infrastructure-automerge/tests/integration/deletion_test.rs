@@ -44,6 +44,7 @@ fn make_test_task(project_id: &ProjectId, task_list_id: &TaskListId, user_id: &U
         title: "Test Task".to_string(),
         description: None,
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 0,
         plan_start_date: None,
         plan_end_date: None,
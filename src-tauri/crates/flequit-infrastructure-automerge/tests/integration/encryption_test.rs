@@ -0,0 +1,157 @@
+//! 保存時暗号化のテスト
+//!
+//! FileStorageの暗号化読み書き、平文ストアからの移行、
+//! プロファイル鍵によるアンロックの動作を検証する
+
+use std::sync::Arc;
+
+use automerge_repo::DocumentId;
+use flequit_infrastructure_automerge::errors::automerge_error::AutomergeError;
+use flequit_infrastructure_automerge::infrastructure::document_manager::DocumentManager;
+use flequit_infrastructure_automerge::infrastructure::encryption::{
+    DocumentCipher, KdfParams, ProfileKeyStore, is_encrypted,
+};
+use flequit_infrastructure_automerge::infrastructure::file_storage::FileStorage;
+use flequit_testing::TestPathGenerator;
+
+fn fast_kdf_params() -> KdfParams {
+    KdfParams {
+        m_cost: 64,
+        t_cost: 1,
+        p_cost: 1,
+    }
+}
+
+fn new_document_id() -> DocumentId {
+    uuid::Uuid::new_v4().to_string().parse().unwrap()
+}
+
+#[tokio::test]
+async fn test_encrypted_file_storage_roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_path =
+        TestPathGenerator::generate_test_dir(file!(), "test_encrypted_file_storage_roundtrip");
+    std::fs::create_dir_all(&storage_path)?;
+
+    let cipher: Arc<dyn DocumentCipher> = Arc::new(
+        ProfileKeyStore::new(&storage_path)
+            .with_kdf_params(fast_kdf_params())
+            .create("passphrase")?,
+    );
+    let storage = FileStorage::new_with_cipher(&storage_path, cipher)?;
+
+    let doc_id = new_document_id();
+    storage.set_mapping(doc_id.clone(), "project_secret".to_string());
+
+    storage
+        .compact(doc_id.clone(), b"first-chunk:top secret".to_vec())
+        .await?;
+    let compacted = std::fs::read(storage_path.join("project_secret.automerge"))?;
+    storage
+        .append(doc_id.clone(), b"|second-chunk".to_vec())
+        .await?;
+
+    // ディスク上は暗号化されており、平文を含まない
+    let raw = std::fs::read(storage_path.join("project_secret.automerge"))?;
+    assert!(is_encrypted(&raw));
+    // 追記は既存の内容を書き換えず、末尾にフレームを加えるだけ
+    assert!(raw.len() > compacted.len());
+    assert!(raw.starts_with(&compacted));
+    assert!(!raw.windows(10).any(|w| w == b"top secret"));
+
+    // 読み込み時は透過的に復号される
+    let loaded = storage.get(doc_id).await?.unwrap();
+    assert_eq!(loaded, b"first-chunk:top secret|second-chunk".to_vec());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_plaintext_store_migration() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_path =
+        TestPathGenerator::generate_test_dir(file!(), "test_plaintext_store_migration");
+    std::fs::create_dir_all(&storage_path)?;
+
+    // 平文ストアにドキュメントを保存
+    let doc_id = new_document_id();
+    let plain_storage = FileStorage::new(&storage_path)?;
+    plain_storage.set_mapping(doc_id.clone(), "user".to_string());
+    plain_storage
+        .compact(doc_id.clone(), b"plain document".to_vec())
+        .await?;
+
+    let key_store = ProfileKeyStore::new(&storage_path).with_kdf_params(fast_kdf_params());
+    let cipher: Arc<dyn DocumentCipher> = Arc::new(key_store.unlock_or_create("passphrase")?);
+    let encrypted_storage = FileStorage::new_with_cipher(&storage_path, cipher.clone())?;
+    encrypted_storage.set_mapping(doc_id.clone(), "user".to_string());
+
+    // 移行前でも平文ファイルを読み込める
+    assert_eq!(
+        encrypted_storage.get(doc_id.clone()).await?.unwrap(),
+        b"plain document".to_vec()
+    );
+
+    assert_eq!(encrypted_storage.encrypt_existing_documents()?, 1);
+    assert_eq!(encrypted_storage.encrypt_existing_documents()?, 0);
+    assert!(is_encrypted(&std::fs::read(
+        storage_path.join("user.automerge")
+    )?));
+    assert_eq!(
+        encrypted_storage.get(doc_id.clone()).await?.unwrap(),
+        b"plain document".to_vec()
+    );
+
+    // 鍵なしでは暗号化済みドキュメントを読み書きできない
+    let locked_storage = FileStorage::new(&storage_path)?;
+    locked_storage.set_mapping(doc_id.clone(), "user".to_string());
+    assert!(matches!(
+        locked_storage.get(doc_id.clone()).await,
+        Err(AutomergeError::EncryptionError(_))
+    ));
+    assert!(
+        locked_storage
+            .append(doc_id.clone(), b"oops".to_vec())
+            .await
+            .is_err()
+    );
+
+    // 平文ストアへ戻す
+    let unlocked: Arc<dyn DocumentCipher> = Arc::new(key_store.unlock("passphrase")?);
+    let storage = FileStorage::new_with_cipher(&storage_path, unlocked)?;
+    assert_eq!(storage.decrypt_existing_documents()?, 1);
+    assert_eq!(
+        std::fs::read(storage_path.join("user.automerge"))?,
+        b"plain document".to_vec()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_document_manager_encrypts_plaintext_store_before_start()
+-> Result<(), Box<dyn std::error::Error>> {
+    let storage_path = TestPathGenerator::generate_test_dir(
+        file!(),
+        "test_document_manager_encrypts_plaintext_store_before_start",
+    );
+    std::fs::create_dir_all(&storage_path)?;
+
+    let doc_id = new_document_id();
+    let plain_storage = FileStorage::new(&storage_path)?;
+    plain_storage.set_mapping(doc_id.clone(), "user".to_string());
+    plain_storage
+        .compact(doc_id, b"plain document".to_vec())
+        .await?;
+
+    // Automerge-Repoが動き出す前に、平文のドキュメントが暗号化形式へ移行されている
+    let cipher: Arc<dyn DocumentCipher> = Arc::new(
+        ProfileKeyStore::new(&storage_path)
+            .with_kdf_params(fast_kdf_params())
+            .create("passphrase")?,
+    );
+    let _manager = DocumentManager::new_with_cipher(&storage_path, cipher)?;
+    assert!(is_encrypted(&std::fs::read(
+        storage_path.join("user.automerge")
+    )?));
+
+    Ok(())
+}
@@ -1052,6 +1052,7 @@ async fn test_task_repository_crud_operations() -> Result<(), Box<dyn std::error
         title: "統合テスト用タスク".to_string(),
         description: Some("Automerge Repository統合テストのためのタスク".to_string()),
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 1,
         plan_start_date: None,
         plan_end_date: None,
@@ -1,4 +1,8 @@
 mod automerge_repo_test;
+mod comment_merge_test;
 mod deletion_test;
+mod encryption_test;
 mod local_automerge_repository_test;
+mod project_bundle_test;
 mod project_document_test;
+mod schema_migration_test;
@@ -0,0 +1,148 @@
+//! プロジェクトバンドル用の履歴入出力テスト
+//!
+//! 変更履歴込みのエクスポート、別環境でのマージ、
+//! JSONからのコピー作成の動作を検証する
+
+use chrono::Utc;
+use flequit_infrastructure_automerge::infrastructure::document::Document;
+use flequit_infrastructure_automerge::infrastructure::task_projects::project::ProjectLocalAutomergeRepository;
+use flequit_model::models::task_projects::project::Project;
+use flequit_model::models::task_projects::task::Task;
+use flequit_model::types::id_types::{ProjectId, TaskId, TaskListId, UserId};
+use flequit_model::types::task_types::TaskStatus;
+use flequit_testing::TestPathGenerator;
+
+fn new_project(user_id: UserId) -> Project {
+    let now = Utc::now();
+    Project {
+        id: ProjectId::new(),
+        name: "バンドル対象".to_string(),
+        description: None,
+        color: None,
+        order_index: 0,
+        is_archived: false,
+        status: None,
+        owner_id: Some(user_id),
+        created_at: now,
+        updated_at: now,
+        updated_by: user_id,
+        deleted: false,
+    }
+}
+
+fn new_task(project_id: ProjectId, title: &str, user_id: UserId) -> Task {
+    let now = Utc::now();
+    Task {
+        id: TaskId::new(),
+        project_id,
+        list_id: TaskListId::new(),
+        title: title.to_string(),
+        description: None,
+        status: TaskStatus::NotStarted,
+        workflow_status_id: None,
+        priority: 1,
+        plan_start_date: None,
+        plan_end_date: None,
+        do_start_date: None,
+        do_end_date: None,
+        is_range_date: None,
+        recurrence_rule: None,
+        assigned_user_ids: vec![user_id],
+        tag_ids: vec![],
+        order_index: 0,
+        is_archived: false,
+        created_at: now,
+        updated_at: now,
+        deleted: false,
+        updated_by: user_id,
+    }
+}
+
+async fn new_repository(
+    test_name: &str,
+    label: &str,
+) -> Result<ProjectLocalAutomergeRepository, Box<dyn std::error::Error>> {
+    let path = TestPathGenerator::generate_test_dir(file!(), test_name).join(label);
+    std::fs::create_dir_all(&path)?;
+    Ok(ProjectLocalAutomergeRepository::new(path).await?)
+}
+
+#[tokio::test]
+async fn test_export_and_merge_project_history() -> Result<(), Box<dyn std::error::Error>> {
+    let test_name = "test_export_and_merge_project_history";
+    let source = new_repository(test_name, "source").await?;
+    let target = new_repository(test_name, "target").await?;
+
+    let user_id = UserId::new();
+    let project = new_project(user_id);
+    source.set_project(&project).await?;
+    let original_task = new_task(project.id, "元のタスク", user_id);
+    source.add_task(&project.id, &original_task).await?;
+
+    // 存在しないプロジェクトはエクスポートできない
+    assert!(
+        source
+            .export_project_history(&ProjectId::new())
+            .await
+            .is_err()
+    );
+
+    // 別環境へ履歴ごと取り込む
+    let history = source.export_project_history(&project.id).await?;
+    target.merge_project_history(&project.id, &history).await?;
+    let imported = target.get_project_document(&project.id).await?.unwrap();
+    assert_eq!(imported.name, project.name);
+    assert_eq!(imported.tasks.len(), 1);
+    assert_eq!(imported.tasks[0].id, original_task.id);
+
+    // 取り込み先で追加した変更を元の環境へマージできる
+    let added_task = new_task(project.id, "取り込み先で追加", user_id);
+    target.add_task(&project.id, &added_task).await?;
+    let returned = target.export_project_history(&project.id).await?;
+    source.merge_project_history(&project.id, &returned).await?;
+
+    let merged = source.get_project_document(&project.id).await?.unwrap();
+    let task_ids: Vec<TaskId> = merged.tasks.iter().map(|t| t.id).collect();
+    assert_eq!(task_ids, vec![original_task.id, added_task.id]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_import_project_json_as_copy() -> Result<(), Box<dyn std::error::Error>> {
+    let test_name = "test_import_project_json_as_copy";
+    let source = new_repository(test_name, "source").await?;
+    let target = new_repository(test_name, "target").await?;
+
+    let user_id = UserId::new();
+    let project = new_project(user_id);
+    source.set_project(&project).await?;
+    source
+        .add_task(&project.id, &new_task(project.id, "コピー元", user_id))
+        .await?;
+
+    let history = source.export_project_history(&project.id).await?;
+    let mut project_json = Document::history_to_json(&history)?;
+    let copy_id = ProjectId::new();
+    project_json["id"] = serde_json::Value::String(copy_id.to_string());
+
+    target.import_project_json(&copy_id, &project_json).await?;
+
+    let copied = target.get_project_document(&copy_id).await?.unwrap();
+    assert_eq!(copied.id, copy_id.to_string());
+    assert_eq!(copied.name, project.name);
+    assert_eq!(copied.owner_id, Some(user_id));
+    assert_eq!(copied.tasks.len(), 1);
+    assert_eq!(copied.tasks[0].title, "コピー元");
+    assert_eq!(copied.tasks[0].assigned_user_ids, vec![user_id]);
+
+    // オブジェクト以外は受け付けない
+    assert!(
+        target
+            .import_project_json(&ProjectId::new(), &serde_json::json!([]))
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
@@ -325,6 +325,7 @@ async fn test_project_document_comprehensive_operations() -> Result<(), Box<dyn
         title: "タスク1".to_string(),
         description: Some("最初のテストタスク".to_string()),
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 1,
         plan_start_date: Some(Utc::now()),
         plan_end_date: None,
@@ -349,6 +350,7 @@ async fn test_project_document_comprehensive_operations() -> Result<(), Box<dyn
         title: "タスク2".to_string(),
         description: Some("2番目のテストタスク".to_string()),
         status: TaskStatus::InProgress,
+        workflow_status_id: None,
         priority: 2,
         plan_start_date: Some(Utc::now()),
         plan_end_date: Some(Utc::now()),
@@ -0,0 +1,274 @@
+//! ドキュメントスキーマ移行 結合テスト
+//!
+//! バージョン管理導入前に書かれたドキュメント（tests/fixtures/schema/*_v0.json）を
+//! 再現し、ロード時の移行と端末間の同時移行を検証する
+
+use automerge::transaction::Transactable;
+use automerge::{AutoSerde, Automerge, ObjId, ObjType, ROOT};
+use flequit_infrastructure_automerge::infrastructure::document_manager::{
+    DocumentManager, DocumentType,
+};
+use flequit_infrastructure_automerge::infrastructure::document_schema::{
+    self, DocumentSchema, SCHEMA_VERSION_KEY,
+};
+use flequit_infrastructure_automerge::infrastructure::task_projects::project::ProjectDocument;
+use flequit_model::models::accounts::account::Account;
+use flequit_model::models::task_projects::project::Project;
+use flequit_model::models::users::user::User;
+use flequit_model::types::id_types::ProjectId;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tempfile::TempDir;
+
+const PROJECT_V0: &str = include_str!("../fixtures/schema/project_v0.json");
+const ACCOUNT_V0: &str = include_str!("../fixtures/schema/account_v0.json");
+const USER_V0: &str = include_str!("../fixtures/schema/user_v0.json");
+const SETTINGS_V0: &str = include_str!("../fixtures/schema/settings_v0.json");
+
+const OWNER_ID: &str = "5a1e7f3c-2d44-4b0e-8c1a-7e2b9f6d0c11";
+const TEAMMATE_ID: &str = "8d3b6e1f-4a7c-4e92-b0d5-6c1f8a3e5b61";
+
+/// フィクスチャのJSONをそのまま書き込んだ移行前のドキュメントを作る
+fn legacy_document(fixture: &str) -> Automerge {
+    let value: Value = serde_json::from_str(fixture).unwrap();
+    let Value::Object(root) = value else {
+        panic!("fixture root must be an object");
+    };
+    let mut doc = Automerge::new();
+    let mut tx = doc.transaction();
+    for (key, value) in &root {
+        put_value(&mut tx, &ROOT, key.as_str(), value);
+    }
+    tx.commit();
+    doc
+}
+
+fn put_value<P: Into<automerge::Prop>>(
+    tx: &mut automerge::transaction::Transaction<'_>,
+    obj: &ObjId,
+    prop: P,
+    value: &Value,
+) {
+    match value {
+        Value::Object(map) => {
+            let child = tx.put_object(obj, prop, ObjType::Map).unwrap();
+            for (key, value) in map {
+                put_value(tx, &child, key.as_str(), value);
+            }
+        }
+        Value::Array(items) => {
+            let child = tx.put_object(obj, prop, ObjType::List).unwrap();
+            for (index, item) in items.iter().enumerate() {
+                insert_value(tx, &child, index, item);
+            }
+        }
+        Value::Null => tx.put(obj, prop, ()).unwrap(),
+        Value::Bool(b) => tx.put(obj, prop, *b).unwrap(),
+        Value::Number(n) if n.is_i64() => tx.put(obj, prop, n.as_i64().unwrap()).unwrap(),
+        Value::Number(n) => tx.put(obj, prop, n.as_f64().unwrap()).unwrap(),
+        Value::String(s) => tx.put(obj, prop, s.as_str()).unwrap(),
+    }
+}
+
+fn insert_value(
+    tx: &mut automerge::transaction::Transaction<'_>,
+    list: &ObjId,
+    index: usize,
+    value: &Value,
+) {
+    match value {
+        Value::Object(map) => {
+            let child = tx.insert_object(list, index, ObjType::Map).unwrap();
+            for (key, value) in map {
+                put_value(tx, &child, key.as_str(), value);
+            }
+        }
+        Value::String(s) => tx.insert(list, index, s.as_str()).unwrap(),
+        other => panic!("unsupported list item in fixture: {}", other),
+    }
+}
+
+fn to_json(doc: &Automerge) -> Value {
+    serde_json::to_value(AutoSerde::from(doc)).unwrap()
+}
+
+fn decode<T: DeserializeOwned>(doc: &Automerge, key: &str) -> serde_json::Result<T> {
+    serde_json::from_value(to_json(doc)[key].clone())
+}
+
+#[test]
+fn test_legacy_project_document_is_migrated() {
+    let mut doc = legacy_document(PROJECT_V0);
+    assert_eq!(document_schema::schema_version(&doc), 0);
+    assert!(serde_json::from_value::<ProjectDocument>(to_json(&doc)).is_err());
+
+    let report = document_schema::migrate(&mut doc, DocumentSchema::Project)
+        .unwrap()
+        .unwrap();
+    assert_eq!(report.from, 0);
+    assert_eq!(report.to, DocumentSchema::Project.current_version());
+    assert_eq!(document_schema::schema_version(&doc), report.to);
+
+    let project: ProjectDocument = serde_json::from_value(to_json(&doc)).unwrap();
+    assert!(!project.deleted);
+    assert_eq!(project.updated_by.to_string(), OWNER_ID);
+    assert_eq!(project.task_lists.len(), 1);
+    assert_eq!(project.task_lists[0].updated_by.to_string(), OWNER_ID);
+    assert_eq!(project.tasks.len(), 2);
+    assert!(!project.tasks[0].deleted);
+    assert_eq!(project.tasks[0].updated_by.to_string(), OWNER_ID);
+    // 既に記録されている値は上書きしない
+    assert!(project.tasks[1].deleted);
+    assert_eq!(project.tasks[1].updated_by.to_string(), TEAMMATE_ID);
+    assert_eq!(project.subtasks[0].updated_by.to_string(), OWNER_ID);
+    assert_eq!(project.tags[0].updated_by.to_string(), OWNER_ID);
+    assert_eq!(project.members[0].updated_by.to_string(), OWNER_ID);
+    // 既存のタスクはワークフローステータスを参照しない
+    assert!(
+        project
+            .tasks
+            .iter()
+            .all(|task| task.workflow_status_id.is_none())
+    );
+    let tasks = to_json(&doc)["tasks"].clone();
+    assert!(tasks.as_array().unwrap().iter().all(
+        |task| task["workflow_status_id"].is_null() && task.get("workflow_status_id").is_some()
+    ));
+}
+
+#[test]
+fn test_legacy_account_user_and_settings_documents_are_migrated() {
+    let mut accounts = legacy_document(ACCOUNT_V0);
+    assert!(decode::<Vec<Account>>(&accounts, "accounts").is_err());
+    document_schema::migrate(&mut accounts, DocumentSchema::Account).unwrap();
+    let accounts: Vec<Account> = decode(&accounts, "accounts").unwrap();
+    assert!(!accounts[0].deleted);
+    assert_eq!(accounts[0].updated_by.to_string(), OWNER_ID);
+
+    let mut users = legacy_document(USER_V0);
+    assert!(decode::<Vec<User>>(&users, "users").is_err());
+    document_schema::migrate(&mut users, DocumentSchema::User).unwrap();
+    let users: Vec<User> = decode(&users, "users").unwrap();
+    assert_eq!(users[0].updated_by.to_string(), OWNER_ID);
+    assert!(!users[0].deleted);
+    assert_eq!(users[1].updated_by.to_string(), TEAMMATE_ID);
+    assert!(users[1].deleted);
+
+    let mut settings = legacy_document(SETTINGS_V0);
+    assert!(decode::<Vec<Project>>(&settings, "projects").is_err());
+    document_schema::migrate(&mut settings, DocumentSchema::Settings).unwrap();
+    let projects: Vec<Project> = decode(&settings, "projects").unwrap();
+    assert_eq!(projects[0].updated_by.to_string(), OWNER_ID);
+    assert!(projects[1].updated_by.as_uuid().is_nil());
+    assert!(projects.iter().all(|project| !project.deleted));
+}
+
+#[test]
+fn test_migration_is_idempotent() {
+    for (fixture, schema) in [
+        (PROJECT_V0, DocumentSchema::Project),
+        (ACCOUNT_V0, DocumentSchema::Account),
+        (USER_V0, DocumentSchema::User),
+        (SETTINGS_V0, DocumentSchema::Settings),
+    ] {
+        let mut doc = legacy_document(fixture);
+        assert!(
+            document_schema::migrate(&mut doc, schema)
+                .unwrap()
+                .is_some()
+        );
+        let heads = doc.get_heads();
+
+        assert_eq!(document_schema::migrate(&mut doc, schema).unwrap(), None);
+        assert_eq!(doc.get_heads(), heads, "{:?}", schema);
+    }
+}
+
+#[test]
+fn test_concurrent_migration_by_two_peers_converges() {
+    let mut single = legacy_document(PROJECT_V0);
+    document_schema::migrate(&mut single, DocumentSchema::Project).unwrap();
+
+    // 同じ移行前の履歴を持つ2つの端末がそれぞれ移行する
+    let mut peer_a = legacy_document(PROJECT_V0);
+    let mut peer_b = peer_a.fork();
+    document_schema::migrate(&mut peer_a, DocumentSchema::Project).unwrap();
+    document_schema::migrate(&mut peer_b, DocumentSchema::Project).unwrap();
+
+    peer_a.merge(&mut peer_b).unwrap();
+    peer_b.merge(&mut peer_a.clone()).unwrap();
+
+    assert_eq!(to_json(&peer_a), to_json(&peer_b));
+    assert_eq!(to_json(&peer_a), to_json(&single));
+    let project: ProjectDocument = serde_json::from_value(to_json(&peer_a)).unwrap();
+    assert_eq!(project.tasks.len(), 2);
+    assert_eq!(project.task_lists.len(), 1);
+
+    // マージ後は再度移行する必要がない
+    let heads = peer_a.get_heads();
+    assert_eq!(
+        document_schema::migrate(&mut peer_a, DocumentSchema::Project).unwrap(),
+        None
+    );
+    assert_eq!(peer_a.get_heads(), heads);
+}
+
+#[test]
+fn test_peer_on_newer_schema_is_not_downgraded() {
+    let mut doc = legacy_document(USER_V0);
+    let mut tx = doc.transaction();
+    let newer = DocumentSchema::User.current_version() as i64 + 1;
+    tx.put(ROOT, SCHEMA_VERSION_KEY, newer).unwrap();
+    tx.commit();
+    let before = to_json(&doc);
+
+    assert_eq!(
+        document_schema::migrate(&mut doc, DocumentSchema::User).unwrap(),
+        None
+    );
+    assert_eq!(to_json(&doc), before);
+}
+
+#[tokio::test]
+async fn test_documents_are_migrated_on_load_and_merge() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut manager = DocumentManager::new(temp_dir.path()).unwrap();
+
+    let doc_type = DocumentType::Project(ProjectId::new());
+    let document = manager.get_or_create(&doc_type).await.unwrap();
+    let version = document.handle.with_doc(document_schema::schema_version);
+    assert_eq!(version, DocumentSchema::Project.current_version());
+
+    // 移行前の端末から受け取った履歴を取り込むと、その内容も移行される
+    let legacy = legacy_document(PROJECT_V0).save();
+    document.merge_history(&legacy).await.unwrap();
+
+    let tasks: Vec<flequit_model::models::task_projects::task::Task> =
+        document.load_data("tasks").await.unwrap().unwrap();
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].updated_by.to_string(), OWNER_ID);
+}
+
+#[test]
+fn test_reminders_get_empty_snooze_time() {
+    // スヌーズ導入前（バージョン2）のリマインダー
+    let mut doc = legacy_document(
+        r#"{
+            "schema_version": 2,
+            "reminders": [
+                { "id": "1b9d6bcd-bbfd-4b2d-9b5d-ab8dfbbd4bed", "offset_minutes": -30 }
+            ]
+        }"#,
+    );
+
+    let report = document_schema::migrate(&mut doc, DocumentSchema::Project)
+        .unwrap()
+        .unwrap();
+    assert_eq!(report.from, 2);
+    let reminders = to_json(&doc)["reminders"].clone();
+    let reminder = &reminders.as_array().unwrap()[0];
+    assert!(reminder.get("snoozed_until").is_some());
+    assert!(reminder["snoozed_until"].is_null());
+    // 既存の値は変えない
+    assert_eq!(reminder["offset_minutes"], -30);
+}
@@ -48,6 +48,7 @@ impl From<SQLiteError> for RepositoryError {
             SQLiteError::ConstraintViolation(msg) => RepositoryError::ConstraintViolation(msg),
             SQLiteError::MultipleErrors(items) => RepositoryError::MultipleErrors(items),
             SQLiteError::Export(msg) => RepositoryError::Export(msg),
+            SQLiteError::EncryptionError(msg) => RepositoryError::EncryptionError(msg),
         }
     }
 }
@@ -50,4 +50,7 @@ pub enum SQLiteError {
 
     #[error("Export error: {0}")]
     Export(String),
+
+    #[error("Encryption error: {0}")]
+    EncryptionError(String),
 }
@@ -33,10 +33,7 @@ impl AccountLocalSqliteRepository {
     /// メールアドレスでアカウントを検索
     pub async fn find_by_email(&self, email: &str) -> Result<Option<Account>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = AccountEntity::find()
             .filter(Column::Email.eq(email))
@@ -61,10 +58,7 @@ impl AccountLocalSqliteRepository {
         provider_id: &str,
     ) -> Result<Option<Account>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = AccountEntity::find()
             .filter(Column::Provider.eq(provider))
@@ -86,10 +80,7 @@ impl AccountLocalSqliteRepository {
     /// アクティブなアカウントを取得
     pub async fn find_active_accounts(&self) -> Result<Vec<Account>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = AccountEntity::find()
             .filter(Column::IsActive.eq(true))
@@ -113,10 +104,7 @@ impl AccountLocalSqliteRepository {
     /// 現在アクティブなアカウントを取得（最新のアクティブアカウント）
     pub async fn find_current_account(&self) -> Result<Option<Account>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = AccountEntity::find()
             .filter(Column::IsActive.eq(true))
@@ -138,10 +126,7 @@ impl AccountLocalSqliteRepository {
     /// アカウントをアクティブ化（他のアカウントは非アクティブ化）
     pub async fn activate_account(&self, account_id: &str) -> Result<Account, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // すべてのアカウントを非アクティブ化
         AccountEntity::update_many()
@@ -176,10 +161,7 @@ impl AccountLocalSqliteRepository {
     /// プロバイダー別のアカウント数を取得
     pub async fn count_by_provider(&self, provider: &str) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = AccountEntity::find()
             .filter(Column::Provider.eq(provider))
@@ -202,10 +184,7 @@ impl Repository<Account, AccountId> for AccountLocalSqliteRepository {
         _timestamp: &DateTime<Utc>,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // 既存のアカウントをチェック（プロバイダーとプロバイダーIDで）
         let existing = if let Some(provider_id) = &account.provider_id {
@@ -252,10 +231,7 @@ impl Repository<Account, AccountId> for AccountLocalSqliteRepository {
 
     async fn find_by_id(&self, id: &AccountId) -> Result<Option<Account>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = AccountEntity::find_by_id(id.to_string())
             .one(db)
@@ -274,10 +250,7 @@ impl Repository<Account, AccountId> for AccountLocalSqliteRepository {
 
     async fn delete(&self, id: &AccountId) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         AccountEntity::delete_by_id(id.to_string())
             .exec(db)
@@ -288,10 +261,7 @@ impl Repository<Account, AccountId> for AccountLocalSqliteRepository {
 
     async fn find_all(&self) -> Result<Vec<Account>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = AccountEntity::find()
             .order_by_asc(Column::CreatedAt)
@@ -313,10 +283,7 @@ impl Repository<Account, AccountId> for AccountLocalSqliteRepository {
 
     async fn exists(&self, id: &AccountId) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = AccountEntity::find_by_id(id.to_string())
             .count(db)
             .await
@@ -326,10 +293,7 @@ impl Repository<Account, AccountId> for AccountLocalSqliteRepository {
 
     async fn count(&self) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = AccountEntity::find()
             .count(db)
             .await
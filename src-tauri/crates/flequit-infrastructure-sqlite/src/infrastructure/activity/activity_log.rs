@@ -0,0 +1,271 @@
+//! アクティビティログ用SQLiteリポジトリ
+//!
+//! 変更は追記のみで、個別のエントリを更新・削除することはない。
+//! Automergeの変更履歴から再構築する場合に限り、プロジェクト単位で置き換える。
+
+use super::super::database_manager::DatabaseManager;
+use crate::errors::sqlite_error::SQLiteError;
+use crate::models::SqliteModelConverter;
+use crate::models::activity::activity_entry::{
+    ActiveModel as ActivityActiveModel, Column, Entity as ActivityEntity, Model as ActivityModel,
+};
+use flequit_model::models::activity::activity_entry::{ActivityEntry, NewActivityEntry};
+use flequit_model::types::id_types::ProjectId;
+use flequit_types::errors::repository_error::RepositoryError;
+use sea_orm::{
+    ColumnTrait, Condition, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    Set, TransactionTrait,
+};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// 1回のINSERTでまとめて追加するエントリ数
+///
+/// SQLiteのバインド変数の上限を超えないようにする。
+const INSERT_CHUNK_SIZE: usize = 100;
+
+fn db_error(e: sea_orm::DbErr) -> RepositoryError {
+    RepositoryError::from(SQLiteError::from(e))
+}
+
+async fn to_domain(model: ActivityModel) -> Result<ActivityEntry, RepositoryError> {
+    model
+        .to_domain_model()
+        .await
+        .map_err(|e: String| RepositoryError::from(SQLiteError::ConversionError(e)))
+}
+
+async fn to_domain_all(models: Vec<ActivityModel>) -> Result<Vec<ActivityEntry>, RepositoryError> {
+    let mut entries = Vec::with_capacity(models.len());
+    for model in models {
+        entries.push(to_domain(model).await?);
+    }
+    Ok(entries)
+}
+
+fn to_active_model(entry: &NewActivityEntry) -> Result<ActivityActiveModel, RepositoryError> {
+    let changes = serde_json::to_string(&entry.changes)
+        .map_err(|e| RepositoryError::SerializationError(e.to_string()))?;
+    Ok(ActivityActiveModel {
+        project_id: Set(entry.project_id.to_string()),
+        entity: Set(entry.entity.clone()),
+        entity_id: Set(entry.entity_id.clone()),
+        related_id: Set(entry.related_id.clone()),
+        action: Set(entry.action.as_str().to_string()),
+        changes: Set(changes),
+        actor: Set(entry.actor.map(|id| id.to_string())),
+        source: Set(entry.source.as_str().to_string()),
+        occurred_at: Set(entry.occurred_at),
+        ..Default::default()
+    })
+}
+
+async fn insert_all<C: ConnectionTrait>(
+    db: &C,
+    entries: &[NewActivityEntry],
+) -> Result<(), RepositoryError> {
+    for chunk in entries.chunks(INSERT_CHUNK_SIZE) {
+        let models = chunk
+            .iter()
+            .map(to_active_model)
+            .collect::<Result<Vec<_>, _>>()?;
+        ActivityEntity::insert_many(models)
+            .exec_without_returning(db)
+            .await
+            .map_err(db_error)?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct ActivityLogLocalSqliteRepository {
+    db_manager: Arc<RwLock<DatabaseManager>>,
+}
+
+impl ActivityLogLocalSqliteRepository {
+    pub fn new(db_manager: Arc<RwLock<DatabaseManager>>) -> Self {
+        Self { db_manager }
+    }
+
+    /// エントリを記録順に追加する
+    pub async fn append(&self, entries: &[NewActivityEntry]) -> Result<(), RepositoryError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        insert_all(db, entries).await
+    }
+
+    /// エンティティのタイムラインを古い順に取得
+    ///
+    /// 対象を関連先・親とするエントリ（タスクのサブタスクやタグ付けなど）も含む。
+    pub async fn find_by_entity(
+        &self,
+        project_id: &ProjectId,
+        entity_id: &str,
+    ) -> Result<Vec<ActivityEntry>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let models = ActivityEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(
+                Condition::any()
+                    .add(Column::EntityId.eq(entity_id))
+                    .add(Column::RelatedId.eq(entity_id)),
+            )
+            .order_by_asc(Column::Seq)
+            .all(db)
+            .await
+            .map_err(db_error)?;
+        to_domain_all(models).await
+    }
+
+    /// プロジェクト全体のエントリを新しい順に取得
+    ///
+    /// `before_seq` を指定した場合は、その連番より前のエントリから取得する（ページング用）。
+    pub async fn find_by_project(
+        &self,
+        project_id: &ProjectId,
+        before_seq: Option<i64>,
+        limit: u64,
+    ) -> Result<Vec<ActivityEntry>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let mut query = ActivityEntity::find().filter(Column::ProjectId.eq(project_id.to_string()));
+        if let Some(before_seq) = before_seq {
+            query = query.filter(Column::Seq.lt(before_seq));
+        }
+        let models = query
+            .order_by_desc(Column::Seq)
+            .limit(limit)
+            .all(db)
+            .await
+            .map_err(db_error)?;
+        to_domain_all(models).await
+    }
+
+    /// プロジェクトのエントリをすべて置き換える
+    pub async fn replace_project(
+        &self,
+        project_id: &ProjectId,
+        entries: &[NewActivityEntry],
+    ) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let txn = db.begin().await.map_err(db_error)?;
+        ActivityEntity::delete_many()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        insert_all(&txn, entries).await?;
+        txn.commit().await.map_err(db_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use flequit_model::models::activity::activity_entry::{
+        ActivityAction, ActivitySource, FieldChange,
+    };
+    use flequit_model::types::id_types::UserId;
+    use tempfile::TempDir;
+
+    async fn create_test_repository() -> (TempDir, ActivityLogLocalSqliteRepository) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("activity_log_test.sqlite");
+        let db_manager = Arc::new(RwLock::new(DatabaseManager::new_for_test(
+            db_path.to_string_lossy().to_string(),
+        )));
+        (temp_dir, ActivityLogLocalSqliteRepository::new(db_manager))
+    }
+
+    fn entry(
+        project_id: ProjectId,
+        entity_id: &str,
+        related_id: Option<&str>,
+        action: ActivityAction,
+    ) -> NewActivityEntry {
+        NewActivityEntry {
+            project_id,
+            entity: "task".to_string(),
+            entity_id: entity_id.to_string(),
+            related_id: related_id.map(str::to_string),
+            action,
+            changes: vec![FieldChange {
+                field: "title".to_string(),
+                from: serde_json::json!("before"),
+                to: serde_json::json!("after"),
+            }],
+            actor: Some(UserId::new()),
+            source: ActivitySource::Local,
+            occurred_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_entity_timeline_includes_related_entries() {
+        let (_dir, repo) = create_test_repository().await;
+        let project_id = ProjectId::new();
+        repo.append(&[
+            entry(project_id, "task-1", None, ActivityAction::Created),
+            entry(
+                project_id,
+                "subtask-1",
+                Some("task-1"),
+                ActivityAction::Updated,
+            ),
+            entry(project_id, "task-2", None, ActivityAction::Created),
+            entry(ProjectId::new(), "task-1", None, ActivityAction::Deleted),
+        ])
+        .await
+        .unwrap();
+
+        let timeline = repo.find_by_entity(&project_id, "task-1").await.unwrap();
+        let ids: Vec<&str> = timeline.iter().map(|e| e.entity_id.as_str()).collect();
+        assert_eq!(ids, vec!["task-1", "subtask-1"]);
+        assert_eq!(timeline[0].action, ActivityAction::Created);
+        assert_eq!(timeline[0].changes[0].to, serde_json::json!("after"));
+        assert!(timeline[0].actor.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_project_feed_pages_newest_first_and_replaces() {
+        let (_dir, repo) = create_test_repository().await;
+        let project_id = ProjectId::new();
+        let entries: Vec<_> = (0..5)
+            .map(|i| {
+                entry(
+                    project_id,
+                    &format!("task-{i}"),
+                    None,
+                    ActivityAction::Created,
+                )
+            })
+            .collect();
+        repo.append(&entries).await.unwrap();
+
+        let first_page = repo.find_by_project(&project_id, None, 2).await.unwrap();
+        let ids: Vec<&str> = first_page.iter().map(|e| e.entity_id.as_str()).collect();
+        assert_eq!(ids, vec!["task-4", "task-3"]);
+        let second_page = repo
+            .find_by_project(&project_id, Some(first_page[1].seq), 10)
+            .await
+            .unwrap();
+        assert_eq!(second_page.len(), 3);
+        assert_eq!(second_page[0].entity_id, "task-2");
+
+        let mut rebuilt = entry(project_id, "task-9", None, ActivityAction::Created);
+        rebuilt.source = ActivitySource::History;
+        repo.replace_project(&project_id, &[rebuilt]).await.unwrap();
+        let feed = repo.find_by_project(&project_id, None, 10).await.unwrap();
+        assert_eq!(feed.len(), 1);
+        assert_eq!(feed[0].source, ActivitySource::History);
+    }
+}
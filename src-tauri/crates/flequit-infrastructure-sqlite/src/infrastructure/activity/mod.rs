@@ -0,0 +1,3 @@
+//! アクティビティログSQLiteリポジトリ
+
+pub mod activity_log;
@@ -2,8 +2,12 @@
 //!
 //! シングルトンパターンでデータベース接続を管理し、
 //! アプリケーション全体で単一の接続を共有する。
+//!
+//! 暗号化モードでは、初回接続前に `set_encryption_key` でSQLCipherの鍵を設定する。
 
 use crate::errors::sqlite_error::SQLiteError;
+use crate::infrastructure::executor::{AmbientTransaction, SqliteExecutor};
+use crate::infrastructure::sqlcipher::{self, SqlCipherKey};
 use async_trait::async_trait;
 use flequit_model::traits::TransactionManager;
 use flequit_types::errors::repository_error::RepositoryError;
@@ -19,6 +23,7 @@ use tokio::sync::{OnceCell, RwLock};
 pub struct DatabaseManager {
     connection: OnceCell<DatabaseConnection>,
     database_path: String,
+    encryption_key: Option<SqlCipherKey>,
 }
 
 /// グローバルなDatabaseManagerのシングルトンインスタンス
@@ -30,6 +35,7 @@ impl DatabaseManager {
         Self {
             connection: OnceCell::new(),
             database_path: database_path.into(),
+            encryption_key: None,
         }
     }
 
@@ -54,6 +60,52 @@ impl DatabaseManager {
             .cloned()
     }
 
+    /// データベースファイルのパス
+    pub fn database_path(&self) -> &str {
+        &self.database_path
+    }
+
+    /// 暗号化モードが有効か
+    pub fn is_encryption_enabled(&self) -> bool {
+        self.encryption_key.is_some()
+    }
+
+    /// SQLCipherの鍵を設定（アンロック）
+    ///
+    /// 接続確立後は鍵を差し替えられないため、初回の `get_connection` より前に呼び出すこと。
+    /// 鍵を変更する場合は `rekey` を使用する。
+    pub fn set_encryption_key(&mut self, key: SqlCipherKey) -> Result<(), SQLiteError> {
+        if self.connection.initialized() {
+            return Err(SQLiteError::InvalidOperation(
+                "Encryption key must be set before the database connection is opened".to_string(),
+            ));
+        }
+        if !sqlcipher::is_sqlcipher_available() {
+            return Err(SQLiteError::EncryptionError(
+                "SQLCipher is not available in this build".to_string(),
+            ));
+        }
+        self.encryption_key = Some(key);
+        Ok(())
+    }
+
+    /// 暗号化データベースの鍵を変更
+    ///
+    /// 既存の接続プールを閉じてから `PRAGMA rekey` を実行する。
+    /// 次回の `get_connection` で新しい鍵を使って再接続される。
+    pub async fn rekey(&mut self, new_key: SqlCipherKey) -> Result<(), SQLiteError> {
+        let current_key = self.encryption_key.clone().ok_or_else(|| {
+            SQLiteError::InvalidOperation(
+                "Database is not encrypted; use set_encryption_key to encrypt it".to_string(),
+            )
+        })?;
+
+        self.close_connection().await?;
+        sqlcipher::rekey_database(&self.database_path, &current_key, &new_key).await?;
+        self.encryption_key = Some(new_key);
+        Ok(())
+    }
+
     /// データベース接続を取得（初回接続時は自動的に初期化）
     pub async fn get_connection(&self) -> Result<&DatabaseConnection, SQLiteError> {
         self.connection
@@ -68,6 +120,13 @@ impl DatabaseManager {
                     })?;
                 }
 
+                // 暗号化モードで既存ファイルが平文の場合は暗号化形式へ変換
+                if let Some(key) = &self.encryption_key
+                    && sqlcipher::is_plaintext_database(&self.database_path)?
+                {
+                    sqlcipher::encrypt_plaintext_database(&self.database_path, key).await?;
+                }
+
                 // 接続オプション設定
                 let mut opt =
                     ConnectOptions::new(format!("sqlite://{}?mode=rwc", self.database_path));
@@ -78,9 +137,16 @@ impl DatabaseManager {
                     .idle_timeout(std::time::Duration::from_secs(8))
                     .max_lifetime(std::time::Duration::from_secs(8))
                     .sqlx_logging(false);
+                if let Some(key) = &self.encryption_key {
+                    // プール内の各接続の確立時に PRAGMA key を適用
+                    sqlcipher::apply_key(&mut opt, key);
+                }
 
                 // データベースに接続
                 let db = Database::connect(opt).await.map_err(SQLiteError::from)?;
+                if self.encryption_key.is_some() {
+                    sqlcipher::verify_connection(&db).await?;
+                }
 
                 // 外部キー制約を有効化（SQLiteでは接続ごとに設定が必要）
                 use sea_orm::ConnectionTrait;
@@ -102,6 +168,61 @@ impl DatabaseManager {
             .await
     }
 
+    /// リポジトリが使用するSQL実行先を取得
+    ///
+    /// 現在のタスクでこのデータベースの `AmbientTransaction` が共有されていれば
+    /// そのトランザクションを、そうでなければコネクションプールを返す。
+    pub async fn executor(&self) -> Result<SqliteExecutor<'_>, SQLiteError> {
+        if let Some(txn) = AmbientTransaction::current(&self.database_path) {
+            return Ok(SqliteExecutor::Transaction(txn));
+        }
+        Ok(SqliteExecutor::Connection(self.get_connection().await?))
+    }
+
+    /// 現在のタスクでこのデータベースのトランザクションが共有されているか
+    pub fn has_ambient_transaction(&self) -> bool {
+        AmbientTransaction::current(&self.database_path).is_some()
+    }
+
+    /// 接続プールを閉じる
+    ///
+    /// データベースファイルを差し替える前などに使用する。
+    /// 次回の `get_connection` で再接続される。
+    pub async fn close_connection(&mut self) -> Result<(), SQLiteError> {
+        if let Some(db) = self.connection.take() {
+            db.close().await.map_err(SQLiteError::from)?;
+        }
+        Ok(())
+    }
+
+    /// データベースの一貫したスナップショットをファイルに書き出す
+    ///
+    /// `VACUUM INTO` を使用するため、接続中でも書き込み途中の状態を含まない。
+    /// 暗号化モードの場合、スナップショットも同じ鍵で暗号化される。
+    pub async fn snapshot_to<P: AsRef<Path>>(&self, dest: P) -> Result<(), SQLiteError> {
+        use sea_orm::ConnectionTrait;
+
+        let dest = dest.as_ref();
+        if dest.exists() {
+            return Err(SQLiteError::InvalidOperation(format!(
+                "Snapshot destination already exists: {:?}",
+                dest
+            )));
+        }
+
+        let db = self.get_connection().await?;
+        db.execute(sea_orm::Statement::from_string(
+            sea_orm::DatabaseBackend::Sqlite,
+            format!(
+                "VACUUM INTO '{}';",
+                dest.to_string_lossy().replace('\'', "''")
+            ),
+        ))
+        .await
+        .map_err(SQLiteError::from)?;
+        Ok(())
+    }
+
     /// データベース接続を閉じる
     pub async fn close(&self) -> Result<(), sea_orm::DbErr> {
         // OncelCellから取得した値は共有参照なので、closeは呼び出さない
@@ -138,7 +259,8 @@ impl TransactionManager for DatabaseManager {
     type Transaction = DatabaseTransaction;
 
     async fn begin(&self) -> Result<Self::Transaction, RepositoryError> {
-        let db = self.get_connection().await.map_err(RepositoryError::from)?;
+        // 共有トランザクション中はSAVEPOINTによる入れ子トランザクションになる
+        let db = self.executor().await.map_err(RepositoryError::from)?;
 
         db.begin()
             .await
@@ -0,0 +1,261 @@
+//! リポジトリが使用するSQL実行先
+//!
+//! 通常はコネクションプールへ直接発行するが、作業単位（Unit of Work）の実行中は
+//! タスクローカルに登録されたトランザクションへ発行する。
+//! これにより、リポジトリのメソッドを変更せずに複数の書き込みを1つの
+//! トランザクションへまとめられる。
+
+use sea_orm::{
+    AccessMode, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr,
+    ExecResult, IsolationLevel, QueryResult, Statement, TransactionError, TransactionTrait,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+tokio::task_local! {
+    static AMBIENT_TRANSACTION: AmbientTransaction;
+}
+
+/// 現在のタスクで共有されるトランザクション
+///
+/// `scope` で実行したFuture内では、同じデータベースへの `DatabaseManager::executor`
+/// がこのトランザクションを返す。`tokio::spawn` した別タスクには引き継がれない。
+#[derive(Debug, Clone)]
+pub struct AmbientTransaction {
+    database_path: String,
+    transaction: Arc<DatabaseTransaction>,
+}
+
+impl AmbientTransaction {
+    pub fn new(database_path: impl Into<String>, transaction: Arc<DatabaseTransaction>) -> Self {
+        Self {
+            database_path: database_path.into(),
+            transaction,
+        }
+    }
+
+    /// 共有するトランザクション
+    pub fn transaction(&self) -> Arc<DatabaseTransaction> {
+        self.transaction.clone()
+    }
+
+    /// このトランザクションを共有した状態でFutureを実行
+    pub async fn scope<F: Future>(self, work: F) -> F::Output {
+        AMBIENT_TRANSACTION.scope(self, work).await
+    }
+
+    /// 現在のタスクで指定データベースのトランザクションが共有されていれば取得
+    pub(crate) fn current(database_path: &str) -> Option<Arc<DatabaseTransaction>> {
+        AMBIENT_TRANSACTION
+            .try_with(|ambient| {
+                (ambient.database_path == database_path).then(|| ambient.transaction.clone())
+            })
+            .ok()
+            .flatten()
+    }
+}
+
+/// SQLの実行先（コネクションプールまたは共有トランザクション）
+#[derive(Debug)]
+pub enum SqliteExecutor<'a> {
+    Connection(&'a DatabaseConnection),
+    Transaction(Arc<DatabaseTransaction>),
+}
+
+impl SqliteExecutor<'_> {
+    /// 共有トランザクション上で実行されるか
+    pub fn is_transaction(&self) -> bool {
+        matches!(self, Self::Transaction(_))
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for SqliteExecutor<'_> {
+    fn get_database_backend(&self) -> DbBackend {
+        match self {
+            Self::Connection(conn) => conn.get_database_backend(),
+            Self::Transaction(txn) => txn.get_database_backend(),
+        }
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        match self {
+            Self::Connection(conn) => conn.execute(stmt).await,
+            Self::Transaction(txn) => txn.execute(stmt).await,
+        }
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        match self {
+            Self::Connection(conn) => conn.execute_unprepared(sql).await,
+            Self::Transaction(txn) => txn.execute_unprepared(sql).await,
+        }
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        match self {
+            Self::Connection(conn) => conn.query_one(stmt).await,
+            Self::Transaction(txn) => txn.query_one(stmt).await,
+        }
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        match self {
+            Self::Connection(conn) => conn.query_all(stmt).await,
+            Self::Transaction(txn) => txn.query_all(stmt).await,
+        }
+    }
+
+    fn support_returning(&self) -> bool {
+        match self {
+            Self::Connection(conn) => conn.support_returning(),
+            Self::Transaction(txn) => txn.support_returning(),
+        }
+    }
+}
+
+/// 共有トランザクション上での `begin` はSAVEPOINTによる入れ子トランザクションになる
+#[async_trait::async_trait]
+impl TransactionTrait for SqliteExecutor<'_> {
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        match self {
+            Self::Connection(conn) => conn.begin().await,
+            Self::Transaction(txn) => txn.begin().await,
+        }
+    }
+
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        match self {
+            Self::Connection(conn) => conn.begin_with_config(isolation_level, access_mode).await,
+            Self::Transaction(txn) => txn.begin_with_config(isolation_level, access_mode).await,
+        }
+    }
+
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        match self {
+            Self::Connection(conn) => conn.transaction(callback).await,
+            Self::Transaction(txn) => txn.transaction(callback).await,
+        }
+    }
+
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        match self {
+            Self::Connection(conn) => {
+                conn.transaction_with_config(callback, isolation_level, access_mode)
+                    .await
+            }
+            Self::Transaction(txn) => {
+                txn.transaction_with_config(callback, isolation_level, access_mode)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_manager::DatabaseManager;
+    use crate::infrastructure::sync::outbox::OutboxLocalSqliteRepository;
+    use chrono::Utc;
+    use flequit_model::models::sync::outbox_operation::{
+        NewOutboxOperation, OutboxOperationKind, OutboxStatus,
+    };
+    use tokio::sync::RwLock;
+
+    fn operation(key: &str) -> NewOutboxOperation {
+        NewOutboxOperation {
+            idempotency_key: key.to_string(),
+            entity: "tasks".to_string(),
+            operation: OutboxOperationKind::Put,
+            project_id: None,
+            payload: "{}".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ambient_transaction_is_shared_and_rolled_back() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir
+            .path()
+            .join("executor_test.sqlite")
+            .to_string_lossy()
+            .to_string();
+        let db_manager = Arc::new(RwLock::new(DatabaseManager::new_for_test(db_path.clone())));
+        let repo = OutboxLocalSqliteRepository::new(db_manager.clone());
+
+        let begin = || async {
+            let manager = db_manager.read().await;
+            let conn = manager.get_connection().await.unwrap();
+            Arc::new(conn.begin().await.unwrap())
+        };
+
+        // ロールバックすると共有トランザクション内の書き込みは残らない
+        let txn = begin().await;
+        AmbientTransaction::new(db_path.clone(), txn.clone())
+            .scope(async {
+                assert!(db_manager.read().await.has_ambient_transaction());
+                let queued = repo.enqueue(&operation("a"), Utc::now()).await.unwrap();
+                // 入れ子のトランザクション（SAVEPOINT）も同じトランザクションに含まれる
+                repo.requeue(queued.seq, Utc::now()).await.unwrap();
+                assert_eq!(
+                    repo.count_by_status(OutboxStatus::Pending).await.unwrap(),
+                    1
+                );
+            })
+            .await;
+        Arc::try_unwrap(txn).unwrap().rollback().await.unwrap();
+        assert!(!db_manager.read().await.has_ambient_transaction());
+        assert_eq!(
+            repo.count_by_status(OutboxStatus::Pending).await.unwrap(),
+            0
+        );
+
+        // 別パスのデータベースには共有されない
+        let txn = begin().await;
+        AmbientTransaction::new("other.sqlite", txn.clone())
+            .scope(async {
+                assert!(!db_manager.read().await.has_ambient_transaction());
+            })
+            .await;
+        drop(txn);
+
+        // コミットすると書き込みが確定する
+        let txn = begin().await;
+        AmbientTransaction::new(db_path, txn.clone())
+            .scope(repo.enqueue(&operation("b"), Utc::now()))
+            .await
+            .unwrap();
+        Arc::try_unwrap(txn).unwrap().commit().await.unwrap();
+        assert_eq!(
+            repo.count_by_status(OutboxStatus::Pending).await.unwrap(),
+            1
+        );
+    }
+}
@@ -5,16 +5,26 @@
 
 use crate::errors::sqlite_error::SQLiteError;
 use crate::infrastructure::{
-    accounts::account::AccountLocalSqliteRepository, database_manager::DatabaseManager,
+    accounts::account::AccountLocalSqliteRepository,
+    activity::activity_log::ActivityLogLocalSqliteRepository, database_manager::DatabaseManager,
+    maintenance::data_backfill::DataBackfillLocalSqliteRepository,
+    sync::outbox::OutboxLocalSqliteRepository,
+    task_projects::comment::CommentLocalSqliteRepository,
     task_projects::project::ProjectLocalSqliteRepository,
+    task_projects::reminder::ReminderLocalSqliteRepository,
+    task_projects::snooze::SnoozeLocalSqliteRepository,
+    task_projects::status_transition_rule::StatusTransitionRuleLocalSqliteRepository,
     task_projects::subtask::SubTaskLocalSqliteRepository,
     task_projects::subtask_assignments::SubtaskAssignmentLocalSqliteRepository,
     task_projects::subtask_tag::SubtaskTagLocalSqliteRepository,
     task_projects::tag::TagLocalSqliteRepository, task_projects::task::TaskLocalSqliteRepository,
     task_projects::task_assignments::TaskAssignmentLocalSqliteRepository,
+    task_projects::task_dependency::TaskDependencyLocalSqliteRepository,
     task_projects::task_list::TaskListLocalSqliteRepository,
     task_projects::task_recurrence::TaskRecurrenceLocalSqliteRepository,
     task_projects::task_tag::TaskTagLocalSqliteRepository,
+    task_projects::time_entry::TimeEntryLocalSqliteRepository,
+    task_projects::workflow_status::WorkflowStatusLocalSqliteRepository,
     user_preferences::tag_bookmark::TagBookmarkLocalSqliteRepository,
     users::user::UserLocalSqliteRepository,
 };
@@ -36,11 +46,21 @@ pub struct LocalSqliteRepositories {
     pub task_tags: TaskTagLocalSqliteRepository,
     pub task_assignments: TaskAssignmentLocalSqliteRepository,
     pub task_recurrences: TaskRecurrenceLocalSqliteRepository,
+    pub task_dependencies: TaskDependencyLocalSqliteRepository,
     pub subtask_tags: SubtaskTagLocalSqliteRepository,
     pub subtask_assignments: SubtaskAssignmentLocalSqliteRepository,
+    pub time_entries: TimeEntryLocalSqliteRepository,
+    pub reminders: ReminderLocalSqliteRepository,
+    pub snoozes: SnoozeLocalSqliteRepository,
+    pub comments: CommentLocalSqliteRepository,
+    pub status_transition_rules: StatusTransitionRuleLocalSqliteRepository,
+    pub workflow_statuses: WorkflowStatusLocalSqliteRepository,
     pub accounts: AccountLocalSqliteRepository,
     pub users: UserLocalSqliteRepository,
     pub tag_bookmarks: TagBookmarkLocalSqliteRepository,
+    pub outbox: OutboxLocalSqliteRepository,
+    pub activity_log: ActivityLogLocalSqliteRepository,
+    pub data_backfills: DataBackfillLocalSqliteRepository,
 }
 
 impl LocalSqliteRepositories {
@@ -48,8 +68,12 @@ impl LocalSqliteRepositories {
     pub async fn new() -> Result<Self, SQLiteError> {
         // シングルトンのデータベースマネージャーを取得
         let db_manager = DatabaseManager::instance().await?;
+        Ok(Self::with_database_manager(db_manager))
+    }
 
-        Ok(Self {
+    /// 指定したDatabaseManagerを使用するSQLiteリポジトリ群を作成
+    pub fn with_database_manager(db_manager: Arc<RwLock<DatabaseManager>>) -> Self {
+        Self {
             db_manager: db_manager.clone(),
             projects: ProjectLocalSqliteRepository::new(db_manager.clone()),
             task_lists: TaskListLocalSqliteRepository::new(db_manager.clone()),
@@ -59,12 +83,24 @@ impl LocalSqliteRepositories {
             task_tags: TaskTagLocalSqliteRepository::new(db_manager.clone()),
             task_assignments: TaskAssignmentLocalSqliteRepository::new(db_manager.clone()),
             task_recurrences: TaskRecurrenceLocalSqliteRepository::new(db_manager.clone()),
+            task_dependencies: TaskDependencyLocalSqliteRepository::new(db_manager.clone()),
             subtask_tags: SubtaskTagLocalSqliteRepository::new(db_manager.clone()),
             subtask_assignments: SubtaskAssignmentLocalSqliteRepository::new(db_manager.clone()),
+            time_entries: TimeEntryLocalSqliteRepository::new(db_manager.clone()),
+            reminders: ReminderLocalSqliteRepository::new(db_manager.clone()),
+            snoozes: SnoozeLocalSqliteRepository::new(db_manager.clone()),
+            comments: CommentLocalSqliteRepository::new(db_manager.clone()),
+            status_transition_rules: StatusTransitionRuleLocalSqliteRepository::new(
+                db_manager.clone(),
+            ),
+            workflow_statuses: WorkflowStatusLocalSqliteRepository::new(db_manager.clone()),
             accounts: AccountLocalSqliteRepository::new(db_manager.clone()),
             users: UserLocalSqliteRepository::new(db_manager.clone()),
-            tag_bookmarks: TagBookmarkLocalSqliteRepository::new(db_manager),
-        })
+            tag_bookmarks: TagBookmarkLocalSqliteRepository::new(db_manager.clone()),
+            outbox: OutboxLocalSqliteRepository::new(db_manager.clone()),
+            activity_log: ActivityLogLocalSqliteRepository::new(db_manager.clone()),
+            data_backfills: DataBackfillLocalSqliteRepository::new(db_manager),
+        }
     }
 
     /// デフォルト設定でリポジトリ群を設定
@@ -117,11 +153,61 @@ impl LocalSqliteRepositories {
         &self.subtask_assignments
     }
 
+    /// タスク依存関係リポジトリへのアクセス
+    pub fn task_dependencies(&self) -> &TaskDependencyLocalSqliteRepository {
+        &self.task_dependencies
+    }
+
+    /// タイムエントリリポジトリへのアクセス
+    pub fn time_entries(&self) -> &TimeEntryLocalSqliteRepository {
+        &self.time_entries
+    }
+
+    /// リマインダーリポジトリへのアクセス
+    pub fn reminders(&self) -> &ReminderLocalSqliteRepository {
+        &self.reminders
+    }
+
+    /// スヌーズリポジトリへのアクセス
+    pub fn snoozes(&self) -> &SnoozeLocalSqliteRepository {
+        &self.snoozes
+    }
+
+    /// コメントリポジトリへのアクセス
+    pub fn comments(&self) -> &CommentLocalSqliteRepository {
+        &self.comments
+    }
+
+    /// ステータス遷移ルールリポジトリへのアクセス
+    pub fn status_transition_rules(&self) -> &StatusTransitionRuleLocalSqliteRepository {
+        &self.status_transition_rules
+    }
+
+    /// ワークフローステータスリポジトリへのアクセス
+    pub fn workflow_statuses(&self) -> &WorkflowStatusLocalSqliteRepository {
+        &self.workflow_statuses
+    }
+
     /// タグブックマークリポジトリへのアクセス
     pub fn tag_bookmarks(&self) -> &TagBookmarkLocalSqliteRepository {
         &self.tag_bookmarks
     }
 
+    /// アウトボックス（未送信のリモート書き込み）リポジトリへのアクセス
+    pub fn outbox(&self) -> &OutboxLocalSqliteRepository {
+        &self.outbox
+    }
+
+    /// アクティビティログリポジトリへのアクセス
+    pub fn activity_log(&self) -> &ActivityLogLocalSqliteRepository {
+        &self.activity_log
+    }
+
+    /// データ補完の予約リポジトリへのアクセス
+    pub fn data_backfills(&self) -> &DataBackfillLocalSqliteRepository {
+        &self.data_backfills
+    }
+
     /// データベースマネージャーへのアクセス
     pub fn database_manager(&self) -> &Arc<RwLock<DatabaseManager>> {
         &self.db_manager
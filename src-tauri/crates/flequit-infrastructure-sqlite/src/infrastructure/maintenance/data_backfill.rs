@@ -0,0 +1,79 @@
+//! データ補完の予約を管理するSQLiteリポジトリ
+//!
+//! カラムの追加など、既存の行をAutomergeの内容から埋め直す必要があるマイグレーションは
+//! `data_backfills` テーブルに補完の名前を登録する。補完が完了したら行を削除する。
+
+use super::super::database_manager::DatabaseManager;
+use crate::errors::sqlite_error::SQLiteError;
+use flequit_types::errors::repository_error::RepositoryError;
+use sea_orm::{ConnectionTrait, DatabaseBackend, Statement};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+fn db_error(e: sea_orm::DbErr) -> RepositoryError {
+    RepositoryError::from(SQLiteError::from(e))
+}
+
+#[derive(Debug, Clone)]
+pub struct DataBackfillLocalSqliteRepository {
+    db_manager: Arc<RwLock<DatabaseManager>>,
+}
+
+impl DataBackfillLocalSqliteRepository {
+    pub fn new(db_manager: Arc<RwLock<DatabaseManager>>) -> Self {
+        Self { db_manager }
+    }
+
+    /// 未完了のデータ補完かどうか
+    pub async fn is_pending(&self, name: &str) -> Result<bool, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let row = db
+            .query_one(Statement::from_sql_and_values(
+                DatabaseBackend::Sqlite,
+                "SELECT name FROM data_backfills WHERE name = ?",
+                vec![name.into()],
+            ))
+            .await
+            .map_err(db_error)?;
+        Ok(row.is_some())
+    }
+
+    /// データ補完を完了として記録する
+    pub async fn complete(&self, name: &str) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        db.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "DELETE FROM data_backfills WHERE name = ?",
+            vec![name.into()],
+        ))
+        .await
+        .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migrator::TASK_WORK_DATES_BACKFILL;
+
+    #[tokio::test]
+    async fn test_migration_schedules_backfill_until_completed() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("backfill_test.sqlite");
+        let db_manager = Arc::new(RwLock::new(DatabaseManager::new_for_test(
+            db_path.to_string_lossy().to_string(),
+        )));
+        let repo = DataBackfillLocalSqliteRepository::new(db_manager);
+
+        assert!(repo.is_pending(TASK_WORK_DATES_BACKFILL).await.unwrap());
+        assert!(!repo.is_pending("unknown").await.unwrap());
+
+        repo.complete(TASK_WORK_DATES_BACKFILL).await.unwrap();
+        assert!(!repo.is_pending(TASK_WORK_DATES_BACKFILL).await.unwrap());
+    }
+}
@@ -0,0 +1,3 @@
+//! データ保守用SQLiteリポジトリ
+
+pub mod data_backfill;
@@ -4,8 +4,13 @@
 //! 高速なクエリとリレーショナルなデータアクセスを提供
 
 pub mod accounts;
+pub mod activity;
 pub mod database_manager;
+pub mod executor;
 pub mod local_sqlite_repositories;
+pub mod maintenance;
+pub mod sqlcipher;
+pub mod sync;
 pub mod task_projects;
 pub mod user_preferences;
 pub mod users;
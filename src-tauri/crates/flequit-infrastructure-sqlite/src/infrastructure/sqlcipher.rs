@@ -0,0 +1,297 @@
+//! SQLCipherによるデータベース暗号化
+//!
+//! `DatabaseManager` が暗号化モードで接続する際に使用する鍵と、
+//! 既存の平文データベースを暗号化形式へ変換するためのユーティリティを提供する。
+//!
+//! 鍵はアンロック時にのみ渡され、プール内の各接続の確立時に `PRAGMA key` として適用される。
+//! SQLCipherが有効でないビルド（`sqlcipher` フィーチャー無効）では暗号化モードはエラーとなる。
+
+use crate::errors::sqlite_error::SQLiteError;
+use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, Statement};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// 平文SQLiteファイルの先頭16バイト
+const SQLITE_PLAINTEXT_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// 変換中の一時ファイルに付与する拡張子
+const ENCRYPTING_SUFFIX: &str = "encrypting";
+
+/// SQLCipherの鍵
+///
+/// パスフレーズ指定の場合は接続ごとにSQLCipher内部でPBKDF2による鍵導出が行われる。
+/// 既に導出済みの256bit鍵を持っている場合は `Raw` を使用すると接続確立が高速になる。
+#[derive(Clone, PartialEq, Eq)]
+pub enum SqlCipherKey {
+    /// パスフレーズ
+    Passphrase(String),
+    /// 256bitの生鍵
+    Raw([u8; 32]),
+}
+
+impl fmt::Debug for SqlCipherKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 鍵の内容はログに出力しない
+        match self {
+            SqlCipherKey::Passphrase(_) => f.write_str("SqlCipherKey::Passphrase(..)"),
+            SqlCipherKey::Raw(_) => f.write_str("SqlCipherKey::Raw(..)"),
+        }
+    }
+}
+
+impl SqlCipherKey {
+    /// パスフレーズから作成
+    pub fn passphrase(passphrase: impl Into<String>) -> Self {
+        SqlCipherKey::Passphrase(passphrase.into())
+    }
+
+    /// 256bitの生鍵から作成
+    pub fn raw(key: [u8; 32]) -> Self {
+        SqlCipherKey::Raw(key)
+    }
+
+    /// `PRAGMA key` / `ATTACH ... KEY` に渡すSQLリテラル
+    pub(crate) fn to_sql_literal(&self) -> String {
+        match self {
+            SqlCipherKey::Passphrase(passphrase) => quote_sql_string(passphrase),
+            SqlCipherKey::Raw(key) => {
+                let hex: String = key.iter().map(|b| format!("{:02X}", b)).collect();
+                format!("\"x'{}'\"", hex)
+            }
+        }
+    }
+}
+
+/// SQLの文字列リテラルとしてクォート
+fn quote_sql_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// SQLCipherが利用可能なビルドかどうか
+pub fn is_sqlcipher_available() -> bool {
+    cfg!(feature = "sqlcipher")
+}
+
+/// データベースファイルが平文SQLiteかどうかを判定
+///
+/// ファイルが存在しない、または空の場合は `false`（変換不要）を返す。
+pub fn is_plaintext_database<P: AsRef<Path>>(path: P) -> Result<bool, SQLiteError> {
+    use std::io::Read;
+
+    let path = path.as_ref();
+    if !path.exists() {
+        return Ok(false);
+    }
+
+    let mut header = [0u8; 16];
+    let mut file = std::fs::File::open(path)
+        .map_err(|e| SQLiteError::IOError(format!("Failed to open {:?}: {}", path, e)))?;
+    let read = file
+        .read(&mut header)
+        .map_err(|e| SQLiteError::IOError(format!("Failed to read {:?}: {}", path, e)))?;
+
+    Ok(read == header.len() && &header == SQLITE_PLAINTEXT_HEADER)
+}
+
+/// 暗号化モードの接続オプションを作成
+///
+/// `PRAGMA key` はsqlxによってプール内の各接続確立時に最初に実行される。
+pub(crate) fn apply_key(opt: &mut ConnectOptions, key: &SqlCipherKey) {
+    let key_literal = key.to_sql_literal();
+    opt.map_sqlx_sqlite_opts(move |sqlite_opts| sqlite_opts.pragma("key", key_literal.clone()));
+}
+
+/// 鍵が正しく適用されているかを検証
+///
+/// SQLCipherが無効なビルドや鍵が誤っている場合はエラーを返す。
+pub(crate) async fn verify_connection(db: &DatabaseConnection) -> Result<(), SQLiteError> {
+    let cipher_version = db
+        .query_one(Statement::from_string(
+            sea_orm::DatabaseBackend::Sqlite,
+            "PRAGMA cipher_version;".to_string(),
+        ))
+        .await
+        .map_err(SQLiteError::from)?;
+    if cipher_version.is_none() {
+        return Err(SQLiteError::EncryptionError(
+            "SQLCipher is not available in this build".to_string(),
+        ));
+    }
+
+    db.query_one(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        "SELECT count(*) FROM sqlite_master;".to_string(),
+    ))
+    .await
+    .map_err(|_| {
+        SQLiteError::EncryptionError(
+            "Failed to unlock database (wrong key or not an encrypted database)".to_string(),
+        )
+    })?;
+
+    Ok(())
+}
+
+/// 単一接続でデータベースを開く
+///
+/// `ATTACH` や `PRAGMA rekey` は接続単位の操作のため、プールを1接続に制限する。
+/// `ATTACH` で新規ファイルを作成する場合は `mode` に `rwc` を指定する。
+async fn connect_single(
+    path: &Path,
+    mode: &str,
+    key: Option<&SqlCipherKey>,
+) -> Result<DatabaseConnection, SQLiteError> {
+    let mut opt = ConnectOptions::new(format!("sqlite://{}?mode={}", path.to_string_lossy(), mode));
+    opt.max_connections(1)
+        .min_connections(1)
+        .connect_timeout(std::time::Duration::from_secs(8))
+        .acquire_timeout(std::time::Duration::from_secs(8))
+        .sqlx_logging(false);
+    if let Some(key) = key {
+        apply_key(&mut opt, key);
+    }
+
+    let db = Database::connect(opt).await.map_err(SQLiteError::from)?;
+    if key.is_some() {
+        verify_connection(&db).await?;
+    }
+    Ok(db)
+}
+
+async fn execute(db: &DatabaseConnection, sql: String) -> Result<(), SQLiteError> {
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        sql,
+    ))
+    .await
+    .map_err(SQLiteError::from)?;
+    Ok(())
+}
+
+/// 既存の平文データベースを暗号化データベースへ変換
+///
+/// `sqlcipher_export` で一時ファイルへ書き出した後、元のファイルと置き換える。
+/// 変換中に失敗した場合、元の平文データベースはそのまま残る。
+pub async fn encrypt_plaintext_database<P: AsRef<Path>>(
+    path: P,
+    key: &SqlCipherKey,
+) -> Result<(), SQLiteError> {
+    let path = path.as_ref();
+    if !is_sqlcipher_available() {
+        return Err(SQLiteError::EncryptionError(
+            "SQLCipher is not available in this build".to_string(),
+        ));
+    }
+    if !is_plaintext_database(path)? {
+        return Err(SQLiteError::InvalidOperation(format!(
+            "Not a plaintext SQLite database: {:?}",
+            path
+        )));
+    }
+
+    let encrypting_path = encrypting_path(path);
+    remove_if_exists(&encrypting_path)?;
+
+    let db = connect_single(path, "rwc", None).await?;
+    let result = async {
+        execute(
+            &db,
+            format!(
+                "ATTACH DATABASE {} AS encrypted KEY {};",
+                quote_sql_string(&encrypting_path.to_string_lossy()),
+                key.to_sql_literal()
+            ),
+        )
+        .await?;
+        execute(&db, "SELECT sqlcipher_export('encrypted');".to_string()).await?;
+        execute(&db, "DETACH DATABASE encrypted;".to_string()).await
+    }
+    .await;
+    db.close().await.map_err(SQLiteError::from)?;
+
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&encrypting_path);
+        return Err(SQLiteError::EncryptionError(format!(
+            "Failed to export encrypted database: {}",
+            e
+        )));
+    }
+
+    std::fs::rename(&encrypting_path, path).map_err(|e| {
+        SQLiteError::IOError(format!(
+            "Failed to replace {:?} with encrypted database: {}",
+            path, e
+        ))
+    })?;
+
+    tracing::info!(
+        "Converted plaintext database to SQLCipher format: {:?}",
+        path
+    );
+    Ok(())
+}
+
+/// 暗号化データベースの鍵を変更
+///
+/// 呼び出し時点で対象データベースへの他の接続が閉じられていること。
+pub async fn rekey_database<P: AsRef<Path>>(
+    path: P,
+    current_key: &SqlCipherKey,
+    new_key: &SqlCipherKey,
+) -> Result<(), SQLiteError> {
+    let path = path.as_ref();
+    let db = connect_single(path, "rw", Some(current_key)).await?;
+    let result = execute(&db, format!("PRAGMA rekey = {};", new_key.to_sql_literal())).await;
+    db.close().await.map_err(SQLiteError::from)?;
+
+    result.map_err(|e| SQLiteError::EncryptionError(format!("Failed to rekey database: {}", e)))?;
+    tracing::info!("Rekeyed encrypted database: {:?}", path);
+    Ok(())
+}
+
+fn encrypting_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".");
+    file_name.push(ENCRYPTING_SUFFIX);
+    path.with_file_name(file_name)
+}
+
+fn remove_if_exists(path: &Path) -> Result<(), SQLiteError> {
+    if path.exists() {
+        std::fs::remove_file(path)
+            .map_err(|e| SQLiteError::IOError(format!("Failed to remove {:?}: {}", path, e)))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_sql_literal() {
+        assert_eq!(
+            SqlCipherKey::passphrase("it's secret").to_sql_literal(),
+            "'it''s secret'"
+        );
+
+        let literal = SqlCipherKey::raw([0xAB; 32]).to_sql_literal();
+        assert!(literal.starts_with("\"x'ABAB"));
+        assert!(literal.ends_with("'\""));
+        assert_eq!(literal.len(), 64 + 5);
+    }
+
+    #[test]
+    fn test_key_debug_hides_secret() {
+        let debug = format!("{:?}", SqlCipherKey::passphrase("hunter2"));
+        assert!(!debug.contains("hunter2"));
+    }
+
+    #[test]
+    fn test_encrypting_path() {
+        assert_eq!(
+            encrypting_path(Path::new("/data/database.sqlite")),
+            PathBuf::from("/data/database.sqlite.encrypting")
+        );
+    }
+}
@@ -0,0 +1,3 @@
+//! リモート同期SQLiteリポジトリ
+
+pub mod outbox;
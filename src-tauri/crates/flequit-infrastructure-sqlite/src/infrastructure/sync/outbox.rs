@@ -0,0 +1,376 @@
+//! アウトボックス用SQLiteリポジトリ
+//!
+//! 未送信のリモート書き込みを `seq` 順のキューとして扱う。
+//! 送信に成功した操作と、ユーザーが破棄した操作は行ごと削除する。
+
+use super::super::database_manager::DatabaseManager;
+use crate::errors::sqlite_error::SQLiteError;
+use crate::models::SqliteModelConverter;
+use crate::models::sync::outbox_operation::{
+    ActiveModel as OutboxActiveModel, Column, Entity as OutboxEntity, Model as OutboxModel,
+};
+use chrono::{DateTime, Utc};
+use flequit_model::models::sync::outbox_operation::{
+    NewOutboxOperation, OutboxOperation, OutboxStatus,
+};
+use flequit_types::errors::repository_error::RepositoryError;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
+};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+fn db_error(e: sea_orm::DbErr) -> RepositoryError {
+    RepositoryError::from(SQLiteError::from(e))
+}
+
+async fn to_domain(model: OutboxModel) -> Result<OutboxOperation, RepositoryError> {
+    model
+        .to_domain_model()
+        .await
+        .map_err(|e: String| RepositoryError::from(SQLiteError::ConversionError(e)))
+}
+
+#[derive(Debug, Clone)]
+pub struct OutboxLocalSqliteRepository {
+    db_manager: Arc<RwLock<DatabaseManager>>,
+}
+
+impl OutboxLocalSqliteRepository {
+    pub fn new(db_manager: Arc<RwLock<DatabaseManager>>) -> Self {
+        Self { db_manager }
+    }
+
+    /// 操作をキューの末尾に追加する
+    ///
+    /// 同じ冪等キーの操作が既にある場合は追加せず、既存の操作を返す。
+    pub async fn enqueue(
+        &self,
+        operation: &NewOutboxOperation,
+        now: DateTime<Utc>,
+    ) -> Result<OutboxOperation, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let active_model = OutboxActiveModel {
+            idempotency_key: Set(operation.idempotency_key.clone()),
+            entity: Set(operation.entity.clone()),
+            operation: Set(operation.operation.as_str().to_string()),
+            project_id: Set(operation.project_id.map(|id| id.to_string())),
+            payload: Set(operation.payload.clone()),
+            status: Set(OutboxStatus::Pending.as_str().to_string()),
+            attempts: Set(0),
+            next_attempt_at: Set(now),
+            last_error: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+        OutboxEntity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(Column::IdempotencyKey)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(db)
+            .await
+            .map_err(db_error)?;
+
+        let model = OutboxEntity::find()
+            .filter(Column::IdempotencyKey.eq(operation.idempotency_key.as_str()))
+            .one(db)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| {
+                RepositoryError::NotFound(format!("Outbox operation {}", operation.idempotency_key))
+            })?;
+        to_domain(model).await
+    }
+
+    /// 連番で操作を取得
+    pub async fn find_by_seq(&self, seq: i64) -> Result<Option<OutboxOperation>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        match OutboxEntity::find_by_id(seq)
+            .one(db)
+            .await
+            .map_err(db_error)?
+        {
+            Some(model) => Ok(Some(to_domain(model).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// 再送待ちキューの先頭を取得
+    pub async fn next_pending(&self) -> Result<Option<OutboxOperation>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        match OutboxEntity::find()
+            .filter(Column::Status.eq(OutboxStatus::Pending.as_str()))
+            .order_by_asc(Column::Seq)
+            .one(db)
+            .await
+            .map_err(db_error)?
+        {
+            Some(model) => Ok(Some(to_domain(model).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// 状態ごとの操作を記録順に取得（`None` の場合は全件）
+    pub async fn find_by_status(
+        &self,
+        status: Option<OutboxStatus>,
+    ) -> Result<Vec<OutboxOperation>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let mut query = OutboxEntity::find();
+        if let Some(status) = status {
+            query = query.filter(Column::Status.eq(status.as_str()));
+        }
+        let models = query
+            .order_by_asc(Column::Seq)
+            .all(db)
+            .await
+            .map_err(db_error)?;
+
+        let mut operations = Vec::with_capacity(models.len());
+        for model in models {
+            operations.push(to_domain(model).await?);
+        }
+        Ok(operations)
+    }
+
+    /// 指定した状態の操作数
+    pub async fn count_by_status(&self, status: OutboxStatus) -> Result<u64, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        OutboxEntity::find()
+            .filter(Column::Status.eq(status.as_str()))
+            .count(db)
+            .await
+            .map_err(db_error)
+    }
+
+    /// 再送の失敗を記録し、次回の再送日時を設定する（状態は再送待ちのまま）
+    pub async fn record_failure(
+        &self,
+        seq: i64,
+        error: &str,
+        next_attempt_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<OutboxOperation, RepositoryError> {
+        self.update(seq, |model| {
+            model.attempts = Set(model.attempts.clone().unwrap() + 1);
+            model.next_attempt_at = Set(next_attempt_at);
+            model.last_error = Set(Some(error.to_string()));
+            model.updated_at = Set(now);
+        })
+        .await
+    }
+
+    /// 再送の失敗を記録し、再送待ちキューから外す（競合・デッドレター）
+    pub async fn mark_failed(
+        &self,
+        seq: i64,
+        status: OutboxStatus,
+        error: &str,
+        now: DateTime<Utc>,
+    ) -> Result<OutboxOperation, RepositoryError> {
+        if status == OutboxStatus::Pending {
+            return Err(RepositoryError::InvalidOperation(
+                "Use record_failure to keep an operation pending".to_string(),
+            ));
+        }
+        self.update(seq, |model| {
+            model.status = Set(status.as_str().to_string());
+            model.attempts = Set(model.attempts.clone().unwrap() + 1);
+            model.last_error = Set(Some(error.to_string()));
+            model.updated_at = Set(now);
+        })
+        .await
+    }
+
+    /// 競合・デッドレターの操作を再送待ちに戻す
+    ///
+    /// 後から記録された操作を上書きしないよう、キューの末尾へ移動する（連番が変わる）。
+    pub async fn requeue(
+        &self,
+        seq: i64,
+        now: DateTime<Utc>,
+    ) -> Result<OutboxOperation, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let txn = db.begin().await.map_err(db_error)?;
+        let model = OutboxEntity::find_by_id(seq)
+            .one(&txn)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| RepositoryError::NotFound(format!("Outbox operation {seq}")))?;
+
+        OutboxEntity::delete_by_id(seq)
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        let requeued = OutboxActiveModel {
+            idempotency_key: Set(model.idempotency_key),
+            entity: Set(model.entity),
+            operation: Set(model.operation),
+            project_id: Set(model.project_id),
+            payload: Set(model.payload),
+            status: Set(OutboxStatus::Pending.as_str().to_string()),
+            attempts: Set(0),
+            next_attempt_at: Set(now),
+            last_error: Set(model.last_error),
+            created_at: Set(model.created_at),
+            updated_at: Set(now),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(db_error)?;
+        txn.commit().await.map_err(db_error)?;
+
+        to_domain(requeued).await
+    }
+
+    /// 操作を削除する（送信成功・破棄）
+    ///
+    /// 削除した場合は `true` を返す。
+    pub async fn remove(&self, seq: i64) -> Result<bool, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let result = OutboxEntity::delete_by_id(seq)
+            .exec(db)
+            .await
+            .map_err(db_error)?;
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn update(
+        &self,
+        seq: i64,
+        apply: impl FnOnce(&mut OutboxActiveModel),
+    ) -> Result<OutboxOperation, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let model = OutboxEntity::find_by_id(seq)
+            .one(db)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| RepositoryError::NotFound(format!("Outbox operation {seq}")))?;
+        let mut active_model: OutboxActiveModel = model.into();
+        apply(&mut active_model);
+        let updated = active_model.update(db).await.map_err(db_error)?;
+        to_domain(updated).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use flequit_model::models::sync::outbox_operation::OutboxOperationKind;
+    use flequit_model::types::id_types::ProjectId;
+    use tempfile::TempDir;
+
+    async fn create_test_repository() -> (TempDir, OutboxLocalSqliteRepository) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("outbox_test.sqlite");
+        let db_manager = Arc::new(RwLock::new(DatabaseManager::new_for_test(
+            db_path.to_string_lossy().to_string(),
+        )));
+        (temp_dir, OutboxLocalSqliteRepository::new(db_manager))
+    }
+
+    fn operation(key: &str) -> NewOutboxOperation {
+        NewOutboxOperation {
+            idempotency_key: key.to_string(),
+            entity: "tasks".to_string(),
+            operation: OutboxOperationKind::Put,
+            project_id: Some(ProjectId::new()),
+            payload: r#"{"segments":["tasks"]}"#.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_is_ordered_and_idempotent() {
+        let (_dir, repo) = create_test_repository().await;
+        let now = Utc::now();
+
+        let first = repo.enqueue(&operation("a"), now).await.unwrap();
+        let second = repo.enqueue(&operation("b"), now).await.unwrap();
+        assert!(first.seq < second.seq);
+        assert_eq!(first.status, OutboxStatus::Pending);
+
+        let duplicate = repo.enqueue(&operation("a"), now).await.unwrap();
+        assert_eq!(duplicate.seq, first.seq);
+        assert_eq!(
+            repo.count_by_status(OutboxStatus::Pending).await.unwrap(),
+            2
+        );
+        assert_eq!(repo.next_pending().await.unwrap().unwrap().seq, first.seq);
+
+        assert!(repo.remove(first.seq).await.unwrap());
+        assert!(!repo.remove(first.seq).await.unwrap());
+        assert_eq!(repo.next_pending().await.unwrap().unwrap().seq, second.seq);
+    }
+
+    #[tokio::test]
+    async fn test_failure_conflict_and_requeue() {
+        let (_dir, repo) = create_test_repository().await;
+        let now = Utc::now();
+        let first = repo.enqueue(&operation("a"), now).await.unwrap();
+        let second = repo.enqueue(&operation("b"), now).await.unwrap();
+
+        let retry_at = now + Duration::seconds(30);
+        let failed = repo
+            .record_failure(first.seq, "offline", retry_at, now)
+            .await
+            .unwrap();
+        assert_eq!(failed.attempts, 1);
+        assert_eq!(failed.status, OutboxStatus::Pending);
+        assert_eq!(failed.next_attempt_at, retry_at);
+        assert_eq!(failed.last_error.as_deref(), Some("offline"));
+
+        let conflict = repo
+            .mark_failed(first.seq, OutboxStatus::Conflict, "409", now)
+            .await
+            .unwrap();
+        assert_eq!(conflict.attempts, 2);
+        assert_eq!(repo.next_pending().await.unwrap().unwrap().seq, second.seq);
+        assert_eq!(
+            repo.find_by_status(Some(OutboxStatus::Conflict))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(
+            repo.mark_failed(first.seq, OutboxStatus::Pending, "", now)
+                .await
+                .is_err()
+        );
+
+        let requeued = repo.requeue(first.seq, now).await.unwrap();
+        assert!(requeued.seq > second.seq);
+        assert_eq!(requeued.idempotency_key, "a");
+        assert_eq!(requeued.attempts, 0);
+        assert_eq!(requeued.status, OutboxStatus::Pending);
+        assert!(repo.find_by_seq(first.seq).await.unwrap().is_none());
+        assert_eq!(repo.find_by_status(None).await.unwrap().len(), 2);
+        assert!(matches!(
+            repo.requeue(first.seq, now).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,174 @@
+//! Comment用SQLiteリポジトリ
+
+use super::super::database_manager::DatabaseManager;
+use crate::errors::sqlite_error::SQLiteError;
+use crate::models::comment::{Column, Entity as CommentEntity, Model};
+use crate::models::{DomainToSqliteConverterWithProjectId, SqliteModelConverter};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::comment::Comment;
+use flequit_model::types::id_types::{CommentId, ProjectId, TaskId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::comment_repository_trait::CommentRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Debug)]
+pub struct CommentLocalSqliteRepository {
+    db_manager: Arc<RwLock<DatabaseManager>>,
+}
+
+impl CommentLocalSqliteRepository {
+    pub fn new(db_manager: Arc<RwLock<DatabaseManager>>) -> Self {
+        Self { db_manager }
+    }
+
+    /// 指定タスク（サブタスクへのものを含む）のコメントを投稿順に取得
+    pub async fn find_by_task(
+        &self,
+        project_id: &ProjectId,
+        task_id: &TaskId,
+    ) -> Result<Vec<Comment>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let models = CommentEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::TaskId.eq(task_id.to_string()))
+            .filter(Column::Deleted.eq(false))
+            .order_by_asc(Column::CreatedAt)
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        to_domain_models(models).await
+    }
+}
+
+async fn to_domain_models(models: Vec<Model>) -> Result<Vec<Comment>, RepositoryError> {
+    let mut comments = Vec::with_capacity(models.len());
+    for model in models {
+        let comment = model
+            .to_domain_model()
+            .await
+            .map_err(|e: String| RepositoryError::from(SQLiteError::ConversionError(e)))?;
+        comments.push(comment);
+    }
+    Ok(comments)
+}
+
+#[async_trait]
+impl CommentRepositoryTrait for CommentLocalSqliteRepository {}
+
+#[async_trait]
+impl ProjectRepository<Comment, CommentId> for CommentLocalSqliteRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        comment: &Comment,
+        _user_id: &UserId,
+        _timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let active_model = comment
+            .to_sqlite_model_with_project_id(project_id)
+            .await
+            .map_err(|e: String| RepositoryError::from(SQLiteError::ConversionError(e)))?;
+
+        let existing = CommentEntity::find_by_id((project_id.to_string(), comment.id.to_string()))
+            .one(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        if existing.is_some() {
+            active_model
+                .update(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        } else {
+            active_model
+                .insert(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &CommentId,
+    ) -> Result<Option<Comment>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let model = CommentEntity::find_by_id((project_id.to_string(), id.to_string()))
+            .one(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        match model {
+            Some(model) => Ok(Some(model.to_domain_model().await.map_err(
+                |e: String| RepositoryError::from(SQLiteError::ConversionError(e)),
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Comment>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let models = CommentEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::Deleted.eq(false))
+            .order_by_asc(Column::CreatedAt)
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        to_domain_models(models).await
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &CommentId) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        CommentEntity::delete_by_id((project_id.to_string(), id.to_string()))
+            .exec(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &CommentId,
+    ) -> Result<bool, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        let count = CommentEntity::find_by_id((project_id.to_string(), id.to_string()))
+            .count(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(count > 0)
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        let count = CommentEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::Deleted.eq(false))
+            .count(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(count)
+    }
+}
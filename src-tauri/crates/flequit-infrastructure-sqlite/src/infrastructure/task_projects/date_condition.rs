@@ -36,10 +36,7 @@ impl ProjectRepository<DateCondition, DateConditionId> for DateConditionLocalSql
         _timestamp: &DateTime<Utc>,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let sqlite_model = entity
             .to_sqlite_model_with_project_id(project_id)
@@ -70,10 +67,7 @@ impl ProjectRepository<DateCondition, DateConditionId> for DateConditionLocalSql
         id: &DateConditionId,
     ) -> Result<Option<DateCondition>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = DateConditionEntity::find()
             .filter(Column::Id.eq(id.as_str()))
@@ -96,10 +90,7 @@ impl ProjectRepository<DateCondition, DateConditionId> for DateConditionLocalSql
         _project_id: &ProjectId,
     ) -> Result<Vec<DateCondition>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = DateConditionEntity::find()
             .all(db)
@@ -124,10 +115,7 @@ impl ProjectRepository<DateCondition, DateConditionId> for DateConditionLocalSql
         id: &DateConditionId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         DateConditionEntity::delete_many()
             .filter(Column::Id.eq(id.as_str()))
@@ -144,10 +132,7 @@ impl ProjectRepository<DateCondition, DateConditionId> for DateConditionLocalSql
         id: &DateConditionId,
     ) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = DateConditionEntity::find()
             .filter(Column::Id.eq(id.as_str()))
@@ -160,10 +145,7 @@ impl ProjectRepository<DateCondition, DateConditionId> for DateConditionLocalSql
 
     async fn count(&self, _project_id: &ProjectId) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = DateConditionEntity::find()
             .count(db)
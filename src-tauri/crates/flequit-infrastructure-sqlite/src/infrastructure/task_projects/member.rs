@@ -39,10 +39,7 @@ impl ProjectRepository<Member, UserId> for MemberLocalSqliteRepository {
         _timestamp: &DateTime<Utc>,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let sqlite_model = entity
             .to_sqlite_model_with_project_id(project_id)
@@ -60,10 +57,17 @@ impl ProjectRepository<Member, UserId> for MemberLocalSqliteRepository {
             updated_by: Set(sqlite_model.updated_by),
         };
 
-        active_model
-            .insert(db)
+        // バンドルの再取り込みなどで同じメンバーを保存しても失敗しないよう、既存の場合は更新する
+        let existing = MemberEntity::find_by_id((project_id.to_string(), sqlite_model.id))
+            .one(db)
             .await
             .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        if existing.is_some() {
+            active_model.update(db).await
+        } else {
+            active_model.insert(db).await
+        }
+        .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
         Ok(())
     }
 
@@ -73,10 +77,7 @@ impl ProjectRepository<Member, UserId> for MemberLocalSqliteRepository {
         id: &UserId,
     ) -> Result<Option<Member>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = MemberEntity::find()
             .filter(Column::Id.eq(id.as_str()))
@@ -96,10 +97,7 @@ impl ProjectRepository<Member, UserId> for MemberLocalSqliteRepository {
 
     async fn find_all(&self, _project_id: &ProjectId) -> Result<Vec<Member>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = MemberEntity::find()
             .all(db)
@@ -120,10 +118,7 @@ impl ProjectRepository<Member, UserId> for MemberLocalSqliteRepository {
 
     async fn delete(&self, _project_id: &ProjectId, id: &UserId) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         MemberEntity::delete_many()
             .filter(Column::Id.eq(id.as_str()))
@@ -136,10 +131,7 @@ impl ProjectRepository<Member, UserId> for MemberLocalSqliteRepository {
 
     async fn exists(&self, _project_id: &ProjectId, id: &UserId) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = MemberEntity::find()
             .filter(Column::Id.eq(id.as_str()))
@@ -152,10 +144,7 @@ impl ProjectRepository<Member, UserId> for MemberLocalSqliteRepository {
 
     async fn count(&self, _project_id: &ProjectId) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = MemberEntity::find()
             .count(db)
@@ -1,7 +1,11 @@
+pub mod comment;
 pub mod date_condition;
 pub mod member;
 pub mod project;
 pub mod recurrence_rule;
+pub mod reminder;
+pub mod snooze;
+pub mod status_transition_rule;
 pub mod subtask;
 pub mod subtask_assignments;
 pub mod subtask_recurrence;
@@ -9,7 +13,10 @@ pub mod subtask_tag;
 pub mod tag;
 pub mod task;
 pub mod task_assignments;
+pub mod task_dependency;
 pub mod task_list;
 pub mod task_recurrence;
 pub mod task_tag;
+pub mod time_entry;
 pub mod weekday_condition;
+pub mod workflow_status;
@@ -28,10 +28,7 @@ impl ProjectLocalSqliteRepository {
 
     pub async fn find_by_owner(&self, owner_id: &str) -> Result<Vec<Project>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = ProjectEntity::find()
             .filter(Column::OwnerId.eq(owner_id))
@@ -54,10 +51,7 @@ impl ProjectLocalSqliteRepository {
 
     pub async fn find_active_projects(&self) -> Result<Vec<Project>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = ProjectEntity::find()
             .filter(Column::IsArchived.eq(false))
@@ -108,10 +102,7 @@ impl Repository<Project, ProjectId> for ProjectLocalSqliteRepository {
         );
         let db_manager = self.db_manager.read().await;
         tracing::info!("ProjectLocalSqliteRepository::save - DB Manager取得完了");
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         tracing::info!("ProjectLocalSqliteRepository::save - DB接続取得完了");
         let active_model = project
             .to_sqlite_model()
@@ -148,10 +139,7 @@ impl Repository<Project, ProjectId> for ProjectLocalSqliteRepository {
 
     async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = ProjectEntity::find_by_id(id.to_string())
             .one(db)
@@ -170,10 +158,7 @@ impl Repository<Project, ProjectId> for ProjectLocalSqliteRepository {
 
     async fn find_all(&self) -> Result<Vec<Project>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = ProjectEntity::find()
             .order_by_asc(Column::OrderIndex)
@@ -195,10 +180,7 @@ impl Repository<Project, ProjectId> for ProjectLocalSqliteRepository {
 
     async fn delete(&self, id: &ProjectId) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         ProjectEntity::delete_by_id(id.to_string())
             .exec(db)
             .await
@@ -208,10 +190,7 @@ impl Repository<Project, ProjectId> for ProjectLocalSqliteRepository {
 
     async fn exists(&self, id: &ProjectId) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = ProjectEntity::find_by_id(id.to_string())
             .count(db)
             .await
@@ -221,10 +200,7 @@ impl Repository<Project, ProjectId> for ProjectLocalSqliteRepository {
 
     async fn count(&self) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = ProjectEntity::find()
             .count(db)
             .await
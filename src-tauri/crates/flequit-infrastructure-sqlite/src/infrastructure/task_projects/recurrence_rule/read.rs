@@ -222,10 +222,7 @@ impl RecurrenceRuleLocalSqliteRepository {
 
     pub async fn find_by_unit(&self, unit: &str) -> Result<Vec<RecurrenceRule>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = RecurrenceRuleEntity::find()
             .filter(Column::Unit.eq(unit))
@@ -251,10 +248,7 @@ impl RecurrenceRuleLocalSqliteRepository {
         interval: i32,
     ) -> Result<Vec<RecurrenceRule>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = RecurrenceRuleEntity::find()
             .filter(Column::Interval.eq(interval))
@@ -277,10 +271,7 @@ impl RecurrenceRuleLocalSqliteRepository {
 
     pub async fn find_with_max_occurrences(&self) -> Result<Vec<RecurrenceRule>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = RecurrenceRuleEntity::find()
             .filter(Column::MaxOccurrences.is_not_null())
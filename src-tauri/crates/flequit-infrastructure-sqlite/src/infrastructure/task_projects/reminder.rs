@@ -0,0 +1,175 @@
+//! Reminder用SQLiteリポジトリ
+
+use super::super::database_manager::DatabaseManager;
+use crate::errors::sqlite_error::SQLiteError;
+use crate::models::reminder::{Column, Entity as ReminderEntity, Model};
+use crate::models::{DomainToSqliteConverterWithProjectId, SqliteModelConverter};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::reminder::Reminder;
+use flequit_model::types::id_types::{ProjectId, ReminderId, TaskId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::reminder_repository_trait::ReminderRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Debug)]
+pub struct ReminderLocalSqliteRepository {
+    db_manager: Arc<RwLock<DatabaseManager>>,
+}
+
+impl ReminderLocalSqliteRepository {
+    pub fn new(db_manager: Arc<RwLock<DatabaseManager>>) -> Self {
+        Self { db_manager }
+    }
+
+    /// 指定タスク（サブタスクのものを含む）のリマインダーを作成日時順に取得
+    pub async fn find_by_task(
+        &self,
+        project_id: &ProjectId,
+        task_id: &TaskId,
+    ) -> Result<Vec<Reminder>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let models = ReminderEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::TaskId.eq(task_id.to_string()))
+            .filter(Column::Deleted.eq(false))
+            .order_by_asc(Column::CreatedAt)
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        to_domain_models(models).await
+    }
+}
+
+async fn to_domain_models(models: Vec<Model>) -> Result<Vec<Reminder>, RepositoryError> {
+    let mut reminders = Vec::with_capacity(models.len());
+    for model in models {
+        let reminder = model
+            .to_domain_model()
+            .await
+            .map_err(|e: String| RepositoryError::from(SQLiteError::ConversionError(e)))?;
+        reminders.push(reminder);
+    }
+    Ok(reminders)
+}
+
+#[async_trait]
+impl ReminderRepositoryTrait for ReminderLocalSqliteRepository {}
+
+#[async_trait]
+impl ProjectRepository<Reminder, ReminderId> for ReminderLocalSqliteRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        reminder: &Reminder,
+        _user_id: &UserId,
+        _timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let active_model = reminder
+            .to_sqlite_model_with_project_id(project_id)
+            .await
+            .map_err(|e: String| RepositoryError::from(SQLiteError::ConversionError(e)))?;
+
+        let existing =
+            ReminderEntity::find_by_id((project_id.to_string(), reminder.id.to_string()))
+                .one(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        if existing.is_some() {
+            active_model
+                .update(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        } else {
+            active_model
+                .insert(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &ReminderId,
+    ) -> Result<Option<Reminder>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let model = ReminderEntity::find_by_id((project_id.to_string(), id.to_string()))
+            .one(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        match model {
+            Some(model) => Ok(Some(model.to_domain_model().await.map_err(
+                |e: String| RepositoryError::from(SQLiteError::ConversionError(e)),
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Reminder>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let models = ReminderEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::Deleted.eq(false))
+            .order_by_asc(Column::CreatedAt)
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        to_domain_models(models).await
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &ReminderId) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        ReminderEntity::delete_by_id((project_id.to_string(), id.to_string()))
+            .exec(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &ReminderId,
+    ) -> Result<bool, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        let count = ReminderEntity::find_by_id((project_id.to_string(), id.to_string()))
+            .count(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(count > 0)
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        let count = ReminderEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::Deleted.eq(false))
+            .count(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(count)
+    }
+}
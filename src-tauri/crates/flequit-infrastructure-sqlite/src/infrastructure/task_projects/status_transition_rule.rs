@@ -0,0 +1,167 @@
+//! StatusTransitionRule用SQLiteリポジトリ
+
+use super::super::database_manager::DatabaseManager;
+use crate::errors::sqlite_error::SQLiteError;
+use crate::models::status_transition_rule::{Column, Entity as StatusTransitionRuleEntity, Model};
+use crate::models::{DomainToSqliteConverterWithProjectId, SqliteModelConverter};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::status_transition_rule::StatusTransitionRule;
+use flequit_model::types::id_types::{ProjectId, StatusTransitionRuleId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::status_transition_rule_repository_trait::StatusTransitionRuleRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Debug)]
+pub struct StatusTransitionRuleLocalSqliteRepository {
+    db_manager: Arc<RwLock<DatabaseManager>>,
+}
+
+impl StatusTransitionRuleLocalSqliteRepository {
+    pub fn new(db_manager: Arc<RwLock<DatabaseManager>>) -> Self {
+        Self { db_manager }
+    }
+}
+
+async fn to_domain_models(
+    models: Vec<Model>,
+) -> Result<Vec<StatusTransitionRule>, RepositoryError> {
+    let mut rules = Vec::with_capacity(models.len());
+    for model in models {
+        let rule = model
+            .to_domain_model()
+            .await
+            .map_err(|e: String| RepositoryError::from(SQLiteError::ConversionError(e)))?;
+        rules.push(rule);
+    }
+    Ok(rules)
+}
+
+#[async_trait]
+impl StatusTransitionRuleRepositoryTrait for StatusTransitionRuleLocalSqliteRepository {}
+
+#[async_trait]
+impl ProjectRepository<StatusTransitionRule, StatusTransitionRuleId>
+    for StatusTransitionRuleLocalSqliteRepository
+{
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        rule: &StatusTransitionRule,
+        _user_id: &UserId,
+        _timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let active_model = rule
+            .to_sqlite_model_with_project_id(project_id)
+            .await
+            .map_err(|e: String| RepositoryError::from(SQLiteError::ConversionError(e)))?;
+
+        let existing =
+            StatusTransitionRuleEntity::find_by_id((project_id.to_string(), rule.id.to_string()))
+                .one(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        if existing.is_some() {
+            active_model
+                .update(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        } else {
+            active_model
+                .insert(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<Option<StatusTransitionRule>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let model =
+            StatusTransitionRuleEntity::find_by_id((project_id.to_string(), id.to_string()))
+                .one(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        match model {
+            Some(model) => Ok(Some(model.to_domain_model().await.map_err(
+                |e: String| RepositoryError::from(SQLiteError::ConversionError(e)),
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<StatusTransitionRule>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let models = StatusTransitionRuleEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::Deleted.eq(false))
+            .order_by_asc(Column::CreatedAt)
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        to_domain_models(models).await
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        StatusTransitionRuleEntity::delete_by_id((project_id.to_string(), id.to_string()))
+            .exec(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<bool, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        let count =
+            StatusTransitionRuleEntity::find_by_id((project_id.to_string(), id.to_string()))
+                .count(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(count > 0)
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        let count = StatusTransitionRuleEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::Deleted.eq(false))
+            .count(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(count)
+    }
+}
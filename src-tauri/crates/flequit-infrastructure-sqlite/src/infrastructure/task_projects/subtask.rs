@@ -36,10 +36,7 @@ impl SubTaskLocalSqliteRepository {
 
     pub async fn find_by_task(&self, task_id: &str) -> Result<Vec<SubTask>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubtaskEntity::find()
             .filter(Column::TaskId.eq(task_id))
@@ -72,10 +69,7 @@ impl SubTaskLocalSqliteRepository {
         task_id: &str,
     ) -> Result<Vec<SubTask>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubtaskEntity::find()
             .filter(Column::TaskId.eq(task_id))
@@ -152,10 +146,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskLocalSqliteRepository {
         _timestamp: &DateTime<Utc>,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // トランザクション開始
         let txn = db
@@ -218,10 +209,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskLocalSqliteRepository {
         id: &SubTaskId,
     ) -> Result<Option<SubTask>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = SubtaskEntity::find_by_id((project_id.to_string(), id.to_string()))
             .one(db)
@@ -247,10 +235,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskLocalSqliteRepository {
 
     async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<SubTask>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubtaskEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -280,10 +265,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskLocalSqliteRepository {
 
     async fn delete(&self, project_id: &ProjectId, id: &SubTaskId) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // トランザクション開始
         let txn = db
@@ -319,10 +301,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskLocalSqliteRepository {
         id: &SubTaskId,
     ) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = SubtaskEntity::find_by_id((project_id.to_string(), id.to_string()))
             .count(db)
             .await
@@ -332,10 +311,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskLocalSqliteRepository {
 
     async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = SubtaskEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
             .count(db)
@@ -2,8 +2,8 @@
 
 use super::super::database_manager::DatabaseManager;
 use crate::errors::sqlite_error::SQLiteError;
-use crate::models::subtask_assignments::{Column, Entity as SubtaskAssignmentEntity};
 use crate::models::SqliteModelConverter;
+use crate::models::subtask_assignments::{Column, Entity as SubtaskAssignmentEntity};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use flequit_model::models::task_projects::subtask_assignment::SubTaskAssignment;
@@ -33,10 +33,7 @@ impl SubtaskAssignmentLocalSqliteRepository {
         subtask_id: &SubTaskId,
     ) -> Result<Vec<UserId>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubtaskAssignmentEntity::find()
             .filter(Column::SubtaskId.eq(subtask_id.to_string()))
@@ -58,10 +55,7 @@ impl SubtaskAssignmentLocalSqliteRepository {
         user_id: &UserId,
     ) -> Result<Vec<SubTaskId>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubtaskAssignmentEntity::find()
             .filter(Column::UserId.eq(user_id.to_string()))
@@ -85,10 +79,7 @@ impl SubtaskAssignmentLocalSqliteRepository {
         user_id: &UserId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // 既存の割り当てが存在するかチェック
         let existing = SubtaskAssignmentEntity::find()
@@ -127,10 +118,7 @@ impl SubtaskAssignmentLocalSqliteRepository {
         user_id: &UserId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         SubtaskAssignmentEntity::delete_many()
             .filter(Column::SubtaskId.eq(subtask_id.to_string()))
@@ -148,10 +136,7 @@ impl SubtaskAssignmentLocalSqliteRepository {
         subtask_id: &SubTaskId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         SubtaskAssignmentEntity::delete_many()
             .filter(Column::SubtaskId.eq(subtask_id.to_string()))
@@ -168,10 +153,7 @@ impl SubtaskAssignmentLocalSqliteRepository {
         user_id: &UserId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         SubtaskAssignmentEntity::delete_many()
             .filter(Column::UserId.eq(user_id.to_string()))
@@ -257,10 +239,7 @@ impl ProjectRelationRepository<SubTaskAssignment, SubTaskId, UserId>
         parent_id: &SubTaskId,
     ) -> Result<Vec<SubTaskAssignment>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubtaskAssignmentEntity::find()
             .filter(Column::SubtaskId.eq(parent_id.to_string()))
@@ -286,10 +265,7 @@ impl ProjectRelationRepository<SubTaskAssignment, SubTaskId, UserId>
         parent_id: &SubTaskId,
     ) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = SubtaskAssignmentEntity::find()
             .filter(Column::SubtaskId.eq(parent_id.to_string()))
@@ -306,10 +282,7 @@ impl ProjectRelationRepository<SubTaskAssignment, SubTaskId, UserId>
         parent_id: &SubTaskId,
     ) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = SubtaskAssignmentEntity::find()
             .filter(Column::SubtaskId.eq(parent_id.to_string()))
@@ -325,10 +298,7 @@ impl ProjectRelationRepository<SubTaskAssignment, SubTaskId, UserId>
         _project_id: &ProjectId,
     ) -> Result<Vec<SubTaskAssignment>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubtaskAssignmentEntity::find()
             .all(db)
@@ -354,10 +324,7 @@ impl ProjectRelationRepository<SubTaskAssignment, SubTaskId, UserId>
         child_id: &UserId,
     ) -> Result<Option<SubTaskAssignment>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let model = SubtaskAssignmentEntity::find()
             .filter(Column::SubtaskId.eq(parent_id.to_string()))
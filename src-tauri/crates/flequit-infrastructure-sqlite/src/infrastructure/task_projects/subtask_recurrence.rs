@@ -144,10 +144,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         _timestamp: &DateTime<Utc>,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let existing = SubTaskRecurrenceEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -185,10 +182,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         child_id: &RecurrenceRuleId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         SubTaskRecurrenceEntity::delete_many()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -207,10 +201,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         parent_id: &SubTaskId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         SubTaskRecurrenceEntity::delete_many()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -228,10 +219,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         parent_id: &SubTaskId,
     ) -> Result<Vec<SubTaskRecurrence>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubTaskRecurrenceEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -262,10 +250,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         parent_id: &SubTaskId,
     ) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = SubTaskRecurrenceEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -283,10 +268,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         parent_id: &SubTaskId,
     ) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = SubTaskRecurrenceEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -303,10 +285,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         project_id: &ProjectId,
     ) -> Result<Vec<SubTaskRecurrence>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubTaskRecurrenceEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -337,10 +316,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         child_id: &RecurrenceRuleId,
     ) -> Result<Option<SubTaskRecurrence>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let model = SubTaskRecurrenceEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
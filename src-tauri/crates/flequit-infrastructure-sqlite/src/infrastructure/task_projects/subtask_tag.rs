@@ -2,8 +2,8 @@
 
 use super::super::database_manager::DatabaseManager;
 use crate::errors::sqlite_error::SQLiteError;
-use crate::models::subtask_tag::{Column, Entity as SubtaskTagEntity};
 use crate::models::SqliteModelConverter;
+use crate::models::subtask_tag::{Column, Entity as SubtaskTagEntity};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use flequit_model::models::task_projects::subtask_tag::SubTaskTag;
@@ -33,10 +33,7 @@ impl SubtaskTagLocalSqliteRepository {
         subtask_id: &SubTaskId,
     ) -> Result<Vec<TagId>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubtaskTagEntity::find()
             .filter(Column::SubtaskId.eq(subtask_id.to_string()))
@@ -58,10 +55,7 @@ impl SubtaskTagLocalSqliteRepository {
         tag_id: &TagId,
     ) -> Result<Vec<SubTaskId>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubtaskTagEntity::find()
             .filter(Column::TagId.eq(tag_id.to_string()))
@@ -85,10 +79,7 @@ impl SubtaskTagLocalSqliteRepository {
         tag_id: &TagId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // 既存の関連が存在するかチェック
         let existing = SubtaskTagEntity::find()
@@ -135,10 +126,7 @@ impl SubtaskTagLocalSqliteRepository {
         tag_id: &TagId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         SubtaskTagEntity::delete_many()
             .filter(Column::SubtaskId.eq(subtask_id.to_string()))
@@ -176,10 +164,7 @@ impl SubtaskTagLocalSqliteRepository {
         subtask_id: &SubTaskId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         SubtaskTagEntity::delete_many()
             .filter(Column::SubtaskId.eq(subtask_id.to_string()))
@@ -216,10 +201,7 @@ impl SubtaskTagLocalSqliteRepository {
         tag_id: &TagId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         SubtaskTagEntity::delete_many()
             .filter(Column::TagId.eq(tag_id.to_string()))
@@ -303,10 +285,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubtaskTagLocal
         parent_id: &SubTaskId,
     ) -> Result<Vec<SubTaskTag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubtaskTagEntity::find()
             .filter(Column::SubtaskId.eq(parent_id.to_string()))
@@ -332,10 +311,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubtaskTagLocal
         parent_id: &SubTaskId,
     ) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = SubtaskTagEntity::find()
             .filter(Column::SubtaskId.eq(parent_id.to_string()))
@@ -352,10 +328,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubtaskTagLocal
         parent_id: &SubTaskId,
     ) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = SubtaskTagEntity::find()
             .filter(Column::SubtaskId.eq(parent_id.to_string()))
@@ -368,10 +341,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubtaskTagLocal
 
     async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<SubTaskTag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = SubtaskTagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -398,10 +368,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubtaskTagLocal
         child_id: &TagId,
     ) -> Result<Option<SubTaskTag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let model = SubtaskTagEntity::find()
             .filter(Column::SubtaskId.eq(parent_id.to_string()))
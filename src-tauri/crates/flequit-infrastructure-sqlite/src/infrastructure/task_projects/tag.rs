@@ -31,10 +31,7 @@ impl TagLocalSqliteRepository {
 
     pub async fn find_by_name(&self, name: &str) -> Result<Option<Tag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = TagEntity::find()
             .filter(Column::Name.eq(name))
@@ -54,10 +51,7 @@ impl TagLocalSqliteRepository {
 
     pub async fn find_by_color(&self, color: &str) -> Result<Vec<Tag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TagEntity::find()
             .filter(Column::Color.eq(color))
@@ -80,10 +74,7 @@ impl TagLocalSqliteRepository {
 
     pub async fn find_popular_tags(&self, limit: u64) -> Result<Vec<Tag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TagEntity::find()
             .order_by_desc(Column::UsageCount)
@@ -110,10 +101,7 @@ impl TagLocalSqliteRepository {
         tag_id: &str,
     ) -> Result<Tag, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let existing = TagEntity::find_by_id((project_id.to_string(), tag_id.to_string()))
             .one(db)
@@ -140,10 +128,7 @@ impl TagLocalSqliteRepository {
         tag_id: &str,
     ) -> Result<Tag, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let existing = TagEntity::find_by_id((project_id.to_string(), tag_id.to_string()))
             .one(db)
@@ -170,10 +155,7 @@ impl TagLocalSqliteRepository {
         name: &str,
     ) -> Result<Option<Tag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = TagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -198,10 +180,7 @@ impl TagLocalSqliteRepository {
         project_id: &ProjectId,
     ) -> Result<Vec<TagId>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -244,10 +223,7 @@ impl ProjectRepository<Tag, TagId> for TagLocalSqliteRepository {
         _timestamp: &DateTime<Utc>,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // 名前での重複チェック（プロジェクト内のみ）
         let existing_by_name = self.find_by_name_in_project(project_id, &tag.name).await?;
@@ -303,10 +279,7 @@ impl ProjectRepository<Tag, TagId> for TagLocalSqliteRepository {
         id: &TagId,
     ) -> Result<Option<Tag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = TagEntity::find_by_id((project_id.to_string(), id.to_string()))
             .one(db)
@@ -325,10 +298,7 @@ impl ProjectRepository<Tag, TagId> for TagLocalSqliteRepository {
 
     async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Tag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -351,10 +321,7 @@ impl ProjectRepository<Tag, TagId> for TagLocalSqliteRepository {
 
     async fn delete(&self, project_id: &ProjectId, id: &TagId) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         TagEntity::delete_by_id((project_id.to_string(), id.to_string()))
             .exec(db)
             .await
@@ -364,10 +331,7 @@ impl ProjectRepository<Tag, TagId> for TagLocalSqliteRepository {
 
     async fn exists(&self, project_id: &ProjectId, id: &TagId) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = TagEntity::find_by_id((project_id.to_string(), id.to_string()))
             .count(db)
             .await
@@ -377,10 +341,7 @@ impl ProjectRepository<Tag, TagId> for TagLocalSqliteRepository {
 
     async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = TagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
             .count(db)
@@ -14,9 +14,10 @@ use flequit_repository::repositories::project_patchable_trait::ProjectPatchable;
 use flequit_repository::repositories::project_repository_trait::ProjectRepository;
 use flequit_repository::repositories::task_projects::task_repository_trait::TaskRepositoryTrait;
 use flequit_types::errors::repository_error::RepositoryError;
+use sea_orm::sea_query::Expr;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
-    TransactionTrait,
+    QuerySelect, TransactionTrait,
 };
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -41,10 +42,7 @@ impl TaskLocalSqliteRepository {
         project_id: &ProjectId,
     ) -> Result<Vec<Task>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -79,10 +77,7 @@ impl TaskLocalSqliteRepository {
         list_id: &str,
     ) -> Result<Vec<Task>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskEntity::find()
             .filter(Column::ListId.eq(list_id))
@@ -117,10 +112,7 @@ impl TaskLocalSqliteRepository {
         status: &str,
     ) -> Result<Vec<Task>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskEntity::find()
             .filter(Column::Status.eq(status))
@@ -171,10 +163,7 @@ impl TaskLocalSqliteRepository {
         project_id: &ProjectId,
     ) -> Result<Vec<TaskId>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -189,6 +178,55 @@ impl TaskLocalSqliteRepository {
 
         Ok(task_ids)
     }
+
+    /// タスクを保存しているプロジェクトのIDリストを取得
+    pub async fn find_project_ids(&self) -> Result<Vec<ProjectId>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let project_ids: Vec<String> = TaskEntity::find()
+            .select_only()
+            .column(Column::ProjectId)
+            .distinct()
+            .into_tuple()
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        Ok(project_ids.into_iter().map(ProjectId::from).collect())
+    }
+
+    /// 実績日時が未保存のタスクに、指定したタスクの実績日時を補完する
+    ///
+    /// 既に実績日時が保存されているタスクは変更しない。補完したタスク数を返す。
+    pub async fn backfill_work_dates(
+        &self,
+        project_id: &ProjectId,
+        tasks: &[Task],
+    ) -> Result<u64, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let mut updated = 0;
+        for task in tasks
+            .iter()
+            .filter(|task| task.do_start_date.is_some() || task.do_end_date.is_some())
+        {
+            let result = TaskEntity::update_many()
+                .col_expr(Column::DoStartDate, Expr::value(task.do_start_date))
+                .col_expr(Column::DoEndDate, Expr::value(task.do_end_date))
+                .filter(Column::ProjectId.eq(project_id.to_string()))
+                .filter(Column::Id.eq(task.id.to_string()))
+                .filter(Column::DoStartDate.is_null())
+                .filter(Column::DoEndDate.is_null())
+                .exec(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+            updated += result.rows_affected;
+        }
+
+        Ok(updated)
+    }
 }
 
 #[async_trait]
@@ -201,10 +239,7 @@ impl ProjectRepository<Task, TaskId> for TaskLocalSqliteRepository {
         _timestamp: &DateTime<Utc>,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // トランザクション開始
         let txn = db
@@ -266,10 +301,7 @@ impl ProjectRepository<Task, TaskId> for TaskLocalSqliteRepository {
         id: &TaskId,
     ) -> Result<Option<Task>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = TaskEntity::find_by_id((project_id.to_string(), id.to_string()))
             .one(db)
@@ -295,10 +327,7 @@ impl ProjectRepository<Task, TaskId> for TaskLocalSqliteRepository {
 
     async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Task>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -333,10 +362,7 @@ impl ProjectRepository<Task, TaskId> for TaskLocalSqliteRepository {
     /// 新しいコードではFacade層でトランザクションを管理し、`delete_with_txn`を使用してください。
     async fn delete(&self, project_id: &ProjectId, id: &TaskId) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // 単純な削除処理（トランザクション制御はFacade層で行う）
         TaskEntity::delete_by_id((project_id.to_string(), id.to_string()))
@@ -348,10 +374,7 @@ impl ProjectRepository<Task, TaskId> for TaskLocalSqliteRepository {
 
     async fn exists(&self, project_id: &ProjectId, id: &TaskId) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = TaskEntity::find_by_id((project_id.to_string(), id.to_string()))
             .count(db)
             .await
@@ -361,10 +384,7 @@ impl ProjectRepository<Task, TaskId> for TaskLocalSqliteRepository {
 
     async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = TaskEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
             .filter(Column::IsArchived.eq(false))
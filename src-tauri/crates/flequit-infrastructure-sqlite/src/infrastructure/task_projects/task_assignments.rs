@@ -2,8 +2,8 @@
 
 use super::super::database_manager::DatabaseManager;
 use crate::errors::sqlite_error::SQLiteError;
-use crate::models::task_assignments::{Column, Entity as TaskAssignmentEntity};
 use crate::models::SqliteModelConverter;
+use crate::models::task_assignments::{Column, Entity as TaskAssignmentEntity};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use flequit_model::models::task_projects::task_assignment::TaskAssignment;
@@ -33,10 +33,7 @@ impl TaskAssignmentLocalSqliteRepository {
         task_id: &TaskId,
     ) -> Result<Vec<UserId>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskAssignmentEntity::find()
             .filter(Column::TaskId.eq(task_id.to_string()))
@@ -58,10 +55,7 @@ impl TaskAssignmentLocalSqliteRepository {
         user_id: &UserId,
     ) -> Result<Vec<TaskId>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskAssignmentEntity::find()
             .filter(Column::UserId.eq(user_id.to_string()))
@@ -85,10 +79,7 @@ impl TaskAssignmentLocalSqliteRepository {
         user_id: &UserId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // 既存の割り当てが存在するかチェック
         let existing = TaskAssignmentEntity::find()
@@ -127,10 +118,7 @@ impl TaskAssignmentLocalSqliteRepository {
         user_id: &UserId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         TaskAssignmentEntity::delete_many()
             .filter(Column::TaskId.eq(task_id.to_string()))
@@ -168,10 +156,7 @@ impl TaskAssignmentLocalSqliteRepository {
         task_id: &TaskId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         TaskAssignmentEntity::delete_many()
             .filter(Column::TaskId.eq(task_id.to_string()))
@@ -188,10 +173,7 @@ impl TaskAssignmentLocalSqliteRepository {
         user_id: &UserId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         TaskAssignmentEntity::delete_many()
             .filter(Column::UserId.eq(user_id.to_string()))
@@ -277,10 +259,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId>
         parent_id: &TaskId,
     ) -> Result<Vec<TaskAssignment>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskAssignmentEntity::find()
             .filter(Column::TaskId.eq(parent_id.to_string()))
@@ -306,10 +285,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId>
         parent_id: &TaskId,
     ) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = TaskAssignmentEntity::find()
             .filter(Column::TaskId.eq(parent_id.to_string()))
@@ -326,10 +302,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId>
         parent_id: &TaskId,
     ) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = TaskAssignmentEntity::find()
             .filter(Column::TaskId.eq(parent_id.to_string()))
@@ -345,10 +318,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId>
         _project_id: &ProjectId,
     ) -> Result<Vec<TaskAssignment>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskAssignmentEntity::find()
             .all(db)
@@ -374,10 +344,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId>
         child_id: &UserId,
     ) -> Result<Option<TaskAssignment>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let model = TaskAssignmentEntity::find()
             .filter(Column::TaskId.eq(parent_id.to_string()))
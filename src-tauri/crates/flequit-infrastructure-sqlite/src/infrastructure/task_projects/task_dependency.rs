@@ -0,0 +1,257 @@
+//! TaskDependency用SQLiteリポジトリ
+
+use super::super::database_manager::DatabaseManager;
+use crate::errors::sqlite_error::SQLiteError;
+use crate::models::SqliteModelConverter;
+use crate::models::task_dependency::{Column, Entity as TaskDependencyEntity, Model};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::task_dependency::TaskDependency;
+use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
+use flequit_repository::repositories::project_relation_repository_trait::ProjectRelationRepository;
+use flequit_repository::repositories::task_projects::task_dependency_repository_trait::TaskDependencyRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder,
+};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Debug)]
+pub struct TaskDependencyLocalSqliteRepository {
+    db_manager: Arc<RwLock<DatabaseManager>>,
+}
+
+impl TaskDependencyLocalSqliteRepository {
+    pub fn new(db_manager: Arc<RwLock<DatabaseManager>>) -> Self {
+        Self { db_manager }
+    }
+
+    /// 指定タスクを先行タスクとする依存関係（後続タスク側）を取得
+    pub async fn find_dependents(
+        &self,
+        project_id: &ProjectId,
+        depends_on_task_id: &TaskId,
+    ) -> Result<Vec<TaskDependency>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let models = TaskDependencyEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::DependsOnTaskId.eq(depends_on_task_id.to_string()))
+            .order_by_asc(Column::CreatedAt)
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        to_domain_models(models).await
+    }
+
+    /// 指定タスクが関わる依存関係（先行・後続の両方）をすべて削除
+    pub async fn remove_all_by_task_id(
+        &self,
+        project_id: &ProjectId,
+        task_id: &TaskId,
+    ) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        TaskDependencyEntity::delete_many()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(
+                Column::TaskId
+                    .eq(task_id.to_string())
+                    .or(Column::DependsOnTaskId.eq(task_id.to_string())),
+            )
+            .exec(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        Ok(())
+    }
+}
+
+async fn to_domain_models(models: Vec<Model>) -> Result<Vec<TaskDependency>, RepositoryError> {
+    let mut domain_models = Vec::with_capacity(models.len());
+    for model in models {
+        domain_models.push(
+            model
+                .to_domain_model()
+                .await
+                .map_err(RepositoryError::ConversionError)?,
+        );
+    }
+    Ok(domain_models)
+}
+
+impl TaskDependencyRepositoryTrait for TaskDependencyLocalSqliteRepository {}
+
+#[async_trait]
+impl ProjectRelationRepository<TaskDependency, TaskId, TaskId>
+    for TaskDependencyLocalSqliteRepository
+{
+    async fn add(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        if self
+            .find_relation(project_id, parent_id, child_id)
+            .await?
+            .is_some()
+        {
+            return Ok(());
+        }
+
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let active_model = crate::models::task_dependency::ActiveModel {
+            project_id: Set(project_id.to_string()),
+            task_id: Set(parent_id.to_string()),
+            depends_on_task_id: Set(child_id.to_string()),
+            created_at: Set(*timestamp),
+            updated_at: Set(*timestamp),
+            deleted: Set(false),
+            updated_by: Set(user_id.to_string()),
+        };
+        active_model
+            .insert(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        Ok(())
+    }
+
+    async fn remove(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+    ) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        TaskDependencyEntity::delete_many()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::TaskId.eq(parent_id.to_string()))
+            .filter(Column::DependsOnTaskId.eq(child_id.to_string()))
+            .exec(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        Ok(())
+    }
+
+    async fn remove_all(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        TaskDependencyEntity::delete_many()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::TaskId.eq(parent_id.to_string()))
+            .exec(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        Ok(())
+    }
+
+    async fn find_relations(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<Vec<TaskDependency>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let models = TaskDependencyEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::TaskId.eq(parent_id.to_string()))
+            .order_by_asc(Column::CreatedAt)
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        to_domain_models(models).await
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<bool, RepositoryError> {
+        Ok(self.count(project_id, parent_id).await? > 0)
+    }
+
+    async fn count(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<u64, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let count = TaskDependencyEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::TaskId.eq(parent_id.to_string()))
+            .count(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        Ok(count)
+    }
+
+    async fn find_all(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<TaskDependency>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let models = TaskDependencyEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .order_by_asc(Column::CreatedAt)
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        to_domain_models(models).await
+    }
+
+    async fn find_relation(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+    ) -> Result<Option<TaskDependency>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let model = TaskDependencyEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::TaskId.eq(parent_id.to_string()))
+            .filter(Column::DependsOnTaskId.eq(child_id.to_string()))
+            .one(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        match model {
+            Some(model) => Ok(Some(
+                model
+                    .to_domain_model()
+                    .await
+                    .map_err(RepositoryError::ConversionError)?,
+            )),
+            None => Ok(None),
+        }
+    }
+}
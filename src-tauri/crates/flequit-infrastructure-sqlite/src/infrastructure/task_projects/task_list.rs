@@ -33,10 +33,7 @@ impl TaskListLocalSqliteRepository {
         project_id: &ProjectId,
     ) -> Result<Vec<TaskList>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskListEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -101,10 +98,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListLocalSqliteRepository {
         _timestamp: &DateTime<Utc>,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let active_model = task_list
             .to_sqlite_model_with_project_id(project_id)
             .await
@@ -139,10 +133,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListLocalSqliteRepository {
         id: &TaskListId,
     ) -> Result<Option<TaskList>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = TaskListEntity::find_by_id((project_id.to_string(), id.to_string()))
             .one(db)
@@ -161,10 +152,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListLocalSqliteRepository {
 
     async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<TaskList>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskListEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -188,10 +176,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListLocalSqliteRepository {
 
     async fn delete(&self, project_id: &ProjectId, id: &TaskListId) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         TaskListEntity::delete_by_id((project_id.to_string(), id.to_string()))
             .exec(db)
             .await
@@ -205,10 +190,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListLocalSqliteRepository {
         id: &TaskListId,
     ) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = TaskListEntity::find_by_id((project_id.to_string(), id.to_string()))
             .count(db)
             .await
@@ -218,10 +200,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListLocalSqliteRepository {
 
     async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
         let count = TaskListEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
             .filter(Column::IsArchived.eq(false))
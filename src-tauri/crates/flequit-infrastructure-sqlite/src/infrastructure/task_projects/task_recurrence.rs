@@ -61,10 +61,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         _timestamp: &DateTime<Utc>,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // 既存チェック
         let existing = TaskRecurrenceEntity::find()
@@ -103,10 +100,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         child_id: &RecurrenceRuleId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         TaskRecurrenceEntity::delete_many()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -125,10 +119,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         parent_id: &TaskId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         TaskRecurrenceEntity::delete_many()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -146,10 +137,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         parent_id: &TaskId,
     ) -> Result<Vec<TaskRecurrence>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskRecurrenceEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -180,10 +168,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         parent_id: &TaskId,
     ) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = TaskRecurrenceEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -201,10 +186,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         parent_id: &TaskId,
     ) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = TaskRecurrenceEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -221,10 +203,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         project_id: &ProjectId,
     ) -> Result<Vec<TaskRecurrence>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskRecurrenceEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -255,10 +234,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         child_id: &RecurrenceRuleId,
     ) -> Result<Option<TaskRecurrence>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let model = TaskRecurrenceEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
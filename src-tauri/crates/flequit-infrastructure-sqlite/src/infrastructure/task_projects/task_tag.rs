@@ -2,8 +2,8 @@
 
 use super::super::database_manager::DatabaseManager;
 use crate::errors::sqlite_error::SQLiteError;
-use crate::models::task_tag::{Column, Entity as TaskTagEntity};
 use crate::models::SqliteModelConverter;
+use crate::models::task_tag::{Column, Entity as TaskTagEntity};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use flequit_model::models::task_projects::task_tag::TaskTag;
@@ -34,10 +34,7 @@ impl TaskTagLocalSqliteRepository {
         task_id: &TaskId,
     ) -> Result<Vec<TagId>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskTagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -61,10 +58,7 @@ impl TaskTagLocalSqliteRepository {
         tag_id: &TagId,
     ) -> Result<Vec<TaskId>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskTagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -89,10 +83,7 @@ impl TaskTagLocalSqliteRepository {
         tag_id: &TagId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // 既存の関連が存在するかチェック
         let existing = TaskTagEntity::find()
@@ -140,10 +131,7 @@ impl TaskTagLocalSqliteRepository {
         tag_id: &TagId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         TaskTagEntity::delete_many()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -185,10 +173,7 @@ impl TaskTagLocalSqliteRepository {
         task_id: &TaskId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         TaskTagEntity::delete_many()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -229,10 +214,7 @@ impl TaskTagLocalSqliteRepository {
         tag_id: &TagId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         TaskTagEntity::delete_many()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -318,10 +300,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagLocalSqliteRep
         parent_id: &TaskId,
     ) -> Result<Vec<TaskTag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskTagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -348,10 +327,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagLocalSqliteRep
         parent_id: &TaskId,
     ) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = TaskTagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -369,10 +345,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagLocalSqliteRep
         parent_id: &TaskId,
     ) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = TaskTagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -386,10 +359,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagLocalSqliteRep
 
     async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<TaskTag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TaskTagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -416,10 +386,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagLocalSqliteRep
         child_id: &TagId,
     ) -> Result<Option<TaskTag>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let model = TaskTagEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
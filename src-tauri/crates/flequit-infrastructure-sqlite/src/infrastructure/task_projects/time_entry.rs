@@ -0,0 +1,177 @@
+//! TimeEntry用SQLiteリポジトリ
+
+use super::super::database_manager::DatabaseManager;
+use crate::errors::sqlite_error::SQLiteError;
+use crate::models::time_entry::{Column, Entity as TimeEntryEntity, Model};
+use crate::models::{DomainToSqliteConverterWithProjectId, SqliteModelConverter};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::time_entry::TimeEntry;
+use flequit_model::types::id_types::{ProjectId, TimeEntryId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::time_entry_repository_trait::TimeEntryRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Debug)]
+pub struct TimeEntryLocalSqliteRepository {
+    db_manager: Arc<RwLock<DatabaseManager>>,
+}
+
+impl TimeEntryLocalSqliteRepository {
+    pub fn new(db_manager: Arc<RwLock<DatabaseManager>>) -> Self {
+        Self { db_manager }
+    }
+
+    /// 指定ユーザーの計測中（終了日時が未記録）のタイムエントリを全プロジェクトから取得
+    pub async fn find_running_by_user(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<TimeEntry>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let models = TimeEntryEntity::find()
+            .filter(Column::UserId.eq(user_id.to_string()))
+            .filter(Column::EndedAt.is_null())
+            .filter(Column::Deleted.eq(false))
+            .order_by_asc(Column::StartedAt)
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        to_domain_models(models).await
+    }
+}
+
+async fn to_domain_models(models: Vec<Model>) -> Result<Vec<TimeEntry>, RepositoryError> {
+    let mut entries = Vec::with_capacity(models.len());
+    for model in models {
+        let entry = model
+            .to_domain_model()
+            .await
+            .map_err(|e: String| RepositoryError::from(SQLiteError::ConversionError(e)))?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+#[async_trait]
+impl TimeEntryRepositoryTrait for TimeEntryLocalSqliteRepository {}
+
+#[async_trait]
+impl ProjectRepository<TimeEntry, TimeEntryId> for TimeEntryLocalSqliteRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entry: &TimeEntry,
+        _user_id: &UserId,
+        _timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let active_model = entry
+            .to_sqlite_model_with_project_id(project_id)
+            .await
+            .map_err(|e: String| RepositoryError::from(SQLiteError::ConversionError(e)))?;
+
+        let existing = TimeEntryEntity::find_by_id((project_id.to_string(), entry.id.to_string()))
+            .one(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        if existing.is_some() {
+            active_model
+                .update(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        } else {
+            active_model
+                .insert(db)
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<Option<TimeEntry>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let model = TimeEntryEntity::find_by_id((project_id.to_string(), id.to_string()))
+            .one(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        match model {
+            Some(model) => Ok(Some(model.to_domain_model().await.map_err(
+                |e: String| RepositoryError::from(SQLiteError::ConversionError(e)),
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<TimeEntry>, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+
+        let models = TimeEntryEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::Deleted.eq(false))
+            .order_by_asc(Column::StartedAt)
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+
+        to_domain_models(models).await
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<(), RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        TimeEntryEntity::delete_by_id((project_id.to_string(), id.to_string()))
+            .exec(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<bool, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        let count = TimeEntryEntity::find_by_id((project_id.to_string(), id.to_string()))
+            .count(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(count > 0)
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        let db_manager = self.db_manager.read().await;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
+        let count = TimeEntryEntity::find()
+            .filter(Column::ProjectId.eq(project_id.to_string()))
+            .filter(Column::Deleted.eq(false))
+            .count(db)
+            .await
+            .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+        Ok(count)
+    }
+}
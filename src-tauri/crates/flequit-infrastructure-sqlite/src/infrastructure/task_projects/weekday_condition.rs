@@ -38,10 +38,7 @@ impl ProjectRepository<WeekdayCondition, WeekdayConditionId>
         _timestamp: &DateTime<Utc>,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let sqlite_model = entity
             .to_sqlite_model_with_project_id(project_id)
@@ -75,10 +72,7 @@ impl ProjectRepository<WeekdayCondition, WeekdayConditionId>
         id: &WeekdayConditionId,
     ) -> Result<Option<WeekdayCondition>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = WeekdayConditionEntity::find()
             .filter(Column::Id.eq(id.as_str()))
@@ -101,10 +95,7 @@ impl ProjectRepository<WeekdayCondition, WeekdayConditionId>
         _project_id: &ProjectId,
     ) -> Result<Vec<WeekdayCondition>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = WeekdayConditionEntity::find()
             .all(db)
@@ -129,10 +120,7 @@ impl ProjectRepository<WeekdayCondition, WeekdayConditionId>
         id: &WeekdayConditionId,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         WeekdayConditionEntity::delete_many()
             .filter(Column::Id.eq(id.as_str()))
@@ -149,10 +137,7 @@ impl ProjectRepository<WeekdayCondition, WeekdayConditionId>
         id: &WeekdayConditionId,
     ) -> Result<bool, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = WeekdayConditionEntity::find()
             .filter(Column::Id.eq(id.as_str()))
@@ -165,10 +150,7 @@ impl ProjectRepository<WeekdayCondition, WeekdayConditionId>
 
     async fn count(&self, _project_id: &ProjectId) -> Result<u64, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = WeekdayConditionEntity::find()
             .count(db)
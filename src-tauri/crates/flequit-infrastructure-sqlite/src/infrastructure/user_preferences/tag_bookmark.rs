@@ -26,10 +26,7 @@ impl TagBookmarkLocalSqliteRepository {
     /// ブックマークを作成
     pub async fn create(&self, bookmark: &TagBookmark) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let active_model = bookmark
             .to_sqlite_model()
@@ -50,10 +47,7 @@ impl TagBookmarkLocalSqliteRepository {
         id: &TagBookmarkId,
     ) -> Result<Option<TagBookmark>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = TagBookmarkEntity::find_by_id(id.to_string())
             .one(db)
@@ -78,10 +72,7 @@ impl TagBookmarkLocalSqliteRepository {
         tag_id: &TagId,
     ) -> Result<Option<TagBookmark>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = TagBookmarkEntity::find()
             .filter(Column::UserId.eq(user_id.to_string()))
@@ -108,10 +99,7 @@ impl TagBookmarkLocalSqliteRepository {
         project_id: &ProjectId,
     ) -> Result<Vec<TagBookmark>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TagBookmarkEntity::find()
             .filter(Column::UserId.eq(user_id.to_string()))
@@ -139,10 +127,7 @@ impl TagBookmarkLocalSqliteRepository {
         user_id: &UserId,
     ) -> Result<Vec<TagBookmark>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TagBookmarkEntity::find()
             .filter(Column::UserId.eq(user_id.to_string()))
@@ -170,10 +155,7 @@ impl TagBookmarkLocalSqliteRepository {
         tag_id: &TagId,
     ) -> Result<Vec<TagBookmark>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = TagBookmarkEntity::find()
             .filter(Column::ProjectId.eq(project_id.to_string()))
@@ -197,10 +179,7 @@ impl TagBookmarkLocalSqliteRepository {
     /// ブックマークを更新
     pub async fn update(&self, bookmark: &TagBookmark) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let existing = TagBookmarkEntity::find_by_id(bookmark.id.to_string())
             .one(db)
@@ -235,10 +214,7 @@ impl TagBookmarkLocalSqliteRepository {
     /// ブックマークを削除
     pub async fn delete(&self, id: &TagBookmarkId) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         TagBookmarkEntity::delete_by_id(id.to_string())
             .exec(db)
@@ -293,6 +269,8 @@ impl Default for TagBookmarkLocalSqliteRepository {
         // ダミーのDatabaseManagerを作成（実際には使用されないことを想定）
         // DatabaseManagerのnew_testメソッドがあればそれを使用、なければpanic
         // この実装は主にテスト用途を想定
-        panic!("TagBookmarkLocalSqliteRepository::default() should not be called directly. Use new() with a proper DatabaseManager instead.")
+        panic!(
+            "TagBookmarkLocalSqliteRepository::default() should not be called directly. Use new() with a proper DatabaseManager instead."
+        )
     }
 }
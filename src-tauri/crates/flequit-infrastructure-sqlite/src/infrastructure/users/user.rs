@@ -35,10 +35,7 @@ impl UserLocalSqliteRepository {
     /// メールアドレスでユーザーを検索
     pub async fn find_by_email(&self, email: &str) -> Result<Option<User>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = UserEntity::find()
             .filter(Column::Email.eq(email))
@@ -62,10 +59,7 @@ impl UserLocalSqliteRepository {
         handle_id: &str,
     ) -> Result<Option<User>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = UserEntity::find()
             .filter(Column::HandleId.eq(handle_id))
@@ -86,10 +80,7 @@ impl UserLocalSqliteRepository {
     /// 名前でユーザーを検索（部分一致）
     pub async fn find_by_name_partial(&self, name: &str) -> Result<Vec<User>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = UserEntity::find()
             .filter(Column::HandleId.contains(name))
@@ -116,10 +107,7 @@ impl UserLocalSqliteRepository {
         display_name: &str,
     ) -> Result<Vec<User>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = UserEntity::find()
             .filter(Column::DisplayName.contains(display_name))
@@ -143,10 +131,7 @@ impl UserLocalSqliteRepository {
     /// 最近作成されたユーザーを取得
     pub async fn find_recent_users(&self, limit: u64) -> Result<Vec<User>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = UserEntity::find()
             .order_by_desc(Column::CreatedAt)
@@ -179,10 +164,7 @@ impl Repository<User, UserId> for UserLocalSqliteRepository {
         _timestamp: &DateTime<Utc>,
     ) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         // 既存のユーザーをチェック（IDで）
         let existing = UserEntity::find_by_id(user.id.to_string())
@@ -228,10 +210,7 @@ impl Repository<User, UserId> for UserLocalSqliteRepository {
 
     async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         if let Some(model) = UserEntity::find_by_id(id.to_string())
             .one(db)
@@ -250,10 +229,7 @@ impl Repository<User, UserId> for UserLocalSqliteRepository {
 
     async fn delete(&self, id: &UserId) -> Result<(), RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         UserEntity::delete_by_id(id.to_string())
             .exec(db)
@@ -264,10 +240,7 @@ impl Repository<User, UserId> for UserLocalSqliteRepository {
 
     async fn find_all(&self) -> Result<Vec<User>, RepositoryError> {
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let models = UserEntity::find()
             .order_by_asc(Column::CreatedAt)
@@ -292,10 +265,7 @@ impl Repository<User, UserId> for UserLocalSqliteRepository {
         info!("{:?}", id);
 
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = UserEntity::find_by_id(id.to_string())
             .count(db)
@@ -309,10 +279,7 @@ impl Repository<User, UserId> for UserLocalSqliteRepository {
         info!("UserLocalSqliteRepository::count");
 
         let db_manager = self.db_manager.read().await;
-        let db = db_manager
-            .get_connection()
-            .await
-            .map_err(RepositoryError::from)?;
+        let db = &db_manager.executor().await.map_err(RepositoryError::from)?;
 
         let count = UserEntity::find()
             .count(db)
@@ -0,0 +1,52 @@
+//! アウトボックステーブルのマイグレーション
+//!
+//! セルフホストサーバーへ送信できなかった書き込みを、再送まで保持するテーブルを作成します。
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                CREATE TABLE IF NOT EXISTS outbox_operations (
+                    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+                    idempotency_key VARCHAR NOT NULL UNIQUE,
+                    entity VARCHAR NOT NULL,
+                    operation VARCHAR NOT NULL,
+                    project_id VARCHAR,
+                    payload TEXT NOT NULL,
+                    status VARCHAR NOT NULL,
+                    attempts INTEGER NOT NULL DEFAULT 0,
+                    next_attempt_at TIMESTAMP NOT NULL,
+                    last_error TEXT,
+                    created_at TIMESTAMP NOT NULL,
+                    updated_at TIMESTAMP NOT NULL
+                );
+                "#,
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE INDEX IF NOT EXISTS idx_outbox_operations_status_seq ON outbox_operations (status, seq);",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP TABLE IF EXISTS outbox_operations;")
+            .await?;
+        Ok(())
+    }
+}
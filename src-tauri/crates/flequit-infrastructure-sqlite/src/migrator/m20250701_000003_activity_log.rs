@@ -0,0 +1,51 @@
+//! アクティビティログテーブルのマイグレーション
+//!
+//! エンティティごとの変更履歴（誰が・いつ・何を変えたか）を記録するテーブルを作成します。
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                CREATE TABLE IF NOT EXISTS activity_log (
+                    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+                    project_id VARCHAR NOT NULL,
+                    entity VARCHAR NOT NULL,
+                    entity_id VARCHAR NOT NULL,
+                    related_id VARCHAR,
+                    action VARCHAR NOT NULL,
+                    changes TEXT NOT NULL,
+                    actor VARCHAR,
+                    source VARCHAR NOT NULL,
+                    occurred_at TIMESTAMP NOT NULL
+                );
+                "#,
+            )
+            .await?;
+
+        for sql in [
+            "CREATE INDEX IF NOT EXISTS idx_activity_log_project_seq ON activity_log (project_id, seq);",
+            "CREATE INDEX IF NOT EXISTS idx_activity_log_entity_id ON activity_log (entity_id);",
+            "CREATE INDEX IF NOT EXISTS idx_activity_log_related_id ON activity_log (related_id);",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP TABLE IF EXISTS activity_log;")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+//! タスクの実績日時カラムのマイグレーション
+//!
+//! サブタスクと同様に、タスクにも作業の開始・終了日時（do_start_date / do_end_date）を保存します。
+//! 既存のタスクの値はAutomergeにのみ残っているため、データ補完の予約も登録します。
+
+use sea_orm_migration::prelude::*;
+
+/// Automergeからタスクの実績日時を補完するデータ補完の名前
+pub const TASK_WORK_DATES_BACKFILL: &str = "task_work_dates";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            "ALTER TABLE tasks ADD COLUMN do_start_date TIMESTAMP;",
+            "ALTER TABLE tasks ADD COLUMN do_end_date TIMESTAMP;",
+            r#"
+            CREATE TABLE IF NOT EXISTS data_backfills (
+                name VARCHAR PRIMARY KEY,
+                created_at TIMESTAMP NOT NULL
+            );
+            "#,
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "INSERT OR IGNORE INTO data_backfills (name, created_at) VALUES ('{}', CURRENT_TIMESTAMP);",
+                TASK_WORK_DATES_BACKFILL
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            "DROP TABLE IF EXISTS data_backfills;",
+            "ALTER TABLE tasks DROP COLUMN do_end_date;",
+            "ALTER TABLE tasks DROP COLUMN do_start_date;",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+}
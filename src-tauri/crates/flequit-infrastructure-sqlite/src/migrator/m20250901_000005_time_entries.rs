@@ -0,0 +1,54 @@
+//! タイムエントリテーブルのマイグレーション
+//!
+//! タスク・サブタスクごとの作業時間の記録（誰が・いつからいつまで作業したか）を保存するテーブルを作成します。
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                CREATE TABLE IF NOT EXISTS time_entries (
+                    project_id VARCHAR NOT NULL,
+                    id VARCHAR NOT NULL,
+                    task_id VARCHAR NOT NULL,
+                    subtask_id VARCHAR,
+                    user_id VARCHAR NOT NULL,
+                    started_at TIMESTAMP NOT NULL,
+                    ended_at TIMESTAMP,
+                    note TEXT,
+                    created_at TIMESTAMP NOT NULL,
+                    updated_at TIMESTAMP NOT NULL,
+                    deleted BOOLEAN NOT NULL DEFAULT FALSE,
+                    updated_by VARCHAR NOT NULL,
+                    CONSTRAINT pk_time_entries PRIMARY KEY (project_id, id)
+                );
+                "#,
+            )
+            .await?;
+
+        for sql in [
+            "CREATE INDEX IF NOT EXISTS idx_time_entries_task_id ON time_entries (project_id, task_id);",
+            "CREATE INDEX IF NOT EXISTS idx_time_entries_user_started_at ON time_entries (user_id, started_at);",
+            "CREATE INDEX IF NOT EXISTS idx_time_entries_running ON time_entries (user_id) WHERE ended_at IS NULL;",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP TABLE IF EXISTS time_entries;")
+            .await?;
+        Ok(())
+    }
+}
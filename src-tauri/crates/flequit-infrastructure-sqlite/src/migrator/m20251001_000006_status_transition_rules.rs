@@ -0,0 +1,50 @@
+//! ステータス遷移ルールテーブルのマイグレーション
+//!
+//! プロジェクトごとのタスク・サブタスクのステータス遷移ルール（遷移ポリシー）を保存するテーブルを作成します。
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                CREATE TABLE IF NOT EXISTS status_transition_rules (
+                    project_id VARCHAR NOT NULL,
+                    id VARCHAR NOT NULL,
+                    from_status VARCHAR NOT NULL,
+                    to_status VARCHAR NOT NULL,
+                    requirement VARCHAR NOT NULL,
+                    created_at TIMESTAMP NOT NULL,
+                    updated_at TIMESTAMP NOT NULL,
+                    deleted BOOLEAN NOT NULL DEFAULT FALSE,
+                    updated_by VARCHAR NOT NULL,
+                    CONSTRAINT pk_status_transition_rules PRIMARY KEY (project_id, id)
+                );
+                "#,
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE INDEX IF NOT EXISTS idx_status_transition_rules_transition ON status_transition_rules (project_id, from_status, to_status);",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP TABLE IF EXISTS status_transition_rules;")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+//! ワークフローステータスのマイグレーション
+//!
+//! プロジェクトごとのワークフローステータスを保存するテーブルを作成し、
+//! タスクにワークフローステータスへの参照（workflow_status_id）を追加します。
+//! 既存のタスクは参照を持たない（組み込みのステータスのみを使う）状態で移行されます。
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            r#"
+            CREATE TABLE IF NOT EXISTS workflow_statuses (
+                project_id VARCHAR NOT NULL,
+                id VARCHAR NOT NULL,
+                name VARCHAR NOT NULL,
+                color VARCHAR,
+                order_index INTEGER NOT NULL DEFAULT 0,
+                category VARCHAR NOT NULL,
+                created_at TIMESTAMP NOT NULL,
+                updated_at TIMESTAMP NOT NULL,
+                deleted BOOLEAN NOT NULL DEFAULT FALSE,
+                updated_by VARCHAR NOT NULL,
+                CONSTRAINT pk_workflow_statuses PRIMARY KEY (project_id, id)
+            );
+            "#,
+            "CREATE INDEX IF NOT EXISTS idx_workflow_statuses_order ON workflow_statuses (project_id, order_index);",
+            "ALTER TABLE tasks ADD COLUMN workflow_status_id VARCHAR;",
+            "CREATE INDEX IF NOT EXISTS idx_tasks_workflow_status ON tasks (project_id, workflow_status_id);",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            "DROP INDEX IF EXISTS idx_tasks_workflow_status;",
+            "ALTER TABLE tasks DROP COLUMN workflow_status_id;",
+            "DROP TABLE IF EXISTS workflow_statuses;",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+//! タスク依存関係のマイグレーション
+//!
+//! タスク間の依存関係（task_id が depends_on_task_id の完了を待つ）を保存するテーブルを作成します。
+//! 依存先の検索（先行タスクから後続タスクを引く）用のインデックスも合わせて作成します。
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            r#"
+            CREATE TABLE IF NOT EXISTS task_dependencies (
+                project_id VARCHAR NOT NULL,
+                task_id VARCHAR NOT NULL,
+                depends_on_task_id VARCHAR NOT NULL,
+                created_at TIMESTAMP NOT NULL,
+                updated_at TIMESTAMP NOT NULL,
+                deleted BOOLEAN NOT NULL DEFAULT FALSE,
+                updated_by VARCHAR NOT NULL,
+                CONSTRAINT pk_task_dependencies PRIMARY KEY (project_id, task_id, depends_on_task_id),
+                FOREIGN KEY (project_id, task_id) REFERENCES tasks (project_id, id) ON DELETE CASCADE,
+                FOREIGN KEY (project_id, depends_on_task_id) REFERENCES tasks (project_id, id) ON DELETE CASCADE
+            );
+            "#,
+            "CREATE INDEX IF NOT EXISTS idx_task_dependencies_depends_on ON task_dependencies (project_id, depends_on_task_id);",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            "DROP INDEX IF EXISTS idx_task_dependencies_depends_on;",
+            "DROP TABLE IF EXISTS task_dependencies;",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+//! リマインダーテーブルのマイグレーション
+//!
+//! タスク・サブタスクごとの通知設定（いつ・何を基準に・何回通知するか）を保存するテーブルを作成します。
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            r#"
+            CREATE TABLE IF NOT EXISTS reminders (
+                project_id VARCHAR NOT NULL,
+                id VARCHAR NOT NULL,
+                task_id VARCHAR NOT NULL,
+                subtask_id VARCHAR,
+                anchor VARCHAR NOT NULL,
+                remind_at TIMESTAMP,
+                offset_minutes BIGINT NOT NULL DEFAULT 0,
+                repeat_interval_minutes BIGINT,
+                repeat_count INTEGER,
+                last_fired_at TIMESTAMP,
+                created_at TIMESTAMP NOT NULL,
+                updated_at TIMESTAMP NOT NULL,
+                deleted BOOLEAN NOT NULL DEFAULT FALSE,
+                updated_by VARCHAR NOT NULL,
+                CONSTRAINT pk_reminders PRIMARY KEY (project_id, id)
+            );
+            "#,
+            "CREATE INDEX IF NOT EXISTS idx_reminders_task_id ON reminders (project_id, task_id);",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            "DROP INDEX IF EXISTS idx_reminders_task_id;",
+            "DROP TABLE IF EXISTS reminders;",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+//! スヌーズのマイグレーション
+//!
+//! タスク・サブタスク・リマインダーのスヌーズの記録（スヌーズ前の予定日時と先送り先の日時）を
+//! 保存するテーブルを作成し、リマインダーにスヌーズ先の日時（snoozed_until）を追加します。
+//! 既存のリマインダーはスヌーズしていない状態で移行されます。
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            r#"
+            CREATE TABLE IF NOT EXISTS snoozes (
+                project_id VARCHAR NOT NULL,
+                id VARCHAR NOT NULL,
+                task_id VARCHAR NOT NULL,
+                subtask_id VARCHAR,
+                reminder_id VARCHAR,
+                original_plan_start_date TIMESTAMP,
+                original_plan_end_date TIMESTAMP,
+                original_remind_at TIMESTAMP,
+                snoozed_until TIMESTAMP NOT NULL,
+                created_at TIMESTAMP NOT NULL,
+                updated_at TIMESTAMP NOT NULL,
+                deleted BOOLEAN NOT NULL DEFAULT FALSE,
+                updated_by VARCHAR NOT NULL,
+                CONSTRAINT pk_snoozes PRIMARY KEY (project_id, id)
+            );
+            "#,
+            "CREATE INDEX IF NOT EXISTS idx_snoozes_task_id ON snoozes (project_id, task_id);",
+            "CREATE INDEX IF NOT EXISTS idx_snoozes_snoozed_until ON snoozes (project_id, snoozed_until);",
+            "ALTER TABLE reminders ADD COLUMN snoozed_until TIMESTAMP;",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            "ALTER TABLE reminders DROP COLUMN snoozed_until;",
+            "DROP INDEX IF EXISTS idx_snoozes_snoozed_until;",
+            "DROP INDEX IF EXISTS idx_snoozes_task_id;",
+            "DROP TABLE IF EXISTS snoozes;",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+//! コメントのマイグレーション
+//!
+//! タスク・サブタスクへのコメント（投稿者・本文・言及したユーザー・編集日時）を
+//! 保存するテーブルを作成します。言及したユーザーのIDはJSON配列として保存します。
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            r#"
+            CREATE TABLE IF NOT EXISTS comments (
+                project_id VARCHAR NOT NULL,
+                id VARCHAR NOT NULL,
+                task_id VARCHAR NOT NULL,
+                subtask_id VARCHAR,
+                author_id VARCHAR NOT NULL,
+                body TEXT NOT NULL,
+                mentioned_user_ids TEXT NOT NULL DEFAULT '[]',
+                created_at TIMESTAMP NOT NULL,
+                edited_at TIMESTAMP,
+                updated_at TIMESTAMP NOT NULL,
+                deleted BOOLEAN NOT NULL DEFAULT FALSE,
+                updated_by VARCHAR NOT NULL,
+                CONSTRAINT pk_comments PRIMARY KEY (project_id, id)
+            );
+            "#,
+            "CREATE INDEX IF NOT EXISTS idx_comments_task_id ON comments (project_id, task_id, created_at);",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for sql in [
+            "DROP INDEX IF EXISTS idx_comments_task_id;",
+            "DROP TABLE IF EXISTS comments;",
+        ] {
+            manager.get_connection().execute_unprepared(sql).await?;
+        }
+        Ok(())
+    }
+}
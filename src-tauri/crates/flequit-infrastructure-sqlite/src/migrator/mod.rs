@@ -5,12 +5,36 @@
 use sea_orm_migration::prelude::*;
 
 mod m20250101_000001_initial_schema;
+mod m20250601_000002_outbox_operations;
+mod m20250701_000003_activity_log;
+mod m20250801_000004_task_work_dates;
+mod m20250901_000005_time_entries;
+mod m20251001_000006_status_transition_rules;
+mod m20251101_000007_workflow_statuses;
+mod m20251201_000008_task_dependencies;
+mod m20260101_000009_reminders;
+mod m20260201_000010_snoozes;
+mod m20260301_000011_comments;
+
+pub use m20250801_000004_task_work_dates::TASK_WORK_DATES_BACKFILL;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20250101_000001_initial_schema::Migration)]
+        vec![
+            Box::new(m20250101_000001_initial_schema::Migration),
+            Box::new(m20250601_000002_outbox_operations::Migration),
+            Box::new(m20250701_000003_activity_log::Migration),
+            Box::new(m20250801_000004_task_work_dates::Migration),
+            Box::new(m20250901_000005_time_entries::Migration),
+            Box::new(m20251001_000006_status_transition_rules::Migration),
+            Box::new(m20251101_000007_workflow_statuses::Migration),
+            Box::new(m20251201_000008_task_dependencies::Migration),
+            Box::new(m20260101_000009_reminders::Migration),
+            Box::new(m20260201_000010_snoozes::Migration),
+            Box::new(m20260301_000011_comments::Migration),
+        ]
     }
 }
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::activity::activity_entry::ActivityEntry;
+use flequit_model::types::id_types::{ProjectId, UserId};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::super::SqliteModelConverter;
+
+/// ActivityEntry用SQLiteエンティティ定義
+///
+/// エンティティの変更を記録順（`seq`）に保持する追記専用のテーブル。
+/// Automergeの変更履歴から再構築した場合のみ、プロジェクト単位で置き換えられる。
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "activity_log")]
+pub struct Model {
+    /// 記録順の連番
+    #[sea_orm(primary_key)]
+    pub seq: i64,
+
+    #[sea_orm(indexed)]
+    pub project_id: String,
+
+    /// 対象エンティティの種類
+    pub entity: String,
+
+    #[sea_orm(indexed)]
+    pub entity_id: String,
+
+    /// 関連先・親エンティティのID
+    #[sea_orm(indexed)]
+    pub related_id: Option<String>,
+
+    /// 変更の種類（created / updated / deleted / restored）
+    pub action: String,
+
+    /// フィールド単位の変更内容（JSON配列）
+    pub changes: String,
+
+    /// 変更を行ったユーザーID
+    pub actor: Option<String>,
+
+    /// 記録元（local / sync / history）
+    pub source: String,
+
+    pub occurred_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// SQLiteモデルからドメインモデルへの変換
+#[async_trait]
+impl SqliteModelConverter<ActivityEntry> for Model {
+    async fn to_domain_model(&self) -> Result<ActivityEntry, String> {
+        Ok(ActivityEntry {
+            seq: self.seq,
+            project_id: ProjectId::from(self.project_id.clone()),
+            entity: self.entity.clone(),
+            entity_id: self.entity_id.clone(),
+            related_id: self.related_id.clone(),
+            action: self.action.parse()?,
+            changes: serde_json::from_str(&self.changes)
+                .map_err(|e| format!("Invalid activity changes: {e}"))?,
+            actor: self.actor.clone().map(UserId::from),
+            source: self.source.parse()?,
+            occurred_at: self.occurred_at,
+        })
+    }
+}
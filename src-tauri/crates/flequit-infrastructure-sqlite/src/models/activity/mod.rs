@@ -0,0 +1,5 @@
+//! アクティビティログSQLiteモデル
+//!
+//! このモジュールは、エンティティの変更履歴をSQLiteで管理するためのモデルを定義します。
+
+pub mod activity_entry;
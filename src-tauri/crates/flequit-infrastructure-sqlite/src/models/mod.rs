@@ -1,8 +1,10 @@
 use async_trait::async_trait;
 
 pub mod accounts;
+pub mod activity;
 pub mod initialized_data;
 pub mod search;
+pub mod sync;
 pub mod task_projects;
 pub mod user_preferences;
 pub mod users;
@@ -11,10 +13,11 @@ pub mod users;
 pub use accounts::account;
 use flequit_model::types::id_types::ProjectId;
 pub use task_projects::{
-    date_condition, member, project, recurrence_adjustment, recurrence_date_condition,
+    comment, date_condition, member, project, recurrence_adjustment, recurrence_date_condition,
     recurrence_days_of_week, recurrence_detail, recurrence_rule, recurrence_weekday_condition,
-    subtask, subtask_assignments, subtask_recurrence, subtask_tag, tag, task, task_assignments,
-    task_list, task_recurrence, task_tag, weekday_condition,
+    reminder, snooze, status_transition_rule, subtask, subtask_assignments, subtask_recurrence,
+    subtask_tag, tag, task, task_assignments, task_dependency, task_list, task_recurrence,
+    task_tag, time_entry, weekday_condition, workflow_status,
 };
 pub use users::user;
 
@@ -0,0 +1,5 @@
+//! リモート同期SQLiteモデル
+//!
+//! このモジュールは、セルフホストサーバーとの同期状態をSQLiteで管理するためのモデルを定義します。
+
+pub mod outbox_operation;
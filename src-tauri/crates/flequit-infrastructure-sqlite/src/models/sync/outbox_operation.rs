@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::sync::outbox_operation::OutboxOperation;
+use flequit_model::types::id_types::ProjectId;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::super::SqliteModelConverter;
+
+/// OutboxOperation用SQLiteエンティティ定義
+///
+/// 未送信のリモート書き込みを記録順（`seq`）に保持する。
+/// 送信に成功した操作は行ごと削除される。
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "outbox_operations")]
+pub struct Model {
+    /// 記録順の連番
+    #[sea_orm(primary_key)]
+    pub seq: i64,
+
+    /// 再送時の冪等キー
+    #[sea_orm(unique)]
+    pub idempotency_key: String,
+
+    /// 対象エンティティのコレクション名
+    pub entity: String,
+
+    /// 操作の種類（put / delete）
+    pub operation: String,
+
+    /// プロジェクト配下のエンティティの場合のプロジェクトID
+    pub project_id: Option<String>,
+
+    /// 送信内容（JSON）
+    pub payload: String,
+
+    /// 状態（pending / conflict / dead_letter）
+    #[sea_orm(indexed)]
+    pub status: String,
+
+    /// 再送に失敗した回数
+    pub attempts: i32,
+
+    /// 次に再送を試みる日時
+    pub next_attempt_at: DateTime<Utc>,
+
+    /// 最後に失敗した際のエラー
+    pub last_error: Option<String>,
+
+    pub created_at: DateTime<Utc>,
+
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// SQLiteモデルからドメインモデルへの変換
+#[async_trait]
+impl SqliteModelConverter<OutboxOperation> for Model {
+    async fn to_domain_model(&self) -> Result<OutboxOperation, String> {
+        Ok(OutboxOperation {
+            seq: self.seq,
+            idempotency_key: self.idempotency_key.clone(),
+            entity: self.entity.clone(),
+            operation: self.operation.parse()?,
+            project_id: self.project_id.clone().map(ProjectId::from),
+            payload: self.payload.clone(),
+            status: self.status.parse()?,
+            attempts: self.attempts.max(0) as u32,
+            next_attempt_at: self.next_attempt_at,
+            last_error: self.last_error.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+}
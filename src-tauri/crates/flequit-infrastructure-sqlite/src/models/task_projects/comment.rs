@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::{
+    models::task_projects::comment::Comment,
+    types::id_types::{CommentId, ProjectId, SubTaskId, TaskId, UserId},
+};
+use sea_orm::{Set, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{DomainToSqliteConverter, DomainToSqliteConverterWithProjectId};
+
+use super::SqliteModelConverter;
+
+/// Comment用SQLiteエンティティ定義
+///
+/// タスク別のスレッド表示（投稿順）に最適化
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "comments")]
+pub struct Model {
+    /// プロジェクトID（SQLite統合テーブル用）
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub project_id: String,
+
+    /// コメントの一意識別子
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+
+    /// コメント先のタスクID
+    #[sea_orm(indexed)] // タスク別一覧用
+    pub task_id: String,
+
+    /// コメント先のサブタスクID
+    pub subtask_id: Option<String>,
+
+    /// 投稿者のユーザーID
+    pub author_id: String,
+
+    /// 本文
+    pub body: String,
+
+    /// 本文中で言及したユーザーのID（JSON配列）
+    pub mentioned_user_ids: String,
+
+    /// 投稿日時
+    #[sea_orm(indexed)] // スレッドの並び順用
+    pub created_at: DateTime<Utc>,
+
+    /// 最後に本文を編集した日時
+    pub edited_at: Option<DateTime<Utc>>,
+
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+
+    /// 論理削除フラグ
+    #[sea_orm(indexed)]
+    pub deleted: bool,
+
+    /// 最終更新者のユーザーID
+    pub updated_by: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// SQLiteモデルからドメインモデルへの変換
+#[async_trait]
+impl SqliteModelConverter<Comment> for Model {
+    async fn to_domain_model(&self) -> Result<Comment, String> {
+        let mentioned_user_ids: Vec<String> = serde_json::from_str(&self.mentioned_user_ids)
+            .map_err(|e| format!("Invalid mentioned user ids: {e}"))?;
+        Ok(Comment {
+            id: CommentId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            task_id: TaskId::from(self.task_id.clone()),
+            subtask_id: self.subtask_id.clone().map(SubTaskId::from),
+            author_id: UserId::from(self.author_id.clone()),
+            body: self.body.clone(),
+            mentioned_user_ids: mentioned_user_ids.into_iter().map(UserId::from).collect(),
+            created_at: self.created_at,
+            edited_at: self.edited_at,
+            updated_at: self.updated_at,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+/// ドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverter<ActiveModel> for Comment {
+    async fn to_sqlite_model(&self) -> Result<ActiveModel, String> {
+        self.to_sqlite_model_with_project_id(&self.project_id).await
+    }
+}
+
+/// プロジェクトID付きのドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverterWithProjectId<ActiveModel> for Comment {
+    async fn to_sqlite_model_with_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<ActiveModel, String> {
+        let mentioned_user_ids: Vec<String> = self
+            .mentioned_user_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+        Ok(ActiveModel {
+            project_id: Set(project_id.to_string()),
+            id: Set(self.id.to_string()),
+            task_id: Set(self.task_id.to_string()),
+            subtask_id: Set(self.subtask_id.map(|id| id.to_string())),
+            author_id: Set(self.author_id.to_string()),
+            body: Set(self.body.clone()),
+            mentioned_user_ids: Set(serde_json::to_string(&mentioned_user_ids)
+                .map_err(|e| format!("Failed to serialize mentioned user ids: {e}"))?),
+            created_at: Set(self.created_at),
+            edited_at: Set(self.edited_at),
+            updated_at: Set(self.updated_at),
+            deleted: Set(self.deleted),
+            updated_by: Set(self.updated_by.to_string()),
+        })
+    }
+}
@@ -1,5 +1,6 @@
 pub use super::{DomainToSqliteConverter, SqliteModelConverter};
 
+pub mod comment;
 pub mod date_condition;
 pub mod member;
 pub mod project;
@@ -9,6 +10,9 @@ pub mod recurrence_days_of_week;
 pub mod recurrence_detail;
 pub mod recurrence_rule;
 pub mod recurrence_weekday_condition;
+pub mod reminder;
+pub mod snooze;
+pub mod status_transition_rule;
 pub mod subtask;
 pub mod subtask_assignments;
 pub mod subtask_recurrence;
@@ -16,7 +20,10 @@ pub mod subtask_tag;
 pub mod tag;
 pub mod task;
 pub mod task_assignments;
+pub mod task_dependency;
 pub mod task_list;
 pub mod task_recurrence;
 pub mod task_tag;
+pub mod time_entry;
 pub mod weekday_condition;
+pub mod workflow_status;
@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::{
+    models::task_projects::reminder::Reminder,
+    types::id_types::{ProjectId, ReminderId, SubTaskId, TaskId, UserId},
+    types::task_types::ReminderAnchor,
+};
+use sea_orm::{Set, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{DomainToSqliteConverter, DomainToSqliteConverterWithProjectId};
+
+use super::SqliteModelConverter;
+
+/// Reminder用SQLiteエンティティ定義
+///
+/// タスク・サブタスクごとのリマインダー一覧の取得に最適化
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "reminders")]
+pub struct Model {
+    /// プロジェクトID（SQLite統合テーブル用）
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub project_id: String,
+
+    /// リマインダーの一意識別子
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+
+    /// 通知対象のタスクID
+    #[sea_orm(indexed)] // タスク別一覧用
+    pub task_id: String,
+
+    /// 通知対象のサブタスクID
+    pub subtask_id: Option<String>,
+
+    /// 通知日時の基準（absolute / plan_start / plan_end）
+    pub anchor: String,
+
+    /// 通知日時（固定日時指定の場合のみ）
+    pub remind_at: Option<DateTime<Utc>>,
+
+    /// 基準日時からのずれ（分）
+    pub offset_minutes: i64,
+
+    /// 繰り返し間隔（分）
+    pub repeat_interval_minutes: Option<i64>,
+
+    /// 最初の通知の後に繰り返す回数
+    pub repeat_count: Option<i32>,
+
+    /// 最後に通知した予定日時
+    pub last_fired_at: Option<DateTime<Utc>>,
+
+    /// スヌーズ先の日時
+    pub snoozed_until: Option<DateTime<Utc>>,
+
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+
+    /// 論理削除フラグ
+    #[sea_orm(indexed)]
+    pub deleted: bool,
+
+    /// 最終更新者のユーザーID
+    pub updated_by: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// SQLiteモデルからドメインモデルへの変換
+#[async_trait]
+impl SqliteModelConverter<Reminder> for Model {
+    async fn to_domain_model(&self) -> Result<Reminder, String> {
+        let anchor = match self.anchor.as_str() {
+            "absolute" => ReminderAnchor::Absolute,
+            "plan_start" => ReminderAnchor::PlanStart,
+            "plan_end" => ReminderAnchor::PlanEnd,
+            _ => return Err(format!("Unknown reminder anchor: {}", self.anchor)),
+        };
+
+        Ok(Reminder {
+            id: ReminderId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            task_id: TaskId::from(self.task_id.clone()),
+            subtask_id: self.subtask_id.clone().map(SubTaskId::from),
+            anchor,
+            remind_at: self.remind_at,
+            offset_minutes: self.offset_minutes,
+            repeat_interval_minutes: self.repeat_interval_minutes,
+            repeat_count: self.repeat_count,
+            last_fired_at: self.last_fired_at,
+            snoozed_until: self.snoozed_until,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+/// ドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverter<ActiveModel> for Reminder {
+    async fn to_sqlite_model(&self) -> Result<ActiveModel, String> {
+        self.to_sqlite_model_with_project_id(&self.project_id).await
+    }
+}
+
+/// プロジェクトID付きのドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverterWithProjectId<ActiveModel> for Reminder {
+    async fn to_sqlite_model_with_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<ActiveModel, String> {
+        Ok(ActiveModel {
+            project_id: Set(project_id.to_string()),
+            id: Set(self.id.to_string()),
+            task_id: Set(self.task_id.to_string()),
+            subtask_id: Set(self.subtask_id.map(|id| id.to_string())),
+            anchor: Set(self.anchor.as_str().to_string()),
+            remind_at: Set(self.remind_at),
+            offset_minutes: Set(self.offset_minutes),
+            repeat_interval_minutes: Set(self.repeat_interval_minutes),
+            repeat_count: Set(self.repeat_count),
+            last_fired_at: Set(self.last_fired_at),
+            snoozed_until: Set(self.snoozed_until),
+            created_at: Set(self.created_at),
+            updated_at: Set(self.updated_at),
+            deleted: Set(self.deleted),
+            updated_by: Set(self.updated_by.to_string()),
+        })
+    }
+}
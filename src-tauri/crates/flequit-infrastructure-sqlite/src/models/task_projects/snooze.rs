@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::{
+    models::task_projects::snooze::Snooze,
+    types::id_types::{ProjectId, ReminderId, SnoozeId, SubTaskId, TaskId, UserId},
+};
+use sea_orm::{Set, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{DomainToSqliteConverter, DomainToSqliteConverterWithProjectId};
+
+use super::SqliteModelConverter;
+
+/// Snooze用SQLiteエンティティ定義
+///
+/// タスク別のスヌーズ履歴の取得と、先送り中のタスクの判定に最適化
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "snoozes")]
+pub struct Model {
+    /// プロジェクトID（SQLite統合テーブル用）
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub project_id: String,
+
+    /// スヌーズの一意識別子
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+
+    /// 対象のタスクID
+    #[sea_orm(indexed)] // タスク別一覧用
+    pub task_id: String,
+
+    /// 対象のサブタスクID
+    pub subtask_id: Option<String>,
+
+    /// 対象のリマインダーID
+    pub reminder_id: Option<String>,
+
+    /// スヌーズ前の予定開始日時
+    pub original_plan_start_date: Option<DateTime<Utc>>,
+
+    /// スヌーズ前の予定終了日時
+    pub original_plan_end_date: Option<DateTime<Utc>>,
+
+    /// スヌーズ前の通知予定日時
+    pub original_remind_at: Option<DateTime<Utc>>,
+
+    /// 先送り先の日時
+    #[sea_orm(indexed)] // 先送り中の判定用
+    pub snoozed_until: DateTime<Utc>,
+
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+
+    /// 論理削除フラグ
+    #[sea_orm(indexed)]
+    pub deleted: bool,
+
+    /// 最終更新者のユーザーID
+    pub updated_by: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// SQLiteモデルからドメインモデルへの変換
+#[async_trait]
+impl SqliteModelConverter<Snooze> for Model {
+    async fn to_domain_model(&self) -> Result<Snooze, String> {
+        Ok(Snooze {
+            id: SnoozeId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            task_id: TaskId::from(self.task_id.clone()),
+            subtask_id: self.subtask_id.clone().map(SubTaskId::from),
+            reminder_id: self.reminder_id.clone().map(ReminderId::from),
+            original_plan_start_date: self.original_plan_start_date,
+            original_plan_end_date: self.original_plan_end_date,
+            original_remind_at: self.original_remind_at,
+            snoozed_until: self.snoozed_until,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+/// ドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverter<ActiveModel> for Snooze {
+    async fn to_sqlite_model(&self) -> Result<ActiveModel, String> {
+        self.to_sqlite_model_with_project_id(&self.project_id).await
+    }
+}
+
+/// プロジェクトID付きのドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverterWithProjectId<ActiveModel> for Snooze {
+    async fn to_sqlite_model_with_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<ActiveModel, String> {
+        Ok(ActiveModel {
+            project_id: Set(project_id.to_string()),
+            id: Set(self.id.to_string()),
+            task_id: Set(self.task_id.to_string()),
+            subtask_id: Set(self.subtask_id.map(|id| id.to_string())),
+            reminder_id: Set(self.reminder_id.map(|id| id.to_string())),
+            original_plan_start_date: Set(self.original_plan_start_date),
+            original_plan_end_date: Set(self.original_plan_end_date),
+            original_remind_at: Set(self.original_remind_at),
+            snoozed_until: Set(self.snoozed_until),
+            created_at: Set(self.created_at),
+            updated_at: Set(self.updated_at),
+            deleted: Set(self.deleted),
+            updated_by: Set(self.updated_by.to_string()),
+        })
+    }
+}
@@ -0,0 +1,131 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::{
+    models::task_projects::status_transition_rule::StatusTransitionRule,
+    types::id_types::{ProjectId, StatusTransitionRuleId, UserId},
+    types::task_types::{StatusTransitionRequirement, TaskStatus},
+};
+use sea_orm::{Set, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{DomainToSqliteConverter, DomainToSqliteConverterWithProjectId};
+
+use super::SqliteModelConverter;
+
+/// StatusTransitionRule用SQLiteエンティティ定義
+///
+/// プロジェクト内の遷移元・遷移先ステータスでの検索に最適化
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "status_transition_rules")]
+pub struct Model {
+    /// プロジェクトID（SQLite統合テーブル用）
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub project_id: String,
+
+    /// ルールの一意識別子
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+
+    /// 遷移元のステータス
+    #[sea_orm(indexed)]
+    pub from_status: String,
+
+    /// 遷移先のステータス
+    #[sea_orm(indexed)]
+    pub to_status: String,
+
+    /// 遷移に課す条件（forbidden / reason_required）
+    pub requirement: String,
+
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+
+    /// 論理削除フラグ
+    #[sea_orm(indexed)]
+    pub deleted: bool,
+
+    /// 最終更新者のユーザーID
+    pub updated_by: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+fn parse_status(value: &str) -> Result<TaskStatus, String> {
+    match value {
+        "not_started" => Ok(TaskStatus::NotStarted),
+        "in_progress" => Ok(TaskStatus::InProgress),
+        "waiting" => Ok(TaskStatus::Waiting),
+        "completed" => Ok(TaskStatus::Completed),
+        "cancelled" => Ok(TaskStatus::Cancelled),
+        _ => Err(format!("Unknown task status: {}", value)),
+    }
+}
+
+/// SQLiteモデルからドメインモデルへの変換
+#[async_trait]
+impl SqliteModelConverter<StatusTransitionRule> for Model {
+    async fn to_domain_model(&self) -> Result<StatusTransitionRule, String> {
+        let requirement = match self.requirement.as_str() {
+            "forbidden" => StatusTransitionRequirement::Forbidden,
+            "reason_required" => StatusTransitionRequirement::ReasonRequired,
+            _ => {
+                return Err(format!(
+                    "Unknown status transition requirement: {}",
+                    self.requirement
+                ));
+            }
+        };
+
+        Ok(StatusTransitionRule {
+            id: StatusTransitionRuleId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            from_status: parse_status(&self.from_status)?,
+            to_status: parse_status(&self.to_status)?,
+            requirement,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+/// ドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverter<ActiveModel> for StatusTransitionRule {
+    async fn to_sqlite_model(&self) -> Result<ActiveModel, String> {
+        self.to_sqlite_model_with_project_id(&self.project_id).await
+    }
+}
+
+/// プロジェクトID付きのドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverterWithProjectId<ActiveModel> for StatusTransitionRule {
+    async fn to_sqlite_model_with_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<ActiveModel, String> {
+        let requirement = match self.requirement {
+            StatusTransitionRequirement::Forbidden => "forbidden",
+            StatusTransitionRequirement::ReasonRequired => "reason_required",
+        };
+
+        Ok(ActiveModel {
+            project_id: Set(project_id.to_string()),
+            id: Set(self.id.to_string()),
+            from_status: Set(self.from_status.as_str().to_string()),
+            to_status: Set(self.to_status.as_str().to_string()),
+            requirement: Set(requirement.to_string()),
+            created_at: Set(self.created_at),
+            updated_at: Set(self.updated_at),
+            deleted: Set(self.deleted),
+            updated_by: Set(self.updated_by.to_string()),
+        })
+    }
+}
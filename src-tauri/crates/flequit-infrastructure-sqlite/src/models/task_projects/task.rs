@@ -3,11 +3,11 @@ use chrono::{DateTime, Utc};
 use flequit_model::{
     models::task_projects::task::Task,
     types::{
-        id_types::{ProjectId, TaskId, TaskListId, UserId},
+        id_types::{ProjectId, TaskId, TaskListId, UserId, WorkflowStatusId},
         task_types::TaskStatus,
     },
 };
-use sea_orm::{entity::prelude::*, Set};
+use sea_orm::{Set, entity::prelude::*};
 use serde::{Deserialize, Serialize};
 
 use crate::models::DomainToSqliteConverterWithProjectId;
@@ -44,6 +44,10 @@ pub struct Model {
     #[sea_orm(indexed)] // ステータス別検索用
     pub status: String,
 
+    /// 参照するワークフローステータスID
+    #[sea_orm(indexed)] // ワークフローステータス別検索用
+    pub workflow_status_id: Option<String>,
+
     /// 優先度（数値）
     #[sea_orm(indexed)] // 優先度別検索用
     pub priority: i32,
@@ -56,6 +60,12 @@ pub struct Model {
     #[sea_orm(indexed)] // 日時範囲検索用
     pub end_date: Option<DateTime<Utc>>,
 
+    /// 実際の作業開始日時
+    pub do_start_date: Option<DateTime<Utc>>,
+
+    /// 実際の作業終了日時
+    pub do_end_date: Option<DateTime<Utc>>,
+
     /// 期間指定フラグ
     pub is_range_date: Option<bool>,
 
@@ -150,11 +160,12 @@ impl SqliteModelConverter<Task> for Model {
             title: self.title.clone(),
             description: self.description.clone(),
             status,
+            workflow_status_id: self.workflow_status_id.clone().map(WorkflowStatusId::from),
             priority: self.priority,
             plan_start_date: self.start_date,
             plan_end_date: self.end_date,
-            do_start_date: None,
-            do_end_date: None,
+            do_start_date: self.do_start_date,
+            do_end_date: self.do_end_date,
             is_range_date: self.is_range_date,
             recurrence_rule,
             assigned_user_ids,
@@ -195,9 +206,12 @@ impl DomainToSqliteConverter<ActiveModel> for Task {
             title: Set(self.title.clone()),
             description: Set(self.description.clone()),
             status: Set(status_string),
+            workflow_status_id: Set(self.workflow_status_id.map(|id| id.to_string())),
             priority: Set(self.priority),
             start_date: Set(self.plan_start_date),
             end_date: Set(self.plan_end_date),
+            do_start_date: Set(self.do_start_date),
+            do_end_date: Set(self.do_end_date),
             is_range_date: Set(self.is_range_date),
             order_index: Set(self.order_index),
             is_archived: Set(self.is_archived),
@@ -238,9 +252,12 @@ impl DomainToSqliteConverterWithProjectId<ActiveModel> for Task {
             title: Set(self.title.clone()),
             description: Set(self.description.clone()),
             status: Set(status_string),
+            workflow_status_id: Set(self.workflow_status_id.map(|id| id.to_string())),
             priority: Set(self.priority),
             start_date: Set(self.plan_start_date),
             end_date: Set(self.plan_end_date),
+            do_start_date: Set(self.do_start_date),
+            do_end_date: Set(self.do_end_date),
             is_range_date: Set(self.is_range_date),
             order_index: Set(self.order_index),
             is_archived: Set(self.is_archived),
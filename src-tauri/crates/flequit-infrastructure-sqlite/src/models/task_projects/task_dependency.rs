@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::task_dependency::TaskDependency;
+use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::models::DomainToSqliteConverterWithProjectId;
+
+use super::SqliteModelConverter;
+
+/// TaskDependency用SQLiteエンティティ定義
+///
+/// タスク間の依存関係を管理する紐づけテーブル
+/// 後続タスク・先行タスクのどちらからも検索できるようにインデックスを持つ
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "task_dependencies")]
+pub struct Model {
+    /// プロジェクトID（SQLite統合テーブル用）
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub project_id: String,
+
+    /// ブロックされる側（後続）のタスクID
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub task_id: String,
+
+    /// ブロックする側（先行）のタスクID
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub depends_on_task_id: String,
+
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+
+    /// 最終更新日時
+    pub updated_at: DateTime<Utc>,
+
+    /// 論理削除フラグ
+    #[sea_orm(indexed)]
+    pub deleted: bool,
+
+    /// 最終更新者のユーザーID
+    pub updated_by: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::task::Entity",
+        from = "(Column::ProjectId, Column::TaskId)",
+        to = "(super::task::Column::ProjectId, super::task::Column::Id)"
+    )]
+    Task,
+    #[sea_orm(
+        belongs_to = "super::task::Entity",
+        from = "(Column::ProjectId, Column::DependsOnTaskId)",
+        to = "(super::task::Column::ProjectId, super::task::Column::Id)"
+    )]
+    DependsOnTask,
+}
+
+impl Related<super::task::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Task.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[async_trait]
+impl SqliteModelConverter<TaskDependency> for Model {
+    async fn to_domain_model(&self) -> Result<TaskDependency, String> {
+        Ok(TaskDependency {
+            task_id: TaskId::from(self.task_id.clone()),
+            depends_on_task_id: TaskId::from(self.depends_on_task_id.clone()),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+#[async_trait]
+impl DomainToSqliteConverterWithProjectId<ActiveModel> for TaskDependency {
+    async fn to_sqlite_model_with_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<ActiveModel, String> {
+        use sea_orm::ActiveValue::Set;
+        Ok(ActiveModel {
+            project_id: Set(project_id.to_string()),
+            task_id: Set(self.task_id.to_string()),
+            depends_on_task_id: Set(self.depends_on_task_id.to_string()),
+            created_at: Set(self.created_at),
+            updated_at: Set(self.updated_at),
+            deleted: Set(self.deleted),
+            updated_by: Set(self.updated_by.to_string()),
+        })
+    }
+}
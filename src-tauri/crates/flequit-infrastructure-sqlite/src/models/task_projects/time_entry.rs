@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::{
+    models::task_projects::time_entry::TimeEntry,
+    types::id_types::{ProjectId, SubTaskId, TaskId, TimeEntryId, UserId},
+};
+use sea_orm::{Set, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{DomainToSqliteConverter, DomainToSqliteConverterWithProjectId};
+
+use super::SqliteModelConverter;
+
+/// TimeEntry用SQLiteエンティティ定義
+///
+/// タスク・ユーザー・期間ごとの作業時間集計に最適化
+/// 計測中タイマー（ended_atがNULL）の検索に対応
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "time_entries")]
+pub struct Model {
+    /// プロジェクトID（SQLite統合テーブル用）
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub project_id: String,
+
+    /// タイムエントリの一意識別子
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+
+    /// 作業対象のタスクID
+    #[sea_orm(indexed)] // タスク別集計用
+    pub task_id: String,
+
+    /// 作業対象のサブタスクID
+    pub subtask_id: Option<String>,
+
+    /// 作業したユーザーのID
+    #[sea_orm(indexed)] // ユーザー別集計・計測中タイマー検索用
+    pub user_id: String,
+
+    /// 作業開始日時
+    #[sea_orm(indexed)] // 期間検索用
+    pub started_at: DateTime<Utc>,
+
+    /// 作業終了日時（計測中はNULL）
+    pub ended_at: Option<DateTime<Utc>>,
+
+    /// 作業内容のメモ
+    pub note: Option<String>,
+
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+
+    /// 論理削除フラグ
+    #[sea_orm(indexed)]
+    pub deleted: bool,
+
+    /// 最終更新者のユーザーID
+    pub updated_by: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// SQLiteモデルからドメインモデルへの変換
+#[async_trait]
+impl SqliteModelConverter<TimeEntry> for Model {
+    async fn to_domain_model(&self) -> Result<TimeEntry, String> {
+        Ok(TimeEntry {
+            id: TimeEntryId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            task_id: TaskId::from(self.task_id.clone()),
+            subtask_id: self.subtask_id.clone().map(SubTaskId::from),
+            user_id: UserId::from(self.user_id.clone()),
+            started_at: self.started_at,
+            ended_at: self.ended_at,
+            note: self.note.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+/// ドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverter<ActiveModel> for TimeEntry {
+    async fn to_sqlite_model(&self) -> Result<ActiveModel, String> {
+        self.to_sqlite_model_with_project_id(&self.project_id).await
+    }
+}
+
+/// プロジェクトID付きのドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverterWithProjectId<ActiveModel> for TimeEntry {
+    async fn to_sqlite_model_with_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<ActiveModel, String> {
+        Ok(ActiveModel {
+            project_id: Set(project_id.to_string()),
+            id: Set(self.id.to_string()),
+            task_id: Set(self.task_id.to_string()),
+            subtask_id: Set(self.subtask_id.map(|id| id.to_string())),
+            user_id: Set(self.user_id.to_string()),
+            started_at: Set(self.started_at),
+            ended_at: Set(self.ended_at),
+            note: Set(self.note.clone()),
+            created_at: Set(self.created_at),
+            updated_at: Set(self.updated_at),
+            deleted: Set(self.deleted),
+            updated_by: Set(self.updated_by.to_string()),
+        })
+    }
+}
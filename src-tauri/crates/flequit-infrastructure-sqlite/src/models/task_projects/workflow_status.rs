@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::{
+    models::task_projects::workflow_status::WorkflowStatus,
+    types::id_types::{ProjectId, UserId, WorkflowStatusId},
+    types::task_types::TaskStatus,
+};
+use sea_orm::{Set, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{DomainToSqliteConverter, DomainToSqliteConverterWithProjectId};
+
+use super::SqliteModelConverter;
+
+/// WorkflowStatus用SQLiteエンティティ定義
+///
+/// プロジェクト内の表示順での取得に最適化
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "workflow_statuses")]
+pub struct Model {
+    /// プロジェクトID（SQLite統合テーブル用）
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub project_id: String,
+
+    /// ステータスの一意識別子
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+
+    /// 表示名
+    pub name: String,
+
+    /// 表示色
+    pub color: Option<String>,
+
+    /// 表示順序
+    #[sea_orm(indexed)] // ソート用
+    pub order_index: i32,
+
+    /// 分類（組み込みのステータスの文字列形式）
+    pub category: String,
+
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+
+    /// 論理削除フラグ
+    #[sea_orm(indexed)]
+    pub deleted: bool,
+
+    /// 最終更新者のユーザーID
+    pub updated_by: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// SQLiteモデルからドメインモデルへの変換
+#[async_trait]
+impl SqliteModelConverter<WorkflowStatus> for Model {
+    async fn to_domain_model(&self) -> Result<WorkflowStatus, String> {
+        let category = match self.category.as_str() {
+            "not_started" => TaskStatus::NotStarted,
+            "in_progress" => TaskStatus::InProgress,
+            "waiting" => TaskStatus::Waiting,
+            "completed" => TaskStatus::Completed,
+            "cancelled" => TaskStatus::Cancelled,
+            _ => return Err(format!("Unknown task status: {}", self.category)),
+        };
+
+        Ok(WorkflowStatus {
+            id: WorkflowStatusId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            name: self.name.clone(),
+            color: self.color.clone(),
+            order_index: self.order_index,
+            category,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+/// ドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverter<ActiveModel> for WorkflowStatus {
+    async fn to_sqlite_model(&self) -> Result<ActiveModel, String> {
+        self.to_sqlite_model_with_project_id(&self.project_id).await
+    }
+}
+
+/// プロジェクトID付きのドメインモデルからSQLiteモデルへの変換
+#[async_trait]
+impl DomainToSqliteConverterWithProjectId<ActiveModel> for WorkflowStatus {
+    async fn to_sqlite_model_with_project_id(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<ActiveModel, String> {
+        Ok(ActiveModel {
+            project_id: Set(project_id.to_string()),
+            id: Set(self.id.to_string()),
+            name: Set(self.name.clone()),
+            color: Set(self.color.clone()),
+            order_index: Set(self.order_index),
+            category: Set(self.category.as_str().to_string()),
+            created_at: Set(self.created_at),
+            updated_at: Set(self.updated_at),
+            deleted: Set(self.deleted),
+            updated_by: Set(self.updated_by.to_string()),
+        })
+    }
+}
@@ -0,0 +1,169 @@
+//! コメント単体テスト
+//!
+//! testing.mdルール準拠のSQLiteコメントリポジトリテスト
+
+use chrono::{DateTime, Duration, Utc};
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::comment::CommentLocalSqliteRepository;
+use flequit_model::models::task_projects::comment::Comment;
+use flequit_model::types::id_types::{CommentId, ProjectId, SubTaskId, TaskId, UserId};
+use flequit_repository::project_repository_trait::ProjectRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use flequit_testing::TestPathGenerator;
+use function_name::named;
+
+use crate::integration::support::sqlite::SqliteTestHarness;
+
+fn new_comment(
+    project_id: ProjectId,
+    task_id: TaskId,
+    author_id: UserId,
+    created_at: DateTime<Utc>,
+) -> Comment {
+    Comment {
+        id: CommentId::from(Uuid::new_v4()),
+        project_id,
+        task_id,
+        subtask_id: None,
+        author_id,
+        body: "進捗を共有します".to_string(),
+        mentioned_user_ids: vec![],
+        created_at,
+        edited_at: None,
+        updated_at: created_at,
+        deleted: false,
+        updated_by: author_id,
+    }
+}
+
+#[named]
+#[tokio::test]
+async fn test_comment_create_and_edit_operation() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let comment_repo = CommentLocalSqliteRepository::new(db_manager_arc);
+
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let author_id = UserId::from(Uuid::new_v4());
+    let mentioned_id = UserId::from(Uuid::new_v4());
+    let created_at = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+
+    // サブタスクへのコメントを作成
+    let mut comment = new_comment(
+        project_id,
+        TaskId::from(Uuid::new_v4()),
+        author_id,
+        created_at,
+    );
+    comment.subtask_id = Some(SubTaskId::from(Uuid::new_v4()));
+    comment.body = "@reviewer 確認をお願いします".to_string();
+    comment.mentioned_user_ids = vec![mentioned_id];
+    comment_repo
+        .save(&project_id, &comment, &author_id, &created_at)
+        .await?;
+
+    let retrieved = comment_repo
+        .find_by_id(&project_id, &comment.id)
+        .await?
+        .expect("保存したコメントが取得できること");
+    assert_eq!(retrieved.subtask_id, comment.subtask_id);
+    assert_eq!(retrieved.author_id, author_id);
+    assert_eq!(retrieved.body, comment.body);
+    assert_eq!(retrieved.mentioned_user_ids, vec![mentioned_id]);
+    assert_eq!(retrieved.edited_at, None);
+
+    // 本文を編集して言及を外す
+    let edited_at = created_at + Duration::minutes(5);
+    comment.body = "確認をお願いします".to_string();
+    comment.mentioned_user_ids.clear();
+    comment.edited_at = Some(edited_at);
+    comment.updated_at = edited_at;
+    comment_repo
+        .save(&project_id, &comment, &author_id, &edited_at)
+        .await?;
+
+    let edited = comment_repo
+        .find_by_id(&project_id, &comment.id)
+        .await?
+        .unwrap();
+    assert_eq!(edited.body, "確認をお願いします");
+    assert!(edited.mentioned_user_ids.is_empty());
+    assert_eq!(edited.edited_at, Some(edited_at));
+    assert_eq!(edited.created_at, created_at);
+
+    Ok(())
+}
+
+#[named]
+#[tokio::test]
+async fn test_comment_list_and_soft_delete_operation() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let comment_repo = CommentLocalSqliteRepository::new(db_manager_arc);
+
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let author_id = UserId::from(Uuid::new_v4());
+    let task_id = TaskId::from(Uuid::new_v4());
+    let base = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+
+    // 投稿日時の逆順で保存する
+    let later = new_comment(project_id, task_id, author_id, base + Duration::hours(1));
+    let mut earlier = new_comment(project_id, task_id, author_id, base);
+    let other_task = new_comment(project_id, TaskId::from(Uuid::new_v4()), author_id, base);
+    for comment in [&later, &earlier, &other_task] {
+        comment_repo
+            .save(&project_id, comment, &author_id, &base)
+            .await?;
+    }
+
+    // タスクごとに投稿順で取得される
+    let by_task = comment_repo.find_by_task(&project_id, &task_id).await?;
+    assert_eq!(
+        by_task.iter().map(|c| c.id).collect::<Vec<_>>(),
+        vec![earlier.id, later.id]
+    );
+    assert_eq!(comment_repo.count(&project_id).await?, 3);
+
+    // 論理削除したコメントは一覧に含まれないが、元に戻せるよう記録は残る
+    earlier.deleted = true;
+    comment_repo
+        .save(&project_id, &earlier, &author_id, &base)
+        .await?;
+    assert_eq!(
+        comment_repo
+            .find_by_task(&project_id, &task_id)
+            .await?
+            .len(),
+        1
+    );
+    assert_eq!(comment_repo.count(&project_id).await?, 2);
+    let deleted = comment_repo
+        .find_by_id(&project_id, &earlier.id)
+        .await?
+        .expect("論理削除したコメントも取得できること");
+    assert!(deleted.deleted);
+
+    Ok(())
+}
@@ -158,6 +158,7 @@ async fn test_multiple_entities_crud_operations() -> Result<(), Box<dyn std::err
         title: "マルチエンティティテストタスク1".to_string(),
         description: Some("複数エンティティテスト用タスク1".to_string()),
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 2,
         plan_start_date: None,
         plan_end_date: None,
@@ -185,6 +186,7 @@ async fn test_multiple_entities_crud_operations() -> Result<(), Box<dyn std::err
         title: "マルチエンティティテストタスク2".to_string(),
         description: Some("複数エンティティテスト用タスク2".to_string()),
         status: TaskStatus::InProgress,
+        workflow_status_id: None,
         priority: 1,
         plan_start_date: None,
         plan_end_date: None,
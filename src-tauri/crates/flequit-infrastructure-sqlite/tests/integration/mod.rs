@@ -1,13 +1,24 @@
 // テーブル単体でのテスト
 mod accounts;
+mod comments;
 mod projects;
+mod reminders;
+mod snoozes;
+mod status_transition_rules;
 mod subtask_tags;
 mod subtasks;
 mod tags;
+mod task_dependencies;
 mod task_lists;
 mod task_tags;
 mod tasks;
+mod time_entries;
 mod users;
+mod workflow_statuses;
+
+// データベース暗号化テスト
+#[cfg(feature = "sqlcipher")]
+mod sqlcipher;
 
 // テーブル統合テスト
 mod integration_data;
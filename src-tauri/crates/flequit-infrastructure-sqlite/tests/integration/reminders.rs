@@ -0,0 +1,179 @@
+//! リマインダー単体テスト
+//!
+//! testing.mdルール準拠のSQLiteリマインダーリポジトリテスト
+
+use chrono::{DateTime, Duration, Utc};
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::reminder::ReminderLocalSqliteRepository;
+use flequit_model::models::task_projects::reminder::Reminder;
+use flequit_model::types::id_types::{ProjectId, ReminderId, SubTaskId, TaskId, UserId};
+use flequit_model::types::task_types::ReminderAnchor;
+use flequit_repository::project_repository_trait::ProjectRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use flequit_testing::TestPathGenerator;
+use function_name::named;
+
+use crate::integration::support::sqlite::SqliteTestHarness;
+
+fn new_reminder(
+    project_id: ProjectId,
+    task_id: TaskId,
+    user_id: UserId,
+    created_at: DateTime<Utc>,
+) -> Reminder {
+    Reminder {
+        id: ReminderId::from(Uuid::new_v4()),
+        project_id,
+        task_id,
+        subtask_id: None,
+        anchor: ReminderAnchor::PlanEnd,
+        remind_at: None,
+        offset_minutes: -30,
+        repeat_interval_minutes: None,
+        repeat_count: None,
+        last_fired_at: None,
+        snoozed_until: None,
+        created_at,
+        updated_at: created_at,
+        deleted: false,
+        updated_by: user_id,
+    }
+}
+
+#[named]
+#[tokio::test]
+async fn test_reminder_create_and_update_operation() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let reminder_repo = ReminderLocalSqliteRepository::new(db_manager_arc);
+
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let user_id = UserId::from(Uuid::new_v4());
+    let created_at = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+
+    // 日時指定で繰り返すサブタスクのリマインダーを作成
+    let mut reminder = new_reminder(
+        project_id,
+        TaskId::from(Uuid::new_v4()),
+        user_id,
+        created_at,
+    );
+    reminder.subtask_id = Some(SubTaskId::from(Uuid::new_v4()));
+    reminder.anchor = ReminderAnchor::Absolute;
+    reminder.remind_at = Some(created_at + Duration::hours(1));
+    reminder.offset_minutes = 0;
+    reminder.repeat_interval_minutes = Some(15);
+    reminder.repeat_count = Some(2);
+    reminder_repo
+        .save(&project_id, &reminder, &user_id, &created_at)
+        .await?;
+
+    let retrieved = reminder_repo
+        .find_by_id(&project_id, &reminder.id)
+        .await?
+        .expect("保存したリマインダーが取得できること");
+    assert_eq!(retrieved.subtask_id, reminder.subtask_id);
+    assert_eq!(retrieved.anchor, ReminderAnchor::Absolute);
+    assert_eq!(retrieved.remind_at, reminder.remind_at);
+    assert_eq!(retrieved.repeat_interval_minutes, Some(15));
+    assert_eq!(retrieved.repeat_count, Some(2));
+    assert_eq!(retrieved.last_fired_at, None);
+
+    // 通知済みの記録を保存する
+    let fired_at = created_at + Duration::hours(1);
+    reminder.last_fired_at = Some(fired_at);
+    reminder_repo
+        .save(&project_id, &reminder, &user_id, &fired_at)
+        .await?;
+
+    let fired = reminder_repo
+        .find_by_id(&project_id, &reminder.id)
+        .await?
+        .unwrap();
+    assert_eq!(fired.last_fired_at, Some(fired_at));
+    assert_eq!(
+        fired.next_fire_at(None, None),
+        Some(fired_at + Duration::minutes(15))
+    );
+
+    // スヌーズ先の日時を保存する
+    let snoozed_until = fired_at + Duration::hours(2);
+    reminder.snoozed_until = Some(snoozed_until);
+    reminder_repo
+        .save(&project_id, &reminder, &user_id, &fired_at)
+        .await?;
+
+    let snoozed = reminder_repo
+        .find_by_id(&project_id, &reminder.id)
+        .await?
+        .unwrap();
+    assert_eq!(snoozed.snoozed_until, Some(snoozed_until));
+
+    Ok(())
+}
+
+#[named]
+#[tokio::test]
+async fn test_reminder_list_and_delete_operation() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let reminder_repo = ReminderLocalSqliteRepository::new(db_manager_arc);
+
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let user_id = UserId::from(Uuid::new_v4());
+    let task_id = TaskId::from(Uuid::new_v4());
+    let base = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+
+    // 作成日時の逆順で保存する
+    let later = new_reminder(project_id, task_id, user_id, base + Duration::hours(1));
+    let earlier = new_reminder(project_id, task_id, user_id, base);
+    let other_task = new_reminder(project_id, TaskId::from(Uuid::new_v4()), user_id, base);
+    for reminder in [&later, &earlier, &other_task] {
+        reminder_repo
+            .save(&project_id, reminder, &user_id, &base)
+            .await?;
+    }
+
+    // タスクごとに作成日時順で取得される
+    let by_task = reminder_repo.find_by_task(&project_id, &task_id).await?;
+    assert_eq!(
+        by_task.iter().map(|r| r.id).collect::<Vec<_>>(),
+        vec![earlier.id, later.id]
+    );
+    assert_eq!(reminder_repo.count(&project_id).await?, 3);
+
+    // 削除
+    reminder_repo.delete(&project_id, &earlier.id).await?;
+    assert!(!reminder_repo.exists(&project_id, &earlier.id).await?);
+    assert_eq!(
+        reminder_repo
+            .find_by_task(&project_id, &task_id)
+            .await?
+            .len(),
+        1
+    );
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+//! スヌーズ単体テスト
+//!
+//! testing.mdルール準拠のSQLiteスヌーズリポジトリテスト
+
+use chrono::{DateTime, Duration, Utc};
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::snooze::SnoozeLocalSqliteRepository;
+use flequit_model::models::task_projects::snooze::Snooze;
+use flequit_model::types::id_types::{ProjectId, ReminderId, SnoozeId, TaskId, UserId};
+use flequit_repository::project_repository_trait::ProjectRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use flequit_testing::TestPathGenerator;
+use function_name::named;
+
+use crate::integration::support::sqlite::SqliteTestHarness;
+
+fn new_snooze(
+    project_id: ProjectId,
+    task_id: TaskId,
+    user_id: UserId,
+    created_at: DateTime<Utc>,
+) -> Snooze {
+    Snooze {
+        id: SnoozeId::from(Uuid::new_v4()),
+        project_id,
+        task_id,
+        subtask_id: None,
+        reminder_id: None,
+        original_plan_start_date: None,
+        original_plan_end_date: Some(created_at),
+        original_remind_at: None,
+        snoozed_until: created_at + Duration::days(1),
+        created_at,
+        updated_at: created_at,
+        deleted: false,
+        updated_by: user_id,
+    }
+}
+
+#[named]
+#[tokio::test]
+async fn test_snooze_create_and_update_operation() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let snooze_repo = SnoozeLocalSqliteRepository::new(db_manager_arc);
+
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let user_id = UserId::from(Uuid::new_v4());
+    let created_at = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+
+    // リマインダーのスヌーズを作成
+    let mut snooze = new_snooze(
+        project_id,
+        TaskId::from(Uuid::new_v4()),
+        user_id,
+        created_at,
+    );
+    snooze.reminder_id = Some(ReminderId::from(Uuid::new_v4()));
+    snooze.original_plan_end_date = None;
+    snooze.original_remind_at = Some(created_at);
+    snooze.snoozed_until = created_at + Duration::minutes(15);
+    snooze_repo
+        .save(&project_id, &snooze, &user_id, &created_at)
+        .await?;
+
+    let retrieved = snooze_repo
+        .find_by_id(&project_id, &snooze.id)
+        .await?
+        .expect("保存したスヌーズが取得できること");
+    assert_eq!(retrieved.reminder_id, snooze.reminder_id);
+    assert_eq!(retrieved.original_remind_at, Some(created_at));
+    assert_eq!(retrieved.original_plan_end_date, None);
+    assert_eq!(retrieved.snoozed_until, created_at + Duration::minutes(15));
+    assert!(!retrieved.is_deferring(created_at));
+
+    // 先送りを取り消す（先送り先を現在時刻にする）
+    let cancelled_at = created_at + Duration::minutes(5);
+    snooze.snoozed_until = cancelled_at;
+    snooze_repo
+        .save(&project_id, &snooze, &user_id, &cancelled_at)
+        .await?;
+
+    let cancelled = snooze_repo
+        .find_by_id(&project_id, &snooze.id)
+        .await?
+        .unwrap();
+    assert_eq!(cancelled.snoozed_until, cancelled_at);
+
+    Ok(())
+}
+
+#[named]
+#[tokio::test]
+async fn test_snooze_list_and_delete_operation() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let snooze_repo = SnoozeLocalSqliteRepository::new(db_manager_arc);
+
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let user_id = UserId::from(Uuid::new_v4());
+    let task_id = TaskId::from(Uuid::new_v4());
+    let base = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+
+    // 作成日時の逆順で保存する
+    let later = new_snooze(project_id, task_id, user_id, base + Duration::hours(1));
+    let earlier = new_snooze(project_id, task_id, user_id, base);
+    let other_task = new_snooze(project_id, TaskId::from(Uuid::new_v4()), user_id, base);
+    for snooze in [&later, &earlier, &other_task] {
+        snooze_repo
+            .save(&project_id, snooze, &user_id, &base)
+            .await?;
+    }
+
+    // タスクごとに作成日時順で取得される
+    let by_task = snooze_repo.find_by_task(&project_id, &task_id).await?;
+    assert_eq!(
+        by_task.iter().map(|s| s.id).collect::<Vec<_>>(),
+        vec![earlier.id, later.id]
+    );
+    assert_eq!(snooze_repo.count(&project_id).await?, 3);
+
+    // 削除
+    snooze_repo.delete(&project_id, &earlier.id).await?;
+    assert!(!snooze_repo.exists(&project_id, &earlier.id).await?);
+    assert_eq!(
+        snooze_repo.find_by_task(&project_id, &task_id).await?.len(),
+        1
+    );
+
+    Ok(())
+}
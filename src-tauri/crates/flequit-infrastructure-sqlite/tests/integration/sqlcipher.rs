@@ -0,0 +1,105 @@
+//! SQLCipher暗号化テスト
+//!
+//! 平文データベースの暗号化変換、鍵によるアンロック、鍵変更を検証する
+
+use chrono::{DateTime, Utc};
+use flequit_infrastructure_sqlite::errors::sqlite_error::SQLiteError;
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+use flequit_infrastructure_sqlite::infrastructure::sqlcipher::{
+    SqlCipherKey, is_plaintext_database,
+};
+use flequit_infrastructure_sqlite::infrastructure::users::user::UserLocalSqliteRepository;
+use flequit_model::models::users::user::User;
+use flequit_model::types::id_types::UserId;
+use flequit_repository::repositories::base_repository_trait::Repository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use ::function_name::named;
+use flequit_testing::TestPathGenerator;
+
+use crate::integration::support::sqlite::SqliteTestHarness;
+
+fn new_user_repository(db_manager: DatabaseManager) -> UserLocalSqliteRepository {
+    UserLocalSqliteRepository::new(Arc::new(tokio::sync::RwLock::new(db_manager)))
+}
+
+#[named]
+#[tokio::test]
+async fn test_sqlcipher_encrypt_unlock_and_rekey() -> Result<(), Box<dyn std::error::Error>> {
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), function_name!());
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+    let db_path = output_file_path.to_string_lossy().to_string();
+
+    // 平文データベースにユーザーを保存
+    let user_id = UserId::from(Uuid::new_v4());
+    let timestamp = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+    let user = User {
+        id: user_id,
+        handle_id: "cipher_user".to_string(),
+        display_name: "暗号化ユーザー".to_string(),
+        email: None,
+        avatar_url: None,
+        bio: None,
+        timezone: None,
+        is_active: true,
+        created_at: timestamp,
+        updated_at: timestamp,
+        deleted: false,
+        updated_by: user_id,
+    };
+    new_user_repository(DatabaseManager::new_for_test(&db_path))
+        .save(&user, &user_id, &timestamp)
+        .await?;
+    assert!(is_plaintext_database(&output_file_path)?);
+
+    // 鍵を設定して接続すると平文データベースが暗号化形式へ変換される
+    let mut db_manager = DatabaseManager::new_for_test(&db_path);
+    db_manager.set_encryption_key(SqlCipherKey::passphrase("first passphrase"))?;
+    let user_repo = new_user_repository(db_manager);
+    let retrieved = user_repo.find_by_id(&user_id).await?.unwrap();
+    assert_eq!(retrieved.handle_id, "cipher_user");
+    assert!(!is_plaintext_database(&output_file_path)?);
+    drop(user_repo);
+
+    // 鍵なし・誤った鍵では開けない
+    assert!(
+        DatabaseManager::new_for_test(&db_path)
+            .get_connection()
+            .await
+            .is_err()
+    );
+    let mut wrong_key_manager = DatabaseManager::new_for_test(&db_path);
+    wrong_key_manager.set_encryption_key(SqlCipherKey::passphrase("wrong"))?;
+    assert!(matches!(
+        wrong_key_manager.get_connection().await,
+        Err(SQLiteError::EncryptionError(_))
+    ));
+
+    // 鍵を生鍵へ変更
+    let mut db_manager = DatabaseManager::new_for_test(&db_path);
+    db_manager.set_encryption_key(SqlCipherKey::passphrase("first passphrase"))?;
+    db_manager.get_connection().await?;
+    let new_key = SqlCipherKey::raw([7u8; 32]);
+    db_manager.rekey(new_key.clone()).await?;
+    assert!(db_manager.set_encryption_key(new_key.clone()).is_ok());
+    db_manager.get_connection().await?;
+    assert!(
+        db_manager
+            .set_encryption_key(SqlCipherKey::passphrase("too late"))
+            .is_err()
+    );
+
+    let mut old_key_manager = DatabaseManager::new_for_test(&db_path);
+    old_key_manager.set_encryption_key(SqlCipherKey::passphrase("first passphrase"))?;
+    assert!(old_key_manager.get_connection().await.is_err());
+
+    let mut new_key_manager = DatabaseManager::new_for_test(&db_path);
+    new_key_manager.set_encryption_key(new_key)?;
+    let user_repo = new_user_repository(new_key_manager);
+    assert!(user_repo.find_by_id(&user_id).await?.is_some());
+
+    Ok(())
+}
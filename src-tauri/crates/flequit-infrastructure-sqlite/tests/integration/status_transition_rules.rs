@@ -0,0 +1,91 @@
+//! ステータス遷移ルール単体テスト
+//!
+//! testing.mdルール準拠のSQLiteステータス遷移ルールリポジトリテスト
+
+use chrono::{DateTime, Utc};
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::status_transition_rule::StatusTransitionRuleLocalSqliteRepository;
+use flequit_model::models::task_projects::status_transition_rule::StatusTransitionRule;
+use flequit_model::types::id_types::{ProjectId, StatusTransitionRuleId, UserId};
+use flequit_model::types::task_types::{StatusTransitionRequirement, TaskStatus};
+use flequit_repository::project_repository_trait::ProjectRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use flequit_testing::TestPathGenerator;
+use function_name::named;
+
+use crate::integration::support::sqlite::SqliteTestHarness;
+
+#[named]
+#[tokio::test]
+async fn test_status_transition_rule_crud_operation() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let rule_repo = StatusTransitionRuleLocalSqliteRepository::new(db_manager_arc);
+
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let user_id = UserId::from(Uuid::new_v4());
+    let timestamp = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+
+    // 中止から実行中への遷移に理由を必須とするルールを作成
+    let mut rule = StatusTransitionRule {
+        id: StatusTransitionRuleId::from(Uuid::new_v4()),
+        project_id,
+        from_status: TaskStatus::Cancelled,
+        to_status: TaskStatus::InProgress,
+        requirement: StatusTransitionRequirement::ReasonRequired,
+        created_at: timestamp,
+        updated_at: timestamp,
+        deleted: false,
+        updated_by: user_id,
+    };
+    rule_repo
+        .save(&project_id, &rule, &user_id, &timestamp)
+        .await?;
+
+    let retrieved = rule_repo
+        .find_by_id(&project_id, &rule.id)
+        .await?
+        .expect("保存したルールが取得できること");
+    assert_eq!(retrieved.from_status, TaskStatus::Cancelled);
+    assert_eq!(retrieved.to_status, TaskStatus::InProgress);
+    assert_eq!(
+        retrieved.requirement,
+        StatusTransitionRequirement::ReasonRequired
+    );
+
+    // 禁止に変更
+    rule.requirement = StatusTransitionRequirement::Forbidden;
+    rule_repo
+        .save(&project_id, &rule, &user_id, &timestamp)
+        .await?;
+    let all = rule_repo.find_all(&project_id).await?;
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].requirement, StatusTransitionRequirement::Forbidden);
+
+    // 他のプロジェクトからは見えない
+    assert!(
+        rule_repo
+            .find_all(&ProjectId::from(Uuid::new_v4()))
+            .await?
+            .is_empty()
+    );
+
+    // 削除
+    rule_repo.delete(&project_id, &rule.id).await?;
+    assert!(!rule_repo.exists(&project_id, &rule.id).await?);
+    assert_eq!(rule_repo.count(&project_id).await?, 0);
+
+    Ok(())
+}
@@ -97,6 +97,7 @@ async fn test_subtask_tag_relation_operations() -> Result<(), Box<dyn std::error
         title: "SubtaskTag紐づけテスト用タスク".to_string(),
         description: None,
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 1,
         plan_start_date: None,
         plan_end_date: None,
@@ -304,6 +305,7 @@ async fn test_subtask_tag_bulk_update() -> Result<(), Box<dyn std::error::Error>
         title: "一括更新テスト用タスク".to_string(),
         description: None,
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 1,
         plan_start_date: None,
         plan_end_date: None,
@@ -95,6 +95,7 @@ async fn test_subtask_create_operation() -> Result<(), Box<dyn std::error::Error
         title: "Create操作親タスク".to_string(),
         description: Some("Create操作テスト用親タスク".to_string()),
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 2,
         plan_start_date: None,
         plan_end_date: None,
@@ -231,6 +232,7 @@ async fn test_subtask_read_operation() -> Result<(), Box<dyn std::error::Error>>
         title: "Read操作親タスク".to_string(),
         description: Some("Read操作テスト用親タスク".to_string()),
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 2,
         plan_start_date: None,
         plan_end_date: None,
@@ -399,6 +401,7 @@ async fn test_subtask_update_operation() -> Result<(), Box<dyn std::error::Error
         title: "Update操作親タスク".to_string(),
         description: Some("Update操作テスト用親タスク".to_string()),
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 2,
         plan_start_date: None,
         plan_end_date: None,
@@ -583,6 +586,7 @@ async fn test_subtask_delete_operation() -> Result<(), Box<dyn std::error::Error
         title: "Delete操作親タスク".to_string(),
         description: Some("Delete操作テスト用親タスク".to_string()),
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 2,
         plan_start_date: None,
         plan_end_date: None,
@@ -0,0 +1,171 @@
+//! タスク依存関係テーブルテスト
+//!
+//! testing.mdルール準拠のSQLiteタスク依存関係リポジトリテスト
+
+use chrono::{DateTime, Utc};
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::{
+    project::ProjectLocalSqliteRepository, task::TaskLocalSqliteRepository,
+    task_dependency::TaskDependencyLocalSqliteRepository, task_list::TaskListLocalSqliteRepository,
+};
+use flequit_model::models::task_projects::{project::Project, task::Task, task_list::TaskList};
+use flequit_model::types::id_types::{ProjectId, TaskId, TaskListId, UserId};
+use flequit_model::types::task_types::TaskStatus;
+use flequit_repository::project_relation_repository_trait::ProjectRelationRepository;
+use flequit_repository::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::base_repository_trait::Repository;
+use function_name::named;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use flequit_testing::TestPathGenerator;
+
+use crate::integration::support::sqlite::SqliteTestHarness;
+
+#[named]
+#[tokio::test]
+async fn test_task_dependency_relation_operations() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let project_repo = ProjectLocalSqliteRepository::new(db_manager_arc.clone());
+    let task_list_repo = TaskListLocalSqliteRepository::new(db_manager_arc.clone());
+    let task_repo = TaskLocalSqliteRepository::new(db_manager_arc.clone());
+    let dependency_repo = TaskDependencyLocalSqliteRepository::new(db_manager_arc);
+
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let user_id = UserId::from(Uuid::new_v4());
+    let timestamp = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+    let project = Project {
+        id: project_id,
+        name: "依存関係テスト用プロジェクト".to_string(),
+        description: None,
+        color: None,
+        order_index: 1,
+        is_archived: false,
+        status: None,
+        owner_id: Some(user_id),
+        created_at: timestamp,
+        updated_at: timestamp,
+        deleted: false,
+        updated_by: user_id,
+    };
+    project_repo.save(&project, &user_id, &timestamp).await?;
+
+    // 依存関係はタスクリストをまたいで設定できる
+    let mut list_ids = Vec::new();
+    for (index, name) in ["設計", "実装"].into_iter().enumerate() {
+        let task_list = TaskList {
+            id: TaskListId::from(Uuid::new_v4()),
+            project_id,
+            name: name.to_string(),
+            description: None,
+            color: None,
+            order_index: index as i32,
+            is_archived: false,
+            created_at: timestamp,
+            updated_at: timestamp,
+            deleted: false,
+            updated_by: user_id,
+        };
+        task_list_repo
+            .save(&project_id, &task_list, &user_id, &timestamp)
+            .await?;
+        list_ids.push(task_list.id);
+    }
+
+    let task = |title: &str, list_id: TaskListId| Task {
+        id: TaskId::from(Uuid::new_v4()),
+        project_id,
+        list_id,
+        title: title.to_string(),
+        description: None,
+        status: TaskStatus::NotStarted,
+        workflow_status_id: None,
+        priority: 1,
+        plan_start_date: None,
+        plan_end_date: None,
+        do_start_date: None,
+        do_end_date: None,
+        is_range_date: None,
+        recurrence_rule: None,
+        assigned_user_ids: vec![],
+        tag_ids: vec![],
+        order_index: 1,
+        is_archived: false,
+        created_at: timestamp,
+        updated_at: timestamp,
+        deleted: false,
+        updated_by: user_id,
+    };
+    let design = task("API設計", list_ids[0]);
+    let implement = task("API実装", list_ids[1]);
+    let release = task("リリース", list_ids[1]);
+    for item in [&design, &implement, &release] {
+        task_repo
+            .save(&project_id, item, &user_id, &timestamp)
+            .await?;
+    }
+
+    // 1. 依存関係の追加（実装は設計に、リリースは設計と実装に依存）
+    for (task_id, depends_on) in [
+        (implement.id, design.id),
+        (release.id, design.id),
+        (release.id, implement.id),
+    ] {
+        dependency_repo
+            .add(&project_id, &task_id, &depends_on, &user_id, &timestamp)
+            .await?;
+    }
+
+    // 2. 重複して追加しても1件のまま
+    dependency_repo
+        .add(&project_id, &implement.id, &design.id, &user_id, &timestamp)
+        .await?;
+    assert_eq!(dependency_repo.count(&project_id, &implement.id).await?, 1);
+    assert_eq!(dependency_repo.find_all(&project_id).await?.len(), 3);
+
+    // 3. 先行タスク・後続タスクの両方向から取得
+    let blockers = dependency_repo
+        .find_relations(&project_id, &release.id)
+        .await?;
+    assert_eq!(blockers.len(), 2);
+    assert!(blockers.iter().all(|d| d.updated_by == user_id));
+    let dependents = dependency_repo
+        .find_dependents(&project_id, &design.id)
+        .await?;
+    let mut dependent_ids: Vec<TaskId> = dependents.iter().map(|d| d.task_id).collect();
+    dependent_ids.sort_by_key(|id| id.to_string());
+    let mut expected = vec![implement.id, release.id];
+    expected.sort_by_key(|id| id.to_string());
+    assert_eq!(dependent_ids, expected);
+
+    // 4. 個別削除
+    dependency_repo
+        .remove(&project_id, &release.id, &design.id)
+        .await?;
+    assert!(
+        dependency_repo
+            .find_relation(&project_id, &release.id, &design.id)
+            .await?
+            .is_none()
+    );
+    assert!(dependency_repo.exists(&project_id, &release.id).await?);
+
+    // 5. タスクが関わる依存関係をまとめて削除
+    dependency_repo
+        .remove_all_by_task_id(&project_id, &implement.id)
+        .await?;
+    assert!(dependency_repo.find_all(&project_id).await?.is_empty());
+
+    Ok(())
+}
@@ -96,6 +96,7 @@ async fn test_task_tag_relation_operations() -> Result<(), Box<dyn std::error::E
         title: "TaskTag紐づけテスト用タスク".to_string(),
         description: None,
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 1,
         plan_start_date: None,
         plan_end_date: None,
@@ -276,6 +277,7 @@ async fn test_task_tag_bulk_update() -> Result<(), Box<dyn std::error::Error>> {
         title: "一括更新テスト用タスク".to_string(),
         description: None,
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 1,
         plan_start_date: None,
         plan_end_date: None,
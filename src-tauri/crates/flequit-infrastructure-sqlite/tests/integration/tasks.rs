@@ -93,6 +93,7 @@ async fn test_task_create_operation() -> Result<(), Box<dyn std::error::Error>>
         title: "Create操作SQLiteタスク".to_string(),
         description: Some("Create操作SQLiteテスト用タスク".to_string()),
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 2,
         plan_start_date: None,
         plan_end_date: None,
@@ -199,6 +200,7 @@ async fn test_task_read_operation() -> Result<(), Box<dyn std::error::Error>> {
         title: "Read操作SQLiteタスク1".to_string(),
         description: Some("Read操作SQLiteテスト用タスク1".to_string()),
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 2,
         plan_start_date: None,
         plan_end_date: None,
@@ -226,6 +228,7 @@ async fn test_task_read_operation() -> Result<(), Box<dyn std::error::Error>> {
         title: "Read操作SQLiteタスク2".to_string(),
         description: Some("Read操作SQLiteテスト用タスク2".to_string()),
         status: TaskStatus::InProgress,
+        workflow_status_id: None,
         priority: 1,
         plan_start_date: None,
         plan_end_date: None,
@@ -338,6 +341,7 @@ async fn test_task_update_operation() -> Result<(), Box<dyn std::error::Error>>
         title: "Update操作SQLiteタスク1".to_string(),
         description: Some("Update操作SQLiteテスト用タスク1".to_string()),
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 2,
         plan_start_date: None,
         plan_end_date: None,
@@ -365,6 +369,7 @@ async fn test_task_update_operation() -> Result<(), Box<dyn std::error::Error>>
         title: "Update操作SQLiteタスク2".to_string(),
         description: Some("Update操作SQLiteテスト用タスク2".to_string()),
         status: TaskStatus::InProgress,
+        workflow_status_id: None,
         priority: 1,
         plan_start_date: None,
         plan_end_date: None,
@@ -490,6 +495,7 @@ async fn test_task_delete_operation() -> Result<(), Box<dyn std::error::Error>>
         title: "Delete操作SQLiteタスク1".to_string(),
         description: Some("Delete操作SQLiteテスト用タスク1".to_string()),
         status: TaskStatus::NotStarted,
+        workflow_status_id: None,
         priority: 2,
         plan_start_date: None,
         plan_end_date: None,
@@ -517,6 +523,7 @@ async fn test_task_delete_operation() -> Result<(), Box<dyn std::error::Error>>
         title: "Delete操作SQLiteタスク2".to_string(),
         description: Some("Delete操作SQLiteテスト用タスク2".to_string()),
         status: TaskStatus::InProgress,
+        workflow_status_id: None,
         priority: 1,
         plan_start_date: None,
         plan_end_date: None,
@@ -557,3 +564,134 @@ async fn test_task_delete_operation() -> Result<(), Box<dyn std::error::Error>>
 
     Ok(())
 }
+
+#[named]
+#[tokio::test]
+async fn test_task_work_dates_roundtrip_and_backfill() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(&output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let project_repo = ProjectLocalSqliteRepository::new(db_manager_arc.clone());
+    let task_list_repo = TaskListLocalSqliteRepository::new(db_manager_arc.clone());
+    let task_repo = TaskLocalSqliteRepository::new(db_manager_arc);
+
+    // 親プロジェクトとタスクリストを作成
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let task_list_id = TaskListId::from(Uuid::new_v4());
+    let user_id = UserId::from(Uuid::new_v4());
+    let timestamp = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+    let project = Project {
+        id: project_id,
+        name: "実績日時テスト用プロジェクト".to_string(),
+        description: None,
+        color: None,
+        order_index: 1,
+        is_archived: false,
+        status: Some(ProjectStatus::Active),
+        owner_id: Some(user_id),
+        created_at: timestamp,
+        updated_at: timestamp,
+        deleted: false,
+        updated_by: user_id,
+    };
+    project_repo.save(&project, &user_id, &timestamp).await?;
+    let task_list = TaskList {
+        id: task_list_id,
+        project_id,
+        name: "実績日時テスト用タスクリスト".to_string(),
+        description: None,
+        color: None,
+        order_index: 1,
+        is_archived: false,
+        created_at: timestamp,
+        updated_at: timestamp,
+        deleted: false,
+        updated_by: user_id,
+    };
+    task_list_repo
+        .save(&project_id, &task_list, &user_id, &timestamp)
+        .await?;
+
+    let do_start = DateTime::<Utc>::from_timestamp(1717750000, 0).unwrap();
+    let do_end = DateTime::<Utc>::from_timestamp(1717760000, 0).unwrap();
+    let task = |title: &str, order_index: i32| Task {
+        id: TaskId::from(Uuid::new_v4()),
+        project_id,
+        list_id: task_list_id,
+        title: title.to_string(),
+        description: None,
+        status: TaskStatus::Completed,
+        workflow_status_id: None,
+        priority: 0,
+        plan_start_date: Some(timestamp),
+        plan_end_date: Some(timestamp),
+        do_start_date: Some(do_start),
+        do_end_date: Some(do_end),
+        is_range_date: Some(true),
+        recurrence_rule: None,
+        assigned_user_ids: vec![],
+        tag_ids: vec![],
+        order_index,
+        is_archived: false,
+        created_at: timestamp,
+        updated_at: timestamp,
+        deleted: false,
+        updated_by: user_id,
+    };
+
+    // 実績日時が保存・取得できる
+    let recorded = task("実績日時を記録済みのタスク", 1);
+    task_repo
+        .save(&project_id, &recorded, &user_id, &timestamp)
+        .await?;
+    let retrieved = task_repo
+        .find_by_id(&project_id, &recorded.id)
+        .await?
+        .unwrap();
+    assert_eq!(retrieved.do_start_date, Some(do_start));
+    assert_eq!(retrieved.do_end_date, Some(do_end));
+
+    // 実績日時が失われていたタスクだけを補完する
+    let mut legacy = task("実績日時が失われたタスク", 2);
+    legacy.do_start_date = None;
+    legacy.do_end_date = None;
+    task_repo
+        .save(&project_id, &legacy, &user_id, &timestamp)
+        .await?;
+
+    let mut source_recorded = recorded.clone();
+    source_recorded.do_end_date = None;
+    let source_legacy = task("実績日時が失われたタスク", 2);
+    let source_legacy = Task {
+        id: legacy.id,
+        ..source_legacy
+    };
+    let updated = task_repo
+        .backfill_work_dates(&project_id, &[source_recorded, source_legacy])
+        .await?;
+    assert_eq!(updated, 1);
+
+    let retrieved = task_repo
+        .find_by_id(&project_id, &legacy.id)
+        .await?
+        .unwrap();
+    assert_eq!(retrieved.do_start_date, Some(do_start));
+    assert_eq!(retrieved.do_end_date, Some(do_end));
+    let retrieved = task_repo
+        .find_by_id(&project_id, &recorded.id)
+        .await?
+        .unwrap();
+    assert_eq!(retrieved.do_end_date, Some(do_end));
+    assert_eq!(task_repo.find_project_ids().await?, vec![project_id]);
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+//! タイムエントリ単体テスト
+//!
+//! testing.mdルール準拠のSQLiteタイムエントリリポジトリテスト
+
+use chrono::{DateTime, Duration, Utc};
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::time_entry::TimeEntryLocalSqliteRepository;
+use flequit_model::models::task_projects::time_entry::TimeEntry;
+use flequit_model::types::id_types::{ProjectId, SubTaskId, TaskId, TimeEntryId, UserId};
+use flequit_repository::project_repository_trait::ProjectRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use flequit_testing::TestPathGenerator;
+use function_name::named;
+
+use crate::integration::support::sqlite::SqliteTestHarness;
+
+fn new_entry(
+    project_id: ProjectId,
+    user_id: UserId,
+    started_at: DateTime<Utc>,
+    ended_at: Option<DateTime<Utc>>,
+) -> TimeEntry {
+    TimeEntry {
+        id: TimeEntryId::from(Uuid::new_v4()),
+        project_id,
+        task_id: TaskId::from(Uuid::new_v4()),
+        subtask_id: None,
+        user_id,
+        started_at,
+        ended_at,
+        note: None,
+        created_at: started_at,
+        updated_at: started_at,
+        deleted: false,
+        updated_by: user_id,
+    }
+}
+
+#[named]
+#[tokio::test]
+async fn test_time_entry_create_and_stop_operation() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let time_entry_repo = TimeEntryLocalSqliteRepository::new(db_manager_arc);
+
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let user_id = UserId::from(Uuid::new_v4());
+    let started_at = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+
+    // 計測中の記録を作成
+    let mut entry = new_entry(project_id, user_id, started_at, None);
+    entry.subtask_id = Some(SubTaskId::from(Uuid::new_v4()));
+    entry.note = Some("計測中のSQLiteタイムエントリ".to_string());
+    time_entry_repo
+        .save(&project_id, &entry, &user_id, &started_at)
+        .await?;
+
+    let retrieved = time_entry_repo
+        .find_by_id(&project_id, &entry.id)
+        .await?
+        .expect("保存した記録が取得できること");
+    assert_eq!(retrieved.task_id, entry.task_id);
+    assert_eq!(retrieved.subtask_id, entry.subtask_id);
+    assert_eq!(retrieved.note, entry.note);
+    assert!(retrieved.is_running());
+
+    let running = time_entry_repo.find_running_by_user(&user_id).await?;
+    assert_eq!(running.len(), 1);
+    assert_eq!(running[0].id, entry.id);
+
+    // 停止すると計測中の記録から外れる
+    let ended_at = started_at + Duration::minutes(45);
+    entry.ended_at = Some(ended_at);
+    time_entry_repo
+        .save(&project_id, &entry, &user_id, &ended_at)
+        .await?;
+
+    let stopped = time_entry_repo
+        .find_by_id(&project_id, &entry.id)
+        .await?
+        .unwrap();
+    assert_eq!(stopped.ended_at, Some(ended_at));
+    assert!(
+        time_entry_repo
+            .find_running_by_user(&user_id)
+            .await?
+            .is_empty()
+    );
+
+    Ok(())
+}
+
+#[named]
+#[tokio::test]
+async fn test_time_entry_list_and_delete_operation() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let time_entry_repo = TimeEntryLocalSqliteRepository::new(db_manager_arc);
+
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let user_id = UserId::from(Uuid::new_v4());
+    let base = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+
+    // 開始日時の逆順で保存する
+    let later = new_entry(
+        project_id,
+        user_id,
+        base + Duration::hours(2),
+        Some(base + Duration::hours(3)),
+    );
+    let earlier = new_entry(project_id, user_id, base, Some(base + Duration::hours(1)));
+    for entry in [&later, &earlier] {
+        time_entry_repo
+            .save(&project_id, entry, &user_id, &base)
+            .await?;
+    }
+
+    // 開始日時順に取得される
+    let all = time_entry_repo.find_all(&project_id).await?;
+    assert_eq!(
+        all.iter().map(|e| e.id).collect::<Vec<_>>(),
+        vec![earlier.id, later.id]
+    );
+    assert_eq!(time_entry_repo.count(&project_id).await?, 2);
+
+    // 削除
+    time_entry_repo.delete(&project_id, &earlier.id).await?;
+    assert!(!time_entry_repo.exists(&project_id, &earlier.id).await?);
+    assert_eq!(time_entry_repo.count(&project_id).await?, 1);
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! ワークフローステータス単体テスト
+//!
+//! testing.mdルール準拠のSQLiteワークフローステータスリポジトリテスト
+
+use chrono::{DateTime, Utc};
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::workflow_status::WorkflowStatusLocalSqliteRepository;
+use flequit_model::models::task_projects::workflow_status::WorkflowStatus;
+use flequit_model::types::id_types::{ProjectId, UserId, WorkflowStatusId};
+use flequit_model::types::task_types::TaskStatus;
+use flequit_repository::project_repository_trait::ProjectRepository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use flequit_testing::TestPathGenerator;
+use function_name::named;
+
+use crate::integration::support::sqlite::SqliteTestHarness;
+
+#[named]
+#[tokio::test]
+async fn test_workflow_status_crud_operation() -> Result<(), Box<dyn std::error::Error>> {
+    // テンプレートディレクトリ
+    let crate_name = env!("CARGO_PKG_NAME");
+    let template_dir = TestPathGenerator::generate_test_crate_dir(crate_name);
+
+    // テストデータベースを作成
+    let test_case = function_name!();
+    let output_dir = TestPathGenerator::generate_test_dir(file!(), test_case);
+    let output_file_path = SqliteTestHarness::copy_database_template(&template_dir, &output_dir)?;
+
+    // リポジトリを初期化
+    let db_manager = DatabaseManager::new_for_test(output_file_path.to_string_lossy().to_string());
+    let db_manager_arc = Arc::new(tokio::sync::RwLock::new(db_manager));
+    let status_repo = WorkflowStatusLocalSqliteRepository::new(db_manager_arc);
+
+    let project_id = ProjectId::from(Uuid::new_v4());
+    let user_id = UserId::from(Uuid::new_v4());
+    let timestamp = DateTime::<Utc>::from_timestamp(1717708800, 0).unwrap();
+    let status = |name: &str, order_index: i32, category: TaskStatus| WorkflowStatus {
+        id: WorkflowStatusId::from(Uuid::new_v4()),
+        project_id,
+        name: name.to_string(),
+        color: None,
+        order_index,
+        category,
+        created_at: timestamp,
+        updated_at: timestamp,
+        deleted: false,
+        updated_by: user_id,
+    };
+
+    // 表示順とは逆の順序で作成
+    let qa = status("QA", 2, TaskStatus::InProgress);
+    let mut review = status("Review", 1, TaskStatus::InProgress);
+    for item in [&qa, &review] {
+        status_repo
+            .save(&project_id, item, &user_id, &timestamp)
+            .await?;
+    }
+
+    let all = status_repo.find_all(&project_id).await?;
+    let names: Vec<&str> = all.iter().map(|item| item.name.as_str()).collect();
+    assert_eq!(names, vec!["Review", "QA"]);
+    assert_eq!(all[0].category, TaskStatus::InProgress);
+
+    // 更新
+    review.color = Some("#f59e0b".to_string());
+    review.category = TaskStatus::Waiting;
+    status_repo
+        .save(&project_id, &review, &user_id, &timestamp)
+        .await?;
+    let retrieved = status_repo
+        .find_by_id(&project_id, &review.id)
+        .await?
+        .expect("保存したステータスが取得できること");
+    assert_eq!(retrieved.color.as_deref(), Some("#f59e0b"));
+    assert_eq!(retrieved.category, TaskStatus::Waiting);
+
+    // 他のプロジェクトからは見えない
+    assert!(
+        status_repo
+            .find_all(&ProjectId::from(Uuid::new_v4()))
+            .await?
+            .is_empty()
+    );
+
+    // 削除
+    status_repo.delete(&project_id, &qa.id).await?;
+    assert!(!status_repo.exists(&project_id, &qa.id).await?);
+    assert_eq!(status_repo.count(&project_id).await?, 1);
+
+    Ok(())
+}
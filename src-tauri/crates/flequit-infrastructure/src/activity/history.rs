@@ -0,0 +1,286 @@
+//! Automergeの変更履歴からのアクティビティログ再構築
+//!
+//! 変更を1つ適用するごとのプロジェクトドキュメントの状態を比較し、
+//! プロジェクト本体と各コレクションのエンティティの追加・更新・削除・復元を取り出す。
+
+use chrono::{DateTime, TimeZone, Utc};
+use flequit_core::events::field_changes;
+use flequit_infrastructure_automerge::infrastructure::document::HistoryState;
+use flequit_model::models::activity::activity_entry::{
+    ActivityAction, ActivitySource, FieldChange, NewActivityEntry,
+};
+use flequit_model::types::id_types::{ProjectId, UserId};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// 記録対象のコレクションと、エントリに記録するエンティティの種類・親IDのフィールド
+const COLLECTIONS: &[(&str, &str, Option<&str>)] = &[
+    ("task_lists", "task_list", None),
+    ("tasks", "task", None),
+    ("subtasks", "sub_task", Some("task_id")),
+    ("tags", "tag", None),
+];
+
+/// 変更履歴の各時点の状態から、アクティビティログのエントリを古い順に生成する
+pub fn entries_from_history(
+    project_id: &ProjectId,
+    states: &[HistoryState],
+) -> Vec<NewActivityEntry> {
+    let empty = Map::new();
+    let mut entries = Vec::new();
+    let mut previous = &empty;
+    for history in states {
+        let Value::Object(current) = &history.state else {
+            continue;
+        };
+        let context = DiffContext {
+            project_id,
+            timestamp: history.timestamp,
+        };
+        context.diff_project(previous, current, &mut entries);
+        for (collection, entity, parent_field) in COLLECTIONS {
+            context.diff_collection(
+                entity,
+                *parent_field,
+                items_by_id(previous.get(*collection)),
+                items_by_id(current.get(*collection)),
+                &mut entries,
+            );
+        }
+        previous = current;
+    }
+    entries
+}
+
+struct DiffContext<'a> {
+    project_id: &'a ProjectId,
+    /// 変更の記録日時（エンティティに更新日時がない場合に使う）
+    timestamp: i64,
+}
+
+impl DiffContext<'_> {
+    /// プロジェクト本体（コレクション以外のフィールド）の変更
+    fn diff_project(
+        &self,
+        previous: &Map<String, Value>,
+        current: &Map<String, Value>,
+        entries: &mut Vec<NewActivityEntry>,
+    ) {
+        let previous_fields = scalar_fields(previous);
+        let current_fields = scalar_fields(current);
+        if current_fields.is_empty() {
+            return;
+        }
+        let entity_id = self.project_id.to_string();
+        if previous_fields.is_empty() {
+            entries.push(self.entry(
+                "project",
+                &entity_id,
+                None,
+                ActivityAction::Created,
+                Vec::new(),
+                current,
+            ));
+            return;
+        }
+        if let Some((action, changes)) = classify(&previous_fields, &current_fields) {
+            entries.push(self.entry("project", &entity_id, None, action, changes, current));
+        }
+    }
+
+    /// コレクション内のエンティティの変更
+    fn diff_collection(
+        &self,
+        entity: &str,
+        parent_field: Option<&str>,
+        previous: HashMap<&str, &Map<String, Value>>,
+        current: HashMap<&str, &Map<String, Value>>,
+        entries: &mut Vec<NewActivityEntry>,
+    ) {
+        let related_id = |item: &Map<String, Value>| {
+            parent_field
+                .and_then(|field| item.get(field))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+
+        let mut ids: Vec<&str> = previous.keys().chain(current.keys()).copied().collect();
+        ids.sort_unstable();
+        ids.dedup();
+        for id in ids {
+            let (action, changes, item) = match (previous.get(id), current.get(id)) {
+                (None, Some(item)) => (ActivityAction::Created, Vec::new(), *item),
+                (Some(item), None) => (ActivityAction::Deleted, Vec::new(), *item),
+                (Some(before), Some(after)) => match classify(before, after) {
+                    Some((action, changes)) => (action, changes, *after),
+                    None => continue,
+                },
+                (None, None) => continue,
+            };
+            entries.push(self.entry(entity, id, related_id(item), action, changes, item));
+        }
+    }
+
+    fn entry(
+        &self,
+        entity: &str,
+        entity_id: &str,
+        related_id: Option<String>,
+        action: ActivityAction,
+        changes: Vec<FieldChange>,
+        item: &Map<String, Value>,
+    ) -> NewActivityEntry {
+        NewActivityEntry {
+            project_id: *self.project_id,
+            entity: entity.to_string(),
+            entity_id: entity_id.to_string(),
+            related_id,
+            action,
+            changes,
+            actor: item
+                .get("updated_by")
+                .and_then(Value::as_str)
+                .map(UserId::from),
+            source: ActivitySource::History,
+            occurred_at: item
+                .get("updated_at")
+                .and_then(Value::as_str)
+                .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+                .map(|value| value.with_timezone(&Utc))
+                .or_else(|| Utc.timestamp_millis_opt(self.timestamp).single())
+                .unwrap_or_else(Utc::now),
+        }
+    }
+}
+
+/// 変更前後の状態から変更の種類を判定する（変更がなければ `None`）
+///
+/// 削除フラグの変化は削除・復元として扱い、それ以外のフィールドの変化は更新とする。
+fn classify(
+    before: &Map<String, Value>,
+    after: &Map<String, Value>,
+) -> Option<(ActivityAction, Vec<FieldChange>)> {
+    let changes = field_changes(before, after);
+    if changes.is_empty() {
+        return None;
+    }
+    let is_deleted = |item: &Map<String, Value>| item.get("deleted") == Some(&Value::Bool(true));
+    match (is_deleted(before), is_deleted(after)) {
+        (false, true) => Some((ActivityAction::Deleted, Vec::new())),
+        (true, false) => Some((ActivityAction::Restored, Vec::new())),
+        _ => Some((ActivityAction::Updated, changes)),
+    }
+}
+
+/// コレクション（配列）を除いたフィールド
+fn scalar_fields(root: &Map<String, Value>) -> Map<String, Value> {
+    root.iter()
+        .filter(|(_, value)| !value.is_array())
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+fn items_by_id(collection: Option<&Value>) -> HashMap<&str, &Map<String, Value>> {
+    collection
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|item| {
+            let item = item.as_object()?;
+            Some((item.get("id")?.as_str()?, item))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn state(timestamp: i64, value: Value) -> HistoryState {
+        HistoryState {
+            timestamp,
+            state: value,
+        }
+    }
+
+    #[test]
+    fn test_entries_follow_each_change() {
+        let project_id = ProjectId::new();
+        let user = UserId::new().to_string();
+        let project = |name: &str, tasks: Value, subtasks: Value| {
+            json!({
+                "id": project_id.to_string(),
+                "name": name,
+                "updated_by": user,
+                "tasks": tasks,
+                "subtasks": subtasks,
+            })
+        };
+        let task = |title: &str, deleted: bool| {
+            json!({
+                "id": "task-1",
+                "title": title,
+                "deleted": deleted,
+                "updated_by": user,
+                "updated_at": "2025-07-01T00:00:00Z",
+            })
+        };
+        let subtask = json!({ "id": "subtask-1", "task_id": "task-1", "title": "s" });
+
+        let states = vec![
+            state(1_000, project("p", json!([]), json!([]))),
+            state(
+                2_000,
+                project("p", json!([task("a", false)]), json!([subtask.clone()])),
+            ),
+            state(
+                3_000,
+                project("renamed", json!([task("b", false)]), json!([subtask])),
+            ),
+            state(
+                4_000,
+                project("renamed", json!([task("b", true)]), json!([])),
+            ),
+        ];
+        let entries = entries_from_history(&project_id, &states);
+        let summary: Vec<(&str, &str, ActivityAction)> = entries
+            .iter()
+            .map(|e| (e.entity.as_str(), e.entity_id.as_str(), e.action))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "project",
+                    project_id.to_string().as_str(),
+                    ActivityAction::Created
+                ),
+                ("task", "task-1", ActivityAction::Created),
+                ("sub_task", "subtask-1", ActivityAction::Created),
+                (
+                    "project",
+                    project_id.to_string().as_str(),
+                    ActivityAction::Updated
+                ),
+                ("task", "task-1", ActivityAction::Updated),
+                ("task", "task-1", ActivityAction::Deleted),
+                ("sub_task", "subtask-1", ActivityAction::Deleted),
+            ]
+        );
+
+        assert!(entries.iter().all(|e| e.source == ActivitySource::History));
+        assert_eq!(entries[2].related_id.as_deref(), Some("task-1"));
+        let renamed = &entries[4];
+        assert_eq!(renamed.changes.len(), 1);
+        assert_eq!(renamed.changes[0].from, json!("a"));
+        assert_eq!(renamed.changes[0].to, json!("b"));
+        assert_eq!(renamed.actor.map(|id| id.to_string()), Some(user.clone()));
+        assert_eq!(
+            renamed.occurred_at.to_rfc3339(),
+            "2025-07-01T00:00:00+00:00"
+        );
+        // 更新日時のないエンティティは変更の記録日時を使う
+        assert_eq!(entries[2].occurred_at.timestamp_millis(), 2_000);
+    }
+}
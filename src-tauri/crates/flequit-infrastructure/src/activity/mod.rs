@@ -0,0 +1,9 @@
+//! エンティティの変更履歴（アクティビティログ）
+//!
+//! 通常の変更は作業単位（Unit of Work）の中でドメインイベントから記録される。
+//! このモジュールは、他の端末から取り込んだプロジェクトなどローカルに記録がない場合に、
+//! Automergeの変更履歴からアクティビティログを再構築する。
+
+mod history;
+
+pub use history::entries_from_history;
@@ -0,0 +1,863 @@
+//! バックアップアーカイブの作成・検証・リストア
+//!
+//! アーカイブは `manifest.json` を先頭に持つ tar.gz で、以下のレイアウトを持つ。
+//!
+//! ```text
+//! manifest.json
+//! sqlite/database.sqlite        # VACUUM INTO によるスナップショット
+//! automerge/*.automerge         # .deleted/ と encryption.key.json を含む
+//! settings/settings.yml
+//! ```
+//!
+//! 暗号化されたデータは暗号化されたままアーカイブされる。
+
+use super::error::BackupError;
+use super::manifest::{
+    AUTOMERGE_DIR, BACKUP_FORMAT_VERSION, BackupEntry, BackupManifest, DATABASE_ENTRY,
+    MANIFEST_FILENAME, SETTINGS_ENTRY, checksum_file,
+};
+use crate::unified::manager::get_default_automerge_path;
+use chrono::Utc;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flequit_infrastructure_automerge::infrastructure::document_manager::DocumentManager;
+use flequit_infrastructure_automerge::infrastructure::encryption::PROFILE_KEY_FILENAME;
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+use flequit_infrastructure_sqlite::infrastructure::sqlcipher;
+use flequit_settings::paths::SettingsPaths;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
+
+/// バックアップ対象のパス
+#[derive(Debug, Clone, Default)]
+pub struct BackupPaths {
+    /// SQLiteデータベースファイル
+    pub database_path: Option<PathBuf>,
+    /// Automergeデータディレクトリ
+    pub automerge_dir: Option<PathBuf>,
+    /// 設定ファイル
+    pub settings_path: Option<PathBuf>,
+}
+
+impl BackupPaths {
+    /// アプリケーションのデフォルトパスを解決
+    pub async fn resolve_default() -> Result<Self, BackupError> {
+        let db_manager = DatabaseManager::instance().await?;
+        let database_path = PathBuf::from(db_manager.read().await.database_path());
+        let automerge_dir = get_default_automerge_path().ok_or_else(|| {
+            BackupError::ConfigurationError("Failed to get default Automerge path".to_string())
+        })?;
+
+        Ok(Self {
+            database_path: Some(database_path),
+            automerge_dir: Some(automerge_dir),
+            settings_path: SettingsPaths::get_settings_file_path().ok(),
+        })
+    }
+}
+
+/// プロファイルのバックアップ・リストアを行う
+#[derive(Debug, Clone)]
+pub struct BackupManager {
+    paths: BackupPaths,
+    db_manager: Option<Arc<RwLock<DatabaseManager>>>,
+    document_manager: Option<Arc<Mutex<DocumentManager>>>,
+}
+
+impl BackupManager {
+    /// バックアップ対象のパスを指定して作成
+    pub fn new(paths: BackupPaths) -> Self {
+        Self {
+            paths,
+            db_manager: None,
+            document_manager: None,
+        }
+    }
+
+    /// 使用中のDatabaseManagerを指定
+    ///
+    /// 指定した場合、データベースは `VACUUM INTO` でスナップショットを取得し、
+    /// リストア時は接続プールを閉じてからファイルを差し替える。
+    pub fn with_database_manager(mut self, db_manager: Arc<RwLock<DatabaseManager>>) -> Self {
+        self.db_manager = Some(db_manager);
+        self
+    }
+
+    /// 使用中のDocumentManagerを指定
+    ///
+    /// 指定した場合、読み込み済みのAutomergeドキュメントはファイルをコピーする代わりに
+    /// メモリ上の内容をスナップショットとして書き出す。
+    pub fn with_document_manager(mut self, document_manager: Arc<Mutex<DocumentManager>>) -> Self {
+        self.document_manager = Some(document_manager);
+        self
+    }
+
+    /// バックアップ対象のパス
+    pub fn paths(&self) -> &BackupPaths {
+        &self.paths
+    }
+
+    /// バックアップアーカイブを作成
+    pub async fn create_backup(&self, dest: &Path) -> Result<BackupManifest, BackupError> {
+        if dest.exists() {
+            return Err(BackupError::ConfigurationError(format!(
+                "Backup destination already exists: {:?}",
+                dest
+            )));
+        }
+        let dest_dir = parent_dir(dest);
+        fs::create_dir_all(dest_dir)?;
+
+        let staging = StagingDir::create(dest_dir, "backup-staging")?;
+        let database_encrypted = self.stage_database(staging.path()).await?;
+        let automerge_encrypted = self.stage_automerge(staging.path()).await?;
+        self.stage_settings(staging.path())?;
+
+        let manifest = BackupManifest {
+            format_version: BACKUP_FORMAT_VERSION,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            created_at: Utc::now(),
+            database_encrypted,
+            automerge_encrypted,
+            entries: build_entries(staging.path())?,
+        };
+        write_archive(&manifest, staging.path(), dest)?;
+
+        tracing::info!(
+            "Created backup {:?} ({} files, {} bytes)",
+            dest,
+            manifest.entries.len(),
+            manifest.total_size()
+        );
+        Ok(manifest)
+    }
+
+    /// アーカイブを検証してマニフェストを返す
+    ///
+    /// 形式バージョン、ファイル構成、チェックサムを全て検証する。
+    pub fn verify_backup(archive: &Path) -> Result<BackupManifest, BackupError> {
+        let staging = StagingDir::create(&std::env::temp_dir(), "backup-verify")?;
+        extract_and_verify(archive, staging.path())
+    }
+
+    /// アーカイブからリストア
+    ///
+    /// アーカイブを検証後、現在のデータを退避してから差し替える。
+    /// 戻り値の `PendingRestore` で、再読み込み成功後に `commit`、失敗時に `rollback` を行う。
+    /// 呼び出し側はリストア中に他の読み書きが発生しないようにすること。
+    pub async fn restore_backup(&self, archive: &Path) -> Result<PendingRestore, BackupError> {
+        let restore_root = self.restore_root()?;
+        fs::create_dir_all(&restore_root)?;
+
+        let staging = StagingDir::create(&restore_root, "restore-staging")?;
+        let manifest = extract_and_verify(archive, staging.path())?;
+
+        if let Some(db_manager) = &self.db_manager {
+            if manifest.database_encrypted && !db_manager.read().await.is_encryption_enabled() {
+                return Err(BackupError::ConfigurationError(
+                    "The database in this backup is encrypted; unlock the database before restoring"
+                        .to_string(),
+                ));
+            }
+            // ファイルを差し替える前に接続プールを閉じる
+            db_manager.write().await.close_connection().await?;
+        }
+
+        let rollback_dir = unique_dir_path(&restore_root, "restore-rollback");
+        fs::create_dir_all(&rollback_dir)?;
+        let mut pending = PendingRestore {
+            manifest,
+            applied: Vec::new(),
+            rollback_dir,
+        };
+
+        if let Err(e) = self.swap_in(staging.path(), &mut pending) {
+            if let Err(undo_error) = pending.undo() {
+                tracing::error!("Failed to roll back restore: {}", undo_error);
+            }
+            return Err(BackupError::RestoreFailed(e.to_string()));
+        }
+
+        tracing::info!("Restored backup from {:?}", archive);
+        Ok(pending)
+    }
+
+    async fn stage_database(&self, staging: &Path) -> Result<bool, BackupError> {
+        let target = staging.join(DATABASE_ENTRY);
+
+        if let Some(db_manager) = &self.db_manager {
+            fs::create_dir_all(parent_dir(&target))?;
+            let db_manager = db_manager.read().await;
+            db_manager.snapshot_to(&target).await?;
+            return Ok(db_manager.is_encryption_enabled());
+        }
+
+        match &self.paths.database_path {
+            Some(path) if path.exists() => {
+                fs::create_dir_all(parent_dir(&target))?;
+                fs::copy(path, &target)?;
+                Ok(!sqlcipher::is_plaintext_database(path)?)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn stage_automerge(&self, staging: &Path) -> Result<bool, BackupError> {
+        let Some(dir) = self.paths.automerge_dir.as_ref().filter(|d| d.exists()) else {
+            return Ok(false);
+        };
+
+        for relative in collect_files(dir)? {
+            // 書き込み途中の一時ファイルは除外
+            if relative.extension().is_some_and(|ext| ext == "tmp") {
+                continue;
+            }
+            let target = staging.join(AUTOMERGE_DIR).join(&relative);
+            fs::create_dir_all(parent_dir(&target))?;
+            fs::copy(dir.join(&relative), &target)?;
+        }
+
+        // 追記途中の状態を含まないよう、読み込み済みのドキュメントはメモリ上の内容で上書きする
+        if let Some(document_manager) = &self.document_manager {
+            document_manager
+                .lock()
+                .await
+                .snapshot_documents_to(&staging.join(AUTOMERGE_DIR))
+                .await?;
+        }
+
+        Ok(dir.join(PROFILE_KEY_FILENAME).exists())
+    }
+
+    fn stage_settings(&self, staging: &Path) -> Result<(), BackupError> {
+        if let Some(path) = self.paths.settings_path.as_ref().filter(|p| p.exists()) {
+            let target = staging.join(SETTINGS_ENTRY);
+            fs::create_dir_all(parent_dir(&target))?;
+            fs::copy(path, &target)?;
+        }
+        Ok(())
+    }
+
+    fn swap_in(&self, staging: &Path, pending: &mut PendingRestore) -> Result<(), BackupError> {
+        if let Some(database_path) = &self.paths.database_path
+            && pending.manifest.has_database()
+        {
+            // 古いジャーナルが新しいデータベースに適用されないよう退避
+            for suffix in ["-wal", "-shm", "-journal"] {
+                let sidecar = sidecar_path(database_path, suffix);
+                if sidecar.exists() {
+                    pending.replace(&sidecar, None)?;
+                }
+            }
+            pending.replace(database_path, Some(&staging.join(DATABASE_ENTRY)))?;
+        }
+
+        if let Some(automerge_dir) = &self.paths.automerge_dir {
+            let staged = staging.join(AUTOMERGE_DIR);
+            fs::create_dir_all(&staged)?;
+            pending.replace(automerge_dir, Some(&staged))?;
+        }
+
+        if let Some(settings_path) = &self.paths.settings_path
+            && pending.manifest.has_settings()
+        {
+            pending.replace(settings_path, Some(&staging.join(SETTINGS_ENTRY)))?;
+        }
+
+        Ok(())
+    }
+
+    /// リストア用の作業ディレクトリを作成する場所
+    ///
+    /// 差し替えをリネームで行えるよう、データと同じディレクトリを使用する。
+    fn restore_root(&self) -> Result<PathBuf, BackupError> {
+        self.paths
+            .automerge_dir
+            .as_ref()
+            .or(self.paths.database_path.as_ref())
+            .map(|p| parent_dir(p).to_path_buf())
+            .ok_or_else(|| {
+                BackupError::ConfigurationError("No data paths configured for restore".to_string())
+            })
+    }
+}
+
+/// 差し替え済みで確定前のリストア
+#[derive(Debug)]
+pub struct PendingRestore {
+    manifest: BackupManifest,
+    applied: Vec<AppliedSwap>,
+    rollback_dir: PathBuf,
+}
+
+#[derive(Debug)]
+struct AppliedSwap {
+    target: PathBuf,
+    original: Option<PathBuf>,
+}
+
+impl PendingRestore {
+    /// リストアしたアーカイブのマニフェスト
+    pub fn manifest(&self) -> &BackupManifest {
+        &self.manifest
+    }
+
+    /// リストアを確定し、退避した元データを削除
+    pub fn commit(self) -> Result<BackupManifest, BackupError> {
+        remove_path(&self.rollback_dir)?;
+        Ok(self.manifest)
+    }
+
+    /// 退避した元データに戻す
+    pub fn rollback(mut self) -> Result<(), BackupError> {
+        self.undo()
+    }
+
+    fn replace(&mut self, target: &Path, replacement: Option<&Path>) -> Result<(), BackupError> {
+        let original = if target.exists() {
+            let original = self.rollback_dir.join(self.applied.len().to_string());
+            move_path(target, &original)?;
+            Some(original)
+        } else {
+            None
+        };
+        self.applied.push(AppliedSwap {
+            target: target.to_path_buf(),
+            original,
+        });
+
+        if let Some(replacement) = replacement {
+            move_path(replacement, target)?;
+        }
+        Ok(())
+    }
+
+    fn undo(&mut self) -> Result<(), BackupError> {
+        while let Some(swap) = self.applied.pop() {
+            remove_path(&swap.target)?;
+            if let Some(original) = swap.original {
+                move_path(&original, &swap.target)?;
+            }
+        }
+        remove_path(&self.rollback_dir)
+    }
+}
+
+/// 処理終了時に削除される作業ディレクトリ
+struct StagingDir(PathBuf);
+
+impl StagingDir {
+    fn create(parent: &Path, label: &str) -> Result<Self, BackupError> {
+        let path = unique_dir_path(parent, label);
+        fs::create_dir_all(&path)?;
+        Ok(Self(path))
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for StagingDir {
+    fn drop(&mut self) {
+        if let Err(e) = remove_path(&self.0) {
+            tracing::warn!("Failed to remove staging directory {:?}: {}", self.0, e);
+        }
+    }
+}
+
+fn unique_dir_path(parent: &Path, label: &str) -> PathBuf {
+    parent.join(format!(
+        ".flequit-{}-{}",
+        label,
+        Utc::now().format("%Y%m%d%H%M%S%6f")
+    ))
+}
+
+fn parent_dir(path: &Path) -> &Path {
+    path.parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."))
+}
+
+fn sidecar_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(suffix);
+    path.with_file_name(file_name)
+}
+
+/// ディレクトリ配下のファイルを相対パスで列挙（ソート済み）
+fn collect_files(root: &Path) -> Result<Vec<PathBuf>, BackupError> {
+    fn walk(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), BackupError> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                walk(root, &path, files)?;
+            } else if path.is_file() {
+                files.push(path.strip_prefix(root).unwrap_or(&path).to_path_buf());
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    walk(root, root, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn build_entries(staging: &Path) -> Result<Vec<BackupEntry>, BackupError> {
+    collect_files(staging)?
+        .into_iter()
+        .map(|relative| {
+            let (size, sha256) = checksum_file(&staging.join(&relative))?;
+            Ok(BackupEntry {
+                path: archive_path(&relative)?,
+                size,
+                sha256,
+            })
+        })
+        .collect()
+}
+
+/// アーカイブ内パスへ変換（通常のパス要素のみ許可）
+fn archive_path(path: &Path) -> Result<String, BackupError> {
+    let mut parts = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy().to_string()),
+            Component::CurDir => {}
+            _ => {
+                return Err(BackupError::InvalidArchive(format!(
+                    "Unsafe path in archive: {:?}",
+                    path
+                )));
+            }
+        }
+    }
+    if parts.is_empty() {
+        return Err(BackupError::InvalidArchive(
+            "Empty path in archive".to_string(),
+        ));
+    }
+    Ok(parts.join("/"))
+}
+
+fn write_archive(
+    manifest: &BackupManifest,
+    staging: &Path,
+    dest: &Path,
+) -> Result<(), BackupError> {
+    let partial = sidecar_path(dest, ".partial");
+    let result = (|| -> Result<(), BackupError> {
+        let file = fs::File::create(&partial)?;
+        let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+
+        let manifest_json = serde_json::to_vec_pretty(manifest)?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(manifest_json.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(manifest.created_at.timestamp().max(0) as u64);
+        header.set_cksum();
+        builder.append_data(&mut header, MANIFEST_FILENAME, manifest_json.as_slice())?;
+
+        for entry in &manifest.entries {
+            builder.append_path_with_name(staging.join(&entry.path), &entry.path)?;
+        }
+
+        let file = builder.into_inner()?.finish()?;
+        file.sync_all()?;
+        Ok(())
+    })();
+
+    match result {
+        Ok(()) => {
+            fs::rename(&partial, dest)?;
+            Ok(())
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&partial);
+            Err(e)
+        }
+    }
+}
+
+/// アーカイブを展開し、マニフェストと内容を検証
+fn extract_and_verify(archive: &Path, staging: &Path) -> Result<BackupManifest, BackupError> {
+    let invalid = |e: std::io::Error| BackupError::InvalidArchive(e.to_string());
+
+    let file = fs::File::open(archive)?;
+    let mut tar = tar::Archive::new(GzDecoder::new(file));
+    let mut manifest_json: Option<Vec<u8>> = None;
+    let mut extracted = BTreeSet::new();
+
+    for entry in tar.entries().map_err(invalid)? {
+        let mut entry = entry.map_err(invalid)?;
+        let path = entry.path().map_err(invalid)?.into_owned();
+        let name = archive_path(&path)?;
+
+        match entry.header().entry_type() {
+            tar::EntryType::Directory => continue,
+            tar::EntryType::Regular => {}
+            other => {
+                return Err(BackupError::InvalidArchive(format!(
+                    "Unsupported entry type {:?}: {}",
+                    other, name
+                )));
+            }
+        }
+
+        if name == MANIFEST_FILENAME {
+            let mut buffer = Vec::new();
+            entry.read_to_end(&mut buffer).map_err(invalid)?;
+            manifest_json = Some(buffer);
+            continue;
+        }
+
+        if !extracted.insert(name.clone()) {
+            return Err(BackupError::InvalidArchive(format!(
+                "Duplicate entry: {}",
+                name
+            )));
+        }
+        let target = staging.join(&name);
+        fs::create_dir_all(parent_dir(&target))?;
+        entry.unpack(&target).map_err(invalid)?;
+    }
+
+    let manifest_json = manifest_json
+        .ok_or_else(|| BackupError::InvalidArchive(format!("{} not found", MANIFEST_FILENAME)))?;
+
+    // 形式バージョンを先に確認（将来の形式は構造が異なる可能性がある）
+    let raw: serde_json::Value = serde_json::from_slice(&manifest_json)?;
+    let format_version = raw
+        .get("format_version")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| BackupError::InvalidArchive("format_version is missing".to_string()))?;
+    if format_version != BACKUP_FORMAT_VERSION as u64 {
+        return Err(BackupError::IncompatibleVersion {
+            found: u32::try_from(format_version).unwrap_or(u32::MAX),
+            supported: BACKUP_FORMAT_VERSION,
+        });
+    }
+    let manifest: BackupManifest = serde_json::from_value(raw)?;
+    manifest.ensure_compatible()?;
+
+    let mut expected = BTreeSet::new();
+    for entry in &manifest.entries {
+        if archive_path(Path::new(&entry.path))? != entry.path || !expected.insert(&entry.path) {
+            return Err(BackupError::InvalidArchive(format!(
+                "Invalid manifest entry: {}",
+                entry.path
+            )));
+        }
+        if !extracted.contains(&entry.path) {
+            return Err(BackupError::InvalidArchive(format!(
+                "Missing file: {}",
+                entry.path
+            )));
+        }
+        let (size, sha256) = checksum_file(&staging.join(&entry.path))?;
+        if size != entry.size || sha256 != entry.sha256 {
+            return Err(BackupError::ChecksumMismatch(entry.path.clone()));
+        }
+    }
+    if let Some(unexpected) = extracted.iter().find(|name| !expected.contains(name)) {
+        return Err(BackupError::InvalidArchive(format!(
+            "File not listed in manifest: {}",
+            unexpected
+        )));
+    }
+
+    Ok(manifest)
+}
+
+/// リネームで移動し、別ファイルシステム間の場合はコピーして削除
+fn move_path(from: &Path, to: &Path) -> Result<(), BackupError> {
+    fs::create_dir_all(parent_dir(to))?;
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    copy_path(from, to)?;
+    remove_path(from)
+}
+
+fn copy_path(from: &Path, to: &Path) -> Result<(), BackupError> {
+    if from.is_dir() {
+        fs::create_dir_all(to)?;
+        for relative in collect_files(from)? {
+            let target = to.join(&relative);
+            fs::create_dir_all(parent_dir(&target))?;
+            fs::copy(from.join(&relative), target)?;
+        }
+    } else {
+        fs::copy(from, to)?;
+    }
+    Ok(())
+}
+
+fn remove_path(path: &Path) -> Result<(), BackupError> {
+    if path.is_dir() {
+        fs::remove_dir_all(path)?;
+    } else if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flequit_testing::TestPathGenerator;
+
+    fn write(path: &Path, contents: &[u8]) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn test_paths(root: &Path) -> BackupPaths {
+        BackupPaths {
+            database_path: Some(root.join("data/database.sqlite")),
+            automerge_dir: Some(root.join("data/automerge")),
+            settings_path: Some(root.join("config/settings.yml")),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backup_and_restore_roundtrip() {
+        let root =
+            TestPathGenerator::generate_test_dir(file!(), "test_backup_and_restore_roundtrip");
+        let paths = test_paths(&root);
+        let automerge_dir = paths.automerge_dir.clone().unwrap();
+        write(paths.database_path.as_ref().unwrap(), b"sqlite bytes");
+        write(&automerge_dir.join("user.automerge"), b"user doc");
+        write(
+            &automerge_dir.join(".deleted/project_1.automerge"),
+            b"deleted doc",
+        );
+        write(
+            &automerge_dir.join("project_2.automerge.tmp"),
+            b"partial write",
+        );
+        write(paths.settings_path.as_ref().unwrap(), b"theme: dark\n");
+
+        let manager = BackupManager::new(paths.clone());
+        let archive = root.join("backups/backup.flqbackup");
+        let manifest = manager.create_backup(&archive).await.unwrap();
+        assert_eq!(manifest.format_version, BACKUP_FORMAT_VERSION);
+        assert!(manifest.has_database());
+        assert!(manifest.has_settings());
+        assert!(!manifest.automerge_encrypted);
+        assert_eq!(manifest.entries.len(), 4);
+        assert_eq!(BackupManager::verify_backup(&archive).unwrap(), manifest);
+
+        // バックアップ後の変更
+        write(paths.database_path.as_ref().unwrap(), b"changed");
+        write(&automerge_dir.join("user.automerge"), b"changed");
+        write(&automerge_dir.join("project_3.automerge"), b"new doc");
+        write(paths.settings_path.as_ref().unwrap(), b"theme: light\n");
+
+        let pending = manager.restore_backup(&archive).await.unwrap();
+        pending.commit().unwrap();
+
+        assert_eq!(
+            fs::read(paths.database_path.as_ref().unwrap()).unwrap(),
+            b"sqlite bytes"
+        );
+        assert_eq!(
+            fs::read(automerge_dir.join("user.automerge")).unwrap(),
+            b"user doc"
+        );
+        assert_eq!(
+            fs::read(automerge_dir.join(".deleted/project_1.automerge")).unwrap(),
+            b"deleted doc"
+        );
+        assert!(!automerge_dir.join("project_3.automerge").exists());
+        assert_eq!(
+            fs::read(paths.settings_path.as_ref().unwrap()).unwrap(),
+            b"theme: dark\n"
+        );
+        // 作業ディレクトリが残っていない
+        let leftovers: Vec<_> = fs::read_dir(root.join("data"))
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name().to_string_lossy().starts_with(".flequit-"))
+            .collect();
+        assert!(leftovers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_restore_rollback() {
+        let root = TestPathGenerator::generate_test_dir(file!(), "test_restore_rollback");
+        let paths = test_paths(&root);
+        let automerge_dir = paths.automerge_dir.clone().unwrap();
+        write(&automerge_dir.join("user.automerge"), b"old");
+
+        let manager = BackupManager::new(paths.clone());
+        let archive = root.join("backup.flqbackup");
+        manager.create_backup(&archive).await.unwrap();
+
+        write(&automerge_dir.join("user.automerge"), b"current");
+        let pending = manager.restore_backup(&archive).await.unwrap();
+        assert_eq!(
+            fs::read(automerge_dir.join("user.automerge")).unwrap(),
+            b"old"
+        );
+        pending.rollback().unwrap();
+        assert_eq!(
+            fs::read(automerge_dir.join("user.automerge")).unwrap(),
+            b"current"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backup_snapshots_loaded_documents() {
+        use flequit_infrastructure_automerge::infrastructure::document_manager::DocumentType;
+
+        let root =
+            TestPathGenerator::generate_test_dir(file!(), "test_backup_snapshots_loaded_documents");
+        let paths = test_paths(&root);
+        let automerge_dir = paths.automerge_dir.clone().unwrap();
+
+        let mut document_manager = DocumentManager::new(&automerge_dir).unwrap();
+        let data = serde_json::json!({ "theme": "dark" });
+        document_manager
+            .save_document(&DocumentType::Settings, &data)
+            .await
+            .unwrap();
+        // Repoによる追記の途中で読み取られたファイルを再現
+        write(&automerge_dir.join("settings.automerge"), b"partial write");
+
+        let document_manager = Arc::new(Mutex::new(document_manager));
+        let archive = root.join("backup.flqbackup");
+        BackupManager::new(paths.clone())
+            .with_document_manager(document_manager.clone())
+            .create_backup(&archive)
+            .await
+            .unwrap();
+        document_manager.lock().await.shutdown().await.unwrap();
+
+        BackupManager::new(paths.clone())
+            .restore_backup(&archive)
+            .await
+            .unwrap()
+            .commit()
+            .unwrap();
+        let mut restored = DocumentManager::new(&automerge_dir).unwrap();
+        let loaded: Option<serde_json::Value> = restored
+            .load_document(&DocumentType::Settings)
+            .await
+            .unwrap();
+        assert_eq!(loaded, Some(data));
+        restored.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_tampered_and_incompatible_archives() {
+        let root = TestPathGenerator::generate_test_dir(
+            file!(),
+            "test_verify_rejects_tampered_and_incompatible_archives",
+        );
+        let paths = test_paths(&root);
+        write(
+            &paths.automerge_dir.as_ref().unwrap().join("user.automerge"),
+            b"user doc",
+        );
+        let archive = root.join("backup.flqbackup");
+        let manifest = BackupManager::new(paths.clone())
+            .create_backup(&archive)
+            .await
+            .unwrap();
+
+        // チェックサム不一致
+        let tampered_dir = root.join("tampered");
+        write(&tampered_dir.join("automerge/user.automerge"), b"evil doc");
+        let tampered = root.join("tampered.flqbackup");
+        write_archive(&manifest, &tampered_dir, &tampered).unwrap();
+        assert!(matches!(
+            BackupManager::verify_backup(&tampered),
+            Err(BackupError::ChecksumMismatch(_))
+        ));
+
+        // 未対応の形式バージョン
+        let mut future = manifest.clone();
+        future.format_version = BACKUP_FORMAT_VERSION + 1;
+        let staged_dir = root.join("future");
+        write(&staged_dir.join("automerge/user.automerge"), b"user doc");
+        let future_archive = root.join("future.flqbackup");
+        write_archive(&future, &staged_dir, &future_archive).unwrap();
+        assert!(matches!(
+            BackupManager::verify_backup(&future_archive),
+            Err(BackupError::IncompatibleVersion { .. })
+        ));
+
+        // 失敗したリストアはデータに触れない
+        assert!(
+            BackupManager::new(paths.clone())
+                .restore_backup(&tampered)
+                .await
+                .is_err()
+        );
+        assert_eq!(
+            fs::read(paths.automerge_dir.as_ref().unwrap().join("user.automerge")).unwrap(),
+            b"user doc"
+        );
+    }
+
+    #[cfg(feature = "sqlcipher")]
+    #[tokio::test]
+    async fn test_backup_encrypted_database_snapshot() {
+        use flequit_infrastructure_sqlite::infrastructure::sqlcipher::SqlCipherKey;
+
+        let root = TestPathGenerator::generate_test_dir(
+            file!(),
+            "test_backup_encrypted_database_snapshot",
+        );
+        let paths = test_paths(&root);
+        let database_path = paths.database_path.clone().unwrap();
+        fs::create_dir_all(database_path.parent().unwrap()).unwrap();
+
+        let mut db_manager =
+            DatabaseManager::new_for_test(database_path.to_string_lossy().to_string());
+        db_manager
+            .set_encryption_key(SqlCipherKey::raw([3u8; 32]))
+            .unwrap();
+        let db_manager = Arc::new(RwLock::new(db_manager));
+        db_manager.read().await.get_connection().await.unwrap();
+
+        let manager = BackupManager::new(paths).with_database_manager(db_manager.clone());
+        let archive = root.join("backup.flqbackup");
+        let manifest = manager.create_backup(&archive).await.unwrap();
+        assert!(manifest.database_encrypted);
+
+        // スナップショットも暗号化されたままアーカイブされる
+        let staging = root.join("extracted");
+        fs::create_dir_all(&staging).unwrap();
+        extract_and_verify(&archive, &staging).unwrap();
+        assert!(!sqlcipher::is_plaintext_database(staging.join(DATABASE_ENTRY)).unwrap());
+
+        // リストア後は同じ鍵で再接続できる
+        manager
+            .restore_backup(&archive)
+            .await
+            .unwrap()
+            .commit()
+            .unwrap();
+        db_manager.read().await.get_connection().await.unwrap();
+    }
+
+    #[test]
+    fn test_archive_path_rejects_unsafe_paths() {
+        assert_eq!(
+            archive_path(Path::new("automerge/user.automerge")).unwrap(),
+            "automerge/user.automerge"
+        );
+        assert!(archive_path(Path::new("../etc/passwd")).is_err());
+        assert!(archive_path(Path::new("/etc/passwd")).is_err());
+    }
+}
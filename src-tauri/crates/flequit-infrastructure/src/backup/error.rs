@@ -0,0 +1,52 @@
+//! バックアップ・リストアのエラー定義
+
+use flequit_infrastructure_automerge::errors::automerge_error::AutomergeError;
+use flequit_infrastructure_sqlite::errors::sqlite_error::SQLiteError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BackupError {
+    #[error("IO error: {0}")]
+    IOError(String),
+
+    #[error("Invalid backup archive: {0}")]
+    InvalidArchive(String),
+
+    #[error("Checksum mismatch: {0}")]
+    ChecksumMismatch(String),
+
+    #[error("Incompatible backup format version {found} (supported: {supported})")]
+    IncompatibleVersion { found: u32, supported: u32 },
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+
+    #[error("Configuration error: {0}")]
+    ConfigurationError(String),
+
+    #[error("Restore failed: {0}")]
+    RestoreFailed(String),
+}
+
+impl From<std::io::Error> for BackupError {
+    fn from(err: std::io::Error) -> Self {
+        BackupError::IOError(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for BackupError {
+    fn from(err: serde_json::Error) -> Self {
+        BackupError::InvalidArchive(format!("Invalid manifest: {}", err))
+    }
+}
+
+impl From<AutomergeError> for BackupError {
+    fn from(err: AutomergeError) -> Self {
+        BackupError::IOError(err.to_string())
+    }
+}
+
+impl From<SQLiteError> for BackupError {
+    fn from(err: SQLiteError) -> Self {
+        BackupError::DatabaseError(err.to_string())
+    }
+}
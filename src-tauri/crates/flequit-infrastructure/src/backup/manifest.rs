@@ -0,0 +1,100 @@
+//! バックアップアーカイブのマニフェスト
+
+use super::error::BackupError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::Read;
+use std::path::Path;
+
+/// 現在のバックアップ形式バージョン
+///
+/// アーカイブのレイアウトやマニフェストの互換性が失われる変更を行った場合に上げる。
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// アーカイブ内のマニフェストファイル名
+pub const MANIFEST_FILENAME: &str = "manifest.json";
+
+/// アーカイブ内のSQLiteデータベースのパス
+pub(crate) const DATABASE_ENTRY: &str = "sqlite/database.sqlite";
+
+/// アーカイブ内のAutomergeデータのディレクトリ
+pub(crate) const AUTOMERGE_DIR: &str = "automerge";
+
+/// アーカイブ内の設定ファイルのパス
+pub(crate) const SETTINGS_ENTRY: &str = "settings/settings.yml";
+
+/// アーカイブに含まれるファイル
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupEntry {
+    /// アーカイブ内の相対パス（`/` 区切り）
+    pub path: String,
+    /// ファイルサイズ（バイト）
+    pub size: u64,
+    /// SHA-256チェックサム（hex）
+    pub sha256: String,
+}
+
+/// バックアップアーカイブのマニフェスト
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// バックアップ形式バージョン
+    pub format_version: u32,
+    /// バックアップを作成したアプリケーションのバージョン
+    pub app_version: String,
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+    /// SQLiteデータベースがSQLCipherで暗号化されているか
+    pub database_encrypted: bool,
+    /// Automergeドキュメントが暗号化されているか
+    pub automerge_encrypted: bool,
+    /// 含まれるファイル一覧
+    pub entries: Vec<BackupEntry>,
+}
+
+impl BackupManifest {
+    /// 現在のバージョンで復元可能か検証
+    pub fn ensure_compatible(&self) -> Result<(), BackupError> {
+        if self.format_version != BACKUP_FORMAT_VERSION {
+            return Err(BackupError::IncompatibleVersion {
+                found: self.format_version,
+                supported: BACKUP_FORMAT_VERSION,
+            });
+        }
+        Ok(())
+    }
+
+    /// SQLiteデータベースを含むか
+    pub fn has_database(&self) -> bool {
+        self.entries.iter().any(|e| e.path == DATABASE_ENTRY)
+    }
+
+    /// 設定ファイルを含むか
+    pub fn has_settings(&self) -> bool {
+        self.entries.iter().any(|e| e.path == SETTINGS_ENTRY)
+    }
+
+    /// 含まれるファイルの合計サイズ
+    pub fn total_size(&self) -> u64 {
+        self.entries.iter().map(|e| e.size).sum()
+    }
+}
+
+/// ファイルのサイズとSHA-256チェックサムを計算
+pub(crate) fn checksum_file(path: &Path) -> Result<(u64, String), BackupError> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    let mut size = 0u64;
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        size += read as u64;
+    }
+
+    Ok((size, hex::encode(hasher.finalize())))
+}
@@ -0,0 +1,20 @@
+//! プロファイルのバックアップ・リストア
+//!
+//! SQLiteデータベース、Automergeドキュメント（`.deleted/` と鍵ファイルを含む）、
+//! 設定ファイルを1つのバージョン付きアーカイブにまとめる。
+//! アーカイブにはマニフェストと各ファイルのチェックサムが含まれ、
+//! リストア時に検証してから現在のデータと安全に差し替える。
+
+mod archive;
+mod error;
+mod manifest;
+mod scheduler;
+
+pub use archive::{BackupManager, BackupPaths, PendingRestore};
+pub use error::BackupError;
+pub use manifest::{BACKUP_FORMAT_VERSION, BackupEntry, BackupManifest, MANIFEST_FILENAME};
+pub use scheduler::{
+    BACKUP_FILE_EXTENSION, BACKUP_FILE_PREFIX, BackupFileInfo, BackupScheduleConfig,
+    BackupScheduler, backup_file_name, get_default_backup_dir, list_backups, resolve_backup_dir,
+    rotate_backups,
+};
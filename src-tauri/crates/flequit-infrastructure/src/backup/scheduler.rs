@@ -0,0 +1,244 @@
+//! 定期バックアップとローテーション
+//!
+//! バックアップディレクトリに `flequit-backup-YYYYMMDD-HHMMSS.flqbackup` 形式で保存し、
+//! 保持数を超えた古いバックアップを削除する。
+
+use super::error::BackupError;
+use super::manifest::BackupManifest;
+use crate::InfrastructureRepositories;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use flequit_settings::BackupSettings;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+
+/// バックアップファイル名の接頭辞
+pub const BACKUP_FILE_PREFIX: &str = "flequit-backup-";
+
+/// バックアップファイルの拡張子
+pub const BACKUP_FILE_EXTENSION: &str = "flqbackup";
+
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// 定期バックアップの設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupScheduleConfig {
+    /// バックアップの保存先ディレクトリ
+    pub backup_dir: PathBuf,
+    /// バックアップ間隔
+    pub interval: Duration,
+    /// 保持するバックアップ数
+    pub keep: usize,
+}
+
+impl BackupScheduleConfig {
+    /// 設定から定期バックアップの設定を作成
+    ///
+    /// 定期バックアップが無効な場合、または保存先を決められない場合はNoneを返す。
+    pub fn from_settings(settings: &BackupSettings) -> Option<Self> {
+        if !settings.enabled {
+            return None;
+        }
+        Some(Self {
+            backup_dir: resolve_backup_dir(settings)?,
+            interval: Duration::from_secs(u64::from(settings.interval_hours) * 60 * 60),
+            keep: settings.keep as usize,
+        })
+    }
+}
+
+/// 設定の保存先、未指定の場合はデフォルトのバックアップ保存先を返す
+pub fn resolve_backup_dir(settings: &BackupSettings) -> Option<PathBuf> {
+    match &settings.directory {
+        Some(directory) => Some(PathBuf::from(directory)),
+        None => get_default_backup_dir(),
+    }
+}
+
+/// デフォルトのバックアップ保存先: ~/.local/share/flequit/backups/
+pub fn get_default_backup_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|data_dir| data_dir.join("flequit").join("backups"))
+}
+
+/// バックアップディレクトリ内のバックアップファイル情報
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupFileInfo {
+    pub path: PathBuf,
+    pub size: u64,
+    /// ファイル名から求めた作成日時
+    pub created_at: DateTime<Utc>,
+}
+
+/// 作成日時からバックアップファイル名を生成
+pub fn backup_file_name(created_at: DateTime<Utc>) -> String {
+    format!(
+        "{}{}.{}",
+        BACKUP_FILE_PREFIX,
+        created_at.format(BACKUP_TIMESTAMP_FORMAT),
+        BACKUP_FILE_EXTENSION
+    )
+}
+
+fn parse_backup_file_name(file_name: &str) -> Option<DateTime<Utc>> {
+    let timestamp = file_name
+        .strip_prefix(BACKUP_FILE_PREFIX)?
+        .strip_suffix(BACKUP_FILE_EXTENSION)?
+        .strip_suffix('.')?;
+    NaiveDateTime::parse_from_str(timestamp, BACKUP_TIMESTAMP_FORMAT)
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+/// バックアップディレクトリ内のバックアップを新しい順に列挙
+pub fn list_backups(backup_dir: &Path) -> Result<Vec<BackupFileInfo>, BackupError> {
+    if !backup_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups = Vec::new();
+    for entry in std::fs::read_dir(backup_dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some(created_at) = parse_backup_file_name(&file_name) else {
+            continue;
+        };
+        let metadata = entry.metadata()?;
+        if metadata.is_file() {
+            backups.push(BackupFileInfo {
+                path: entry.path(),
+                size: metadata.len(),
+                created_at,
+            });
+        }
+    }
+
+    backups.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(backups)
+}
+
+/// 保持数を超えた古いバックアップを削除し、削除したパスを返す
+pub fn rotate_backups(backup_dir: &Path, keep: usize) -> Result<Vec<PathBuf>, BackupError> {
+    let mut removed = Vec::new();
+    for backup in list_backups(backup_dir)?.into_iter().skip(keep) {
+        std::fs::remove_file(&backup.path)?;
+        removed.push(backup.path);
+    }
+    Ok(removed)
+}
+
+/// 定期バックアップの実行
+pub struct BackupScheduler;
+
+impl BackupScheduler {
+    /// 設定に従ってバックアップを1回作成し、ローテーションを行う
+    pub async fn run_once(
+        repositories: &InfrastructureRepositories,
+        config: &BackupScheduleConfig,
+    ) -> Result<(PathBuf, BackupManifest), BackupError> {
+        let dest = config.backup_dir.join(backup_file_name(Utc::now()));
+        let manifest = repositories.create_backup(&dest).await?;
+
+        for removed in rotate_backups(&config.backup_dir, config.keep)? {
+            tracing::info!("Removed old backup: {:?}", removed);
+        }
+        Ok((dest, manifest))
+    }
+
+    /// 定期バックアップタスクを開始
+    ///
+    /// 最新のバックアップからの経過時間を考慮して初回の実行時刻を決めるため、
+    /// アプリケーションを再起動しても間隔が保たれる。
+    /// バックアップ中はリポジトリの読み取りロックを保持し、リストアと排他になる。
+    pub fn spawn(
+        repositories: Arc<RwLock<InfrastructureRepositories>>,
+        config: BackupScheduleConfig,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(Self::delay_until_next(&config)).await;
+
+                let repositories = repositories.read().await;
+                match Self::run_once(&repositories, &config).await {
+                    Ok((path, _)) => tracing::info!("Scheduled backup created: {:?}", path),
+                    Err(e) => {
+                        tracing::error!("Scheduled backup failed: {}", e);
+                        // 失敗時も次回の間隔まで待機する
+                        drop(repositories);
+                        tokio::time::sleep(config.interval).await;
+                    }
+                }
+            }
+        })
+    }
+
+    fn delay_until_next(config: &BackupScheduleConfig) -> Duration {
+        let latest = list_backups(&config.backup_dir)
+            .ok()
+            .and_then(|backups| backups.into_iter().next());
+        match latest {
+            Some(latest) => {
+                let elapsed = (Utc::now() - latest.created_at)
+                    .to_std()
+                    .unwrap_or(Duration::ZERO);
+                config.interval.saturating_sub(elapsed)
+            }
+            None => Duration::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flequit_testing::TestPathGenerator;
+
+    #[test]
+    fn test_list_and_rotate_backups() {
+        let dir = TestPathGenerator::generate_test_dir(file!(), "test_list_and_rotate_backups");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let base = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        for day in 0..5 {
+            let name = backup_file_name(base + chrono::Duration::days(day));
+            std::fs::write(dir.join(name), b"backup").unwrap();
+        }
+        std::fs::write(dir.join("unrelated.txt"), b"keep me").unwrap();
+
+        let backups = list_backups(&dir).unwrap();
+        assert_eq!(backups.len(), 5);
+        assert_eq!(backups[0].created_at, base + chrono::Duration::days(4));
+
+        let removed = rotate_backups(&dir, 3).unwrap();
+        assert_eq!(removed.len(), 2);
+        let remaining = list_backups(&dir).unwrap();
+        assert_eq!(remaining.len(), 3);
+        assert_eq!(remaining[2].created_at, base + chrono::Duration::days(2));
+        assert!(dir.join("unrelated.txt").exists());
+    }
+
+    #[test]
+    fn test_schedule_config_from_settings() {
+        let mut settings = BackupSettings {
+            enabled: true,
+            interval_hours: 6,
+            directory: Some("/tmp/flequit-backups".to_string()),
+            keep: 3,
+        };
+        assert_eq!(
+            BackupScheduleConfig::from_settings(&settings),
+            Some(BackupScheduleConfig {
+                backup_dir: PathBuf::from("/tmp/flequit-backups"),
+                interval: Duration::from_secs(6 * 60 * 60),
+                keep: 3,
+            })
+        );
+
+        // 無効な場合はスケジュールしない
+        settings.enabled = false;
+        assert_eq!(BackupScheduleConfig::from_settings(&settings), None);
+    }
+}
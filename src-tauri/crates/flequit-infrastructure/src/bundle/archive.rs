@@ -0,0 +1,230 @@
+//! バンドルファイルの読み書き
+//!
+//! バンドルはtar.gz形式で、マニフェストとプロジェクトドキュメントの2ファイルのみを含む。
+
+use super::error::BundleError;
+use super::manifest::{BUNDLE_MANIFEST_FILENAME, BundleManifest, PROJECT_DOCUMENT_ENTRY, checksum};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+/// バンドルファイルを書き出す
+///
+/// 一時ファイルに書き込んでからリネームするため、途中で失敗しても
+/// 不完全なバンドルが残ることはない。
+pub fn write_bundle(
+    dest: &Path,
+    manifest: &BundleManifest,
+    document: &[u8],
+) -> Result<(), BundleError> {
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut partial_name = dest.as_os_str().to_owned();
+    partial_name.push(".partial");
+    let partial = std::path::PathBuf::from(partial_name);
+
+    let result = (|| -> Result<(), BundleError> {
+        let file = fs::File::create(&partial)?;
+        let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+        let mtime = manifest.exported_at.timestamp().max(0) as u64;
+
+        let manifest_json = serde_json::to_vec_pretty(manifest)?;
+        append_entry(
+            &mut builder,
+            BUNDLE_MANIFEST_FILENAME,
+            &manifest_json,
+            mtime,
+        )?;
+        append_entry(&mut builder, PROJECT_DOCUMENT_ENTRY, document, mtime)?;
+
+        let file = builder.into_inner()?.finish()?;
+        file.sync_all()?;
+        Ok(())
+    })();
+
+    match result {
+        Ok(()) => {
+            fs::rename(&partial, dest)?;
+            Ok(())
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&partial);
+            Err(e)
+        }
+    }
+}
+
+/// バンドルファイルを読み込み、マニフェストとプロジェクトドキュメントを検証して返す
+pub fn read_bundle(path: &Path) -> Result<(BundleManifest, Vec<u8>), BundleError> {
+    let file = fs::File::open(path)?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+
+    let mut manifest: Option<BundleManifest> = None;
+    let mut document: Option<Vec<u8>> = None;
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let name = entry.path()?.to_string_lossy().to_string();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data)?;
+
+        match name.as_str() {
+            BUNDLE_MANIFEST_FILENAME => manifest = Some(serde_json::from_slice(&data)?),
+            PROJECT_DOCUMENT_ENTRY => document = Some(data),
+            other => {
+                return Err(BundleError::InvalidBundle(format!(
+                    "Unexpected entry in bundle: {}",
+                    other
+                )));
+            }
+        }
+    }
+
+    let manifest = manifest.ok_or_else(|| {
+        BundleError::InvalidBundle(format!("Missing {}", BUNDLE_MANIFEST_FILENAME))
+    })?;
+    manifest.ensure_compatible()?;
+
+    let document = document
+        .ok_or_else(|| BundleError::InvalidBundle(format!("Missing {}", PROJECT_DOCUMENT_ENTRY)))?;
+    if checksum(&document) != manifest.document_sha256 {
+        return Err(BundleError::ChecksumMismatch(
+            PROJECT_DOCUMENT_ENTRY.to_string(),
+        ));
+    }
+
+    Ok((manifest, document))
+}
+
+fn append_entry<W: std::io::Write>(
+    builder: &mut tar::Builder<W>,
+    name: &str,
+    data: &[u8],
+    mtime: u64,
+) -> Result<(), BundleError> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(mtime);
+    header.set_cksum();
+    builder.append_data(&mut header, name, data)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bundle::manifest::{BUNDLE_FORMAT_VERSION, BundleEntityCounts};
+    use chrono::Utc;
+    use flate2::write::GzEncoder;
+    use flequit_testing::TestPathGenerator;
+
+    fn manifest_for(document: &[u8]) -> BundleManifest {
+        BundleManifest {
+            format_version: BUNDLE_FORMAT_VERSION,
+            app_version: "test".to_string(),
+            exported_at: Utc::now(),
+            project_id: "project-1".to_string(),
+            project_name: "Project".to_string(),
+            counts: BundleEntityCounts::from_project_json(&serde_json::json!({
+                "tasks": [{}, {}],
+                "tags": [{}]
+            })),
+            document_sha256: checksum(document),
+        }
+    }
+
+    fn write_raw_bundle(dest: &Path, entries: &[(&str, &[u8])]) {
+        let file = fs::File::create(dest).unwrap();
+        let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+        for (name, data) in entries {
+            append_entry(&mut builder, name, data, 0).unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+    }
+
+    #[test]
+    fn test_bundle_roundtrip() {
+        let dir = TestPathGenerator::generate_test_dir(file!(), "test_bundle_roundtrip");
+        let dest = dir.join("project.flequit");
+        let document = b"automerge document".to_vec();
+        let manifest = manifest_for(&document);
+
+        write_bundle(&dest, &manifest, &document).unwrap();
+        assert!(!dir.join("project.flequit.partial").exists());
+
+        let (read_manifest, read_document) = read_bundle(&dest).unwrap();
+        assert_eq!(read_manifest, manifest);
+        assert_eq!(read_manifest.counts.tasks, 2);
+        assert_eq!(read_manifest.counts.tags, 1);
+        assert_eq!(read_document, document);
+    }
+
+    #[test]
+    fn test_read_bundle_rejects_invalid_bundles() {
+        let dir = TestPathGenerator::generate_test_dir(
+            file!(),
+            "test_read_bundle_rejects_invalid_bundles",
+        );
+        fs::create_dir_all(&dir).unwrap();
+        let document = b"automerge document".to_vec();
+
+        // 改ざんされたドキュメント
+        let manifest_json = serde_json::to_vec(&manifest_for(&document)).unwrap();
+        let tampered = dir.join("tampered.flequit");
+        write_raw_bundle(
+            &tampered,
+            &[
+                (BUNDLE_MANIFEST_FILENAME, &manifest_json),
+                (PROJECT_DOCUMENT_ENTRY, b"tampered document"),
+            ],
+        );
+        assert!(matches!(
+            read_bundle(&tampered),
+            Err(BundleError::ChecksumMismatch(_))
+        ));
+
+        // 未対応のバージョン
+        let mut future = manifest_for(&document);
+        future.format_version = BUNDLE_FORMAT_VERSION + 1;
+        let future_json = serde_json::to_vec(&future).unwrap();
+        let incompatible = dir.join("incompatible.flequit");
+        write_raw_bundle(
+            &incompatible,
+            &[
+                (BUNDLE_MANIFEST_FILENAME, &future_json),
+                (PROJECT_DOCUMENT_ENTRY, &document),
+            ],
+        );
+        assert!(matches!(
+            read_bundle(&incompatible),
+            Err(BundleError::IncompatibleVersion { .. })
+        ));
+
+        // 想定外のエントリ・ドキュメント欠落
+        let unexpected = dir.join("unexpected.flequit");
+        write_raw_bundle(
+            &unexpected,
+            &[
+                (BUNDLE_MANIFEST_FILENAME, &manifest_json),
+                ("extra.txt", b"x"),
+            ],
+        );
+        assert!(matches!(
+            read_bundle(&unexpected),
+            Err(BundleError::InvalidBundle(_))
+        ));
+
+        let missing = dir.join("missing.flequit");
+        write_raw_bundle(&missing, &[(BUNDLE_MANIFEST_FILENAME, &manifest_json)]);
+        assert!(matches!(
+            read_bundle(&missing),
+            Err(BundleError::InvalidBundle(_))
+        ));
+    }
+}
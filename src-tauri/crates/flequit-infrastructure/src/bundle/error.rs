@@ -0,0 +1,42 @@
+//! プロジェクトバンドルのエラー定義
+
+use flequit_types::errors::repository_error::RepositoryError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+    #[error("IO error: {0}")]
+    IOError(String),
+
+    #[error("Invalid bundle: {0}")]
+    InvalidBundle(String),
+
+    #[error("Checksum mismatch: {0}")]
+    ChecksumMismatch(String),
+
+    #[error("Incompatible bundle format version {found} (supported: {supported})")]
+    IncompatibleVersion { found: u32, supported: u32 },
+
+    #[error("Project not found: {0}")]
+    ProjectNotFound(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<std::io::Error> for BundleError {
+    fn from(err: std::io::Error) -> Self {
+        BundleError::IOError(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for BundleError {
+    fn from(err: serde_json::Error) -> Self {
+        BundleError::InvalidBundle(format!("Invalid JSON: {}", err))
+    }
+}
+
+impl From<RepositoryError> for BundleError {
+    fn from(err: RepositoryError) -> Self {
+        BundleError::RepositoryError(err.to_string())
+    }
+}
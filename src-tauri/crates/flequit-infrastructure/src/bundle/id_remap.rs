@@ -0,0 +1,335 @@
+//! バンドルをコピーとして取り込む際のID振り直し
+//!
+//! JSON化したプロジェクトドキュメント内の `id` フィールドを全て収集して新しいIDを割り当て、
+//! 参照している箇所（`project_id`、`task_id`、`tag_ids` など）も同じ対応表で置き換える。
+//! 置き換えるのはIDを保持するフィールド（`id`、`*_id`、`*_ids`）の値のみで、
+//! タイトルや説明、コメント本文などの文字列は旧IDと一致しても書き換えない。
+//! コメントのように `comment:{id}` のキーで保存されるエンティティは、キーのIDも振り直す。
+//! ユーザーIDはプロジェクトの外部で管理されるため振り直さない。
+
+use flequit_infrastructure_automerge::infrastructure::task_projects::comment::COMMENT_KEY_PREFIX;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// ユーザーを参照するキー（値は振り直しの対象外）
+pub const USER_REFERENCE_KEYS: &[&str] = &[
+    "user_id",
+    "owner_id",
+    "updated_by",
+    "created_by",
+    "deleted_by",
+    "author_id",
+    "assigned_user_ids",
+    "mentioned_user_ids",
+];
+
+/// キーにエンティティIDを含むエントリの接頭辞
+const ID_KEYED_PREFIXES: &[&str] = &[COMMENT_KEY_PREFIX];
+
+/// 旧ID -> 新IDの対応表
+#[derive(Debug, Clone, Default)]
+pub struct IdRemapper {
+    mapping: HashMap<String, String>,
+}
+
+impl IdRemapper {
+    /// プロジェクトドキュメント内の全エンティティIDに新しいIDを割り当てる
+    pub fn for_project(project_json: &Value) -> Self {
+        let mut remapper = Self::default();
+        remapper.collect(project_json);
+        remapper
+    }
+
+    /// 振り直すIDの数
+    pub fn len(&self) -> usize {
+        self.mapping.len()
+    }
+
+    /// 振り直すIDが無いか
+    pub fn is_empty(&self) -> bool {
+        self.mapping.is_empty()
+    }
+
+    /// 旧IDに対応する新IDを取得
+    pub fn get(&self, old_id: &str) -> Option<&str> {
+        self.mapping.get(old_id).map(String::as_str)
+    }
+
+    /// 対応表に従ってIDを置き換えたJSONを返す
+    pub fn apply(&self, value: &Value) -> Value {
+        self.apply_with_key(value, None)
+    }
+
+    fn collect(&mut self, value: &Value) {
+        match value {
+            Value::Object(fields) => {
+                if let Some(Value::String(id)) = fields.get("id") {
+                    self.mapping
+                        .entry(id.clone())
+                        .or_insert_with(|| uuid::Uuid::new_v4().to_string());
+                }
+                for (key, child) in fields {
+                    if !is_user_reference(key) {
+                        self.collect(child);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter().for_each(|item| self.collect(item)),
+            _ => {}
+        }
+    }
+
+    fn apply_with_key(&self, value: &Value, key: Option<&str>) -> Value {
+        if key.is_some_and(is_user_reference) {
+            return value.clone();
+        }
+        match value {
+            Value::String(s) if key.is_some_and(is_id_field) => {
+                Value::String(self.get(s).unwrap_or(s).to_string())
+            }
+            Value::Array(items) => Value::Array(
+                items
+                    .iter()
+                    .map(|item| self.apply_with_key(item, key))
+                    .collect(),
+            ),
+            Value::Object(fields) => Value::Object(
+                fields
+                    .iter()
+                    .map(|(k, v)| (self.remap_key(k), self.apply_with_key(v, Some(k))))
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    /// `comment:{id}` のようなキーに含まれるIDを置き換える
+    fn remap_key(&self, key: &str) -> String {
+        ID_KEYED_PREFIXES
+            .iter()
+            .find_map(|prefix| {
+                let id = key.strip_prefix(prefix)?;
+                Some(format!("{}{}", prefix, self.get(id)?))
+            })
+            .unwrap_or_else(|| key.to_string())
+    }
+}
+
+fn is_user_reference(key: &str) -> bool {
+    USER_REFERENCE_KEYS.contains(&key)
+}
+
+/// IDを保持するフィールド（`id`、`*_id`、`*_ids`）かどうか
+fn is_id_field(key: &str) -> bool {
+    key == "id" || key.ends_with("_id") || key.ends_with("_ids")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_remap_entity_ids_and_references() {
+        let project = json!({
+            "id": "p1",
+            "name": "Project",
+            "owner_id": "u1",
+            "updated_by": "u1",
+            "task_lists": [{ "id": "l1", "project_id": "p1", "updated_by": "u1" }],
+            "tasks": [{
+                "id": "t1",
+                "project_id": "p1",
+                "list_id": "l1",
+                "tag_ids": ["g1"],
+                "assigned_user_ids": ["u1"],
+                "recurrence_rule": { "id": "r1", "unit": "day" }
+            }],
+            "tags": [{ "id": "g1", "name": "tag" }],
+            "members": [{ "id": "m1", "user_id": "u1" }],
+            "task_tags": [{ "task_id": "t1", "tag_id": "g1" }],
+            "task_recurrences": [{ "task_id": "t1", "recurrence_rule_id": "r1" }]
+        });
+
+        let remapper = IdRemapper::for_project(&project);
+        assert_eq!(remapper.len(), 6);
+        assert!(remapper.get("u1").is_none());
+
+        let remapped = remapper.apply(&project);
+        let new_project_id = remapper.get("p1").unwrap();
+        let new_task_id = remapper.get("t1").unwrap();
+        let new_tag_id = remapper.get("g1").unwrap();
+
+        assert_eq!(remapped["id"], new_project_id);
+        assert_eq!(remapped["name"], "Project");
+        assert_eq!(remapped["owner_id"], "u1");
+        assert_eq!(remapped["task_lists"][0]["project_id"], new_project_id);
+        assert_eq!(remapped["tasks"][0]["id"], new_task_id);
+        assert_eq!(remapped["tasks"][0]["list_id"], remapper.get("l1").unwrap());
+        assert_eq!(remapped["tasks"][0]["tag_ids"][0], new_tag_id);
+        assert_eq!(remapped["tasks"][0]["assigned_user_ids"][0], "u1");
+        assert_eq!(
+            remapped["tasks"][0]["recurrence_rule"]["id"],
+            remapper.get("r1").unwrap()
+        );
+        assert_eq!(remapped["members"][0]["user_id"], "u1");
+        assert_eq!(remapped["task_tags"][0]["task_id"], new_task_id);
+        assert_eq!(remapped["task_tags"][0]["tag_id"], new_tag_id);
+        assert_eq!(
+            remapped["task_recurrences"][0]["recurrence_rule_id"],
+            remapper.get("r1").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_member_id_equal_to_user_id_keeps_user_references() {
+        // メンバーIDとユーザーIDが同じ値でも、ユーザー参照は書き換えない
+        let project = json!({
+            "id": "p1",
+            "members": [{ "id": "u1", "user_id": "u1" }],
+            "tasks": [{ "id": "t1", "assigned_user_ids": ["u1"] }]
+        });
+
+        let remapper = IdRemapper::for_project(&project);
+        let remapped = remapper.apply(&project);
+
+        assert_ne!(remapped["members"][0]["id"], "u1");
+        assert_eq!(remapped["members"][0]["user_id"], "u1");
+        assert_eq!(remapped["tasks"][0]["assigned_user_ids"][0], "u1");
+    }
+
+    #[test]
+    fn test_text_equal_to_an_id_is_not_rewritten() {
+        // IDと同じ文字列を含むタイトル・説明・コメント本文はそのまま残す
+        let project = json!({
+            "id": "p1",
+            "name": "p1",
+            "description": "t1",
+            "tasks": [{ "id": "t1", "project_id": "p1", "title": "t1" }],
+            "comment:c1": {
+                "id": "c1",
+                "task_id": "t1",
+                "author_id": "u1",
+                "body": "t1",
+                "mentioned_user_ids": ["u1"]
+            }
+        });
+
+        let remapper = IdRemapper::for_project(&project);
+        let remapped = remapper.apply(&project);
+        let new_task_id = remapper.get("t1").unwrap();
+        let comment = &remapped[format!("comment:{}", remapper.get("c1").unwrap())];
+
+        assert_eq!(remapped["id"], remapper.get("p1").unwrap());
+        assert_eq!(remapped["name"], "p1");
+        assert_eq!(remapped["description"], "t1");
+        assert_eq!(remapped["tasks"][0]["id"], new_task_id);
+        assert_eq!(remapped["tasks"][0]["title"], "t1");
+        assert_eq!(comment["task_id"], new_task_id);
+        assert_eq!(comment["body"], "t1");
+        assert_eq!(comment["author_id"], "u1");
+        assert_eq!(comment["mentioned_user_ids"][0], "u1");
+    }
+
+    #[test]
+    fn test_comment_keys_follow_remapped_ids() {
+        // コメントはキーでIDを引くため、キーと中身のIDが一致したまま振り直す
+        let project = json!({
+            "id": "p1",
+            "comment:c1": { "id": "c1", "task_id": "t1" },
+            "comment:unknown": { "task_id": "t1" }
+        });
+
+        let remapper = IdRemapper::for_project(&project);
+        let remapped = remapper.apply(&project);
+        let new_comment_id = remapper.get("c1").unwrap();
+
+        assert!(remapped.get("comment:c1").is_none());
+        assert_eq!(
+            remapped[format!("comment:{}", new_comment_id)]["id"],
+            new_comment_id
+        );
+        assert!(remapped.get("comment:unknown").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_remapped_document_is_readable_as_project() {
+        use chrono::Utc;
+        use flequit_infrastructure_automerge::infrastructure::document::Document;
+        use flequit_infrastructure_automerge::infrastructure::task_projects::project::ProjectLocalAutomergeRepository;
+        use flequit_model::models::task_projects::{project::Project, tag::Tag};
+        use flequit_model::types::id_types::{ProjectId, TagId, UserId};
+        use flequit_testing::TestPathGenerator;
+
+        let dir = TestPathGenerator::generate_test_dir(
+            file!(),
+            "test_remapped_document_is_readable_as_project",
+        );
+        std::fs::create_dir_all(&dir).unwrap();
+        let repository = ProjectLocalAutomergeRepository::new(dir).await.unwrap();
+
+        let now = Utc::now();
+        let user_id = UserId::new();
+        let project = Project {
+            id: ProjectId::new(),
+            name: "Original".to_string(),
+            description: None,
+            color: None,
+            order_index: 0,
+            is_archived: false,
+            status: None,
+            owner_id: Some(user_id),
+            created_at: now,
+            updated_at: now,
+            updated_by: user_id,
+            deleted: false,
+        };
+        let tag = Tag {
+            id: TagId::new(),
+            name: "tag".to_string(),
+            color: None,
+            order_index: None,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        repository.set_project(&project).await.unwrap();
+        repository.add_tag(&project.id, &tag).await.unwrap();
+
+        let history = repository
+            .export_project_history(&project.id)
+            .await
+            .unwrap();
+        let project_json = Document::history_to_json(&history).unwrap();
+        let remapper = IdRemapper::for_project(&project_json);
+        let copy_id = ProjectId::from(remapper.get(&project.id.to_string()).unwrap());
+
+        repository
+            .import_project_json(&copy_id, &remapper.apply(&project_json))
+            .await
+            .unwrap();
+
+        let copy = repository
+            .get_project_document(&copy_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(copy.id, copy_id.to_string());
+        assert_eq!(copy.owner_id, Some(user_id));
+        assert_eq!(copy.tags.len(), 1);
+        assert_ne!(copy.tags[0].id, tag.id);
+        assert_eq!(
+            copy.tags[0].id.to_string(),
+            remapper.get(&tag.id.to_string()).unwrap()
+        );
+
+        // 元のプロジェクトはそのまま残る
+        let original = repository
+            .get_project_document(&project.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(original.tags[0].id, tag.id);
+    }
+}
@@ -0,0 +1,86 @@
+//! プロジェクトバンドルのマニフェスト
+
+use super::error::BundleError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// 現在のバンドル形式バージョン
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// バンドルファイルの拡張子
+pub const BUNDLE_FILE_EXTENSION: &str = "flequit";
+
+/// バンドル内のマニフェストファイル名
+pub const BUNDLE_MANIFEST_FILENAME: &str = "bundle.json";
+
+/// バンドル内のプロジェクトドキュメント（Automerge保存形式）のパス
+pub const PROJECT_DOCUMENT_ENTRY: &str = "project.automerge";
+
+/// バンドルに含まれるエンティティ数
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleEntityCounts {
+    pub task_lists: usize,
+    pub tasks: usize,
+    pub subtasks: usize,
+    pub tags: usize,
+    pub members: usize,
+    pub recurrence_rules: usize,
+}
+
+impl BundleEntityCounts {
+    /// JSON化したプロジェクトドキュメントから件数を集計
+    pub fn from_project_json(project_json: &serde_json::Value) -> Self {
+        let count = |key: &str| {
+            project_json
+                .get(key)
+                .and_then(|v| v.as_array())
+                .map_or(0, |items| items.len())
+        };
+        Self {
+            task_lists: count("task_lists"),
+            tasks: count("tasks"),
+            subtasks: count("subtasks"),
+            tags: count("tags"),
+            members: count("members"),
+            recurrence_rules: count("recurrence_rules"),
+        }
+    }
+}
+
+/// プロジェクトバンドルのマニフェスト
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleManifest {
+    /// バンドル形式バージョン
+    pub format_version: u32,
+    /// バンドルを作成したアプリケーションのバージョン
+    pub app_version: String,
+    /// エクスポート日時
+    pub exported_at: DateTime<Utc>,
+    /// エクスポート元のプロジェクトID
+    pub project_id: String,
+    /// エクスポート時点のプロジェクト名
+    pub project_name: String,
+    /// 含まれるエンティティ数
+    pub counts: BundleEntityCounts,
+    /// プロジェクトドキュメントのSHA-256チェックサム（hex）
+    pub document_sha256: String,
+}
+
+impl BundleManifest {
+    /// 現在のバージョンでインポート可能か検証
+    pub fn ensure_compatible(&self) -> Result<(), BundleError> {
+        if self.format_version != BUNDLE_FORMAT_VERSION {
+            return Err(BundleError::IncompatibleVersion {
+                found: self.format_version,
+                supported: BUNDLE_FORMAT_VERSION,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// バイト列のSHA-256チェックサムを計算
+pub(crate) fn checksum(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
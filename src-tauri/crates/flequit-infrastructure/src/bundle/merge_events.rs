@@ -0,0 +1,185 @@
+//! 履歴のマージで取り込んだ変更のドメインイベント
+//!
+//! マージ前後のプロジェクトドキュメントを比較し、他の端末で行われた変更を
+//! エンティティごとの作成・更新・削除・復元として組み立てる。
+//! 組み立てたイベントの発生元は同期（`EventOrigin::Sync`）になる。
+
+use flequit_core::events::{self, DomainEvent, EntityKind, EventOrigin};
+use flequit_infrastructure_automerge::infrastructure::task_projects::comment::COMMENT_KEY_PREFIX;
+use flequit_model::types::id_types::ProjectId;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// プロジェクトドキュメント内の配列とそのエンティティ
+const ENTITY_COLLECTIONS: &[(&str, EntityKind)] = &[
+    ("task_lists", EntityKind::TaskList),
+    ("tasks", EntityKind::Task),
+    ("subtasks", EntityKind::SubTask),
+    ("tags", EntityKind::Tag),
+    ("recurrence_rules", EntityKind::RecurrenceRule),
+    ("time_entries", EntityKind::TimeEntry),
+    ("reminders", EntityKind::Reminder),
+    ("snoozes", EntityKind::Snooze),
+    ("workflow_statuses", EntityKind::WorkflowStatus),
+    ("status_transition_rules", EntityKind::StatusTransitionRule),
+];
+
+/// `id` をキーにしたエンティティのオブジェクト
+type EntityObjects<'a> = HashMap<String, &'a Map<String, Value>>;
+
+/// マージ前後のプロジェクトドキュメントの差分をイベントにする
+///
+/// マージ前にプロジェクトが存在しなかった場合は `before` に `Value::Null` を渡す。
+/// マージで消えたエンティティ（物理削除）は削除として扱う。
+pub(crate) fn merged_entity_events(
+    project_id: &ProjectId,
+    before: &Value,
+    after: &Value,
+) -> Vec<DomainEvent> {
+    let mut collections: Vec<(EntityKind, EntityObjects, EntityObjects)> = ENTITY_COLLECTIONS
+        .iter()
+        .map(|(key, entity)| {
+            (
+                *entity,
+                collection(before.get(key)),
+                collection(after.get(key)),
+            )
+        })
+        .collect();
+    collections.push((EntityKind::Comment, comments(before), comments(after)));
+
+    let mut merged = Vec::new();
+    for (entity, before, after) in collections {
+        let mut ids: Vec<&String> = before
+            .keys()
+            .chain(after.keys().filter(|id| !before.contains_key(*id)))
+            .collect();
+        ids.sort();
+        for id in ids {
+            let event = match (before.get(id), after.get(id)) {
+                (None, Some(_)) => DomainEvent::created(entity, id.as_str()),
+                (Some(before), None) => {
+                    DomainEvent::deleted(entity, id.as_str()).with_snapshot(before)
+                }
+                (Some(before), Some(after)) if before != after => {
+                    match (is_deleted(before), is_deleted(after)) {
+                        (false, true) => {
+                            DomainEvent::deleted(entity, id.as_str()).with_snapshot(before)
+                        }
+                        (true, false) => DomainEvent::restored(entity, id.as_str()),
+                        _ => DomainEvent::updated(entity, id.as_str())
+                            .with_changes(events::field_changes(before, after)),
+                    }
+                }
+                _ => continue,
+            };
+            let object = after.get(id).or_else(|| before.get(id));
+            let event = match object.and_then(|object| object.get("task_id")) {
+                Some(Value::String(task_id)) if entity != EntityKind::Task => {
+                    event.related_to(task_id)
+                }
+                _ => event,
+            };
+            merged.push(event.in_project(project_id).from_origin(EventOrigin::Sync));
+        }
+    }
+    merged
+}
+
+/// `id` をキーにした配列内のオブジェクト
+fn collection(items: Option<&Value>) -> EntityObjects<'_> {
+    items
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_object)
+        .filter_map(|object| Some((object.get("id")?.as_str()?.to_string(), object)))
+        .collect()
+}
+
+/// ルート直下の `comment:{id}` キーに置かれたコメント
+fn comments(project: &Value) -> EntityObjects<'_> {
+    project
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(key, value)| {
+            Some((
+                key.strip_prefix(COMMENT_KEY_PREFIX)?.to_string(),
+                value.as_object()?,
+            ))
+        })
+        .collect()
+}
+
+fn is_deleted(object: &Map<String, Value>) -> bool {
+    object.get("deleted").and_then(Value::as_bool) == Some(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flequit_core::events::ChangeKind;
+    use serde_json::json;
+
+    fn project(tasks: Value) -> Value {
+        json!({ "id": "project-1", "tasks": tasks })
+    }
+
+    #[test]
+    fn test_merged_changes_become_sync_events() {
+        let project_id = ProjectId::new();
+        let before = project(json!([
+            { "id": "kept", "title": "Kept", "deleted": false },
+            { "id": "renamed", "title": "Before", "deleted": false },
+            { "id": "removed", "title": "Removed", "deleted": false },
+        ]));
+        let after = project(json!([
+            { "id": "kept", "title": "Kept", "deleted": false },
+            { "id": "renamed", "title": "After", "deleted": false },
+            { "id": "removed", "title": "Removed", "deleted": true },
+            { "id": "added", "title": "Added", "deleted": false },
+        ]));
+
+        let merged = merged_entity_events(&project_id, &before, &after);
+        let changes: Vec<_> = merged
+            .iter()
+            .map(|event| (event.entity_id.as_str(), event.change))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("added", ChangeKind::Created),
+                ("removed", ChangeKind::Deleted),
+                ("renamed", ChangeKind::Updated),
+            ]
+        );
+        assert!(merged.iter().all(|event| {
+            event.origin == EventOrigin::Sync
+                && event.entity == EntityKind::Task
+                && event.project_id == Some(project_id)
+        }));
+        assert_eq!(merged[2].changed_fields, vec!["title".to_string()]);
+    }
+
+    #[test]
+    fn test_comments_and_child_entities_refer_to_their_task() {
+        let project_id = ProjectId::new();
+        let after = json!({
+            "id": "project-1",
+            "subtasks": [{ "id": "subtask-1", "task_id": "task-1" }],
+            "comment:comment-1": { "id": "comment-1", "task_id": "task-1" },
+        });
+
+        let merged = merged_entity_events(&project_id, &Value::Null, &after);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].entity, EntityKind::SubTask);
+        assert_eq!(merged[1].entity, EntityKind::Comment);
+        assert_eq!(merged[1].entity_id, "comment-1");
+        assert!(
+            merged
+                .iter()
+                .all(|event| event.related_id.as_deref() == Some("task-1"))
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! プロジェクト単位のポータブルバンドル（`.flequit` ファイル）
+//!
+//! 1つのプロジェクトのAutomergeドキュメントを変更履歴ごと書き出し、
+//! タスクリスト・タスク・サブタスク・タグ・繰り返し・担当者・メンバーを
+//! 自己完結したファイルとして別環境へ受け渡す。
+//!
+//! インポート時はIDを保持して履歴をマージするか、
+//! IDを振り直して独立したコピーとして取り込むかを選択できる。
+
+mod archive;
+mod error;
+mod id_remap;
+mod manifest;
+mod merge_events;
+
+pub use archive::{read_bundle, write_bundle};
+pub use error::BundleError;
+pub use id_remap::{IdRemapper, USER_REFERENCE_KEYS};
+pub(crate) use manifest::checksum;
+pub use manifest::{
+    BUNDLE_FILE_EXTENSION, BUNDLE_FORMAT_VERSION, BUNDLE_MANIFEST_FILENAME, BundleEntityCounts,
+    BundleManifest, PROJECT_DOCUMENT_ENTRY,
+};
+pub(crate) use merge_events::merged_entity_events;
+
+/// インポート時のID取り扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BundleImportMode {
+    /// IDを保持し、変更履歴をマージする（後から双方の変更を統合できる）
+    KeepIds,
+    /// 全エンティティのIDを振り直し、独立したコピーとして取り込む
+    RemapIds,
+}
+
+/// インポート結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleImportResult {
+    /// 取り込み先のプロジェクトID
+    pub project_id: String,
+    /// 使用したインポートモード
+    pub mode: BundleImportMode,
+    /// 既存のプロジェクトにマージしたか
+    pub merged: bool,
+    /// 振り直したIDの数（KeepIdsの場合は0）
+    pub remapped_ids: usize,
+    /// バンドルのマニフェスト
+    pub manifest: BundleManifest,
+}
@@ -0,0 +1,331 @@
+//! HTTPによるCalDAVクライアント
+
+use super::error::CalDavError;
+use super::remote::{CalDavRemote, RemoteCalendar, RemoteItem};
+use super::xml::{
+    DavResponse, PROPFIND_CALENDARS, PROPFIND_CTAG, REPORT_TODO_ETAGS, calendar_multiget,
+    mkcalendar, parse_multistatus,
+};
+use async_trait::async_trait;
+use reqwest::header::{CONTENT_TYPE, ETAG, HeaderMap, HeaderValue, IF_MATCH, IF_NONE_MATCH};
+use reqwest::{Method, RequestBuilder, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+const XML_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+const CALENDAR_CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 認証情報（Basic認証）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalDavCredentials {
+    pub username: String,
+    pub password: String,
+}
+
+/// CalDAVサーバーのクライアント
+///
+/// `base_url` にはカレンダーホーム（例: Radicaleの `http://localhost:5232/alice/`）を指定する。
+#[derive(Debug, Clone)]
+pub struct CalDavClient {
+    base_url: Url,
+    credentials: Option<CalDavCredentials>,
+    http: reqwest::Client,
+}
+
+fn method(name: &'static str) -> Method {
+    Method::from_bytes(name.as_bytes()).expect("valid WebDAV method")
+}
+
+impl CalDavClient {
+    pub fn new(
+        base_url: &str,
+        credentials: Option<CalDavCredentials>,
+    ) -> Result<Self, CalDavError> {
+        let mut base_url = Url::parse(base_url)
+            .map_err(|e| CalDavError::InvalidUrl(format!("{base_url}: {e}")))?;
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()?;
+        Ok(Self {
+            base_url,
+            credentials,
+            http,
+        })
+    }
+
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    fn url(&self, href: &str) -> Result<Url, CalDavError> {
+        self.base_url
+            .join(href)
+            .map_err(|e| CalDavError::InvalidUrl(format!("{href}: {e}")))
+    }
+
+    fn request(&self, method: Method, href: &str) -> Result<RequestBuilder, CalDavError> {
+        let builder = self.http.request(method, self.url(href)?);
+        Ok(match &self.credentials {
+            Some(credentials) => {
+                builder.basic_auth(&credentials.username, Some(&credentials.password))
+            }
+            None => builder,
+        })
+    }
+
+    async fn multistatus(
+        &self,
+        method_name: &'static str,
+        href: &str,
+        depth: &'static str,
+        body: String,
+    ) -> Result<Vec<DavResponse>, CalDavError> {
+        let response = self
+            .request(method(method_name), href)?
+            .header("Depth", depth)
+            .header(CONTENT_TYPE, XML_CONTENT_TYPE)
+            .body(body)
+            .send()
+            .await?;
+        let status = response.status();
+        if status != StatusCode::MULTI_STATUS {
+            return Err(CalDavError::UnexpectedStatus {
+                method: method_name.to_string(),
+                url: self.url(href)?.to_string(),
+                status: status.as_u16(),
+            });
+        }
+        parse_multistatus(&response.text().await?)
+    }
+
+    /// カレンダーホーム直下のVTODOに対応したカレンダーを取得
+    pub async fn list_calendars(&self) -> Result<Vec<RemoteCalendar>, CalDavError> {
+        let home = self.base_url.path().to_string();
+        let responses = self
+            .multistatus("PROPFIND", &home, "1", PROPFIND_CALENDARS.to_string())
+            .await?;
+        Ok(responses
+            .into_iter()
+            .filter(|r| r.is_calendar)
+            .filter(|r| r.components.is_empty() || r.components.iter().any(|c| c == "VTODO"))
+            .map(|r| RemoteCalendar {
+                href: r.href,
+                display_name: r.display_name,
+                ctag: r.ctag,
+            })
+            .collect())
+    }
+
+    /// VTODO用のカレンダーを作成
+    pub async fn create_calendar(&self, href: &str, display_name: &str) -> Result<(), CalDavError> {
+        let response = self
+            .request(method("MKCALENDAR"), href)?
+            .header(CONTENT_TYPE, XML_CONTENT_TYPE)
+            .body(mkcalendar(display_name))
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(CalDavError::UnexpectedStatus {
+                method: "MKCALENDAR".to_string(),
+                url: self.url(href)?.to_string(),
+                status: response.status().as_u16(),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn etag_header(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+#[async_trait]
+impl CalDavRemote for CalDavClient {
+    async fn get_ctag(&self, calendar_href: &str) -> Result<Option<String>, CalDavError> {
+        let responses = self
+            .multistatus("PROPFIND", calendar_href, "0", PROPFIND_CTAG.to_string())
+            .await?;
+        Ok(responses.into_iter().find_map(|r| r.ctag))
+    }
+
+    async fn list_etags(
+        &self,
+        calendar_href: &str,
+    ) -> Result<HashMap<String, Option<String>>, CalDavError> {
+        let responses = self
+            .multistatus("REPORT", calendar_href, "1", REPORT_TODO_ETAGS.to_string())
+            .await?;
+        Ok(responses
+            .into_iter()
+            .filter(|r| !r.missing && !r.href.ends_with('/'))
+            .map(|r| (r.href, r.etag))
+            .collect())
+    }
+
+    async fn fetch(
+        &self,
+        calendar_href: &str,
+        hrefs: &[String],
+    ) -> Result<Vec<RemoteItem>, CalDavError> {
+        if hrefs.is_empty() {
+            return Ok(Vec::new());
+        }
+        let responses = self
+            .multistatus("REPORT", calendar_href, "1", calendar_multiget(hrefs))
+            .await?;
+        Ok(responses
+            .into_iter()
+            .filter(|r| !r.missing)
+            .filter_map(|r| {
+                Some(RemoteItem {
+                    data: r.calendar_data?,
+                    href: r.href,
+                    etag: r.etag,
+                })
+            })
+            .collect())
+    }
+
+    async fn put(
+        &self,
+        href: &str,
+        data: &str,
+        etag: Option<&str>,
+    ) -> Result<Option<String>, CalDavError> {
+        let mut request = self
+            .request(Method::PUT, href)?
+            .header(CONTENT_TYPE, CALENDAR_CONTENT_TYPE)
+            .body(data.to_string());
+        request = match etag {
+            Some(etag) => request.header(
+                IF_MATCH,
+                HeaderValue::from_str(etag).map_err(|e| CalDavError::HttpError(e.to_string()))?,
+            ),
+            None => request.header(IF_NONE_MATCH, "*"),
+        };
+        let response = request.send().await?;
+        match response.status() {
+            status if status.is_success() => Ok(etag_header(response.headers())),
+            StatusCode::PRECONDITION_FAILED => {
+                Err(CalDavError::PreconditionFailed(href.to_string()))
+            }
+            status => Err(CalDavError::UnexpectedStatus {
+                method: "PUT".to_string(),
+                url: self.url(href)?.to_string(),
+                status: status.as_u16(),
+            }),
+        }
+    }
+
+    async fn delete(&self, href: &str, etag: Option<&str>) -> Result<(), CalDavError> {
+        let mut request = self.request(Method::DELETE, href)?;
+        if let Some(etag) = etag {
+            request = request.header(
+                IF_MATCH,
+                HeaderValue::from_str(etag).map_err(|e| CalDavError::HttpError(e.to_string()))?,
+            );
+        }
+        let response = request.send().await?;
+        match response.status() {
+            status if status.is_success() || status == StatusCode::NOT_FOUND => Ok(()),
+            StatusCode::PRECONDITION_FAILED => {
+                Err(CalDavError::PreconditionFailed(href.to_string()))
+            }
+            status => Err(CalDavError::UnexpectedStatus {
+                method: "DELETE".to_string(),
+                url: self.url(href)?.to_string(),
+                status: status.as_u16(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_url_is_a_collection() {
+        let client = CalDavClient::new("http://localhost:5232/alice", None).unwrap();
+        assert_eq!(client.base_url().as_str(), "http://localhost:5232/alice/");
+        assert_eq!(
+            client.url("/alice/work/a.ics").unwrap().as_str(),
+            "http://localhost:5232/alice/work/a.ics"
+        );
+        assert!(CalDavClient::new("not a url", None).is_err());
+    }
+
+    /// Radicaleなどのローカルサーバーに対する往復テスト
+    ///
+    /// `FLEQUIT_CALDAV_TEST_URL`（カレンダーホーム）、`FLEQUIT_CALDAV_TEST_USER`、
+    /// `FLEQUIT_CALDAV_TEST_PASSWORD` を設定して `cargo test -- --ignored` で実行する。
+    /// 例: `radicale --storage-filesystem-folder=/tmp/radicale --auth-type=none` に対して
+    /// `FLEQUIT_CALDAV_TEST_URL=http://localhost:5232/test/`
+    #[tokio::test]
+    #[ignore]
+    async fn test_round_trip_against_local_server() {
+        let Ok(url) = std::env::var("FLEQUIT_CALDAV_TEST_URL") else {
+            return;
+        };
+        let credentials = std::env::var("FLEQUIT_CALDAV_TEST_USER")
+            .ok()
+            .map(|username| CalDavCredentials {
+                username,
+                password: std::env::var("FLEQUIT_CALDAV_TEST_PASSWORD").unwrap_or_default(),
+            });
+        let client = CalDavClient::new(&url, credentials).unwrap();
+
+        let calendar_href = format!(
+            "{}flequit-{}/",
+            client.base_url().path(),
+            uuid::Uuid::new_v4()
+        );
+        client
+            .create_calendar(&calendar_href, "Flequit test")
+            .await
+            .unwrap();
+        assert!(
+            client
+                .list_calendars()
+                .await
+                .unwrap()
+                .iter()
+                .any(|c| c.href == calendar_href)
+        );
+
+        let href = format!("{calendar_href}item.ics");
+        let now = chrono::Utc::now();
+        let fields = super::super::vtodo::TodoFields {
+            summary: "Radicale".to_string(),
+            ..Default::default()
+        };
+        let data = super::super::vtodo::new_vtodo("radicale-test", &fields, &now, &now);
+        client.put(&href, &data, None).await.unwrap();
+        // 同じhrefへの新規作成は拒否される
+        assert!(client.put(&href, &data, None).await.is_err());
+
+        let etags = client.list_etags(&calendar_href).await.unwrap();
+        let etag = etags.get(&href).cloned().flatten();
+        assert!(etag.is_some());
+        let items = client
+            .fetch(&calendar_href, std::slice::from_ref(&href))
+            .await
+            .unwrap();
+        assert!(items[0].data.contains("SUMMARY:Radicale"));
+
+        assert!(matches!(
+            client.put(&href, &data, Some("\"stale\"")).await,
+            Err(CalDavError::PreconditionFailed(_))
+        ));
+        client.delete(&href, etag.as_deref()).await.unwrap();
+        client.delete(&calendar_href, None).await.unwrap();
+    }
+}
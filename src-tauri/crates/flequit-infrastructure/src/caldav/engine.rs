@@ -0,0 +1,729 @@
+//! 同期エンジン
+//!
+//! 1. CTagが前回と同じであればリモートの一覧取得を省略し、変わっていればETagの一覧を取得する。
+//! 2. ETagが変わった項目と、ローカルで変更された項目の内容を取得する。
+//! 3. 前回同期時の値を基準にフィールド単位でマージし、ローカル・リモートへ反映する。
+//!
+//! ETagが一致しない（同期中に他のクライアントが更新した）項目は前回の記録を残し、次回の同期で再度マージする。
+
+use super::error::CalDavError;
+use super::merge::merge_fields;
+use super::remote::{CalDavRemote, RemoteItem};
+use super::state::{CalDavSyncState, CalendarBinding, SyncRecord};
+use super::vtodo::{RemoteTodo, TodoFields, new_vtodo, parse_vtodo, update_vtodo};
+use chrono::Utc;
+use flequit_core::InfrastructureRepositoriesTrait;
+use flequit_core::events::{EventOrigin, with_origin};
+use flequit_core::exporters::ical::task_uid;
+use flequit_core::services::task_service;
+use flequit_model::models::task_projects::task::Task;
+use flequit_model::types::id_types::{TaskId, UserId};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// 同期結果
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncReport {
+    pub pulled_created: usize,
+    pub pulled_updated: usize,
+    pub pulled_deleted: usize,
+    pub pushed_created: usize,
+    pub pushed_updated: usize,
+    pub pushed_deleted: usize,
+    /// 両方で変更されていたフィールド数と、ETag不一致で次回に持ち越した項目数
+    pub conflicts: usize,
+    pub errors: Vec<String>,
+}
+
+impl SyncReport {
+    fn add(&mut self, other: SyncReport) {
+        self.pulled_created += other.pulled_created;
+        self.pulled_updated += other.pulled_updated;
+        self.pulled_deleted += other.pulled_deleted;
+        self.pushed_created += other.pushed_created;
+        self.pushed_updated += other.pushed_updated;
+        self.pushed_deleted += other.pushed_deleted;
+        self.conflicts += other.conflicts;
+        self.errors.extend(other.errors);
+    }
+
+    fn pushed(&self) -> bool {
+        self.pushed_created + self.pushed_updated + self.pushed_deleted > 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum LocalChange {
+    None,
+    Create,
+    /// `previous` はタスクから作成した現在の値
+    Update {
+        previous: TodoFields,
+    },
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum RemoteChange {
+    None,
+    Create,
+    /// `original` は取得済みのカレンダーオブジェクト、`previous` はその値
+    Update {
+        original: Option<String>,
+        previous: TodoFields,
+    },
+    Delete,
+}
+
+/// 1項目分の同期計画
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PlannedItem {
+    pub task_id: TaskId,
+    pub href: String,
+    pub uid: String,
+    pub etag: Option<String>,
+    pub merged: TodoFields,
+    pub local: LocalChange,
+    pub remote: RemoteChange,
+    pub conflicts: usize,
+    /// 失敗した場合に残す前回の記録
+    pub previous: Option<SyncRecord>,
+}
+
+fn list_tasks<'a>(binding: &CalendarBinding, tasks: &'a [Task]) -> HashMap<TaskId, &'a Task> {
+    tasks
+        .iter()
+        .filter(|task| task.list_id == binding.task_list_id && !task.deleted)
+        .map(|task| (task.id, task))
+        .collect()
+}
+
+/// 内容を取得する必要のあるhref（ETagが変わったもの、新しいもの、ローカルで変更されたもの）
+pub(crate) fn hrefs_to_fetch(
+    binding: &CalendarBinding,
+    tasks: &[Task],
+    remote_etags: &HashMap<String, Option<String>>,
+) -> Vec<String> {
+    let tasks = list_tasks(binding, tasks);
+    let records: HashMap<&str, &SyncRecord> = binding
+        .items
+        .iter()
+        .map(|record| (record.href.as_str(), record))
+        .collect();
+
+    let mut hrefs: Vec<String> = remote_etags
+        .iter()
+        .filter(|(href, etag)| match records.get(href.as_str()) {
+            Some(record) => record.etag.is_none() || record.etag != **etag,
+            None => true,
+        })
+        .map(|(href, _)| href.clone())
+        .collect();
+    let locally_changed: Vec<String> = binding
+        .items
+        .iter()
+        .filter(|record| remote_etags.contains_key(&record.href) && !hrefs.contains(&record.href))
+        .filter(|record| {
+            tasks
+                .get(&record.task_id)
+                .is_some_and(|task| TodoFields::from_task(task) != record.base)
+        })
+        .map(|record| record.href.clone())
+        .collect();
+    hrefs.extend(locally_changed);
+    hrefs.sort();
+    hrefs
+}
+
+/// ローカルのタスクとリモートの状態から同期計画を作成
+pub(crate) fn plan_binding(
+    binding: &CalendarBinding,
+    tasks: &[Task],
+    remote_etags: &HashMap<String, Option<String>>,
+    fetched: &HashMap<String, RemoteItem>,
+) -> Vec<PlannedItem> {
+    let tasks = list_tasks(binding, tasks);
+    let remote_todo = |href: &str| -> Option<RemoteTodo> {
+        fetched.get(href).and_then(|item| parse_vtodo(&item.data))
+    };
+    let current_etag = |href: &str| -> Option<String> {
+        fetched
+            .get(href)
+            .and_then(|item| item.etag.clone())
+            .or_else(|| remote_etags.get(href).cloned().flatten())
+    };
+
+    let mut plan = Vec::new();
+    let mut known_hrefs: HashSet<&str> = HashSet::new();
+    let mut known_tasks: HashSet<TaskId> = HashSet::new();
+
+    for record in &binding.items {
+        known_hrefs.insert(&record.href);
+        known_tasks.insert(record.task_id);
+        let local = tasks.get(&record.task_id);
+        let remote_etag = remote_etags.get(&record.href);
+        let remote = remote_todo(&record.href);
+        let remote_fields = remote
+            .as_ref()
+            .map(|todo| todo.fields.clone())
+            .unwrap_or_else(|| record.base.clone());
+
+        let mut item = PlannedItem {
+            task_id: record.task_id,
+            href: record.href.clone(),
+            uid: record.uid.clone(),
+            etag: current_etag(&record.href),
+            merged: record.base.clone(),
+            local: LocalChange::None,
+            remote: RemoteChange::None,
+            conflicts: 0,
+            previous: Some(record.clone()),
+        };
+
+        match (local, remote_etag) {
+            (Some(task), Some(_)) => {
+                let local_fields = TodoFields::from_task(task);
+                let prefer_local = remote
+                    .as_ref()
+                    .and_then(|todo| todo.last_modified)
+                    .is_none_or(|modified| task.updated_at >= modified);
+                let (merged, conflicts) =
+                    merge_fields(&record.base, &local_fields, &remote_fields, prefer_local);
+                if merged != local_fields {
+                    item.local = LocalChange::Update {
+                        previous: local_fields,
+                    };
+                }
+                if merged != remote_fields {
+                    item.remote = RemoteChange::Update {
+                        original: fetched.get(&record.href).map(|i| i.data.clone()),
+                        previous: remote_fields,
+                    };
+                }
+                item.merged = merged;
+                item.conflicts = conflicts;
+            }
+            // リモートで削除された
+            (Some(task), None) => {
+                let local_fields = TodoFields::from_task(task);
+                if local_fields == record.base {
+                    item.local = LocalChange::Delete;
+                } else {
+                    // 削除後にローカルで変更されていれば作り直す
+                    item.remote = RemoteChange::Create;
+                    item.etag = None;
+                    item.merged = local_fields;
+                }
+            }
+            // ローカルで削除された
+            (None, Some(etag)) => {
+                if record.etag.is_some() && record.etag == *etag {
+                    item.remote = RemoteChange::Delete;
+                } else {
+                    // 削除後にリモートで変更されていれば取り込み直す
+                    item.task_id = TaskId::new();
+                    item.local = LocalChange::Create;
+                    item.merged = remote_fields;
+                }
+            }
+            (None, None) => continue,
+        }
+        plan.push(item);
+    }
+
+    // リモートで新しく作成された項目
+    let uid_to_task: HashMap<String, TaskId> = tasks
+        .keys()
+        .filter(|id| !known_tasks.contains(id))
+        .map(|id| (task_uid(id), *id))
+        .collect();
+    let mut new_hrefs: Vec<&String> = remote_etags
+        .keys()
+        .filter(|href| !known_hrefs.contains(href.as_str()))
+        .collect();
+    new_hrefs.sort();
+    for href in new_hrefs {
+        let Some(todo) = remote_todo(href) else {
+            continue;
+        };
+        let mut item = PlannedItem {
+            task_id: TaskId::new(),
+            href: href.clone(),
+            uid: todo.uid.clone(),
+            etag: current_etag(href),
+            merged: todo.fields.clone(),
+            local: LocalChange::Create,
+            remote: RemoteChange::None,
+            conflicts: 0,
+            previous: None,
+        };
+        // 同期記録を失った場合など、UIDから既存のタスクが分かるときは対応付け直してローカルを優先する
+        if let Some(task_id) = uid_to_task.get(&todo.uid)
+            && let Some(task) = tasks.get(task_id)
+        {
+            known_tasks.insert(*task_id);
+            let local_fields = TodoFields::from_task(task);
+            item.task_id = *task_id;
+            item.local = LocalChange::None;
+            if local_fields != todo.fields {
+                item.remote = RemoteChange::Update {
+                    original: fetched.get(href).map(|i| i.data.clone()),
+                    previous: todo.fields,
+                };
+            }
+            item.merged = local_fields;
+        }
+        plan.push(item);
+    }
+
+    // ローカルで新しく作成されたタスク
+    let mut new_tasks: Vec<&&Task> = tasks
+        .values()
+        .filter(|task| !known_tasks.contains(&task.id))
+        .collect();
+    new_tasks.sort_by_key(|task| (task.order_index, task.created_at));
+    for task in new_tasks {
+        plan.push(PlannedItem {
+            task_id: task.id,
+            href: binding.item_href(&task.id),
+            uid: task_uid(&task.id),
+            etag: None,
+            merged: TodoFields::from_task(task),
+            local: LocalChange::None,
+            remote: RemoteChange::Create,
+            conflicts: 0,
+            previous: None,
+        });
+    }
+    plan
+}
+
+fn new_task(
+    binding: &CalendarBinding,
+    task_id: TaskId,
+    fields: &TodoFields,
+    order_index: i32,
+    user_id: &UserId,
+) -> Task {
+    let now = Utc::now();
+    Task {
+        id: task_id,
+        project_id: binding.project_id,
+        list_id: binding.task_list_id,
+        title: fields.summary.clone(),
+        description: fields.description.clone(),
+        status: fields.status.clone(),
+        workflow_status_id: None,
+        priority: fields.priority,
+        plan_start_date: fields.start,
+        plan_end_date: fields.due,
+        do_start_date: None,
+        do_end_date: None,
+        is_range_date: Some(false),
+        recurrence_rule: None,
+        order_index,
+        is_archived: false,
+        assigned_user_ids: Vec::new(),
+        tag_ids: Vec::new(),
+        created_at: now,
+        updated_at: now,
+        deleted: false,
+        updated_by: *user_id,
+    }
+}
+
+/// 計画の反映に必要な情報
+struct ApplyContext<'a, R, C> {
+    repositories: &'a R,
+    remote: &'a C,
+    binding: &'a CalendarBinding,
+    tasks: &'a HashMap<TaskId, &'a Task>,
+    user_id: &'a UserId,
+}
+
+/// 計画した1項目をローカル・リモートへ反映し、新しい同期記録を返す（削除した場合は `None`）
+async fn apply_item<R, C>(
+    context: &ApplyContext<'_, R, C>,
+    item: &PlannedItem,
+    order_index: i32,
+    report: &mut SyncReport,
+) -> Result<Option<SyncRecord>, CalDavError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+    C: CalDavRemote,
+{
+    let ApplyContext {
+        repositories,
+        remote,
+        binding,
+        tasks,
+        user_id,
+    } = *context;
+    let now = Utc::now();
+    match &item.local {
+        LocalChange::None => {}
+        LocalChange::Create => {
+            let task = new_task(binding, item.task_id, &item.merged, order_index, user_id);
+            task_service::create_task(repositories, &binding.project_id, &task, user_id).await?;
+            report.pulled_created += 1;
+        }
+        LocalChange::Update { previous } => {
+            if let Some(task) = tasks.get(&item.task_id) {
+                let mut task = (*task).clone();
+                item.merged.apply_changes(previous, &mut task);
+                task.updated_at = now;
+                task.updated_by = *user_id;
+                task_service::save_task(repositories, &binding.project_id, &task, user_id).await?;
+                report.pulled_updated += 1;
+            }
+        }
+        LocalChange::Delete => {
+            task_service::delete_task(repositories, &binding.project_id, &item.task_id).await?;
+            report.pulled_deleted += 1;
+            return Ok(None);
+        }
+    }
+
+    let mut etag = item.etag.clone();
+    match &item.remote {
+        RemoteChange::None => {}
+        RemoteChange::Create => {
+            let created_at = tasks
+                .get(&item.task_id)
+                .map(|task| task.created_at)
+                .unwrap_or(now);
+            let data = new_vtodo(&item.uid, &item.merged, &created_at, &now);
+            etag = remote.put(&item.href, &data, None).await?;
+            report.pushed_created += 1;
+        }
+        RemoteChange::Update { original, previous } => {
+            let data = match original {
+                Some(original) => update_vtodo(original, previous, &item.merged, &now),
+                None => new_vtodo(&item.uid, &item.merged, &now, &now),
+            };
+            etag = remote.put(&item.href, &data, item.etag.as_deref()).await?;
+            report.pushed_updated += 1;
+        }
+        RemoteChange::Delete => {
+            remote.delete(&item.href, item.etag.as_deref()).await?;
+            report.pushed_deleted += 1;
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(SyncRecord {
+        task_id: item.task_id,
+        href: item.href.clone(),
+        uid: item.uid.clone(),
+        etag,
+        base: item.merged.clone(),
+    }))
+}
+
+/// 1つのタスクリストとカレンダーを同期
+pub async fn sync_binding<R, C>(
+    repositories: &R,
+    remote: &C,
+    binding: &mut CalendarBinding,
+    user_id: &UserId,
+) -> Result<SyncReport, CalDavError>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+    C: CalDavRemote,
+{
+    let mut report = SyncReport::default();
+    let ctag = remote.get_ctag(&binding.calendar_href).await?;
+    let remote_etags = if ctag.is_some() && ctag == binding.ctag {
+        binding
+            .items
+            .iter()
+            .map(|record| (record.href.clone(), record.etag.clone()))
+            .collect()
+    } else {
+        remote.list_etags(&binding.calendar_href).await?
+    };
+
+    let tasks = task_service::list_tasks(repositories, &binding.project_id).await?;
+    let fetch = hrefs_to_fetch(binding, &tasks, &remote_etags);
+    let fetched: HashMap<String, RemoteItem> = remote
+        .fetch(&binding.calendar_href, &fetch)
+        .await?
+        .into_iter()
+        .map(|item| (item.href.clone(), item))
+        .collect();
+    let plan = plan_binding(binding, &tasks, &remote_etags, &fetched);
+
+    let task_map = list_tasks(binding, &tasks);
+    let context = ApplyContext {
+        repositories,
+        remote,
+        binding,
+        tasks: &task_map,
+        user_id,
+    };
+    let mut order_index = task_map.len() as i32;
+    let mut records = Vec::with_capacity(plan.len());
+    for item in &plan {
+        report.conflicts += item.conflicts;
+        if item.local == LocalChange::Create {
+            order_index += 1;
+        }
+        // リモートへの反映に失敗した場合はローカルの変更も取り消し、同期記録と揃える
+        let mut item_report = SyncReport::default();
+        let applied = repositories
+            .run_in_unit_of_work(with_origin(
+                EventOrigin::Sync,
+                apply_item(&context, item, order_index, &mut item_report),
+            ))
+            .await;
+        if applied.is_ok() {
+            report.add(item_report);
+        }
+        match applied {
+            Ok(Some(record)) => records.push(record),
+            Ok(None) => {}
+            Err(e) => {
+                if let CalDavError::PreconditionFailed(href) = &e {
+                    tracing::info!(
+                        "CalDAV item changed during sync, retrying next time: {}",
+                        href
+                    );
+                    report.conflicts += 1;
+                } else {
+                    report.errors.push(format!("{}: {}", item.href, e));
+                }
+                records.extend(item.previous.clone());
+            }
+        }
+    }
+
+    binding.items = records;
+    // 自分の書き込みでCTagが変わるため、書き込んだ場合は次回の一覧取得を省略しない
+    binding.ctag = if report.pushed() || !report.errors.is_empty() {
+        None
+    } else {
+        ctag
+    };
+    binding.last_synced_at = Some(Utc::now());
+    Ok(report)
+}
+
+/// 対応付けられた全てのタスクリストを同期
+///
+/// 1つのカレンダーで失敗しても他のカレンダーの同期は続ける。
+pub async fn sync_all<R, C>(
+    repositories: &R,
+    remote: &C,
+    state: &mut CalDavSyncState,
+    user_id: &UserId,
+) -> SyncReport
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync,
+    C: CalDavRemote,
+{
+    let mut report = SyncReport::default();
+    for binding in &mut state.bindings {
+        match sync_binding(repositories, remote, binding, user_id).await {
+            Ok(result) => report.add(result),
+            Err(e) => {
+                tracing::error!("CalDAV sync failed for {}: {}", binding.calendar_href, e);
+                report
+                    .errors
+                    .push(format!("{}: {}", binding.calendar_href, e));
+            }
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use flequit_model::types::id_types::{ProjectId, TaskListId};
+    use flequit_model::types::task_types::TaskStatus;
+
+    fn binding() -> CalendarBinding {
+        CalendarBinding::new(ProjectId::new(), TaskListId::new(), "/alice/work/", None)
+    }
+
+    fn task(binding: &CalendarBinding, title: &str) -> Task {
+        let mut task = new_task(
+            binding,
+            TaskId::new(),
+            &TodoFields {
+                summary: title.to_string(),
+                ..Default::default()
+            },
+            0,
+            &UserId::new(),
+        );
+        task.updated_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        task
+    }
+
+    fn record(binding: &CalendarBinding, task: &Task, etag: &str) -> SyncRecord {
+        SyncRecord {
+            task_id: task.id,
+            href: binding.item_href(&task.id),
+            uid: task_uid(&task.id),
+            etag: Some(etag.to_string()),
+            base: TodoFields::from_task(task),
+        }
+    }
+
+    fn remote_item(href: &str, etag: &str, uid: &str, fields: &TodoFields) -> RemoteItem {
+        let modified = Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap();
+        RemoteItem {
+            href: href.to_string(),
+            etag: Some(etag.to_string()),
+            data: new_vtodo(uid, fields, &modified, &modified),
+        }
+    }
+
+    #[test]
+    fn test_new_items_on_both_sides() {
+        let binding = binding();
+        let local = task(&binding, "Local");
+        let fields = TodoFields {
+            summary: "Remote".to_string(),
+            ..Default::default()
+        };
+        let etags = HashMap::from([("/alice/work/r.ics".to_string(), Some("\"1\"".to_string()))]);
+        assert_eq!(
+            hrefs_to_fetch(&binding, std::slice::from_ref(&local), &etags),
+            vec!["/alice/work/r.ics"]
+        );
+        let fetched = HashMap::from([(
+            "/alice/work/r.ics".to_string(),
+            remote_item("/alice/work/r.ics", "\"1\"", "r", &fields),
+        )]);
+
+        let plan = plan_binding(&binding, std::slice::from_ref(&local), &etags, &fetched);
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].local, LocalChange::Create);
+        assert_eq!(plan[0].merged.summary, "Remote");
+        assert_eq!(plan[0].uid, "r");
+        assert_eq!(plan[1].remote, RemoteChange::Create);
+        assert_eq!(plan[1].href, binding.item_href(&local.id));
+        assert_eq!(plan[1].uid, task_uid(&local.id));
+    }
+
+    #[test]
+    fn test_field_level_merge_of_existing_item() {
+        let mut binding = binding();
+        let mut local = task(&binding, "Report");
+        binding.items.push(record(&binding, &local, "\"1\""));
+        let href = binding.items[0].href.clone();
+
+        // ローカルでは優先度、リモートではステータスを変更
+        local.priority = 1;
+        let mut remote_fields = binding.items[0].base.clone();
+        remote_fields.status = TaskStatus::Completed;
+        let etags = HashMap::from([(href.clone(), Some("\"2\"".to_string()))]);
+        assert_eq!(
+            hrefs_to_fetch(&binding, std::slice::from_ref(&local), &etags),
+            vec![href.clone()]
+        );
+        let fetched = HashMap::from([(
+            href.clone(),
+            remote_item(&href, "\"2\"", &binding.items[0].uid, &remote_fields),
+        )]);
+
+        let plan = plan_binding(&binding, std::slice::from_ref(&local), &etags, &fetched);
+        let item = &plan[0];
+        assert_eq!(item.merged.priority, 1);
+        assert_eq!(item.merged.status, TaskStatus::Completed);
+        assert_eq!(item.conflicts, 0);
+        assert_eq!(item.etag.as_deref(), Some("\"2\""));
+        assert!(matches!(item.local, LocalChange::Update { .. }));
+        assert!(matches!(
+            &item.remote,
+            RemoteChange::Update {
+                original: Some(_),
+                ..
+            }
+        ));
+
+        let mut updated = local.clone();
+        if let LocalChange::Update { previous } = &item.local {
+            item.merged.apply_changes(previous, &mut updated);
+        }
+        assert_eq!(updated.status, TaskStatus::Completed);
+        assert_eq!(updated.priority, 1);
+    }
+
+    #[test]
+    fn test_conflict_prefers_newer_side() {
+        let mut binding = binding();
+        let mut local = task(&binding, "Report");
+        binding.items.push(record(&binding, &local, "\"1\""));
+        let href = binding.items[0].href.clone();
+
+        local.title = "Local title".to_string();
+        let mut remote_fields = binding.items[0].base.clone();
+        remote_fields.summary = "Remote title".to_string();
+        let etags = HashMap::from([(href.clone(), Some("\"2\"".to_string()))]);
+        let fetched = HashMap::from([(
+            href.clone(),
+            remote_item(&href, "\"2\"", &binding.items[0].uid, &remote_fields),
+        )]);
+
+        // リモートの方が新しい
+        let plan = plan_binding(&binding, std::slice::from_ref(&local), &etags, &fetched);
+        assert_eq!(plan[0].merged.summary, "Remote title");
+        assert_eq!(plan[0].conflicts, 1);
+        assert_eq!(plan[0].remote, RemoteChange::None);
+
+        local.updated_at += Duration::days(7);
+        let plan = plan_binding(&binding, &[local], &etags, &fetched);
+        assert_eq!(plan[0].merged.summary, "Local title");
+    }
+
+    #[test]
+    fn test_deletions() {
+        let mut binding = binding();
+        let unchanged = task(&binding, "Unchanged");
+        let mut edited = task(&binding, "Edited");
+        let deleted_locally = task(&binding, "Deleted locally");
+        binding.items.push(record(&binding, &unchanged, "\"1\""));
+        binding.items.push(record(&binding, &edited, "\"1\""));
+        binding
+            .items
+            .push(record(&binding, &deleted_locally, "\"1\""));
+        edited.title = "Edited after remote delete".to_string();
+
+        // 最初の2件はリモートで削除、3件目はリモートでは変更なし
+        let etags = HashMap::from([(binding.items[2].href.clone(), Some("\"1\"".to_string()))]);
+        let plan = plan_binding(&binding, &[unchanged, edited], &etags, &HashMap::new());
+
+        assert_eq!(plan[0].local, LocalChange::Delete);
+        assert_eq!(plan[1].remote, RemoteChange::Create);
+        assert_eq!(plan[1].merged.summary, "Edited after remote delete");
+        assert_eq!(plan[2].remote, RemoteChange::Delete);
+        assert_eq!(plan[2].etag.as_deref(), Some("\"1\""));
+    }
+
+    #[test]
+    fn test_relinks_by_uid_when_record_is_missing() {
+        let binding = binding();
+        let local = task(&binding, "Report");
+        let href = binding.item_href(&local.id);
+        let etags = HashMap::from([(href.clone(), Some("\"1\"".to_string()))]);
+        let fetched = HashMap::from([(
+            href.clone(),
+            remote_item(
+                &href,
+                "\"1\"",
+                &task_uid(&local.id),
+                &TodoFields::from_task(&local),
+            ),
+        )]);
+
+        let plan = plan_binding(&binding, std::slice::from_ref(&local), &etags, &fetched);
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].task_id, local.id);
+        assert_eq!(plan[0].local, LocalChange::None);
+        assert_eq!(plan[0].remote, RemoteChange::None);
+    }
+}
@@ -0,0 +1,72 @@
+//! CalDAV同期のエラー定義
+
+use flequit_types::errors::repository_error::RepositoryError;
+use flequit_types::errors::service_error::ServiceError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CalDavError {
+    #[error("HTTP error: {0}")]
+    HttpError(String),
+
+    #[error("Unexpected response {status} for {method} {url}")]
+    UnexpectedStatus {
+        method: String,
+        url: String,
+        status: u16,
+    },
+
+    /// ETagが一致しない（他のクライアントが先に更新した）
+    #[error("Precondition failed for {0}")]
+    PreconditionFailed(String),
+
+    #[error("Invalid XML response: {0}")]
+    InvalidXml(String),
+
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(String),
+
+    #[error("IO error: {0}")]
+    IOError(String),
+
+    #[error("Invalid sync state: {0}")]
+    InvalidState(String),
+
+    #[error("Service error: {0}")]
+    ServiceError(String),
+}
+
+impl From<reqwest::Error> for CalDavError {
+    fn from(err: reqwest::Error) -> Self {
+        CalDavError::HttpError(err.to_string())
+    }
+}
+
+impl From<quick_xml::Error> for CalDavError {
+    fn from(err: quick_xml::Error) -> Self {
+        CalDavError::InvalidXml(err.to_string())
+    }
+}
+
+impl From<std::io::Error> for CalDavError {
+    fn from(err: std::io::Error) -> Self {
+        CalDavError::IOError(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for CalDavError {
+    fn from(err: serde_json::Error) -> Self {
+        CalDavError::InvalidState(err.to_string())
+    }
+}
+
+impl From<ServiceError> for CalDavError {
+    fn from(err: ServiceError) -> Self {
+        CalDavError::ServiceError(err.to_string())
+    }
+}
+
+impl From<RepositoryError> for CalDavError {
+    fn from(err: RepositoryError) -> Self {
+        CalDavError::ServiceError(err.to_string())
+    }
+}
@@ -0,0 +1,132 @@
+//! フィールド単位の3-wayマージ
+
+use super::vtodo::TodoFields;
+
+fn pick<T: PartialEq + Clone>(
+    base: &T,
+    local: &T,
+    remote: &T,
+    prefer_local: bool,
+    conflicts: &mut usize,
+) -> T {
+    match (local != base, remote != base) {
+        (false, _) => remote.clone(),
+        (true, false) => local.clone(),
+        (true, true) if local == remote => local.clone(),
+        (true, true) => {
+            *conflicts += 1;
+            if prefer_local {
+                local.clone()
+            } else {
+                remote.clone()
+            }
+        }
+    }
+}
+
+/// 前回同期時の値（`base`）を基準に、ローカルとリモートの変更をフィールドごとに統合する
+///
+/// 同じフィールドが両方で変更されていた場合は `prefer_local` に従い、衝突数を返す。
+pub fn merge_fields(
+    base: &TodoFields,
+    local: &TodoFields,
+    remote: &TodoFields,
+    prefer_local: bool,
+) -> (TodoFields, usize) {
+    let mut conflicts = 0;
+    let merged = TodoFields {
+        summary: pick(
+            &base.summary,
+            &local.summary,
+            &remote.summary,
+            prefer_local,
+            &mut conflicts,
+        ),
+        description: pick(
+            &base.description,
+            &local.description,
+            &remote.description,
+            prefer_local,
+            &mut conflicts,
+        ),
+        status: pick(
+            &base.status,
+            &local.status,
+            &remote.status,
+            prefer_local,
+            &mut conflicts,
+        ),
+        priority: pick(
+            &base.priority,
+            &local.priority,
+            &remote.priority,
+            prefer_local,
+            &mut conflicts,
+        ),
+        start: pick(
+            &base.start,
+            &local.start,
+            &remote.start,
+            prefer_local,
+            &mut conflicts,
+        ),
+        due: pick(
+            &base.due,
+            &local.due,
+            &remote.due,
+            prefer_local,
+            &mut conflicts,
+        ),
+    };
+    (merged, conflicts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flequit_model::types::task_types::TaskStatus;
+
+    fn base() -> TodoFields {
+        TodoFields {
+            summary: "Report".to_string(),
+            priority: 2,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_non_overlapping_changes_are_combined() {
+        let mut local = base();
+        local.summary = "Quarterly report".to_string();
+        let mut remote = base();
+        remote.status = TaskStatus::Completed;
+
+        let (merged, conflicts) = merge_fields(&base(), &local, &remote, false);
+        assert_eq!(merged.summary, "Quarterly report");
+        assert_eq!(merged.status, TaskStatus::Completed);
+        assert_eq!(conflicts, 0);
+    }
+
+    #[test]
+    fn test_conflicting_field_uses_preferred_side() {
+        let mut local = base();
+        local.priority = 1;
+        let mut remote = base();
+        remote.priority = 4;
+
+        assert_eq!(
+            merge_fields(&base(), &local, &remote, true),
+            (local.clone(), 1)
+        );
+        assert_eq!(
+            merge_fields(&base(), &local, &remote, false),
+            (remote.clone(), 1)
+        );
+
+        // 同じ値への変更は衝突ではない
+        assert_eq!(
+            merge_fields(&base(), &local, &local, false),
+            (local.clone(), 0)
+        );
+    }
+}
@@ -0,0 +1,23 @@
+//! CalDAVによるタスクの双方向同期
+//!
+//! タスクリストをカレンダーコレクションに、タスクをVTODOに対応付ける。
+//! 変更の検出にはCTag・ETagを使用し、前回同期時の値を基準にフィールド単位でマージする。
+//! タスクごとのリモートのhref・ETagは同期状態ファイル（[`CalDavSyncState`]）に記録する。
+//!
+//! サブタスク、タグ、繰り返し設定は同期の対象外。
+
+pub mod client;
+pub mod engine;
+pub mod error;
+pub mod merge;
+pub mod remote;
+pub mod state;
+pub mod vtodo;
+pub mod xml;
+
+pub use client::{CalDavClient, CalDavCredentials};
+pub use engine::{SyncReport, sync_all, sync_binding};
+pub use error::CalDavError;
+pub use remote::{CalDavRemote, RemoteCalendar, RemoteItem};
+pub use state::{CalDavSyncState, CalendarBinding, SyncRecord, get_default_caldav_state_path};
+pub use vtodo::TodoFields;
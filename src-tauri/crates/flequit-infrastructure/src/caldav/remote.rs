@@ -0,0 +1,54 @@
+//! CalDAVサーバーへのアクセス抽象
+
+use super::error::CalDavError;
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// カレンダーコレクション
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteCalendar {
+    pub href: String,
+    pub display_name: Option<String>,
+    pub ctag: Option<String>,
+}
+
+/// カレンダーオブジェクト
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteItem {
+    pub href: String,
+    pub etag: Option<String>,
+    pub data: String,
+}
+
+/// 同期エンジンが使用するサーバー操作
+#[async_trait]
+pub trait CalDavRemote: Send + Sync {
+    /// カレンダーのCTag（非対応のサーバーは `None`）
+    async fn get_ctag(&self, calendar_href: &str) -> Result<Option<String>, CalDavError>;
+
+    /// カレンダー内のVTODOのhrefとETag
+    async fn list_etags(
+        &self,
+        calendar_href: &str,
+    ) -> Result<HashMap<String, Option<String>>, CalDavError>;
+
+    /// 指定したhrefの内容を取得（存在しないものは結果に含まれない）
+    async fn fetch(
+        &self,
+        calendar_href: &str,
+        hrefs: &[String],
+    ) -> Result<Vec<RemoteItem>, CalDavError>;
+
+    /// 作成（`etag` が `None`）または更新し、新しいETagを返す
+    ///
+    /// ETagが一致しない場合は `CalDavError::PreconditionFailed` を返す。
+    async fn put(
+        &self,
+        href: &str,
+        data: &str,
+        etag: Option<&str>,
+    ) -> Result<Option<String>, CalDavError>;
+
+    /// 削除（既に存在しない場合は成功とみなす）
+    async fn delete(&self, href: &str, etag: Option<&str>) -> Result<(), CalDavError>;
+}
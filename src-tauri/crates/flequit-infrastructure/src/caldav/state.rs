@@ -0,0 +1,177 @@
+//! 同期状態（タスクリストとカレンダーの対応、タスクごとのリモートhref・ETag・前回同期時の値）
+
+use super::error::CalDavError;
+use super::vtodo::TodoFields;
+use chrono::{DateTime, Utc};
+use flequit_model::types::id_types::{ProjectId, TaskId, TaskListId};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// 同期状態ファイルのデフォルトの保存先
+pub fn get_default_caldav_state_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|data_dir| {
+        data_dir
+            .join("flequit")
+            .join("caldav")
+            .join("sync_state.json")
+    })
+}
+
+/// タスクとリモートのVTODOの対応
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncRecord {
+    pub task_id: TaskId,
+    /// カレンダーオブジェクトのhref（サーバーが返した形式のまま保持）
+    pub href: String,
+    pub uid: String,
+    /// 最後に確認したETag（PUTのレスポンスに含まれなかった場合は `None`）
+    pub etag: Option<String>,
+    /// 前回同期した時点の値（3-wayマージの基準）
+    pub base: TodoFields,
+}
+
+/// タスクリストとカレンダーの対応
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarBinding {
+    pub project_id: ProjectId,
+    pub task_list_id: TaskListId,
+    /// カレンダーコレクションのhref（末尾は `/`）
+    pub calendar_href: String,
+    pub display_name: Option<String>,
+    /// 前回同期時のCTag（変化が無ければリモートの一覧取得を省略する）
+    pub ctag: Option<String>,
+    #[serde(default)]
+    pub items: Vec<SyncRecord>,
+    pub last_synced_at: Option<DateTime<Utc>>,
+}
+
+impl CalendarBinding {
+    pub fn new(
+        project_id: ProjectId,
+        task_list_id: TaskListId,
+        calendar_href: &str,
+        display_name: Option<String>,
+    ) -> Self {
+        let calendar_href = if calendar_href.ends_with('/') {
+            calendar_href.to_string()
+        } else {
+            format!("{calendar_href}/")
+        };
+        Self {
+            project_id,
+            task_list_id,
+            calendar_href,
+            display_name,
+            ctag: None,
+            items: Vec::new(),
+            last_synced_at: None,
+        }
+    }
+
+    /// 新しく作成するカレンダーオブジェクトのhref
+    pub fn item_href(&self, task_id: &TaskId) -> String {
+        format!("{}{}.ics", self.calendar_href, task_id)
+    }
+}
+
+/// CalDAV同期状態
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CalDavSyncState {
+    /// カレンダーホームのURL
+    pub server_url: Option<String>,
+    pub username: Option<String>,
+    #[serde(default)]
+    pub bindings: Vec<CalendarBinding>,
+}
+
+impl CalDavSyncState {
+    /// ファイルから読み込む（存在しない場合は空の状態）
+    pub fn load(path: &Path) -> Result<Self, CalDavError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// 一時ファイルへ書き込んでから置き換える
+    pub fn save(&self, path: &Path) -> Result<(), CalDavError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let temp_path = path.with_extension("json.tmp");
+        std::fs::write(&temp_path, serde_json::to_string_pretty(self)?)?;
+        std::fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    pub fn binding(&self, task_list_id: &TaskListId) -> Option<&CalendarBinding> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.task_list_id == *task_list_id)
+    }
+
+    /// タスクリストをカレンダーに対応付ける（既存の対応は置き換え、同期記録は破棄する）
+    pub fn bind(&mut self, binding: CalendarBinding) -> Result<(), CalDavError> {
+        if self.bindings.iter().any(|b| {
+            b.calendar_href == binding.calendar_href && b.task_list_id != binding.task_list_id
+        }) {
+            return Err(CalDavError::InvalidState(format!(
+                "Calendar {} is already bound to another task list",
+                binding.calendar_href
+            )));
+        }
+        self.unbind(&binding.task_list_id);
+        self.bindings.push(binding);
+        Ok(())
+    }
+
+    /// 対応を解除（ローカル・リモートのデータは削除しない）
+    pub fn unbind(&mut self, task_list_id: &TaskListId) -> bool {
+        let before = self.bindings.len();
+        self.bindings
+            .retain(|binding| binding.task_list_id != *task_list_id);
+        self.bindings.len() != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flequit_testing::TestPathGenerator;
+
+    #[test]
+    fn test_bind_save_and_load() {
+        let dir = TestPathGenerator::generate_test_dir(file!(), "test_bind_save_and_load");
+        let path = dir.join("sync_state.json");
+
+        let list_a = TaskListId::new();
+        let list_b = TaskListId::new();
+        let mut state = CalDavSyncState::default();
+        state
+            .bind(CalendarBinding::new(
+                ProjectId::new(),
+                list_a,
+                "/alice/work",
+                None,
+            ))
+            .unwrap();
+        assert_eq!(state.bindings[0].calendar_href, "/alice/work/");
+        assert!(
+            state
+                .bind(CalendarBinding::new(
+                    ProjectId::new(),
+                    list_b,
+                    "/alice/work/",
+                    None
+                ))
+                .is_err()
+        );
+
+        state.save(&path).unwrap();
+        assert_eq!(CalDavSyncState::load(&path).unwrap(), state);
+
+        assert!(state.unbind(&list_a));
+        assert!(state.binding(&list_a).is_none());
+    }
+}
@@ -0,0 +1,512 @@
+//! VTODOとタスクの相互変換
+//!
+//! サーバー上のVTODOを書き換える際は、変更したフィールドに対応するプロパティだけを差し替え、
+//! VALARMや他のクライアント固有のプロパティはそのまま残す。
+//!
+//! タイムゾーンの定義は持たないため、`TZID` 付きや浮動時刻の日時はUTCとして扱う。
+//! 差し替えるのは変更したフィールドだけなので、読み込んだだけの日時がサーバー上で書き換わることはない。
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Timelike, Utc};
+use flequit_core::exporters::ical::writer::{escape_text, format_datetime, write_line};
+use flequit_model::models::task_projects::task::Task;
+use flequit_model::types::task_types::TaskStatus;
+use serde::{Deserialize, Serialize};
+
+const PRODID: &str = "-//Flequit//Flequit CalDAV//EN";
+
+/// 同期対象のフィールド（フィールド単位でマージする）
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct TodoFields {
+    pub summary: String,
+    pub description: Option<String>,
+    pub status: TaskStatus,
+    /// Flequitの優先度（1が最高、4が最低、0は未設定）
+    pub priority: i32,
+    pub start: Option<DateTime<Utc>>,
+    pub due: Option<DateTime<Utc>>,
+}
+
+/// iCalendarは秒単位のため、比較の前にミリ秒以下を切り捨てる
+fn truncate(value: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+    value.map(|v| v.with_nanosecond(0).unwrap_or(v))
+}
+
+impl TodoFields {
+    pub fn from_task(task: &Task) -> Self {
+        Self {
+            summary: task.title.clone(),
+            description: task.description.clone().filter(|d| !d.is_empty()),
+            status: task.status.clone(),
+            priority: if (1..=4).contains(&task.priority) {
+                task.priority
+            } else {
+                0
+            },
+            start: truncate(task.plan_start_date),
+            due: truncate(task.plan_end_date),
+        }
+    }
+
+    /// `previous`（タスクから作成した値）と異なるフィールドだけをタスクへ反映
+    pub fn apply_changes(&self, previous: &TodoFields, task: &mut Task) {
+        if self.summary != previous.summary {
+            task.title = self.summary.clone();
+        }
+        if self.description != previous.description {
+            task.description = self.description.clone();
+        }
+        if self.status != previous.status {
+            // CalDAVにはワークフローステータスがないため、ステータスが変わったら参照を外す
+            task.status = self.status.clone();
+            task.workflow_status_id = None;
+        }
+        if self.priority != previous.priority {
+            task.priority = self.priority;
+        }
+        if self.start != previous.start {
+            task.plan_start_date = self.start;
+        }
+        if self.due != previous.due {
+            task.plan_end_date = self.due;
+        }
+    }
+}
+
+/// サーバーから取得したVTODO
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteTodo {
+    pub uid: String,
+    pub fields: TodoFields,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+/// 1行分のプロパティ（`NAME;PARAMS:VALUE`）
+struct ContentLine<'a> {
+    name: String,
+    params: &'a str,
+    value: &'a str,
+}
+
+fn parse_line(line: &str) -> Option<ContentLine<'_>> {
+    // パラメータ値の引用符内の `:` は区切りとみなさない
+    let mut quoted = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            quoted = !quoted;
+            None
+        }
+        ':' if !quoted => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let (name, params) = head.split_once(';').unwrap_or((head, ""));
+    Some(ContentLine {
+        name: name.to_ascii_uppercase(),
+        params,
+        value,
+    })
+}
+
+/// 折り返された行を元に戻す
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if let Some(rest) = line.strip_prefix([' ', '\t'])
+            && let Some(last) = lines.last_mut()
+        {
+            last.push_str(rest);
+        } else if !line.is_empty() {
+            lines.push(line.to_string());
+        }
+    }
+    lines
+}
+
+fn unescape_text(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => {}
+        }
+    }
+    unescaped
+}
+
+fn parse_datetime_value(params: &str, value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if params.to_ascii_uppercase().contains("VALUE=DATE") && !value.contains('T')
+        || value.len() == 8
+    {
+        return NaiveDate::parse_from_str(value, "%Y%m%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|dt| dt.and_utc());
+    }
+    NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), "%Y%m%dT%H%M%S")
+        .ok()
+        .map(|dt| dt.and_utc())
+}
+
+fn to_ical_status(status: &TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::NotStarted | TaskStatus::Waiting => "NEEDS-ACTION",
+        TaskStatus::InProgress => "IN-PROCESS",
+        TaskStatus::Completed => "COMPLETED",
+        TaskStatus::Cancelled => "CANCELLED",
+    }
+}
+
+fn flequit_status_value(status: &TaskStatus) -> String {
+    serde_json::to_value(status)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// STATUSと `X-FLEQUIT-STATUS` からステータスを決定
+///
+/// 他のクライアントがSTATUSだけを書き換えた場合はSTATUSを優先する。
+fn status_from(ical: Option<&str>, flequit: Option<&str>, completed: bool) -> TaskStatus {
+    let ical = ical.map(str::to_ascii_uppercase).unwrap_or_else(|| {
+        if completed {
+            "COMPLETED"
+        } else {
+            "NEEDS-ACTION"
+        }
+        .to_string()
+    });
+    if let Some(status) = flequit
+        .and_then(|s| {
+            serde_json::from_value::<TaskStatus>(serde_json::Value::String(s.to_string())).ok()
+        })
+        .filter(|status| to_ical_status(status) == ical)
+    {
+        return status;
+    }
+    match ical.as_str() {
+        "IN-PROCESS" => TaskStatus::InProgress,
+        "COMPLETED" => TaskStatus::Completed,
+        "CANCELLED" => TaskStatus::Cancelled,
+        _ => TaskStatus::NotStarted,
+    }
+}
+
+/// iCalendarの優先度（1〜9）をFlequitの優先度へ変換
+fn from_ical_priority(priority: i32) -> i32 {
+    match priority {
+        1..=4 => 1,
+        5 => 2,
+        6 | 7 => 3,
+        8 | 9 => 4,
+        _ => 0,
+    }
+}
+
+fn to_ical_priority(priority: i32) -> Option<i32> {
+    match priority {
+        1 => Some(1),
+        2 => Some(5),
+        3 => Some(7),
+        4 => Some(9),
+        _ => None,
+    }
+}
+
+/// カレンダーオブジェクトから最初のVTODOを読み込む
+pub fn parse_vtodo(ics: &str) -> Option<RemoteTodo> {
+    let mut in_todo = false;
+    let mut nested = 0usize;
+    let mut uid = None;
+    let mut fields = TodoFields::default();
+    let mut status = None;
+    let mut flequit_status = None;
+    let mut completed = false;
+    let mut last_modified = None;
+
+    for line in unfold(ics) {
+        let Some(line) = parse_line(&line) else {
+            continue;
+        };
+        match (line.name.as_str(), line.value.to_ascii_uppercase().as_str()) {
+            ("BEGIN", "VTODO") if !in_todo => {
+                in_todo = true;
+                continue;
+            }
+            ("END", "VTODO") if in_todo && nested == 0 => break,
+            ("BEGIN", _) if in_todo => nested += 1,
+            ("END", _) if in_todo => nested = nested.saturating_sub(1),
+            _ => {}
+        }
+        if !in_todo || nested > 0 {
+            continue;
+        }
+        match line.name.as_str() {
+            "UID" => uid = Some(line.value.trim().to_string()),
+            "SUMMARY" => fields.summary = unescape_text(line.value),
+            "DESCRIPTION" => {
+                fields.description = Some(unescape_text(line.value)).filter(|d| !d.is_empty())
+            }
+            "STATUS" => status = Some(line.value.trim().to_string()),
+            "X-FLEQUIT-STATUS" => flequit_status = Some(line.value.trim().to_string()),
+            "COMPLETED" => completed = true,
+            "PRIORITY" => {
+                fields.priority = from_ical_priority(line.value.trim().parse().unwrap_or(0))
+            }
+            "DTSTART" => fields.start = parse_datetime_value(line.params, line.value),
+            "DUE" => fields.due = parse_datetime_value(line.params, line.value),
+            "LAST-MODIFIED" => last_modified = parse_datetime_value(line.params, line.value),
+            _ => {}
+        }
+    }
+
+    if !in_todo {
+        return None;
+    }
+    fields.status = status_from(status.as_deref(), flequit_status.as_deref(), completed);
+    Some(RemoteTodo {
+        uid: uid?,
+        fields,
+        last_modified,
+    })
+}
+
+/// フィールドに対応するプロパティ名
+fn managed_properties(fields: &TodoFields, previous: Option<&TodoFields>) -> Vec<&'static str> {
+    let changed = |f: &dyn Fn(&TodoFields) -> bool| previous.is_none_or(f);
+    let mut names = vec!["DTSTAMP", "LAST-MODIFIED", "SEQUENCE"];
+    if changed(&|p| p.summary != fields.summary) {
+        names.push("SUMMARY");
+    }
+    if changed(&|p| p.description != fields.description) {
+        names.push("DESCRIPTION");
+    }
+    if changed(&|p| p.status != fields.status) {
+        names.extend([
+            "STATUS",
+            "COMPLETED",
+            "PERCENT-COMPLETE",
+            "X-FLEQUIT-STATUS",
+        ]);
+    }
+    if changed(&|p| p.priority != fields.priority) {
+        names.push("PRIORITY");
+    }
+    if changed(&|p| p.start != fields.start) {
+        names.push("DTSTART");
+    }
+    if changed(&|p| p.due != fields.due) {
+        names.push("DUE");
+    }
+    names
+}
+
+fn write_properties(
+    out: &mut String,
+    names: &[&str],
+    fields: &TodoFields,
+    sequence: u32,
+    now: &DateTime<Utc>,
+) {
+    let now = format_datetime(now);
+    for name in names {
+        match *name {
+            "DTSTAMP" | "LAST-MODIFIED" => write_line(out, &format!("{name}:{now}")),
+            "SEQUENCE" => write_line(out, &format!("SEQUENCE:{sequence}")),
+            "SUMMARY" => write_line(out, &format!("SUMMARY:{}", escape_text(&fields.summary))),
+            "DESCRIPTION" => {
+                if let Some(description) = &fields.description {
+                    write_line(out, &format!("DESCRIPTION:{}", escape_text(description)));
+                }
+            }
+            "STATUS" => write_line(out, &format!("STATUS:{}", to_ical_status(&fields.status))),
+            "X-FLEQUIT-STATUS" => write_line(
+                out,
+                &format!("X-FLEQUIT-STATUS:{}", flequit_status_value(&fields.status)),
+            ),
+            "COMPLETED" if fields.status == TaskStatus::Completed => {
+                write_line(out, &format!("COMPLETED:{now}"));
+            }
+            "PERCENT-COMPLETE" if fields.status == TaskStatus::Completed => {
+                write_line(out, "PERCENT-COMPLETE:100");
+            }
+            "PRIORITY" => {
+                if let Some(priority) = to_ical_priority(fields.priority) {
+                    write_line(out, &format!("PRIORITY:{priority}"));
+                }
+            }
+            "DTSTART" => {
+                if let Some(start) = &fields.start {
+                    write_line(out, &format!("DTSTART:{}", format_datetime(start)));
+                }
+            }
+            "DUE" => {
+                if let Some(due) = &fields.due {
+                    write_line(out, &format!("DUE:{}", format_datetime(due)));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 新しいカレンダーオブジェクトを作成
+///
+/// CalDAVのリソースには `METHOD` を含めてはならないため、エクスポート用の書き出しとは別に生成する。
+pub fn new_vtodo(
+    uid: &str,
+    fields: &TodoFields,
+    created_at: &DateTime<Utc>,
+    now: &DateTime<Utc>,
+) -> String {
+    let mut out = String::new();
+    write_line(&mut out, "BEGIN:VCALENDAR");
+    write_line(&mut out, "VERSION:2.0");
+    write_line(&mut out, &format!("PRODID:{PRODID}"));
+    write_line(&mut out, "BEGIN:VTODO");
+    write_line(&mut out, &format!("UID:{uid}"));
+    write_line(
+        &mut out,
+        &format!("CREATED:{}", format_datetime(created_at)),
+    );
+    write_properties(&mut out, &managed_properties(fields, None), fields, 0, now);
+    write_line(&mut out, "END:VTODO");
+    write_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+/// 既存のカレンダーオブジェクトの、`previous` から変更されたフィールドだけを書き換える
+pub fn update_vtodo(
+    original: &str,
+    previous: &TodoFields,
+    fields: &TodoFields,
+    now: &DateTime<Utc>,
+) -> String {
+    let names = managed_properties(fields, Some(previous));
+    let mut out = String::new();
+    let mut in_todo = false;
+    let mut done = false;
+    let mut nested = 0usize;
+    let mut sequence = 0u32;
+
+    for raw in unfold(original) {
+        let Some(line) = parse_line(&raw) else {
+            continue;
+        };
+        let value = line.value.to_ascii_uppercase();
+        if !done && in_todo {
+            match (line.name.as_str(), value.as_str()) {
+                ("END", "VTODO") if nested == 0 => {
+                    write_properties(&mut out, &names, fields, sequence + 1, now);
+                    in_todo = false;
+                    done = true;
+                }
+                ("BEGIN", _) => nested += 1,
+                ("END", _) => nested = nested.saturating_sub(1),
+                ("SEQUENCE", _) if nested == 0 => {
+                    sequence = line.value.trim().parse().unwrap_or(0);
+                    continue;
+                }
+                (name, _) if nested == 0 && names.contains(&name) => continue,
+                _ => {}
+            }
+        } else if !done && line.name == "BEGIN" && value == "VTODO" {
+            in_todo = true;
+        }
+        write_line(&mut out, &raw);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn fields() -> TodoFields {
+        TodoFields {
+            summary: "Write report, part 1".to_string(),
+            description: Some("line1\nline2".to_string()),
+            status: TaskStatus::Waiting,
+            priority: 2,
+            start: None,
+            due: Some(Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let now = Utc::now();
+        let ics = new_vtodo("task-1@flequit.app", &fields(), &now, &now);
+        assert!(!ics.contains("METHOD"));
+
+        let todo = parse_vtodo(&ics).unwrap();
+        assert_eq!(todo.uid, "task-1@flequit.app");
+        assert_eq!(todo.fields, fields());
+    }
+
+    #[test]
+    fn test_status_changed_by_other_client() {
+        let now = Utc::now();
+        let ics = new_vtodo("u", &fields(), &now, &now)
+            .replace("STATUS:NEEDS-ACTION", "STATUS:COMPLETED");
+        assert_eq!(
+            parse_vtodo(&ics).unwrap().fields.status,
+            TaskStatus::Completed
+        );
+    }
+
+    #[test]
+    fn test_parse_foreign_todo() {
+        let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VTODO\r\nUID:abc\r\nSUMMARY:Buy\r\n  milk\r\n\
+            DUE;VALUE=DATE:20250401\r\nDTSTART;TZID=Europe/Berlin:20250330T080000\r\nPRIORITY:9\r\n\
+            BEGIN:VALARM\r\nACTION:DISPLAY\r\nDESCRIPTION:Reminder\r\nEND:VALARM\r\nEND:VTODO\r\nEND:VCALENDAR\r\n";
+        let todo = parse_vtodo(ics).unwrap();
+        assert_eq!(todo.fields.summary, "Buy milk");
+        // VALARM内のDESCRIPTIONはタスクの説明ではない
+        assert_eq!(todo.fields.description, None);
+        assert_eq!(todo.fields.priority, 4);
+        assert_eq!(
+            todo.fields.due,
+            Some(Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap())
+        );
+        assert!(todo.fields.start.is_some());
+        assert_eq!(todo.fields.status, TaskStatus::NotStarted);
+    }
+
+    #[test]
+    fn test_update_keeps_unknown_properties() {
+        let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VTODO\r\nUID:abc\r\nSUMMARY:Buy milk\r\n\
+            DTSTART;TZID=Europe/Berlin:20250330T080000\r\nSEQUENCE:3\r\nX-APPLE-SORT-ORDER:5\r\n\
+            BEGIN:VALARM\r\nACTION:DISPLAY\r\nDESCRIPTION:Reminder\r\nEND:VALARM\r\nEND:VTODO\r\nEND:VCALENDAR\r\n";
+        let previous = parse_vtodo(ics).unwrap().fields;
+        let mut fields = previous.clone();
+        fields.summary = "Buy oat milk".to_string();
+        fields.status = TaskStatus::Completed;
+
+        let updated = update_vtodo(ics, &previous, &fields, &Utc::now());
+        assert!(updated.contains("SUMMARY:Buy oat milk\r\n"));
+        assert!(!updated.contains("SUMMARY:Buy milk\r\n"));
+        assert!(updated.contains("STATUS:COMPLETED\r\n"));
+        assert!(updated.contains("PERCENT-COMPLETE:100\r\n"));
+        assert!(updated.contains("SEQUENCE:4\r\n"));
+        assert!(updated.contains("X-APPLE-SORT-ORDER:5\r\n"));
+        // 変更していない日時とアラームはそのまま
+        assert!(updated.contains("DTSTART;TZID=Europe/Berlin:20250330T080000\r\n"));
+        assert!(
+            updated.contains(
+                "BEGIN:VALARM\r\nACTION:DISPLAY\r\nDESCRIPTION:Reminder\r\nEND:VALARM\r\n"
+            )
+        );
+
+        let reparsed = parse_vtodo(&updated).unwrap();
+        assert_eq!(reparsed.fields, fields);
+    }
+}
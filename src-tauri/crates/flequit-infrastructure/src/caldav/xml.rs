@@ -0,0 +1,319 @@
+//! WebDAV / CalDAV のリクエストボディとmultistatusレスポンスの解析
+
+use super::error::CalDavError;
+use quick_xml::Reader;
+use quick_xml::escape::{escape, resolve_predefined_entity};
+use quick_xml::events::Event;
+
+/// カレンダーホーム直下のカレンダー一覧を取得するPROPFIND
+pub const PROPFIND_CALENDARS: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav" xmlns:cs="http://calendarserver.org/ns/">
+  <d:prop>
+    <d:resourcetype/>
+    <d:displayname/>
+    <cs:getctag/>
+    <c:supported-calendar-component-set/>
+  </d:prop>
+</d:propfind>"#;
+
+/// カレンダーのCTagを取得するPROPFIND
+pub const PROPFIND_CTAG: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:" xmlns:cs="http://calendarserver.org/ns/">
+  <d:prop>
+    <cs:getctag/>
+  </d:prop>
+</d:propfind>"#;
+
+/// カレンダー内の全VTODOのETagを取得するREPORT
+pub const REPORT_TODO_ETAGS: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop>
+    <d:getetag/>
+  </d:prop>
+  <c:filter>
+    <c:comp-filter name="VCALENDAR">
+      <c:comp-filter name="VTODO"/>
+    </c:comp-filter>
+  </c:filter>
+</c:calendar-query>"#;
+
+/// 指定したリソースの内容を取得するREPORT（calendar-multiget）
+pub fn calendar_multiget(hrefs: &[String]) -> String {
+    let hrefs: String = hrefs
+        .iter()
+        .map(|href| format!("  <d:href>{}</d:href>\n", escape(href.as_str())))
+        .collect();
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-multiget xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop>
+    <d:getetag/>
+    <c:calendar-data/>
+  </d:prop>
+{hrefs}</c:calendar-multiget>"#
+    )
+}
+
+/// VTODO用のカレンダーを作成するMKCALENDAR
+pub fn mkcalendar(display_name: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<c:mkcalendar xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:set>
+    <d:prop>
+      <d:displayname>{}</d:displayname>
+      <c:supported-calendar-component-set>
+        <c:comp name="VTODO"/>
+      </c:supported-calendar-component-set>
+    </d:prop>
+  </d:set>
+</c:mkcalendar>"#,
+        escape(display_name)
+    )
+}
+
+/// multistatus内の1リソース分の情報
+///
+/// 200以外のpropstatに含まれるプロパティは無視する。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DavResponse {
+    pub href: String,
+    /// responseの `status` が200番台以外（multigetで存在しないhrefなど）
+    pub missing: bool,
+    pub etag: Option<String>,
+    pub display_name: Option<String>,
+    pub ctag: Option<String>,
+    pub is_calendar: bool,
+    /// 対応しているコンポーネント（空の場合は未指定）
+    pub components: Vec<String>,
+    pub calendar_data: Option<String>,
+}
+
+#[derive(Default)]
+struct PropStat {
+    etag: Option<String>,
+    display_name: Option<String>,
+    ctag: Option<String>,
+    is_calendar: bool,
+    components: Vec<String>,
+    calendar_data: Option<String>,
+    ok: bool,
+}
+
+fn is_success_status(status: &str) -> bool {
+    status
+        .split_whitespace()
+        .nth(1)
+        .is_some_and(|code| code.starts_with('2'))
+}
+
+fn comp_name(element: &quick_xml::events::BytesStart) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key.local_name().as_ref() == b"name")
+        .and_then(|attr| String::from_utf8(attr.value.into_owned()).ok())
+}
+
+/// multistatusレスポンスを解析（名前空間の接頭辞は区別しない）
+pub fn parse_multistatus(xml: &str) -> Result<Vec<DavResponse>, CalDavError> {
+    let mut reader = Reader::from_str(xml);
+    let mut path: Vec<String> = Vec::new();
+    let mut text = String::new();
+    let mut responses = Vec::new();
+    let mut response: Option<DavResponse> = None;
+    let mut propstat: Option<PropStat> = None;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(element) => {
+                let name = String::from_utf8_lossy(element.local_name().as_ref()).to_string();
+                match name.as_str() {
+                    "response" => response = Some(DavResponse::default()),
+                    "propstat" => propstat = Some(PropStat::default()),
+                    "calendar" if path.last().is_some_and(|p| p == "resourcetype") => {
+                        if let Some(propstat) = propstat.as_mut() {
+                            propstat.is_calendar = true;
+                        }
+                    }
+                    "comp" => {
+                        if let (Some(propstat), Some(comp)) =
+                            (propstat.as_mut(), comp_name(&element))
+                        {
+                            propstat.components.push(comp);
+                        }
+                    }
+                    _ => {}
+                }
+                text.clear();
+                path.push(name);
+            }
+            Event::Empty(element) => {
+                let name = element.local_name();
+                if let Some(propstat) = propstat.as_mut() {
+                    match name.as_ref() {
+                        b"calendar" if path.last().is_some_and(|p| p == "resourcetype") => {
+                            propstat.is_calendar = true;
+                        }
+                        b"comp" => {
+                            if let Some(comp) = comp_name(&element) {
+                                propstat.components.push(comp);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Event::Text(content) => {
+                text.push_str(
+                    &content
+                        .decode()
+                        .map_err(|e| CalDavError::InvalidXml(e.to_string()))?,
+                );
+            }
+            Event::CData(content) => {
+                text.push_str(
+                    &content
+                        .decode()
+                        .map_err(|e| CalDavError::InvalidXml(e.to_string()))?,
+                );
+            }
+            Event::GeneralRef(reference) => {
+                if let Some(c) = reference.resolve_char_ref()? {
+                    text.push(c);
+                } else {
+                    let name = reference
+                        .decode()
+                        .map_err(|e| CalDavError::InvalidXml(e.to_string()))?;
+                    let resolved = resolve_predefined_entity(&name).ok_or_else(|| {
+                        CalDavError::InvalidXml(format!("Unknown entity: {name}"))
+                    })?;
+                    text.push_str(resolved);
+                }
+            }
+            Event::End(_) => {
+                let Some(name) = path.pop() else {
+                    return Err(CalDavError::InvalidXml("Unbalanced end tag".to_string()));
+                };
+                let value = std::mem::take(&mut text).trim().to_string();
+                let in_propstat = propstat.is_some();
+                match name.as_str() {
+                    "href" if path.last().is_some_and(|p| p == "response") => {
+                        if let Some(response) = response.as_mut() {
+                            response.href = value;
+                        }
+                    }
+                    "status" if in_propstat && path.last().is_some_and(|p| p == "propstat") => {
+                        if let Some(propstat) = propstat.as_mut() {
+                            propstat.ok = is_success_status(&value);
+                        }
+                    }
+                    "status" if path.last().is_some_and(|p| p == "response") => {
+                        if let Some(response) = response.as_mut() {
+                            response.missing = !is_success_status(&value);
+                        }
+                    }
+                    "getetag" | "displayname" | "getctag" | "calendar-data" => {
+                        if let Some(propstat) = propstat.as_mut() {
+                            let value = Some(value).filter(|v| !v.is_empty());
+                            match name.as_str() {
+                                "getetag" => propstat.etag = value,
+                                "displayname" => propstat.display_name = value,
+                                "getctag" => propstat.ctag = value,
+                                _ => propstat.calendar_data = value,
+                            }
+                        }
+                    }
+                    "propstat" => {
+                        if let (Some(stat), Some(response)) = (propstat.take(), response.as_mut())
+                            && stat.ok
+                        {
+                            response.etag = stat.etag.or(response.etag.take());
+                            response.display_name =
+                                stat.display_name.or(response.display_name.take());
+                            response.ctag = stat.ctag.or(response.ctag.take());
+                            response.is_calendar |= stat.is_calendar;
+                            response.components.extend(stat.components);
+                            response.calendar_data =
+                                stat.calendar_data.or(response.calendar_data.take());
+                        }
+                    }
+                    "response" => {
+                        if let Some(response) = response.take() {
+                            responses.push(response);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(responses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_calendar_list() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<multistatus xmlns="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav" xmlns:CS="http://calendarserver.org/ns/">
+  <response>
+    <href>/alice/</href>
+    <propstat><prop><resourcetype><collection/></resourcetype></prop><status>HTTP/1.1 200 OK</status></propstat>
+    <propstat><prop><CS:getctag/></prop><status>HTTP/1.1 404 Not Found</status></propstat>
+  </response>
+  <response>
+    <href>/alice/work/</href>
+    <propstat>
+      <prop>
+        <resourcetype><collection/><C:calendar/></resourcetype>
+        <displayname>Work &amp; Home</displayname>
+        <CS:getctag>"ctag-1"</CS:getctag>
+        <C:supported-calendar-component-set><C:comp name="VEVENT"/><C:comp name="VTODO"/></C:supported-calendar-component-set>
+      </prop>
+      <status>HTTP/1.1 200 OK</status>
+    </propstat>
+  </response>
+</multistatus>"#;
+        let responses = parse_multistatus(xml).unwrap();
+        assert_eq!(responses.len(), 2);
+        assert!(!responses[0].is_calendar);
+        assert_eq!(responses[0].ctag, None);
+
+        let work = &responses[1];
+        assert_eq!(work.href, "/alice/work/");
+        assert!(work.is_calendar);
+        assert_eq!(work.display_name.as_deref(), Some("Work & Home"));
+        assert_eq!(work.ctag.as_deref(), Some("\"ctag-1\""));
+        assert_eq!(work.components, vec!["VEVENT", "VTODO"]);
+    }
+
+    #[test]
+    fn test_parse_multiget() {
+        let xml = "<d:multistatus xmlns:d=\"DAV:\" xmlns:c=\"urn:ietf:params:xml:ns:caldav\">\
+            <d:response><d:href>/alice/work/a.ics</d:href><d:propstat><d:prop>\
+            <d:getetag>\"e1\"</d:getetag>\
+            <c:calendar-data>BEGIN:VCALENDAR&#13;\nSUMMARY:a &lt; b&#13;\nEND:VCALENDAR&#13;\n</c:calendar-data>\
+            </d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>\
+            <d:response><d:href>/alice/work/gone.ics</d:href><d:status>HTTP/1.1 404 Not Found</d:status></d:response>\
+            </d:multistatus>";
+        let responses = parse_multistatus(xml).unwrap();
+        assert_eq!(responses[0].etag.as_deref(), Some("\"e1\""));
+        assert_eq!(
+            responses[0].calendar_data.as_deref(),
+            Some("BEGIN:VCALENDAR\r\nSUMMARY:a < b\r\nEND:VCALENDAR")
+        );
+        assert!(!responses[0].missing);
+        assert!(responses[1].missing);
+    }
+
+    #[test]
+    fn test_multiget_escapes_hrefs() {
+        let body = calendar_multiget(&["/a&b.ics".to_string()]);
+        assert!(body.contains("<d:href>/a&amp;b.ics</d:href>"));
+    }
+}
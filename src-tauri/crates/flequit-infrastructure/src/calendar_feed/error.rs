@@ -0,0 +1,9 @@
+//! カレンダーフィード配信のエラー定義
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CalendarFeedError {
+    #[error("Failed to start calendar feed server: {0}")]
+    BindError(#[from] std::io::Error),
+}
@@ -0,0 +1,98 @@
+//! フィード配信用の最小限のHTTP/1.1処理
+//!
+//! GET / HEAD のリクエスト行のみを解釈し、応答後は接続を閉じる。
+
+use std::io;
+use tokio::io::{AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// リクエストヘッダーの最大サイズ
+const MAX_HEADER_BYTES: usize = 8 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: String,
+    /// クエリ文字列を除いたパス
+    pub path: String,
+}
+
+/// ヘッダー終端まで読み込み、リクエスト行を解釈する
+///
+/// 不正なリクエストやヘッダーが大きすぎる場合は `None` を返す。
+pub async fn read_request<S>(stream: &mut S) -> io::Result<Option<HttpRequest>>
+where
+    S: AsyncReadExt + Unpin,
+{
+    let mut buffer = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+    loop {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        if buffer.windows(4).any(|w| w == b"\r\n\r\n") {
+            break;
+        }
+        if buffer.len() > MAX_HEADER_BYTES {
+            return Ok(None);
+        }
+    }
+
+    let head = String::from_utf8_lossy(&buffer);
+    let mut parts = head.lines().next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(target), Some(version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Ok(None);
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Ok(None);
+    }
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    Ok(Some(HttpRequest {
+        method: method.to_string(),
+        path: path.to_string(),
+    }))
+}
+
+/// 応答を書き込む
+pub async fn write_response<S>(
+    stream: &mut S,
+    status: &str,
+    headers: &[(&str, &str)],
+    body: &[u8],
+    include_body: bool,
+) -> io::Result<()>
+where
+    S: AsyncWrite + Unpin,
+{
+    let mut head = format!(
+        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        body.len()
+    );
+    for (name, value) in headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes()).await?;
+    if include_body {
+        stream.write_all(body).await?;
+    }
+    stream.flush().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read_request() {
+        let mut input: &[u8] = b"GET /feeds/abc.ics?x=1 HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        let request = read_request(&mut input).await.unwrap().unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/feeds/abc.ics");
+
+        let mut garbage: &[u8] = b"NOT HTTP\r\n\r\n";
+        assert!(read_request(&mut garbage).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,72 @@
+//! ローカルHTTPサーバーによるカレンダー購読フィード
+//!
+//! `http://127.0.0.1:{port}/feeds/{token}.ics` でプロジェクトまたは絞り込み条件ごとの
+//! iCalendarフィードを配信する。フィードはリクエストのたびに統合リポジトリから生成するため、
+//! カレンダーアプリは購読するだけで最新の状態を取得できる。
+//!
+//! フィードの定義は `Settings::calendar_feed` に保存され、トークンの再発行や削除は即座に反映される。
+
+pub mod error;
+pub mod http;
+pub mod server;
+
+pub use error::CalendarFeedError;
+pub use server::{CalendarFeedHandle, CalendarFeedServer};
+
+use chrono::{Duration, Utc};
+use flequit_core::exporters::ical::CalendarExportFilter;
+use flequit_model::types::id_types::{ProjectId, TagId, TaskListId};
+use flequit_model::types::task_types::TaskStatus;
+use flequit_settings::CalendarFeed;
+
+/// フィードURLのパス接頭辞
+pub const FEED_PATH_PREFIX: &str = "/feeds/";
+
+/// フィードURLの拡張子
+pub const FEED_FILE_EXTENSION: &str = ".ics";
+
+/// フィード用のランダムなトークンを生成（128ビット×2の英数字64文字）
+pub fn generate_feed_token() -> String {
+    format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+/// フィードのURL
+pub fn feed_url(port: u16, token: &str) -> String {
+    format!("http://127.0.0.1:{port}{FEED_PATH_PREFIX}{token}{FEED_FILE_EXTENSION}")
+}
+
+fn non_empty(ids: &[String]) -> Option<&[String]> {
+    (!ids.is_empty()).then_some(ids)
+}
+
+/// フィード設定をエクスポート対象と絞り込み条件に変換
+pub fn export_params(feed: &CalendarFeed) -> (Vec<ProjectId>, CalendarExportFilter) {
+    let project_ids = feed
+        .project_ids
+        .iter()
+        .map(|id| ProjectId::from(id.as_str()))
+        .collect();
+    let statuses: Vec<TaskStatus> = feed
+        .statuses
+        .iter()
+        .filter_map(|s| serde_json::from_value(serde_json::Value::String(s.clone())).ok())
+        .collect();
+
+    let filter = CalendarExportFilter {
+        task_list_ids: non_empty(&feed.task_list_ids)
+            .map(|ids| ids.iter().map(|id| TaskListId::from(id.as_str())).collect()),
+        tag_ids: non_empty(&feed.tag_ids)
+            .map(|ids| ids.iter().map(|id| TagId::from(id.as_str())).collect()),
+        statuses: (!statuses.is_empty()).then_some(statuses),
+        from: feed
+            .days_back
+            .map(|days| Utc::now() - Duration::days(i64::from(days))),
+        include_subtasks: feed.include_subtasks,
+        ..Default::default()
+    };
+    (project_ids, filter)
+}
@@ -0,0 +1,262 @@
+//! フィード配信サーバー
+
+use super::error::CalendarFeedError;
+use super::http::{read_request, write_response};
+use super::{FEED_FILE_EXTENSION, FEED_PATH_PREFIX, export_params};
+use flequit_core::InfrastructureRepositoriesTrait;
+use flequit_core::services::calendar_export_service;
+use flequit_settings::Settings;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+
+/// リクエストの読み込みを待つ最大時間
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 起動中のサーバー
+#[derive(Debug)]
+pub struct CalendarFeedHandle {
+    addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl CalendarFeedHandle {
+    /// 待ち受けアドレス
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// サーバーを停止
+    pub fn shutdown(self) {
+        self.task.abort();
+    }
+}
+
+/// ループバックアドレスでフィードを配信するサーバー
+pub struct CalendarFeedServer;
+
+impl CalendarFeedServer {
+    /// `127.0.0.1:{port}` で待ち受けを開始する（`port` が0の場合は空きポート）
+    ///
+    /// フィードの定義は `settings` をリクエストごとに参照するため、
+    /// トークンの再発行・削除はサーバーを再起動せずに反映される。
+    pub async fn start<R>(
+        repositories: Arc<RwLock<R>>,
+        settings: Arc<RwLock<Settings>>,
+        port: u16,
+    ) -> Result<CalendarFeedHandle, CalendarFeedError>
+    where
+        R: InfrastructureRepositoriesTrait + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port)).await?;
+        let addr = listener.local_addr()?;
+        tracing::info!("Calendar feed server listening on {}", addr);
+
+        let task = tokio::spawn(async move {
+            loop {
+                let (stream, peer) = match listener.accept().await {
+                    Ok(accepted) => accepted,
+                    Err(e) => {
+                        tracing::warn!("Calendar feed accept failed: {}", e);
+                        continue;
+                    }
+                };
+                // ループバック以外からの接続は受け付けない
+                if !peer.ip().is_loopback() {
+                    continue;
+                }
+                let repositories = repositories.clone();
+                let settings = settings.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(stream, repositories, settings).await {
+                        tracing::debug!("Calendar feed connection error: {}", e);
+                    }
+                });
+            }
+        });
+
+        Ok(CalendarFeedHandle { addr, task })
+    }
+}
+
+/// タイミング攻撃を避けるため、長さ以外で早期に抜けない比較を行う
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+async fn handle_connection<R>(
+    mut stream: TcpStream,
+    repositories: Arc<RwLock<R>>,
+    settings: Arc<RwLock<Settings>>,
+) -> std::io::Result<()>
+where
+    R: InfrastructureRepositoriesTrait + Send + Sync + 'static,
+{
+    let request = match tokio::time::timeout(REQUEST_TIMEOUT, read_request(&mut stream)).await {
+        Ok(Ok(Some(request))) => request,
+        Ok(Ok(None)) => {
+            return write_response(&mut stream, "400 Bad Request", &[], b"", true).await;
+        }
+        Ok(Err(e)) => return Err(e),
+        Err(_) => return Ok(()),
+    };
+
+    let include_body = match request.method.as_str() {
+        "GET" => true,
+        "HEAD" => false,
+        _ => {
+            return write_response(
+                &mut stream,
+                "405 Method Not Allowed",
+                &[("Allow", "GET, HEAD")],
+                b"",
+                true,
+            )
+            .await;
+        }
+    };
+
+    let token = request
+        .path
+        .strip_prefix(FEED_PATH_PREFIX)
+        .and_then(|rest| rest.strip_suffix(FEED_FILE_EXTENSION))
+        .unwrap_or_default();
+    let feed = {
+        let settings = settings.read().await;
+        settings
+            .calendar_feed
+            .enabled
+            .then(|| {
+                settings
+                    .calendar_feed
+                    .feeds
+                    .iter()
+                    .find(|feed| constant_time_eq(feed.token.as_bytes(), token.as_bytes()))
+                    .cloned()
+            })
+            .flatten()
+    };
+    // 無効なトークンと削除済みのトークンは区別しない
+    let Some(feed) = feed.filter(|_| !token.is_empty()) else {
+        return write_response(&mut stream, "404 Not Found", &[], b"", include_body).await;
+    };
+
+    let (project_ids, filter) = export_params(&feed);
+    let result = {
+        let repositories = repositories.read().await;
+        calendar_export_service::export_calendar(
+            &*repositories,
+            &project_ids,
+            &filter,
+            Some(feed.name.clone()),
+        )
+        .await
+    };
+
+    match result {
+        Ok(ics) => {
+            write_response(
+                &mut stream,
+                "200 OK",
+                &[
+                    ("Content-Type", "text/calendar; charset=utf-8"),
+                    ("Cache-Control", "no-cache"),
+                ],
+                ics.as_bytes(),
+                include_body,
+            )
+            .await
+        }
+        Err(e) => {
+            tracing::error!(
+                "Calendar feed generation failed for '{}': {:?}",
+                feed.name,
+                e
+            );
+            write_response(
+                &mut stream,
+                "500 Internal Server Error",
+                &[],
+                b"",
+                include_body,
+            )
+            .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar_feed::generate_feed_token;
+    use crate::infrastructure_repositories::mock::MockInfrastructureRepositories;
+    use flequit_settings::CalendarFeed;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    async fn get(addr: SocketAddr, path: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn test_serves_feed_and_revokes_token() {
+        let token = generate_feed_token();
+        let mut settings = Settings::default();
+        settings.calendar_feed.enabled = true;
+        settings.calendar_feed.feeds.push(CalendarFeed {
+            id: "feed-1".to_string(),
+            name: "All tasks".to_string(),
+            token: token.clone(),
+            project_ids: vec![],
+            task_list_ids: vec![],
+            tag_ids: vec![],
+            statuses: vec!["completed".to_string()],
+            include_subtasks: true,
+            days_back: Some(30),
+            created_at: chrono::Utc::now(),
+        });
+        let settings = Arc::new(RwLock::new(settings));
+        let repositories = Arc::new(RwLock::new(MockInfrastructureRepositories::new()));
+
+        let handle = CalendarFeedServer::start(repositories, settings.clone(), 0)
+            .await
+            .unwrap();
+        let addr = handle.local_addr();
+        assert!(addr.ip().is_loopback());
+
+        let response = get(addr, &format!("/feeds/{token}.ics")).await;
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("text/calendar"));
+        assert!(response.contains("BEGIN:VCALENDAR"));
+        assert!(response.contains("X-WR-CALNAME:All tasks"));
+
+        let response = get(addr, "/feeds/unknown.ics").await;
+        assert!(response.starts_with("HTTP/1.1 404"));
+
+        // トークンを削除するとURLは即座に無効になる
+        settings.write().await.calendar_feed.feeds.clear();
+        let response = get(addr, &format!("/feeds/{token}.ics")).await;
+        assert!(response.starts_with("HTTP/1.1 404"));
+
+        handle.shutdown();
+    }
+
+    #[test]
+    fn test_generate_feed_token() {
+        let token = generate_feed_token();
+        assert_eq!(token.len(), 64);
+        assert!(token.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert_ne!(token, generate_feed_token());
+        assert!(constant_time_eq(token.as_bytes(), token.clone().as_bytes()));
+        assert!(!constant_time_eq(token.as_bytes(), b"other"));
+    }
+}
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 /// Infrastructure層の統合設定
 ///
-/// SQLiteとAutomergeの各機能の有効/無効を制御する。
+/// SQLite・Automerge・Webの各機能の有効/無効を制御する。
 /// 外部クレートから設定値をセットして関数の引数として渡される。
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InfrastructureConfig {
@@ -19,6 +19,69 @@ pub struct InfrastructureConfig {
 
     /// Automergeストレージ機能の有効/無効
     pub automerge_storage_enabled: bool,
+
+    /// Webストレージ（セルフホストサーバーへの保存）の有効/無効
+    #[serde(default)]
+    pub web_storage_enabled: bool,
+
+    /// Web検索（セルフホストサーバーからの読み込み）の有効/無効
+    #[serde(default)]
+    pub web_search_enabled: bool,
+
+    /// 接続先のセルフホストサーバー設定
+    #[serde(default)]
+    pub web_server: Option<WebServerConfig>,
+}
+
+/// セルフホストFlequitサーバーへの接続設定
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebServerConfig {
+    /// サーバーのベースURL（例: `https://flequit.example.com`）
+    pub base_url: String,
+
+    /// `Authorization: Bearer` で送信するAPIトークン
+    #[serde(default)]
+    pub api_token: Option<String>,
+
+    /// 1リクエストあたりのタイムアウト（秒）
+    #[serde(default = "default_web_timeout_secs")]
+    pub timeout_secs: u64,
+
+    /// 接続エラー・一時的なエラー時の最大リトライ回数
+    #[serde(default = "default_web_max_retries")]
+    pub max_retries: u32,
+}
+
+fn default_web_timeout_secs() -> u64 {
+    30
+}
+
+fn default_web_max_retries() -> u32 {
+    3
+}
+
+impl WebServerConfig {
+    /// デフォルトのタイムアウト・リトライ回数で設定を作成
+    pub fn new(base_url: impl Into<String>, api_token: Option<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            api_token,
+            timeout_secs: default_web_timeout_secs(),
+            max_retries: default_web_max_retries(),
+        }
+    }
+}
+
+impl std::fmt::Debug for WebServerConfig {
+    // APIトークンをログに出力しない
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebServerConfig")
+            .field("base_url", &self.base_url)
+            .field("api_token", &self.api_token.as_ref().map(|_| "***"))
+            .field("timeout_secs", &self.timeout_secs)
+            .field("max_retries", &self.max_retries)
+            .finish()
+    }
 }
 
 impl InfrastructureConfig {
@@ -37,9 +100,30 @@ impl InfrastructureConfig {
             sqlite_search_enabled,
             sqlite_storage_enabled,
             automerge_storage_enabled,
+            web_storage_enabled: false,
+            web_search_enabled: false,
+            web_server: None,
         }
     }
 
+    /// セルフホストサーバーを保存先・検索先として追加する
+    pub fn with_web_server(
+        mut self,
+        web_server: WebServerConfig,
+        storage_enabled: bool,
+        search_enabled: bool,
+    ) -> Self {
+        self.web_server = Some(web_server);
+        self.web_storage_enabled = storage_enabled;
+        self.web_search_enabled = search_enabled;
+        self
+    }
+
+    /// Webリポジトリを使用するかどうか
+    pub fn web_enabled(&self) -> bool {
+        self.web_storage_enabled || self.web_search_enabled
+    }
+
     /// 設定値の検証
     ///
     /// 最低限1つのストレージ機能が有効になっていることを確認する。
@@ -62,6 +146,21 @@ impl InfrastructureConfig {
             );
         }
 
+        if self.web_enabled() {
+            let Some(web_server) = &self.web_server else {
+                return Err(
+                    "Web機能を使用する場合は、接続先サーバーを設定する必要があります".to_string(),
+                );
+            };
+            let base_url = web_server.base_url.trim();
+            if !(base_url.starts_with("http://") || base_url.starts_with("https://")) {
+                return Err(format!(
+                    "接続先サーバーのURLはhttp://またはhttps://で始まる必要があります: {}",
+                    web_server.base_url
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -75,6 +174,9 @@ impl Default for InfrastructureConfig {
             sqlite_search_enabled: false,
             sqlite_storage_enabled: true,
             automerge_storage_enabled: true,
+            web_storage_enabled: false,
+            web_search_enabled: false,
+            web_server: None,
         }
     }
 }
@@ -119,4 +221,34 @@ mod tests {
         let config = InfrastructureConfig::new(true, false, true);
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_config_validation_web() {
+        let mut config = InfrastructureConfig::new(false, true, true);
+        config.web_storage_enabled = true;
+        assert!(config.validate().is_err());
+
+        let config = InfrastructureConfig::new(false, true, true).with_web_server(
+            WebServerConfig::new("ftp://example.com", None),
+            true,
+            false,
+        );
+        assert!(config.validate().is_err());
+
+        let config = InfrastructureConfig::new(false, true, true).with_web_server(
+            WebServerConfig::new("http://127.0.0.1:8080", Some("token".to_string())),
+            true,
+            true,
+        );
+        assert!(config.validate().is_ok());
+        assert!(!format!("{:?}", config).contains("\"token\""));
+    }
+
+    #[test]
+    fn test_config_deserialize_without_web_fields() {
+        let json = r#"{"sqlite_search_enabled":true,"sqlite_storage_enabled":true,"automerge_storage_enabled":false}"#;
+        let config: InfrastructureConfig = serde_json::from_str(json).unwrap();
+        assert!(!config.web_enabled());
+        assert!(config.web_server.is_none());
+    }
 }
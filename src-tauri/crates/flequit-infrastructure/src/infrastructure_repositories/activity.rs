@@ -0,0 +1,95 @@
+//! InfrastructureRepositories のアクティビティログ操作
+//!
+//! エンティティごとのタイムラインとプロジェクト全体のフィードの取得、
+//! Automergeの変更履歴からの再構築を提供する。
+
+use super::InfrastructureRepositories;
+use crate::activity::entries_from_history;
+use flequit_infrastructure_automerge::infrastructure::document::Document;
+use flequit_infrastructure_sqlite::infrastructure::activity::activity_log::ActivityLogLocalSqliteRepository;
+use flequit_model::models::activity::activity_entry::ActivityEntry;
+use flequit_model::types::id_types::{ProjectId, TaskId};
+use flequit_types::errors::repository_error::RepositoryError;
+
+impl InfrastructureRepositories {
+    /// アクティビティログのリポジトリ（SQLiteが無効な場合は `None`）
+    async fn activity_log(&self) -> Option<ActivityLogLocalSqliteRepository> {
+        let sqlite_repos = self.unified_manager.sqlite_repositories()?;
+        Some(sqlite_repos.read().await.activity_log().clone())
+    }
+
+    /// タスクのタイムラインを古い順に取得（サブタスクの変更も含む）
+    pub async fn task_activity(
+        &self,
+        project_id: &ProjectId,
+        task_id: &TaskId,
+    ) -> Result<Vec<ActivityEntry>, RepositoryError> {
+        match self.activity_log().await {
+            Some(activity_log) => {
+                activity_log
+                    .find_by_entity(project_id, &task_id.to_string())
+                    .await
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// プロジェクト全体のアクティビティを新しい順に取得
+    ///
+    /// `before_seq` を指定した場合は、その連番より前のエントリから取得する。
+    pub async fn project_activity_feed(
+        &self,
+        project_id: &ProjectId,
+        before_seq: Option<i64>,
+        limit: u64,
+    ) -> Result<Vec<ActivityEntry>, RepositoryError> {
+        match self.activity_log().await {
+            Some(activity_log) => {
+                activity_log
+                    .find_by_project(project_id, before_seq, limit)
+                    .await
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// プロジェクトのアクティビティログをAutomergeの変更履歴から作り直す
+    ///
+    /// 既存の記録は置き換えられる。再構築したエントリ数を返す。
+    pub async fn rebuild_project_activity(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<usize, RepositoryError> {
+        let activity_log = self.activity_log().await.ok_or_else(|| {
+            RepositoryError::ConfigurationError(
+                "SQLiteが無効なため、アクティビティログを利用できません".to_string(),
+            )
+        })?;
+        let automerge_repos = self
+            .unified_manager
+            .automerge_repositories()
+            .ok_or_else(|| {
+                RepositoryError::ConfigurationError(
+                    "Automerge repositories not initialized".to_string(),
+                )
+            })?;
+
+        let history = automerge_repos
+            .read()
+            .await
+            .projects()
+            .export_project_history(project_id)
+            .await?;
+        let states = Document::history_states(&history)
+            .map_err(|e| RepositoryError::AutomergeError(e.to_string()))?;
+        let entries = entries_from_history(project_id, &states);
+
+        activity_log.replace_project(project_id, &entries).await?;
+        tracing::info!(
+            "Rebuilt {} activity entries for project {}",
+            entries.len(),
+            project_id
+        );
+        Ok(entries.len())
+    }
+}
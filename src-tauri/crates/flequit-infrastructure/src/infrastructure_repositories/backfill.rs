@@ -0,0 +1,47 @@
+//! InfrastructureRepositories のデータ補完
+//!
+//! SQLiteのマイグレーションで追加したカラムを、Automergeに残っている値から埋め直す。
+//! 補完はマイグレーションが予約したものだけを起動時に1回実行する。
+
+use super::InfrastructureRepositories;
+use flequit_infrastructure_sqlite::migrator::TASK_WORK_DATES_BACKFILL;
+use flequit_types::errors::repository_error::RepositoryError;
+
+impl InfrastructureRepositories {
+    /// 予約されているデータ補完を実行
+    ///
+    /// SQLiteまたはAutomergeが無効な場合は何もしない。
+    pub async fn run_pending_backfills(&self) -> Result<(), RepositoryError> {
+        let (Some(sqlite_repos), Some(automerge_repos)) = (
+            self.unified_manager.sqlite_repositories(),
+            self.unified_manager.automerge_repositories(),
+        ) else {
+            return Ok(());
+        };
+        let sqlite_repos = sqlite_repos.read().await;
+        let automerge_repos = automerge_repos.read().await;
+
+        if sqlite_repos
+            .data_backfills()
+            .is_pending(TASK_WORK_DATES_BACKFILL)
+            .await?
+        {
+            // タスクの実績日時はこれまでAutomergeにのみ保存されていた
+            let mut backfilled = 0;
+            for project_id in sqlite_repos.tasks().find_project_ids().await? {
+                let tasks = automerge_repos.projects().get_tasks(&project_id).await?;
+                backfilled += sqlite_repos
+                    .tasks()
+                    .backfill_work_dates(&project_id, &tasks)
+                    .await?;
+            }
+            sqlite_repos
+                .data_backfills()
+                .complete(TASK_WORK_DATES_BACKFILL)
+                .await?;
+            tracing::info!("Backfilled work dates of {} tasks", backfilled);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+//! InfrastructureRepositories のバックアップ・リストア
+//!
+//! 使用中のDatabaseManagerとAutomergeディレクトリを対象にバックアップを作成し、
+//! リストア時はバックエンドを停止してからファイルを差し替え、再構築して読み込み直す。
+
+use super::InfrastructureRepositories;
+use crate::backup::{BackupError, BackupManager, BackupManifest, BackupPaths};
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+use flequit_settings::paths::SettingsPaths;
+use std::path::{Path, PathBuf};
+
+impl InfrastructureRepositories {
+    /// 現在のプロファイルを対象とするBackupManagerを作成
+    ///
+    /// 対象パスは既定値ではなく、使用中のDatabaseManagerとAutomergeディレクトリから解決する。
+    pub async fn backup_manager(&self) -> Result<BackupManager, BackupError> {
+        let db_manager = match self.unified_manager.sqlite_repositories() {
+            Some(sqlite_repos) => sqlite_repos.read().await.database_manager().clone(),
+            None => DatabaseManager::instance().await?,
+        };
+        let paths = BackupPaths {
+            database_path: Some(PathBuf::from(db_manager.read().await.database_path())),
+            automerge_dir: self.unified_manager.automerge_path().map(Path::to_path_buf),
+            settings_path: SettingsPaths::get_settings_file_path().ok(),
+        };
+
+        let backup_manager = BackupManager::new(paths).with_database_manager(db_manager);
+        Ok(match self.unified_manager.document_manager() {
+            Some(document_manager) => {
+                backup_manager.with_document_manager(document_manager.clone())
+            }
+            None => backup_manager,
+        })
+    }
+
+    /// バックアップアーカイブを作成
+    pub async fn create_backup(&self, dest: &Path) -> Result<BackupManifest, BackupError> {
+        self.backup_manager().await?.create_backup(dest).await
+    }
+
+    /// バックアップアーカイブからリストア
+    ///
+    /// `&mut self` を要求するため、呼び出し側の書き込みロックによって
+    /// リストア中の他の読み書きは発生しない。
+    /// ファイルを差し替える前に現在のバックエンドを停止し、差し替え後に再構築する。
+    /// 再読み込みに失敗した場合は元のデータに戻す。
+    pub async fn restore_backup(&mut self, archive: &Path) -> Result<BackupManifest, BackupError> {
+        let backup_manager = self.backup_manager().await?;
+        let document_cipher = self.unified_manager.document_cipher();
+        // 停止前のRepoや接続プールが差し替えたファイルへ書き込まないようにする
+        self.shutdown().await.map_err(BackupError::RestoreFailed)?;
+
+        let pending = match backup_manager.restore_backup(archive).await {
+            Ok(pending) => pending,
+            Err(e) => {
+                self.reload(document_cipher.clone())
+                    .await
+                    .map_err(BackupError::RestoreFailed)?;
+                return Err(e);
+            }
+        };
+
+        match self.reload(document_cipher.clone()).await {
+            Ok(()) => pending.commit(),
+            Err(e) => {
+                tracing::error!("Failed to load restored data, rolling back: {}", e);
+                self.shutdown().await.map_err(BackupError::RestoreFailed)?;
+                pending.rollback()?;
+                self.reload(document_cipher)
+                    .await
+                    .map_err(BackupError::RestoreFailed)?;
+                Err(BackupError::RestoreFailed(e))
+            }
+        }
+    }
+}
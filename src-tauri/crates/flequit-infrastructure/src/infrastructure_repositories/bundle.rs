@@ -0,0 +1,1001 @@
+//! InfrastructureRepositories のプロジェクトバンドル入出力
+//!
+//! Automergeのプロジェクトドキュメントを正としてバンドルを読み書きし、
+//! インポート後はSQLite側にも取り込んだプロジェクトを反映して検索可能にする。
+
+use super::InfrastructureRepositories;
+use crate::bundle::{
+    BUNDLE_FORMAT_VERSION, BundleEntityCounts, BundleError, BundleImportMode, BundleImportResult,
+    BundleManifest, IdRemapper, merged_entity_events, read_bundle, write_bundle,
+};
+use chrono::Utc;
+use flequit_core::events::{self, DomainEvent, EntityKind, EventOrigin};
+use flequit_infrastructure_automerge::infrastructure::document::Document;
+use flequit_infrastructure_automerge::infrastructure::local_automerge_repositories::LocalAutomergeRepositories;
+use flequit_infrastructure_automerge::infrastructure::task_projects::comment::COMMENT_KEY_PREFIX;
+use flequit_infrastructure_sqlite::infrastructure::local_sqlite_repositories::LocalSqliteRepositories;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::member::MemberLocalSqliteRepository;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::recurrence_rule::RecurrenceRuleLocalSqliteRepository;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::subtask_recurrence::SubtaskRecurrenceLocalSqliteRepository;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::task_recurrence::TaskRecurrenceLocalSqliteRepository;
+use flequit_model::models::task_projects::{
+    comment::Comment, member::Member, project::Project, recurrence_rule::RecurrenceRule,
+    reminder::Reminder, snooze::Snooze, status_transition_rule::StatusTransitionRule,
+    subtask::SubTask, subtask_recurrence::SubTaskRecurrence, subtask_tag::SubTaskTag, tag::Tag,
+    task::Task, task_dependency::TaskDependency, task_list::TaskList,
+    task_recurrence::TaskRecurrence, task_tag::TaskTag, time_entry::TimeEntry,
+    workflow_status::WorkflowStatus,
+};
+use flequit_model::types::id_types::ProjectId;
+use flequit_repository::base_repository_trait::Repository;
+use flequit_repository::repositories::project_relation_repository_trait::ProjectRelationRepository;
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::repository_error::RepositoryError;
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// SQLiteへ反映するプロジェクト内エンティティ
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ProjectIndexData {
+    task_lists: Vec<TaskList>,
+    tasks: Vec<Task>,
+    subtasks: Vec<SubTask>,
+    tags: Vec<Tag>,
+    members: Vec<Member>,
+    task_tags: Vec<TaskTag>,
+    subtask_tags: Vec<SubTaskTag>,
+    recurrence_rules: Vec<RecurrenceRule>,
+    task_recurrences: Vec<TaskRecurrence>,
+    subtask_recurrences: Vec<SubTaskRecurrence>,
+    time_entries: Vec<TimeEntry>,
+    status_transition_rules: Vec<StatusTransitionRule>,
+    workflow_statuses: Vec<WorkflowStatus>,
+    task_dependencies: Vec<TaskDependency>,
+    reminders: Vec<Reminder>,
+    snoozes: Vec<Snooze>,
+    /// ルート直下の `comment:{id}` キーから集める
+    #[serde(skip)]
+    comments: Vec<Comment>,
+}
+
+impl ProjectIndexData {
+    fn from_project_json(project_json: serde_json::Value) -> Result<Self, BundleError> {
+        let comments = project_json
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(key, _)| key.starts_with(COMMENT_KEY_PREFIX))
+            .map(|(_, value)| serde_json::from_value(value.clone()))
+            .collect::<Result<Vec<Comment>, _>>()?;
+        let mut data: Self = serde_json::from_value(project_json)?;
+        data.comments = comments;
+        Ok(data)
+    }
+}
+
+impl InfrastructureRepositories {
+    fn automerge_repositories_for_bundle(
+        &self,
+    ) -> Result<&Arc<RwLock<LocalAutomergeRepositories>>, BundleError> {
+        self.unified_manager
+            .automerge_repositories()
+            .ok_or_else(|| {
+                BundleError::RepositoryError("Automerge repositories not initialized".to_string())
+            })
+    }
+
+    /// プロジェクトを変更履歴ごとバンドルファイルに書き出す
+    ///
+    /// 保存時暗号化が有効でも、バンドル内のドキュメントは受け渡しのため平文で書き出される。
+    pub async fn export_project_bundle(
+        &self,
+        project_id: &ProjectId,
+        dest: &Path,
+    ) -> Result<BundleManifest, BundleError> {
+        let document = {
+            let automerge_repos = self.automerge_repositories_for_bundle()?.read().await;
+            automerge_repos
+                .projects()
+                .export_project_history(project_id)
+                .await
+                .map_err(|e| match e {
+                    RepositoryError::NotFound(_) => {
+                        BundleError::ProjectNotFound(project_id.to_string())
+                    }
+                    other => other.into(),
+                })?
+        };
+
+        let project_json = history_to_json(&document)?;
+        let manifest = BundleManifest {
+            format_version: BUNDLE_FORMAT_VERSION,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            exported_at: Utc::now(),
+            project_id: project_id.to_string(),
+            project_name: project_json
+                .get("name")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            counts: BundleEntityCounts::from_project_json(&project_json),
+            document_sha256: crate::bundle::checksum(&document),
+        };
+
+        write_bundle(dest, &manifest, &document)?;
+        tracing::info!("Exported project {} to bundle {:?}", project_id, dest);
+        Ok(manifest)
+    }
+
+    /// バンドルファイルからプロジェクトを取り込む
+    ///
+    /// - `KeepIds`: 同じIDのプロジェクトへ変更履歴をマージする。既存であれば双方の変更が統合される。
+    /// - `RemapIds`: 全エンティティのIDを振り直し、新しいプロジェクトとして作成する。
+    pub async fn import_project_bundle(
+        &self,
+        src: &Path,
+        mode: BundleImportMode,
+    ) -> Result<BundleImportResult, BundleError> {
+        let (manifest, document) = read_bundle(src)?;
+        let project_json = history_to_json(&document)?;
+        if project_json.get("id").and_then(|v| v.as_str()) != Some(manifest.project_id.as_str()) {
+            return Err(BundleError::InvalidBundle(
+                "Project ID in manifest does not match the document".to_string(),
+            ));
+        }
+
+        let (project_id, merged, remapped_ids, merged_events) = {
+            let automerge_repos = self.automerge_repositories_for_bundle()?.read().await;
+            let projects = automerge_repos.projects();
+
+            match mode {
+                BundleImportMode::KeepIds => {
+                    let project_id = ProjectId::from(manifest.project_id.as_str());
+                    let merged = projects.get_project_document(&project_id).await?.is_some();
+                    let before = if merged {
+                        history_to_json(&projects.export_project_history(&project_id).await?)?
+                    } else {
+                        serde_json::Value::Null
+                    };
+                    projects
+                        .merge_project_history(&project_id, &document)
+                        .await?;
+                    let after =
+                        history_to_json(&projects.export_project_history(&project_id).await?)?;
+                    let merged_events = merged_entity_events(&project_id, &before, &after);
+                    (project_id, merged, 0, merged_events)
+                }
+                BundleImportMode::RemapIds => {
+                    let remapper = IdRemapper::for_project(&project_json);
+                    let new_id = remapper.get(&manifest.project_id).ok_or_else(|| {
+                        BundleError::InvalidBundle("Project ID could not be remapped".to_string())
+                    })?;
+                    let project_id = ProjectId::from(new_id);
+                    projects
+                        .import_project_json(&project_id, &remapper.apply(&project_json))
+                        .await?;
+                    (project_id, false, remapper.len(), Vec::new())
+                }
+            }
+        };
+
+        self.index_project_in_sqlite(&project_id).await?;
+        // 取り込んだ変更はローカルに記録がないため、変更履歴からアクティビティログを作り直す
+        if self.unified_manager.sqlite_repositories().is_some()
+            && let Err(e) = self.rebuild_project_activity(&project_id).await
+        {
+            tracing::warn!(
+                "Failed to rebuild activity log for imported project {}: {}",
+                project_id,
+                e
+            );
+        }
+
+        // 履歴のマージは他の端末の変更の取り込み、IDの振り直しは新しいプロジェクトの作成として通知する
+        let (event, origin) = match mode {
+            BundleImportMode::KeepIds if merged => (
+                DomainEvent::updated(EntityKind::Project, project_id),
+                EventOrigin::Sync,
+            ),
+            BundleImportMode::KeepIds => (
+                DomainEvent::created(EntityKind::Project, project_id),
+                EventOrigin::Sync,
+            ),
+            BundleImportMode::RemapIds => (
+                DomainEvent::created(EntityKind::Project, project_id),
+                EventOrigin::Local,
+            ),
+        };
+        events::publish(event.in_project(&project_id).from_origin(origin));
+        // マージで取り込んだ変更はエンティティごとにも通知する
+        for event in merged_events {
+            events::publish(event);
+        }
+
+        tracing::info!(
+            "Imported bundle {:?} as project {} ({:?}, merged: {})",
+            src,
+            project_id,
+            mode,
+            merged
+        );
+        Ok(BundleImportResult {
+            project_id: project_id.to_string(),
+            mode,
+            merged,
+            remapped_ids,
+            manifest,
+        })
+    }
+
+    /// Automerge上のプロジェクトの内容をSQLiteへ反映する
+    ///
+    /// SQLiteが無効な場合は何もしない。
+    async fn index_project_in_sqlite(&self, project_id: &ProjectId) -> Result<(), BundleError> {
+        let Some(sqlite_repos) = self.unified_manager.sqlite_repositories() else {
+            return Ok(());
+        };
+
+        let (project, data) = {
+            let automerge_repos = self.automerge_repositories_for_bundle()?.read().await;
+            let projects = automerge_repos.projects();
+            let project = projects
+                .get_project(&project_id.to_string())
+                .await?
+                .ok_or_else(|| BundleError::ProjectNotFound(project_id.to_string()))?;
+            let project_json =
+                history_to_json(&projects.export_project_history(project_id).await?)?;
+            (project, ProjectIndexData::from_project_json(project_json)?)
+        };
+
+        index_project_data(&*sqlite_repos.read().await, &project, &data).await
+    }
+}
+
+/// プロジェクトとプロジェクト内エンティティをSQLiteへ保存する
+///
+/// 既に同じIDのエンティティがある場合は上書きされるため、同じバンドルを繰り返し取り込める。
+async fn index_project_data(
+    sqlite_repos: &LocalSqliteRepositories,
+    project: &Project,
+    data: &ProjectIndexData,
+) -> Result<(), BundleError> {
+    let project_id = &project.id;
+    let db_manager = sqlite_repos.database_manager().clone();
+
+    sqlite_repos
+        .projects()
+        .save(project, &project.updated_by, &project.updated_at)
+        .await?;
+    let members = MemberLocalSqliteRepository::new(db_manager.clone());
+    for member in &data.members {
+        // ユーザーはバンドルに含まれないため、ローカルに存在するユーザーのメンバーのみ反映する
+        if sqlite_repos
+            .users()
+            .find_by_id(&member.user_id)
+            .await?
+            .is_none()
+        {
+            tracing::debug!(
+                "Skipped indexing member {} of project {}: user {} is not known locally",
+                member.id,
+                project_id,
+                member.user_id
+            );
+            continue;
+        }
+        members
+            .save(project_id, member, &member.updated_by, &member.updated_at)
+            .await?;
+    }
+    for tag in &data.tags {
+        sqlite_repos
+            .tags()
+            .save(project_id, tag, &tag.updated_by, &tag.updated_at)
+            .await?;
+    }
+    for task_list in &data.task_lists {
+        sqlite_repos
+            .task_lists()
+            .save(
+                project_id,
+                task_list,
+                &task_list.updated_by,
+                &task_list.updated_at,
+            )
+            .await?;
+    }
+    for task in &data.tasks {
+        sqlite_repos
+            .tasks()
+            .save(project_id, task, &task.updated_by, &task.updated_at)
+            .await?;
+        for user_id in &task.assigned_user_ids {
+            sqlite_repos
+                .task_assignments()
+                .add_assignment(project_id, &task.id, user_id)
+                .await?;
+        }
+    }
+    for subtask in &data.subtasks {
+        sqlite_repos
+            .sub_tasks()
+            .save(
+                project_id,
+                subtask,
+                &subtask.updated_by,
+                &subtask.updated_at,
+            )
+            .await?;
+        for user_id in &subtask.assigned_user_ids {
+            sqlite_repos
+                .subtask_assignments()
+                .add_assignment(project_id, &subtask.id, user_id)
+                .await?;
+        }
+    }
+    for relation in data.task_tags.iter().filter(|r| !r.deleted) {
+        sqlite_repos
+            .task_tags
+            .add_relation(project_id, &relation.task_id, &relation.tag_id)
+            .await?;
+    }
+    for relation in data.subtask_tags.iter().filter(|r| !r.deleted) {
+        sqlite_repos
+            .subtask_tags
+            .add_relation(project_id, &relation.subtask_id, &relation.tag_id)
+            .await?;
+    }
+
+    let recurrence_rules = RecurrenceRuleLocalSqliteRepository::new(db_manager.clone());
+    for rule in &data.recurrence_rules {
+        recurrence_rules
+            .save(project_id, rule, &rule.updated_by, &rule.updated_at)
+            .await?;
+    }
+    let task_recurrences = TaskRecurrenceLocalSqliteRepository::new(db_manager.clone());
+    for relation in &data.task_recurrences {
+        task_recurrences
+            .add(
+                project_id,
+                &relation.task_id,
+                &relation.recurrence_rule_id,
+                &relation.updated_by,
+                &relation.updated_at,
+            )
+            .await?;
+    }
+    let subtask_recurrences = SubtaskRecurrenceLocalSqliteRepository::new(db_manager);
+    for relation in &data.subtask_recurrences {
+        subtask_recurrences
+            .add(
+                project_id,
+                &relation.subtask_id,
+                &relation.recurrence_rule_id,
+                &relation.updated_by,
+                &relation.updated_at,
+            )
+            .await?;
+    }
+
+    for entry in &data.time_entries {
+        sqlite_repos
+            .time_entries()
+            .save(project_id, entry, &entry.updated_by, &entry.updated_at)
+            .await?;
+    }
+
+    for rule in &data.status_transition_rules {
+        sqlite_repos
+            .status_transition_rules()
+            .save(project_id, rule, &rule.updated_by, &rule.updated_at)
+            .await?;
+    }
+
+    for status in &data.workflow_statuses {
+        sqlite_repos
+            .workflow_statuses()
+            .save(project_id, status, &status.updated_by, &status.updated_at)
+            .await?;
+    }
+
+    for dependency in data.task_dependencies.iter().filter(|d| !d.deleted) {
+        sqlite_repos
+            .task_dependencies()
+            .add(
+                project_id,
+                &dependency.task_id,
+                &dependency.depends_on_task_id,
+                &dependency.updated_by,
+                &dependency.updated_at,
+            )
+            .await?;
+    }
+
+    for reminder in &data.reminders {
+        sqlite_repos
+            .reminders()
+            .save(
+                project_id,
+                reminder,
+                &reminder.updated_by,
+                &reminder.updated_at,
+            )
+            .await?;
+    }
+
+    for snooze in &data.snoozes {
+        sqlite_repos
+            .snoozes()
+            .save(project_id, snooze, &snooze.updated_by, &snooze.updated_at)
+            .await?;
+    }
+
+    for comment in &data.comments {
+        sqlite_repos
+            .comments()
+            .save(
+                project_id,
+                comment,
+                &comment.updated_by,
+                &comment.updated_at,
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+fn history_to_json(document: &[u8]) -> Result<serde_json::Value, BundleError> {
+    Document::history_to_json(document).map_err(|e| BundleError::InvalidBundle(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+    use flequit_core::services::schedule_service;
+    use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+    use flequit_model::models::users::user::User;
+    use flequit_model::types::id_types::{
+        CommentId, MemberId, ReminderId, SnoozeId, StatusTransitionRuleId, SubTaskId, TagId,
+        TaskId, TaskListId, TimeEntryId, UserId, WorkflowStatusId,
+    };
+    use flequit_model::types::project_types::MemberRole;
+    use flequit_model::types::task_types::{
+        ReminderAnchor, StatusTransitionRequirement, TaskStatus,
+    };
+    use flequit_testing::TestPathGenerator;
+
+    async fn create_sqlite_repositories(test_name: &str) -> LocalSqliteRepositories {
+        let dir = TestPathGenerator::generate_test_dir(file!(), test_name);
+        let db_path = dir.join("bundle_index.sqlite");
+        let db_manager = Arc::new(RwLock::new(DatabaseManager::new_for_test(
+            db_path.to_string_lossy().to_string(),
+        )));
+        db_manager.read().await.get_connection().await.unwrap();
+        LocalSqliteRepositories::with_database_manager(db_manager)
+    }
+
+    struct Fixture {
+        project: Project,
+        data: ProjectIndexData,
+    }
+
+    fn fixture(now: DateTime<Utc>) -> Fixture {
+        let user_id = UserId::new();
+        let project = Project {
+            id: ProjectId::new(),
+            name: "Imported".to_string(),
+            description: None,
+            color: None,
+            order_index: 0,
+            is_archived: false,
+            status: None,
+            owner_id: Some(user_id),
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let task_list = TaskList {
+            id: TaskListId::new(),
+            project_id: project.id,
+            name: "List".to_string(),
+            description: None,
+            color: None,
+            order_index: 0,
+            is_archived: false,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let tag = Tag {
+            id: TagId::new(),
+            name: "tag".to_string(),
+            color: None,
+            order_index: None,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let task = Task {
+            id: TaskId::new(),
+            project_id: project.id,
+            list_id: task_list.id,
+            title: "Task".to_string(),
+            description: None,
+            status: TaskStatus::NotStarted,
+            workflow_status_id: None,
+            priority: 0,
+            plan_start_date: None,
+            plan_end_date: None,
+            do_start_date: None,
+            do_end_date: None,
+            is_range_date: None,
+            recurrence_rule: None,
+            order_index: 0,
+            is_archived: false,
+            assigned_user_ids: vec![],
+            tag_ids: vec![tag.id],
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let subtask = SubTask {
+            id: SubTaskId::new(),
+            task_id: task.id,
+            title: "Subtask".to_string(),
+            description: None,
+            status: TaskStatus::NotStarted,
+            priority: None,
+            plan_start_date: None,
+            plan_end_date: None,
+            do_start_date: None,
+            do_end_date: None,
+            is_range_date: None,
+            recurrence_rule: None,
+            assigned_user_ids: vec![],
+            tag_ids: vec![tag.id],
+            order_index: 0,
+            completed: false,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let member = Member {
+            id: MemberId::new(),
+            user_id,
+            role: MemberRole::Owner,
+            joined_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let time_entry = TimeEntry {
+            id: TimeEntryId::new(),
+            project_id: project.id,
+            task_id: task.id,
+            subtask_id: None,
+            user_id,
+            started_at: now,
+            ended_at: Some(now + chrono::Duration::minutes(30)),
+            note: Some("imported".to_string()),
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let status_transition_rule = StatusTransitionRule {
+            id: StatusTransitionRuleId::new(),
+            project_id: project.id,
+            from_status: TaskStatus::NotStarted,
+            to_status: TaskStatus::Completed,
+            requirement: StatusTransitionRequirement::ReasonRequired,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let workflow_status = WorkflowStatus {
+            id: WorkflowStatusId::new(),
+            project_id: project.id,
+            name: "Review".to_string(),
+            color: Some("#ff9900".to_string()),
+            order_index: 1,
+            category: TaskStatus::InProgress,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let blocking_task = Task {
+            id: TaskId::new(),
+            title: "Blocking task".to_string(),
+            tag_ids: vec![],
+            ..task.clone()
+        };
+        let task_dependency = TaskDependency {
+            task_id: task.id,
+            depends_on_task_id: blocking_task.id,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let reminder = Reminder {
+            id: ReminderId::new(),
+            project_id: project.id,
+            task_id: task.id,
+            subtask_id: None,
+            anchor: ReminderAnchor::Absolute,
+            remind_at: Some(now + chrono::Duration::hours(1)),
+            offset_minutes: 0,
+            repeat_interval_minutes: Some(10),
+            repeat_count: Some(2),
+            last_fired_at: None,
+            snoozed_until: None,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let snooze = Snooze {
+            id: SnoozeId::new(),
+            project_id: project.id,
+            task_id: task.id,
+            subtask_id: None,
+            reminder_id: Some(reminder.id),
+            original_plan_start_date: None,
+            original_plan_end_date: None,
+            original_remind_at: reminder.remind_at,
+            snoozed_until: now + chrono::Duration::hours(2),
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let comment = Comment {
+            id: CommentId::new(),
+            project_id: project.id,
+            task_id: task.id,
+            subtask_id: None,
+            author_id: user_id,
+            body: "Imported comment".to_string(),
+            mentioned_user_ids: vec![user_id],
+            created_at: now,
+            edited_at: None,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let data = ProjectIndexData {
+            task_tags: vec![TaskTag {
+                task_id: task.id,
+                tag_id: tag.id,
+                created_at: now,
+                updated_at: now,
+                deleted: false,
+                updated_by: user_id,
+            }],
+            subtask_tags: vec![SubTaskTag {
+                subtask_id: subtask.id,
+                tag_id: tag.id,
+                created_at: now,
+                updated_at: now,
+                deleted: false,
+                updated_by: user_id,
+            }],
+            task_lists: vec![task_list],
+            tasks: vec![task, blocking_task],
+            subtasks: vec![subtask],
+            tags: vec![tag],
+            members: vec![member],
+            comments: vec![comment],
+            snoozes: vec![snooze],
+            reminders: vec![reminder],
+            task_dependencies: vec![task_dependency],
+            workflow_statuses: vec![workflow_status],
+            status_transition_rules: vec![status_transition_rule],
+            time_entries: vec![time_entry],
+            ..Default::default()
+        };
+        Fixture { project, data }
+    }
+
+    #[tokio::test]
+    async fn test_index_project_data_indexes_tag_links_and_members() {
+        let sqlite_repos =
+            create_sqlite_repositories("test_index_project_data_indexes_tag_links_and_members")
+                .await;
+        let now = Utc::now();
+        let Fixture { project, data } = fixture(now);
+        let user_id = data.members[0].user_id;
+        sqlite_repos
+            .users()
+            .save(
+                &User {
+                    id: user_id,
+                    handle_id: "member".to_string(),
+                    display_name: "Member".to_string(),
+                    email: None,
+                    avatar_url: None,
+                    bio: None,
+                    timezone: None,
+                    is_active: true,
+                    created_at: now,
+                    updated_at: now,
+                    deleted: false,
+                    updated_by: user_id,
+                },
+                &user_id,
+                &now,
+            )
+            .await
+            .unwrap();
+
+        // 同じバンドルを再度取り込んでも失敗しないこと
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+
+        let task = &data.tasks[0];
+        let subtask = &data.subtasks[0];
+        let tag_id = data.tags[0].id;
+        assert_eq!(
+            sqlite_repos
+                .task_tags
+                .find_tag_ids_by_task_id(&project.id, &task.id)
+                .await
+                .unwrap(),
+            vec![tag_id]
+        );
+        assert_eq!(
+            sqlite_repos
+                .subtask_tags
+                .find_tag_ids_by_subtask_id(&subtask.id)
+                .await
+                .unwrap(),
+            vec![tag_id]
+        );
+
+        let members = MemberLocalSqliteRepository::new(sqlite_repos.database_manager().clone())
+            .find_all(&project.id)
+            .await
+            .unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].user_id, data.members[0].user_id);
+        assert!(matches!(members[0].role, MemberRole::Owner));
+    }
+
+    #[tokio::test]
+    async fn test_index_project_data_indexes_time_entries() {
+        let sqlite_repos =
+            create_sqlite_repositories("test_index_project_data_indexes_time_entries").await;
+        let Fixture { project, data } = fixture(Utc::now());
+
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+
+        let entries = sqlite_repos
+            .time_entries()
+            .find_all(&project.id)
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, data.time_entries[0].id);
+        assert_eq!(entries[0].task_id, data.tasks[0].id);
+        assert_eq!(entries[0].ended_at, data.time_entries[0].ended_at);
+        assert_eq!(entries[0].note.as_deref(), Some("imported"));
+    }
+
+    #[tokio::test]
+    async fn test_index_project_data_indexes_status_transition_rules() {
+        let sqlite_repos =
+            create_sqlite_repositories("test_index_project_data_indexes_status_transition_rules")
+                .await;
+        let Fixture { project, data } = fixture(Utc::now());
+
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+
+        let rules = sqlite_repos
+            .status_transition_rules()
+            .find_all(&project.id)
+            .await
+            .unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].id, data.status_transition_rules[0].id);
+        assert!(rules[0].applies_to(&TaskStatus::NotStarted, &TaskStatus::Completed));
+        assert_eq!(
+            rules[0].requirement,
+            StatusTransitionRequirement::ReasonRequired
+        );
+    }
+
+    #[tokio::test]
+    async fn test_index_project_data_indexes_workflow_statuses() {
+        let sqlite_repos =
+            create_sqlite_repositories("test_index_project_data_indexes_workflow_statuses").await;
+        let Fixture { project, data } = fixture(Utc::now());
+
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+
+        let statuses = sqlite_repos
+            .workflow_statuses()
+            .find_all(&project.id)
+            .await
+            .unwrap();
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].id, data.workflow_statuses[0].id);
+        assert_eq!(statuses[0].name, "Review");
+        assert_eq!(statuses[0].category, TaskStatus::InProgress);
+    }
+
+    #[tokio::test]
+    async fn test_index_project_data_indexes_task_dependencies() {
+        let sqlite_repos =
+            create_sqlite_repositories("test_index_project_data_indexes_task_dependencies").await;
+        let Fixture { project, data } = fixture(Utc::now());
+
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+
+        let dependencies = sqlite_repos
+            .task_dependencies()
+            .find_relations(&project.id, &data.tasks[0].id)
+            .await
+            .unwrap();
+        assert_eq!(dependencies.len(), 1);
+        assert_eq!(dependencies[0].depends_on_task_id, data.tasks[1].id);
+    }
+
+    #[tokio::test]
+    async fn test_imported_tasks_and_dependencies_are_schedulable() {
+        let sqlite_repos =
+            create_sqlite_repositories("test_imported_tasks_and_dependencies_are_schedulable")
+                .await;
+        let now = Utc::now();
+        let Fixture { project, mut data } = fixture(now);
+        // 依存先タスク（1時間）の後に依存元タスク（2時間）が続く予定にする
+        data.tasks[1].plan_start_date = Some(now);
+        data.tasks[1].plan_end_date = Some(now + chrono::Duration::hours(1));
+        data.tasks[0].plan_start_date = Some(now + chrono::Duration::hours(1));
+        data.tasks[0].plan_end_date = Some(now + chrono::Duration::hours(3));
+
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+
+        // スケジュール計算はSQLiteのタスクと依存関係を読むため、取り込んだ内容だけで計算できること
+        let tasks = sqlite_repos.tasks().find_all(&project.id).await.unwrap();
+        let dependencies = sqlite_repos
+            .task_dependencies()
+            .find_all(&project.id)
+            .await
+            .unwrap();
+        let schedule = schedule_service::compute(&tasks, &dependencies, now).unwrap();
+
+        assert_eq!(
+            schedule.critical_path,
+            vec![data.tasks[1].id, data.tasks[0].id]
+        );
+        assert!(schedule.conflicts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_index_project_data_indexes_reminders() {
+        let sqlite_repos =
+            create_sqlite_repositories("test_index_project_data_indexes_reminders").await;
+        let Fixture { project, data } = fixture(Utc::now());
+
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+
+        let reminders = sqlite_repos
+            .reminders()
+            .find_by_task(&project.id, &data.tasks[0].id)
+            .await
+            .unwrap();
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(reminders[0].id, data.reminders[0].id);
+        assert_eq!(reminders[0].remind_at, data.reminders[0].remind_at);
+        assert_eq!(reminders[0].repeat_count, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_index_project_data_indexes_snoozes() {
+        let sqlite_repos =
+            create_sqlite_repositories("test_index_project_data_indexes_snoozes").await;
+        let Fixture { project, data } = fixture(Utc::now());
+
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+
+        let snoozes = sqlite_repos
+            .snoozes()
+            .find_by_task(&project.id, &data.tasks[0].id)
+            .await
+            .unwrap();
+        assert_eq!(snoozes.len(), 1);
+        assert_eq!(snoozes[0].id, data.snoozes[0].id);
+        assert_eq!(snoozes[0].reminder_id, Some(data.reminders[0].id));
+        assert_eq!(snoozes[0].snoozed_until, data.snoozes[0].snoozed_until);
+    }
+
+    #[test]
+    fn test_project_index_data_collects_comment_entries() {
+        let Fixture { data, .. } = fixture(Utc::now());
+        let comment = &data.comments[0];
+        let project_json = serde_json::json!({
+            "tasks": [],
+            format!("comment:{}", comment.id): serde_json::to_value(comment).unwrap(),
+        });
+
+        let parsed = ProjectIndexData::from_project_json(project_json).unwrap();
+
+        assert_eq!(parsed.comments.len(), 1);
+        assert_eq!(parsed.comments[0].id, comment.id);
+    }
+
+    #[tokio::test]
+    async fn test_index_project_data_indexes_comments() {
+        let sqlite_repos =
+            create_sqlite_repositories("test_index_project_data_indexes_comments").await;
+        let Fixture { project, data } = fixture(Utc::now());
+
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+        index_project_data(&sqlite_repos, &project, &data)
+            .await
+            .unwrap();
+
+        let comments = sqlite_repos
+            .comments()
+            .find_by_task(&project.id, &data.tasks[0].id)
+            .await
+            .unwrap();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].id, data.comments[0].id);
+        assert_eq!(comments[0].body, "Imported comment");
+        assert_eq!(
+            comments[0].mentioned_user_ids,
+            data.comments[0].mentioned_user_ids
+        );
+    }
+}
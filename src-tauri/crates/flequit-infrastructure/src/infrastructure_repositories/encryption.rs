@@ -0,0 +1,172 @@
+//! InfrastructureRepositories のAutomergeドキュメント暗号化
+//!
+//! Automergeデータディレクトリのプロファイル鍵を作成・アンロック・削除し、
+//! 新しい暗号でバックエンドを再構築する。
+//! 鍵ファイルが存在するのにアンロックされていない間は、暗号化済みドキュメントの
+//! 読み書きはFileStorage層で拒否される（ロック状態）。
+
+use super::InfrastructureRepositories;
+use flequit_infrastructure_automerge::errors::automerge_error::AutomergeError;
+use flequit_infrastructure_automerge::infrastructure::encryption::{
+    DocumentCipher, ProfileKeyStore,
+};
+use flequit_infrastructure_automerge::infrastructure::file_storage::FileStorage;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Automergeドキュメント暗号化のエラー
+#[derive(Debug, Error)]
+pub enum DocumentEncryptionError {
+    #[error("Automerge storage is not enabled")]
+    StorageDisabled,
+
+    #[error("Document encryption is already enabled")]
+    AlreadyEnabled,
+
+    #[error("Document encryption is not enabled")]
+    NotEnabled,
+
+    #[error("Key error: {0}")]
+    Key(#[from] AutomergeError),
+
+    #[error("Failed to rebuild backends: {0}")]
+    Backend(String),
+}
+
+/// Automergeドキュメント暗号化の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DocumentEncryptionStatus {
+    /// プロファイル鍵が存在するか（＝ドキュメントが暗号化されているか）
+    pub enabled: bool,
+    /// 鍵がアンロックされ、ドキュメントを読み書きできるか
+    pub unlocked: bool,
+}
+
+impl InfrastructureRepositories {
+    /// 現在のAutomergeデータディレクトリのプロファイル鍵
+    fn profile_key_store(&self) -> Result<ProfileKeyStore, DocumentEncryptionError> {
+        self.unified_manager
+            .automerge_path()
+            .map(ProfileKeyStore::new)
+            .ok_or(DocumentEncryptionError::StorageDisabled)
+    }
+
+    /// Automergeドキュメント暗号化の状態を取得
+    pub fn document_encryption_status(
+        &self,
+    ) -> Result<DocumentEncryptionStatus, DocumentEncryptionError> {
+        Ok(DocumentEncryptionStatus {
+            enabled: self.profile_key_store()?.exists(),
+            unlocked: self.unified_manager.is_document_encryption_enabled(),
+        })
+    }
+
+    /// Automergeドキュメント暗号化を有効化
+    ///
+    /// 新しいプロファイル鍵を作成してバックエンドを再構築する。
+    /// 既存の平文ドキュメントは再構築時に暗号化形式へ移行される。
+    pub async fn enable_document_encryption(
+        &mut self,
+        passphrase: &str,
+    ) -> Result<(), DocumentEncryptionError> {
+        let key_store = self.profile_key_store()?;
+        if key_store.exists() {
+            return Err(DocumentEncryptionError::AlreadyEnabled);
+        }
+
+        self.shutdown()
+            .await
+            .map_err(DocumentEncryptionError::Backend)?;
+        let cipher: Arc<dyn DocumentCipher> = match key_store.create(passphrase) {
+            Ok(cipher) => Arc::new(cipher),
+            Err(e) => {
+                self.reload(None)
+                    .await
+                    .map_err(DocumentEncryptionError::Backend)?;
+                return Err(e.into());
+            }
+        };
+        self.reload(Some(cipher))
+            .await
+            .map_err(DocumentEncryptionError::Backend)
+    }
+
+    /// パスフレーズでプロファイル鍵をアンロックし、暗号化済みドキュメントを読み書き可能にする
+    ///
+    /// 既にアンロック済みの場合は何もしない。
+    pub async fn unlock_document_encryption(
+        &mut self,
+        passphrase: &str,
+    ) -> Result<(), DocumentEncryptionError> {
+        let key_store = self.profile_key_store()?;
+        if !key_store.exists() {
+            return Err(DocumentEncryptionError::NotEnabled);
+        }
+        if self.unified_manager.is_document_encryption_enabled() {
+            return Ok(());
+        }
+
+        // パスフレーズを検証してから停止する
+        let cipher: Arc<dyn DocumentCipher> = Arc::new(key_store.unlock(passphrase)?);
+        self.shutdown()
+            .await
+            .map_err(DocumentEncryptionError::Backend)?;
+        self.reload(Some(cipher))
+            .await
+            .map_err(DocumentEncryptionError::Backend)
+    }
+
+    /// Automergeドキュメント暗号化を無効化
+    ///
+    /// バックエンドを停止して全ドキュメントを平文に戻し、プロファイル鍵を削除してから再構築する。
+    pub async fn disable_document_encryption(
+        &mut self,
+        passphrase: &str,
+    ) -> Result<(), DocumentEncryptionError> {
+        let key_store = self.profile_key_store()?;
+        if !key_store.exists() {
+            return Err(DocumentEncryptionError::NotEnabled);
+        }
+        let automerge_path = self
+            .unified_manager
+            .automerge_path()
+            .ok_or(DocumentEncryptionError::StorageDisabled)?
+            .to_path_buf();
+
+        let cipher: Arc<dyn DocumentCipher> = Arc::new(key_store.unlock(passphrase)?);
+        self.shutdown()
+            .await
+            .map_err(DocumentEncryptionError::Backend)?;
+
+        let decrypted = FileStorage::new_with_cipher(&automerge_path, cipher.clone())
+            .and_then(|storage| storage.decrypt_existing_documents())
+            .and_then(|_| key_store.remove());
+        if let Err(e) = decrypted {
+            // 一部が平文に戻っていても、鍵が残っていれば暗号化状態で読み書きできる
+            self.reload(Some(cipher))
+                .await
+                .map_err(DocumentEncryptionError::Backend)?;
+            return Err(e.into());
+        }
+
+        self.reload(None)
+            .await
+            .map_err(DocumentEncryptionError::Backend)
+    }
+
+    /// プロファイル鍵のパスフレーズを変更
+    ///
+    /// データ鍵は変わらないため、ドキュメントの再暗号化やバックエンドの再構築は行わない。
+    pub fn change_document_passphrase(
+        &self,
+        old_passphrase: &str,
+        new_passphrase: &str,
+    ) -> Result<(), DocumentEncryptionError> {
+        let key_store = self.profile_key_store()?;
+        if !key_store.exists() {
+            return Err(DocumentEncryptionError::NotEnabled);
+        }
+        key_store.change_passphrase(old_passphrase, new_passphrase)?;
+        Ok(())
+    }
+}
@@ -28,10 +28,17 @@ pub struct MockInfrastructureRepositories {
     pub recurrence_rules: RecurrenceRuleUnifiedRepository,
     pub task_assignments: TaskAssignmentUnifiedRepository,
     pub subtask_assignments: SubTaskAssignmentUnifiedRepository,
+    pub task_dependencies: TaskDependencyUnifiedRepository,
     pub task_tags: TaskTagUnifiedRepository,
     pub subtask_tags: SubTaskTagUnifiedRepository,
     pub task_recurrences: TaskRecurrenceUnifiedRepository,
     pub subtask_recurrences: SubTaskRecurrenceUnifiedRepository,
+    pub time_entries: TimeEntryUnifiedRepository,
+    pub reminders: ReminderUnifiedRepository,
+    pub snoozes: SnoozeUnifiedRepository,
+    pub comments: CommentUnifiedRepository,
+    pub status_transition_rules: StatusTransitionRuleUnifiedRepository,
+    pub workflow_statuses: WorkflowStatusUnifiedRepository,
     pub tag_bookmarks_sqlite: flequit_infrastructure_sqlite::infrastructure::user_preferences::tag_bookmark::TagBookmarkLocalSqliteRepository,
     pub tag_bookmarks_automerge: flequit_infrastructure_automerge::infrastructure::user_preferences::tag_bookmark::TagBookmarkLocalAutomergeRepository,
     pub unified_manager: UnifiedManager,
@@ -51,10 +58,17 @@ impl MockInfrastructureRepositories {
             recurrence_rules: RecurrenceRuleUnifiedRepository::default(),
             task_assignments: TaskAssignmentUnifiedRepository::default(),
             subtask_assignments: SubTaskAssignmentUnifiedRepository::default(),
+            task_dependencies: TaskDependencyUnifiedRepository::default(),
             task_tags: TaskTagUnifiedRepository::default(),
             subtask_tags: SubTaskTagUnifiedRepository::default(),
             task_recurrences: TaskRecurrenceUnifiedRepository::default(),
             subtask_recurrences: SubTaskRecurrenceUnifiedRepository::default(),
+            time_entries: TimeEntryUnifiedRepository::default(),
+            reminders: ReminderUnifiedRepository::default(),
+            snoozes: SnoozeUnifiedRepository::default(),
+            comments: CommentUnifiedRepository::default(),
+            status_transition_rules: StatusTransitionRuleUnifiedRepository::default(),
+            workflow_statuses: WorkflowStatusUnifiedRepository::default(),
             tag_bookmarks_sqlite:
                 flequit_infrastructure_sqlite::infrastructure::user_preferences::tag_bookmark::TagBookmarkLocalSqliteRepository::new(
                     Arc::new(RwLock::new(DatabaseManager::new_for_test(
@@ -101,10 +115,17 @@ impl InfrastructureRepositoriesTrait for MockInfrastructureRepositories {
     type RecurrenceRulesRepository = RecurrenceRuleUnifiedRepository;
     type TaskAssignmentsRepository = TaskAssignmentUnifiedRepository;
     type SubtaskAssignmentsRepository = SubTaskAssignmentUnifiedRepository;
+    type TaskDependenciesRepository = TaskDependencyUnifiedRepository;
     type TaskTagsRepository = TaskTagUnifiedRepository;
     type SubtaskTagsRepository = SubTaskTagUnifiedRepository;
     type TaskRecurrencesRepository = TaskRecurrenceUnifiedRepository;
     type SubtaskRecurrencesRepository = SubTaskRecurrenceUnifiedRepository;
+    type TimeEntriesRepository = TimeEntryUnifiedRepository;
+    type RemindersRepository = ReminderUnifiedRepository;
+    type SnoozesRepository = SnoozeUnifiedRepository;
+    type CommentsRepository = CommentUnifiedRepository;
+    type StatusTransitionRulesRepository = StatusTransitionRuleUnifiedRepository;
+    type WorkflowStatusesRepository = WorkflowStatusUnifiedRepository;
     type TagBookmarksSqliteRepository = TagBookmarkLocalSqliteRepository;
     type TagBookmarksAutomergeRepository = TagBookmarkLocalAutomergeRepository;
     type SqliteRepositories = LocalSqliteRepositories;
@@ -170,6 +191,11 @@ impl InfrastructureRepositoriesTrait for MockInfrastructureRepositories {
         &self.subtask_assignments
     }
 
+    fn task_dependencies(&self) -> &Self::TaskDependenciesRepository {
+        self.log_call("task_dependencies");
+        &self.task_dependencies
+    }
+
     fn task_tags(&self) -> &Self::TaskTagsRepository {
         self.log_call("task_tags");
         &self.task_tags
@@ -190,6 +216,36 @@ impl InfrastructureRepositoriesTrait for MockInfrastructureRepositories {
         &self.subtask_recurrences
     }
 
+    fn time_entries(&self) -> &Self::TimeEntriesRepository {
+        self.log_call("time_entries");
+        &self.time_entries
+    }
+
+    fn reminders(&self) -> &Self::RemindersRepository {
+        self.log_call("reminders");
+        &self.reminders
+    }
+
+    fn snoozes(&self) -> &Self::SnoozesRepository {
+        self.log_call("snoozes");
+        &self.snoozes
+    }
+
+    fn comments(&self) -> &Self::CommentsRepository {
+        self.log_call("comments");
+        &self.comments
+    }
+
+    fn status_transition_rules(&self) -> &Self::StatusTransitionRulesRepository {
+        self.log_call("status_transition_rules");
+        &self.status_transition_rules
+    }
+
+    fn workflow_statuses(&self) -> &Self::WorkflowStatusesRepository {
+        self.log_call("workflow_statuses");
+        &self.workflow_statuses
+    }
+
     fn tag_bookmarks_sqlite(&self) -> &Self::TagBookmarksSqliteRepository {
         self.log_call("tag_bookmarks_sqlite");
         &self.tag_bookmarks_sqlite
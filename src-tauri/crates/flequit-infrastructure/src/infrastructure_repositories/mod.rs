@@ -2,13 +2,23 @@
 //!
 //! Service層からアクセスするためのリポジトリ統合管理クラス
 
+mod activity;
+mod backfill;
+mod backup;
+mod bundle;
+mod encryption;
+mod outbox;
 mod transaction;
+mod unit_of_work;
+
+pub use encryption::{DocumentEncryptionError, DocumentEncryptionStatus};
 
 use crate::unified::*;
 use async_trait::async_trait;
 use flequit_core::ports::infrastructure_repositories::{
     InfrastructureRepositoriesTrait, TagRepositoryExt,
 };
+use flequit_infrastructure_automerge::infrastructure::encryption::DocumentCipher;
 use flequit_infrastructure_automerge::infrastructure::local_automerge_repositories::LocalAutomergeRepositories;
 use flequit_infrastructure_automerge::infrastructure::user_preferences::tag_bookmark::TagBookmarkLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::local_sqlite_repositories::LocalSqliteRepositories;
@@ -45,10 +55,17 @@ pub struct InfrastructureRepositories {
     pub recurrence_rules: RecurrenceRuleUnifiedRepository,
     pub task_assignments: TaskAssignmentUnifiedRepository,
     pub subtask_assignments: SubTaskAssignmentUnifiedRepository,
+    pub task_dependencies: TaskDependencyUnifiedRepository,
     pub task_tags: TaskTagUnifiedRepository,
     pub subtask_tags: SubTaskTagUnifiedRepository,
     pub task_recurrences: TaskRecurrenceUnifiedRepository,
     pub subtask_recurrences: SubTaskRecurrenceUnifiedRepository,
+    pub time_entries: TimeEntryUnifiedRepository,
+    pub reminders: ReminderUnifiedRepository,
+    pub snoozes: SnoozeUnifiedRepository,
+    pub comments: CommentUnifiedRepository,
+    pub status_transition_rules: StatusTransitionRuleUnifiedRepository,
+    pub workflow_statuses: WorkflowStatusUnifiedRepository,
 
     // User Preferences
     pub tag_bookmarks_sqlite: flequit_infrastructure_sqlite::infrastructure::user_preferences::tag_bookmark::TagBookmarkLocalSqliteRepository,
@@ -74,10 +91,17 @@ impl InfrastructureRepositories {
             recurrence_rules: RecurrenceRuleUnifiedRepository::default(),
             task_assignments: TaskAssignmentUnifiedRepository::default(),
             subtask_assignments: SubTaskAssignmentUnifiedRepository::default(),
+            task_dependencies: TaskDependencyUnifiedRepository::default(),
             task_tags: TaskTagUnifiedRepository::default(),
             subtask_tags: SubTaskTagUnifiedRepository::default(),
             task_recurrences: TaskRecurrenceUnifiedRepository::default(),
             subtask_recurrences: SubTaskRecurrenceUnifiedRepository::default(),
+            time_entries: TimeEntryUnifiedRepository::default(),
+            reminders: ReminderUnifiedRepository::default(),
+            snoozes: SnoozeUnifiedRepository::default(),
+            comments: CommentUnifiedRepository::default(),
+            status_transition_rules: StatusTransitionRuleUnifiedRepository::default(),
+            workflow_statuses: WorkflowStatusUnifiedRepository::default(),
             // User Preferences - テスト用のダミーインスタンス
             // 実際の使用時はsetup_with_sqlite_and_automerge()を使用すること
             tag_bookmarks_sqlite: {
@@ -101,7 +125,40 @@ impl InfrastructureRepositories {
         config: UnifiedConfig,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         let unified_manager = UnifiedManager::from_config(config).await?;
+        Self::from_unified_manager(unified_manager).await
+    }
 
+    /// Automergeドキュメントを暗号化して保存するInfrastructureRepositoriesを作成
+    ///
+    /// 暗号化はFileStorage層で完結するため、各UnifiedRepositoryの動作は変わらない。
+    pub async fn setup_with_document_cipher(
+        config: UnifiedConfig,
+        document_cipher: Arc<dyn DocumentCipher>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let unified_manager =
+            UnifiedManager::from_config_with_cipher(config, Some(document_cipher)).await?;
+        Self::from_unified_manager(unified_manager).await
+    }
+
+    /// テスト用のSQLiteデータベースとAutomergeデータディレクトリを使うInfrastructureRepositoriesを作成
+    #[cfg(test)]
+    pub(crate) async fn for_test(test_name: &str) -> Self {
+        use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+
+        let dir = flequit_testing::TestPathGenerator::generate_test_dir(file!(), test_name);
+        let db_manager = Arc::new(RwLock::new(DatabaseManager::new_for_test(
+            dir.join("flequit.sqlite").to_string_lossy().to_string(),
+        )));
+        db_manager.read().await.get_connection().await.unwrap();
+        let unified_manager = UnifiedManager::for_test(db_manager, dir.join("automerge"))
+            .await
+            .unwrap();
+        Self::from_unified_manager(unified_manager).await.unwrap()
+    }
+
+    async fn from_unified_manager(
+        unified_manager: UnifiedManager,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         let projects = unified_manager.create_project_unified_repository().await?;
         let accounts = unified_manager.create_account_unified_repository().await?;
         let tasks = unified_manager.create_task_unified_repository().await?;
@@ -120,6 +177,9 @@ impl InfrastructureRepositories {
         let subtask_assignments = unified_manager
             .create_sub_task_assignment_unified_repository()
             .await?;
+        let task_dependencies = unified_manager
+            .create_task_dependency_unified_repository()
+            .await?;
         let task_tags = unified_manager.create_task_tag_unified_repository().await?;
         let subtask_tags = unified_manager
             .create_sub_task_tag_unified_repository()
@@ -130,6 +190,18 @@ impl InfrastructureRepositories {
         let subtask_recurrences = unified_manager
             .create_subtask_recurrence_unified_repository()
             .await?;
+        let time_entries = unified_manager
+            .create_time_entry_unified_repository()
+            .await?;
+        let reminders = unified_manager.create_reminder_unified_repository().await?;
+        let snoozes = unified_manager.create_snooze_unified_repository().await?;
+        let comments = unified_manager.create_comment_unified_repository().await?;
+        let status_transition_rules = unified_manager
+            .create_status_transition_rule_unified_repository()
+            .await?;
+        let workflow_statuses = unified_manager
+            .create_workflow_status_unified_repository()
+            .await?;
 
         // User Preferences - LocalRepositoriesから取得
         // SQLiteまたはAutomergeが無効な場合、TagBookmarkリポジトリは使用不可
@@ -162,10 +234,17 @@ impl InfrastructureRepositories {
             recurrence_rules,
             task_assignments,
             subtask_assignments,
+            task_dependencies,
             task_tags,
             subtask_tags,
             task_recurrences,
             subtask_recurrences,
+            time_entries,
+            reminders,
+            snoozes,
+            comments,
+            status_transition_rules,
+            workflow_statuses,
             tag_bookmarks_sqlite,
             tag_bookmarks_automerge,
             unified_manager,
@@ -199,6 +278,33 @@ impl InfrastructureRepositories {
     pub fn config(&self) -> &UnifiedConfig {
         self.unified_manager.config()
     }
+
+    /// 現在のバックエンドを停止
+    ///
+    /// 停止後はデータファイルを差し替えられる。続けて `reload` で再構築する。
+    async fn shutdown(&mut self) -> Result<(), String> {
+        self.unified_manager
+            .shutdown()
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// 現在の設定と指定した暗号でバックエンドとリポジトリを再構築
+    ///
+    /// Tauriコマンドから呼び出せるよう（Futureを `Send` に保つため）、エラーは文字列で返す。
+    async fn reload(
+        &mut self,
+        document_cipher: Option<Arc<dyn DocumentCipher>>,
+    ) -> Result<(), String> {
+        let unified_manager =
+            UnifiedManager::from_config_with_cipher(self.config().clone(), document_cipher)
+                .await
+                .map_err(|e| e.to_string())?;
+        *self = Self::from_unified_manager(unified_manager)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
 }
 
 impl Default for InfrastructureRepositories {
@@ -219,10 +325,17 @@ impl InfrastructureRepositoriesTrait for InfrastructureRepositories {
     type RecurrenceRulesRepository = RecurrenceRuleUnifiedRepository;
     type TaskAssignmentsRepository = TaskAssignmentUnifiedRepository;
     type SubtaskAssignmentsRepository = SubTaskAssignmentUnifiedRepository;
+    type TaskDependenciesRepository = TaskDependencyUnifiedRepository;
     type TaskTagsRepository = TaskTagUnifiedRepository;
     type SubtaskTagsRepository = SubTaskTagUnifiedRepository;
     type TaskRecurrencesRepository = TaskRecurrenceUnifiedRepository;
     type SubtaskRecurrencesRepository = SubTaskRecurrenceUnifiedRepository;
+    type TimeEntriesRepository = TimeEntryUnifiedRepository;
+    type RemindersRepository = ReminderUnifiedRepository;
+    type SnoozesRepository = SnoozeUnifiedRepository;
+    type CommentsRepository = CommentUnifiedRepository;
+    type StatusTransitionRulesRepository = StatusTransitionRuleUnifiedRepository;
+    type WorkflowStatusesRepository = WorkflowStatusUnifiedRepository;
     type TagBookmarksSqliteRepository = TagBookmarkLocalSqliteRepository;
     type TagBookmarksAutomergeRepository = TagBookmarkLocalAutomergeRepository;
     type SqliteRepositories = LocalSqliteRepositories;
@@ -268,6 +381,10 @@ impl InfrastructureRepositoriesTrait for InfrastructureRepositories {
         &self.subtask_assignments
     }
 
+    fn task_dependencies(&self) -> &Self::TaskDependenciesRepository {
+        &self.task_dependencies
+    }
+
     fn task_tags(&self) -> &Self::TaskTagsRepository {
         &self.task_tags
     }
@@ -284,6 +401,30 @@ impl InfrastructureRepositoriesTrait for InfrastructureRepositories {
         &self.subtask_recurrences
     }
 
+    fn time_entries(&self) -> &Self::TimeEntriesRepository {
+        &self.time_entries
+    }
+
+    fn reminders(&self) -> &Self::RemindersRepository {
+        &self.reminders
+    }
+
+    fn snoozes(&self) -> &Self::SnoozesRepository {
+        &self.snoozes
+    }
+
+    fn comments(&self) -> &Self::CommentsRepository {
+        &self.comments
+    }
+
+    fn status_transition_rules(&self) -> &Self::StatusTransitionRulesRepository {
+        &self.status_transition_rules
+    }
+
+    fn workflow_statuses(&self) -> &Self::WorkflowStatusesRepository {
+        &self.workflow_statuses
+    }
+
     fn tag_bookmarks_sqlite(&self) -> &Self::TagBookmarksSqliteRepository {
         &self.tag_bookmarks_sqlite
     }
@@ -306,6 +447,15 @@ impl InfrastructureRepositoriesTrait for InfrastructureRepositories {
         Ok(())
     }
 
+    async fn run_in_unit_of_work<T, E, F>(&self, work: F) -> Result<T, E>
+    where
+        T: Send,
+        E: From<RepositoryError> + Send,
+        F: std::future::Future<Output = Result<T, E>> + Send,
+    {
+        self.unit_of_work(work).await
+    }
+
     fn sqlite_repositories(&self) -> Option<&Arc<RwLock<Self::SqliteRepositories>>> {
         self.unified_manager.sqlite_repositories()
     }
@@ -337,6 +487,7 @@ mod tests {
         let _users = repos.users();
         let _task_assignments = repos.task_assignments();
         let _subtask_assignments = repos.subtask_assignments();
+        let _task_dependencies = repos.task_dependencies();
 
         // 非同期メソッドのテストはここでは省略
         // （実際のテストでは適切なテスト用ランタイムを使用する）
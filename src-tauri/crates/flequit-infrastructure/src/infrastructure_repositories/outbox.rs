@@ -0,0 +1,64 @@
+//! InfrastructureRepositories のアウトボックス操作
+//!
+//! セルフホストサーバーへ送信できなかった書き込みの確認・再送・破棄を提供する。
+
+use super::InfrastructureRepositories;
+use crate::web::{OutboxFlushReport, OutboxSummary, WebClient, WebOutbox};
+use flequit_model::models::sync::outbox_operation::{OutboxOperation, OutboxStatus};
+use flequit_types::errors::repository_error::RepositoryError;
+
+impl InfrastructureRepositories {
+    /// アウトボックスを持つWebクライアント（Web機能またはSQLiteが無効な場合は `None`）
+    pub(crate) fn outbox_client(&self) -> Option<(&WebClient, &WebOutbox)> {
+        let client = self.unified_manager.web_client()?;
+        Some((client, client.outbox()?))
+    }
+
+    fn require_outbox_client(&self) -> Result<(&WebClient, &WebOutbox), RepositoryError> {
+        self.outbox_client().ok_or_else(|| {
+            RepositoryError::ConfigurationError(
+                "Web機能が無効なため、アウトボックスを利用できません".to_string(),
+            )
+        })
+    }
+
+    /// アウトボックス内の操作数（Web機能が無効な場合は全て0）
+    pub async fn outbox_summary(&self) -> Result<OutboxSummary, RepositoryError> {
+        match self.outbox_client() {
+            Some((_, outbox)) => outbox.summary().await,
+            None => Ok(OutboxSummary::default()),
+        }
+    }
+
+    /// アウトボックス内の操作を記録順に取得（`None` の場合は全件）
+    pub async fn list_outbox_operations(
+        &self,
+        status: Option<OutboxStatus>,
+    ) -> Result<Vec<OutboxOperation>, RepositoryError> {
+        match self.outbox_client() {
+            Some((_, outbox)) => outbox.operations(status).await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// 再送日時を待たずにアウトボックスを再送する
+    pub async fn flush_outbox(&self) -> Result<OutboxFlushReport, RepositoryError> {
+        let (client, outbox) = self.require_outbox_client()?;
+        outbox.flush_now(client).await
+    }
+
+    /// 競合・デッドレターの操作を再送キューに戻す
+    pub async fn retry_outbox_operation(
+        &self,
+        seq: i64,
+    ) -> Result<OutboxOperation, RepositoryError> {
+        let (_, outbox) = self.require_outbox_client()?;
+        outbox.retry(seq).await
+    }
+
+    /// 操作を送信せずに破棄する
+    pub async fn discard_outbox_operation(&self, seq: i64) -> Result<bool, RepositoryError> {
+        let (_, outbox) = self.require_outbox_client()?;
+        outbox.discard(seq).await
+    }
+}
@@ -0,0 +1,374 @@
+//! SQLiteとAutomergeにまたがる作業単位（Unit of Work）
+//!
+//! 作業単位の実行中は、SQLiteリポジトリの書き込みがタスクローカルに共有された
+//! 1つのトランザクションへまとめられ、Automergeドキュメントは最初に触れた時点の
+//! 状態が記録される。作業が失敗した場合はトランザクションをロールバックし、
+//! 記録したドキュメントを元の状態へ戻す。
+//!
+//! Webリポジトリへの送信は即座にサーバーへ反映されるため取り消しの対象外。
+//! オフライン時にアウトボックスへ積まれた操作はSQLiteと一緒に取り消される。
+//!
+//! 作業中に発行されたドメインイベントは同じトランザクションでアクティビティログへ記録し、
+//! コミット成功後に取り消し履歴へ積んでからまとめて配信する。失敗した場合はいずれも破棄する。
+
+use super::InfrastructureRepositories;
+use flequit_core::events::{DomainEvent, EventBus, defer_events};
+use flequit_core::undo;
+use flequit_infrastructure_automerge::infrastructure::document_journal::DocumentJournal;
+use flequit_infrastructure_sqlite::errors::sqlite_error::SQLiteError;
+use flequit_infrastructure_sqlite::infrastructure::activity::activity_log::ActivityLogLocalSqliteRepository;
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+use flequit_infrastructure_sqlite::infrastructure::executor::AmbientTransaction;
+use flequit_types::errors::repository_error::RepositoryError;
+use sea_orm::{DatabaseTransaction, TransactionTrait};
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+impl InfrastructureRepositories {
+    /// `work` を1つの作業単位として実行する
+    pub(crate) async fn unit_of_work<T, E, F>(&self, work: F) -> Result<T, E>
+    where
+        E: From<RepositoryError>,
+        F: Future<Output = Result<T, E>>,
+    {
+        let db_manager = match self.unified_manager.sqlite_repositories() {
+            Some(sqlite_repos) => Some(sqlite_repos.read().await.database_manager().clone()),
+            None => None,
+        };
+        run_unit_of_work(db_manager.as_ref(), work).await
+    }
+}
+
+/// SQLiteトランザクションとAutomergeのドキュメント記録の中で `work` を実行する
+///
+/// 既に作業単位の中であれば、新たな作業単位は開始せず外側に含める。
+pub(crate) async fn run_unit_of_work<T, E, F>(
+    db_manager: Option<&Arc<RwLock<DatabaseManager>>>,
+    work: F,
+) -> Result<T, E>
+where
+    E: From<RepositoryError>,
+    F: Future<Output = Result<T, E>>,
+{
+    if DocumentJournal::is_active() {
+        return work.await;
+    }
+
+    let ambient = match db_manager {
+        Some(db_manager) => {
+            let db_manager = db_manager.read().await;
+            let txn = db_manager
+                .get_connection()
+                .await
+                .map_err(RepositoryError::from)?
+                .begin()
+                .await
+                .map_err(|e| RepositoryError::from(SQLiteError::from(e)))?;
+            Some(AmbientTransaction::new(
+                db_manager.database_path(),
+                Arc::new(txn),
+            ))
+        }
+        None => None,
+    };
+    let txn = ambient.as_ref().map(AmbientTransaction::transaction);
+
+    // 入れ子の作業単位でスタックを使い切らないよう、作業はヒープに置く
+    let work = Box::pin(work);
+    let journal = DocumentJournal::new();
+    let (result, events) = match (ambient, db_manager) {
+        (Some(ambient), Some(db_manager)) => {
+            journal
+                .scope(ambient.scope(Box::pin(async {
+                    let (result, events) = defer_events(work).await;
+                    // 変更の記録も同じトランザクションに含め、書き込みと不可分にする
+                    let result = match result {
+                        Ok(value) => record_activity(db_manager, &events)
+                            .await
+                            .map(|()| value)
+                            .map_err(E::from),
+                        Err(e) => Err(e),
+                    };
+                    (result, events)
+                })))
+                .await
+        }
+        _ => journal.scope(defer_events(work)).await,
+    };
+
+    match result {
+        Ok(value) => {
+            if let Some(txn) = txn
+                && let Err(e) = commit(txn).await
+            {
+                revert_documents(&journal).await;
+                return Err(E::from(e));
+            }
+            journal.clear();
+            undo::record_events(&events);
+            EventBus::global().publish_all(events);
+            Ok(value)
+        }
+        Err(e) => {
+            if let Some(txn) = txn
+                && let Err(rollback_err) = rollback(txn).await
+            {
+                tracing::error!("Failed to roll back unit of work: {:?}", rollback_err);
+            }
+            revert_documents(&journal).await;
+            Err(e)
+        }
+    }
+}
+
+/// 作業中に発行されたイベントをアクティビティログへ追加する
+async fn record_activity(
+    db_manager: &Arc<RwLock<DatabaseManager>>,
+    events: &[DomainEvent],
+) -> Result<(), RepositoryError> {
+    let entries: Vec<_> = events
+        .iter()
+        .filter_map(DomainEvent::to_activity_entry)
+        .collect();
+    ActivityLogLocalSqliteRepository::new(db_manager.clone())
+        .append(&entries)
+        .await
+}
+
+fn unwrap_transaction(
+    txn: Arc<DatabaseTransaction>,
+) -> Result<DatabaseTransaction, RepositoryError> {
+    Arc::try_unwrap(txn).map_err(|_| {
+        RepositoryError::TransactionError(
+            "Transaction is still in use after the unit of work finished".to_string(),
+        )
+    })
+}
+
+async fn commit(txn: Arc<DatabaseTransaction>) -> Result<(), RepositoryError> {
+    unwrap_transaction(txn)?
+        .commit()
+        .await
+        .map_err(|e| RepositoryError::from(SQLiteError::from(e)))
+}
+
+async fn rollback(txn: Arc<DatabaseTransaction>) -> Result<(), RepositoryError> {
+    unwrap_transaction(txn)?
+        .rollback()
+        .await
+        .map_err(|e| RepositoryError::from(SQLiteError::from(e)))
+}
+
+async fn revert_documents(journal: &DocumentJournal) {
+    if let Err(e) = journal.rollback().await {
+        tracing::error!("Failed to revert Automerge documents: {:?}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unified::ProjectUnifiedRepository;
+    use chrono::Utc;
+    use flequit_core::events::{self, DomainEvent, EntityKind};
+    use flequit_infrastructure_automerge::infrastructure::document_manager::DocumentManager as AutomergeDocumentManager;
+    use flequit_infrastructure_automerge::infrastructure::task_projects::project::ProjectLocalAutomergeRepository;
+    use flequit_infrastructure_sqlite::infrastructure::task_projects::project::ProjectLocalSqliteRepository;
+    use flequit_model::models::activity::activity_entry::ActivityEntry;
+    use flequit_model::models::task_projects::project::Project;
+    use flequit_model::types::id_types::{ProjectId, UserId};
+    use flequit_repository::repositories::base_repository_trait::Repository;
+    use flequit_testing::TestPathGenerator;
+    use tokio::sync::Mutex;
+
+    struct Fixture {
+        db_manager: Arc<RwLock<DatabaseManager>>,
+        sqlite: ProjectLocalSqliteRepository,
+        automerge: ProjectLocalAutomergeRepository,
+        unified: ProjectUnifiedRepository,
+    }
+
+    async fn fixture(name: &str) -> Fixture {
+        let dir = TestPathGenerator::generate_test_dir(file!(), name);
+        let db_manager = Arc::new(RwLock::new(DatabaseManager::new_for_test(
+            dir.join("unit_of_work.sqlite")
+                .to_string_lossy()
+                .to_string(),
+        )));
+        let document_manager = Arc::new(Mutex::new(
+            AutomergeDocumentManager::new(dir.join("automerge")).unwrap(),
+        ));
+        let automerge = || async {
+            ProjectLocalAutomergeRepository::new_with_manager(document_manager.clone())
+                .await
+                .unwrap()
+        };
+
+        let mut unified = ProjectUnifiedRepository::default();
+        unified.add_sqlite_for_save(ProjectLocalSqliteRepository::new(db_manager.clone()));
+        unified.add_automerge_for_save(automerge().await);
+        Fixture {
+            sqlite: ProjectLocalSqliteRepository::new(db_manager.clone()),
+            automerge: automerge().await,
+            db_manager,
+            unified,
+        }
+    }
+
+    /// 受信済みのイベントから指定エンティティのものを取り出す
+    fn received_for(
+        receiver: &mut tokio::sync::broadcast::Receiver<DomainEvent>,
+        entity_id: &str,
+    ) -> Vec<DomainEvent> {
+        std::iter::from_fn(|| receiver.try_recv().ok())
+            .filter(|event| event.entity_id == entity_id)
+            .collect()
+    }
+
+    async fn activity_for(f: &Fixture, project_id: &ProjectId) -> Vec<ActivityEntry> {
+        ActivityLogLocalSqliteRepository::new(f.db_manager.clone())
+            .find_by_entity(project_id, &project_id.to_string())
+            .await
+            .unwrap()
+    }
+
+    fn project(name: &str) -> Project {
+        let now = Utc::now();
+        Project {
+            id: ProjectId::new(),
+            name: name.to_string(),
+            description: None,
+            color: None,
+            order_index: 0,
+            is_archived: false,
+            status: None,
+            owner_id: None,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_unit_rolls_back_both_backends() {
+        let f = fixture("rollback").await;
+        let existing = project("existing");
+        f.unified
+            .save(&existing, &existing.updated_by, &Utc::now())
+            .await
+            .unwrap();
+
+        let created = project("created");
+        let mut renamed = existing.clone();
+        renamed.name = "renamed".to_string();
+        let mut receiver = EventBus::global().subscribe();
+        let result: Result<(), RepositoryError> = run_unit_of_work(Some(&f.db_manager), async {
+            f.unified
+                .save(&created, &created.updated_by, &Utc::now())
+                .await?;
+            events::publish(
+                DomainEvent::created(EntityKind::Project, created.id).in_project(&created.id),
+            );
+            f.unified
+                .save(&renamed, &renamed.updated_by, &Utc::now())
+                .await?;
+            Err(RepositoryError::AutomergeError(
+                "simulated failure".to_string(),
+            ))
+        })
+        .await;
+        assert!(result.is_err());
+        // 失敗した作業単位のイベントは配信されない
+        assert!(received_for(&mut receiver, &created.id.to_string()).is_empty());
+        assert!(activity_for(&f, &created.id).await.is_empty());
+
+        assert!(f.sqlite.find_by_id(&created.id).await.unwrap().is_none());
+        assert!(f.automerge.find_by_id(&created.id).await.unwrap().is_none());
+        let sqlite_existing = f.sqlite.find_by_id(&existing.id).await.unwrap().unwrap();
+        let automerge_existing = f.automerge.find_by_id(&existing.id).await.unwrap().unwrap();
+        assert_eq!(sqlite_existing.name, "existing");
+        assert_eq!(automerge_existing.name, "existing");
+    }
+
+    #[tokio::test]
+    async fn test_successful_unit_commits_and_nests() {
+        let f = fixture("commit").await;
+        let outer = project("outer");
+        let inner = project("inner");
+        let mut receiver = EventBus::global().subscribe();
+
+        run_unit_of_work(Some(&f.db_manager), async {
+            f.unified
+                .save(&outer, &outer.updated_by, &Utc::now())
+                .await?;
+            // 入れ子の作業単位は外側に含まれる
+            run_unit_of_work(Some(&f.db_manager), async {
+                f.unified
+                    .save(&inner, &inner.updated_by, &Utc::now())
+                    .await?;
+                events::publish(
+                    DomainEvent::created(EntityKind::Project, inner.id).in_project(&inner.id),
+                );
+                Ok::<_, RepositoryError>(())
+            })
+            .await?;
+            // コミットまでは配信されない
+            assert!(received_for(&mut receiver, &inner.id.to_string()).is_empty());
+            Ok::<_, RepositoryError>(())
+        })
+        .await
+        .unwrap();
+        assert_eq!(received_for(&mut receiver, &inner.id.to_string()).len(), 1);
+        // コミットされたイベントはアクティビティログにも記録される
+        assert_eq!(activity_for(&f, &inner.id).await.len(), 1);
+
+        for saved in [&outer, &inner] {
+            assert!(f.sqlite.find_by_id(&saved.id).await.unwrap().is_some());
+            assert!(f.automerge.find_by_id(&saved.id).await.unwrap().is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_committed_unit_is_recorded_as_one_undo_step() {
+        let f = fixture("undo").await;
+        let session = "unit-of-work-test";
+        let first = project("first");
+        let second = project("second");
+
+        undo::in_session(session, async {
+            run_unit_of_work(Some(&f.db_manager), async {
+                for created in [&first, &second] {
+                    f.unified
+                        .save(created, &created.updated_by, &Utc::now())
+                        .await?;
+                    events::publish(
+                        DomainEvent::created(EntityKind::Project, created.id)
+                            .in_project(&created.id),
+                    );
+                }
+                Ok::<_, RepositoryError>(())
+            })
+            .await
+            .unwrap();
+            // 失敗した作業単位は記録されない
+            let _ = run_unit_of_work(Some(&f.db_manager), async {
+                events::publish(
+                    DomainEvent::created(EntityKind::Project, "failed").in_project(&first.id),
+                );
+                Err::<(), _>(RepositoryError::AutomergeError(
+                    "simulated failure".to_string(),
+                ))
+            })
+            .await;
+        })
+        .await;
+
+        let journal = undo::UndoJournal::global();
+        let step = journal.pop_undo(session).unwrap();
+        assert_eq!(step.operations.len(), 2);
+        assert_eq!(step.operations[0].target().entity_id, second.id.to_string());
+        assert!(journal.pop_undo(session).is_none());
+    }
+}
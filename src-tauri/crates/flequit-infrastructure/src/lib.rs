@@ -1,6 +1,6 @@
 //! Infrastructure統合層
 //!
-//! SQLite、Automerge等の各Infrastructure層を統合し、
+//! SQLite、Automerge、セルフホストサーバー（Web）等の各Infrastructure層を統合し、
 //! Service層から直接アクセスできる統一リポジトリインターフェースを提供する。
 //!
 //! 設計原則:
@@ -8,9 +8,19 @@
 //! - 保存系操作: Automerge（永続化） → SQLite（同期）
 //! - 統一インターフェース: 全エンティティで一貫したアクセス方法
 
+pub mod activity;
+pub mod backup;
+pub mod bundle;
+pub mod caldav;
+pub mod calendar_feed;
 pub mod config;
 pub mod infrastructure_repositories;
+pub mod reminder;
 pub mod unified;
+pub mod web;
+
+#[cfg(test)]
+mod service_tests;
 
 // 公開API
 pub use config::InfrastructureConfig;
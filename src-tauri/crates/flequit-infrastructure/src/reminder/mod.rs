@@ -0,0 +1,8 @@
+//! リマインダーの通知スケジューラ
+//!
+//! 通知時刻を迎えたリマインダーを定期的に判定し、通知先のコールバックへ渡す。
+//! 起動直後の最初の判定で、アプリケーションの停止中に過ぎたリマインダーもまとめて通知する。
+
+mod scheduler;
+
+pub use scheduler::ReminderScheduler;
@@ -0,0 +1,87 @@
+//! リマインダーの通知スケジューラ
+
+use crate::InfrastructureRepositories;
+use chrono::Utc;
+use flequit_core::events::{DomainEvent, EntityKind, EventBus};
+use flequit_core::facades::reminder_facades;
+use flequit_core::services::reminder_service::FiredReminder;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::sync::broadcast::{Receiver, error::RecvError};
+use tokio::task::JoinHandle;
+
+/// 次の判定までの最大待ち時間
+///
+/// 同期で取り込んだ変更はドメインイベントにならないため、この間隔で判定し直す。
+const MAX_WAIT: Duration = Duration::from_secs(60);
+
+/// 通知時刻を迎えたリマインダーを通知するスケジューラ
+pub struct ReminderScheduler;
+
+impl ReminderScheduler {
+    /// スケジューラを起動する
+    ///
+    /// 次のリマインダーの通知時刻まで待機し、通知時刻を迎えたリマインダーごとに `on_fire` を呼ぶ。
+    /// リマインダーや通知対象のタスク・サブタスクが変更された場合は待機を打ち切って判定し直す。
+    pub fn spawn<F>(
+        repositories: Arc<RwLock<InfrastructureRepositories>>,
+        on_fire: F,
+    ) -> JoinHandle<()>
+    where
+        F: Fn(&FiredReminder) + Send + Sync + 'static,
+    {
+        let mut events = EventBus::global().subscribe();
+        tokio::spawn(async move {
+            loop {
+                let now = Utc::now();
+                let result = {
+                    let repositories = repositories.read().await;
+                    reminder_facades::fire_due_reminders(&*repositories, now).await
+                };
+                let wait = match result {
+                    Ok(sweep) => {
+                        if !sweep.fired.is_empty() {
+                            tracing::info!("リマインダーを{}件通知しました", sweep.fired.len());
+                        }
+                        for fired in &sweep.fired {
+                            on_fire(fired);
+                        }
+                        sweep
+                            .next_fire_at
+                            .and_then(|next| (next - now).to_std().ok())
+                            .map_or(MAX_WAIT, |wait| wait.min(MAX_WAIT))
+                    }
+                    Err(e) => {
+                        tracing::error!("Reminder check failed: {}", e);
+                        MAX_WAIT
+                    }
+                };
+
+                // 待機中にリマインダーの設定や予定日時が変わったら、すぐに判定し直す
+                let _ = tokio::time::timeout(wait, wait_for_change(&mut events)).await;
+            }
+        })
+    }
+}
+
+/// 通知日時に影響する変更が起きるまで待つ
+async fn wait_for_change(events: &mut Receiver<DomainEvent>) {
+    loop {
+        match events.recv().await {
+            Ok(event) if affects_reminders(&event) => return,
+            Ok(_) => continue,
+            // 取りこぼしたイベントに該当するものがあったかもしれないため判定し直す
+            Err(RecvError::Lagged(_)) => return,
+            // イベントが届かなくなった後は最大待ち時間ごとの判定だけを行う
+            Err(RecvError::Closed) => std::future::pending::<()>().await,
+        }
+    }
+}
+
+fn affects_reminders(event: &DomainEvent) -> bool {
+    matches!(
+        event.entity,
+        EntityKind::Reminder | EntityKind::Task | EntityKind::SubTask | EntityKind::Project
+    )
+}
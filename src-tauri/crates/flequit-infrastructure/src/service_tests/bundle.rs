@@ -0,0 +1,74 @@
+use super::ProjectFixture;
+use crate::InfrastructureRepositories;
+use crate::bundle::BundleImportMode;
+use flequit_core::events::{self, ChangeKind, EntityKind, EventOrigin};
+use flequit_core::services::task_service;
+use flequit_model::models::task_projects::task::PartialTask;
+use flequit_testing::TestPathGenerator;
+
+#[tokio::test]
+async fn test_merged_bundle_changes_are_published_as_sync_events() {
+    let test_name = "test_merged_bundle_changes_are_published_as_sync_events";
+    let source = ProjectFixture::new(test_name).await;
+    let task_id = source.add_task("Task").await;
+    let dir = TestPathGenerator::generate_test_dir(file!(), test_name);
+    let first = dir.join("first.flequit");
+    let second = dir.join("second.flequit");
+
+    let target = InfrastructureRepositories::for_test(&format!("{}_target", test_name)).await;
+    source
+        .repositories
+        .export_project_bundle(&source.project_id, &first)
+        .await
+        .unwrap();
+    target
+        .import_project_bundle(&first, BundleImportMode::KeepIds)
+        .await
+        .unwrap();
+
+    // 取り込み元で変更してから、もう一度書き出してマージする
+    let patch = PartialTask {
+        title: Some("Renamed".to_string()),
+        ..Default::default()
+    };
+    task_service::update_task(
+        &source.repositories,
+        &source.project_id,
+        &task_id,
+        &patch,
+        &source.user_id,
+    )
+    .await
+    .unwrap();
+    let added_id = source.add_task("Added").await;
+    source
+        .repositories
+        .export_project_bundle(&source.project_id, &second)
+        .await
+        .unwrap();
+
+    let (result, published) =
+        events::defer_events(target.import_project_bundle(&second, BundleImportMode::KeepIds))
+            .await;
+    assert!(result.unwrap().merged);
+
+    let task_events: Vec<_> = published
+        .iter()
+        .filter(|event| event.entity == EntityKind::Task)
+        .collect();
+    assert_eq!(task_events.len(), 2);
+    assert!(task_events.iter().all(|event| {
+        event.origin == EventOrigin::Sync && event.project_id == Some(source.project_id)
+    }));
+    let renamed = task_events
+        .iter()
+        .find(|event| event.entity_id == task_id.to_string())
+        .unwrap();
+    assert_eq!(renamed.change, ChangeKind::Updated);
+    assert!(renamed.changed_fields.iter().any(|field| field == "title"));
+    let added = task_events
+        .iter()
+        .find(|event| event.entity_id == added_id.to_string())
+        .unwrap();
+    assert_eq!(added.change, ChangeKind::Created);
+}
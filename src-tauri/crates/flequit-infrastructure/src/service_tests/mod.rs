@@ -0,0 +1,107 @@
+//! サービス層をSQLite・Automergeのリポジトリに対して実行するテスト
+//!
+//! flequit-core のサービスはリポジトリのトレイトにのみ依存するため、
+//! 実際のリポジトリと組み合わせた動作はこのクレートで確認する。
+
+mod bundle;
+mod status_transition;
+mod task_dependency;
+mod time_entry;
+mod undo;
+mod workflow_status;
+
+use crate::InfrastructureRepositories;
+use chrono::Utc;
+use flequit_core::services::{project_service, task_list_service, task_service};
+use flequit_model::models::task_projects::{project::Project, task::Task, task_list::TaskList};
+use flequit_model::types::id_types::{ProjectId, TaskId, TaskListId, UserId};
+use flequit_model::types::task_types::TaskStatus;
+
+/// プロジェクトとタスクリストを1つずつ作成した状態のリポジトリ
+struct ProjectFixture {
+    repositories: InfrastructureRepositories,
+    project_id: ProjectId,
+    list_id: TaskListId,
+    user_id: UserId,
+}
+
+impl ProjectFixture {
+    async fn new(test_name: &str) -> Self {
+        let repositories = InfrastructureRepositories::for_test(test_name).await;
+        let user_id = UserId::new();
+        let now = Utc::now();
+        let project = Project {
+            id: ProjectId::new(),
+            name: "Project".to_string(),
+            description: None,
+            color: None,
+            order_index: 0,
+            is_archived: false,
+            status: None,
+            owner_id: Some(user_id),
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        let project = project_service::create_project(&repositories, &project, &user_id)
+            .await
+            .unwrap();
+        let task_list = TaskList {
+            id: TaskListId::new(),
+            project_id: project.id,
+            name: "List".to_string(),
+            description: None,
+            color: None,
+            order_index: 0,
+            is_archived: false,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: user_id,
+        };
+        task_list_service::create_task_list(&repositories, &project.id, &task_list, &user_id)
+            .await
+            .unwrap();
+
+        Self {
+            repositories,
+            project_id: project.id,
+            list_id: task_list.id,
+            user_id,
+        }
+    }
+
+    /// 未着手のタスクを作成する
+    async fn add_task(&self, title: &str) -> TaskId {
+        let now = Utc::now();
+        let task = Task {
+            id: TaskId::new(),
+            project_id: self.project_id,
+            list_id: self.list_id,
+            title: title.to_string(),
+            description: None,
+            status: TaskStatus::NotStarted,
+            workflow_status_id: None,
+            priority: 0,
+            plan_start_date: None,
+            plan_end_date: None,
+            do_start_date: None,
+            do_end_date: None,
+            is_range_date: None,
+            recurrence_rule: None,
+            order_index: 0,
+            is_archived: false,
+            assigned_user_ids: vec![],
+            tag_ids: vec![],
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: self.user_id,
+        };
+        task_service::create_task(&self.repositories, &self.project_id, &task, &self.user_id)
+            .await
+            .unwrap();
+        task.id
+    }
+}
@@ -0,0 +1,145 @@
+use super::ProjectFixture;
+use chrono::Utc;
+use flequit_core::events::{self, EntityKind, REASON_FIELD};
+use flequit_core::services::{status_transition_service, task_service};
+use flequit_model::models::task_projects::status_transition_rule::StatusTransitionRule;
+use flequit_model::models::task_projects::task::PartialTask;
+use flequit_model::types::id_types::{StatusTransitionRuleId, TaskId};
+use flequit_model::types::task_types::{StatusTransitionRequirement, TaskStatus};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::service_error::ServiceError;
+
+impl ProjectFixture {
+    async fn add_rule(
+        &self,
+        from: TaskStatus,
+        to: TaskStatus,
+        requirement: StatusTransitionRequirement,
+    ) {
+        let now = Utc::now();
+        let rule = StatusTransitionRule {
+            id: StatusTransitionRuleId::new(),
+            project_id: self.project_id,
+            from_status: from,
+            to_status: to,
+            requirement,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: self.user_id,
+        };
+        status_transition_service::create_status_transition_rule(
+            &self.repositories,
+            &self.project_id,
+            &rule,
+            &self.user_id,
+        )
+        .await
+        .unwrap();
+    }
+
+    async fn task_status(&self, task_id: &TaskId) -> TaskStatus {
+        self.repositories
+            .tasks
+            .find_by_id(&self.project_id, task_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .status
+    }
+}
+
+#[tokio::test]
+async fn test_disallowed_transition_is_rejected() {
+    let fixture = ProjectFixture::new("test_disallowed_transition_is_rejected").await;
+    let task_id = fixture.add_task("Task").await;
+    fixture
+        .add_rule(
+            TaskStatus::NotStarted,
+            TaskStatus::Completed,
+            StatusTransitionRequirement::Forbidden,
+        )
+        .await;
+
+    let result = task_service::update_task_status(
+        &fixture.repositories,
+        &fixture.project_id.to_string(),
+        &task_id.to_string(),
+        &TaskStatus::Completed,
+        Some("skip"),
+        &fixture.user_id,
+    )
+    .await;
+    assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+    let patch = PartialTask {
+        status: Some(TaskStatus::Completed),
+        ..Default::default()
+    };
+    let result = task_service::update_task(
+        &fixture.repositories,
+        &fixture.project_id,
+        &task_id,
+        &patch,
+        &fixture.user_id,
+    )
+    .await;
+    assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+    assert_eq!(fixture.task_status(&task_id).await, TaskStatus::NotStarted);
+}
+
+#[tokio::test]
+async fn test_reason_is_required_and_published_with_the_change() {
+    let fixture =
+        ProjectFixture::new("test_reason_is_required_and_published_with_the_change").await;
+    let task_id = fixture.add_task("Task").await;
+    fixture
+        .add_rule(
+            TaskStatus::NotStarted,
+            TaskStatus::Cancelled,
+            StatusTransitionRequirement::ReasonRequired,
+        )
+        .await;
+
+    // 部分更新では理由を指定できないため拒否する
+    let patch = PartialTask {
+        status: Some(TaskStatus::Cancelled),
+        ..Default::default()
+    };
+    let Err(ServiceError::ValidationError(message)) = task_service::update_task(
+        &fixture.repositories,
+        &fixture.project_id,
+        &task_id,
+        &patch,
+        &fixture.user_id,
+    )
+    .await
+    else {
+        panic!("expected a validation error");
+    };
+    assert!(message.contains("reason"));
+
+    let (result, published) = events::defer_events(task_service::update_task_status(
+        &fixture.repositories,
+        &fixture.project_id.to_string(),
+        &task_id.to_string(),
+        &TaskStatus::Cancelled,
+        Some("Out of scope"),
+        &fixture.user_id,
+    ))
+    .await;
+    result.unwrap();
+    assert_eq!(fixture.task_status(&task_id).await, TaskStatus::Cancelled);
+
+    let event = published
+        .iter()
+        .find(|event| event.entity == EntityKind::Task && event.entity_id == task_id.to_string())
+        .unwrap();
+    let reason = event
+        .changes
+        .iter()
+        .find(|change| change.field == REASON_FIELD)
+        .unwrap();
+    assert_eq!(reason.to, serde_json::json!("Out of scope"));
+    assert!(event.changed_fields.iter().any(|field| field == "status"));
+}
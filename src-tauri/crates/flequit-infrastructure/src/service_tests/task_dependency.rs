@@ -0,0 +1,113 @@
+use super::ProjectFixture;
+use flequit_core::events::{self, ChangeKind, DomainEvent, EntityKind};
+use flequit_core::services::{task_dependency_service, task_service};
+use flequit_model::types::id_types::TaskId;
+use flequit_model::types::task_types::TaskStatus;
+use flequit_types::errors::service_error::ServiceError;
+
+impl ProjectFixture {
+    async fn add_dependency(&self, task_id: &TaskId, depends_on_task_id: &TaskId) {
+        task_dependency_service::add_task_dependency(
+            &self.repositories,
+            &self.project_id,
+            task_id,
+            depends_on_task_id,
+            &self.user_id,
+        )
+        .await
+        .unwrap();
+    }
+
+    /// タスクを完了し、その間に発行されたブロック解除の通知を返す
+    async fn complete_task(&self, task_id: &TaskId) -> Vec<DomainEvent> {
+        let (result, published) = events::defer_events(task_service::update_task_status(
+            &self.repositories,
+            &self.project_id.to_string(),
+            &task_id.to_string(),
+            &TaskStatus::Completed,
+            None,
+            &self.user_id,
+        ))
+        .await;
+        result.unwrap();
+        published
+            .into_iter()
+            .filter(|event| {
+                event.entity == EntityKind::TaskDependency && event.change == ChangeKind::Updated
+            })
+            .collect()
+    }
+}
+
+#[tokio::test]
+async fn test_dependency_cycle_is_rejected() {
+    let fixture = ProjectFixture::new("test_dependency_cycle_is_rejected").await;
+    let first = fixture.add_task("First").await;
+    let second = fixture.add_task("Second").await;
+    let third = fixture.add_task("Third").await;
+    fixture.add_dependency(&second, &first).await;
+    fixture.add_dependency(&third, &second).await;
+
+    let result = task_dependency_service::add_task_dependency(
+        &fixture.repositories,
+        &fixture.project_id,
+        &first,
+        &third,
+        &fixture.user_id,
+    )
+    .await;
+    assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+    let blocking = task_dependency_service::list_blocking_task_ids(
+        &fixture.repositories,
+        &fixture.project_id,
+        &first,
+    )
+    .await
+    .unwrap();
+    assert!(blocking.is_empty());
+}
+
+#[tokio::test]
+async fn test_dependent_is_notified_once_every_blocker_is_closed() {
+    let fixture =
+        ProjectFixture::new("test_dependent_is_notified_once_every_blocker_is_closed").await;
+    let design = fixture.add_task("Design").await;
+    let review = fixture.add_task("Review").await;
+    let release = fixture.add_task("Release").await;
+    fixture.add_dependency(&release, &design).await;
+    fixture.add_dependency(&release, &review).await;
+
+    // 他の先行タスクが残っている間は通知しない
+    assert!(fixture.complete_task(&design).await.is_empty());
+
+    let unblocked = fixture.complete_task(&review).await;
+    assert_eq!(unblocked.len(), 1);
+    assert_eq!(unblocked[0].entity_id, release.to_string());
+    assert_eq!(unblocked[0].related_id, Some(review.to_string()));
+
+    // 先行タスクを指定して直接呼び出しても、ブロックが解除された後続タスクを通知する
+    let (result, published) =
+        events::defer_events(task_dependency_service::publish_unblocked_dependents(
+            &fixture.repositories,
+            &fixture.project_id,
+            &design,
+            &fixture.user_id,
+        ))
+        .await;
+    result.unwrap();
+    assert_eq!(published.len(), 1);
+    assert_eq!(published[0].entity_id, release.to_string());
+
+    // 後続タスクが完了していれば通知しない
+    fixture.complete_task(&release).await;
+    let (result, published) =
+        events::defer_events(task_dependency_service::publish_unblocked_dependents(
+            &fixture.repositories,
+            &fixture.project_id,
+            &design,
+            &fixture.user_id,
+        ))
+        .await;
+    result.unwrap();
+    assert!(published.is_empty());
+}
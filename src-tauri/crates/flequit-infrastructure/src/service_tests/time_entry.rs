@@ -0,0 +1,60 @@
+use super::ProjectFixture;
+use flequit_core::services::time_entry_service;
+use flequit_types::errors::service_error::ServiceError;
+
+#[tokio::test]
+async fn test_only_one_timer_runs_at_a_time() {
+    let fixture = ProjectFixture::new("test_only_one_timer_runs_at_a_time").await;
+    let first = fixture.add_task("First").await;
+    let second = fixture.add_task("Second").await;
+    let repositories = &fixture.repositories;
+
+    let running = time_entry_service::start_timer(
+        repositories,
+        &fixture.project_id,
+        &first,
+        None,
+        None,
+        &fixture.user_id,
+    )
+    .await
+    .unwrap();
+
+    // 計測中のタイマーがある間は別のタスクでも開始できない
+    let result = time_entry_service::start_timer(
+        repositories,
+        &fixture.project_id,
+        &second,
+        None,
+        None,
+        &fixture.user_id,
+    )
+    .await;
+    assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+
+    let stopped = time_entry_service::stop_timer(repositories, &fixture.user_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stopped.id, running.id);
+    assert!(stopped.ended_at.is_some());
+
+    // 停止後は開始できる
+    let restarted = time_entry_service::start_timer(
+        repositories,
+        &fixture.project_id,
+        &second,
+        None,
+        None,
+        &fixture.user_id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        time_entry_service::find_running_time_entry(repositories, &fixture.user_id)
+            .await
+            .unwrap()
+            .map(|entry| entry.id),
+        Some(restarted.id)
+    );
+}
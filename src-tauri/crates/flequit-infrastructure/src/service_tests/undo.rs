@@ -0,0 +1,260 @@
+use super::ProjectFixture;
+use chrono::Utc;
+use flequit_core::facades::{tag_facades, task_facades, undo_facades, workflow_status_facades};
+use flequit_core::undo;
+use flequit_model::models::task_projects::tag::Tag;
+use flequit_model::models::task_projects::task::{PartialTask, Task};
+use flequit_model::models::task_projects::workflow_status::{
+    PartialWorkflowStatus, WorkflowStatus,
+};
+use flequit_model::types::id_types::{TagId, TaskId, WorkflowStatusId};
+use flequit_model::types::task_types::TaskStatus;
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+
+impl ProjectFixture {
+    async fn undo(&self, session: &str) {
+        undo_facades::undo(&self.repositories, session, &self.user_id)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    async fn redo(&self, session: &str) {
+        undo_facades::redo(&self.repositories, session, &self.user_id)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    /// 論理削除されていないタスク
+    async fn active_task(&self, task_id: &TaskId) -> Option<Task> {
+        self.repositories
+            .tasks
+            .find_by_id(&self.project_id, task_id)
+            .await
+            .unwrap()
+            .filter(|task| !task.deleted)
+    }
+
+    async fn workflow_status(&self, id: &WorkflowStatusId) -> Option<WorkflowStatus> {
+        self.repositories
+            .workflow_statuses
+            .find_by_id(&self.project_id, id)
+            .await
+            .unwrap()
+    }
+
+    async fn task_tag_ids(&self, task_id: &TaskId) -> Vec<TagId> {
+        task_facades::get_tag_ids_by_task_id(&self.repositories, &self.project_id, task_id)
+            .await
+            .unwrap()
+    }
+}
+
+#[tokio::test]
+async fn test_undo_and_redo_task_create_update_and_delete() {
+    let session = "test_undo_and_redo_task_create_update_and_delete";
+    let fixture = ProjectFixture::new(session).await;
+    let task_id = fixture.add_task("Task").await;
+    let task = fixture.active_task(&task_id).await.unwrap();
+
+    // 作成
+    let created = Task {
+        id: TaskId::new(),
+        title: "Created".to_string(),
+        ..task
+    };
+    undo::in_session(
+        session,
+        task_facades::create_task(
+            &fixture.repositories,
+            &fixture.project_id,
+            &created,
+            &fixture.user_id,
+        ),
+    )
+    .await
+    .unwrap();
+    fixture.undo(session).await;
+    assert!(fixture.active_task(&created.id).await.is_none());
+    fixture.redo(session).await;
+    assert!(fixture.active_task(&created.id).await.is_some());
+
+    // 更新
+    let patch = PartialTask {
+        title: Some("Renamed".to_string()),
+        ..Default::default()
+    };
+    undo::in_session(
+        session,
+        task_facades::update_task(
+            &fixture.repositories,
+            &fixture.project_id,
+            &task_id,
+            &patch,
+            &fixture.user_id,
+        ),
+    )
+    .await
+    .unwrap();
+    fixture.undo(session).await;
+    assert_eq!(fixture.active_task(&task_id).await.unwrap().title, "Task");
+    fixture.redo(session).await;
+    assert_eq!(
+        fixture.active_task(&task_id).await.unwrap().title,
+        "Renamed"
+    );
+
+    // 論理削除
+    undo::in_session(
+        session,
+        task_facades::delete_task(
+            &fixture.repositories,
+            &fixture.project_id,
+            &task_id,
+            &fixture.user_id,
+            &Utc::now(),
+        ),
+    )
+    .await
+    .unwrap();
+    assert!(fixture.active_task(&task_id).await.is_none());
+    fixture.undo(session).await;
+    assert!(fixture.active_task(&task_id).await.is_some());
+    fixture.redo(session).await;
+    assert!(fixture.active_task(&task_id).await.is_none());
+}
+
+#[tokio::test]
+async fn test_undo_and_redo_tag_relation_add_and_remove() {
+    let session = "test_undo_and_redo_tag_relation_add_and_remove";
+    let fixture = ProjectFixture::new(session).await;
+    let task_id = fixture.add_task("Task").await;
+    let now = Utc::now();
+    let tag = Tag {
+        id: TagId::new(),
+        name: "Tag".to_string(),
+        color: None,
+        order_index: None,
+        created_at: now,
+        updated_at: now,
+        deleted: false,
+        updated_by: fixture.user_id,
+    };
+    tag_facades::create_tag(
+        &fixture.repositories,
+        &fixture.project_id,
+        &tag,
+        &fixture.user_id,
+    )
+    .await
+    .unwrap();
+
+    undo::in_session(
+        session,
+        task_facades::add_task_tag_relation(
+            &fixture.repositories,
+            &fixture.project_id,
+            &task_id,
+            &tag.id,
+            &fixture.user_id,
+        ),
+    )
+    .await
+    .unwrap();
+    fixture.undo(session).await;
+    assert!(fixture.task_tag_ids(&task_id).await.is_empty());
+    fixture.redo(session).await;
+    assert_eq!(fixture.task_tag_ids(&task_id).await, vec![tag.id]);
+
+    undo::in_session(
+        session,
+        task_facades::remove_task_tag_relation(
+            &fixture.repositories,
+            &fixture.project_id,
+            &task_id,
+            &tag.id,
+        ),
+    )
+    .await
+    .unwrap();
+    assert!(fixture.task_tag_ids(&task_id).await.is_empty());
+    fixture.undo(session).await;
+    assert_eq!(fixture.task_tag_ids(&task_id).await, vec![tag.id]);
+    fixture.redo(session).await;
+    assert!(fixture.task_tag_ids(&task_id).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_undo_and_redo_workflow_status_changes() {
+    let session = "test_undo_and_redo_workflow_status_changes";
+    let fixture = ProjectFixture::new(session).await;
+    let now = Utc::now();
+    let review = WorkflowStatus {
+        id: WorkflowStatusId::new(),
+        project_id: fixture.project_id,
+        name: "Review".to_string(),
+        color: None,
+        order_index: 0,
+        category: TaskStatus::InProgress,
+        created_at: now,
+        updated_at: now,
+        deleted: false,
+        updated_by: fixture.user_id,
+    };
+    undo::in_session(
+        session,
+        workflow_status_facades::create_workflow_status(
+            &fixture.repositories,
+            &fixture.project_id,
+            &review,
+            &fixture.user_id,
+        ),
+    )
+    .await
+    .unwrap();
+    fixture.undo(session).await;
+    assert!(fixture.workflow_status(&review.id).await.is_none());
+    fixture.redo(session).await;
+    assert!(fixture.workflow_status(&review.id).await.is_some());
+
+    let patch = PartialWorkflowStatus {
+        name: Some("QA".to_string()),
+        ..Default::default()
+    };
+    undo::in_session(
+        session,
+        workflow_status_facades::update_workflow_status(
+            &fixture.repositories,
+            &fixture.project_id,
+            &review.id,
+            &patch,
+            &fixture.user_id,
+        ),
+    )
+    .await
+    .unwrap();
+    fixture.undo(session).await;
+    assert_eq!(
+        fixture.workflow_status(&review.id).await.unwrap().name,
+        "Review"
+    );
+
+    // 物理削除は削除前の状態から作り直す
+    undo::in_session(
+        session,
+        workflow_status_facades::delete_workflow_status(
+            &fixture.repositories,
+            &fixture.project_id,
+            &review.id,
+            &fixture.user_id,
+        ),
+    )
+    .await
+    .unwrap();
+    assert!(fixture.workflow_status(&review.id).await.is_none());
+    fixture.undo(session).await;
+    let recreated = fixture.workflow_status(&review.id).await.unwrap();
+    assert_eq!(recreated.name, "Review");
+    assert_eq!(recreated.category, TaskStatus::InProgress);
+}
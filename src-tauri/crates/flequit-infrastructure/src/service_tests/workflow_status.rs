@@ -0,0 +1,92 @@
+use super::ProjectFixture;
+use chrono::Utc;
+use flequit_core::services::{task_service, workflow_status_service};
+use flequit_model::models::task_projects::task::PartialTask;
+use flequit_model::models::task_projects::workflow_status::WorkflowStatus;
+use flequit_model::types::id_types::WorkflowStatusId;
+use flequit_model::types::task_types::TaskStatus;
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::service_error::ServiceError;
+
+#[tokio::test]
+async fn test_workflow_status_in_use_cannot_be_deleted() {
+    let fixture = ProjectFixture::new("test_workflow_status_in_use_cannot_be_deleted").await;
+    let now = Utc::now();
+    let review = WorkflowStatus {
+        id: WorkflowStatusId::new(),
+        project_id: fixture.project_id,
+        name: "Review".to_string(),
+        color: None,
+        order_index: 0,
+        category: TaskStatus::InProgress,
+        created_at: now,
+        updated_at: now,
+        deleted: false,
+        updated_by: fixture.user_id,
+    };
+    workflow_status_service::create_workflow_status(
+        &fixture.repositories,
+        &fixture.project_id,
+        &review,
+        &fixture.user_id,
+    )
+    .await
+    .unwrap();
+
+    let task_id = fixture.add_task("Task").await;
+    let patch = PartialTask {
+        workflow_status_id: Some(Some(review.id)),
+        ..Default::default()
+    };
+    task_service::update_task(
+        &fixture.repositories,
+        &fixture.project_id,
+        &task_id,
+        &patch,
+        &fixture.user_id,
+    )
+    .await
+    .unwrap();
+
+    let result = workflow_status_service::delete_workflow_status(
+        &fixture.repositories,
+        &fixture.project_id,
+        &review.id,
+        &fixture.user_id,
+    )
+    .await;
+    assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+    assert!(
+        fixture
+            .repositories
+            .workflow_statuses
+            .find_by_id(&fixture.project_id, &review.id)
+            .await
+            .unwrap()
+            .is_some()
+    );
+
+    // 参照を外せば削除できる
+    let patch = PartialTask {
+        workflow_status_id: Some(None),
+        ..Default::default()
+    };
+    task_service::update_task(
+        &fixture.repositories,
+        &fixture.project_id,
+        &task_id,
+        &patch,
+        &fixture.user_id,
+    )
+    .await
+    .unwrap();
+    let deleted = workflow_status_service::delete_workflow_status(
+        &fixture.repositories,
+        &fixture.project_id,
+        &review.id,
+        &fixture.user_id,
+    )
+    .await
+    .unwrap();
+    assert!(deleted);
+}
@@ -8,6 +8,7 @@ use chrono::{DateTime, Utc};
 use flequit_repository::patchable_trait::Patchable;
 use tracing::info;
 
+use crate::web::AccountWebRepository;
 use flequit_infrastructure_automerge::infrastructure::accounts::account::AccountLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::accounts::account::AccountLocalSqliteRepository;
 use flequit_model::models::accounts::account::Account;
@@ -21,8 +22,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum AccountRepositoryVariant {
     LocalSqlite(AccountLocalSqliteRepository),
     LocalAutomerge(AccountLocalAutomergeRepository),
-    // 将来的にWebの実装が追加される予定
-    // Web(WebAccountRepository),
+    Web(AccountWebRepository),
 }
 
 impl AccountRepositoryTrait for AccountRepositoryVariant {}
@@ -38,6 +38,7 @@ impl Repository<Account, AccountId> for AccountRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.save(entity, user_id, timestamp).await,
             Self::LocalAutomerge(repo) => repo.save(entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(entity, user_id, timestamp).await,
         }
     }
 
@@ -45,6 +46,7 @@ impl Repository<Account, AccountId> for AccountRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_by_id(id).await,
             Self::LocalAutomerge(repo) => repo.find_by_id(id).await,
+            Self::Web(repo) => repo.find_by_id(id).await,
         }
     }
 
@@ -52,6 +54,7 @@ impl Repository<Account, AccountId> for AccountRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_all().await,
             Self::LocalAutomerge(repo) => repo.find_all().await,
+            Self::Web(repo) => repo.find_all().await,
         }
     }
 
@@ -59,6 +62,7 @@ impl Repository<Account, AccountId> for AccountRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.delete(id).await,
             Self::LocalAutomerge(repo) => repo.delete(id).await,
+            Self::Web(repo) => repo.delete(id).await,
         }
     }
 
@@ -66,6 +70,7 @@ impl Repository<Account, AccountId> for AccountRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.exists(id).await,
             Self::LocalAutomerge(repo) => repo.exists(id).await,
+            Self::Web(repo) => repo.exists(id).await,
         }
     }
 
@@ -73,6 +78,7 @@ impl Repository<Account, AccountId> for AccountRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.count().await,
             Self::LocalAutomerge(repo) => repo.count().await,
+            Self::Web(repo) => repo.count().await,
         }
     }
 }
@@ -135,6 +141,18 @@ impl AccountUnifiedRepository {
             .push(AccountRepositoryVariant::LocalAutomerge(automerge_repo));
     }
 
+    /// Webリポジトリを保存用に追加
+    pub fn add_web_for_save(&mut self, web_repo: AccountWebRepository) {
+        self.save_repositories
+            .push(AccountRepositoryVariant::Web(web_repo));
+    }
+
+    /// Webリポジトリを検索用に追加
+    pub fn add_web_for_search(&mut self, web_repo: AccountWebRepository) {
+        self.search_repositories
+            .push(AccountRepositoryVariant::Web(web_repo));
+    }
+
     /// 便利メソッド: SQLiteを保存用と検索用の両方に追加
     pub fn add_sqlite_for_both(
         &mut self,
@@ -4,11 +4,11 @@
 
 use super::{UnifiedManager, get_default_automerge_path};
 use crate::unified::{SubTaskAssignmentUnifiedRepository, TaskAssignmentUnifiedRepository};
+use crate::web::{SubTaskAssignmentWebRepository, TaskAssignmentWebRepository};
 use flequit_infrastructure_automerge::infrastructure::task_projects::{
     subtask_assignments::SubtaskAssignmentLocalAutomergeRepository,
     task_assignments::TaskAssignmentLocalAutomergeRepository,
 };
-use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::{
     subtask_assignments::SubtaskAssignmentLocalSqliteRepository,
     task_assignments::TaskAssignmentLocalSqliteRepository,
@@ -23,7 +23,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             if self.config.sqlite_search_enabled {
                 let sqlite_repo = TaskAssignmentLocalSqliteRepository::new(db_manager.clone());
@@ -53,6 +53,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（TaskAssignment）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(TaskAssignmentWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（TaskAssignment）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(TaskAssignmentWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（TaskAssignment）");
+            }
+        }
+
         tracing::info!(
             "TaskAssignmentUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
             repo.save_repositories_count(),
@@ -70,7 +83,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             if self.config.sqlite_search_enabled {
                 let sqlite_repo = SubtaskAssignmentLocalSqliteRepository::new(db_manager.clone());
@@ -100,6 +113,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（SubTaskAssignment）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(SubTaskAssignmentWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（SubTaskAssignment）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(SubTaskAssignmentWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（SubTaskAssignment）");
+            }
+        }
+
         tracing::info!(
             "SubTaskAssignmentUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
             repo.save_repositories_count(),
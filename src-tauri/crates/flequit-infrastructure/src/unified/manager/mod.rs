@@ -3,18 +3,30 @@
 //! 設定に基づいてバックエンドリポジトリを初期化・管理する
 
 mod assignment_builders;
+mod comment_builders;
 mod project_builders;
 mod recurrence_builders;
+mod reminder_builders;
+mod snooze_builders;
+mod status_transition_rule_builders;
 mod tag_builders;
 mod task_builders;
+mod task_dependency_builders;
+mod time_entry_builders;
+mod workflow_status_builders;
 
 use flequit_infrastructure_automerge::LocalAutomergeRepositories;
 use flequit_infrastructure_automerge::infrastructure::document_manager::DocumentManager;
+use flequit_infrastructure_automerge::infrastructure::encryption::DocumentCipher;
+use flequit_infrastructure_sqlite::errors::sqlite_error::SQLiteError;
+use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
 use flequit_infrastructure_sqlite::infrastructure::local_sqlite_repositories::LocalSqliteRepositories;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 
 use crate::unified::UnifiedConfig;
+use crate::web::{WebClient, WebOutbox};
 
 /// Unified層のマネージャー
 ///
@@ -26,6 +38,12 @@ pub struct UnifiedManager {
     pub(super) automerge_repositories: Option<Arc<RwLock<LocalAutomergeRepositories>>>,
     /// 共有DocumentManager - Automerge Repoの重複を避けるため
     pub(super) shared_document_manager: Option<Arc<Mutex<DocumentManager>>>,
+    /// Automergeデータディレクトリ（Automergeが無効な場合はNone）
+    pub(super) automerge_path: Option<PathBuf>,
+    /// Automergeドキュメントの保存時暗号化（Noneの場合は平文）
+    pub(super) document_cipher: Option<Arc<dyn DocumentCipher>>,
+    /// セルフホストサーバーのクライアント（Web機能が無効な場合はNone）
+    pub(super) web_client: Option<WebClient>,
 }
 
 impl UnifiedManager {
@@ -36,11 +54,24 @@ impl UnifiedManager {
             sqlite_repositories: None,
             automerge_repositories: None,
             shared_document_manager: None,
+            automerge_path: None,
+            document_cipher: None,
+            web_client: None,
         }
     }
 
     /// 設定から新しいUnifiedManagerを作成
     pub async fn from_config(config: UnifiedConfig) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_config_with_cipher(config, None).await
+    }
+
+    /// 設定とAutomerge暗号化を指定して新しいUnifiedManagerを作成
+    ///
+    /// `document_cipher` を指定した場合、既存の平文ドキュメントは初期化時に暗号化形式へ移行される。
+    pub async fn from_config_with_cipher(
+        config: UnifiedConfig,
+        document_cipher: Option<Arc<dyn DocumentCipher>>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         config.validate()?;
 
         let mut manager = Self {
@@ -48,6 +79,9 @@ impl UnifiedManager {
             sqlite_repositories: None,
             automerge_repositories: None,
             shared_document_manager: None,
+            automerge_path: None,
+            document_cipher,
+            web_client: None,
         };
 
         manager.initialize_backends().await?;
@@ -89,8 +123,15 @@ impl UnifiedManager {
                     .map_err(|e| format!("Failed to create Automerge directory: {}", e))?;
             }
 
-            let document_manager = DocumentManager::new(base_path.clone())?;
+            // 平文で残っているドキュメントはDocumentManagerの作成時に暗号化形式へ移行される
+            let document_manager = match &self.document_cipher {
+                Some(cipher) => {
+                    DocumentManager::new_with_cipher(base_path.clone(), cipher.clone())?
+                }
+                None => DocumentManager::new(base_path.clone())?,
+            };
             self.shared_document_manager = Some(Arc::new(Mutex::new(document_manager)));
+            self.automerge_path = Some(base_path.clone());
 
             // 共有DocumentManagerを使用してAutomergeリポジトリを初期化
             let automerge_repos = flequit_infrastructure_automerge::LocalAutomergeRepositories::setup_with_shared_manager(
@@ -105,9 +146,46 @@ impl UnifiedManager {
         } else {
             self.automerge_repositories = None;
             self.shared_document_manager = None;
+            self.automerge_path = None;
             tracing::info!("Automergeリポジトリを無効にしました");
         }
 
+        // セルフホストサーバーのクライアントの初期化
+        match &self.config.web_server {
+            Some(web_server) if self.config.web_enabled() => {
+                let mut web_client = WebClient::new(web_server)?;
+                // SQLiteが有効な場合は、オフライン中の書き込みをアウトボックスに記録する
+                if let Some(sqlite_repos) = &self.sqlite_repositories {
+                    let outbox = sqlite_repos.read().await.outbox().clone();
+                    web_client = web_client.with_outbox(WebOutbox::new(outbox));
+                }
+                self.web_client = Some(web_client);
+                tracing::info!("Webリポジトリを初期化しました: {}", web_server.base_url);
+            }
+            _ => {
+                self.web_client = None;
+                tracing::info!("Webリポジトリを無効にしました");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// バックエンドを停止する
+    ///
+    /// Automerge Repoを停止してドキュメントを書き出し、SQLiteの接続プールを閉じる。
+    /// 停止後のマネージャーは使用できないため、データファイルを差し替える前に呼び出して
+    /// 新しいマネージャーに置き換える。
+    pub async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.automerge_repositories = None;
+        if let Some(document_manager) = self.shared_document_manager.take() {
+            document_manager.lock().await.shutdown().await?;
+        }
+
+        if let Some(sqlite_repos) = &self.sqlite_repositories {
+            let db_manager = sqlite_repos.read().await.database_manager().clone();
+            db_manager.write().await.close_connection().await?;
+        }
         Ok(())
     }
 
@@ -116,11 +194,75 @@ impl UnifiedManager {
         &self.config
     }
 
+    /// Automergeドキュメントの保存時暗号化が有効かどうか
+    pub fn is_document_encryption_enabled(&self) -> bool {
+        self.document_cipher.is_some()
+    }
+
+    /// Automergeドキュメントの暗号化に使用している暗号
+    pub fn document_cipher(&self) -> Option<Arc<dyn DocumentCipher>> {
+        self.document_cipher.clone()
+    }
+
+    /// 使用中のAutomergeデータディレクトリ
+    pub fn automerge_path(&self) -> Option<&Path> {
+        self.automerge_path.as_deref()
+    }
+
+    /// 共有DocumentManagerへのアクセス（内部用）
+    pub(crate) fn document_manager(&self) -> Option<&Arc<Mutex<DocumentManager>>> {
+        self.shared_document_manager.as_ref()
+    }
+
+    /// 各UnifiedRepositoryのSQLiteリポジトリが使用するDatabaseManager
+    ///
+    /// SQLiteリポジトリを初期化済みの場合はそれと同じDatabaseManagerを、未初期化の場合は共有インスタンスを返す。
+    pub(super) async fn database_manager(
+        &self,
+    ) -> Result<Arc<RwLock<DatabaseManager>>, SQLiteError> {
+        match &self.sqlite_repositories {
+            Some(sqlite_repos) => Ok(sqlite_repos.read().await.database_manager().clone()),
+            None => DatabaseManager::instance().await,
+        }
+    }
+
+    /// 指定したSQLiteデータベースとAutomergeデータディレクトリを使うUnifiedManagerを作成（テスト用）
+    #[cfg(test)]
+    pub(crate) async fn for_test(
+        db_manager: Arc<RwLock<DatabaseManager>>,
+        automerge_path: PathBuf,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        std::fs::create_dir_all(&automerge_path)?;
+        let document_manager = Arc::new(Mutex::new(DocumentManager::new(automerge_path.clone())?));
+        let automerge_repos =
+            LocalAutomergeRepositories::setup_with_shared_manager(document_manager.clone()).await?;
+        Ok(Self {
+            config: UnifiedConfig {
+                sqlite_search_enabled: true,
+                sqlite_storage_enabled: true,
+                automerge_storage_enabled: true,
+                ..UnifiedConfig::default()
+            },
+            sqlite_repositories: Some(Arc::new(RwLock::new(
+                LocalSqliteRepositories::with_database_manager(db_manager),
+            ))),
+            automerge_repositories: Some(Arc::new(RwLock::new(automerge_repos))),
+            shared_document_manager: Some(document_manager),
+            automerge_path: Some(automerge_path),
+            ..Self::new()
+        })
+    }
+
     /// SQLiteリポジトリへのアクセス（内部用）
     pub(crate) fn sqlite_repositories(&self) -> Option<&Arc<RwLock<LocalSqliteRepositories>>> {
         self.sqlite_repositories.as_ref()
     }
 
+    /// セルフホストサーバーのクライアント
+    pub fn web_client(&self) -> Option<&WebClient> {
+        self.web_client.as_ref()
+    }
+
     /// Automergeリポジトリへのアクセス
     pub fn automerge_repositories(&self) -> Option<&Arc<RwLock<LocalAutomergeRepositories>>> {
         self.automerge_repositories.as_ref()
@@ -135,7 +277,7 @@ impl Default for UnifiedManager {
 
 /// デフォルトのAutomergeデータディレクトリパスを取得
 /// SQLiteと同じディレクトリ構造を使用: ~/.local/share/flequit/automerge/
-pub(super) fn get_default_automerge_path() -> Option<std::path::PathBuf> {
+pub fn get_default_automerge_path() -> Option<std::path::PathBuf> {
     use std::env;
 
     // 環境変数からAutomergeパスを取得
@@ -162,6 +304,7 @@ mod tests {
         let manager = UnifiedManager::new();
         assert!(manager.sqlite_repositories.is_none());
         assert!(manager.automerge_repositories.is_none());
+        assert!(manager.web_client.is_none());
     }
 
     #[tokio::test]
@@ -4,11 +4,11 @@
 
 use super::{UnifiedManager, get_default_automerge_path};
 use crate::unified::{AccountUnifiedRepository, ProjectUnifiedRepository, UserUnifiedRepository};
+use crate::web::{AccountWebRepository, ProjectWebRepository, UserWebRepository};
 use flequit_infrastructure_automerge::infrastructure::accounts::account::AccountLocalAutomergeRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::project::ProjectLocalAutomergeRepository;
 use flequit_infrastructure_automerge::infrastructure::users::user::UserLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::accounts::account::AccountLocalSqliteRepository;
-use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::project::ProjectLocalSqliteRepository;
 use flequit_infrastructure_sqlite::infrastructure::users::user::UserLocalSqliteRepository;
 
@@ -22,7 +22,7 @@ impl UnifiedManager {
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
             // DatabaseManagerを取得して新しいProjectLocalSqliteRepositoryを作成
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             // 検索にSQLiteリポジトリを追加
             if self.config.sqlite_search_enabled {
@@ -55,6 +55,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(ProjectWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(ProjectWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました");
+            }
+        }
+
         tracing::info!(
             "ProjectUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
             repo.save_repositories_count(),
@@ -73,7 +86,7 @@ impl UnifiedManager {
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
             // DatabaseManagerを取得して新しいAccountLocalSqliteRepositoryを作成
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             // 検索にSQLiteリポジトリを追加
             if self.config.sqlite_search_enabled {
@@ -106,6 +119,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（Account）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(AccountWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（Account）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(AccountWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（Account）");
+            }
+        }
+
         tracing::info!("AccountUnifiedRepository構築完了");
 
         Ok(repo)
@@ -119,7 +145,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             // 検索にSQLiteリポジトリを追加
             if self.config.sqlite_search_enabled {
@@ -150,6 +176,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（User）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(UserWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（User）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(UserWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（User）");
+            }
+        }
+
         tracing::info!(
             "UserUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
             repo.save_repositories().len(),
@@ -7,12 +7,14 @@ use crate::unified::{
     RecurrenceRuleUnifiedRepository, SubTaskRecurrenceUnifiedRepository,
     TaskRecurrenceUnifiedRepository,
 };
+use crate::web::{
+    RecurrenceRuleWebRepository, SubTaskRecurrenceWebRepository, TaskRecurrenceWebRepository,
+};
 use flequit_infrastructure_automerge::infrastructure::task_projects::{
     recurrence_rule::RecurrenceRuleLocalAutomergeRepository,
     subtask_recurrence::SubtaskRecurrenceLocalAutomergeRepository,
     task_recurrence::TaskRecurrenceLocalAutomergeRepository,
 };
-use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::{
     recurrence_rule::RecurrenceRuleLocalSqliteRepository,
     subtask_recurrence::SubtaskRecurrenceLocalSqliteRepository,
@@ -28,7 +30,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             if self.config.sqlite_search_enabled {
                 let sqlite_repo = RecurrenceRuleLocalSqliteRepository::new(db_manager.clone());
@@ -58,6 +60,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（RecurrenceRule）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(RecurrenceRuleWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（RecurrenceRule）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(RecurrenceRuleWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（RecurrenceRule）");
+            }
+        }
+
         tracing::info!(
             "RecurrenceRuleUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
             repo.save_repositories_count(),
@@ -75,7 +90,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             if self.config.sqlite_search_enabled {
                 let sqlite_repo = TaskRecurrenceLocalSqliteRepository::new(db_manager.clone());
@@ -105,6 +120,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（TaskRecurrence）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(TaskRecurrenceWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（TaskRecurrence）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(TaskRecurrenceWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（TaskRecurrence）");
+            }
+        }
+
         tracing::info!(
             "TaskRecurrenceUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
             repo.save_repositories_count(),
@@ -122,7 +150,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             if self.config.sqlite_search_enabled {
                 let sqlite_repo = SubtaskRecurrenceLocalSqliteRepository::new(db_manager.clone());
@@ -152,6 +180,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（SubTaskRecurrence）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(SubTaskRecurrenceWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（SubTaskRecurrence）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(SubTaskRecurrenceWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（SubTaskRecurrence）");
+            }
+        }
+
         tracing::info!(
             "SubTaskRecurrenceUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
             repo.save_repositories_count(),
@@ -0,0 +1,70 @@
+//! リマインダー用UnifiedRepositoryビルダー
+//!
+//! Reminder エンティティのUnifiedRepositoryを構築するメソッドを提供する
+
+use super::{UnifiedManager, get_default_automerge_path};
+use crate::unified::ReminderUnifiedRepository;
+use crate::web::ReminderWebRepository;
+use flequit_infrastructure_automerge::infrastructure::task_projects::reminder::ReminderLocalAutomergeRepository;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::reminder::ReminderLocalSqliteRepository;
+
+impl UnifiedManager {
+    /// Reminder用UnifiedRepositoryを構築
+    pub async fn create_reminder_unified_repository(
+        &self,
+    ) -> Result<ReminderUnifiedRepository, Box<dyn std::error::Error>> {
+        let mut repo = ReminderUnifiedRepository::default();
+
+        // SQLiteリポジトリの設定
+        if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
+            let db_manager = self.database_manager().await?;
+
+            if self.config.sqlite_search_enabled {
+                let sqlite_repo = ReminderLocalSqliteRepository::new(db_manager.clone());
+                repo.add_sqlite_for_search(sqlite_repo);
+                tracing::info!("SQLiteリポジトリを検索用に追加しました（Reminder）");
+            }
+
+            if self.config.sqlite_storage_enabled {
+                let sqlite_repo = ReminderLocalSqliteRepository::new(db_manager.clone());
+                repo.add_sqlite_for_save(sqlite_repo);
+                tracing::info!("SQLiteリポジトリを保存用に追加しました（Reminder）");
+            }
+        }
+
+        // Automergeリポジトリの設定
+        if self.config.automerge_storage_enabled {
+            let automerge_repo = if let Some(doc_manager) = &self.shared_document_manager {
+                ReminderLocalAutomergeRepository::new_with_manager(doc_manager.clone()).await?
+            } else {
+                let base_path =
+                    get_default_automerge_path().ok_or("Failed to get default Automerge path")?;
+                ReminderLocalAutomergeRepository::new(base_path).await?
+            };
+
+            repo.add_automerge_for_save(automerge_repo);
+            tracing::info!("Automergeリポジトリを保存用に追加しました（Reminder）");
+        }
+
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(ReminderWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（Reminder）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(ReminderWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（Reminder）");
+            }
+        }
+
+        tracing::info!(
+            "ReminderUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
+            repo.save_repositories_count(),
+            repo.search_repositories_count()
+        );
+
+        Ok(repo)
+    }
+}
@@ -4,11 +4,11 @@
 
 use super::{UnifiedManager, get_default_automerge_path};
 use crate::unified::{SubTaskTagUnifiedRepository, TagUnifiedRepository, TaskTagUnifiedRepository};
+use crate::web::{SubTaskTagWebRepository, TagWebRepository, TaskTagWebRepository};
 use flequit_infrastructure_automerge::infrastructure::task_projects::{
     subtask_tag::SubtaskTagLocalAutomergeRepository, tag::TagLocalAutomergeRepository,
     task_tag::TaskTagLocalAutomergeRepository,
 };
-use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::{
     subtask_tag::SubtaskTagLocalSqliteRepository, tag::TagLocalSqliteRepository,
     task_tag::TaskTagLocalSqliteRepository,
@@ -23,7 +23,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             if self.config.sqlite_search_enabled {
                 let sqlite_repo = TagLocalSqliteRepository::new(db_manager.clone());
@@ -52,6 +52,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（Tag）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(TagWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（Tag）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(TagWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（Tag）");
+            }
+        }
+
         tracing::info!(
             "TagUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
             repo.save_repositories_count(),
@@ -69,7 +82,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             // 検索にSQLiteリポジトリを追加
             if self.config.sqlite_search_enabled {
@@ -100,6 +113,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（TaskTag）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(TaskTagWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（TaskTag）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(TaskTagWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（TaskTag）");
+            }
+        }
+
         tracing::info!(
             "TaskTagUnifiedRepository構築完了 - 保存用: {} 検索用: 1 リポジトリ",
             repo.save_repositories_count()
@@ -116,7 +142,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             if self.config.sqlite_search_enabled {
                 let sqlite_repo = SubtaskTagLocalSqliteRepository::new(db_manager.clone());
@@ -145,6 +171,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（SubTaskTag）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(SubTaskTagWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（SubTaskTag）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(SubTaskTagWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（SubTaskTag）");
+            }
+        }
+
         tracing::info!(
             "SubTaskTagUnifiedRepository構築完了 - 保存用: {} 検索用: 1 リポジトリ",
             repo.save_repositories_count()
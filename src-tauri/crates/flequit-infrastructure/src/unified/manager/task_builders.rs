@@ -4,11 +4,11 @@
 
 use super::{UnifiedManager, get_default_automerge_path};
 use crate::unified::{SubTaskUnifiedRepository, TaskListUnifiedRepository, TaskUnifiedRepository};
+use crate::web::{SubTaskWebRepository, TaskListWebRepository, TaskWebRepository};
 use flequit_infrastructure_automerge::infrastructure::task_projects::{
     subtask::SubTaskLocalAutomergeRepository, task::TaskLocalAutomergeRepository,
     task_list::TaskListLocalAutomergeRepository,
 };
-use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::{
     subtask::SubTaskLocalSqliteRepository, task::TaskLocalSqliteRepository,
     task_list::TaskListLocalSqliteRepository,
@@ -23,7 +23,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             // 検索にSQLiteリポジトリを追加
             if self.config.sqlite_search_enabled {
@@ -54,6 +54,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（Task）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(TaskWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（Task）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(TaskWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（Task）");
+            }
+        }
+
         tracing::info!(
             "TaskUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
             repo.save_repositories_count(),
@@ -71,7 +84,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             // 検索にSQLiteリポジトリを追加
             if self.config.sqlite_search_enabled {
@@ -102,6 +115,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（TaskList）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(TaskListWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（TaskList）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(TaskListWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（TaskList）");
+            }
+        }
+
         tracing::info!(
             "TaskListUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
             repo.save_repositories_count(),
@@ -119,7 +145,7 @@ impl UnifiedManager {
 
         // SQLiteリポジトリの設定
         if self.config.sqlite_search_enabled || self.config.sqlite_storage_enabled {
-            let db_manager = DatabaseManager::instance().await?;
+            let db_manager = self.database_manager().await?;
 
             if self.config.sqlite_search_enabled {
                 let sqlite_repo = SubTaskLocalSqliteRepository::new(db_manager.clone());
@@ -148,6 +174,19 @@ impl UnifiedManager {
             tracing::info!("Automergeリポジトリを保存用に追加しました（SubTask）");
         }
 
+        // Webリポジトリの設定
+        if let Some(web_client) = &self.web_client {
+            if self.config.web_search_enabled {
+                repo.add_web_for_search(SubTaskWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを検索用に追加しました（SubTask）");
+            }
+
+            if self.config.web_storage_enabled {
+                repo.add_web_for_save(SubTaskWebRepository::new(web_client.clone()));
+                tracing::info!("Webリポジトリを保存用に追加しました（SubTask）");
+            }
+        }
+
         tracing::info!(
             "SubTaskUnifiedRepository構築完了 - 保存用: {} 検索用: {} リポジトリ",
             repo.save_repositories_count(),
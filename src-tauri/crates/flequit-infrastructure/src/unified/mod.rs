@@ -29,10 +29,13 @@ pub use manager::UnifiedManager;
 // 公開エクスポート（既存の互換性維持）
 pub use accounts::AccountUnifiedRepository;
 pub use task_projects::{
-    ProjectUnifiedRepository, RecurrenceRuleUnifiedRepository, SubTaskAssignmentUnifiedRepository,
-    SubTaskRecurrenceUnifiedRepository, SubTaskTagUnifiedRepository, SubTaskUnifiedRepository,
-    TagUnifiedRepository, TaskAssignmentUnifiedRepository, TaskListUnifiedRepository,
+    CommentUnifiedRepository, ProjectUnifiedRepository, RecurrenceRuleUnifiedRepository,
+    ReminderUnifiedRepository, SnoozeUnifiedRepository, StatusTransitionRuleUnifiedRepository,
+    SubTaskAssignmentUnifiedRepository, SubTaskRecurrenceUnifiedRepository,
+    SubTaskTagUnifiedRepository, SubTaskUnifiedRepository, TagUnifiedRepository,
+    TaskAssignmentUnifiedRepository, TaskDependencyUnifiedRepository, TaskListUnifiedRepository,
     TaskRecurrenceUnifiedRepository, TaskTagUnifiedRepository, TaskUnifiedRepository,
+    TimeEntryUnifiedRepository, WorkflowStatusUnifiedRepository,
 };
 pub use users::UserUnifiedRepository;
 
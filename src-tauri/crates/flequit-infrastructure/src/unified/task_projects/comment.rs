@@ -0,0 +1,246 @@
+//! コメント用統合リポジトリ
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::info;
+
+use crate::web::CommentWebRepository;
+use flequit_infrastructure_automerge::infrastructure::task_projects::comment::CommentLocalAutomergeRepository;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::comment::CommentLocalSqliteRepository;
+use flequit_model::models::task_projects::comment::Comment;
+use flequit_model::types::id_types::{CommentId, ProjectId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::comment_repository_trait::CommentRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+
+#[derive(Debug)]
+pub enum CommentRepositoryVariant {
+    LocalSqlite(CommentLocalSqliteRepository),
+    LocalAutomerge(CommentLocalAutomergeRepository),
+    Web(CommentWebRepository),
+}
+
+impl CommentRepositoryTrait for CommentRepositoryVariant {}
+
+#[async_trait]
+impl ProjectRepository<Comment, CommentId> for CommentRepositoryVariant {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &Comment,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &CommentId,
+    ) -> Result<Option<Comment>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
+        }
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Comment>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_all(project_id).await,
+            Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
+        }
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &CommentId) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
+        }
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &CommentId,
+    ) -> Result<bool, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
+        }
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.count(project_id).await,
+            Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CommentUnifiedRepository {
+    save_repositories: Vec<CommentRepositoryVariant>,
+    search_repositories: Vec<CommentRepositoryVariant>,
+}
+
+impl Default for CommentUnifiedRepository {
+    fn default() -> Self {
+        Self::new(vec![], vec![])
+    }
+}
+
+impl CommentUnifiedRepository {
+    pub fn new(
+        save_repositories: Vec<CommentRepositoryVariant>,
+        search_repositories: Vec<CommentRepositoryVariant>,
+    ) -> Self {
+        Self {
+            save_repositories,
+            search_repositories,
+        }
+    }
+
+    pub fn add_sqlite_for_save(&mut self, sqlite_repo: CommentLocalSqliteRepository) {
+        self.save_repositories
+            .push(CommentRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_save(&mut self, automerge_repo: CommentLocalAutomergeRepository) {
+        self.save_repositories
+            .push(CommentRepositoryVariant::LocalAutomerge(automerge_repo));
+    }
+
+    pub fn add_sqlite_for_search(&mut self, sqlite_repo: CommentLocalSqliteRepository) {
+        self.search_repositories
+            .push(CommentRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_search(&mut self, automerge_repo: CommentLocalAutomergeRepository) {
+        self.search_repositories
+            .push(CommentRepositoryVariant::LocalAutomerge(automerge_repo));
+    }
+
+    pub fn add_web_for_save(&mut self, web_repo: CommentWebRepository) {
+        self.save_repositories
+            .push(CommentRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn add_web_for_search(&mut self, web_repo: CommentWebRepository) {
+        self.search_repositories
+            .push(CommentRepositoryVariant::Web(web_repo));
+    }
+
+    /// 保存用リポジトリの数を取得
+    pub fn save_repositories_count(&self) -> usize {
+        self.save_repositories.len()
+    }
+
+    /// 検索用リポジトリの数を取得
+    pub fn search_repositories_count(&self) -> usize {
+        self.search_repositories.len()
+    }
+}
+
+impl CommentRepositoryTrait for CommentUnifiedRepository {}
+
+#[async_trait]
+impl ProjectRepository<Comment, CommentId> for CommentUnifiedRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &Comment,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Saving comment with ID: {} in project: {}",
+            entity.id, project_id
+        );
+
+        for repository in &self.save_repositories {
+            repository
+                .save(project_id, entity, user_id, timestamp)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &CommentId,
+    ) -> Result<Option<Comment>, RepositoryError> {
+        info!("Finding comment by ID: {} in project: {}", id, project_id);
+
+        for repository in &self.search_repositories {
+            if let Some(entity) = repository.find_by_id(project_id, id).await? {
+                return Ok(Some(entity));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Comment>, RepositoryError> {
+        info!("Finding all comments in project: {}", project_id);
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.find_all(project_id).await
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &CommentId) -> Result<(), RepositoryError> {
+        info!(
+            "Deleting comment with ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.save_repositories {
+            repository.delete(project_id, id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &CommentId,
+    ) -> Result<bool, RepositoryError> {
+        info!(
+            "Checking if comment exists with ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.search_repositories {
+            if repository.exists(project_id, id).await? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        info!("Counting comments in project: {}", project_id);
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.count(project_id).await
+        } else {
+            Ok(0)
+        }
+    }
+}
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::info;
 
+use crate::web::MemberWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::member::MemberLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::member::MemberLocalSqliteRepository;
 use flequit_model::models::task_projects::member::Member;
@@ -15,6 +16,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum MemberRepositoryVariant {
     LocalSqlite(MemberLocalSqliteRepository),
     LocalAutomerge(MemberLocalAutomergeRepository),
+    Web(MemberWebRepository),
 }
 
 #[async_trait]
@@ -29,6 +31,7 @@ impl ProjectRepository<Member, UserId> for MemberRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
             Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
         }
     }
 
@@ -40,6 +43,7 @@ impl ProjectRepository<Member, UserId> for MemberRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
         }
     }
 
@@ -47,6 +51,7 @@ impl ProjectRepository<Member, UserId> for MemberRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_all(project_id).await,
             Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
         }
     }
 
@@ -54,6 +59,7 @@ impl ProjectRepository<Member, UserId> for MemberRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
         }
     }
 
@@ -61,6 +67,7 @@ impl ProjectRepository<Member, UserId> for MemberRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
         }
     }
 
@@ -68,6 +75,7 @@ impl ProjectRepository<Member, UserId> for MemberRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.count(project_id).await,
             Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
         }
     }
 }
@@ -114,6 +122,16 @@ impl MemberUnifiedRepository {
         self.search_repositories
             .push(MemberRepositoryVariant::LocalAutomerge(automerge_repo));
     }
+
+    pub fn add_web_for_save(&mut self, web_repo: MemberWebRepository) {
+        self.save_repositories
+            .push(MemberRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn add_web_for_search(&mut self, web_repo: MemberWebRepository) {
+        self.search_repositories
+            .push(MemberRepositoryVariant::Web(web_repo));
+    }
 }
 
 #[async_trait]
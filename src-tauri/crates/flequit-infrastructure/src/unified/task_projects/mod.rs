@@ -4,12 +4,18 @@
 //! タスク管理の核心機能を提供する統合リポジトリ群。
 
 // 基本エンティティ
+pub mod comment;
 pub mod member;
 pub mod project;
+pub mod reminder;
+pub mod snooze;
+pub mod status_transition_rule;
 pub mod subtask;
 pub mod tag;
 pub mod task;
 pub mod task_list;
+pub mod time_entry;
+pub mod workflow_status;
 
 // 関連テーブル
 pub mod recurrence_rule;
@@ -17,12 +23,17 @@ pub mod subtask_assignments;
 pub mod subtask_recurrence;
 pub mod subtask_tag;
 pub mod task_assignments;
+pub mod task_dependency;
 pub mod task_recurrence;
 pub mod task_tag;
 
 // 公開エクスポート
+pub use comment::CommentUnifiedRepository;
 pub use project::ProjectUnifiedRepository;
 pub use recurrence_rule::RecurrenceRuleUnifiedRepository;
+pub use reminder::ReminderUnifiedRepository;
+pub use snooze::SnoozeUnifiedRepository;
+pub use status_transition_rule::StatusTransitionRuleUnifiedRepository;
 pub use subtask::SubTaskUnifiedRepository;
 pub use subtask_assignments::SubTaskAssignmentUnifiedRepository;
 pub use subtask_recurrence::SubTaskRecurrenceUnifiedRepository;
@@ -30,6 +41,9 @@ pub use subtask_tag::SubTaskTagUnifiedRepository;
 pub use tag::TagUnifiedRepository;
 pub use task::TaskUnifiedRepository;
 pub use task_assignments::TaskAssignmentUnifiedRepository;
+pub use task_dependency::TaskDependencyUnifiedRepository;
 pub use task_list::TaskListUnifiedRepository;
 pub use task_recurrence::TaskRecurrenceUnifiedRepository;
 pub use task_tag::TaskTagUnifiedRepository;
+pub use time_entry::TimeEntryUnifiedRepository;
+pub use workflow_status::WorkflowStatusUnifiedRepository;
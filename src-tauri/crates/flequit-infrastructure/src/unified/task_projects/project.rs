@@ -5,6 +5,7 @@ use chrono::{DateTime, Utc};
 use flequit_repository::patchable_trait::Patchable;
 use tracing::info;
 
+use crate::web::ProjectWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::project::ProjectLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::project::ProjectLocalSqliteRepository;
 use flequit_model::models::task_projects::project::Project;
@@ -17,6 +18,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum ProjectRepositoryVariant {
     LocalSqlite(ProjectLocalSqliteRepository),
     LocalAutomerge(ProjectLocalAutomergeRepository),
+    Web(ProjectWebRepository),
 }
 
 impl ProjectRepositoryTrait for ProjectRepositoryVariant {}
@@ -32,6 +34,7 @@ impl Repository<Project, ProjectId> for ProjectRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.save(entity, user_id, timestamp).await,
             Self::LocalAutomerge(repo) => repo.save(entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(entity, user_id, timestamp).await,
         }
     }
 
@@ -39,6 +42,7 @@ impl Repository<Project, ProjectId> for ProjectRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_by_id(id).await,
             Self::LocalAutomerge(repo) => repo.find_by_id(id).await,
+            Self::Web(repo) => repo.find_by_id(id).await,
         }
     }
 
@@ -46,6 +50,7 @@ impl Repository<Project, ProjectId> for ProjectRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_all().await,
             Self::LocalAutomerge(repo) => repo.find_all().await,
+            Self::Web(repo) => repo.find_all().await,
         }
     }
 
@@ -53,6 +58,7 @@ impl Repository<Project, ProjectId> for ProjectRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.delete(id).await,
             Self::LocalAutomerge(repo) => repo.delete(id).await,
+            Self::Web(repo) => repo.delete(id).await,
         }
     }
 
@@ -60,6 +66,7 @@ impl Repository<Project, ProjectId> for ProjectRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.exists(id).await,
             Self::LocalAutomerge(repo) => repo.exists(id).await,
+            Self::Web(repo) => repo.exists(id).await,
         }
     }
 
@@ -67,6 +74,7 @@ impl Repository<Project, ProjectId> for ProjectRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.count().await,
             Self::LocalAutomerge(repo) => repo.count().await,
+            Self::Web(repo) => repo.count().await,
         }
     }
 }
@@ -114,14 +122,14 @@ impl ProjectUnifiedRepository {
             .push(ProjectRepositoryVariant::LocalAutomerge(automerge_repo));
     }
 
-    pub fn add_web_for_save(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.save_repositories.push(ProjectRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_save(&mut self, web_repo: ProjectWebRepository) {
+        self.save_repositories
+            .push(ProjectRepositoryVariant::Web(web_repo));
     }
 
-    pub fn add_web_for_search(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.search_repositories.push(ProjectRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_search(&mut self, web_repo: ProjectWebRepository) {
+        self.search_repositories
+            .push(ProjectRepositoryVariant::Web(web_repo));
     }
 
     /// 保存用リポジトリの数を取得
@@ -5,6 +5,7 @@ use chrono::{DateTime, Utc};
 use tracing::info;
 
 // RecurrenceRuleリポジトリ実装をインポート
+use crate::web::RecurrenceRuleWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::recurrence_rule::RecurrenceRuleLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::recurrence_rule::RecurrenceRuleLocalSqliteRepository;
 use flequit_model::{
@@ -20,6 +21,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum RecurrenceRuleRepositoryVariant {
     LocalSqlite(RecurrenceRuleLocalSqliteRepository),
     LocalAutomerge(RecurrenceRuleLocalAutomergeRepository),
+    Web(RecurrenceRuleWebRepository),
 }
 
 impl RecurrenceRuleRepositoryTrait for RecurrenceRuleRepositoryVariant {}
@@ -38,6 +40,7 @@ impl ProjectRepository<RecurrenceRule, RecurrenceRuleId> for RecurrenceRuleRepos
         match self {
             Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
             Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
         }
     }
     async fn find_by_id(
@@ -48,6 +51,7 @@ impl ProjectRepository<RecurrenceRule, RecurrenceRuleId> for RecurrenceRuleRepos
         match self {
             Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
         }
     }
 
@@ -58,6 +62,7 @@ impl ProjectRepository<RecurrenceRule, RecurrenceRuleId> for RecurrenceRuleRepos
         match self {
             Self::LocalSqlite(repo) => repo.find_all(project_id).await,
             Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
         }
     }
 
@@ -69,6 +74,7 @@ impl ProjectRepository<RecurrenceRule, RecurrenceRuleId> for RecurrenceRuleRepos
         match self {
             Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
         }
     }
 
@@ -80,6 +86,7 @@ impl ProjectRepository<RecurrenceRule, RecurrenceRuleId> for RecurrenceRuleRepos
         match self {
             Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
         }
     }
 
@@ -87,6 +94,7 @@ impl ProjectRepository<RecurrenceRule, RecurrenceRuleId> for RecurrenceRuleRepos
         match self {
             Self::LocalSqlite(repo) => repo.count(project_id).await,
             Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
         }
     }
 }
@@ -165,6 +173,18 @@ impl RecurrenceRuleUnifiedRepository {
             ));
     }
 
+    /// Webリポジトリを保存用に追加
+    pub fn add_web_for_save(&mut self, web_repo: RecurrenceRuleWebRepository) {
+        self.save_repositories
+            .push(RecurrenceRuleRepositoryVariant::Web(web_repo));
+    }
+
+    /// Webリポジトリを検索用に追加
+    pub fn add_web_for_search(&mut self, web_repo: RecurrenceRuleWebRepository) {
+        self.search_repositories
+            .push(RecurrenceRuleRepositoryVariant::Web(web_repo));
+    }
+
     /// 保存用リポジトリの数を取得
     pub fn save_repositories_count(&self) -> usize {
         self.save_repositories.len()
@@ -0,0 +1,246 @@
+//! リマインダー用統合リポジトリ
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::info;
+
+use crate::web::ReminderWebRepository;
+use flequit_infrastructure_automerge::infrastructure::task_projects::reminder::ReminderLocalAutomergeRepository;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::reminder::ReminderLocalSqliteRepository;
+use flequit_model::models::task_projects::reminder::Reminder;
+use flequit_model::types::id_types::{ProjectId, ReminderId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::reminder_repository_trait::ReminderRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+
+#[derive(Debug)]
+pub enum ReminderRepositoryVariant {
+    LocalSqlite(ReminderLocalSqliteRepository),
+    LocalAutomerge(ReminderLocalAutomergeRepository),
+    Web(ReminderWebRepository),
+}
+
+impl ReminderRepositoryTrait for ReminderRepositoryVariant {}
+
+#[async_trait]
+impl ProjectRepository<Reminder, ReminderId> for ReminderRepositoryVariant {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &Reminder,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &ReminderId,
+    ) -> Result<Option<Reminder>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
+        }
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Reminder>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_all(project_id).await,
+            Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
+        }
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &ReminderId) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
+        }
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &ReminderId,
+    ) -> Result<bool, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
+        }
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.count(project_id).await,
+            Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ReminderUnifiedRepository {
+    save_repositories: Vec<ReminderRepositoryVariant>,
+    search_repositories: Vec<ReminderRepositoryVariant>,
+}
+
+impl Default for ReminderUnifiedRepository {
+    fn default() -> Self {
+        Self::new(vec![], vec![])
+    }
+}
+
+impl ReminderUnifiedRepository {
+    pub fn new(
+        save_repositories: Vec<ReminderRepositoryVariant>,
+        search_repositories: Vec<ReminderRepositoryVariant>,
+    ) -> Self {
+        Self {
+            save_repositories,
+            search_repositories,
+        }
+    }
+
+    pub fn add_sqlite_for_save(&mut self, sqlite_repo: ReminderLocalSqliteRepository) {
+        self.save_repositories
+            .push(ReminderRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_save(&mut self, automerge_repo: ReminderLocalAutomergeRepository) {
+        self.save_repositories
+            .push(ReminderRepositoryVariant::LocalAutomerge(automerge_repo));
+    }
+
+    pub fn add_sqlite_for_search(&mut self, sqlite_repo: ReminderLocalSqliteRepository) {
+        self.search_repositories
+            .push(ReminderRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_search(&mut self, automerge_repo: ReminderLocalAutomergeRepository) {
+        self.search_repositories
+            .push(ReminderRepositoryVariant::LocalAutomerge(automerge_repo));
+    }
+
+    pub fn add_web_for_save(&mut self, web_repo: ReminderWebRepository) {
+        self.save_repositories
+            .push(ReminderRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn add_web_for_search(&mut self, web_repo: ReminderWebRepository) {
+        self.search_repositories
+            .push(ReminderRepositoryVariant::Web(web_repo));
+    }
+
+    /// 保存用リポジトリの数を取得
+    pub fn save_repositories_count(&self) -> usize {
+        self.save_repositories.len()
+    }
+
+    /// 検索用リポジトリの数を取得
+    pub fn search_repositories_count(&self) -> usize {
+        self.search_repositories.len()
+    }
+}
+
+impl ReminderRepositoryTrait for ReminderUnifiedRepository {}
+
+#[async_trait]
+impl ProjectRepository<Reminder, ReminderId> for ReminderUnifiedRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &Reminder,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Saving reminder with ID: {} in project: {}",
+            entity.id, project_id
+        );
+
+        for repository in &self.save_repositories {
+            repository
+                .save(project_id, entity, user_id, timestamp)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &ReminderId,
+    ) -> Result<Option<Reminder>, RepositoryError> {
+        info!("Finding reminder by ID: {} in project: {}", id, project_id);
+
+        for repository in &self.search_repositories {
+            if let Some(entity) = repository.find_by_id(project_id, id).await? {
+                return Ok(Some(entity));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Reminder>, RepositoryError> {
+        info!("Finding all reminders in project: {}", project_id);
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.find_all(project_id).await
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &ReminderId) -> Result<(), RepositoryError> {
+        info!(
+            "Deleting reminder with ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.save_repositories {
+            repository.delete(project_id, id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &ReminderId,
+    ) -> Result<bool, RepositoryError> {
+        info!(
+            "Checking if reminder exists with ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.search_repositories {
+            if repository.exists(project_id, id).await? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        info!("Counting reminders in project: {}", project_id);
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.count(project_id).await
+        } else {
+            Ok(0)
+        }
+    }
+}
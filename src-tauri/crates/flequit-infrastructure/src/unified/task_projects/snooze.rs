@@ -0,0 +1,235 @@
+//! スヌーズ用統合リポジトリ
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::info;
+
+use crate::web::SnoozeWebRepository;
+use flequit_infrastructure_automerge::infrastructure::task_projects::snooze::SnoozeLocalAutomergeRepository;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::snooze::SnoozeLocalSqliteRepository;
+use flequit_model::models::task_projects::snooze::Snooze;
+use flequit_model::types::id_types::{ProjectId, SnoozeId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::snooze_repository_trait::SnoozeRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+
+#[derive(Debug)]
+pub enum SnoozeRepositoryVariant {
+    LocalSqlite(SnoozeLocalSqliteRepository),
+    LocalAutomerge(SnoozeLocalAutomergeRepository),
+    Web(SnoozeWebRepository),
+}
+
+impl SnoozeRepositoryTrait for SnoozeRepositoryVariant {}
+
+#[async_trait]
+impl ProjectRepository<Snooze, SnoozeId> for SnoozeRepositoryVariant {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &Snooze,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &SnoozeId,
+    ) -> Result<Option<Snooze>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
+        }
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Snooze>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_all(project_id).await,
+            Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
+        }
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &SnoozeId) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
+        }
+    }
+
+    async fn exists(&self, project_id: &ProjectId, id: &SnoozeId) -> Result<bool, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
+        }
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.count(project_id).await,
+            Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SnoozeUnifiedRepository {
+    save_repositories: Vec<SnoozeRepositoryVariant>,
+    search_repositories: Vec<SnoozeRepositoryVariant>,
+}
+
+impl Default for SnoozeUnifiedRepository {
+    fn default() -> Self {
+        Self::new(vec![], vec![])
+    }
+}
+
+impl SnoozeUnifiedRepository {
+    pub fn new(
+        save_repositories: Vec<SnoozeRepositoryVariant>,
+        search_repositories: Vec<SnoozeRepositoryVariant>,
+    ) -> Self {
+        Self {
+            save_repositories,
+            search_repositories,
+        }
+    }
+
+    pub fn add_sqlite_for_save(&mut self, sqlite_repo: SnoozeLocalSqliteRepository) {
+        self.save_repositories
+            .push(SnoozeRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_save(&mut self, automerge_repo: SnoozeLocalAutomergeRepository) {
+        self.save_repositories
+            .push(SnoozeRepositoryVariant::LocalAutomerge(automerge_repo));
+    }
+
+    pub fn add_sqlite_for_search(&mut self, sqlite_repo: SnoozeLocalSqliteRepository) {
+        self.search_repositories
+            .push(SnoozeRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_search(&mut self, automerge_repo: SnoozeLocalAutomergeRepository) {
+        self.search_repositories
+            .push(SnoozeRepositoryVariant::LocalAutomerge(automerge_repo));
+    }
+
+    pub fn add_web_for_save(&mut self, web_repo: SnoozeWebRepository) {
+        self.save_repositories
+            .push(SnoozeRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn add_web_for_search(&mut self, web_repo: SnoozeWebRepository) {
+        self.search_repositories
+            .push(SnoozeRepositoryVariant::Web(web_repo));
+    }
+
+    /// 保存用リポジトリの数を取得
+    pub fn save_repositories_count(&self) -> usize {
+        self.save_repositories.len()
+    }
+
+    /// 検索用リポジトリの数を取得
+    pub fn search_repositories_count(&self) -> usize {
+        self.search_repositories.len()
+    }
+}
+
+impl SnoozeRepositoryTrait for SnoozeUnifiedRepository {}
+
+#[async_trait]
+impl ProjectRepository<Snooze, SnoozeId> for SnoozeUnifiedRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &Snooze,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Saving snooze with ID: {} in project: {}",
+            entity.id, project_id
+        );
+
+        for repository in &self.save_repositories {
+            repository
+                .save(project_id, entity, user_id, timestamp)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &SnoozeId,
+    ) -> Result<Option<Snooze>, RepositoryError> {
+        info!("Finding snooze by ID: {} in project: {}", id, project_id);
+
+        for repository in &self.search_repositories {
+            if let Some(entity) = repository.find_by_id(project_id, id).await? {
+                return Ok(Some(entity));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<Snooze>, RepositoryError> {
+        info!("Finding all snoozes in project: {}", project_id);
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.find_all(project_id).await
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &SnoozeId) -> Result<(), RepositoryError> {
+        info!("Deleting snooze with ID: {} in project: {}", id, project_id);
+
+        for repository in &self.save_repositories {
+            repository.delete(project_id, id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn exists(&self, project_id: &ProjectId, id: &SnoozeId) -> Result<bool, RepositoryError> {
+        info!(
+            "Checking if snooze exists with ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.search_repositories {
+            if repository.exists(project_id, id).await? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        info!("Counting snoozes in project: {}", project_id);
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.count(project_id).await
+        } else {
+            Ok(0)
+        }
+    }
+}
@@ -0,0 +1,290 @@
+//! ステータス遷移ルール用統合リポジトリ
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::info;
+
+use crate::web::StatusTransitionRuleWebRepository;
+use flequit_infrastructure_automerge::infrastructure::task_projects::status_transition_rule::StatusTransitionRuleLocalAutomergeRepository;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::status_transition_rule::StatusTransitionRuleLocalSqliteRepository;
+use flequit_model::models::task_projects::status_transition_rule::StatusTransitionRule;
+use flequit_model::types::id_types::{ProjectId, StatusTransitionRuleId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::status_transition_rule_repository_trait::StatusTransitionRuleRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+
+#[derive(Debug)]
+pub enum StatusTransitionRuleRepositoryVariant {
+    LocalSqlite(StatusTransitionRuleLocalSqliteRepository),
+    LocalAutomerge(StatusTransitionRuleLocalAutomergeRepository),
+    Web(StatusTransitionRuleWebRepository),
+}
+
+impl StatusTransitionRuleRepositoryTrait for StatusTransitionRuleRepositoryVariant {}
+
+#[async_trait]
+impl ProjectRepository<StatusTransitionRule, StatusTransitionRuleId>
+    for StatusTransitionRuleRepositoryVariant
+{
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &StatusTransitionRule,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<Option<StatusTransitionRule>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
+        }
+    }
+
+    async fn find_all(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<StatusTransitionRule>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_all(project_id).await,
+            Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
+        }
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
+        }
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<bool, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
+        }
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.count(project_id).await,
+            Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct StatusTransitionRuleUnifiedRepository {
+    save_repositories: Vec<StatusTransitionRuleRepositoryVariant>,
+    search_repositories: Vec<StatusTransitionRuleRepositoryVariant>,
+}
+
+impl Default for StatusTransitionRuleUnifiedRepository {
+    fn default() -> Self {
+        Self::new(vec![], vec![])
+    }
+}
+
+impl StatusTransitionRuleUnifiedRepository {
+    pub fn new(
+        save_repositories: Vec<StatusTransitionRuleRepositoryVariant>,
+        search_repositories: Vec<StatusTransitionRuleRepositoryVariant>,
+    ) -> Self {
+        Self {
+            save_repositories,
+            search_repositories,
+        }
+    }
+
+    pub fn add_sqlite_for_save(&mut self, sqlite_repo: StatusTransitionRuleLocalSqliteRepository) {
+        self.save_repositories
+            .push(StatusTransitionRuleRepositoryVariant::LocalSqlite(
+                sqlite_repo,
+            ));
+    }
+
+    pub fn add_automerge_for_save(
+        &mut self,
+        automerge_repo: StatusTransitionRuleLocalAutomergeRepository,
+    ) {
+        self.save_repositories
+            .push(StatusTransitionRuleRepositoryVariant::LocalAutomerge(
+                automerge_repo,
+            ));
+    }
+
+    pub fn add_sqlite_for_search(
+        &mut self,
+        sqlite_repo: StatusTransitionRuleLocalSqliteRepository,
+    ) {
+        self.search_repositories
+            .push(StatusTransitionRuleRepositoryVariant::LocalSqlite(
+                sqlite_repo,
+            ));
+    }
+
+    pub fn add_automerge_for_search(
+        &mut self,
+        automerge_repo: StatusTransitionRuleLocalAutomergeRepository,
+    ) {
+        self.search_repositories
+            .push(StatusTransitionRuleRepositoryVariant::LocalAutomerge(
+                automerge_repo,
+            ));
+    }
+
+    pub fn add_web_for_save(&mut self, web_repo: StatusTransitionRuleWebRepository) {
+        self.save_repositories
+            .push(StatusTransitionRuleRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn add_web_for_search(&mut self, web_repo: StatusTransitionRuleWebRepository) {
+        self.search_repositories
+            .push(StatusTransitionRuleRepositoryVariant::Web(web_repo));
+    }
+
+    /// 保存用リポジトリの数を取得
+    pub fn save_repositories_count(&self) -> usize {
+        self.save_repositories.len()
+    }
+
+    /// 検索用リポジトリの数を取得
+    pub fn search_repositories_count(&self) -> usize {
+        self.search_repositories.len()
+    }
+}
+
+impl StatusTransitionRuleRepositoryTrait for StatusTransitionRuleUnifiedRepository {}
+
+#[async_trait]
+impl ProjectRepository<StatusTransitionRule, StatusTransitionRuleId>
+    for StatusTransitionRuleUnifiedRepository
+{
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &StatusTransitionRule,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Saving status transition rule with ID: {} in project: {}",
+            entity.id, project_id
+        );
+
+        for repository in &self.save_repositories {
+            repository
+                .save(project_id, entity, user_id, timestamp)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<Option<StatusTransitionRule>, RepositoryError> {
+        info!(
+            "Finding status transition rule by ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.search_repositories {
+            if let Some(entity) = repository.find_by_id(project_id, id).await? {
+                return Ok(Some(entity));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn find_all(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<StatusTransitionRule>, RepositoryError> {
+        info!(
+            "Finding all status transition rules in project: {}",
+            project_id
+        );
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.find_all(project_id).await
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Deleting status transition rule with ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.save_repositories {
+            repository.delete(project_id, id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &StatusTransitionRuleId,
+    ) -> Result<bool, RepositoryError> {
+        info!(
+            "Checking if status transition rule exists with ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.search_repositories {
+            if repository.exists(project_id, id).await? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        info!(
+            "Counting status transition rules in project: {}",
+            project_id
+        );
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.count(project_id).await
+        } else {
+            Ok(0)
+        }
+    }
+}
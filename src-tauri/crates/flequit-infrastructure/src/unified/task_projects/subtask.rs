@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::info;
 
+use crate::web::SubTaskWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::subtask::SubTaskLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::subtask::SubTaskLocalSqliteRepository;
 use flequit_model::models::task_projects::subtask::SubTask;
@@ -17,6 +18,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum SubTaskRepositoryVariant {
     LocalSqlite(SubTaskLocalSqliteRepository),
     LocalAutomerge(SubTaskLocalAutomergeRepository),
+    Web(SubTaskWebRepository),
 }
 
 impl SubTaskRepositoryTrait for SubTaskRepositoryVariant {}
@@ -33,6 +35,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
             Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
         }
     }
 
@@ -44,6 +47,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
         }
     }
 
@@ -51,6 +55,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_all(project_id).await,
             Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
         }
     }
 
@@ -58,6 +63,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
         }
     }
 
@@ -69,6 +75,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
         }
     }
 
@@ -76,6 +83,7 @@ impl ProjectRepository<SubTask, SubTaskId> for SubTaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.count(project_id).await,
             Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
         }
     }
 }
@@ -123,14 +131,14 @@ impl SubTaskUnifiedRepository {
             .push(SubTaskRepositoryVariant::LocalAutomerge(automerge_repo));
     }
 
-    pub fn add_web_for_save(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.save_repositories.push(SubTaskRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_save(&mut self, web_repo: SubTaskWebRepository) {
+        self.save_repositories
+            .push(SubTaskRepositoryVariant::Web(web_repo));
     }
 
-    pub fn add_web_for_search(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.search_repositories.push(SubTaskRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_search(&mut self, web_repo: SubTaskWebRepository) {
+        self.search_repositories
+            .push(SubTaskRepositoryVariant::Web(web_repo));
     }
 
     /// 保存用リポジトリの数を取得
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::info;
 
+use crate::web::SubTaskRecurrenceWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::subtask_recurrence::SubtaskRecurrenceLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::subtask_recurrence::SubtaskRecurrenceLocalSqliteRepository;
 use flequit_model::models::task_projects::subtask_recurrence::SubTaskRecurrence;
@@ -16,6 +17,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum SubTaskRecurrenceRepositoryVariant {
     LocalSqlite(SubtaskRecurrenceLocalSqliteRepository),
     LocalAutomerge(SubtaskRecurrenceLocalAutomergeRepository),
+    Web(SubTaskRecurrenceWebRepository),
 }
 
 #[async_trait]
@@ -28,6 +30,7 @@ impl SubtaskRecurrenceRepositoryTrait for SubTaskRecurrenceRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_by_subtask_id(subtask_id).await,
             Self::LocalAutomerge(repo) => repo.find_by_subtask_id(subtask_id).await,
+            Self::Web(repo) => repo.find_by_subtask_id(subtask_id).await,
         }
     }
 
@@ -38,6 +41,7 @@ impl SubtaskRecurrenceRepositoryTrait for SubTaskRecurrenceRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_by_recurrence_rule_id(recurrence_rule_id).await,
             Self::LocalAutomerge(repo) => repo.find_by_recurrence_rule_id(recurrence_rule_id).await,
+            Self::Web(repo) => repo.find_by_recurrence_rule_id(recurrence_rule_id).await,
         }
     }
 
@@ -49,6 +53,7 @@ impl SubtaskRecurrenceRepositoryTrait for SubTaskRecurrenceRepositoryVariant {
             Self::LocalAutomerge(repo) => {
                 <_ as SubtaskRecurrenceRepositoryTrait>::find_all(repo).await
             }
+            Self::Web(repo) => <_ as SubtaskRecurrenceRepositoryTrait>::find_all(repo).await,
         }
     }
 
@@ -56,6 +61,7 @@ impl SubtaskRecurrenceRepositoryTrait for SubTaskRecurrenceRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.save(recurrence).await,
             Self::LocalAutomerge(repo) => repo.save(recurrence).await,
+            Self::Web(repo) => repo.save(recurrence).await,
         }
     }
 
@@ -63,6 +69,7 @@ impl SubtaskRecurrenceRepositoryTrait for SubTaskRecurrenceRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.delete_by_subtask_id(subtask_id).await,
             Self::LocalAutomerge(repo) => repo.delete_by_subtask_id(subtask_id).await,
+            Self::Web(repo) => repo.delete_by_subtask_id(subtask_id).await,
         }
     }
 
@@ -75,6 +82,7 @@ impl SubtaskRecurrenceRepositoryTrait for SubTaskRecurrenceRepositoryVariant {
             Self::LocalAutomerge(repo) => {
                 repo.delete_by_recurrence_rule_id(recurrence_rule_id).await
             }
+            Self::Web(repo) => repo.delete_by_recurrence_rule_id(recurrence_rule_id).await,
         }
     }
 
@@ -82,6 +90,7 @@ impl SubtaskRecurrenceRepositoryTrait for SubTaskRecurrenceRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.exists_by_subtask_id(subtask_id).await,
             Self::LocalAutomerge(repo) => repo.exists_by_subtask_id(subtask_id).await,
+            Self::Web(repo) => repo.exists_by_subtask_id(subtask_id).await,
         }
     }
 }
@@ -107,6 +116,10 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
                 repo.add(project_id, parent_id, child_id, user_id, timestamp)
                     .await
             }
+            Self::Web(repo) => {
+                repo.add(project_id, parent_id, child_id, user_id, timestamp)
+                    .await
+            }
         }
     }
 
@@ -119,6 +132,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.remove(project_id, parent_id, child_id).await,
             Self::LocalAutomerge(repo) => repo.remove(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.remove(project_id, parent_id, child_id).await,
         }
     }
 
@@ -130,6 +144,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.remove_all(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.remove_all(project_id, parent_id).await,
+            Self::Web(repo) => repo.remove_all(project_id, parent_id).await,
         }
     }
 
@@ -141,6 +156,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.find_relations(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.find_relations(project_id, parent_id).await,
+            Self::Web(repo) => repo.find_relations(project_id, parent_id).await,
         }
     }
 
@@ -161,6 +177,12 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
                 RecurrenceRuleId,
             >>::find_all(repo, project_id)
             .await,
+            Self::Web(repo) => <_ as ProjectRelationRepository<
+                SubTaskRecurrence,
+                SubTaskId,
+                RecurrenceRuleId,
+            >>::find_all(repo, project_id)
+            .await,
         }
     }
 
@@ -172,6 +194,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.exists(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.exists(project_id, parent_id).await,
+            Self::Web(repo) => repo.exists(project_id, parent_id).await,
         }
     }
 
@@ -183,6 +206,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.count(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.count(project_id, parent_id).await,
+            Self::Web(repo) => repo.count(project_id, parent_id).await,
         }
     }
 
@@ -195,6 +219,7 @@ impl ProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.find_relation(project_id, parent_id, child_id).await,
             Self::LocalAutomerge(repo) => repo.find_relation(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.find_relation(project_id, parent_id, child_id).await,
         }
     }
 }
@@ -252,6 +277,16 @@ impl SubTaskRecurrenceUnifiedRepository {
             ));
     }
 
+    pub fn add_web_for_save(&mut self, web_repo: SubTaskRecurrenceWebRepository) {
+        self.save_repositories
+            .push(SubTaskRecurrenceRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn add_web_for_search(&mut self, web_repo: SubTaskRecurrenceWebRepository) {
+        self.search_repositories
+            .push(SubTaskRecurrenceRepositoryVariant::Web(web_repo));
+    }
+
     pub fn save_repositories_count(&self) -> usize {
         self.save_repositories.len()
     }
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::info;
 
+use crate::web::SubTaskTagWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::subtask_tag::SubtaskTagLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::subtask_tag::SubtaskTagLocalSqliteRepository;
 use flequit_model::models::task_projects::subtask_tag::SubTaskTag;
@@ -16,6 +17,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum SubTaskTagRepositoryVariant {
     LocalSqlite(SubtaskTagLocalSqliteRepository),
     LocalAutomerge(SubtaskTagLocalAutomergeRepository),
+    Web(SubTaskTagWebRepository),
 }
 
 impl SubTaskTagRepositoryVariant {
@@ -31,6 +33,7 @@ impl SubTaskTagRepositoryVariant {
                 repo.remove_all_relations_by_tag_id(project_id, tag_id)
                     .await
             }
+            Self::Web(repo) => repo.remove_all_by_child(project_id, tag_id).await,
         }
     }
 }
@@ -56,6 +59,10 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubTaskTagRepos
                 repo.add(project_id, parent_id, child_id, user_id, timestamp)
                     .await
             }
+            Self::Web(repo) => {
+                repo.add(project_id, parent_id, child_id, user_id, timestamp)
+                    .await
+            }
         }
     }
 
@@ -68,6 +75,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubTaskTagRepos
         match self {
             Self::LocalSqlite(repo) => repo.remove(project_id, parent_id, child_id).await,
             Self::LocalAutomerge(repo) => repo.remove(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.remove(project_id, parent_id, child_id).await,
         }
     }
 
@@ -79,6 +87,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubTaskTagRepos
         match self {
             Self::LocalSqlite(repo) => repo.remove_all(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.remove_all(project_id, parent_id).await,
+            Self::Web(repo) => repo.remove_all(project_id, parent_id).await,
         }
     }
 
@@ -90,6 +99,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubTaskTagRepos
         match self {
             Self::LocalSqlite(repo) => repo.find_relations(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.find_relations(project_id, parent_id).await,
+            Self::Web(repo) => repo.find_relations(project_id, parent_id).await,
         }
     }
 
@@ -97,6 +107,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubTaskTagRepos
         match self {
             Self::LocalSqlite(repo) => repo.find_all(project_id).await,
             Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
         }
     }
 
@@ -108,6 +119,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubTaskTagRepos
         match self {
             Self::LocalSqlite(repo) => repo.exists(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.exists(project_id, parent_id).await,
+            Self::Web(repo) => repo.exists(project_id, parent_id).await,
         }
     }
 
@@ -119,6 +131,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubTaskTagRepos
         match self {
             Self::LocalSqlite(repo) => repo.count(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.count(project_id, parent_id).await,
+            Self::Web(repo) => repo.count(project_id, parent_id).await,
         }
     }
 
@@ -131,6 +144,7 @@ impl ProjectRelationRepository<SubTaskTag, SubTaskId, TagId> for SubTaskTagRepos
         match self {
             Self::LocalSqlite(repo) => repo.find_relation(project_id, parent_id, child_id).await,
             Self::LocalAutomerge(repo) => repo.find_relation(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.find_relation(project_id, parent_id, child_id).await,
         }
     }
 }
@@ -178,14 +192,14 @@ impl SubTaskTagUnifiedRepository {
             .push(SubTaskTagRepositoryVariant::LocalAutomerge(automerge_repo));
     }
 
-    pub fn add_web_for_save(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.save_repositories.push(SubTaskTagRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_save(&mut self, web_repo: SubTaskTagWebRepository) {
+        self.save_repositories
+            .push(SubTaskTagRepositoryVariant::Web(web_repo));
     }
 
-    pub fn add_web_for_search(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.search_repositories.push(SubTaskTagRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_search(&mut self, web_repo: SubTaskTagWebRepository) {
+        self.search_repositories
+            .push(SubTaskTagRepositoryVariant::Web(web_repo));
     }
 
     pub fn save_repositories_count(&self) -> usize {
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::info;
 
+use crate::web::TagWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::tag::TagLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::tag::TagLocalSqliteRepository;
 use flequit_model::models::task_projects::tag::Tag;
@@ -16,6 +17,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum TagRepositoryVariant {
     LocalSqlite(TagLocalSqliteRepository),
     LocalAutomerge(TagLocalAutomergeRepository),
+    Web(TagWebRepository),
 }
 
 impl TagRepositoryTrait for TagRepositoryVariant {}
@@ -32,6 +34,7 @@ impl ProjectRepository<Tag, TagId> for TagRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
             Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
         }
     }
 
@@ -43,6 +46,7 @@ impl ProjectRepository<Tag, TagId> for TagRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
         }
     }
 
@@ -50,6 +54,7 @@ impl ProjectRepository<Tag, TagId> for TagRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_all(project_id).await,
             Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
         }
     }
 
@@ -57,6 +62,7 @@ impl ProjectRepository<Tag, TagId> for TagRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
         }
     }
 
@@ -64,6 +70,7 @@ impl ProjectRepository<Tag, TagId> for TagRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
         }
     }
 
@@ -71,6 +78,7 @@ impl ProjectRepository<Tag, TagId> for TagRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.count(project_id).await,
             Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
         }
     }
 }
@@ -118,14 +126,14 @@ impl TagUnifiedRepository {
             .push(TagRepositoryVariant::LocalAutomerge(automerge_repo));
     }
 
-    pub fn add_web_for_save(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.save_repositories.push(TagRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_save(&mut self, web_repo: TagWebRepository) {
+        self.save_repositories
+            .push(TagRepositoryVariant::Web(web_repo));
     }
 
-    pub fn add_web_for_search(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.search_repositories.push(TagRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_search(&mut self, web_repo: TagWebRepository) {
+        self.search_repositories
+            .push(TagRepositoryVariant::Web(web_repo));
     }
 
     /// 保存用リポジトリの数を取得
@@ -160,6 +168,10 @@ impl TagUnifiedRepository {
                     // Automergeは通常の削除（トランザクション不要）
                     repo.delete(project_id, tag_id).await?;
                 }
+                TagRepositoryVariant::Web(repo) => {
+                    // 関連付けの削除はサーバー側で行う
+                    repo.delete(project_id, tag_id).await?;
+                }
             }
         }
 
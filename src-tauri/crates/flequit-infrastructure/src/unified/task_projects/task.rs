@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::{error, info};
 
+use crate::web::TaskWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::task::TaskLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::task::TaskLocalSqliteRepository;
 use flequit_model::models::task_projects::task::Task;
@@ -17,6 +18,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum TaskRepositoryVariant {
     LocalSqlite(TaskLocalSqliteRepository),
     LocalAutomerge(TaskLocalAutomergeRepository),
+    Web(TaskWebRepository),
 }
 
 impl TaskRepositoryTrait for TaskRepositoryVariant {}
@@ -35,6 +37,7 @@ impl ProjectRepository<Task, TaskId> for TaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
             Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
         }
     }
 
@@ -46,6 +49,7 @@ impl ProjectRepository<Task, TaskId> for TaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
         }
     }
 
@@ -53,6 +57,7 @@ impl ProjectRepository<Task, TaskId> for TaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_all(project_id).await,
             Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
         }
     }
 
@@ -60,6 +65,7 @@ impl ProjectRepository<Task, TaskId> for TaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
         }
     }
 
@@ -67,6 +73,7 @@ impl ProjectRepository<Task, TaskId> for TaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
         }
     }
 
@@ -74,6 +81,7 @@ impl ProjectRepository<Task, TaskId> for TaskRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.count(project_id).await,
             Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
         }
     }
 }
@@ -121,14 +129,14 @@ impl TaskUnifiedRepository {
             .push(TaskRepositoryVariant::LocalAutomerge(automerge_repo));
     }
 
-    pub fn add_web_for_save(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.save_repositories.push(TaskRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_save(&mut self, web_repo: TaskWebRepository) {
+        self.save_repositories
+            .push(TaskRepositoryVariant::Web(web_repo));
     }
 
-    pub fn add_web_for_search(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.search_repositories.push(TaskRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_search(&mut self, web_repo: TaskWebRepository) {
+        self.search_repositories
+            .push(TaskRepositoryVariant::Web(web_repo));
     }
 
     /// 保存用リポジトリの数を取得
@@ -229,15 +237,9 @@ impl ProjectRepository<Task, TaskId> for TaskUnifiedRepository {
                         "Failed to delete task {} from repository {}: {:?}",
                         id, idx, e
                     );
-                    // 削除失敗: ロールバック処理
-                    error!(
-                        "Rolling back deletions due to failure in repository {}",
-                        idx
-                    );
-
-                    // TODO: ロールバック処理を実装
-                    // 現時点では、削除されたデータを復元する機能がないため、
-                    // エラーをログに記録して失敗を返す
+                    // 削除済みのリポジトリは、呼び出し元の作業単位
+                    // （InfrastructureRepositoriesTrait::run_in_unit_of_work）が
+                    // エラーを受けてまとめて元に戻す
                     return Err(e);
                 }
             }
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::info;
 
+use crate::web::TaskAssignmentWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::task_assignments::TaskAssignmentLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::task_assignments::TaskAssignmentLocalSqliteRepository;
 use flequit_model::models::task_projects::task_assignment::TaskAssignment;
@@ -16,6 +17,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum TaskAssignmentRepositoryVariant {
     LocalSqlite(TaskAssignmentLocalSqliteRepository),
     LocalAutomerge(TaskAssignmentLocalAutomergeRepository),
+    Web(TaskAssignmentWebRepository),
 }
 
 impl TaskAssignmentRepositoryTrait for TaskAssignmentRepositoryVariant {}
@@ -39,6 +41,10 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId> for TaskAssignmen
                 repo.add(project_id, parent_id, child_id, user_id, timestamp)
                     .await
             }
+            Self::Web(repo) => {
+                repo.add(project_id, parent_id, child_id, user_id, timestamp)
+                    .await
+            }
         }
     }
 
@@ -51,6 +57,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId> for TaskAssignmen
         match self {
             Self::LocalSqlite(repo) => repo.remove(project_id, parent_id, child_id).await,
             Self::LocalAutomerge(repo) => repo.remove(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.remove(project_id, parent_id, child_id).await,
         }
     }
 
@@ -62,6 +69,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId> for TaskAssignmen
         match self {
             Self::LocalSqlite(repo) => repo.remove_all(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.remove_all(project_id, parent_id).await,
+            Self::Web(repo) => repo.remove_all(project_id, parent_id).await,
         }
     }
 
@@ -73,6 +81,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId> for TaskAssignmen
         match self {
             Self::LocalSqlite(repo) => repo.find_relations(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.find_relations(project_id, parent_id).await,
+            Self::Web(repo) => repo.find_relations(project_id, parent_id).await,
         }
     }
 
@@ -83,6 +92,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId> for TaskAssignmen
         match self {
             Self::LocalSqlite(repo) => repo.find_all(project_id).await,
             Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
         }
     }
 
@@ -94,6 +104,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId> for TaskAssignmen
         match self {
             Self::LocalSqlite(repo) => repo.exists(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.exists(project_id, parent_id).await,
+            Self::Web(repo) => repo.exists(project_id, parent_id).await,
         }
     }
 
@@ -105,6 +116,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId> for TaskAssignmen
         match self {
             Self::LocalSqlite(repo) => repo.count(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.count(project_id, parent_id).await,
+            Self::Web(repo) => repo.count(project_id, parent_id).await,
         }
     }
 
@@ -117,6 +129,7 @@ impl ProjectRelationRepository<TaskAssignment, TaskId, UserId> for TaskAssignmen
         match self {
             Self::LocalSqlite(repo) => repo.find_relation(project_id, parent_id, child_id).await,
             Self::LocalAutomerge(repo) => repo.find_relation(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.find_relation(project_id, parent_id, child_id).await,
         }
     }
 }
@@ -174,14 +187,14 @@ impl TaskAssignmentUnifiedRepository {
             ));
     }
 
-    pub fn add_web_for_save(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.save_repositories.push(TaskAssignmentRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_save(&mut self, web_repo: TaskAssignmentWebRepository) {
+        self.save_repositories
+            .push(TaskAssignmentRepositoryVariant::Web(web_repo));
     }
 
-    pub fn add_web_for_search(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.search_repositories.push(TaskAssignmentRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_search(&mut self, web_repo: TaskAssignmentWebRepository) {
+        self.search_repositories
+            .push(TaskAssignmentRepositoryVariant::Web(web_repo));
     }
 
     /// 保存用リポジトリの数を取得
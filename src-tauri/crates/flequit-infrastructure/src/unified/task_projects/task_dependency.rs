@@ -0,0 +1,358 @@
+//! タスク依存関係用統合リポジトリ
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::info;
+
+use crate::web::TaskDependencyWebRepository;
+use flequit_infrastructure_automerge::infrastructure::task_projects::task_dependency::TaskDependencyLocalAutomergeRepository;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::task_dependency::TaskDependencyLocalSqliteRepository;
+use flequit_model::models::task_projects::task_dependency::TaskDependency;
+use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
+use flequit_repository::repositories::project_relation_repository_trait::ProjectRelationRepository;
+use flequit_repository::repositories::task_projects::task_dependency_repository_trait::TaskDependencyRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+
+#[derive(Debug)]
+pub enum TaskDependencyRepositoryVariant {
+    LocalSqlite(TaskDependencyLocalSqliteRepository),
+    LocalAutomerge(TaskDependencyLocalAutomergeRepository),
+    Web(TaskDependencyWebRepository),
+}
+
+impl TaskDependencyRepositoryTrait for TaskDependencyRepositoryVariant {}
+
+#[async_trait]
+impl ProjectRelationRepository<TaskDependency, TaskId, TaskId> for TaskDependencyRepositoryVariant {
+    async fn add(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => {
+                repo.add(project_id, parent_id, child_id, user_id, timestamp)
+                    .await
+            }
+            Self::LocalAutomerge(repo) => {
+                repo.add(project_id, parent_id, child_id, user_id, timestamp)
+                    .await
+            }
+            Self::Web(repo) => {
+                repo.add(project_id, parent_id, child_id, user_id, timestamp)
+                    .await
+            }
+        }
+    }
+
+    async fn remove(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.remove(project_id, parent_id, child_id).await,
+            Self::LocalAutomerge(repo) => repo.remove(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.remove(project_id, parent_id, child_id).await,
+        }
+    }
+
+    async fn remove_all(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.remove_all(project_id, parent_id).await,
+            Self::LocalAutomerge(repo) => repo.remove_all(project_id, parent_id).await,
+            Self::Web(repo) => repo.remove_all(project_id, parent_id).await,
+        }
+    }
+
+    async fn find_relations(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<Vec<TaskDependency>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_relations(project_id, parent_id).await,
+            Self::LocalAutomerge(repo) => repo.find_relations(project_id, parent_id).await,
+            Self::Web(repo) => repo.find_relations(project_id, parent_id).await,
+        }
+    }
+
+    async fn find_all(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<TaskDependency>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_all(project_id).await,
+            Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
+        }
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<bool, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.exists(project_id, parent_id).await,
+            Self::LocalAutomerge(repo) => repo.exists(project_id, parent_id).await,
+            Self::Web(repo) => repo.exists(project_id, parent_id).await,
+        }
+    }
+
+    async fn count(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<u64, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.count(project_id, parent_id).await,
+            Self::LocalAutomerge(repo) => repo.count(project_id, parent_id).await,
+            Self::Web(repo) => repo.count(project_id, parent_id).await,
+        }
+    }
+
+    async fn find_relation(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+    ) -> Result<Option<TaskDependency>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_relation(project_id, parent_id, child_id).await,
+            Self::LocalAutomerge(repo) => repo.find_relation(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.find_relation(project_id, parent_id, child_id).await,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TaskDependencyUnifiedRepository {
+    save_repositories: Vec<TaskDependencyRepositoryVariant>,
+    search_repositories: Vec<TaskDependencyRepositoryVariant>,
+}
+
+impl Default for TaskDependencyUnifiedRepository {
+    fn default() -> Self {
+        Self::new(vec![], vec![])
+    }
+}
+
+impl TaskDependencyUnifiedRepository {
+    pub fn new(
+        save_repositories: Vec<TaskDependencyRepositoryVariant>,
+        search_repositories: Vec<TaskDependencyRepositoryVariant>,
+    ) -> Self {
+        Self {
+            save_repositories,
+            search_repositories,
+        }
+    }
+
+    pub fn add_sqlite_for_save(&mut self, sqlite_repo: TaskDependencyLocalSqliteRepository) {
+        self.save_repositories
+            .push(TaskDependencyRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_save(
+        &mut self,
+        automerge_repo: TaskDependencyLocalAutomergeRepository,
+    ) {
+        self.save_repositories
+            .push(TaskDependencyRepositoryVariant::LocalAutomerge(
+                automerge_repo,
+            ));
+    }
+
+    pub fn add_sqlite_for_search(&mut self, sqlite_repo: TaskDependencyLocalSqliteRepository) {
+        self.search_repositories
+            .push(TaskDependencyRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_search(
+        &mut self,
+        automerge_repo: TaskDependencyLocalAutomergeRepository,
+    ) {
+        self.search_repositories
+            .push(TaskDependencyRepositoryVariant::LocalAutomerge(
+                automerge_repo,
+            ));
+    }
+
+    pub fn add_web_for_save(&mut self, web_repo: TaskDependencyWebRepository) {
+        self.save_repositories
+            .push(TaskDependencyRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn add_web_for_search(&mut self, web_repo: TaskDependencyWebRepository) {
+        self.search_repositories
+            .push(TaskDependencyRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn save_repositories_count(&self) -> usize {
+        self.save_repositories.len()
+    }
+
+    pub fn search_repositories_count(&self) -> usize {
+        self.search_repositories.len()
+    }
+}
+
+impl TaskDependencyRepositoryTrait for TaskDependencyUnifiedRepository {}
+
+#[async_trait]
+impl ProjectRelationRepository<TaskDependency, TaskId, TaskId> for TaskDependencyUnifiedRepository {
+    async fn add(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Adding task dependency - project: {}, task: {}, depends on: {}",
+            project_id, parent_id, child_id
+        );
+
+        for repository in &self.save_repositories {
+            repository
+                .add(project_id, parent_id, child_id, user_id, timestamp)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn remove(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Removing task dependency - project: {}, task: {}, depends on: {}",
+            project_id, parent_id, child_id
+        );
+
+        for repository in &self.save_repositories {
+            repository.remove(project_id, parent_id, child_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn remove_all(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Removing all task dependencies for task - project: {}, task: {}",
+            project_id, parent_id
+        );
+
+        for repository in &self.save_repositories {
+            repository.remove_all(project_id, parent_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_relations(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<Vec<TaskDependency>, RepositoryError> {
+        info!(
+            "Finding task dependencies - project: {}, task: {}",
+            project_id, parent_id
+        );
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.find_relations(project_id, parent_id).await
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    async fn find_all(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<TaskDependency>, RepositoryError> {
+        info!("Finding all task dependencies in project: {}", project_id);
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.find_all(project_id).await
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<bool, RepositoryError> {
+        info!(
+            "Checking if task dependencies exist - project: {}, task: {}",
+            project_id, parent_id
+        );
+
+        for repository in &self.search_repositories {
+            if repository.exists(project_id, parent_id).await? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn count(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+    ) -> Result<u64, RepositoryError> {
+        info!(
+            "Counting task dependencies for task - project: {}, task: {}",
+            project_id, parent_id
+        );
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.count(project_id, parent_id).await
+        } else {
+            Ok(0)
+        }
+    }
+
+    async fn find_relation(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &TaskId,
+        child_id: &TaskId,
+    ) -> Result<Option<TaskDependency>, RepositoryError> {
+        info!(
+            "Finding specific task dependency - project: {}, task: {}, depends on: {}",
+            project_id, parent_id, child_id
+        );
+
+        for repository in &self.search_repositories {
+            if let Some(relation) = repository
+                .find_relation(project_id, parent_id, child_id)
+                .await?
+            {
+                return Ok(Some(relation));
+            }
+        }
+
+        Ok(None)
+    }
+}
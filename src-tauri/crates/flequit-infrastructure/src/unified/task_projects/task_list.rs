@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::info;
 
+use crate::web::TaskListWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::task_list::TaskListLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::task_list::TaskListLocalSqliteRepository;
 use flequit_model::models::task_projects::task_list::TaskList;
@@ -17,6 +18,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum TaskListRepositoryVariant {
     LocalSqlite(TaskListLocalSqliteRepository),
     LocalAutomerge(TaskListLocalAutomergeRepository),
+    Web(TaskListWebRepository),
 }
 
 impl TaskListRepositoryTrait for TaskListRepositoryVariant {}
@@ -35,6 +37,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
             Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
         }
     }
 
@@ -46,6 +49,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
         }
     }
 
@@ -53,6 +57,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_all(project_id).await,
             Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
         }
     }
 
@@ -60,6 +65,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
         }
     }
 
@@ -71,6 +77,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
             Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
         }
     }
 
@@ -78,6 +85,7 @@ impl ProjectRepository<TaskList, TaskListId> for TaskListRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.count(project_id).await,
             Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
         }
     }
 }
@@ -125,14 +133,14 @@ impl TaskListUnifiedRepository {
             .push(TaskListRepositoryVariant::LocalAutomerge(automerge_repo));
     }
 
-    pub fn add_web_for_save(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.save_repositories.push(TaskListRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_save(&mut self, web_repo: TaskListWebRepository) {
+        self.save_repositories
+            .push(TaskListRepositoryVariant::Web(web_repo));
     }
 
-    pub fn add_web_for_search(&mut self, _web_repo: impl std::fmt::Debug + Send + Sync + 'static) {
-        // 将来のWeb実装用の拡張ポイント
-        // self.search_repositories.push(TaskListRepositoryVariant::Web(web_repo));
+    pub fn add_web_for_search(&mut self, web_repo: TaskListWebRepository) {
+        self.search_repositories
+            .push(TaskListRepositoryVariant::Web(web_repo));
     }
 
     /// 保存用リポジトリの数を取得
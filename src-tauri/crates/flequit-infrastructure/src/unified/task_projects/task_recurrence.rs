@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::info;
 
+use crate::web::TaskRecurrenceWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::task_recurrence::TaskRecurrenceLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::task_recurrence::TaskRecurrenceLocalSqliteRepository;
 use flequit_model::models::task_projects::task_recurrence::TaskRecurrence;
@@ -16,6 +17,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum TaskRecurrenceRepositoryVariant {
     LocalSqlite(TaskRecurrenceLocalSqliteRepository),
     LocalAutomerge(TaskRecurrenceLocalAutomergeRepository),
+    Web(TaskRecurrenceWebRepository),
 }
 
 impl TaskRecurrenceRepositoryTrait for TaskRecurrenceRepositoryVariant {}
@@ -41,6 +43,10 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
                 repo.add(project_id, parent_id, child_id, user_id, timestamp)
                     .await
             }
+            Self::Web(repo) => {
+                repo.add(project_id, parent_id, child_id, user_id, timestamp)
+                    .await
+            }
         }
     }
 
@@ -53,6 +59,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.remove(project_id, parent_id, child_id).await,
             Self::LocalAutomerge(repo) => repo.remove(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.remove(project_id, parent_id, child_id).await,
         }
     }
 
@@ -64,6 +71,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.remove_all(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.remove_all(project_id, parent_id).await,
+            Self::Web(repo) => repo.remove_all(project_id, parent_id).await,
         }
     }
 
@@ -75,6 +83,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.find_relations(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.find_relations(project_id, parent_id).await,
+            Self::Web(repo) => repo.find_relations(project_id, parent_id).await,
         }
     }
 
@@ -85,6 +94,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.find_all(project_id).await,
             Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
         }
     }
 
@@ -96,6 +106,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.exists(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.exists(project_id, parent_id).await,
+            Self::Web(repo) => repo.exists(project_id, parent_id).await,
         }
     }
 
@@ -107,6 +118,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.count(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.count(project_id, parent_id).await,
+            Self::Web(repo) => repo.count(project_id, parent_id).await,
         }
     }
 
@@ -119,6 +131,7 @@ impl ProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>
         match self {
             Self::LocalSqlite(repo) => repo.find_relation(project_id, parent_id, child_id).await,
             Self::LocalAutomerge(repo) => repo.find_relation(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.find_relation(project_id, parent_id, child_id).await,
         }
     }
 }
@@ -176,6 +189,16 @@ impl TaskRecurrenceUnifiedRepository {
             ));
     }
 
+    pub fn add_web_for_save(&mut self, web_repo: TaskRecurrenceWebRepository) {
+        self.save_repositories
+            .push(TaskRecurrenceRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn add_web_for_search(&mut self, web_repo: TaskRecurrenceWebRepository) {
+        self.search_repositories
+            .push(TaskRecurrenceRepositoryVariant::Web(web_repo));
+    }
+
     pub fn save_repositories_count(&self) -> usize {
         self.save_repositories.len()
     }
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::info;
 
+use crate::web::TaskTagWebRepository;
 use flequit_infrastructure_automerge::infrastructure::task_projects::task_tag::TaskTagLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::task_projects::task_tag::TaskTagLocalSqliteRepository;
 use flequit_model::models::task_projects::task_tag::TaskTag;
@@ -16,6 +17,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum TaskTagRepositoryVariant {
     LocalSqlite(TaskTagLocalSqliteRepository),
     LocalAutomerge(TaskTagLocalAutomergeRepository),
+    Web(TaskTagWebRepository),
 }
 
 impl TaskTagRepositoryVariant {
@@ -34,6 +36,7 @@ impl TaskTagRepositoryVariant {
                 repo.remove_all_relations_by_tag_id(project_id, tag_id)
                     .await
             }
+            Self::Web(repo) => repo.remove_all_by_child(project_id, tag_id).await,
         }
     }
 }
@@ -59,6 +62,10 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagRepositoryVari
                 repo.add(project_id, parent_id, child_id, user_id, timestamp)
                     .await
             }
+            Self::Web(repo) => {
+                repo.add(project_id, parent_id, child_id, user_id, timestamp)
+                    .await
+            }
         }
     }
 
@@ -71,6 +78,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagRepositoryVari
         match self {
             Self::LocalSqlite(repo) => repo.remove(project_id, parent_id, child_id).await,
             Self::LocalAutomerge(repo) => repo.remove(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.remove(project_id, parent_id, child_id).await,
         }
     }
 
@@ -82,6 +90,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagRepositoryVari
         match self {
             Self::LocalSqlite(repo) => repo.remove_all(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.remove_all(project_id, parent_id).await,
+            Self::Web(repo) => repo.remove_all(project_id, parent_id).await,
         }
     }
 
@@ -93,6 +102,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagRepositoryVari
         match self {
             Self::LocalSqlite(repo) => repo.find_relations(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.find_relations(project_id, parent_id).await,
+            Self::Web(repo) => repo.find_relations(project_id, parent_id).await,
         }
     }
 
@@ -100,6 +110,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagRepositoryVari
         match self {
             Self::LocalSqlite(repo) => repo.find_all(project_id).await,
             Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
         }
     }
 
@@ -111,6 +122,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagRepositoryVari
         match self {
             Self::LocalSqlite(repo) => repo.exists(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.exists(project_id, parent_id).await,
+            Self::Web(repo) => repo.exists(project_id, parent_id).await,
         }
     }
 
@@ -122,6 +134,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagRepositoryVari
         match self {
             Self::LocalSqlite(repo) => repo.count(project_id, parent_id).await,
             Self::LocalAutomerge(repo) => repo.count(project_id, parent_id).await,
+            Self::Web(repo) => repo.count(project_id, parent_id).await,
         }
     }
 
@@ -134,6 +147,7 @@ impl ProjectRelationRepository<TaskTag, TaskId, TagId> for TaskTagRepositoryVari
         match self {
             Self::LocalSqlite(repo) => repo.find_relation(project_id, parent_id, child_id).await,
             Self::LocalAutomerge(repo) => repo.find_relation(project_id, parent_id, child_id).await,
+            Self::Web(repo) => repo.find_relation(project_id, parent_id, child_id).await,
         }
     }
 }
@@ -181,6 +195,16 @@ impl TaskTagUnifiedRepository {
             .push(TaskTagRepositoryVariant::LocalAutomerge(automerge_repo));
     }
 
+    pub fn add_web_for_save(&mut self, web_repo: TaskTagWebRepository) {
+        self.save_repositories
+            .push(TaskTagRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn add_web_for_search(&mut self, web_repo: TaskTagWebRepository) {
+        self.search_repositories
+            .push(TaskTagRepositoryVariant::Web(web_repo));
+    }
+
     pub fn save_repositories_count(&self) -> usize {
         self.save_repositories.len()
     }
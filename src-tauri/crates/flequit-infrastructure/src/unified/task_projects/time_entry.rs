@@ -0,0 +1,257 @@
+//! タイムエントリ用統合リポジトリ
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::info;
+
+use crate::web::TimeEntryWebRepository;
+use flequit_infrastructure_automerge::infrastructure::task_projects::time_entry::TimeEntryLocalAutomergeRepository;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::time_entry::TimeEntryLocalSqliteRepository;
+use flequit_model::models::task_projects::time_entry::TimeEntry;
+use flequit_model::types::id_types::{ProjectId, TimeEntryId, UserId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::time_entry_repository_trait::TimeEntryRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+
+#[derive(Debug)]
+pub enum TimeEntryRepositoryVariant {
+    LocalSqlite(TimeEntryLocalSqliteRepository),
+    LocalAutomerge(TimeEntryLocalAutomergeRepository),
+    Web(TimeEntryWebRepository),
+}
+
+impl TimeEntryRepositoryTrait for TimeEntryRepositoryVariant {}
+
+#[async_trait]
+impl ProjectRepository<TimeEntry, TimeEntryId> for TimeEntryRepositoryVariant {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &TimeEntry,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<Option<TimeEntry>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
+        }
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<TimeEntry>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_all(project_id).await,
+            Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
+        }
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
+        }
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<bool, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
+        }
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.count(project_id).await,
+            Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TimeEntryUnifiedRepository {
+    save_repositories: Vec<TimeEntryRepositoryVariant>,
+    search_repositories: Vec<TimeEntryRepositoryVariant>,
+}
+
+impl Default for TimeEntryUnifiedRepository {
+    fn default() -> Self {
+        Self::new(vec![], vec![])
+    }
+}
+
+impl TimeEntryUnifiedRepository {
+    pub fn new(
+        save_repositories: Vec<TimeEntryRepositoryVariant>,
+        search_repositories: Vec<TimeEntryRepositoryVariant>,
+    ) -> Self {
+        Self {
+            save_repositories,
+            search_repositories,
+        }
+    }
+
+    pub fn add_sqlite_for_save(&mut self, sqlite_repo: TimeEntryLocalSqliteRepository) {
+        self.save_repositories
+            .push(TimeEntryRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_save(&mut self, automerge_repo: TimeEntryLocalAutomergeRepository) {
+        self.save_repositories
+            .push(TimeEntryRepositoryVariant::LocalAutomerge(automerge_repo));
+    }
+
+    pub fn add_sqlite_for_search(&mut self, sqlite_repo: TimeEntryLocalSqliteRepository) {
+        self.search_repositories
+            .push(TimeEntryRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_search(&mut self, automerge_repo: TimeEntryLocalAutomergeRepository) {
+        self.search_repositories
+            .push(TimeEntryRepositoryVariant::LocalAutomerge(automerge_repo));
+    }
+
+    pub fn add_web_for_save(&mut self, web_repo: TimeEntryWebRepository) {
+        self.save_repositories
+            .push(TimeEntryRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn add_web_for_search(&mut self, web_repo: TimeEntryWebRepository) {
+        self.search_repositories
+            .push(TimeEntryRepositoryVariant::Web(web_repo));
+    }
+
+    /// 保存用リポジトリの数を取得
+    pub fn save_repositories_count(&self) -> usize {
+        self.save_repositories.len()
+    }
+
+    /// 検索用リポジトリの数を取得
+    pub fn search_repositories_count(&self) -> usize {
+        self.search_repositories.len()
+    }
+}
+
+impl TimeEntryRepositoryTrait for TimeEntryUnifiedRepository {}
+
+#[async_trait]
+impl ProjectRepository<TimeEntry, TimeEntryId> for TimeEntryUnifiedRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &TimeEntry,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Saving time entry with ID: {} in project: {}",
+            entity.id, project_id
+        );
+
+        for repository in &self.save_repositories {
+            repository
+                .save(project_id, entity, user_id, timestamp)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<Option<TimeEntry>, RepositoryError> {
+        info!(
+            "Finding time entry by ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.search_repositories {
+            if let Some(entity) = repository.find_by_id(project_id, id).await? {
+                return Ok(Some(entity));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<TimeEntry>, RepositoryError> {
+        info!("Finding all time entries in project: {}", project_id);
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.find_all(project_id).await
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Deleting time entry with ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.save_repositories {
+            repository.delete(project_id, id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &TimeEntryId,
+    ) -> Result<bool, RepositoryError> {
+        info!(
+            "Checking if time entry exists with ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.search_repositories {
+            if repository.exists(project_id, id).await? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        info!("Counting time entries in project: {}", project_id);
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.count(project_id).await
+        } else {
+            Ok(0)
+        }
+    }
+}
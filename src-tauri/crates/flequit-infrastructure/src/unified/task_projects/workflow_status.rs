@@ -0,0 +1,273 @@
+//! ワークフローステータス用統合リポジトリ
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::info;
+
+use crate::web::WorkflowStatusWebRepository;
+use flequit_infrastructure_automerge::infrastructure::task_projects::workflow_status::WorkflowStatusLocalAutomergeRepository;
+use flequit_infrastructure_sqlite::infrastructure::task_projects::workflow_status::WorkflowStatusLocalSqliteRepository;
+use flequit_model::models::task_projects::workflow_status::WorkflowStatus;
+use flequit_model::types::id_types::{ProjectId, UserId, WorkflowStatusId};
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_repository::repositories::task_projects::workflow_status_repository_trait::WorkflowStatusRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+
+#[derive(Debug)]
+pub enum WorkflowStatusRepositoryVariant {
+    LocalSqlite(WorkflowStatusLocalSqliteRepository),
+    LocalAutomerge(WorkflowStatusLocalAutomergeRepository),
+    Web(WorkflowStatusWebRepository),
+}
+
+impl WorkflowStatusRepositoryTrait for WorkflowStatusRepositoryVariant {}
+
+#[async_trait]
+impl ProjectRepository<WorkflowStatus, WorkflowStatusId> for WorkflowStatusRepositoryVariant {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &WorkflowStatus,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::LocalAutomerge(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(project_id, entity, user_id, timestamp).await,
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &WorkflowStatusId,
+    ) -> Result<Option<WorkflowStatus>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_by_id(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.find_by_id(project_id, id).await,
+            Self::Web(repo) => repo.find_by_id(project_id, id).await,
+        }
+    }
+
+    async fn find_all(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<WorkflowStatus>, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.find_all(project_id).await,
+            Self::LocalAutomerge(repo) => repo.find_all(project_id).await,
+            Self::Web(repo) => repo.find_all(project_id).await,
+        }
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        id: &WorkflowStatusId,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.delete(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.delete(project_id, id).await,
+            Self::Web(repo) => repo.delete(project_id, id).await,
+        }
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &WorkflowStatusId,
+    ) -> Result<bool, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.exists(project_id, id).await,
+            Self::LocalAutomerge(repo) => repo.exists(project_id, id).await,
+            Self::Web(repo) => repo.exists(project_id, id).await,
+        }
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        match self {
+            Self::LocalSqlite(repo) => repo.count(project_id).await,
+            Self::LocalAutomerge(repo) => repo.count(project_id).await,
+            Self::Web(repo) => repo.count(project_id).await,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct WorkflowStatusUnifiedRepository {
+    save_repositories: Vec<WorkflowStatusRepositoryVariant>,
+    search_repositories: Vec<WorkflowStatusRepositoryVariant>,
+}
+
+impl Default for WorkflowStatusUnifiedRepository {
+    fn default() -> Self {
+        Self::new(vec![], vec![])
+    }
+}
+
+impl WorkflowStatusUnifiedRepository {
+    pub fn new(
+        save_repositories: Vec<WorkflowStatusRepositoryVariant>,
+        search_repositories: Vec<WorkflowStatusRepositoryVariant>,
+    ) -> Self {
+        Self {
+            save_repositories,
+            search_repositories,
+        }
+    }
+
+    pub fn add_sqlite_for_save(&mut self, sqlite_repo: WorkflowStatusLocalSqliteRepository) {
+        self.save_repositories
+            .push(WorkflowStatusRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_save(
+        &mut self,
+        automerge_repo: WorkflowStatusLocalAutomergeRepository,
+    ) {
+        self.save_repositories
+            .push(WorkflowStatusRepositoryVariant::LocalAutomerge(
+                automerge_repo,
+            ));
+    }
+
+    pub fn add_sqlite_for_search(&mut self, sqlite_repo: WorkflowStatusLocalSqliteRepository) {
+        self.search_repositories
+            .push(WorkflowStatusRepositoryVariant::LocalSqlite(sqlite_repo));
+    }
+
+    pub fn add_automerge_for_search(
+        &mut self,
+        automerge_repo: WorkflowStatusLocalAutomergeRepository,
+    ) {
+        self.search_repositories
+            .push(WorkflowStatusRepositoryVariant::LocalAutomerge(
+                automerge_repo,
+            ));
+    }
+
+    pub fn add_web_for_save(&mut self, web_repo: WorkflowStatusWebRepository) {
+        self.save_repositories
+            .push(WorkflowStatusRepositoryVariant::Web(web_repo));
+    }
+
+    pub fn add_web_for_search(&mut self, web_repo: WorkflowStatusWebRepository) {
+        self.search_repositories
+            .push(WorkflowStatusRepositoryVariant::Web(web_repo));
+    }
+
+    /// 保存用リポジトリの数を取得
+    pub fn save_repositories_count(&self) -> usize {
+        self.save_repositories.len()
+    }
+
+    /// 検索用リポジトリの数を取得
+    pub fn search_repositories_count(&self) -> usize {
+        self.search_repositories.len()
+    }
+}
+
+impl WorkflowStatusRepositoryTrait for WorkflowStatusUnifiedRepository {}
+
+#[async_trait]
+impl ProjectRepository<WorkflowStatus, WorkflowStatusId> for WorkflowStatusUnifiedRepository {
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &WorkflowStatus,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Saving workflow status with ID: {} in project: {}",
+            entity.id, project_id
+        );
+
+        for repository in &self.save_repositories {
+            repository
+                .save(project_id, entity, user_id, timestamp)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &WorkflowStatusId,
+    ) -> Result<Option<WorkflowStatus>, RepositoryError> {
+        info!(
+            "Finding workflow status by ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.search_repositories {
+            if let Some(entity) = repository.find_by_id(project_id, id).await? {
+                return Ok(Some(entity));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn find_all(
+        &self,
+        project_id: &ProjectId,
+    ) -> Result<Vec<WorkflowStatus>, RepositoryError> {
+        info!("Finding all workflow statuses in project: {}", project_id);
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.find_all(project_id).await
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn delete(
+        &self,
+        project_id: &ProjectId,
+        id: &WorkflowStatusId,
+    ) -> Result<(), RepositoryError> {
+        info!(
+            "Deleting workflow status with ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.save_repositories {
+            repository.delete(project_id, id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        project_id: &ProjectId,
+        id: &WorkflowStatusId,
+    ) -> Result<bool, RepositoryError> {
+        info!(
+            "Checking if workflow status exists with ID: {} in project: {}",
+            id, project_id
+        );
+
+        for repository in &self.search_repositories {
+            if repository.exists(project_id, id).await? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        info!("Counting workflow statuses in project: {}", project_id);
+
+        if let Some(repository) = self.search_repositories.first() {
+            repository.count(project_id).await
+        } else {
+            Ok(0)
+        }
+    }
+}
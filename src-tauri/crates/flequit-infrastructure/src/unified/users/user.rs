@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::info;
 
+use crate::web::UserWebRepository;
 use flequit_infrastructure_automerge::infrastructure::users::user::UserLocalAutomergeRepository;
 use flequit_infrastructure_sqlite::infrastructure::users::user::UserLocalSqliteRepository;
 use flequit_model::models::users::User;
@@ -18,7 +19,7 @@ use flequit_types::errors::repository_error::RepositoryError;
 pub enum UserRepositoryVariant {
     LocalSqlite(UserLocalSqliteRepository),
     LocalAutomerge(UserLocalAutomergeRepository),
-    // 将来的にWeb実装などを追加
+    Web(UserWebRepository),
 }
 
 impl UserRepositoryTrait for UserRepositoryVariant {}
@@ -34,6 +35,7 @@ impl Repository<User, UserId> for UserRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.save(entity, user_id, timestamp).await,
             Self::LocalAutomerge(repo) => repo.save(entity, user_id, timestamp).await,
+            Self::Web(repo) => repo.save(entity, user_id, timestamp).await,
         }
     }
 
@@ -41,6 +43,7 @@ impl Repository<User, UserId> for UserRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_by_id(id).await,
             Self::LocalAutomerge(repo) => repo.find_by_id(id).await,
+            Self::Web(repo) => repo.find_by_id(id).await,
         }
     }
 
@@ -48,6 +51,7 @@ impl Repository<User, UserId> for UserRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.find_all().await,
             Self::LocalAutomerge(repo) => repo.find_all().await,
+            Self::Web(repo) => repo.find_all().await,
         }
     }
 
@@ -55,6 +59,7 @@ impl Repository<User, UserId> for UserRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.delete(id).await,
             Self::LocalAutomerge(repo) => repo.delete(id).await,
+            Self::Web(repo) => repo.delete(id).await,
         }
     }
 
@@ -62,6 +67,7 @@ impl Repository<User, UserId> for UserRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.exists(id).await,
             Self::LocalAutomerge(repo) => repo.exists(id).await,
+            Self::Web(repo) => repo.exists(id).await,
         }
     }
 
@@ -69,6 +75,7 @@ impl Repository<User, UserId> for UserRepositoryVariant {
         match self {
             Self::LocalSqlite(repo) => repo.count().await,
             Self::LocalAutomerge(repo) => repo.count().await,
+            Self::Web(repo) => repo.count().await,
         }
     }
 }
@@ -130,6 +137,18 @@ impl UserUnifiedRepository {
             .push(UserRepositoryVariant::LocalAutomerge(automerge_repo));
     }
 
+    /// Webリポジトリを保存用に追加
+    pub fn add_web_for_save(&mut self, web_repo: UserWebRepository) {
+        self.save_repositories
+            .push(UserRepositoryVariant::Web(web_repo));
+    }
+
+    /// Webリポジトリを検索用に追加
+    pub fn add_web_for_search(&mut self, web_repo: UserWebRepository) {
+        self.search_repositories
+            .push(UserRepositoryVariant::Web(web_repo));
+    }
+
     /// 便利メソッド: SQLiteを保存用と検索用の両方に追加
     pub fn add_sqlite_for_both(
         &mut self,
@@ -0,0 +1,525 @@
+//! セルフホストFlequitサーバー用のREST/JSONクライアント
+
+use super::outbox::{IDEMPOTENCY_KEY_HEADER, OutboxFlushReport, OutboxRequest, WebOutbox};
+use crate::config::WebServerConfig;
+use chrono::{DateTime, SecondsFormat, Utc};
+use flequit_model::models::sync::outbox_operation::OutboxOperationKind;
+use flequit_model::types::id_types::UserId;
+use flequit_types::errors::repository_error::RepositoryError;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{Method, Response, StatusCode, Url};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 全エンドポイント共通のパス接頭辞
+pub const API_PREFIX: [&str; 2] = ["api", "v1"];
+/// 書き込みを行ったユーザーIDを伝えるヘッダー
+pub const USER_ID_HEADER: &str = "X-Flequit-User-Id";
+/// 書き込み時刻（RFC 3339）を伝えるヘッダー
+pub const TIMESTAMP_HEADER: &str = "X-Flequit-Timestamp";
+
+const JSON_CONTENT_TYPE: &str = "application/json";
+const BINARY_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// 一時的なエラーに対するリトライ方針
+///
+/// リトライするのは冪等なGET/PUT/DELETEのみ（同期メッセージのPOSTはリトライしない）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// 初回を除いた最大リトライ回数
+    pub max_retries: u32,
+    /// 初回リトライまでの待機時間（以降は倍々に増加）
+    pub initial_backoff: Duration,
+    /// 待機時間の上限（`Retry-After` もこの値で頭打ちにする）
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Self::default()
+        }
+    }
+
+    /// `attempt` 回目（0始まり）のリトライ前の待機時間
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+/// リトライで回復が見込めるステータスか
+fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::REQUEST_TIMEOUT
+            | StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// 失敗ステータスを `RepositoryError` に変換
+fn status_error(method: &Method, url: &Url, status: StatusCode, body: &str) -> RepositoryError {
+    let message = format!("{method} {url} -> {status}: {}", body.trim());
+    match status {
+        StatusCode::NOT_FOUND => RepositoryError::NotFound(message),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            RepositoryError::ConfigurationError(format!("認証に失敗しました: {message}"))
+        }
+        StatusCode::CONFLICT | StatusCode::PRECONDITION_FAILED => {
+            RepositoryError::ConstraintViolation(message)
+        }
+        StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => {
+            RepositoryError::ValidationError(message)
+        }
+        _ => RepositoryError::ConnectionError(message),
+    }
+}
+
+/// セルフホストサーバーのクライアント
+///
+/// 内部の `reqwest::Client` はコネクションプールを共有するため、`clone` は安価。
+#[derive(Clone)]
+pub struct WebClient {
+    base_url: Url,
+    api_token: Option<String>,
+    retry: RetryPolicy,
+    http: reqwest::Client,
+    /// 送信できなかった書き込みの記録先（`None` の場合は書き込みエラーをそのまま返す）
+    outbox: Option<Arc<WebOutbox>>,
+}
+
+impl std::fmt::Debug for WebClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebClient")
+            .field("base_url", &self.base_url.as_str())
+            .field("authenticated", &self.api_token.is_some())
+            .field("retry", &self.retry)
+            .field("outbox", &self.outbox.is_some())
+            .finish()
+    }
+}
+
+impl WebClient {
+    pub fn new(config: &WebServerConfig) -> Result<Self, RepositoryError> {
+        let base_url = Url::parse(config.base_url.trim()).map_err(|e| {
+            RepositoryError::ConfigurationError(format!("{}: {e}", config.base_url))
+        })?;
+        if base_url.cannot_be_a_base() {
+            return Err(RepositoryError::ConfigurationError(format!(
+                "接続先サーバーのURLが不正です: {}",
+                config.base_url
+            )));
+        }
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs.max(1)))
+            .build()
+            .map_err(|e| RepositoryError::ConfigurationError(e.to_string()))?;
+        Ok(Self {
+            base_url,
+            api_token: config.api_token.clone().filter(|t| !t.is_empty()),
+            retry: RetryPolicy::new(config.max_retries),
+            http,
+            outbox: None,
+        })
+    }
+
+    /// リトライ方針を差し替える
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// アウトボックスを設定する
+    ///
+    /// 設定した場合、サーバーに接続できない間の書き込みはエラーにせずアウトボックスに記録し、
+    /// `flush_outbox` で記録順に再送する。
+    pub fn with_outbox(mut self, outbox: WebOutbox) -> Self {
+        self.outbox = Some(Arc::new(outbox));
+        self
+    }
+
+    pub fn outbox(&self) -> Option<&WebOutbox> {
+        self.outbox.as_deref()
+    }
+
+    /// アウトボックスの再送日時を迎えた操作を再送する
+    pub async fn flush_outbox(&self) -> Result<OutboxFlushReport, RepositoryError> {
+        match &self.outbox {
+            Some(outbox) => outbox.flush(self).await,
+            None => Ok(OutboxFlushReport::default()),
+        }
+    }
+
+    /// アウトボックスからの再送用のクライアント（アウトボックスを持たず、リトライもしない）
+    pub(super) fn for_replay(&self) -> Self {
+        Self {
+            retry: RetryPolicy::new(0),
+            outbox: None,
+            ..self.clone()
+        }
+    }
+
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// `/api/v1/` 以下のセグメントとクエリからURLを組み立てる
+    ///
+    /// 各セグメントはパーセントエンコードされる。
+    pub fn url(&self, segments: &[&str], query: &[(&str, &str)]) -> Url {
+        let mut url = self.base_url.clone();
+        {
+            let mut path = url
+                .path_segments_mut()
+                .expect("base URL is validated in WebClient::new");
+            path.pop_if_empty();
+            path.extend(API_PREFIX);
+            path.extend(segments);
+        }
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+        url
+    }
+
+    /// JSONを取得する（404の場合は `None`）
+    pub async fn get_json<T>(
+        &self,
+        segments: &[&str],
+        query: &[(&str, &str)],
+    ) -> Result<Option<T>, RepositoryError>
+    where
+        T: DeserializeOwned,
+    {
+        let url = self.url(segments, query);
+        let response = self
+            .send(Method::GET, url.clone(), None, None, None)
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = Self::ensure_success(&Method::GET, &url, response).await?;
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| RepositoryError::ConnectionError(e.to_string()))?;
+        serde_json::from_slice(&bytes)
+            .map(Some)
+            .map_err(|e| RepositoryError::SerializationError(format!("{url}: {e}")))
+    }
+
+    /// JSONの一覧を取得する（404の場合は空）
+    pub async fn get_list<T>(
+        &self,
+        segments: &[&str],
+        query: &[(&str, &str)],
+    ) -> Result<Vec<T>, RepositoryError>
+    where
+        T: DeserializeOwned,
+    {
+        Ok(self.get_json(segments, query).await?.unwrap_or_default())
+    }
+
+    /// バイナリを送信し、JSONの応答を受け取る
+    ///
+    /// POSTは冪等ではないため、一時的なエラーでもリトライせず、アウトボックスにも記録しない。
+    pub async fn post_bytes<T>(
+        &self,
+        segments: &[&str],
+        body: Vec<u8>,
+    ) -> Result<T, RepositoryError>
+    where
+        T: DeserializeOwned,
+    {
+        let url = self.url(segments, &[]);
+        let response = self
+            .for_replay()
+            .send(
+                Method::POST,
+                url.clone(),
+                Some((BINARY_CONTENT_TYPE, body)),
+                None,
+                None,
+            )
+            .await?;
+        let response = Self::ensure_success(&Method::POST, &url, response).await?;
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| RepositoryError::ConnectionError(e.to_string()))?;
+        serde_json::from_slice(&bytes)
+            .map_err(|e| RepositoryError::SerializationError(format!("{url}: {e}")))
+    }
+
+    /// JSONを作成または置換する
+    ///
+    /// アウトボックスが設定されている場合、接続できなければアウトボックスに記録して成功を返す。
+    pub async fn put_json<T>(
+        &self,
+        segments: &[&str],
+        body: &T,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let body = serde_json::to_value(body)
+            .map_err(|e| RepositoryError::SerializationError(e.to_string()))?;
+        self.write(OutboxRequest::put(segments, body, user_id, timestamp))
+            .await?;
+        Ok(())
+    }
+
+    /// リソースを削除する
+    ///
+    /// 既に存在しない場合も成功として扱い、`false` を返す。
+    /// アウトボックスに記録した場合は `true` を返す。
+    pub async fn delete(
+        &self,
+        segments: &[&str],
+        query: &[(&str, &str)],
+    ) -> Result<bool, RepositoryError> {
+        self.write(OutboxRequest::delete(segments, query)).await
+    }
+
+    /// 書き込みを送信し、接続できない場合はアウトボックスに記録する
+    async fn write(&self, request: OutboxRequest) -> Result<bool, RepositoryError> {
+        let Some(outbox) = &self.outbox else {
+            return self.execute(&request, None).await;
+        };
+        // 未送信の書き込みが残っている間は、順序を保つため後続もアウトボックスに積む
+        if outbox.has_pending().await? {
+            outbox.enqueue(&request).await?;
+            return Ok(true);
+        }
+        match self.execute(&request, None).await {
+            Err(RepositoryError::ConnectionError(e)) => {
+                tracing::warn!(
+                    "サーバーに接続できないため、書き込みをアウトボックスに記録します: {}",
+                    e
+                );
+                outbox.enqueue(&request).await?;
+                Ok(true)
+            }
+            result => result,
+        }
+    }
+
+    /// 書き込みリクエストを1件送信する（削除で対象が存在しない場合は `false`）
+    pub(super) async fn execute(
+        &self,
+        request: &OutboxRequest,
+        idempotency_key: Option<&str>,
+    ) -> Result<bool, RepositoryError> {
+        let segments: Vec<&str> = request.segments.iter().map(String::as_str).collect();
+        let query: Vec<(&str, &str)> = request
+            .query
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        let url = self.url(&segments, &query);
+        let writer = match (&request.user_id, &request.timestamp) {
+            (Some(user_id), Some(timestamp)) => Some((user_id, timestamp)),
+            _ => None,
+        };
+
+        match request.operation {
+            OutboxOperationKind::Put => {
+                let body = serde_json::to_vec(&request.body)
+                    .map_err(|e| RepositoryError::SerializationError(e.to_string()))?;
+                let response = self
+                    .send(
+                        Method::PUT,
+                        url.clone(),
+                        Some((JSON_CONTENT_TYPE, body)),
+                        writer,
+                        idempotency_key,
+                    )
+                    .await?;
+                Self::ensure_success(&Method::PUT, &url, response).await?;
+                Ok(true)
+            }
+            OutboxOperationKind::Delete => {
+                let response = self
+                    .send(Method::DELETE, url.clone(), None, writer, idempotency_key)
+                    .await?;
+                if response.status() == StatusCode::NOT_FOUND {
+                    return Ok(false);
+                }
+                Self::ensure_success(&Method::DELETE, &url, response).await?;
+                Ok(true)
+            }
+        }
+    }
+
+    async fn ensure_success(
+        method: &Method,
+        url: &Url,
+        response: Response,
+    ) -> Result<Response, RepositoryError> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        let body = response.text().await.unwrap_or_default();
+        Err(status_error(method, url, status, &body))
+    }
+
+    /// 認証ヘッダーを付与して送信し、一時的なエラーはバックオフしながら再送する
+    async fn send(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<(&'static str, Vec<u8>)>,
+        writer: Option<(&UserId, &DateTime<Utc>)>,
+        idempotency_key: Option<&str>,
+    ) -> Result<Response, RepositoryError> {
+        let mut attempt = 0;
+        loop {
+            let mut request = self.http.request(method.clone(), url.clone());
+            if let Some(token) = &self.api_token {
+                request = request.header(AUTHORIZATION, format!("Bearer {token}"));
+            }
+            if let Some((user_id, timestamp)) = writer {
+                request = request.header(USER_ID_HEADER, user_id.to_string()).header(
+                    TIMESTAMP_HEADER,
+                    timestamp.to_rfc3339_opts(SecondsFormat::Millis, true),
+                );
+            }
+            if let Some(key) = idempotency_key {
+                request = request.header(IDEMPOTENCY_KEY_HEADER, key);
+            }
+            if let Some((content_type, body)) = &body {
+                request = request
+                    .header(CONTENT_TYPE, *content_type)
+                    .body(body.clone());
+            }
+
+            let can_retry = attempt < self.retry.max_retries;
+            let wait = match request.send().await {
+                Ok(response) if can_retry && is_retryable_status(response.status()) => {
+                    let retry_after = response
+                        .headers()
+                        .get(RETRY_AFTER)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.trim().parse::<u64>().ok())
+                        .map(Duration::from_secs);
+                    tracing::warn!(
+                        "{} {} -> {}、リトライします（{}/{}）",
+                        method,
+                        url,
+                        response.status(),
+                        attempt + 1,
+                        self.retry.max_retries
+                    );
+                    retry_after
+                        .map(|d| d.min(self.retry.max_backoff))
+                        .unwrap_or_else(|| self.retry.backoff(attempt))
+                }
+                Ok(response) => return Ok(response),
+                Err(e) if can_retry && (e.is_connect() || e.is_timeout() || e.is_request()) => {
+                    tracing::warn!(
+                        "{} {} に接続できません、リトライします（{}/{}）: {}",
+                        method,
+                        url,
+                        attempt + 1,
+                        self.retry.max_retries,
+                        e
+                    );
+                    self.retry.backoff(attempt)
+                }
+                Err(e) => {
+                    return Err(RepositoryError::ConnectionError(format!(
+                        "{method} {url}: {e}"
+                    )));
+                }
+            };
+            tokio::time::sleep(wait).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(base_url: &str) -> WebClient {
+        WebClient::new(&WebServerConfig::new(base_url, None)).unwrap()
+    }
+
+    #[test]
+    fn test_url_building() {
+        let url = client("http://localhost:8080").url(&["projects", "p 1", "tasks"], &[]);
+        assert_eq!(
+            url.as_str(),
+            "http://localhost:8080/api/v1/projects/p%201/tasks"
+        );
+
+        let url = client("http://localhost:8080/flequit/")
+            .url(&["subtask_recurrences"], &[("recurrence_rule_id", "r1")]);
+        assert_eq!(
+            url.as_str(),
+            "http://localhost:8080/flequit/api/v1/subtask_recurrences?recurrence_rule_id=r1"
+        );
+
+        assert!(WebClient::new(&WebServerConfig::new("not a url", None)).is_err());
+        assert!(WebClient::new(&WebServerConfig::new("mailto:a@example.com", None)).is_err());
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(400));
+        assert_eq!(policy.backoff(3), Duration::from_millis(500));
+        assert_eq!(policy.backoff(40), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_status_error_mapping() {
+        let url = Url::parse("http://localhost/api/v1/projects").unwrap();
+        let error = |status| status_error(&Method::GET, &url, status, "");
+        assert!(matches!(
+            error(StatusCode::UNAUTHORIZED),
+            RepositoryError::ConfigurationError(_)
+        ));
+        assert!(matches!(
+            error(StatusCode::CONFLICT),
+            RepositoryError::ConstraintViolation(_)
+        ));
+        assert!(matches!(
+            error(StatusCode::UNPROCESSABLE_ENTITY),
+            RepositoryError::ValidationError(_)
+        ));
+        assert!(matches!(
+            error(StatusCode::SERVICE_UNAVAILABLE),
+            RepositoryError::ConnectionError(_)
+        ));
+    }
+}
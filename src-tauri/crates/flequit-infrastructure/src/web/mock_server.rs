@@ -0,0 +1,302 @@
+//! テスト用のインメモリRESTサーバー
+//!
+//! Webリポジトリと同じパス構成を解釈し、JSONをパスごとに保持する。
+//! 認証トークンの検証と、一時的なエラー（503）・競合（409）の注入に対応する。
+
+use crate::config::WebServerConfig;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+/// 親ID/子IDの2階層で保存される関連付けコレクション
+const RELATION_COLLECTIONS: [&str; 6] = [
+    "task_tags",
+    "subtask_tags",
+    "task_assignments",
+    "subtask_assignments",
+    "task_recurrences",
+    "subtask_recurrences",
+];
+
+/// サーバーが受け取ったリクエストの記録
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RecordedRequest {
+    pub method: String,
+    /// `/api/v1/` を除いたパス
+    pub path: String,
+    pub user_id: Option<String>,
+    pub timestamp: Option<String>,
+    pub idempotency_key: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    token: Option<String>,
+    failures_remaining: u32,
+    conflicts_remaining: u32,
+    items: BTreeMap<String, Value>,
+    requests: Vec<RecordedRequest>,
+}
+
+struct ParsedRequest {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl ParsedRequest {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub(crate) struct MockWebServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    task: JoinHandle<()>,
+}
+
+impl MockWebServer {
+    /// ループバックの空きポートで起動する
+    pub async fn start(token: Option<&str>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let state = Arc::new(Mutex::new(MockState {
+            token: token.map(str::to_string),
+            ..MockState::default()
+        }));
+        let task_state = state.clone();
+        let task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let state = task_state.clone();
+                tokio::spawn(async move {
+                    let _ = handle_connection(stream, state).await;
+                });
+            }
+        });
+        Self { addr, state, task }
+    }
+
+    /// このサーバーに接続する設定（トークンは引数で指定）
+    pub fn config(&self, token: Option<&str>) -> WebServerConfig {
+        WebServerConfig::new(format!("http://{}", self.addr), token.map(str::to_string))
+    }
+
+    /// 次の `count` 件のリクエストに503を返す
+    pub fn fail_next(&self, count: u32) {
+        self.state.lock().unwrap().failures_remaining = count;
+    }
+
+    /// 次の `count` 件のリクエストに409を返す
+    pub fn conflict_next(&self, count: u32) {
+        self.state.lock().unwrap().conflicts_remaining = count;
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    pub fn item(&self, path: &str) -> Option<Value> {
+        self.state.lock().unwrap().items.get(path).cloned()
+    }
+}
+
+impl Drop for MockWebServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    state: Arc<Mutex<MockState>>,
+) -> std::io::Result<()> {
+    let Some(request) = read_request(&mut stream).await? else {
+        return Ok(());
+    };
+    let (status, body) = respond(&state, request);
+    let head = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nRetry-After: 0\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body.as_bytes()).await?;
+    stream.flush().await
+}
+
+async fn read_request(stream: &mut TcpStream) -> std::io::Result<Option<ParsedRequest>> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(pos) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let target = request_line.next().unwrap_or_default();
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect();
+
+    let content_length = headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, v)| v.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = buffer[header_end..].to_vec();
+    while body.len() < content_length {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+
+    Ok(Some(ParsedRequest {
+        method,
+        path: path.to_string(),
+        query,
+        headers,
+        body,
+    }))
+}
+
+/// GETで一覧を返す場合の、基準パスから見た子孫の階層数
+fn list_depth(segments: &[&str]) -> Option<usize> {
+    let is_relation = |collection: &str| RELATION_COLLECTIONS.contains(&collection);
+    match segments {
+        [_] => Some(1),
+        ["projects", _, collection] => Some(if is_relation(collection) { 2 } else { 1 }),
+        ["projects", _, collection, _] if is_relation(collection) => Some(1),
+        _ => None,
+    }
+}
+
+fn matches_query(value: &Value, query: &[(String, String)]) -> bool {
+    query
+        .iter()
+        .all(|(key, expected)| value.get(key).and_then(Value::as_str) == Some(expected))
+}
+
+fn respond(state: &Mutex<MockState>, request: ParsedRequest) -> (&'static str, String) {
+    let mut state = state.lock().unwrap();
+
+    if let Some(token) = &state.token {
+        let expected = format!("Bearer {token}");
+        if request.header("Authorization") != Some(expected.as_str()) {
+            return (
+                "401 Unauthorized",
+                r#"{"error":"unauthorized"}"#.to_string(),
+            );
+        }
+    }
+    if state.failures_remaining > 0 {
+        state.failures_remaining -= 1;
+        return ("503 Service Unavailable", String::new());
+    }
+    if state.conflicts_remaining > 0 {
+        state.conflicts_remaining -= 1;
+        return ("409 Conflict", r#"{"error":"conflict"}"#.to_string());
+    }
+
+    let Some(key) = request.path.strip_prefix("/api/v1/") else {
+        return ("404 Not Found", String::new());
+    };
+    let key = key.trim_end_matches('/').to_string();
+    state.requests.push(RecordedRequest {
+        method: request.method.clone(),
+        path: key.clone(),
+        user_id: request.header("X-Flequit-User-Id").map(str::to_string),
+        timestamp: request.header("X-Flequit-Timestamp").map(str::to_string),
+        idempotency_key: request.header("Idempotency-Key").map(str::to_string),
+    });
+
+    let segments: Vec<&str> = key.split('/').collect();
+    let prefix = format!("{key}/");
+    let descendants = |items: &BTreeMap<String, Value>, depth: usize| -> Vec<String> {
+        items
+            .keys()
+            .filter(|k| {
+                k.strip_prefix(&prefix)
+                    .is_some_and(|rest| rest.split('/').count() == depth)
+            })
+            .cloned()
+            .collect()
+    };
+
+    match request.method.as_str() {
+        "GET" => match list_depth(&segments) {
+            Some(depth) => {
+                let list: Vec<Value> = descendants(&state.items, depth)
+                    .iter()
+                    .filter_map(|k| state.items.get(k))
+                    .filter(|v| matches_query(v, &request.query))
+                    .cloned()
+                    .collect();
+                ("200 OK", Value::Array(list).to_string())
+            }
+            None => match state.items.get(&key) {
+                Some(value) => ("200 OK", value.to_string()),
+                None => ("404 Not Found", String::new()),
+            },
+        },
+        "PUT" => match serde_json::from_slice::<Value>(&request.body) {
+            Ok(value) => {
+                state.items.insert(key, value);
+                ("204 No Content", String::new())
+            }
+            Err(e) => ("400 Bad Request", e.to_string()),
+        },
+        "DELETE" => {
+            let targets: Vec<String> = match request.query.first() {
+                Some((name, child_id)) if name == "child_id" => descendants(&state.items, 2)
+                    .into_iter()
+                    .filter(|k| k.rsplit('/').next() == Some(child_id.as_str()))
+                    .collect(),
+                Some(_) => descendants(&state.items, 1)
+                    .into_iter()
+                    .filter(|k| matches_query(&state.items[k], &request.query))
+                    .collect(),
+                None => state
+                    .items
+                    .keys()
+                    .filter(|k| **k == key || k.starts_with(&prefix))
+                    .cloned()
+                    .collect(),
+            };
+            if targets.is_empty() {
+                return ("404 Not Found", String::new());
+            }
+            for target in targets {
+                state.items.remove(&target);
+            }
+            ("204 No Content", String::new())
+        }
+        _ => ("405 Method Not Allowed", String::new()),
+    }
+}
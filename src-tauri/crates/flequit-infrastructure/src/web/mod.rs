@@ -0,0 +1,256 @@
+//! セルフホストFlequitサーバー用のWebリポジトリ
+//!
+//! 各エンティティをREST/JSONで保存・取得する。Unified層には
+//! `InfrastructureConfig` の `web_storage_enabled` / `web_search_enabled` に従って登録される。
+//!
+//! パス構成（全て `/api/v1/` 以下）:
+//! - `accounts` / `users` / `projects`: `{collection}/{id}`
+//! - プロジェクト配下のエンティティ: `projects/{project_id}/{collection}/{id}`
+//! - 関連付け: `projects/{project_id}/{collection}/{parent_id}/{child_id}`
+//!   （`{parent_id}` へのGET/DELETEで親単位の一覧取得・一括削除、
+//!   コレクションへの `?child_id=` 付きDELETEで子単位の一括削除）
+//! - Automerge同期メッセージの中継: `projects/{project_id}/sync`（[`SyncRelayClient`]）
+//!
+//! 保存はPUT（作成・置換）で行い、書き込みユーザーと時刻を
+//! `X-Flequit-User-Id` / `X-Flequit-Timestamp` ヘッダーで送信する。
+//! 認証は `Authorization: Bearer {api_token}`。
+//!
+//! アウトボックス（[`WebOutbox`]）を設定したクライアントでは、接続できない間の書き込みを
+//! SQLiteに記録し、再接続後に記録順に再送する（`Idempotency-Key` ヘッダー付き）。
+
+pub mod client;
+pub mod outbox;
+pub mod repository;
+pub mod resources;
+pub mod sync_relay;
+
+#[cfg(test)]
+pub(crate) mod mock_server;
+
+pub use client::{RetryPolicy, WebClient};
+pub use outbox::{
+    OutboxFlushReport, OutboxFlusher, OutboxPolicy, OutboxRequest, OutboxSummary, WebOutbox,
+};
+pub use repository::{
+    WebEntity, WebProjectRelationRepository, WebProjectRepository, WebRelation, WebRepository,
+    WebResource,
+};
+pub use resources::*;
+pub use sync_relay::{RelayedSyncMessage, SyncRelayClient, SyncRelayPage};
+
+#[cfg(test)]
+mod tests {
+    use super::mock_server::MockWebServer;
+    use super::*;
+    use crate::unified::TaskUnifiedRepository;
+    use chrono::Utc;
+    use flequit_model::models::task_projects::task::Task;
+    use flequit_model::types::id_types::{ProjectId, SubTaskId, TagId, TaskId, TaskListId, UserId};
+    use flequit_model::types::task_types::TaskStatus;
+    use flequit_repository::repositories::project_relation_repository_trait::ProjectRelationRepository;
+    use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+    use flequit_types::errors::repository_error::RepositoryError;
+    use std::time::Duration;
+
+    const TOKEN: &str = "secret-token";
+
+    fn fast_retry(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+        }
+    }
+
+    fn client(server: &MockWebServer, token: Option<&str>) -> WebClient {
+        WebClient::new(&server.config(token))
+            .unwrap()
+            .with_retry_policy(fast_retry(2))
+    }
+
+    fn task(project_id: ProjectId, title: &str) -> Task {
+        let now = Utc::now();
+        Task {
+            id: TaskId::new(),
+            project_id,
+            list_id: TaskListId::new(),
+            title: title.to_string(),
+            description: None,
+            status: TaskStatus::NotStarted,
+            workflow_status_id: None,
+            priority: 0,
+            plan_start_date: None,
+            plan_end_date: None,
+            do_start_date: None,
+            do_end_date: None,
+            is_range_date: None,
+            recurrence_rule: None,
+            order_index: 0,
+            is_archived: false,
+            assigned_user_ids: Vec::new(),
+            tag_ids: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_project_repository_round_trip() {
+        let server = MockWebServer::start(Some(TOKEN)).await;
+        let repo = TaskWebRepository::new(client(&server, Some(TOKEN)));
+        let project_id = ProjectId::new();
+        let user_id = UserId::new();
+        let mut task = task(project_id, "Write docs");
+
+        repo.save(&project_id, &task, &user_id, &Utc::now())
+            .await
+            .unwrap();
+        let found = repo
+            .find_by_id(&project_id, &task.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.title, "Write docs");
+        assert_eq!(repo.count(&project_id).await.unwrap(), 1);
+
+        task.title = "Write more docs".to_string();
+        repo.save(&project_id, &task, &user_id, &Utc::now())
+            .await
+            .unwrap();
+        let all = repo.find_all(&project_id).await.unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].title, "Write more docs");
+
+        let put = server
+            .requests()
+            .into_iter()
+            .find(|r| r.method == "PUT")
+            .unwrap();
+        assert_eq!(
+            put.path,
+            format!("projects/{}/tasks/{}", project_id, task.id)
+        );
+        assert_eq!(put.user_id, Some(user_id.to_string()));
+        assert!(put.timestamp.is_some());
+
+        repo.delete(&project_id, &task.id).await.unwrap();
+        assert!(!repo.exists(&project_id, &task.id).await.unwrap());
+        // 存在しないリソースの削除は成功扱い
+        repo.delete(&project_id, &task.id).await.unwrap();
+        assert!(repo.find_all(&project_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_relation_repository() {
+        let server = MockWebServer::start(None).await;
+        let repo = SubTaskTagWebRepository::new(client(&server, None));
+        let project_id = ProjectId::new();
+        let user_id = UserId::new();
+        let now = Utc::now();
+        let (subtask_a, subtask_b) = (SubTaskId::new(), SubTaskId::new());
+        let (tag_x, tag_y) = (TagId::new(), TagId::new());
+
+        repo.add_all(
+            &project_id,
+            &[(subtask_a, tag_x), (subtask_a, tag_y), (subtask_b, tag_x)],
+            &user_id,
+            &now,
+        )
+        .await
+        .unwrap();
+
+        let relation = repo
+            .find_relation(&project_id, &subtask_a, &tag_y)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(relation.updated_by, user_id);
+        assert_eq!(repo.count(&project_id, &subtask_a).await.unwrap(), 2);
+        assert_eq!(repo.find_all(&project_id).await.unwrap().len(), 3);
+
+        repo.remove_all_by_child(&project_id, &tag_x).await.unwrap();
+        let remaining = repo.find_all(&project_id).await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].tag_id, tag_y);
+        assert!(!repo.exists(&project_id, &subtask_b).await.unwrap());
+
+        repo.remove_all(&project_id, &subtask_a).await.unwrap();
+        assert!(repo.find_all(&project_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_failures() {
+        let server = MockWebServer::start(None).await;
+        let project_id = ProjectId::new();
+        let task = task(project_id, "Retry");
+
+        server.fail_next(2);
+        let repo = TaskWebRepository::new(client(&server, None));
+        repo.save(&project_id, &task, &UserId::new(), &Utc::now())
+            .await
+            .unwrap();
+        assert!(
+            server
+                .item(&format!("projects/{}/tasks/{}", project_id, task.id))
+                .is_some()
+        );
+
+        server.fail_next(3);
+        let error = repo.find_all(&project_id).await.unwrap_err();
+        assert!(matches!(error, RepositoryError::ConnectionError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_auth_failure_is_not_retried() {
+        let server = MockWebServer::start(Some(TOKEN)).await;
+        let repo = TaskWebRepository::new(client(&server, Some("wrong")));
+        let error = repo.find_all(&ProjectId::new()).await.unwrap_err();
+        assert!(matches!(error, RepositoryError::ConfigurationError(_)));
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_connection_refused() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let config = crate::config::WebServerConfig::new(format!("http://{addr}"), None);
+        let repo = TaskWebRepository::new(
+            WebClient::new(&config)
+                .unwrap()
+                .with_retry_policy(fast_retry(1)),
+        );
+        let error = repo.find_all(&ProjectId::new()).await.unwrap_err();
+        assert!(matches!(error, RepositoryError::ConnectionError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_unified_repository_with_web_backend() {
+        let server = MockWebServer::start(Some(TOKEN)).await;
+        let web_client = client(&server, Some(TOKEN));
+        let mut unified = TaskUnifiedRepository::default();
+        unified.add_web_for_save(TaskWebRepository::new(web_client.clone()));
+        unified.add_web_for_search(TaskWebRepository::new(web_client));
+
+        let project_id = ProjectId::new();
+        let task = task(project_id, "Unified");
+        unified
+            .save(&project_id, &task, &UserId::new(), &Utc::now())
+            .await
+            .unwrap();
+        assert!(unified.exists(&project_id, &task.id).await.unwrap());
+        assert_eq!(unified.find_all(&project_id).await.unwrap().len(), 1);
+
+        unified.delete(&project_id, &task.id).await.unwrap();
+        assert!(
+            unified
+                .find_by_id(&project_id, &task.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
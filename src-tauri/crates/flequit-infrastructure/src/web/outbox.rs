@@ -0,0 +1,674 @@
+//! オフライン時の書き込みを保持するアウトボックス
+//!
+//! サーバーに接続できない間の書き込みは、エラーにせずSQLiteのアウトボックスに記録する。
+//! 再接続後に `flush` で記録順に再送し、結果に応じて次のように扱う。
+//!
+//! - 成功（削除対象が既に無い場合を含む）: アウトボックスから削除
+//! - 競合（409/412、置換対象の消失）: 競合としてキューから外し、ユーザーの判断を待つ
+//! - 不正なリクエスト（400/422）: デッドレターとしてキューから外す
+//! - 接続できない・認証エラー: 指数バックオフで再送日時を延ばし、そこで再送を中断する。
+//!   失敗回数が上限に達した操作はデッドレターとする
+//!
+//! 競合・デッドレターの操作は `retry` でキューの末尾に戻すか、`discard` で破棄する。
+
+use super::client::WebClient;
+use crate::InfrastructureRepositories;
+use chrono::{DateTime, Utc};
+use flequit_infrastructure_sqlite::infrastructure::sync::outbox::OutboxLocalSqliteRepository;
+use flequit_model::models::sync::outbox_operation::{
+    NewOutboxOperation, OutboxOperation, OutboxOperationKind, OutboxStatus,
+};
+use flequit_model::types::id_types::{ProjectId, UserId};
+use flequit_types::errors::repository_error::RepositoryError;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, RwLock};
+use tokio::task::JoinHandle;
+
+/// 再送時に冪等キーを伝えるヘッダー
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// 再送の方針
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutboxPolicy {
+    /// デッドレターとするまでの最大試行回数
+    pub max_attempts: u32,
+    /// 初回の再送失敗後の待機時間（以降は倍々に増加）
+    pub initial_backoff: Duration,
+    /// 待機時間の上限
+    pub max_backoff: Duration,
+}
+
+impl OutboxPolicy {
+    /// `failures` 回失敗した後の待機時間
+    pub fn backoff(&self, failures: u32) -> Duration {
+        let factor = 2u32.saturating_pow(failures.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for OutboxPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 20,
+            initial_backoff: Duration::from_secs(5),
+            max_backoff: Duration::from_secs(30 * 60),
+        }
+    }
+}
+
+/// アウトボックスに記録する書き込みリクエスト（`payload` にJSONで保存される）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxRequest {
+    pub operation: OutboxOperationKind,
+    /// `/api/v1/` 以下のパスセグメント
+    pub segments: Vec<String>,
+    #[serde(default)]
+    pub query: Vec<(String, String)>,
+    #[serde(default)]
+    pub body: Option<serde_json::Value>,
+    #[serde(default)]
+    pub user_id: Option<UserId>,
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+impl OutboxRequest {
+    pub fn put(
+        segments: &[&str],
+        body: serde_json::Value,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Self {
+        Self {
+            operation: OutboxOperationKind::Put,
+            segments: segments.iter().map(|s| s.to_string()).collect(),
+            query: Vec::new(),
+            body: Some(body),
+            user_id: Some(*user_id),
+            timestamp: Some(*timestamp),
+        }
+    }
+
+    pub fn delete(segments: &[&str], query: &[(&str, &str)]) -> Self {
+        Self {
+            operation: OutboxOperationKind::Delete,
+            segments: segments.iter().map(|s| s.to_string()).collect(),
+            query: query
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            body: None,
+            user_id: None,
+            timestamp: None,
+        }
+    }
+
+    /// 対象エンティティのコレクション名とプロジェクトID
+    fn target(&self) -> (String, Option<ProjectId>) {
+        match self.segments.as_slice() {
+            [projects, project_id, collection, ..] if projects == "projects" => (
+                collection.clone(),
+                Some(ProjectId::from(project_id.as_str())),
+            ),
+            [projects, project_id] if projects == "projects" => {
+                (projects.clone(), Some(ProjectId::from(project_id.as_str())))
+            }
+            [collection, ..] => (collection.clone(), None),
+            [] => (String::new(), None),
+        }
+    }
+}
+
+/// アウトボックス内の操作数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutboxSummary {
+    pub pending: u64,
+    pub conflicts: u64,
+    pub dead_letters: u64,
+}
+
+/// 再送の結果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OutboxFlushReport {
+    /// 送信に成功した操作数
+    pub sent: usize,
+    /// 今回の再送で競合した操作
+    pub conflicts: Vec<OutboxOperation>,
+    /// 今回の再送でデッドレターになった操作
+    pub dead_letters: Vec<OutboxOperation>,
+    /// 再送待ちのまま残っている操作数
+    pub pending: u64,
+    /// 次に再送を試みる日時（再送待ちが無い場合は `None`）
+    pub next_attempt_at: Option<DateTime<Utc>>,
+}
+
+impl OutboxFlushReport {
+    /// ユーザーに知らせるべき操作があるか
+    pub fn needs_attention(&self) -> bool {
+        !self.conflicts.is_empty() || !self.dead_letters.is_empty()
+    }
+}
+
+/// 再送結果の分類
+enum ReplayOutcome {
+    Sent,
+    Failed(OutboxStatus, String),
+    /// 接続できないため、再送を中断する
+    Unavailable(String),
+}
+
+fn classify(operation: &OutboxOperation, result: Result<bool, RepositoryError>) -> ReplayOutcome {
+    match result {
+        Ok(_) => ReplayOutcome::Sent,
+        Err(RepositoryError::ConstraintViolation(e)) => {
+            ReplayOutcome::Failed(OutboxStatus::Conflict, e)
+        }
+        // 置換対象（プロジェクトなど）がサーバー側で削除されている
+        Err(RepositoryError::NotFound(e)) if operation.operation == OutboxOperationKind::Put => {
+            ReplayOutcome::Failed(OutboxStatus::Conflict, e)
+        }
+        Err(RepositoryError::ValidationError(e) | RepositoryError::SerializationError(e)) => {
+            ReplayOutcome::Failed(OutboxStatus::DeadLetter, e)
+        }
+        Err(e) => ReplayOutcome::Unavailable(e.to_string()),
+    }
+}
+
+/// アウトボックス
+///
+/// `clone` したものは同じキューを共有する。
+#[derive(Debug, Clone)]
+pub struct WebOutbox {
+    repository: OutboxLocalSqliteRepository,
+    policy: OutboxPolicy,
+    /// 再送の多重実行を防ぐ
+    flush_lock: Arc<Mutex<()>>,
+}
+
+impl WebOutbox {
+    pub fn new(repository: OutboxLocalSqliteRepository) -> Self {
+        Self {
+            repository,
+            policy: OutboxPolicy::default(),
+            flush_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// 再送の方針を差し替える
+    pub fn with_policy(mut self, policy: OutboxPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn policy(&self) -> &OutboxPolicy {
+        &self.policy
+    }
+
+    /// 再送待ちの操作があるか
+    pub async fn has_pending(&self) -> Result<bool, RepositoryError> {
+        Ok(self.repository.next_pending().await?.is_some())
+    }
+
+    /// 書き込みリクエストを記録する
+    pub async fn enqueue(
+        &self,
+        request: &OutboxRequest,
+    ) -> Result<OutboxOperation, RepositoryError> {
+        let (entity, project_id) = request.target();
+        let payload = serde_json::to_string(request)
+            .map_err(|e| RepositoryError::SerializationError(e.to_string()))?;
+        let operation = NewOutboxOperation {
+            idempotency_key: uuid::Uuid::new_v4().to_string(),
+            entity,
+            operation: request.operation,
+            project_id,
+            payload,
+        };
+        self.repository.enqueue(&operation, Utc::now()).await
+    }
+
+    pub async fn summary(&self) -> Result<OutboxSummary, RepositoryError> {
+        Ok(OutboxSummary {
+            pending: self
+                .repository
+                .count_by_status(OutboxStatus::Pending)
+                .await?,
+            conflicts: self
+                .repository
+                .count_by_status(OutboxStatus::Conflict)
+                .await?,
+            dead_letters: self
+                .repository
+                .count_by_status(OutboxStatus::DeadLetter)
+                .await?,
+        })
+    }
+
+    /// 操作を記録順に取得（`None` の場合は全件）
+    pub async fn operations(
+        &self,
+        status: Option<OutboxStatus>,
+    ) -> Result<Vec<OutboxOperation>, RepositoryError> {
+        self.repository.find_by_status(status).await
+    }
+
+    /// 競合・デッドレターの操作をキューの末尾に戻す
+    pub async fn retry(&self, seq: i64) -> Result<OutboxOperation, RepositoryError> {
+        let _guard = self.flush_lock.lock().await;
+        let operation = self
+            .repository
+            .find_by_seq(seq)
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound(format!("Outbox operation {seq}")))?;
+        if operation.status == OutboxStatus::Pending {
+            return Err(RepositoryError::InvalidOperation(format!(
+                "Outbox operation {seq} is already pending"
+            )));
+        }
+        self.repository.requeue(seq, Utc::now()).await
+    }
+
+    /// 操作を送信せずに破棄する
+    pub async fn discard(&self, seq: i64) -> Result<bool, RepositoryError> {
+        let _guard = self.flush_lock.lock().await;
+        self.repository.remove(seq).await
+    }
+
+    /// 再送日時を迎えた操作を記録順に再送する
+    pub async fn flush(&self, client: &WebClient) -> Result<OutboxFlushReport, RepositoryError> {
+        self.flush_inner(client, false).await
+    }
+
+    /// 再送日時を待たずに再送する（ユーザー操作による再送）
+    pub async fn flush_now(
+        &self,
+        client: &WebClient,
+    ) -> Result<OutboxFlushReport, RepositoryError> {
+        self.flush_inner(client, true).await
+    }
+
+    async fn flush_inner(
+        &self,
+        client: &WebClient,
+        force: bool,
+    ) -> Result<OutboxFlushReport, RepositoryError> {
+        let _guard = self.flush_lock.lock().await;
+        // 再送自体は1回ずつ行い、間隔はアウトボックスのバックオフで制御する
+        let client = client.for_replay();
+        let mut report = OutboxFlushReport::default();
+
+        while let Some(operation) = self.repository.next_pending().await? {
+            let now = Utc::now();
+            if !force && operation.next_attempt_at > now {
+                report.next_attempt_at = Some(operation.next_attempt_at);
+                break;
+            }
+
+            let result = match serde_json::from_str::<OutboxRequest>(&operation.payload) {
+                Ok(request) => {
+                    client
+                        .execute(&request, Some(&operation.idempotency_key))
+                        .await
+                }
+                Err(e) => Err(RepositoryError::SerializationError(e.to_string())),
+            };
+
+            match classify(&operation, result) {
+                ReplayOutcome::Sent => {
+                    self.repository.remove(operation.seq).await?;
+                    report.sent += 1;
+                }
+                ReplayOutcome::Failed(status, error) => {
+                    tracing::warn!(
+                        "アウトボックスの操作 #{} ({} {}) を再送できません: {}",
+                        operation.seq,
+                        operation.operation,
+                        operation.entity,
+                        error
+                    );
+                    let failed = self
+                        .repository
+                        .mark_failed(operation.seq, status, &error, now)
+                        .await?;
+                    match status {
+                        OutboxStatus::Conflict => report.conflicts.push(failed),
+                        _ => report.dead_letters.push(failed),
+                    }
+                }
+                ReplayOutcome::Unavailable(error) => {
+                    let failures = operation.attempts + 1;
+                    if failures >= self.policy.max_attempts {
+                        tracing::warn!(
+                            "アウトボックスの操作 #{} を{}回再送できなかったため、デッドレターにしました: {}",
+                            operation.seq,
+                            failures,
+                            error
+                        );
+                        let failed = self
+                            .repository
+                            .mark_failed(operation.seq, OutboxStatus::DeadLetter, &error, now)
+                            .await?;
+                        report.dead_letters.push(failed);
+                        continue;
+                    }
+                    let backoff = chrono::Duration::from_std(self.policy.backoff(failures))
+                        .unwrap_or(chrono::Duration::MAX);
+                    let next_attempt_at = now + backoff;
+                    self.repository
+                        .record_failure(operation.seq, &error, next_attempt_at, now)
+                        .await?;
+                    report.next_attempt_at = Some(next_attempt_at);
+                    break;
+                }
+            }
+        }
+
+        report.pending = self
+            .repository
+            .count_by_status(OutboxStatus::Pending)
+            .await?;
+        if report.pending == 0 {
+            report.next_attempt_at = None;
+        }
+        Ok(report)
+    }
+}
+
+/// アウトボックスの定期再送
+pub struct OutboxFlusher;
+
+impl OutboxFlusher {
+    /// `interval` ごとにアウトボックスを再送するタスクを起動する
+    ///
+    /// 競合・デッドレターが発生した場合は `on_attention` に結果を渡す。
+    pub fn spawn<F>(
+        repositories: Arc<RwLock<InfrastructureRepositories>>,
+        interval: Duration,
+        on_attention: F,
+    ) -> JoinHandle<()>
+    where
+        F: Fn(&OutboxFlushReport) + Send + Sync + 'static,
+    {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+
+                let repositories = repositories.read().await;
+                let Some((client, outbox)) = repositories.outbox_client() else {
+                    continue;
+                };
+                match outbox.flush(client).await {
+                    Ok(report) => {
+                        if report.sent > 0 {
+                            tracing::info!(
+                                "アウトボックスから{}件を再送しました（残り{}件）",
+                                report.sent,
+                                report.pending
+                            );
+                        }
+                        if report.needs_attention() {
+                            on_attention(&report);
+                        }
+                    }
+                    Err(e) => tracing::error!("Outbox flush failed: {}", e),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock_server::MockWebServer;
+    use super::super::{RetryPolicy, TaskWebRepository};
+    use super::*;
+    use flequit_infrastructure_sqlite::infrastructure::database_manager::DatabaseManager;
+    use flequit_model::models::task_projects::task::Task;
+    use flequit_model::types::id_types::{TaskId, TaskListId};
+    use flequit_model::types::task_types::TaskStatus;
+    use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+    use flequit_testing::TestPathGenerator;
+
+    fn outbox(test_name: &str) -> WebOutbox {
+        let dir = TestPathGenerator::generate_test_dir(file!(), test_name);
+        let db_manager =
+            DatabaseManager::new_for_test(dir.join("outbox.sqlite").to_string_lossy().to_string());
+        WebOutbox::new(OutboxLocalSqliteRepository::new(Arc::new(RwLock::new(
+            db_manager,
+        ))))
+        .with_policy(OutboxPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        })
+    }
+
+    fn client(server: &MockWebServer, outbox: &WebOutbox) -> WebClient {
+        WebClient::new(&server.config(None))
+            .unwrap()
+            .with_retry_policy(RetryPolicy::new(0))
+            .with_outbox(outbox.clone())
+    }
+
+    fn task(project_id: ProjectId, title: &str) -> Task {
+        let now = Utc::now();
+        Task {
+            id: TaskId::new(),
+            project_id,
+            list_id: TaskListId::new(),
+            title: title.to_string(),
+            description: None,
+            status: TaskStatus::NotStarted,
+            workflow_status_id: None,
+            priority: 0,
+            plan_start_date: None,
+            plan_end_date: None,
+            do_start_date: None,
+            do_end_date: None,
+            is_range_date: None,
+            recurrence_rule: None,
+            order_index: 0,
+            is_archived: false,
+            assigned_user_ids: Vec::new(),
+            tag_ids: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        }
+    }
+
+    fn task_path(task: &Task) -> String {
+        format!("projects/{}/tasks/{}", task.project_id, task.id)
+    }
+
+    #[test]
+    fn test_backoff_and_target() {
+        let policy = OutboxPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(10),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(4), Duration::from_secs(8));
+        assert_eq!(policy.backoff(5), Duration::from_secs(10));
+
+        let project_id = ProjectId::new();
+        let project = project_id.to_string();
+        let request = OutboxRequest::delete(&["projects", &project, "task_tags", "t1"], &[]);
+        assert_eq!(
+            request.target(),
+            ("task_tags".to_string(), Some(project_id))
+        );
+        let request = OutboxRequest::delete(&["projects", &project], &[]);
+        assert_eq!(request.target(), ("projects".to_string(), Some(project_id)));
+        let request = OutboxRequest::delete(&["users", "u1"], &[]);
+        assert_eq!(request.target(), ("users".to_string(), None));
+    }
+
+    #[tokio::test]
+    async fn test_offline_writes_are_queued_and_replayed_in_order() {
+        let server = MockWebServer::start(None).await;
+        let outbox = outbox("test_offline_writes_are_queued_and_replayed_in_order");
+        let repo = TaskWebRepository::new(client(&server, &outbox));
+        let project_id = ProjectId::new();
+        let user_id = UserId::new();
+        let first = task(project_id, "First");
+        let second = task(project_id, "Second");
+
+        // オフライン中の書き込みはエラーにならずアウトボックスに記録される
+        server.fail_next(1);
+        repo.save(&project_id, &first, &user_id, &Utc::now())
+            .await
+            .unwrap();
+        // 未送信の操作が残っている間は、接続できても順序を保つためキューに積む
+        repo.save(&project_id, &second, &user_id, &Utc::now())
+            .await
+            .unwrap();
+        repo.delete(&project_id, &first.id).await.unwrap();
+        assert!(server.requests().is_empty());
+
+        let queued = outbox.operations(None).await.unwrap();
+        assert_eq!(queued.len(), 3);
+        assert_eq!(queued[0].entity, "tasks");
+        assert_eq!(queued[0].project_id, Some(project_id));
+        assert_eq!(queued[2].operation, OutboxOperationKind::Delete);
+
+        let report = client(&server, &outbox).flush_outbox().await.unwrap();
+        assert_eq!(report.sent, 3);
+        assert_eq!(report.pending, 0);
+        assert!(!report.needs_attention());
+
+        let requests = server.requests();
+        let sequence: Vec<(&str, String)> = requests
+            .iter()
+            .map(|r| (r.method.as_str(), r.path.clone()))
+            .collect();
+        assert_eq!(
+            sequence,
+            vec![
+                ("PUT", task_path(&first)),
+                ("PUT", task_path(&second)),
+                ("DELETE", task_path(&first)),
+            ]
+        );
+        assert!(requests.iter().all(|r| r.idempotency_key.is_some()));
+        assert_eq!(requests[0].user_id, Some(user_id.to_string()));
+        assert!(server.item(&task_path(&first)).is_none());
+        assert!(server.item(&task_path(&second)).is_some());
+
+        // キューが空になれば直接送信に戻る
+        repo.save(&project_id, &first, &user_id, &Utc::now())
+            .await
+            .unwrap();
+        assert!(server.item(&task_path(&first)).is_some());
+        assert!(!outbox.has_pending().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_backoff_and_dead_letter() {
+        let server = MockWebServer::start(None).await;
+        let outbox = outbox("test_backoff_and_dead_letter").with_policy(OutboxPolicy {
+            max_attempts: 2,
+            initial_backoff: Duration::from_secs(60),
+            max_backoff: Duration::from_secs(60),
+        });
+        let client = client(&server, &outbox);
+        let project_id = ProjectId::new();
+        let first = task(project_id, "First");
+        let second = task(project_id, "Second");
+
+        server.fail_next(1);
+        let repo = TaskWebRepository::new(client.clone());
+        repo.save(&project_id, &first, &UserId::new(), &Utc::now())
+            .await
+            .unwrap();
+        repo.save(&project_id, &second, &UserId::new(), &Utc::now())
+            .await
+            .unwrap();
+
+        // 先頭の再送に失敗すると、バックオフしてそこで中断する
+        server.fail_next(1);
+        let report = client.flush_outbox().await.unwrap();
+        assert_eq!(report.sent, 0);
+        assert_eq!(report.pending, 2);
+        let next_attempt_at = report.next_attempt_at.unwrap();
+        assert!(next_attempt_at > Utc::now() + chrono::Duration::seconds(30));
+        let head = &outbox.operations(None).await.unwrap()[0];
+        assert_eq!(head.attempts, 1);
+        assert!(head.last_error.is_some());
+
+        // 再送日時までは送信しない
+        let report = client.flush_outbox().await.unwrap();
+        assert_eq!(report.sent, 0);
+        assert_eq!(report.next_attempt_at, Some(next_attempt_at));
+        assert!(server.requests().is_empty());
+
+        // 上限に達した操作はデッドレターとなり、後続の送信は続行する
+        server.fail_next(1);
+        let report = outbox.flush_now(&client).await.unwrap();
+        assert_eq!(report.dead_letters.len(), 1);
+        assert_eq!(report.dead_letters[0].status, OutboxStatus::DeadLetter);
+        assert_eq!(report.sent, 1);
+        assert!(server.item(&task_path(&first)).is_none());
+        assert!(server.item(&task_path(&second)).is_some());
+        assert_eq!(
+            outbox.summary().await.unwrap(),
+            OutboxSummary {
+                pending: 0,
+                conflicts: 0,
+                dead_letters: 1,
+            }
+        );
+
+        // デッドレターを再送キューに戻して送信する
+        let seq = report.dead_letters[0].seq;
+        assert!(outbox.retry(seq).await.unwrap().seq > seq);
+        let report = client.flush_outbox().await.unwrap();
+        assert_eq!(report.sent, 1);
+        assert!(server.item(&task_path(&first)).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_conflict_is_reported_without_failing_save() {
+        let server = MockWebServer::start(None).await;
+        let outbox = outbox("test_conflict_is_reported_without_failing_save");
+        let client = client(&server, &outbox);
+        let repo = TaskWebRepository::new(client.clone());
+        let project_id = ProjectId::new();
+        let conflicting = task(project_id, "Conflicting");
+        let other = task(project_id, "Other");
+
+        server.fail_next(1);
+        repo.save(&project_id, &conflicting, &UserId::new(), &Utc::now())
+            .await
+            .unwrap();
+        repo.save(&project_id, &other, &UserId::new(), &Utc::now())
+            .await
+            .unwrap();
+
+        server.conflict_next(1);
+        let report = client.flush_outbox().await.unwrap();
+        assert!(report.needs_attention());
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].status, OutboxStatus::Conflict);
+        assert_eq!(report.sent, 1);
+        assert!(server.item(&task_path(&other)).is_some());
+
+        // 競合した操作は再送待ちに含まれず、破棄できる
+        assert!(!outbox.has_pending().await.unwrap());
+        let seq = report.conflicts[0].seq;
+        assert!(matches!(
+            outbox.retry(seq + 1000).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+        assert!(outbox.discard(seq).await.unwrap());
+        assert_eq!(outbox.summary().await.unwrap(), OutboxSummary::default());
+        assert!(server.item(&task_path(&conflicting)).is_none());
+    }
+}
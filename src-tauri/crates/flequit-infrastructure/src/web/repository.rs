@@ -0,0 +1,404 @@
+//! REST/JSONによる汎用Webリポジトリ
+//!
+//! エンティティ種別ごとの差分は [`WebResource::COLLECTION`] と
+//! [`WebEntity::resource_key`] のみで、パス構成は全エンティティで共通。
+
+use super::client::WebClient;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::types::id_types::{ProjectId, UserId};
+use flequit_repository::repositories::base_repository_trait::Repository;
+use flequit_repository::repositories::patchable_trait::Patchable;
+use flequit_repository::repositories::project_patchable_trait::ProjectPatchable;
+use flequit_repository::repositories::project_relation_repository_trait::ProjectRelationRepository;
+use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+use flequit_types::errors::repository_error::RepositoryError;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// プロジェクトのコレクション名（プロジェクト配下のリソースの親パスにもなる）
+pub const PROJECTS_COLLECTION: &str = "projects";
+
+/// 型パラメーターを保持するだけのマーカー（`Send`/`Sync` を型パラメーターに依存させない）
+type Marker<T> = PhantomData<fn() -> T>;
+
+/// サーバー上のコレクションに対応するモデル
+pub trait WebResource: Serialize + DeserializeOwned + Send + Sync {
+    /// REST上のコレクション名（例: `tasks`）
+    const COLLECTION: &'static str;
+}
+
+/// IDで個別に保存されるモデル
+pub trait WebEntity: WebResource {
+    /// パス上でエンティティを識別するキー
+    fn resource_key(&self) -> String;
+}
+
+/// 親IDと子IDの組で保存される関連付けモデル
+pub trait WebRelation<P, C>: WebResource {
+    /// 追加時にサーバーへ送信する関連付けを作成
+    fn new_relation(
+        parent_id: &P,
+        child_id: &C,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Self;
+}
+
+/// プロジェクトに属さないエンティティ用のWebリポジトリ
+///
+/// `/api/v1/{collection}/{id}`
+pub struct WebRepository<T, Id> {
+    client: WebClient,
+    _marker: Marker<(T, Id)>,
+}
+
+/// プロジェクト配下のエンティティ用のWebリポジトリ
+///
+/// `/api/v1/projects/{project_id}/{collection}/{id}`
+pub struct WebProjectRepository<T, Id> {
+    client: WebClient,
+    _marker: Marker<(T, Id)>,
+}
+
+/// プロジェクト配下の関連付け用のWebリポジトリ
+///
+/// `/api/v1/projects/{project_id}/{collection}/{parent_id}/{child_id}`
+pub struct WebProjectRelationRepository<R, P, C> {
+    client: WebClient,
+    _marker: Marker<(R, P, C)>,
+}
+
+macro_rules! impl_web_repository_common {
+    ($name:ident < $($param:ident),+ >) => {
+        impl<$($param),+> $name<$($param),+> {
+            pub fn new(client: WebClient) -> Self {
+                Self {
+                    client,
+                    _marker: PhantomData,
+                }
+            }
+
+            pub fn client(&self) -> &WebClient {
+                &self.client
+            }
+        }
+
+        impl<$($param),+> Clone for $name<$($param),+> {
+            fn clone(&self) -> Self {
+                Self::new(self.client.clone())
+            }
+        }
+
+        impl<$($param),+> std::fmt::Debug for $name<$($param),+> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("resource", &std::any::type_name::<($($param),+)>())
+                    .field("client", &self.client)
+                    .finish()
+            }
+        }
+    };
+}
+
+impl_web_repository_common!(WebRepository<T, Id>);
+impl_web_repository_common!(WebProjectRepository<T, Id>);
+impl_web_repository_common!(WebProjectRelationRepository<R, P, C>);
+
+#[async_trait]
+impl<T, Id> Repository<T, Id> for WebRepository<T, Id>
+where
+    T: WebEntity,
+    Id: Display + Send + Sync,
+{
+    async fn save(
+        &self,
+        entity: &T,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        self.client
+            .put_json(
+                &[T::COLLECTION, &entity.resource_key()],
+                entity,
+                user_id,
+                timestamp,
+            )
+            .await
+    }
+
+    async fn find_by_id(&self, id: &Id) -> Result<Option<T>, RepositoryError> {
+        self.client
+            .get_json(&[T::COLLECTION, &id.to_string()], &[])
+            .await
+    }
+
+    async fn find_all(&self) -> Result<Vec<T>, RepositoryError> {
+        self.client.get_list(&[T::COLLECTION], &[]).await
+    }
+
+    async fn delete(&self, id: &Id) -> Result<(), RepositoryError> {
+        self.client
+            .delete(&[T::COLLECTION, &id.to_string()], &[])
+            .await?;
+        Ok(())
+    }
+
+    async fn exists(&self, id: &Id) -> Result<bool, RepositoryError> {
+        Ok(self.find_by_id(id).await?.is_some())
+    }
+
+    async fn count(&self) -> Result<u64, RepositoryError> {
+        Ok(self.find_all().await?.len() as u64)
+    }
+}
+
+impl<T, Id> Patchable<T, Id> for WebRepository<T, Id>
+where
+    T: WebEntity,
+    Id: Display + Send + Sync,
+{
+}
+
+#[async_trait]
+impl<T, Id> ProjectRepository<T, Id> for WebProjectRepository<T, Id>
+where
+    T: WebEntity,
+    Id: Display + Send + Sync,
+{
+    async fn save(
+        &self,
+        project_id: &ProjectId,
+        entity: &T,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let project_id = project_id.to_string();
+        self.client
+            .put_json(
+                &[
+                    PROJECTS_COLLECTION,
+                    &project_id,
+                    T::COLLECTION,
+                    &entity.resource_key(),
+                ],
+                entity,
+                user_id,
+                timestamp,
+            )
+            .await
+    }
+
+    async fn find_by_id(
+        &self,
+        project_id: &ProjectId,
+        id: &Id,
+    ) -> Result<Option<T>, RepositoryError> {
+        let project_id = project_id.to_string();
+        self.client
+            .get_json(
+                &[
+                    PROJECTS_COLLECTION,
+                    &project_id,
+                    T::COLLECTION,
+                    &id.to_string(),
+                ],
+                &[],
+            )
+            .await
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<T>, RepositoryError> {
+        let project_id = project_id.to_string();
+        self.client
+            .get_list(&[PROJECTS_COLLECTION, &project_id, T::COLLECTION], &[])
+            .await
+    }
+
+    async fn delete(&self, project_id: &ProjectId, id: &Id) -> Result<(), RepositoryError> {
+        let project_id = project_id.to_string();
+        self.client
+            .delete(
+                &[
+                    PROJECTS_COLLECTION,
+                    &project_id,
+                    T::COLLECTION,
+                    &id.to_string(),
+                ],
+                &[],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn exists(&self, project_id: &ProjectId, id: &Id) -> Result<bool, RepositoryError> {
+        Ok(self.find_by_id(project_id, id).await?.is_some())
+    }
+
+    async fn count(&self, project_id: &ProjectId) -> Result<u64, RepositoryError> {
+        Ok(self.find_all(project_id).await?.len() as u64)
+    }
+}
+
+impl<T, Id> ProjectPatchable<T, Id> for WebProjectRepository<T, Id>
+where
+    T: WebEntity,
+    Id: Display + Send + Sync + std::fmt::Debug,
+{
+}
+
+impl<R, P, C> WebProjectRelationRepository<R, P, C>
+where
+    R: WebResource,
+    C: Display,
+{
+    /// 指定した子（タグ等）を参照する関連付けをプロジェクト内から全て削除
+    pub async fn remove_all_by_child(
+        &self,
+        project_id: &ProjectId,
+        child_id: &C,
+    ) -> Result<(), RepositoryError> {
+        let project_id = project_id.to_string();
+        let child_id = child_id.to_string();
+        self.client
+            .delete(
+                &[PROJECTS_COLLECTION, &project_id, R::COLLECTION],
+                &[("child_id", &child_id)],
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R, P, C> ProjectRelationRepository<R, P, C> for WebProjectRelationRepository<R, P, C>
+where
+    R: WebRelation<P, C>,
+    P: Display + Send + Sync,
+    C: Display + Send + Sync,
+{
+    async fn add(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &P,
+        child_id: &C,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let relation = R::new_relation(parent_id, child_id, user_id, timestamp);
+        let project_id = project_id.to_string();
+        self.client
+            .put_json(
+                &[
+                    PROJECTS_COLLECTION,
+                    &project_id,
+                    R::COLLECTION,
+                    &parent_id.to_string(),
+                    &child_id.to_string(),
+                ],
+                &relation,
+                user_id,
+                timestamp,
+            )
+            .await
+    }
+
+    async fn remove(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &P,
+        child_id: &C,
+    ) -> Result<(), RepositoryError> {
+        let project_id = project_id.to_string();
+        self.client
+            .delete(
+                &[
+                    PROJECTS_COLLECTION,
+                    &project_id,
+                    R::COLLECTION,
+                    &parent_id.to_string(),
+                    &child_id.to_string(),
+                ],
+                &[],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn remove_all(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &P,
+    ) -> Result<(), RepositoryError> {
+        let project_id = project_id.to_string();
+        self.client
+            .delete(
+                &[
+                    PROJECTS_COLLECTION,
+                    &project_id,
+                    R::COLLECTION,
+                    &parent_id.to_string(),
+                ],
+                &[],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn find_relations(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &P,
+    ) -> Result<Vec<R>, RepositoryError> {
+        let project_id = project_id.to_string();
+        self.client
+            .get_list(
+                &[
+                    PROJECTS_COLLECTION,
+                    &project_id,
+                    R::COLLECTION,
+                    &parent_id.to_string(),
+                ],
+                &[],
+            )
+            .await
+    }
+
+    async fn exists(&self, project_id: &ProjectId, parent_id: &P) -> Result<bool, RepositoryError> {
+        Ok(!self.find_relations(project_id, parent_id).await?.is_empty())
+    }
+
+    async fn count(&self, project_id: &ProjectId, parent_id: &P) -> Result<u64, RepositoryError> {
+        Ok(self.find_relations(project_id, parent_id).await?.len() as u64)
+    }
+
+    async fn find_all(&self, project_id: &ProjectId) -> Result<Vec<R>, RepositoryError> {
+        let project_id = project_id.to_string();
+        self.client
+            .get_list(&[PROJECTS_COLLECTION, &project_id, R::COLLECTION], &[])
+            .await
+    }
+
+    async fn find_relation(
+        &self,
+        project_id: &ProjectId,
+        parent_id: &P,
+        child_id: &C,
+    ) -> Result<Option<R>, RepositoryError> {
+        let project_id = project_id.to_string();
+        self.client
+            .get_json(
+                &[
+                    PROJECTS_COLLECTION,
+                    &project_id,
+                    R::COLLECTION,
+                    &parent_id.to_string(),
+                    &child_id.to_string(),
+                ],
+                &[],
+            )
+            .await
+    }
+}
@@ -0,0 +1,261 @@
+//! エンティティごとのWebリポジトリ定義
+//!
+//! コレクション名と各リポジトリトレイトの実装をまとめる。
+
+use super::repository::{
+    WebEntity, WebProjectRelationRepository, WebProjectRepository, WebRelation, WebRepository,
+    WebResource,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::accounts::account::Account;
+use flequit_model::models::task_projects::{
+    comment::Comment, member::Member, project::Project, recurrence_rule::RecurrenceRule,
+    reminder::Reminder, snooze::Snooze, status_transition_rule::StatusTransitionRule,
+    subtask::SubTask, subtask_assignment::SubTaskAssignment, subtask_recurrence::SubTaskRecurrence,
+    subtask_tag::SubTaskTag, tag::Tag, task::Task, task_assignment::TaskAssignment,
+    task_dependency::TaskDependency, task_list::TaskList, task_recurrence::TaskRecurrence,
+    task_tag::TaskTag, time_entry::TimeEntry, workflow_status::WorkflowStatus,
+};
+use flequit_model::models::users::User;
+use flequit_model::types::id_types::{
+    AccountId, CommentId, ProjectId, RecurrenceRuleId, ReminderId, SnoozeId,
+    StatusTransitionRuleId, SubTaskId, TagId, TaskId, TaskListId, TimeEntryId, UserId,
+    WorkflowStatusId,
+};
+use flequit_repository::repositories::accounts::AccountRepositoryTrait;
+use flequit_repository::repositories::task_projects::{
+    comment_repository_trait::CommentRepositoryTrait,
+    member_repository_trait::MemberRepositoryTrait,
+    project_repository_trait::ProjectRepositoryTrait,
+    recurrence_rule_repository_trait::RecurrenceRuleRepositoryTrait,
+    reminder_repository_trait::ReminderRepositoryTrait,
+    snooze_repository_trait::SnoozeRepositoryTrait,
+    status_transition_rule_repository_trait::StatusTransitionRuleRepositoryTrait,
+    subtask_assignment_repository_trait::SubTaskAssignmentRepositoryTrait,
+    subtask_recurrence_repository_trait::SubtaskRecurrenceRepositoryTrait,
+    subtask_repository_trait::SubTaskRepositoryTrait,
+    subtask_tag_repository_trait::SubTaskTagRepositoryTrait,
+    tag_repository_trait::TagRepositoryTrait,
+    task_assignment_repository_trait::TaskAssignmentRepositoryTrait,
+    task_dependency_repository_trait::TaskDependencyRepositoryTrait,
+    task_list_repository_trait::TaskListRepositoryTrait,
+    task_recurrence_repository_trait::TaskRecurrenceRepositoryTrait,
+    task_repository_trait::TaskRepositoryTrait, task_tag_repository_trait::TaskTagRepositoryTrait,
+    time_entry_repository_trait::TimeEntryRepositoryTrait,
+    workflow_status_repository_trait::WorkflowStatusRepositoryTrait,
+};
+use flequit_repository::repositories::users::UserRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+
+pub type AccountWebRepository = WebRepository<Account, AccountId>;
+pub type UserWebRepository = WebRepository<User, UserId>;
+pub type ProjectWebRepository = WebRepository<Project, ProjectId>;
+pub type MemberWebRepository = WebProjectRepository<Member, UserId>;
+pub type TaskWebRepository = WebProjectRepository<Task, TaskId>;
+pub type TaskListWebRepository = WebProjectRepository<TaskList, TaskListId>;
+pub type SubTaskWebRepository = WebProjectRepository<SubTask, SubTaskId>;
+pub type TagWebRepository = WebProjectRepository<Tag, TagId>;
+pub type RecurrenceRuleWebRepository = WebProjectRepository<RecurrenceRule, RecurrenceRuleId>;
+pub type TimeEntryWebRepository = WebProjectRepository<TimeEntry, TimeEntryId>;
+pub type ReminderWebRepository = WebProjectRepository<Reminder, ReminderId>;
+pub type SnoozeWebRepository = WebProjectRepository<Snooze, SnoozeId>;
+pub type CommentWebRepository = WebProjectRepository<Comment, CommentId>;
+pub type StatusTransitionRuleWebRepository =
+    WebProjectRepository<StatusTransitionRule, StatusTransitionRuleId>;
+pub type WorkflowStatusWebRepository = WebProjectRepository<WorkflowStatus, WorkflowStatusId>;
+pub type TaskTagWebRepository = WebProjectRelationRepository<TaskTag, TaskId, TagId>;
+pub type SubTaskTagWebRepository = WebProjectRelationRepository<SubTaskTag, SubTaskId, TagId>;
+pub type TaskAssignmentWebRepository = WebProjectRelationRepository<TaskAssignment, TaskId, UserId>;
+pub type TaskDependencyWebRepository = WebProjectRelationRepository<TaskDependency, TaskId, TaskId>;
+pub type SubTaskAssignmentWebRepository =
+    WebProjectRelationRepository<SubTaskAssignment, SubTaskId, UserId>;
+pub type TaskRecurrenceWebRepository =
+    WebProjectRelationRepository<TaskRecurrence, TaskId, RecurrenceRuleId>;
+pub type SubTaskRecurrenceWebRepository =
+    WebProjectRelationRepository<SubTaskRecurrence, SubTaskId, RecurrenceRuleId>;
+
+macro_rules! web_entity {
+    ($model:ty, $collection:literal, $key:ident) => {
+        impl WebResource for $model {
+            const COLLECTION: &'static str = $collection;
+        }
+
+        impl WebEntity for $model {
+            fn resource_key(&self) -> String {
+                self.$key.to_string()
+            }
+        }
+    };
+}
+
+macro_rules! web_relation {
+    ($model:ident, $collection:literal, $parent:ident: $parent_ty:ty, $child:ident: $child_ty:ty) => {
+        impl WebResource for $model {
+            const COLLECTION: &'static str = $collection;
+        }
+
+        impl WebRelation<$parent_ty, $child_ty> for $model {
+            fn new_relation(
+                parent_id: &$parent_ty,
+                child_id: &$child_ty,
+                user_id: &UserId,
+                timestamp: &DateTime<Utc>,
+            ) -> Self {
+                $model {
+                    $parent: *parent_id,
+                    $child: *child_id,
+                    created_at: *timestamp,
+                    updated_at: *timestamp,
+                    deleted: false,
+                    updated_by: *user_id,
+                }
+            }
+        }
+    };
+}
+
+web_entity!(Account, "accounts", id);
+web_entity!(User, "users", id);
+web_entity!(Project, "projects", id);
+// メンバーはプロジェクト内でユーザーIDにより一意
+web_entity!(Member, "members", user_id);
+web_entity!(Task, "tasks", id);
+web_entity!(TaskList, "task_lists", id);
+web_entity!(SubTask, "subtasks", id);
+web_entity!(Tag, "tags", id);
+web_entity!(RecurrenceRule, "recurrence_rules", id);
+web_entity!(TimeEntry, "time_entries", id);
+web_entity!(Reminder, "reminders", id);
+web_entity!(Snooze, "snoozes", id);
+web_entity!(Comment, "comments", id);
+web_entity!(StatusTransitionRule, "status_transition_rules", id);
+web_entity!(WorkflowStatus, "workflow_statuses", id);
+
+web_relation!(TaskTag, "task_tags", task_id: TaskId, tag_id: TagId);
+web_relation!(SubTaskTag, "subtask_tags", subtask_id: SubTaskId, tag_id: TagId);
+web_relation!(TaskAssignment, "task_assignments", task_id: TaskId, user_id: UserId);
+web_relation!(
+    TaskDependency,
+    "task_dependencies",
+    task_id: TaskId,
+    depends_on_task_id: TaskId
+);
+web_relation!(
+    SubTaskAssignment,
+    "subtask_assignments",
+    subtask_id: SubTaskId,
+    user_id: UserId
+);
+web_relation!(
+    TaskRecurrence,
+    "task_recurrences",
+    task_id: TaskId,
+    recurrence_rule_id: RecurrenceRuleId
+);
+web_relation!(
+    SubTaskRecurrence,
+    "subtask_recurrences",
+    subtask_id: SubTaskId,
+    recurrence_rule_id: RecurrenceRuleId
+);
+
+impl AccountRepositoryTrait for AccountWebRepository {}
+impl UserRepositoryTrait for UserWebRepository {}
+impl ProjectRepositoryTrait for ProjectWebRepository {}
+impl MemberRepositoryTrait for MemberWebRepository {}
+impl TaskRepositoryTrait for TaskWebRepository {}
+impl TaskListRepositoryTrait for TaskListWebRepository {}
+impl SubTaskRepositoryTrait for SubTaskWebRepository {}
+impl TagRepositoryTrait for TagWebRepository {}
+impl RecurrenceRuleRepositoryTrait for RecurrenceRuleWebRepository {}
+impl TimeEntryRepositoryTrait for TimeEntryWebRepository {}
+impl ReminderRepositoryTrait for ReminderWebRepository {}
+impl SnoozeRepositoryTrait for SnoozeWebRepository {}
+impl CommentRepositoryTrait for CommentWebRepository {}
+impl StatusTransitionRuleRepositoryTrait for StatusTransitionRuleWebRepository {}
+impl WorkflowStatusRepositoryTrait for WorkflowStatusWebRepository {}
+impl TaskTagRepositoryTrait for TaskTagWebRepository {}
+impl SubTaskTagRepositoryTrait for SubTaskTagWebRepository {}
+impl TaskAssignmentRepositoryTrait for TaskAssignmentWebRepository {}
+impl SubTaskAssignmentRepositoryTrait for SubTaskAssignmentWebRepository {}
+impl TaskDependencyRepositoryTrait for TaskDependencyWebRepository {}
+impl TaskRecurrenceRepositoryTrait for TaskRecurrenceWebRepository {}
+
+/// プロジェクトを横断するサブタスク繰り返しの操作
+///
+/// `/api/v1/subtask_recurrences/{subtask_id}` と、
+/// `recurrence_rule_id` クエリによる絞り込み・一括削除で表現する。
+#[async_trait]
+impl SubtaskRecurrenceRepositoryTrait for SubTaskRecurrenceWebRepository {
+    async fn find_by_subtask_id(
+        &self,
+        subtask_id: &SubTaskId,
+    ) -> Result<Option<SubTaskRecurrence>, RepositoryError> {
+        self.client()
+            .get_json(
+                &[SubTaskRecurrence::COLLECTION, &subtask_id.to_string()],
+                &[],
+            )
+            .await
+    }
+
+    async fn find_by_recurrence_rule_id(
+        &self,
+        recurrence_rule_id: &RecurrenceRuleId,
+    ) -> Result<Vec<SubTaskRecurrence>, RepositoryError> {
+        self.client()
+            .get_list(
+                &[SubTaskRecurrence::COLLECTION],
+                &[("recurrence_rule_id", &recurrence_rule_id.to_string())],
+            )
+            .await
+    }
+
+    async fn find_all(&self) -> Result<Vec<SubTaskRecurrence>, RepositoryError> {
+        self.client()
+            .get_list(&[SubTaskRecurrence::COLLECTION], &[])
+            .await
+    }
+
+    async fn save(&self, recurrence: &SubTaskRecurrence) -> Result<(), RepositoryError> {
+        self.client()
+            .put_json(
+                &[
+                    SubTaskRecurrence::COLLECTION,
+                    &recurrence.subtask_id.to_string(),
+                ],
+                recurrence,
+                &recurrence.updated_by,
+                &recurrence.updated_at,
+            )
+            .await
+    }
+
+    async fn delete_by_subtask_id(&self, subtask_id: &SubTaskId) -> Result<(), RepositoryError> {
+        self.client()
+            .delete(
+                &[SubTaskRecurrence::COLLECTION, &subtask_id.to_string()],
+                &[],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_by_recurrence_rule_id(
+        &self,
+        recurrence_rule_id: &RecurrenceRuleId,
+    ) -> Result<(), RepositoryError> {
+        self.client()
+            .delete(
+                &[SubTaskRecurrence::COLLECTION],
+                &[("recurrence_rule_id", &recurrence_rule_id.to_string())],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn exists_by_subtask_id(&self, subtask_id: &SubTaskId) -> Result<bool, RepositoryError> {
+        Ok(self.find_by_subtask_id(subtask_id).await?.is_some())
+    }
+}
@@ -0,0 +1,126 @@
+//! サーバーを介したAutomerge同期メッセージの中継
+//!
+//! `projects/{project_id}/sync` に同期メッセージを送信し、他の端末が送信したメッセージを
+//! サーバーが割り当てた連番（`since` カーソル）の順に取得する。
+//! メッセージの内容はサーバーでは解釈されない。
+
+use super::client::WebClient;
+use super::repository::PROJECTS_COLLECTION;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chrono::{DateTime, Utc};
+use flequit_model::types::id_types::ProjectId;
+use flequit_types::errors::repository_error::RepositoryError;
+use serde::Deserialize;
+
+const SYNC_SEGMENT: &str = "sync";
+
+/// 中継された同期メッセージ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayedSyncMessage {
+    /// サーバー全体で単調増加する連番
+    pub seq: i64,
+    /// 送信したサーバーアカウントのID
+    pub account_id: String,
+    /// Automergeの同期メッセージ
+    pub payload: Vec<u8>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 同期メッセージの取得結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncRelayPage {
+    pub messages: Vec<RelayedSyncMessage>,
+    /// 次回の取得で `since` に指定する連番
+    pub next_since: i64,
+}
+
+#[derive(Deserialize)]
+struct PushResponse {
+    seq: i64,
+}
+
+#[derive(Deserialize)]
+struct MessageResponse {
+    seq: i64,
+    account_id: String,
+    /// Base64
+    payload: String,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct PageResponse {
+    messages: Vec<MessageResponse>,
+    next_since: i64,
+}
+
+/// 同期メッセージの中継クライアント
+#[derive(Debug, Clone)]
+pub struct SyncRelayClient {
+    client: WebClient,
+}
+
+impl SyncRelayClient {
+    pub fn new(client: WebClient) -> Self {
+        Self { client }
+    }
+
+    /// 同期メッセージを送信し、割り当てられた連番を返す
+    pub async fn push(
+        &self,
+        project_id: &ProjectId,
+        payload: &[u8],
+    ) -> Result<i64, RepositoryError> {
+        let project_id = project_id.to_string();
+        let response: PushResponse = self
+            .client
+            .post_bytes(
+                &[PROJECTS_COLLECTION, &project_id, SYNC_SEGMENT],
+                payload.to_vec(),
+            )
+            .await?;
+        Ok(response.seq)
+    }
+
+    /// `since` より後の同期メッセージを取得する（`limit` 省略時はサーバーの既定件数）
+    pub async fn pull(
+        &self,
+        project_id: &ProjectId,
+        since: i64,
+        limit: Option<u64>,
+    ) -> Result<SyncRelayPage, RepositoryError> {
+        let project_id = project_id.to_string();
+        let since = since.to_string();
+        let limit = limit.map(|limit| limit.to_string());
+        let mut query = vec![("since", since.as_str())];
+        if let Some(limit) = &limit {
+            query.push(("limit", limit.as_str()));
+        }
+        let page: PageResponse = self
+            .client
+            .get_json(&[PROJECTS_COLLECTION, &project_id, SYNC_SEGMENT], &query)
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound(format!("Project not found: {project_id}")))?;
+
+        let messages = page
+            .messages
+            .into_iter()
+            .map(|message| {
+                let payload = BASE64.decode(&message.payload).map_err(|e| {
+                    RepositoryError::SerializationError(format!("Invalid sync payload: {e}"))
+                })?;
+                Ok(RelayedSyncMessage {
+                    seq: message.seq,
+                    account_id: message.account_id,
+                    payload,
+                    created_at: message.created_at,
+                })
+            })
+            .collect::<Result<_, RepositoryError>>()?;
+        Ok(SyncRelayPage {
+            messages,
+            next_since: page.next_since,
+        })
+    }
+}
@@ -0,0 +1,132 @@
+//! アクティビティログのエントリ
+
+use crate::types::id_types::{ProjectId, UserId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// 変更の種類
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityAction {
+    Created,
+    Updated,
+    Deleted,
+    Restored,
+}
+
+/// 変更の記録元
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivitySource {
+    /// このデバイスでの操作
+    Local,
+    /// 同期で取り込んだ変更
+    Sync,
+    /// Automergeの変更履歴から再構築した記録
+    History,
+}
+
+impl ActivityAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActivityAction::Created => "created",
+            ActivityAction::Updated => "updated",
+            ActivityAction::Deleted => "deleted",
+            ActivityAction::Restored => "restored",
+        }
+    }
+}
+
+impl ActivitySource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActivitySource::Local => "local",
+            ActivitySource::Sync => "sync",
+            ActivitySource::History => "history",
+        }
+    }
+}
+
+impl fmt::Display for ActivityAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for ActivitySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ActivityAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created" => Ok(ActivityAction::Created),
+            "updated" => Ok(ActivityAction::Updated),
+            "deleted" => Ok(ActivityAction::Deleted),
+            "restored" => Ok(ActivityAction::Restored),
+            other => Err(format!("Unknown activity action: {other}")),
+        }
+    }
+}
+
+impl FromStr for ActivitySource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local" => Ok(ActivitySource::Local),
+            "sync" => Ok(ActivitySource::Sync),
+            "history" => Ok(ActivitySource::History),
+            other => Err(format!("Unknown activity source: {other}")),
+        }
+    }
+}
+
+/// フィールド単位の変更内容
+///
+/// 値はエンティティをJSONにシリアライズした際の値をそのまま保持する。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub from: serde_json::Value,
+    pub to: serde_json::Value,
+}
+
+/// アクティビティログに追加するエントリ
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewActivityEntry {
+    pub project_id: ProjectId,
+    /// 対象エンティティの種類（`task` / `sub_task` / `task_tag` など）
+    pub entity: String,
+    pub entity_id: String,
+    /// 関連先・親エンティティのID（タグ付けのタグID、サブタスクの親タスクIDなど）
+    pub related_id: Option<String>,
+    pub action: ActivityAction,
+    pub changes: Vec<FieldChange>,
+    /// 変更を行ったユーザー（不明な場合は `None`）
+    pub actor: Option<UserId>,
+    pub source: ActivitySource,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// アクティビティログに記録されたエントリ
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    /// 記録順の連番
+    pub seq: i64,
+    pub project_id: ProjectId,
+    pub entity: String,
+    pub entity_id: String,
+    pub related_id: Option<String>,
+    pub action: ActivityAction,
+    pub changes: Vec<FieldChange>,
+    pub actor: Option<UserId>,
+    pub source: ActivitySource,
+    pub occurred_at: DateTime<Utc>,
+}
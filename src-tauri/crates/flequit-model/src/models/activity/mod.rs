@@ -0,0 +1,10 @@
+//! アクティビティログモデル
+//!
+//! このモジュールは、プロジェクト内のエンティティに対して「誰が・いつ・何を」変更したかを
+//! 記録するモデルを定義します。
+//!
+//! ## 構成
+//!
+//! - [`activity_entry`] - アクティビティログの1件（作成・更新・削除・復元とフィールドの変更内容）
+
+pub mod activity_entry;
@@ -73,12 +73,14 @@
 use async_trait::async_trait;
 
 pub mod accounts;
+pub mod activity;
 pub mod initialized_data;
 pub mod task_projects;
 pub mod user_preferences;
 pub mod users;
 
 pub mod search;
+pub mod sync;
 
 /// 通常モデルとTree系モデル間の相互変換を定義するトレイト
 ///
@@ -0,0 +1,10 @@
+//! リモート同期モデル
+//!
+//! このモジュールは、セルフホストサーバーとの同期状態を管理するエンティティを定義します。
+//! 端末ローカルの状態であり、他の端末やユーザーとは共有しません。
+//!
+//! ## 構成
+//!
+//! - [`outbox_operation`] - 未送信のリモート書き込み（オフライン時のアウトボックス）
+
+pub mod outbox_operation;
@@ -0,0 +1,125 @@
+//! アウトボックス（未送信のリモート書き込み）モデル
+//!
+//! オフライン中などにサーバーへ送信できなかった書き込みを記録し、
+//! 再接続時に記録順に再送するためのモデルを定義します。
+
+use crate::types::id_types::ProjectId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// リモートに対する操作の種類
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxOperationKind {
+    /// 作成または置換（PUT）
+    Put,
+    /// 削除（DELETE）
+    Delete,
+}
+
+/// アウトボックス内の操作の状態
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxStatus {
+    /// 再送待ち
+    Pending,
+    /// 再送時にサーバー側の状態と競合した（ユーザーの判断待ち）
+    Conflict,
+    /// 再送を諦めた（ユーザーの判断待ち）
+    DeadLetter,
+}
+
+impl OutboxOperationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutboxOperationKind::Put => "put",
+            OutboxOperationKind::Delete => "delete",
+        }
+    }
+}
+
+impl OutboxStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutboxStatus::Pending => "pending",
+            OutboxStatus::Conflict => "conflict",
+            OutboxStatus::DeadLetter => "dead_letter",
+        }
+    }
+}
+
+impl fmt::Display for OutboxOperationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for OutboxStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OutboxOperationKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "put" => Ok(OutboxOperationKind::Put),
+            "delete" => Ok(OutboxOperationKind::Delete),
+            other => Err(format!("Unknown outbox operation: {other}")),
+        }
+    }
+}
+
+impl FromStr for OutboxStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(OutboxStatus::Pending),
+            "conflict" => Ok(OutboxStatus::Conflict),
+            "dead_letter" => Ok(OutboxStatus::DeadLetter),
+            other => Err(format!("Unknown outbox status: {other}")),
+        }
+    }
+}
+
+/// アウトボックスに追加する操作
+///
+/// `payload` は送信に必要な情報（パス・本文・書き込みユーザーなど）をJSONで保持する。
+/// 内容の解釈は送信側（Webクライアント）に委ねる。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewOutboxOperation {
+    /// 再送時にサーバーへ伝える冪等キー
+    pub idempotency_key: String,
+    /// 対象エンティティのコレクション名（`tasks` など）
+    pub entity: String,
+    pub operation: OutboxOperationKind,
+    /// プロジェクト配下のエンティティの場合のプロジェクトID
+    pub project_id: Option<ProjectId>,
+    pub payload: String,
+}
+
+/// アウトボックスに記録された操作
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxOperation {
+    /// 記録順の連番（再送はこの順に行う）
+    pub seq: i64,
+    pub idempotency_key: String,
+    pub entity: String,
+    pub operation: OutboxOperationKind,
+    pub project_id: Option<ProjectId>,
+    pub payload: String,
+    pub status: OutboxStatus,
+    /// 再送に失敗した回数
+    pub attempts: u32,
+    /// 次に再送を試みる日時
+    pub next_attempt_at: DateTime<Utc>,
+    /// 最後に失敗した際のエラー
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
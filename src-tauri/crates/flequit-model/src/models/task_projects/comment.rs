@@ -0,0 +1,170 @@
+//! コメントモデル
+//!
+//! このモジュールはタスク・サブタスクへのコメント（ディスカッション）を定義します。
+//!
+//! ## 概要
+//!
+//! `Comment`は1件の書き込みを表し、1つのタスク・サブタスクに任意の数だけ追加できます。
+//! 本文中の`@ハンドル`で言及したユーザーは`mentioned_user_ids`に記録し、
+//! 通知や「自分宛て」の絞り込みに使えるようにします。
+//!
+//! 削除は論理削除とし、元に戻せるよう削除済みのコメントも記録に残します。
+
+use crate::traits::Trackable;
+use crate::types::id_types::{CommentId, ProjectId, SubTaskId, TaskId, UserId};
+use chrono::{DateTime, Utc};
+use partially::Partial;
+use serde::{Deserialize, Serialize};
+
+/// コメントを表現する構造体
+///
+/// # フィールド
+///
+/// * `id` - コメントの一意識別子
+/// * `project_id` - 所属プロジェクトID
+/// * `task_id` - コメント先のタスクID
+/// * `subtask_id` - コメント先のサブタスクID（タスク自体へのコメントの場合は`None`）
+/// * `author_id` - 投稿者のユーザーID
+/// * `body` - 本文
+/// * `mentioned_user_ids` - 本文中で言及したユーザーのID
+/// * `edited_at` - 最後に本文を編集した日時（未編集の場合は`None`）
+///
+/// # 使用例
+///
+/// ```rust,no_run
+/// # use chrono::Utc;
+/// # use flequit_model::models::task_projects::comment::Comment;
+/// # use flequit_model::types::id_types::{CommentId, ProjectId, TaskId, UserId};
+///
+/// let now = Utc::now();
+/// let author_id = UserId::new();
+/// let comment = Comment {
+///     id: CommentId::new(),
+///     project_id: ProjectId::new(),
+///     task_id: TaskId::new(),
+///     subtask_id: None,
+///     author_id,
+///     body: "@alice レビューをお願いします".to_string(),
+///     mentioned_user_ids: vec![],
+///     created_at: now,
+///     edited_at: None,
+///     updated_at: now,
+///     deleted: false,
+///     updated_by: author_id,
+/// };
+/// assert_eq!(comment.mentioned_handles(), vec!["alice".to_string()]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Partial)]
+#[partially(derive(Debug, Clone, Serialize, Deserialize, Default))]
+pub struct Comment {
+    /// コメントの一意識別子
+    #[partially(omit)] // IDは更新対象外
+    pub id: CommentId,
+    /// 所属プロジェクトID
+    #[partially(omit)] // プロジェクト間の移動は対象外
+    pub project_id: ProjectId,
+    /// コメント先のタスクID
+    #[partially(omit)] // コメント先の付け替えは対象外
+    pub task_id: TaskId,
+    /// コメント先のサブタスクID（タスク自体へのコメントの場合は`None`）
+    #[partially(omit)]
+    pub subtask_id: Option<SubTaskId>,
+    /// 投稿者のユーザーID
+    #[partially(omit)] // 投稿者は変更不可
+    pub author_id: UserId,
+    /// 本文
+    pub body: String,
+    /// 本文中で言及したユーザーのID
+    pub mentioned_user_ids: Vec<UserId>,
+    /// 投稿日時
+    pub created_at: DateTime<Utc>,
+    /// 最後に本文を編集した日時（未編集の場合は`None`）
+    pub edited_at: Option<DateTime<Utc>>,
+    /// 最終更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 論理削除フラグ
+    pub deleted: bool,
+    /// 最終更新者のユーザーID（必須、作成・更新・削除・復元すべての操作で記録）
+    pub updated_by: UserId,
+}
+
+impl Comment {
+    /// 本文中で`@ハンドル`の形で言及したハンドル（重複を除き、出現順）
+    ///
+    /// メールアドレスのように英数字の直後に続く`@`は言及とみなさない。
+    pub fn mentioned_handles(&self) -> Vec<String> {
+        let mut handles: Vec<String> = Vec::new();
+        let mut previous: Option<char> = None;
+        let mut chars = self.body.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            let starts_mention = c == '@' && !previous.is_some_and(is_handle_char);
+            previous = Some(c);
+            if !starts_mention {
+                continue;
+            }
+            let start = index + c.len_utf8();
+            let mut end = start;
+            while let Some(&(next_index, next)) = chars.peek() {
+                if !is_handle_char(next) {
+                    break;
+                }
+                end = next_index + next.len_utf8();
+                previous = Some(next);
+                chars.next();
+            }
+            // 文末の句読点はハンドルに含めない
+            let handle = self.body[start..end].trim_end_matches(['.', '-']);
+            if !handle.is_empty() && !handles.iter().any(|h| h == handle) {
+                handles.push(handle.to_string());
+            }
+        }
+        handles
+    }
+}
+
+/// ハンドルに使える文字
+fn is_handle_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+impl Trackable for Comment {
+    fn mark_created(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.created_at = timestamp;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+        self.deleted = false;
+    }
+
+    fn mark_updated(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_deleted(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = true;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_restored(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = false;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    fn get_updated_by(&self) -> UserId {
+        self.updated_by
+    }
+
+    fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn get_updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
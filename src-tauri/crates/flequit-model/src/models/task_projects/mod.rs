@@ -1,9 +1,13 @@
+pub mod comment;
 pub mod date_condition;
 pub mod member;
 pub mod project;
 pub mod recurrence_adjustment;
 pub mod recurrence_details;
 pub mod recurrence_rule;
+pub mod reminder;
+pub mod snooze;
+pub mod status_transition_rule;
 pub mod subtask;
 pub mod subtask_assignment;
 pub mod subtask_recurrence;
@@ -11,15 +15,24 @@ pub mod subtask_tag;
 pub mod tag;
 pub mod task;
 pub mod task_assignment;
+pub mod task_dependency;
 pub mod task_list;
 pub mod task_recurrence;
 pub mod task_tag;
+pub mod time_entry;
 pub mod weekday_condition;
+pub mod workflow_status;
 
 // Re-export main types
+pub use comment::Comment;
 pub use member::Member;
 pub use project::{Project, ProjectTree};
+pub use reminder::Reminder;
+pub use snooze::Snooze;
+pub use status_transition_rule::StatusTransitionRule;
 pub use subtask::{SubTask, SubTaskTree};
 pub use tag::Tag;
 pub use task::{Task, TaskTree};
 pub use task_list::{TaskList, TaskListTree};
+pub use time_entry::TimeEntry;
+pub use workflow_status::WorkflowStatus;
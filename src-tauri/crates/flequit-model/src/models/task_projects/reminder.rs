@@ -0,0 +1,295 @@
+//! リマインダーモデル
+//!
+//! このモジュールはタスク・サブタスクに設定する通知（リマインダー）を定義します。
+//!
+//! ## 概要
+//!
+//! `Reminder`は1つの通知設定を表し、1つのタスク・サブタスクに任意の数だけ設定できます。
+//! 通知日時は固定の日時、または対象の予定開始日時・予定終了日時からの相対時間で指定します。
+//! 相対指定のリマインダーは対象の予定日時が変わると通知日時も追従するため、
+//! 繰り返しタスクの次の回に予定日時が進むと再び通知されます。
+//!
+//! 通知済みかどうかは最後に通知した予定日時（`last_fired_at`）で判定し、
+//! それより後の通知予定だけを通知の対象とします。
+//!
+//! スヌーズしたリマインダーは、スヌーズ先の日時（`snoozed_until`）までの通知予定を通知済みとし、
+//! スヌーズ先の日時に改めて通知します。
+
+use crate::traits::Trackable;
+use crate::types::id_types::{ProjectId, ReminderId, SubTaskId, TaskId, UserId};
+use crate::types::task_types::ReminderAnchor;
+use chrono::{DateTime, Duration, Utc};
+use partially::Partial;
+use serde::{Deserialize, Serialize};
+
+/// リマインダーを表現する構造体
+///
+/// # フィールド
+///
+/// * `id` - リマインダーの一意識別子
+/// * `project_id` - 所属プロジェクトID
+/// * `task_id` - 通知対象のタスクID
+/// * `subtask_id` - 通知対象のサブタスクID（タスク自体への通知の場合は`None`）
+/// * `anchor` - 通知日時の基準
+/// * `remind_at` - 通知日時（`anchor`が`Absolute`の場合のみ）
+/// * `offset_minutes` - 基準日時からのずれ（分、負の値は基準日時より前）
+/// * `repeat_interval_minutes` - 繰り返し間隔（分、繰り返さない場合は`None`）
+/// * `repeat_count` - 最初の通知の後に繰り返す回数（`None`は対象が完了するまで繰り返す）
+/// * `last_fired_at` - 最後に通知した予定日時
+/// * `snoozed_until` - スヌーズ先の日時（スヌーズしていない場合は`None`）
+///
+/// # 使用例
+///
+/// ```rust,no_run
+/// # use chrono::Utc;
+/// # use flequit_model::models::task_projects::reminder::Reminder;
+/// # use flequit_model::types::id_types::{ProjectId, ReminderId, TaskId, UserId};
+/// # use flequit_model::types::task_types::ReminderAnchor;
+///
+/// // 期限の30分前に通知し、10分おきに2回まで繰り返す
+/// let user_id = UserId::new();
+/// let reminder = Reminder {
+///     id: ReminderId::new(),
+///     project_id: ProjectId::new(),
+///     task_id: TaskId::new(),
+///     subtask_id: None,
+///     anchor: ReminderAnchor::PlanEnd,
+///     remind_at: None,
+///     offset_minutes: -30,
+///     repeat_interval_minutes: Some(10),
+///     repeat_count: Some(2),
+///     last_fired_at: None,
+///     snoozed_until: None,
+///     created_at: Utc::now(),
+///     updated_at: Utc::now(),
+///     deleted: false,
+///     updated_by: user_id,
+/// };
+/// assert!(reminder.first_fire_at(None, None).is_none());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Partial)]
+#[partially(derive(Debug, Clone, Serialize, Deserialize, Default))]
+pub struct Reminder {
+    /// リマインダーの一意識別子
+    #[partially(omit)] // IDは更新対象外
+    pub id: ReminderId,
+    /// 所属プロジェクトID
+    #[partially(omit)] // プロジェクト間の移動は対象外
+    pub project_id: ProjectId,
+    /// 通知対象のタスクID
+    #[partially(omit)] // 通知対象の付け替えは対象外
+    pub task_id: TaskId,
+    /// 通知対象のサブタスクID（タスク自体への通知の場合は`None`）
+    #[partially(omit)]
+    pub subtask_id: Option<SubTaskId>,
+    /// 通知日時の基準
+    pub anchor: ReminderAnchor,
+    /// 通知日時（`anchor`が`Absolute`の場合のみ）
+    pub remind_at: Option<DateTime<Utc>>,
+    /// 基準日時からのずれ（分、負の値は基準日時より前）
+    pub offset_minutes: i64,
+    /// 繰り返し間隔（分、繰り返さない場合は`None`）
+    pub repeat_interval_minutes: Option<i64>,
+    /// 最初の通知の後に繰り返す回数（`None`は対象が完了するまで繰り返す）
+    pub repeat_count: Option<i32>,
+    /// 最後に通知した予定日時（未通知の場合は`None`）
+    pub last_fired_at: Option<DateTime<Utc>>,
+    /// スヌーズ先の日時（スヌーズしていない場合は`None`）
+    #[serde(default)]
+    pub snoozed_until: Option<DateTime<Utc>>,
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+    /// 最終更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 論理削除フラグ（Automerge同期用）
+    pub deleted: bool,
+    /// 最終更新者のユーザーID（必須、作成・更新・削除・復元すべての操作で記録）
+    pub updated_by: UserId,
+}
+
+impl Reminder {
+    /// 最初の通知日時
+    ///
+    /// 相対指定で基準となる予定日時が未設定の場合は`None`。
+    pub fn first_fire_at(
+        &self,
+        plan_start: Option<DateTime<Utc>>,
+        plan_end: Option<DateTime<Utc>>,
+    ) -> Option<DateTime<Utc>> {
+        let base = match self.anchor {
+            ReminderAnchor::Absolute => return self.remind_at,
+            ReminderAnchor::PlanStart => plan_start?,
+            ReminderAnchor::PlanEnd => plan_end?,
+        };
+        Some(base + Duration::minutes(self.offset_minutes))
+    }
+
+    /// 有効な繰り返し間隔
+    fn repeat_interval(&self) -> Option<Duration> {
+        self.repeat_interval_minutes
+            .filter(|minutes| *minutes > 0)
+            .map(Duration::minutes)
+    }
+
+    /// `first`から数えて`index`回目（0始まり）の通知日時
+    ///
+    /// 繰り返し回数を超える場合は`None`。
+    fn fire_at(&self, first: DateTime<Utc>, index: i64) -> Option<DateTime<Utc>> {
+        if index == 0 {
+            return Some(first);
+        }
+        let interval = self.repeat_interval()?;
+        if self
+            .repeat_count
+            .is_some_and(|count| index > i64::from(count.max(0)))
+        {
+            return None;
+        }
+        Some(first + interval * i32::try_from(index).ok()?)
+    }
+
+    /// `first`から`at`までに経過した繰り返し間隔の数
+    fn elapsed_intervals(&self, first: DateTime<Utc>, at: DateTime<Utc>) -> i64 {
+        match self.repeat_interval() {
+            Some(interval) if at >= first => {
+                (at - first).num_seconds() / interval.num_seconds().max(1)
+            }
+            _ => 0,
+        }
+    }
+
+    /// まだ通知していない最初の通知日時（スヌーズ先の日時を含む）
+    pub fn next_fire_at(
+        &self,
+        plan_start: Option<DateTime<Utc>>,
+        plan_end: Option<DateTime<Utc>>,
+    ) -> Option<DateTime<Utc>> {
+        match (
+            self.next_scheduled_at(plan_start, plan_end),
+            self.snoozed_until,
+        ) {
+            (Some(scheduled), Some(snoozed)) => Some(scheduled.min(snoozed)),
+            (scheduled, snoozed) => scheduled.or(snoozed),
+        }
+    }
+
+    /// `now`の時点で通知すべき通知日時
+    ///
+    /// 未通知の通知日時とスヌーズ先の日時のうち`now`以前で最も新しいものを返す。
+    /// アプリケーションの停止中に複数の通知日時を過ぎていた場合も、通知は1回にまとめる。
+    pub fn due_at(
+        &self,
+        plan_start: Option<DateTime<Utc>>,
+        plan_end: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let snoozed = self.snoozed_until.filter(|until| *until <= now);
+        self.scheduled_due_at(plan_start, plan_end, now).max(snoozed)
+    }
+
+    /// `now`の時点までの通知を通知済みとして記録する
+    pub fn mark_fired(
+        &mut self,
+        plan_start: Option<DateTime<Utc>>,
+        plan_end: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) {
+        if let Some(scheduled) = self.scheduled_due_at(plan_start, plan_end, now) {
+            self.last_fired_at = Some(scheduled);
+        }
+        if self.snoozed_until.is_some_and(|until| until <= now) {
+            self.snoozed_until = None;
+        }
+    }
+
+    /// `until`まで通知を見送り、`until`に改めて通知する
+    ///
+    /// `until`までの通知予定は通知済みとして扱う。
+    pub fn snooze(
+        &mut self,
+        plan_start: Option<DateTime<Utc>>,
+        plan_end: Option<DateTime<Utc>>,
+        until: DateTime<Utc>,
+    ) {
+        if let Some(scheduled) = self.scheduled_due_at(plan_start, plan_end, until) {
+            self.last_fired_at = Some(scheduled);
+        }
+        self.snoozed_until = Some(until);
+    }
+
+    /// 通知予定のうち、まだ通知していない最初の通知日時
+    fn next_scheduled_at(
+        &self,
+        plan_start: Option<DateTime<Utc>>,
+        plan_end: Option<DateTime<Utc>>,
+    ) -> Option<DateTime<Utc>> {
+        let first = self.first_fire_at(plan_start, plan_end)?;
+        match self.last_fired_at {
+            Some(last) if last >= first => {
+                self.fire_at(first, self.elapsed_intervals(first, last) + 1)
+            }
+            _ => Some(first),
+        }
+    }
+
+    /// 通知予定のうち、`now`以前で最も新しい未通知の通知日時
+    fn scheduled_due_at(
+        &self,
+        plan_start: Option<DateTime<Utc>>,
+        plan_end: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let next = self.next_scheduled_at(plan_start, plan_end)?;
+        if next > now {
+            return None;
+        }
+        let first = self.first_fire_at(plan_start, plan_end)?;
+        let mut index = self.elapsed_intervals(first, now);
+        if let Some(count) = self.repeat_count {
+            index = index.min(i64::from(count.max(0)));
+        }
+        Some(self.fire_at(first, index).unwrap_or(next).max(next))
+    }
+}
+
+impl Trackable for Reminder {
+    fn mark_created(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.created_at = timestamp;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+        self.deleted = false;
+    }
+
+    fn mark_updated(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_deleted(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = true;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_restored(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = false;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    fn get_updated_by(&self) -> UserId {
+        self.updated_by
+    }
+
+    fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn get_updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
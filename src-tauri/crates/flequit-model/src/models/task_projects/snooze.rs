@@ -0,0 +1,144 @@
+//! スヌーズモデル
+//!
+//! このモジュールはタスク・サブタスク・リマインダーのスヌーズ（先送り）の記録を定義します。
+//!
+//! ## 概要
+//!
+//! `Snooze`は1回のスヌーズを表し、スヌーズ前の予定日時を残して分析に使えるようにします。
+//! タスク・サブタスクのスヌーズは予定日時を後ろへずらし、`snoozed_until`まで
+//! スマートリストから隠します（先送り中）。リマインダーのスヌーズは
+//! `snoozed_until`に改めて通知します。
+
+use crate::traits::Trackable;
+use crate::types::id_types::{ProjectId, ReminderId, SnoozeId, SubTaskId, TaskId, UserId};
+use chrono::{DateTime, Utc};
+use partially::Partial;
+use serde::{Deserialize, Serialize};
+
+/// スヌーズの記録を表現する構造体
+///
+/// # フィールド
+///
+/// * `id` - スヌーズの一意識別子
+/// * `project_id` - 所属プロジェクトID
+/// * `task_id` - 対象のタスクID
+/// * `subtask_id` - 対象のサブタスクID（タスク自体のスヌーズの場合は`None`）
+/// * `reminder_id` - 対象のリマインダーID（タスク・サブタスクのスヌーズの場合は`None`）
+/// * `original_plan_start_date` - スヌーズ前の予定開始日時
+/// * `original_plan_end_date` - スヌーズ前の予定終了日時
+/// * `original_remind_at` - スヌーズ前の通知予定日時（リマインダーのスヌーズのみ）
+/// * `snoozed_until` - 先送り先の日時
+///
+/// # 使用例
+///
+/// ```rust,no_run
+/// # use chrono::{Duration, Utc};
+/// # use flequit_model::models::task_projects::snooze::Snooze;
+/// # use flequit_model::types::id_types::{ProjectId, SnoozeId, TaskId, UserId};
+///
+/// // 期限が今日のタスクを1日先送りする
+/// let now = Utc::now();
+/// let user_id = UserId::new();
+/// let snooze = Snooze {
+///     id: SnoozeId::new(),
+///     project_id: ProjectId::new(),
+///     task_id: TaskId::new(),
+///     subtask_id: None,
+///     reminder_id: None,
+///     original_plan_start_date: None,
+///     original_plan_end_date: Some(now),
+///     original_remind_at: None,
+///     snoozed_until: now + Duration::days(1),
+///     created_at: now,
+///     updated_at: now,
+///     deleted: false,
+///     updated_by: user_id,
+/// };
+/// assert!(snooze.is_deferring(now));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Partial)]
+#[partially(derive(Debug, Clone, Serialize, Deserialize, Default))]
+pub struct Snooze {
+    /// スヌーズの一意識別子
+    #[partially(omit)] // IDは更新対象外
+    pub id: SnoozeId,
+    /// 所属プロジェクトID
+    #[partially(omit)] // プロジェクト間の移動は対象外
+    pub project_id: ProjectId,
+    /// 対象のタスクID
+    #[partially(omit)] // 対象の付け替えは対象外
+    pub task_id: TaskId,
+    /// 対象のサブタスクID（タスク自体のスヌーズの場合は`None`）
+    #[partially(omit)]
+    pub subtask_id: Option<SubTaskId>,
+    /// 対象のリマインダーID（タスク・サブタスクのスヌーズの場合は`None`）
+    #[partially(omit)]
+    pub reminder_id: Option<ReminderId>,
+    /// スヌーズ前の予定開始日時
+    pub original_plan_start_date: Option<DateTime<Utc>>,
+    /// スヌーズ前の予定終了日時
+    pub original_plan_end_date: Option<DateTime<Utc>>,
+    /// スヌーズ前の通知予定日時（リマインダーのスヌーズのみ）
+    pub original_remind_at: Option<DateTime<Utc>>,
+    /// 先送り先の日時
+    pub snoozed_until: DateTime<Utc>,
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+    /// 最終更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 論理削除フラグ（Automerge同期用）
+    pub deleted: bool,
+    /// 最終更新者のユーザーID（必須、作成・更新・削除・復元すべての操作で記録）
+    pub updated_by: UserId,
+}
+
+impl Snooze {
+    /// タスク・サブタスクを`now`の時点で先送り中にしているかどうか
+    ///
+    /// リマインダーのスヌーズは対象を先送り中にしない。
+    pub fn is_deferring(&self, now: DateTime<Utc>) -> bool {
+        !self.deleted && self.reminder_id.is_none() && self.snoozed_until > now
+    }
+}
+
+impl Trackable for Snooze {
+    fn mark_created(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.created_at = timestamp;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+        self.deleted = false;
+    }
+
+    fn mark_updated(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_deleted(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = true;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_restored(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = false;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    fn get_updated_by(&self) -> UserId {
+        self.updated_by
+    }
+
+    fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn get_updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
@@ -0,0 +1,123 @@
+//! ステータス遷移ルールモデル
+//!
+//! このモジュールはプロジェクトごとのタスク・サブタスクのステータス遷移ルールを定義します。
+//!
+//! ## 概要
+//!
+//! `StatusTransitionRule`は「あるステータスから別のステータスへの遷移」に対する制約を表します。
+//! プロジェクト内のルールの集合がそのプロジェクトの遷移ポリシーとなり、
+//! ルールが存在しない遷移はすべて許可されます。
+
+use crate::traits::Trackable;
+use crate::types::id_types::{ProjectId, StatusTransitionRuleId, UserId};
+use crate::types::task_types::{StatusTransitionRequirement, TaskStatus};
+use chrono::{DateTime, Utc};
+use partially::Partial;
+use serde::{Deserialize, Serialize};
+
+/// ステータス遷移ルールを表現する構造体
+///
+/// # フィールド
+///
+/// * `id` - ルールの一意識別子
+/// * `project_id` - 所属プロジェクトID
+/// * `from_status` - 遷移元のステータス
+/// * `to_status` - 遷移先のステータス
+/// * `requirement` - 遷移に課す条件（禁止・理由必須）
+///
+/// # 使用例
+///
+/// ```rust,no_run
+/// # use chrono::Utc;
+/// # use flequit_model::models::task_projects::status_transition_rule::StatusTransitionRule;
+/// # use flequit_model::types::id_types::{ProjectId, StatusTransitionRuleId, UserId};
+/// # use flequit_model::types::task_types::{StatusTransitionRequirement, TaskStatus};
+///
+/// // 中止したタスクを再開するときは理由を必須にする
+/// let user_id = UserId::new();
+/// let rule = StatusTransitionRule {
+///     id: StatusTransitionRuleId::new(),
+///     project_id: ProjectId::new(),
+///     from_status: TaskStatus::Cancelled,
+///     to_status: TaskStatus::InProgress,
+///     requirement: StatusTransitionRequirement::ReasonRequired,
+///     created_at: Utc::now(),
+///     updated_at: Utc::now(),
+///     deleted: false,
+///     updated_by: user_id,
+/// };
+/// assert!(rule.applies_to(&TaskStatus::Cancelled, &TaskStatus::InProgress));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Partial)]
+#[partially(derive(Debug, Clone, Serialize, Deserialize, Default))]
+pub struct StatusTransitionRule {
+    /// ルールの一意識別子
+    #[partially(omit)] // IDは更新対象外
+    pub id: StatusTransitionRuleId,
+    /// 所属プロジェクトID
+    #[partially(omit)] // プロジェクト間の移動は対象外
+    pub project_id: ProjectId,
+    /// 遷移元のステータス
+    pub from_status: TaskStatus,
+    /// 遷移先のステータス
+    pub to_status: TaskStatus,
+    /// 遷移に課す条件
+    pub requirement: StatusTransitionRequirement,
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+    /// 最終更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 論理削除フラグ（Automerge同期用）
+    pub deleted: bool,
+    /// 最終更新者のユーザーID（必須、作成・更新・削除・復元すべての操作で記録）
+    pub updated_by: UserId,
+}
+
+impl StatusTransitionRule {
+    /// `from`から`to`への遷移に適用されるルールかどうか
+    pub fn applies_to(&self, from: &TaskStatus, to: &TaskStatus) -> bool {
+        self.from_status == *from && self.to_status == *to
+    }
+}
+
+impl Trackable for StatusTransitionRule {
+    fn mark_created(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.created_at = timestamp;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+        self.deleted = false;
+    }
+
+    fn mark_updated(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_deleted(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = true;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_restored(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = false;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    fn get_updated_by(&self) -> UserId {
+        self.updated_by
+    }
+
+    fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn get_updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
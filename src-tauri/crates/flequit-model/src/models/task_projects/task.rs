@@ -10,7 +10,7 @@
 
 use super::recurrence_rule::RecurrenceRule;
 use crate::types::{
-    id_types::{TagId, TaskId, TaskListId, UserId},
+    id_types::{TagId, TaskId, TaskListId, UserId, WorkflowStatusId},
     task_types::TaskStatus,
 };
 use async_trait::async_trait;
@@ -40,6 +40,7 @@ use crate::types::id_types::ProjectId;
 ///
 /// ## 状態管理
 /// * `status` - タスクステータス（TODO、進行中、完了等）
+/// * `workflow_status_id` - 参照するワークフローステータスID（Optional、`status`はその分類と一致）
 /// * `priority` - 優先度（数値、高いほど優先）
 /// * `is_archived` - アーカイブ状態フラグ
 /// * `order_index` - 表示順序（昇順ソート用）
@@ -86,6 +87,9 @@ pub struct Task {
     pub description: Option<String>,
     /// タスクステータス（TODO、進行中、完了等）
     pub status: TaskStatus,
+    /// 参照するワークフローステータスID（未設定なら組み込みのステータスのみを使う）
+    #[serde(default)]
+    pub workflow_status_id: Option<WorkflowStatusId>,
     /// 優先度（数値、高いほど優先）
     pub priority: i32,
     /// 予定開始日時（Optional）
@@ -164,6 +168,7 @@ pub struct Task {
 ///     title: "新機能の実装".to_string(),
 ///     description: Some("ユーザー管理機能を実装".to_string()),
 ///     status: TaskStatus::InProgress,
+///     workflow_status_id: None,
 ///     priority: 5,
 ///     plan_start_date: Some(Utc::now()),
 ///     plan_end_date: None,
@@ -196,6 +201,9 @@ pub struct TaskTree {
     pub description: Option<String>,
     /// タスクステータス（TODO、進行中、完了等）
     pub status: TaskStatus, // StringからTaskStatusに修正
+    /// 参照するワークフローステータスID（未設定なら組み込みのステータスのみを使う）
+    #[serde(default)]
+    pub workflow_status_id: Option<WorkflowStatusId>,
     /// 優先度（数値、高いほど優先）
     pub priority: i32,
     /// 予定開始日時（Optional）
@@ -241,6 +249,7 @@ impl ModelConverter<Task> for TaskTree {
             title: self.title.clone(),
             description: self.description.clone(),
             status: self.status.clone(),
+            workflow_status_id: self.workflow_status_id,
             priority: self.priority,
             plan_start_date: self.plan_start_date,
             plan_end_date: self.plan_end_date,
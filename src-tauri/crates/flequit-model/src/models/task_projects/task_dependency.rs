@@ -0,0 +1,95 @@
+//! タスク依存関係モデル
+//!
+//! このモジュールは、タスク間の依存関係（先行タスクの完了待ち）を管理する
+//! モデルを定義します。
+
+use crate::traits::Trackable;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::id_types::{TaskId, UserId};
+
+/// タスク間の依存関係を表現するモデル
+///
+/// `task_id` のタスクは `depends_on_task_id` のタスク（先行タスク）が完了するまでブロックされます。
+/// 同じプロジェクト内であれば、異なるタスクリストのタスク同士でも依存関係を設定できます。
+///
+/// # フィールド
+///
+/// * `task_id` - ブロックされる側（後続）のタスクID
+/// * `depends_on_task_id` - ブロックする側（先行）のタスクID
+/// * `created_at` - 依存関係の作成日時
+///
+/// # 使用例
+///
+/// ```rust,no_run
+/// # use flequit_model::models::task_projects::task_dependency::TaskDependency;
+/// # use flequit_model::types::id_types::{TaskId, UserId};
+/// # use chrono::Utc;
+///
+/// let dependency = TaskDependency {
+///     task_id: TaskId::from("task_123".to_string()),
+///     depends_on_task_id: TaskId::from("task_456".to_string()),
+///     created_at: Utc::now(),
+///     updated_at: Utc::now(),
+///     deleted: false,
+///     updated_by: UserId::new(),
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TaskDependency {
+    /// ブロックされる側（後続）のタスクID
+    pub task_id: TaskId,
+    /// ブロックする側（先行）のタスクID
+    pub depends_on_task_id: TaskId,
+    /// 依存関係の作成日時
+    pub created_at: DateTime<Utc>,
+    /// 最終更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 論理削除フラグ（Automerge同期用）
+    pub deleted: bool,
+    /// 最終更新者のユーザーID（必須、作成・更新・削除・復元すべての操作で記録）
+    pub updated_by: UserId,
+}
+
+impl Trackable for TaskDependency {
+    fn mark_created(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.created_at = timestamp;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+        self.deleted = false;
+    }
+
+    fn mark_updated(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_deleted(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = true;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_restored(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = false;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    fn get_updated_by(&self) -> UserId {
+        self.updated_by
+    }
+
+    fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn get_updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
@@ -0,0 +1,152 @@
+//! 作業時間記録モデル
+//!
+//! このモジュールはタスク・サブタスクに対する作業時間の記録（タイムエントリ）を定義します。
+//!
+//! ## 概要
+//!
+//! `TimeEntry`は1回分の作業セッションを表し、1つのタスクに対して複数記録できます。
+//! タスクの`do_start_date`/`do_end_date`が作業期間全体を表すのに対し、
+//! タイムエントリは実際に作業した区間を積み上げて作業時間を集計するために使用します。
+
+use crate::traits::Trackable;
+use crate::types::id_types::{ProjectId, SubTaskId, TaskId, TimeEntryId, UserId};
+use chrono::{DateTime, Duration, Utc};
+use partially::Partial;
+use serde::{Deserialize, Serialize};
+
+/// 作業時間の記録を表現する構造体
+///
+/// # フィールド
+///
+/// * `id` - タイムエントリの一意識別子
+/// * `project_id` - 所属プロジェクトID
+/// * `task_id` - 作業対象のタスクID
+/// * `subtask_id` - 作業対象のサブタスクID（タスク自体の作業の場合は`None`）
+/// * `user_id` - 作業したユーザーのID
+/// * `started_at` - 作業開始日時
+/// * `ended_at` - 作業終了日時（タイマー計測中は`None`）
+/// * `note` - 作業内容のメモ
+///
+/// # 使用例
+///
+/// ```rust,no_run
+/// # use chrono::Utc;
+/// # use flequit_model::models::task_projects::time_entry::TimeEntry;
+/// # use flequit_model::types::id_types::{ProjectId, TaskId, TimeEntryId, UserId};
+///
+/// let user_id = UserId::new();
+/// let entry = TimeEntry {
+///     id: TimeEntryId::new(),
+///     project_id: ProjectId::new(),
+///     task_id: TaskId::new(),
+///     subtask_id: None,
+///     user_id,
+///     started_at: Utc::now(),
+///     ended_at: None,
+///     note: Some("レビュー対応".to_string()),
+///     created_at: Utc::now(),
+///     updated_at: Utc::now(),
+///     deleted: false,
+///     updated_by: user_id,
+/// };
+/// assert!(entry.is_running());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Partial)]
+#[partially(derive(Debug, Clone, Serialize, Deserialize, Default))]
+pub struct TimeEntry {
+    /// タイムエントリの一意識別子
+    #[partially(omit)] // IDは更新対象外
+    pub id: TimeEntryId,
+    /// 所属プロジェクトID
+    #[partially(omit)] // プロジェクト間の移動は対象外
+    pub project_id: ProjectId,
+    /// 作業対象のタスクID
+    pub task_id: TaskId,
+    /// 作業対象のサブタスクID（タスク自体の作業の場合は`None`）
+    pub subtask_id: Option<SubTaskId>,
+    /// 作業したユーザーのID
+    #[partially(omit)] // 記録者は変更しない
+    pub user_id: UserId,
+    /// 作業開始日時
+    pub started_at: DateTime<Utc>,
+    /// 作業終了日時（タイマー計測中は`None`）
+    pub ended_at: Option<DateTime<Utc>>,
+    /// 作業内容のメモ
+    pub note: Option<String>,
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+    /// 最終更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 論理削除フラグ（Automerge同期用）
+    pub deleted: bool,
+    /// 最終更新者のユーザーID（必須、作成・更新・削除・復元すべての操作で記録）
+    pub updated_by: UserId,
+}
+
+impl TimeEntry {
+    /// タイマー計測中（終了日時が未記録）かどうか
+    pub fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// `from`〜`to`の期間に含まれる作業時間
+    ///
+    /// 期間をまたぐ記録は期間内の部分だけを数える。
+    /// 計測中の記録は`now`まで作業しているものとして扱う。
+    pub fn duration_within(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Duration {
+        let start = self.started_at.max(from);
+        let end = self.ended_at.unwrap_or(now).min(to);
+        if end > start {
+            end - start
+        } else {
+            Duration::zero()
+        }
+    }
+}
+
+impl Trackable for TimeEntry {
+    fn mark_created(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.created_at = timestamp;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+        self.deleted = false;
+    }
+
+    fn mark_updated(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_deleted(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = true;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_restored(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = false;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    fn get_updated_by(&self) -> UserId {
+        self.updated_by
+    }
+
+    fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn get_updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
@@ -0,0 +1,122 @@
+//! ワークフローステータスモデル
+//!
+//! このモジュールはプロジェクトごとに定義するタスクのステータス（カンバンの列）を定義します。
+//!
+//! ## 概要
+//!
+//! `WorkflowStatus`は「レビュー」「QA」「ブロック中」のような、プロジェクト独自のステータスを表します。
+//! 各ステータスは組み込みの`TaskStatus`のいずれかを分類（`category`）として持ち、
+//! タスクがワークフローステータスを参照するとき、タスクの`status`は常にその分類と一致します。
+//! 集計や作業日時の自動設定は分類（`TaskStatus`）に基づいて行われます。
+//!
+//! ワークフローステータスを定義していないプロジェクト、
+//! およびワークフローステータスを参照していないタスクでは組み込みの`TaskStatus`をそのまま使います。
+
+use crate::traits::Trackable;
+use crate::types::id_types::{ProjectId, UserId, WorkflowStatusId};
+use crate::types::task_types::TaskStatus;
+use chrono::{DateTime, Utc};
+use partially::Partial;
+use serde::{Deserialize, Serialize};
+
+/// ワークフローステータスを表現する構造体
+///
+/// # フィールド
+///
+/// * `id` - ステータスの一意識別子
+/// * `project_id` - 所属プロジェクトID
+/// * `name` - 表示名
+/// * `color` - 表示色（Optional）
+/// * `order_index` - 表示順序（昇順ソート用）
+/// * `category` - 分類（集計に使う組み込みのステータス）
+///
+/// # 使用例
+///
+/// ```rust,no_run
+/// # use chrono::Utc;
+/// # use flequit_model::models::task_projects::workflow_status::WorkflowStatus;
+/// # use flequit_model::types::id_types::{ProjectId, UserId, WorkflowStatusId};
+/// # use flequit_model::types::task_types::TaskStatus;
+///
+/// // 「レビュー」は実行中として集計する
+/// let status = WorkflowStatus {
+///     id: WorkflowStatusId::new(),
+///     project_id: ProjectId::new(),
+///     name: "レビュー".to_string(),
+///     color: Some("#f59e0b".to_string()),
+///     order_index: 2,
+///     category: TaskStatus::InProgress,
+///     created_at: Utc::now(),
+///     updated_at: Utc::now(),
+///     deleted: false,
+///     updated_by: UserId::new(),
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Partial)]
+#[partially(derive(Debug, Clone, Serialize, Deserialize, Default))]
+pub struct WorkflowStatus {
+    /// ステータスの一意識別子
+    #[partially(omit)] // IDは更新対象外
+    pub id: WorkflowStatusId,
+    /// 所属プロジェクトID
+    #[partially(omit)] // プロジェクト間の移動は対象外
+    pub project_id: ProjectId,
+    /// 表示名
+    pub name: String,
+    /// 表示色（Optional）
+    pub color: Option<String>,
+    /// 表示順序（昇順ソート用）
+    pub order_index: i32,
+    /// 分類（集計に使う組み込みのステータス）
+    pub category: TaskStatus,
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+    /// 最終更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 論理削除フラグ（Automerge同期用）
+    pub deleted: bool,
+    /// 最終更新者のユーザーID（必須、作成・更新・削除・復元すべての操作で記録）
+    pub updated_by: UserId,
+}
+
+impl Trackable for WorkflowStatus {
+    fn mark_created(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.created_at = timestamp;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+        self.deleted = false;
+    }
+
+    fn mark_updated(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_deleted(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = true;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn mark_restored(&mut self, user_id: UserId, timestamp: DateTime<Utc>) {
+        self.deleted = false;
+        self.updated_at = timestamp;
+        self.updated_by = user_id;
+    }
+
+    fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    fn get_updated_by(&self) -> UserId {
+        self.updated_by
+    }
+
+    fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn get_updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
@@ -76,3 +76,9 @@ define_id!(WeekdayConditionId);
 define_id!(TaskRecurrenceId);
 define_id!(SubTaskRecurrenceId);
 define_id!(TagBookmarkId);
+define_id!(TimeEntryId);
+define_id!(StatusTransitionRuleId);
+define_id!(WorkflowStatusId);
+define_id!(ReminderId);
+define_id!(SnoozeId);
+define_id!(CommentId);
@@ -18,3 +18,61 @@ pub enum TaskStatus {
     /// 中止
     Cancelled,
 }
+
+impl TaskStatus {
+    /// シリアライズ時と同じ表記（`in_progress`など）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::NotStarted => "not_started",
+            TaskStatus::InProgress => "in_progress",
+            TaskStatus::Waiting => "waiting",
+            TaskStatus::Completed => "completed",
+            TaskStatus::Cancelled => "cancelled",
+        }
+    }
+
+    /// 完了・中止のどちらか（作業が終わっている状態）かどうか
+    pub fn is_closed(&self) -> bool {
+        matches!(self, TaskStatus::Completed | TaskStatus::Cancelled)
+    }
+}
+
+impl std::fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// ステータス遷移ルールが遷移に課す条件
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusTransitionRequirement {
+    /// 遷移を禁止する
+    Forbidden,
+    /// 遷移の理由の入力を必須とする
+    ReasonRequired,
+}
+
+/// リマインダーの通知日時の基準
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReminderAnchor {
+    /// 指定した日時に通知する
+    #[default]
+    Absolute,
+    /// 予定開始日時を基準にする
+    PlanStart,
+    /// 予定終了日時（期限）を基準にする
+    PlanEnd,
+}
+
+impl ReminderAnchor {
+    /// シリアライズ時と同じ表記（`plan_start`など）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReminderAnchor::Absolute => "absolute",
+            ReminderAnchor::PlanStart => "plan_start",
+            ReminderAnchor::PlanEnd => "plan_end",
+        }
+    }
+}
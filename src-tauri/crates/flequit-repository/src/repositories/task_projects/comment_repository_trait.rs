@@ -0,0 +1,13 @@
+use crate::repositories::project_repository_trait::ProjectRepository;
+use async_trait::async_trait;
+use flequit_model::models::task_projects::comment::Comment;
+use flequit_model::types::id_types::CommentId;
+
+/// 統合コメントリポジトリトレイト
+///
+/// ProjectRepositoryから基本CRUD操作を継承する。
+/// 削除は論理削除とし、Service層で`deleted`を立てて保存する。
+#[async_trait]
+pub trait CommentRepositoryTrait: ProjectRepository<Comment, CommentId> + Send + Sync {
+    // ProjectRepositoryのfind_allでプロジェクト内の全コメントを取得可能
+}
@@ -0,0 +1,13 @@
+use crate::repositories::project_repository_trait::ProjectRepository;
+use async_trait::async_trait;
+use flequit_model::models::task_projects::reminder::Reminder;
+use flequit_model::types::id_types::ReminderId;
+
+/// 統合リマインダーリポジトリトレイト
+///
+/// ProjectRepositoryから基本CRUD操作を継承する。
+/// 通知日時の判定や通知済みの記録はService層で基本CRUDを組み合わせて実装。
+#[async_trait]
+pub trait ReminderRepositoryTrait: ProjectRepository<Reminder, ReminderId> + Send + Sync {
+    // ProjectRepositoryのfind_allでプロジェクト内の全リマインダーを取得可能
+}
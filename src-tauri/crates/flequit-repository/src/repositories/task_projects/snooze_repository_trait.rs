@@ -0,0 +1,13 @@
+use crate::repositories::project_repository_trait::ProjectRepository;
+use async_trait::async_trait;
+use flequit_model::models::task_projects::snooze::Snooze;
+use flequit_model::types::id_types::SnoozeId;
+
+/// 統合スヌーズリポジトリトレイト
+///
+/// ProjectRepositoryから基本CRUD操作を継承する。
+/// 先送り中かどうかの判定はService層で基本CRUDを組み合わせて実装。
+#[async_trait]
+pub trait SnoozeRepositoryTrait: ProjectRepository<Snooze, SnoozeId> + Send + Sync {
+    // ProjectRepositoryのfind_allでプロジェクト内の全スヌーズの記録を取得可能
+}
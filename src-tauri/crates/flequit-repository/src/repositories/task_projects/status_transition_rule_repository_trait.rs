@@ -0,0 +1,15 @@
+use crate::repositories::project_repository_trait::ProjectRepository;
+use async_trait::async_trait;
+use flequit_model::models::task_projects::status_transition_rule::StatusTransitionRule;
+use flequit_model::types::id_types::StatusTransitionRuleId;
+
+/// 統合ステータス遷移ルールリポジトリトレイト
+///
+/// ProjectRepositoryから基本CRUD操作を継承する。
+/// 遷移の可否の判定はService層でプロジェクト内の全ルールを取得して行う。
+#[async_trait]
+pub trait StatusTransitionRuleRepositoryTrait:
+    ProjectRepository<StatusTransitionRule, StatusTransitionRuleId> + Send + Sync
+{
+    // ProjectRepositoryのfind_allでプロジェクトの遷移ポリシー（全ルール）を取得可能
+}
@@ -0,0 +1,18 @@
+//! タスク依存関係リポジトリトレイト
+
+use async_trait::async_trait;
+use flequit_model::models::task_projects::task_dependency::TaskDependency;
+use flequit_model::types::id_types::TaskId;
+
+use crate::repositories::project_relation_repository_trait::ProjectRelationRepository;
+
+#[async_trait]
+pub trait TaskDependencyRepositoryTrait:
+    ProjectRelationRepository<TaskDependency, TaskId, TaskId>
+{
+    // ProjectRelationRepositoryのメソッドを使用：
+    // - add: プロジェクト内でタスクに先行タスクを紐付け
+    // - remove: プロジェクト内で依存関係を削除
+    // - find_relations: プロジェクト内でタスクの先行タスク一覧を取得
+    // - find_all: プロジェクト内の全依存関係を取得
+}
@@ -0,0 +1,15 @@
+use crate::repositories::project_repository_trait::ProjectRepository;
+use async_trait::async_trait;
+use flequit_model::models::task_projects::time_entry::TimeEntry;
+use flequit_model::types::id_types::TimeEntryId;
+
+/// 統合タイムエントリリポジトリトレイト
+///
+/// ProjectRepositoryから基本CRUD操作を継承する。
+/// 計測中タイマーの検索や作業時間の集計はService層で基本CRUDを組み合わせて実装。
+#[async_trait]
+pub trait TimeEntryRepositoryTrait:
+    ProjectRepository<TimeEntry, TimeEntryId> + Send + Sync
+{
+    // ProjectRepositoryのfind_allでプロジェクト内の全タイムエントリを取得可能
+}
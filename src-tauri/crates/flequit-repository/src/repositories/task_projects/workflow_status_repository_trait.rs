@@ -0,0 +1,14 @@
+use crate::repositories::project_repository_trait::ProjectRepository;
+use async_trait::async_trait;
+use flequit_model::models::task_projects::workflow_status::WorkflowStatus;
+use flequit_model::types::id_types::WorkflowStatusId;
+
+/// 統合ワークフローステータスリポジトリトレイト
+///
+/// ProjectRepositoryから基本CRUD操作を継承する。
+#[async_trait]
+pub trait WorkflowStatusRepositoryTrait:
+    ProjectRepository<WorkflowStatus, WorkflowStatusId> + Send + Sync
+{
+    // ProjectRepositoryのfind_allでプロジェクトのワークフローステータスを取得可能
+}
@@ -0,0 +1,682 @@
+//! REST APIのルーティングとハンドラー
+//!
+//! パス構成はクライアントのWebリポジトリ（`flequit-infrastructure` の `web` モジュール）と同一。
+//! 加えてプロジェクトごとのAutomerge同期メッセージの中継を提供する。
+//!
+//! - `GET/POST /api/v1/projects/{project_id}/sync`: 同期メッセージの取得・送信
+//! - `GET /health`: 稼働確認（認証不要）
+//!
+//! プロジェクトへのアクセスはアカウント単位で管理する。
+//! どのアカウントにも割り当てられていないプロジェクトは、最初に書き込んだアカウントに割り当てられる。
+//! プロジェクトに属さないリソース（アカウント・ユーザー）は最初に書き込んだアカウントが所有し、
+//! 他のアカウントからは参照・更新・削除できない。
+
+use crate::collections::{Collection, CollectionKind, PROJECTS, SUBTASK_RECURRENCES};
+use crate::error::ServerError;
+use crate::repository::ServerProjectRepository;
+use crate::store::{GLOBAL_SCOPE, ResourceKey, ResourceQuery, ServerAccount, ServerStore};
+use axum::body::Bytes;
+use axum::extract::{FromRequestParts, Path, Query, State};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::routing::get;
+use axum::{Json, Router};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::project::Project;
+use flequit_model::types::id_types::{ProjectId, UserId};
+use flequit_repository::repositories::base_repository_trait::Repository;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// 書き込みユーザーを示すヘッダー
+pub const USER_ID_HEADER: &str = "X-Flequit-User-Id";
+/// 書き込み時刻（RFC3339）を示すヘッダー
+pub const TIMESTAMP_HEADER: &str = "X-Flequit-Timestamp";
+
+/// 同期メッセージ取得の既定件数
+const DEFAULT_SYNC_LIMIT: u64 = 100;
+/// 同期メッセージ取得の最大件数
+const MAX_SYNC_LIMIT: u64 = 1000;
+
+/// リレーションの子IDで絞り込むクエリパラメーター
+const CHILD_ID_PARAM: &str = "child_id";
+
+type ApiResult<T> = Result<T, ServerError>;
+type QueryParams = Query<Vec<(String, String)>>;
+
+/// Bearerトークンで認証されたアカウント
+#[derive(Debug, Clone)]
+pub struct Authenticated(pub ServerAccount);
+
+impl FromRequestParts<ServerStore> for Authenticated {
+    type Rejection = ServerError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        store: &ServerStore,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .ok_or(ServerError::Unauthorized)?;
+        store
+            .authenticate(token)
+            .await?
+            .map(Self)
+            .ok_or(ServerError::Unauthorized)
+    }
+}
+
+/// プロジェクトに対する操作の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProjectAccess {
+    /// 参照・削除（未割り当てのプロジェクトは存在しない扱い）
+    Existing,
+    /// 作成・更新（未割り当てのプロジェクトは操作したアカウントに割り当てる）
+    ClaimIfNew,
+}
+
+async fn authorize(
+    store: &ServerStore,
+    account: &ServerAccount,
+    project_id: &str,
+    access: ProjectAccess,
+) -> ApiResult<()> {
+    if store.has_access(project_id, &account.id).await? {
+        return Ok(());
+    }
+    if access == ProjectAccess::ClaimIfNew && store.claim_project(project_id, &account.id).await? {
+        tracing::info!("Project {} claimed by {}", project_id, account.name);
+        return Ok(());
+    }
+    if store.is_claimed(project_id).await? {
+        Err(ServerError::Forbidden(format!(
+            "No access to project {project_id}"
+        )))
+    } else {
+        Err(ServerError::NotFound(format!(
+            "Project not found: {project_id}"
+        )))
+    }
+}
+
+/// 書き込みヘッダーから更新者と更新時刻を取得
+fn write_metadata(headers: &HeaderMap) -> ApiResult<(Option<String>, Option<DateTime<Utc>>)> {
+    let header_value = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+    let updated_by = header_value(USER_ID_HEADER).map(str::to_string);
+    let updated_at = header_value(TIMESTAMP_HEADER)
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|t| t.with_timezone(&Utc))
+                .map_err(|e| ServerError::BadRequest(format!("Invalid {TIMESTAMP_HEADER}: {e}")))
+        })
+        .transpose()?;
+    Ok((updated_by, updated_at))
+}
+
+async fn put_resource(
+    store: &ServerStore,
+    collection: &Collection,
+    key: ResourceKey,
+    headers: &HeaderMap,
+    body: &Value,
+) -> ApiResult<StatusCode> {
+    let project_id = (key.project_id != GLOBAL_SCOPE).then_some(key.project_id.as_str());
+    let child = (!key.child.is_empty()).then_some(key.child.as_str());
+    collection.validate(body, project_id, &key.key, child)?;
+    let (updated_by, updated_at) = write_metadata(headers)?;
+    if !store
+        .put_resource(&key, body, updated_by.as_deref(), updated_at)
+        .await?
+    {
+        return Err(ServerError::Forbidden(format!(
+            "{} {} belongs to another account",
+            key.collection, key.key
+        )));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 条件に一致するリソースがない場合のエラー
+///
+/// 所有者で絞り込んだ条件で、他のアカウントのリソースだけが一致する場合は403とする。
+async fn missing(store: &ServerStore, query: &ResourceQuery) -> ServerError {
+    if query.owner.is_some() {
+        match store.find_resources(&query.clone().any_owner()).await {
+            Ok(others) if !others.is_empty() => {
+                return ServerError::Forbidden(format!(
+                    "{} belongs to another account",
+                    query.collection
+                ));
+            }
+            Ok(_) => {}
+            Err(e) => return e,
+        }
+    }
+    ServerError::NotFound(format!("{} not found", query.collection))
+}
+
+async fn find_one(store: &ServerStore, query: &ResourceQuery) -> ApiResult<Json<Value>> {
+    match store.find_resources(query).await?.into_iter().next() {
+        Some(body) => Ok(Json(body)),
+        None => Err(missing(store, query).await),
+    }
+}
+
+async fn delete_matching(store: &ServerStore, query: &ResourceQuery) -> ApiResult<StatusCode> {
+    if store.delete_resources(query).await? == 0 {
+        return Err(missing(store, query).await);
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// コレクション単位の一括削除は条件付きのみ受け付ける
+fn require_filter(query: &ResourceQuery) -> ApiResult<()> {
+    if query.child.is_none() && query.filters.is_empty() {
+        return Err(ServerError::BadRequest(
+            "Deleting a whole collection requires a filter".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// コレクションへのクエリパラメーターを検索条件に変換
+///
+/// 関連付けでは `child_id` を子IDの条件として扱い、それ以外は本文のフィールド条件とする。
+fn collection_query(
+    mut query: ResourceQuery,
+    collection: &Collection,
+    params: Vec<(String, String)>,
+) -> ResourceQuery {
+    let mut filters = Vec::new();
+    for (name, value) in params {
+        if collection.kind == CollectionKind::Relation && name == CHILD_ID_PARAM {
+            query = query.child(value);
+        } else {
+            filters.push((name, value));
+        }
+    }
+    query.filters(filters)
+}
+
+pub fn router(store: ServerStore) -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/api/v1/projects", get(list_projects))
+        .route(
+            "/api/v1/projects/{project_id}",
+            get(get_project).put(put_project).delete(delete_project),
+        )
+        .route(
+            "/api/v1/projects/{project_id}/sync",
+            get(pull_sync_messages).post(push_sync_message),
+        )
+        .route(
+            "/api/v1/projects/{project_id}/{collection}",
+            get(list_project_collection).delete(delete_project_collection),
+        )
+        .route(
+            "/api/v1/projects/{project_id}/{collection}/{key}",
+            get(get_project_resource)
+                .put(put_project_resource)
+                .delete(delete_project_resource),
+        )
+        .route(
+            "/api/v1/projects/{project_id}/{collection}/{key}/{child}",
+            get(get_relation).put(put_relation).delete(delete_relation),
+        )
+        .route(
+            "/api/v1/{collection}",
+            get(list_global_collection).delete(delete_global_collection),
+        )
+        .route(
+            "/api/v1/{collection}/{key}",
+            get(get_global_resource)
+                .put(put_global_resource)
+                .delete(delete_global_resource),
+        )
+        .with_state(store)
+}
+
+async fn health() -> Json<Value> {
+    Json(serde_json::json!({ "status": "ok" }))
+}
+
+// -------------------------------------------------------------------------
+// プロジェクト
+// -------------------------------------------------------------------------
+
+fn parse_project_id(project_id: &str) -> ApiResult<ProjectId> {
+    ProjectId::try_from_str(project_id)
+        .map_err(|e| ServerError::BadRequest(format!("Invalid project id {project_id}: {e}")))
+}
+
+async fn list_projects(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+) -> ApiResult<Json<Vec<Project>>> {
+    let accessible = store.accessible_projects(&account.id).await?;
+    if accessible.is_empty() {
+        return Ok(Json(Vec::new()));
+    }
+    let projects = ServerProjectRepository::new(store)
+        .find_all()
+        .await?
+        .into_iter()
+        .filter(|project| accessible.contains(&project.id.to_string()))
+        .collect();
+    Ok(Json(projects))
+}
+
+async fn get_project(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path(project_id): Path<String>,
+) -> ApiResult<Json<Project>> {
+    let id = parse_project_id(&project_id)?;
+    authorize(&store, &account, &project_id, ProjectAccess::Existing).await?;
+    ServerProjectRepository::new(store)
+        .find_by_id(&id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| ServerError::NotFound(format!("Project not found: {project_id}")))
+}
+
+async fn put_project(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path(project_id): Path<String>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> ApiResult<StatusCode> {
+    let collection = Collection::lookup(PROJECTS, CollectionKind::Global)?;
+    // 割り当て前に検証し、不正な本文でプロジェクトが割り当てられないようにする
+    collection.validate(&body, None, &project_id, None)?;
+    let project: Project = serde_json::from_value(body)
+        .map_err(|e| ServerError::Validation(format!("Invalid {PROJECTS}: {e}")))?;
+    let (updated_by, updated_at) = write_metadata(&headers)?;
+    let user_id = match updated_by {
+        Some(user_id) => UserId::try_from_str(&user_id)
+            .map_err(|e| ServerError::BadRequest(format!("Invalid {USER_ID_HEADER}: {e}")))?,
+        None => project.updated_by,
+    };
+    authorize(&store, &account, &project_id, ProjectAccess::ClaimIfNew).await?;
+    ServerProjectRepository::new(store)
+        .save(
+            &project,
+            &user_id,
+            &updated_at.unwrap_or(project.updated_at),
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn delete_project(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path(project_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    let id = parse_project_id(&project_id)?;
+    authorize(&store, &account, &project_id, ProjectAccess::Existing).await?;
+    ServerProjectRepository::new(store).delete(&id).await?;
+    tracing::info!("Project {} deleted by {}", project_id, account.name);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// -------------------------------------------------------------------------
+// プロジェクト配下のエンティティ・関連付け
+// -------------------------------------------------------------------------
+
+/// プロジェクト配下のコレクション（エンティティまたは関連付け）を取得
+fn project_collection(name: &str) -> ApiResult<&'static Collection> {
+    Collection::lookup(name, CollectionKind::Project)
+        .or_else(|_| Collection::lookup(name, CollectionKind::Relation))
+}
+
+async fn list_project_collection(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path((project_id, collection)): Path<(String, String)>,
+    Query(params): QueryParams,
+) -> ApiResult<Json<Vec<Value>>> {
+    let collection = project_collection(&collection)?;
+    authorize(&store, &account, &project_id, ProjectAccess::Existing).await?;
+    let query = collection_query(
+        ResourceQuery::new(&project_id, collection.name),
+        collection,
+        params,
+    );
+    Ok(Json(store.find_resources(&query).await?))
+}
+
+async fn delete_project_collection(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path((project_id, collection)): Path<(String, String)>,
+    Query(params): QueryParams,
+) -> ApiResult<StatusCode> {
+    let collection = project_collection(&collection)?;
+    authorize(&store, &account, &project_id, ProjectAccess::Existing).await?;
+    let query = collection_query(
+        ResourceQuery::new(&project_id, collection.name),
+        collection,
+        params,
+    );
+    require_filter(&query)?;
+    delete_matching(&store, &query).await
+}
+
+/// エンティティの取得、または関連付けの親単位の一覧
+async fn get_project_resource(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path((project_id, collection, key)): Path<(String, String, String)>,
+) -> ApiResult<Json<Value>> {
+    let collection = project_collection(&collection)?;
+    authorize(&store, &account, &project_id, ProjectAccess::Existing).await?;
+    let query = ResourceQuery::new(&project_id, collection.name).key(key);
+    if collection.kind == CollectionKind::Relation {
+        return Ok(Json(Value::Array(store.find_resources(&query).await?)));
+    }
+    find_one(&store, &query).await
+}
+
+async fn put_project_resource(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path((project_id, collection, key)): Path<(String, String, String)>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> ApiResult<StatusCode> {
+    let collection = Collection::lookup(&collection, CollectionKind::Project)?;
+    collection.validate(&body, Some(&project_id), &key, None)?;
+    authorize(&store, &account, &project_id, ProjectAccess::ClaimIfNew).await?;
+    let key = ResourceKey {
+        project_id,
+        collection: collection.name,
+        key,
+        child: String::new(),
+        owner: None,
+    };
+    put_resource(&store, collection, key, &headers, &body).await
+}
+
+/// エンティティの削除、または関連付けの親単位の一括削除
+async fn delete_project_resource(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path((project_id, collection, key)): Path<(String, String, String)>,
+) -> ApiResult<StatusCode> {
+    let collection = project_collection(&collection)?;
+    authorize(&store, &account, &project_id, ProjectAccess::Existing).await?;
+    delete_matching(
+        &store,
+        &ResourceQuery::new(&project_id, collection.name).key(key),
+    )
+    .await
+}
+
+async fn get_relation(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path((project_id, collection, key, child)): Path<(String, String, String, String)>,
+) -> ApiResult<Json<Value>> {
+    let collection = Collection::lookup(&collection, CollectionKind::Relation)?;
+    authorize(&store, &account, &project_id, ProjectAccess::Existing).await?;
+    find_one(
+        &store,
+        &ResourceQuery::new(&project_id, collection.name)
+            .key(key)
+            .child(child),
+    )
+    .await
+}
+
+async fn put_relation(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path((project_id, collection, key, child)): Path<(String, String, String, String)>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> ApiResult<StatusCode> {
+    let collection = Collection::lookup(&collection, CollectionKind::Relation)?;
+    collection.validate(&body, Some(&project_id), &key, Some(&child))?;
+    authorize(&store, &account, &project_id, ProjectAccess::ClaimIfNew).await?;
+    let key = ResourceKey {
+        project_id,
+        collection: collection.name,
+        key,
+        child,
+        owner: None,
+    };
+    put_resource(&store, collection, key, &headers, &body).await
+}
+
+async fn delete_relation(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path((project_id, collection, key, child)): Path<(String, String, String, String)>,
+) -> ApiResult<StatusCode> {
+    let collection = Collection::lookup(&collection, CollectionKind::Relation)?;
+    authorize(&store, &account, &project_id, ProjectAccess::Existing).await?;
+    delete_matching(
+        &store,
+        &ResourceQuery::new(&project_id, collection.name)
+            .key(key)
+            .child(child),
+    )
+    .await
+}
+
+// -------------------------------------------------------------------------
+// プロジェクトに属さないコレクション
+// -------------------------------------------------------------------------
+
+/// プロジェクトに属さないパスで扱うコレクションと、その検索範囲
+///
+/// サブタスク繰り返しはプロジェクト配下に保存し、
+/// アカウントがアクセスできる全プロジェクトを横断して扱う。
+/// それ以外はアカウントが所有するリソースに限る。
+async fn global_scope(
+    store: &ServerStore,
+    account: &ServerAccount,
+    name: &str,
+) -> ApiResult<(&'static Collection, ResourceQuery)> {
+    if name == SUBTASK_RECURRENCES {
+        let collection = Collection::lookup(name, CollectionKind::Relation)?;
+        let projects = store.accessible_projects(&account.id).await?;
+        return Ok((
+            collection,
+            ResourceQuery::in_projects(projects, collection.name),
+        ));
+    }
+    let collection = Collection::lookup(name, CollectionKind::Global)?;
+    if collection.name == PROJECTS {
+        // プロジェクトは専用のルートで扱う
+        return Err(ServerError::NotFound(format!("Unknown collection: {name}")));
+    }
+    Ok((
+        collection,
+        ResourceQuery::new(GLOBAL_SCOPE, collection.name).owned_by(&account.id),
+    ))
+}
+
+async fn list_global_collection(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path(collection): Path<String>,
+    Query(params): QueryParams,
+) -> ApiResult<Json<Vec<Value>>> {
+    let (_, query) = global_scope(&store, &account, &collection).await?;
+    Ok(Json(store.find_resources(&query.filters(params)).await?))
+}
+
+async fn delete_global_collection(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path(collection): Path<String>,
+    Query(params): QueryParams,
+) -> ApiResult<StatusCode> {
+    let (_, query) = global_scope(&store, &account, &collection).await?;
+    let query = query.filters(params);
+    require_filter(&query)?;
+    delete_matching(&store, &query).await
+}
+
+async fn get_global_resource(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path((collection, key)): Path<(String, String)>,
+) -> ApiResult<Json<Value>> {
+    let (_, query) = global_scope(&store, &account, &collection).await?;
+    find_one(&store, &query.key(key)).await
+}
+
+async fn put_global_resource(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path((collection, key)): Path<(String, String)>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> ApiResult<StatusCode> {
+    let (collection, query) = global_scope(&store, &account, &collection).await?;
+    if collection.kind == CollectionKind::Global {
+        let key = ResourceKey {
+            project_id: GLOBAL_SCOPE.to_string(),
+            collection: collection.name,
+            key,
+            child: String::new(),
+            owner: Some(account.id),
+        };
+        return put_resource(&store, collection, key, &headers, &body).await;
+    }
+
+    // サブタスク繰り返し: 既存の関連付けかサブタスクが属するプロジェクトに保存する
+    let mut projects = store
+        .find_resource_projects(&query.clone().key(&key))
+        .await?;
+    if projects.is_empty() {
+        projects = store
+            .find_resource_projects(
+                &ResourceQuery::in_projects(query.project_ids, "subtasks").key(&key),
+            )
+            .await?;
+    }
+    let project_id = projects
+        .into_iter()
+        .next()
+        .ok_or_else(|| ServerError::NotFound(format!("Subtask not found: {key}")))?;
+    let child = collection
+        .child_field
+        .and_then(|field| body.get(field))
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+
+    // サブタスクに設定できる繰り返しは1つのみ
+    store
+        .delete_resources(&ResourceQuery::new(&project_id, collection.name).key(&key))
+        .await?;
+    let key = ResourceKey {
+        project_id,
+        collection: collection.name,
+        key,
+        child,
+        owner: None,
+    };
+    put_resource(&store, collection, key, &headers, &body).await
+}
+
+async fn delete_global_resource(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path((collection, key)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    let (_, query) = global_scope(&store, &account, &collection).await?;
+    delete_matching(&store, &query.key(key)).await
+}
+
+// -------------------------------------------------------------------------
+// Automerge同期メッセージの中継
+// -------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+struct SyncParams {
+    /// この連番より後のメッセージを取得する
+    since: Option<i64>,
+    limit: Option<u64>,
+}
+
+/// 中継された同期メッセージ（`payload` はBase64）
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SyncMessageResponse {
+    pub seq: i64,
+    pub account_id: String,
+    pub payload: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 同期メッセージの取得結果
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SyncPageResponse {
+    pub messages: Vec<SyncMessageResponse>,
+    /// 次回の取得で `since` に指定する連番
+    pub next_since: i64,
+}
+
+/// 同期メッセージの送信結果
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SyncPushResponse {
+    pub seq: i64,
+}
+
+async fn push_sync_message(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path(project_id): Path<String>,
+    payload: Bytes,
+) -> ApiResult<Json<SyncPushResponse>> {
+    if payload.is_empty() {
+        return Err(ServerError::BadRequest(
+            "Sync message must not be empty".to_string(),
+        ));
+    }
+    authorize(&store, &account, &project_id, ProjectAccess::ClaimIfNew).await?;
+    let seq = store
+        .append_sync_message(&project_id, &account.id, &payload)
+        .await?;
+    Ok(Json(SyncPushResponse { seq }))
+}
+
+async fn pull_sync_messages(
+    State(store): State<ServerStore>,
+    Authenticated(account): Authenticated,
+    Path(project_id): Path<String>,
+    Query(params): Query<SyncParams>,
+) -> ApiResult<Json<SyncPageResponse>> {
+    authorize(&store, &account, &project_id, ProjectAccess::Existing).await?;
+    let since = params.since.unwrap_or(0);
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_SYNC_LIMIT)
+        .clamp(1, MAX_SYNC_LIMIT);
+    let messages = store.sync_messages_since(&project_id, since, limit).await?;
+    let next_since = messages.last().map_or(since, |m| m.seq);
+    Ok(Json(SyncPageResponse {
+        messages: messages
+            .into_iter()
+            .map(|m| SyncMessageResponse {
+                seq: m.seq,
+                account_id: m.account_id,
+                payload: BASE64.encode(m.payload),
+                created_at: m.created_at,
+            })
+            .collect(),
+        next_since,
+    }))
+}
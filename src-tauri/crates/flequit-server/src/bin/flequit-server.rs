@@ -0,0 +1,94 @@
+//! Flequit同期サーバーの実行バイナリ
+//!
+//! `serve` でサーバーを起動し、その他のサブコマンドでアカウントと
+//! プロジェクトのアクセス権を管理する。
+
+use flequit_server::cli::{Cli, Command, USAGE};
+use flequit_server::{FlequitServer, ServerError, ServerStore};
+use tracing_subscriber::EnvFilter;
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+    if let Err(e) = run().await {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
+
+async fn run() -> Result<(), ServerError> {
+    let cli = match Cli::parse(std::env::args().skip(1), |key| std::env::var(key).ok()) {
+        Ok(cli) => cli,
+        Err(e) => {
+            eprintln!("{USAGE}");
+            return Err(e);
+        }
+    };
+    if cli.command == Command::Help {
+        println!("{USAGE}");
+        return Ok(());
+    }
+
+    let store = ServerStore::open(&cli.database_path).await?;
+    match cli.command {
+        Command::Serve { bind } => {
+            if store.list_accounts().await?.is_empty() {
+                tracing::warn!(
+                    "No accounts yet; create one with `flequit-server account add <name>`"
+                );
+            }
+            let handle = FlequitServer::start(store, bind).await?;
+            println!("Flequit server listening on http://{}", handle.local_addr());
+            tokio::signal::ctrl_c().await?;
+            tracing::info!("Shutting down");
+            handle.shutdown().await?;
+        }
+        Command::AddAccount { name } => {
+            let (account, token) = store.create_account(&name).await?;
+            println!("Created account '{}' ({})", account.name, account.id);
+            println!("API token (shown only once): {token}");
+        }
+        Command::ListAccounts => {
+            for account in store.list_accounts().await? {
+                let projects = store.accessible_projects(&account.id).await?;
+                println!(
+                    "{}\t{}\tcreated {}\t{} project(s)",
+                    account.name,
+                    account.id,
+                    account.created_at.to_rfc3339(),
+                    projects.len()
+                );
+            }
+        }
+        Command::RotateToken { name } => {
+            let token = store.rotate_token(&name).await?;
+            println!("New API token for '{name}' (shown only once): {token}");
+        }
+        Command::RemoveAccount { name } => {
+            store.remove_account(&name).await?;
+            println!("Removed account '{name}'");
+        }
+        Command::Grant {
+            project_id,
+            account,
+        } => {
+            store.grant_access(&project_id, &account).await?;
+            println!("Granted '{account}' access to project {project_id}");
+        }
+        Command::Revoke {
+            project_id,
+            account,
+        } => {
+            store.revoke_access(&project_id, &account).await?;
+            println!("Revoked '{account}' access to project {project_id}");
+        }
+        Command::Help => unreachable!("handled above"),
+    }
+    Ok(())
+}
@@ -0,0 +1,188 @@
+//! コマンドライン引数の解析
+
+use crate::error::ServerError;
+use std::net::SocketAddr;
+
+/// 既定の待ち受けアドレス
+pub const DEFAULT_BIND: &str = "127.0.0.1:8787";
+/// 既定のデータベースファイル
+pub const DEFAULT_DATABASE: &str = "flequit-server.db";
+/// データベースファイルを指定する環境変数
+pub const DATABASE_ENV: &str = "FLEQUIT_SERVER_DB";
+/// 待ち受けアドレスを指定する環境変数
+pub const BIND_ENV: &str = "FLEQUIT_SERVER_BIND";
+
+pub const USAGE: &str = "\
+Usage: flequit-server [--db <path>] <command>
+
+Commands:
+  serve [--bind <addr>]              Start the server (default: 127.0.0.1:8787)
+  account add <name>                 Create an account and print its API token
+  account list                       List accounts
+  account rotate <name>              Issue a new API token for an account
+  account remove <name>              Remove an account and its project access
+  grant <project_id> <account>       Allow an account to access a project
+  revoke <project_id> <account>      Revoke an account's access to a project
+
+Environment:
+  FLEQUIT_SERVER_DB                  Database file (default: flequit-server.db)
+  FLEQUIT_SERVER_BIND                Listen address for `serve`";
+
+/// 実行するコマンド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Serve { bind: SocketAddr },
+    AddAccount { name: String },
+    ListAccounts,
+    RotateToken { name: String },
+    RemoveAccount { name: String },
+    Grant { project_id: String, account: String },
+    Revoke { project_id: String, account: String },
+    Help,
+}
+
+/// 解析済みのコマンドライン
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cli {
+    pub database_path: String,
+    pub command: Command,
+}
+
+fn parse_bind(value: &str) -> Result<SocketAddr, ServerError> {
+    value
+        .parse()
+        .map_err(|e| ServerError::Configuration(format!("Invalid bind address '{value}': {e}")))
+}
+
+impl Cli {
+    /// 引数（プログラム名を除く）と環境変数から解析する
+    pub fn parse<I>(args: I, env: impl Fn(&str) -> Option<String>) -> Result<Self, ServerError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut database_path = env(DATABASE_ENV).unwrap_or_else(|| DEFAULT_DATABASE.to_string());
+        let mut bind = None;
+        let mut positional = Vec::new();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value_of = |flag: &str| {
+                args.next()
+                    .ok_or_else(|| ServerError::Configuration(format!("{flag} requires a value")))
+            };
+            match arg.as_str() {
+                "--db" => database_path = value_of("--db")?,
+                "--bind" => bind = Some(parse_bind(&value_of("--bind")?)?),
+                "-h" | "--help" => positional = vec!["help".to_string()],
+                flag if flag.starts_with("--") => {
+                    return Err(ServerError::Configuration(format!(
+                        "Unknown option: {flag}"
+                    )));
+                }
+                _ => positional.push(arg),
+            }
+        }
+
+        let positional: Vec<&str> = positional.iter().map(String::as_str).collect();
+        let command = match positional.as_slice() {
+            [] | ["serve"] => Command::Serve {
+                bind: match bind {
+                    Some(bind) => bind,
+                    None => parse_bind(&env(BIND_ENV).unwrap_or_else(|| DEFAULT_BIND.to_string()))?,
+                },
+            },
+            ["account", "add", name] => Command::AddAccount {
+                name: name.to_string(),
+            },
+            ["account", "list"] => Command::ListAccounts,
+            ["account", "rotate", name] => Command::RotateToken {
+                name: name.to_string(),
+            },
+            ["account", "remove", name] => Command::RemoveAccount {
+                name: name.to_string(),
+            },
+            ["grant", project_id, account] => Command::Grant {
+                project_id: project_id.to_string(),
+                account: account.to_string(),
+            },
+            ["revoke", project_id, account] => Command::Revoke {
+                project_id: project_id.to_string(),
+                account: account.to_string(),
+            },
+            ["help"] => Command::Help,
+            other => {
+                return Err(ServerError::Configuration(format!(
+                    "Unknown command: {}",
+                    other.join(" ")
+                )));
+            }
+        };
+
+        Ok(Self {
+            database_path,
+            command,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str], env: &[(&str, &str)]) -> Result<Cli, ServerError> {
+        let env: Vec<(String, String)> = env
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        Cli::parse(args.iter().map(|a| a.to_string()), |key| {
+            env.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone())
+        })
+    }
+
+    #[test]
+    fn test_parse_serve_defaults_and_overrides() {
+        let cli = parse(&[], &[]).unwrap();
+        assert_eq!(cli.database_path, DEFAULT_DATABASE);
+        assert_eq!(
+            cli.command,
+            Command::Serve {
+                bind: DEFAULT_BIND.parse().unwrap()
+            }
+        );
+
+        let cli = parse(
+            &["serve", "--bind", "0.0.0.0:9000"],
+            &[(DATABASE_ENV, "/srv/flequit.db"), (BIND_ENV, "127.0.0.1:1")],
+        )
+        .unwrap();
+        assert_eq!(cli.database_path, "/srv/flequit.db");
+        assert_eq!(
+            cli.command,
+            Command::Serve {
+                bind: "0.0.0.0:9000".parse().unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_admin_commands() {
+        let cli = parse(&["--db", "team.db", "account", "add", "alice"], &[]).unwrap();
+        assert_eq!(cli.database_path, "team.db");
+        assert_eq!(
+            cli.command,
+            Command::AddAccount {
+                name: "alice".to_string()
+            }
+        );
+        assert_eq!(
+            parse(&["grant", "p1", "bob"], &[]).unwrap().command,
+            Command::Grant {
+                project_id: "p1".to_string(),
+                account: "bob".to_string()
+            }
+        );
+        assert!(parse(&["account", "delete"], &[]).is_err());
+        assert!(parse(&["serve", "--bind", "nowhere"], &[]).is_err());
+        assert!(parse(&["--db"], &[]).is_err());
+    }
+}
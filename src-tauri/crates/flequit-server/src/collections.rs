@@ -0,0 +1,247 @@
+//! サーバーが受け付けるコレクションの定義
+//!
+//! コレクション名と保存単位はクライアントのWebリポジトリと一致させる。
+//! 受け取ったJSONはモデルへデシリアライズできることと、
+//! パス上のキーと本文のキーが一致することを検証してから保存する。
+
+use crate::error::ServerError;
+use flequit_model::models::accounts::account::Account;
+use flequit_model::models::task_projects::{
+    comment::Comment, member::Member, project::Project, recurrence_rule::RecurrenceRule,
+    reminder::Reminder, snooze::Snooze, status_transition_rule::StatusTransitionRule,
+    subtask::SubTask, subtask_assignment::SubTaskAssignment, subtask_recurrence::SubTaskRecurrence,
+    subtask_tag::SubTaskTag, tag::Tag, task::Task, task_assignment::TaskAssignment,
+    task_dependency::TaskDependency, task_list::TaskList, task_recurrence::TaskRecurrence,
+    task_tag::TaskTag, time_entry::TimeEntry, workflow_status::WorkflowStatus,
+};
+use flequit_model::models::users::User;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// プロジェクトのコレクション名
+pub const PROJECTS: &str = "projects";
+
+/// プロジェクトを横断して参照されるサブタスク繰り返しのコレクション名
+pub const SUBTASK_RECURRENCES: &str = "subtask_recurrences";
+
+/// コレクションの保存単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionKind {
+    /// `/{collection}/{id}`
+    Global,
+    /// `/projects/{project_id}/{collection}/{id}`
+    Project,
+    /// `/projects/{project_id}/{collection}/{parent_id}/{child_id}`
+    Relation,
+}
+
+/// コレクションの定義
+#[derive(Debug, Clone, Copy)]
+pub struct Collection {
+    pub name: &'static str,
+    pub kind: CollectionKind,
+    /// 第1キー（エンティティID、関連付けでは親ID）を保持するフィールド
+    pub key_field: &'static str,
+    /// 関連付けの子IDを保持するフィールド
+    pub child_field: Option<&'static str>,
+    validate_model: fn(&Value) -> Result<(), serde_json::Error>,
+}
+
+fn parse<T: DeserializeOwned>(value: &Value) -> Result<(), serde_json::Error> {
+    T::deserialize(value).map(|_| ())
+}
+
+macro_rules! collection {
+    ($name:literal, Global, $model:ty, $key:literal) => {
+        Collection {
+            name: $name,
+            kind: CollectionKind::Global,
+            key_field: $key,
+            child_field: None,
+            validate_model: parse::<$model>,
+        }
+    };
+    ($name:literal, Project, $model:ty, $key:literal) => {
+        Collection {
+            name: $name,
+            kind: CollectionKind::Project,
+            key_field: $key,
+            child_field: None,
+            validate_model: parse::<$model>,
+        }
+    };
+    ($name:literal, Relation, $model:ty, $key:literal, $child:literal) => {
+        Collection {
+            name: $name,
+            kind: CollectionKind::Relation,
+            key_field: $key,
+            child_field: Some($child),
+            validate_model: parse::<$model>,
+        }
+    };
+}
+
+const COLLECTIONS: [Collection; 22] = [
+    collection!("accounts", Global, Account, "id"),
+    collection!("users", Global, User, "id"),
+    collection!("projects", Global, Project, "id"),
+    // メンバーはプロジェクト内でユーザーIDにより一意
+    collection!("members", Project, Member, "user_id"),
+    collection!("tasks", Project, Task, "id"),
+    collection!("task_lists", Project, TaskList, "id"),
+    collection!("subtasks", Project, SubTask, "id"),
+    collection!("tags", Project, Tag, "id"),
+    collection!("recurrence_rules", Project, RecurrenceRule, "id"),
+    collection!("time_entries", Project, TimeEntry, "id"),
+    collection!("reminders", Project, Reminder, "id"),
+    collection!("snoozes", Project, Snooze, "id"),
+    collection!("comments", Project, Comment, "id"),
+    collection!(
+        "status_transition_rules",
+        Project,
+        StatusTransitionRule,
+        "id"
+    ),
+    collection!("workflow_statuses", Project, WorkflowStatus, "id"),
+    collection!("task_tags", Relation, TaskTag, "task_id", "tag_id"),
+    collection!("subtask_tags", Relation, SubTaskTag, "subtask_id", "tag_id"),
+    collection!(
+        "task_assignments",
+        Relation,
+        TaskAssignment,
+        "task_id",
+        "user_id"
+    ),
+    collection!(
+        "subtask_assignments",
+        Relation,
+        SubTaskAssignment,
+        "subtask_id",
+        "user_id"
+    ),
+    collection!(
+        "task_recurrences",
+        Relation,
+        TaskRecurrence,
+        "task_id",
+        "recurrence_rule_id"
+    ),
+    collection!(
+        "task_dependencies",
+        Relation,
+        TaskDependency,
+        "task_id",
+        "depends_on_task_id"
+    ),
+    collection!(
+        "subtask_recurrences",
+        Relation,
+        SubTaskRecurrence,
+        "subtask_id",
+        "recurrence_rule_id"
+    ),
+];
+
+impl Collection {
+    /// コレクション名から定義を取得
+    pub fn find(name: &str) -> Option<&'static Collection> {
+        COLLECTIONS.iter().find(|c| c.name == name)
+    }
+
+    /// 指定した保存単位のコレクションを取得（存在しない場合は404）
+    pub fn lookup(name: &str, kind: CollectionKind) -> Result<&'static Collection, ServerError> {
+        Self::find(name)
+            .filter(|c| c.kind == kind)
+            .ok_or_else(|| ServerError::NotFound(format!("Unknown collection: {name}")))
+    }
+
+    /// 保存前の検証
+    ///
+    /// モデルとして解釈できること、パス上のキーと本文が一致すること、
+    /// 本文に `project_id` がある場合はパスのプロジェクトと一致することを確認する。
+    pub fn validate(
+        &self,
+        body: &Value,
+        project_id: Option<&str>,
+        key: &str,
+        child: Option<&str>,
+    ) -> Result<(), ServerError> {
+        (self.validate_model)(body)
+            .map_err(|e| ServerError::Validation(format!("Invalid {}: {e}", self.name)))?;
+
+        let field_matches =
+            |field: &str, expected: &str| body.get(field).and_then(Value::as_str) == Some(expected);
+        if !field_matches(self.key_field, key) {
+            return Err(ServerError::BadRequest(format!(
+                "{} does not match the request path",
+                self.key_field
+            )));
+        }
+        if let (Some(field), Some(child)) = (self.child_field, child)
+            && !field_matches(field, child)
+        {
+            return Err(ServerError::BadRequest(format!(
+                "{field} does not match the request path"
+            )));
+        }
+        if let (Some(project_id), Some(value)) = (project_id, body.get("project_id"))
+            && value.as_str() != Some(project_id)
+        {
+            return Err(ServerError::BadRequest(
+                "project_id does not match the request path".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const TASK_ID: &str = "0b7c1a52-4f0e-4b8e-9a55-0d3f0f6f2a01";
+    const TAG_ID: &str = "5d1f7c0e-8f21-4c7b-b6a4-7c3d2e9f1b02";
+    const OTHER_ID: &str = "9a0e2b13-6c5d-4e7f-8a91-2b3c4d5e6f03";
+
+    fn task_tag(task_id: &str, tag_id: &str) -> Value {
+        json!({
+            "task_id": task_id,
+            "tag_id": tag_id,
+            "created_at": "2025-01-01T00:00:00Z",
+            "updated_at": "2025-01-01T00:00:00Z",
+            "deleted": false,
+            "updated_by": OTHER_ID,
+        })
+    }
+
+    #[test]
+    fn test_lookup_by_kind() {
+        assert!(Collection::lookup("tasks", CollectionKind::Project).is_ok());
+        assert!(Collection::lookup("tasks", CollectionKind::Global).is_err());
+        assert!(Collection::lookup("settings", CollectionKind::Global).is_err());
+        assert_eq!(
+            Collection::find(SUBTASK_RECURRENCES).unwrap().kind,
+            CollectionKind::Relation
+        );
+    }
+
+    #[test]
+    fn test_validate_relation_keys() {
+        let collection = Collection::find("task_tags").unwrap();
+        let body = task_tag(TASK_ID, TAG_ID);
+        assert!(
+            collection
+                .validate(&body, Some("p1"), TASK_ID, Some(TAG_ID))
+                .is_ok()
+        );
+        assert!(matches!(
+            collection.validate(&body, Some("p1"), TASK_ID, Some(OTHER_ID)),
+            Err(ServerError::BadRequest(_))
+        ));
+        assert!(matches!(
+            collection.validate(&json!({ "task_id": TASK_ID }), Some("p1"), TASK_ID, None),
+            Err(ServerError::Validation(_))
+        ));
+    }
+}
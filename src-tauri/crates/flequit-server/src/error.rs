@@ -0,0 +1,89 @@
+//! 同期サーバーのエラー型
+
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use flequit_types::errors::repository_error::RepositoryError;
+use sea_orm::DbErr;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ServerError {
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("Bad request: {0}")]
+    BadRequest(String),
+
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Configuration error: {0}")]
+    Configuration(String),
+
+    #[error("Database error: {0}")]
+    Database(String),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl ServerError {
+    /// HTTPレスポンスのステータスコード
+    ///
+    /// クライアント（Webリポジトリ）はこのコードから `RepositoryError` の種別を決める。
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            ServerError::Unauthorized => StatusCode::UNAUTHORIZED,
+            ServerError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ServerError::NotFound(_) => StatusCode::NOT_FOUND,
+            ServerError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ServerError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            ServerError::Conflict(_) => StatusCode::CONFLICT,
+            ServerError::Configuration(_) | ServerError::Database(_) | ServerError::Io(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl From<DbErr> for ServerError {
+    fn from(err: DbErr) -> Self {
+        ServerError::Database(err.to_string())
+    }
+}
+
+impl From<RepositoryError> for ServerError {
+    fn from(err: RepositoryError) -> Self {
+        match err {
+            RepositoryError::NotFound(message) => ServerError::NotFound(message),
+            RepositoryError::ValidationError(message) => ServerError::Validation(message),
+            RepositoryError::ConstraintViolation(message) => ServerError::Conflict(message),
+            err => ServerError::Database(err.to_string()),
+        }
+    }
+}
+
+impl IntoResponse for ServerError {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+        if status.is_server_error() {
+            tracing::error!("Request failed: {}", self);
+        }
+        // サーバー内部のエラー詳細はクライアントへ返さない
+        let message = if status.is_server_error() {
+            "internal server error".to_string()
+        } else {
+            self.to_string()
+        };
+        (status, Json(serde_json::json!({ "error": message }))).into_response()
+    }
+}
@@ -0,0 +1,22 @@
+//! Flequitのセルフホスト同期サーバー
+//!
+//! 小規模なチームが自前のマシンで1インスタンスを運用することを想定する。
+//! アプリのWebリポジトリが使うREST APIを提供し、エンティティを
+//! SQLiteデータベースに保存するとともに、プロジェクトごとの
+//! Automerge同期メッセージを中継する。
+//!
+//! 接続はサーバー上のアカウントとAPIトークン（Bearer）で認証する。
+//! TLSは前段のリバースプロキシで終端することを想定している。
+
+pub mod api;
+pub mod cli;
+pub mod collections;
+pub mod error;
+pub mod repository;
+pub mod server;
+pub mod store;
+
+pub use error::ServerError;
+pub use repository::ServerProjectRepository;
+pub use server::{FlequitServer, ServerHandle};
+pub use store::ServerStore;
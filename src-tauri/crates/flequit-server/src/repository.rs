@@ -0,0 +1,173 @@
+//! サーバーに保存したプロジェクトのリポジトリ
+//!
+//! `flequit-repository` のリポジトリトレイトを [`ServerStore`] 上に実装する。
+//! プロジェクトの削除では、配下のリソース・同期メッセージ・アクセス権もまとめて削除する。
+
+use crate::collections::PROJECTS;
+use crate::error::ServerError;
+use crate::store::{GLOBAL_SCOPE, ResourceKey, ResourceQuery, ServerStore};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::task_projects::project::Project;
+use flequit_model::types::id_types::{ProjectId, UserId};
+use flequit_repository::repositories::base_repository_trait::Repository;
+use flequit_repository::repositories::task_projects::project_repository_trait::ProjectRepositoryTrait;
+use flequit_types::errors::repository_error::RepositoryError;
+
+/// ストアのエラーをリポジトリのエラーに変換
+fn repository_error(err: ServerError) -> RepositoryError {
+    match err {
+        ServerError::NotFound(message) => RepositoryError::NotFound(message),
+        ServerError::BadRequest(message) | ServerError::Validation(message) => {
+            RepositoryError::ValidationError(message)
+        }
+        ServerError::Conflict(message) => RepositoryError::ConstraintViolation(message),
+        err => RepositoryError::DatabaseError(err.to_string()),
+    }
+}
+
+fn parse_project(body: serde_json::Value) -> Result<Project, RepositoryError> {
+    serde_json::from_value(body).map_err(|e| RepositoryError::SerializationError(e.to_string()))
+}
+
+/// サーバーに保存したプロジェクト
+#[derive(Debug, Clone)]
+pub struct ServerProjectRepository {
+    store: ServerStore,
+}
+
+impl ServerProjectRepository {
+    pub fn new(store: ServerStore) -> Self {
+        Self { store }
+    }
+
+    fn query(&self) -> ResourceQuery {
+        ResourceQuery::new(GLOBAL_SCOPE, PROJECTS)
+    }
+}
+
+#[async_trait]
+impl Repository<Project, ProjectId> for ServerProjectRepository {
+    async fn save(
+        &self,
+        entity: &Project,
+        user_id: &UserId,
+        timestamp: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let body = serde_json::to_value(entity)
+            .map_err(|e| RepositoryError::SerializationError(e.to_string()))?;
+        let key = ResourceKey {
+            project_id: GLOBAL_SCOPE.to_string(),
+            collection: PROJECTS,
+            key: entity.id.to_string(),
+            child: String::new(),
+            owner: None,
+        };
+        self.store
+            .put_resource(&key, &body, Some(&user_id.to_string()), Some(*timestamp))
+            .await
+            .map_err(repository_error)?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, RepositoryError> {
+        self.store
+            .find_resources(&self.query().key(id.to_string()))
+            .await
+            .map_err(repository_error)?
+            .into_iter()
+            .next()
+            .map(parse_project)
+            .transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<Project>, RepositoryError> {
+        self.store
+            .find_resources(&self.query())
+            .await
+            .map_err(repository_error)?
+            .into_iter()
+            .map(parse_project)
+            .collect()
+    }
+
+    async fn delete(&self, id: &ProjectId) -> Result<(), RepositoryError> {
+        self.store
+            .delete_project(&id.to_string())
+            .await
+            .map_err(repository_error)?;
+        Ok(())
+    }
+
+    async fn exists(&self, id: &ProjectId) -> Result<bool, RepositoryError> {
+        Ok(self.find_by_id(id).await?.is_some())
+    }
+
+    async fn count(&self) -> Result<u64, RepositoryError> {
+        Ok(self.find_all().await?.len() as u64)
+    }
+}
+
+impl ProjectRepositoryTrait for ServerProjectRepository {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn project(name: &str) -> Project {
+        let now = Utc::now();
+        Project {
+            id: ProjectId::new(),
+            name: name.to_string(),
+            description: None,
+            color: None,
+            order_index: 0,
+            is_archived: false,
+            status: None,
+            owner_id: None,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_project_repository_deletes_project_resources() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ServerStore::open(dir.path().join("server.db").to_string_lossy())
+            .await
+            .unwrap();
+        let repository = ServerProjectRepository::new(store.clone());
+        let home = project("Home");
+        let work = project("Work");
+        for project in [&home, &work] {
+            repository
+                .save(project, &UserId::new(), &Utc::now())
+                .await
+                .unwrap();
+        }
+        let task_key = ResourceKey {
+            project_id: home.id.to_string(),
+            collection: "tasks",
+            key: "t1".to_string(),
+            child: String::new(),
+            owner: None,
+        };
+        store
+            .put_resource(&task_key, &json!({ "id": "t1" }), None, None)
+            .await
+            .unwrap();
+
+        let found = repository.find_by_id(&home.id).await.unwrap().unwrap();
+        assert_eq!(found.name, "Home");
+        assert_eq!(repository.count().await.unwrap(), 2);
+
+        repository.delete(&home.id).await.unwrap();
+        assert!(!repository.exists(&home.id).await.unwrap());
+        assert!(repository.exists(&work.id).await.unwrap());
+        let tasks = ResourceQuery::new(home.id.to_string(), "tasks");
+        assert!(store.find_resources(&tasks).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,464 @@
+//! サーバーの起動と停止
+
+use crate::api;
+use crate::error::ServerError;
+use crate::store::ServerStore;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+/// 起動中のサーバー
+#[derive(Debug)]
+pub struct ServerHandle {
+    addr: SocketAddr,
+    shutdown: oneshot::Sender<()>,
+    task: JoinHandle<std::io::Result<()>>,
+}
+
+impl ServerHandle {
+    /// 待ち受けアドレス
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// 処理中のリクエストの完了を待ってからサーバーを停止
+    pub async fn shutdown(self) -> Result<(), ServerError> {
+        let _ = self.shutdown.send(());
+        self.task
+            .await
+            .map_err(|e| ServerError::Io(std::io::Error::other(e)))??;
+        Ok(())
+    }
+}
+
+/// Flequit同期サーバー
+pub struct FlequitServer;
+
+impl FlequitServer {
+    /// `addr` で待ち受けを開始する（ポートが0の場合は空きポート）
+    pub async fn start(store: ServerStore, addr: SocketAddr) -> Result<ServerHandle, ServerError> {
+        let listener = TcpListener::bind(addr).await?;
+        let addr = listener.local_addr()?;
+        tracing::info!("Flequit server listening on {}", addr);
+
+        let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+        let task = tokio::spawn(async move {
+            axum::serve(listener, api::router(store))
+                .with_graceful_shutdown(async move {
+                    let _ = shutdown_rx.await;
+                })
+                .await
+        });
+        Ok(ServerHandle {
+            addr,
+            shutdown,
+            task,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use flequit_infrastructure::config::WebServerConfig;
+    use flequit_infrastructure::web::{
+        ProjectWebRepository, SubTaskRecurrenceWebRepository, SubTaskWebRepository,
+        SyncRelayClient, TaskTagWebRepository, TaskWebRepository, UserWebRepository, WebClient,
+    };
+    use flequit_model::models::task_projects::project::Project;
+    use flequit_model::models::task_projects::subtask::SubTask;
+    use flequit_model::models::task_projects::subtask_recurrence::SubTaskRecurrence;
+    use flequit_model::models::task_projects::task::Task;
+    use flequit_model::models::users::User;
+    use flequit_model::types::id_types::{
+        ProjectId, RecurrenceRuleId, SubTaskId, TagId, TaskId, TaskListId, UserId,
+    };
+    use flequit_model::types::task_types::TaskStatus;
+    use flequit_repository::repositories::base_repository_trait::Repository;
+    use flequit_repository::repositories::project_relation_repository_trait::ProjectRelationRepository;
+    use flequit_repository::repositories::project_repository_trait::ProjectRepository;
+    use flequit_repository::repositories::task_projects::subtask_recurrence_repository_trait::SubtaskRecurrenceRepositoryTrait;
+    use flequit_types::errors::repository_error::RepositoryError;
+    use std::net::Ipv4Addr;
+
+    struct TestServer {
+        handle: ServerHandle,
+        store: ServerStore,
+        _dir: tempfile::TempDir,
+    }
+
+    impl TestServer {
+        async fn start() -> Self {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("server.db");
+            let store = ServerStore::open(path.to_string_lossy().to_string())
+                .await
+                .unwrap();
+            let handle = FlequitServer::start(store.clone(), (Ipv4Addr::LOCALHOST, 0).into())
+                .await
+                .unwrap();
+            Self {
+                handle,
+                store,
+                _dir: dir,
+            }
+        }
+
+        fn base_url(&self) -> String {
+            format!("http://{}", self.handle.local_addr())
+        }
+
+        /// アカウントを作成し、APIトークンを返す
+        async fn account(&self, name: &str) -> String {
+            self.store.create_account(name).await.unwrap().1
+        }
+
+        fn client(&self, token: &str) -> WebClient {
+            WebClient::new(&WebServerConfig::new(
+                self.base_url(),
+                Some(token.to_string()),
+            ))
+            .unwrap()
+        }
+
+        async fn client_for(&self, name: &str) -> WebClient {
+            let token = self.account(name).await;
+            self.client(&token)
+        }
+    }
+
+    fn project(id: ProjectId, name: &str) -> Project {
+        let now = Utc::now();
+        Project {
+            id,
+            name: name.to_string(),
+            description: None,
+            color: None,
+            order_index: 0,
+            is_archived: false,
+            status: None,
+            owner_id: None,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        }
+    }
+
+    fn task(project_id: ProjectId, title: &str) -> Task {
+        let now = Utc::now();
+        Task {
+            id: TaskId::new(),
+            project_id,
+            list_id: TaskListId::new(),
+            title: title.to_string(),
+            description: None,
+            status: TaskStatus::NotStarted,
+            workflow_status_id: None,
+            priority: 0,
+            plan_start_date: None,
+            plan_end_date: None,
+            do_start_date: None,
+            do_end_date: None,
+            is_range_date: None,
+            recurrence_rule: None,
+            order_index: 0,
+            is_archived: false,
+            assigned_user_ids: Vec::new(),
+            tag_ids: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_web_repositories_against_server() {
+        let server = TestServer::start().await;
+        let client = server.client_for("alice").await;
+        let projects = ProjectWebRepository::new(client.clone());
+        let tasks = TaskWebRepository::new(client.clone());
+        let task_tags = TaskTagWebRepository::new(client);
+        let user_id = UserId::new();
+        let now = Utc::now();
+
+        let project_id = ProjectId::new();
+        projects
+            .save(&project(project_id, "Home"), &user_id, &now)
+            .await
+            .unwrap();
+        let mut task = task(project_id, "Buy milk");
+        tasks
+            .save(&project_id, &task, &user_id, &now)
+            .await
+            .unwrap();
+        task.title = "Buy oat milk".to_string();
+        tasks
+            .save(&project_id, &task, &user_id, &now)
+            .await
+            .unwrap();
+
+        let found = tasks
+            .find_by_id(&project_id, &task.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.title, "Buy oat milk");
+        assert_eq!(tasks.count(&project_id).await.unwrap(), 1);
+        assert_eq!(projects.find_all().await.unwrap().len(), 1);
+
+        let (tag_a, tag_b) = (TagId::new(), TagId::new());
+        task_tags
+            .add_all(
+                &project_id,
+                &[(task.id, tag_a), (task.id, tag_b)],
+                &user_id,
+                &now,
+            )
+            .await
+            .unwrap();
+        assert_eq!(task_tags.count(&project_id, &task.id).await.unwrap(), 2);
+        task_tags
+            .remove_all_by_child(&project_id, &tag_a)
+            .await
+            .unwrap();
+        let remaining = task_tags.find_all(&project_id).await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].tag_id, tag_b);
+
+        tasks.delete(&project_id, &task.id).await.unwrap();
+        assert!(!tasks.exists(&project_id, &task.id).await.unwrap());
+
+        projects.delete(&project_id).await.unwrap();
+        assert!(projects.find_all().await.unwrap().is_empty());
+        assert!(task_tags.find_all(&project_id).await.unwrap().is_empty());
+        server.handle.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_project_access_between_accounts() {
+        let server = TestServer::start().await;
+        let alice = TaskWebRepository::new(server.client_for("alice").await);
+        let bob = TaskWebRepository::new(server.client_for("bob").await);
+        let project_id = ProjectId::new();
+        let task = task(project_id, "Private");
+
+        // 最初に書き込んだアカウントにプロジェクトが割り当てられる
+        alice
+            .save(&project_id, &task, &UserId::new(), &Utc::now())
+            .await
+            .unwrap();
+        let error = bob.find_all(&project_id).await.unwrap_err();
+        assert!(matches!(error, RepositoryError::ConfigurationError(_)));
+        let error = bob
+            .save(&project_id, &task, &UserId::new(), &Utc::now())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, RepositoryError::ConfigurationError(_)));
+
+        server
+            .store
+            .grant_access(&project_id.to_string(), "bob")
+            .await
+            .unwrap();
+        assert_eq!(bob.find_all(&project_id).await.unwrap().len(), 1);
+
+        // 未割り当てのプロジェクトは空として扱う
+        assert!(alice.find_all(&ProjectId::new()).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_global_resources_belong_to_their_account() {
+        let server = TestServer::start().await;
+        let alice = UserWebRepository::new(server.client_for("alice").await);
+        let bob = UserWebRepository::new(server.client_for("bob").await);
+        let now = Utc::now();
+        let user = User {
+            id: UserId::new(),
+            handle_id: "alice".to_string(),
+            display_name: "Alice".to_string(),
+            email: None,
+            avatar_url: None,
+            bio: None,
+            timezone: None,
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        };
+        alice.save(&user, &user.id, &now).await.unwrap();
+
+        // 他のアカウントのリソースは一覧に含まれず、参照・更新・削除もできない
+        assert!(bob.find_all().await.unwrap().is_empty());
+        let error = bob.find_by_id(&user.id).await.unwrap_err();
+        assert!(matches!(error, RepositoryError::ConfigurationError(_)));
+        let error = bob.delete(&user.id).await.unwrap_err();
+        assert!(matches!(error, RepositoryError::ConfigurationError(_)));
+        let mut overwrite = user.clone();
+        overwrite.display_name = "Mallory".to_string();
+        let error = bob.save(&overwrite, &user.id, &now).await.unwrap_err();
+        assert!(matches!(error, RepositoryError::ConfigurationError(_)));
+
+        let found = alice.find_by_id(&user.id).await.unwrap().unwrap();
+        assert_eq!(found.display_name, "Alice");
+        alice.delete(&user.id).await.unwrap();
+        assert!(alice.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_requests() {
+        let server = TestServer::start().await;
+        let invalid = TaskWebRepository::new(server.client("flq_invalid"));
+        let error = invalid.find_all(&ProjectId::new()).await.unwrap_err();
+        assert!(matches!(error, RepositoryError::ConfigurationError(_)));
+
+        let token = server.account("alice").await;
+        let http = reqwest::Client::new();
+        let project_id = ProjectId::new();
+        let mismatched = task(ProjectId::new(), "Wrong project");
+        let response = http
+            .put(format!(
+                "{}/api/v1/projects/{}/tasks/{}",
+                server.base_url(),
+                project_id,
+                mismatched.id
+            ))
+            .bearer_auth(&token)
+            .json(&mismatched)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+        // 検証に失敗した書き込みではプロジェクトは割り当てられない
+        assert!(
+            !server
+                .store
+                .is_claimed(&project_id.to_string())
+                .await
+                .unwrap()
+        );
+
+        let response = http
+            .put(format!(
+                "{}/api/v1/projects/{}/tasks/{}",
+                server.base_url(),
+                project_id,
+                mismatched.id
+            ))
+            .bearer_auth(&token)
+            .json(&serde_json::json!({ "id": mismatched.id }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_subtask_recurrence_across_projects() {
+        let server = TestServer::start().await;
+        let client = server.client_for("alice").await;
+        let project_id = ProjectId::new();
+        let now = Utc::now();
+        let subtask = SubTask {
+            id: SubTaskId::new(),
+            task_id: TaskId::new(),
+            title: "Step".to_string(),
+            description: None,
+            status: TaskStatus::NotStarted,
+            priority: None,
+            plan_start_date: None,
+            plan_end_date: None,
+            do_start_date: None,
+            do_end_date: None,
+            is_range_date: None,
+            recurrence_rule: None,
+            assigned_user_ids: Vec::new(),
+            tag_ids: Vec::new(),
+            order_index: 0,
+            completed: false,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        };
+        let subtask_id = subtask.id;
+        SubTaskWebRepository::new(client.clone())
+            .save(&project_id, &subtask, &UserId::new(), &now)
+            .await
+            .unwrap();
+
+        let repo = SubTaskRecurrenceWebRepository::new(client);
+        let rule_id = RecurrenceRuleId::new();
+        repo.save(&SubTaskRecurrence {
+            subtask_id,
+            recurrence_rule_id: rule_id,
+            created_at: now,
+            updated_at: now,
+            deleted: false,
+            updated_by: UserId::new(),
+        })
+        .await
+        .unwrap();
+
+        let found = repo.find_by_subtask_id(&subtask_id).await.unwrap().unwrap();
+        assert_eq!(found.recurrence_rule_id, rule_id);
+        assert_eq!(
+            repo.find_by_recurrence_rule_id(&rule_id)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        // プロジェクト配下のパスからも参照できる
+        assert_eq!(
+            repo.find_relations(&project_id, &subtask_id)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        repo.delete_by_recurrence_rule_id(&rule_id).await.unwrap();
+        assert!(!repo.exists_by_subtask_id(&subtask_id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_relays_sync_messages() {
+        let server = TestServer::start().await;
+        let alice = SyncRelayClient::new(server.client_for("alice").await);
+        let bob = SyncRelayClient::new(server.client_for("bob").await);
+        let project_id = ProjectId::new();
+
+        let first = alice.push(&project_id, b"change-1").await.unwrap();
+        // 割り当て前のプロジェクトには参加できない
+        let error = bob.push(&project_id, b"intruder").await.unwrap_err();
+        assert!(matches!(error, RepositoryError::ConfigurationError(_)));
+        server
+            .store
+            .grant_access(&project_id.to_string(), "bob")
+            .await
+            .unwrap();
+        let second = bob.push(&project_id, b"change-2").await.unwrap();
+        assert!(second > first);
+
+        let page = bob.pull(&project_id, first, None).await.unwrap();
+        assert_eq!(page.messages.len(), 1);
+        assert_eq!(page.messages[0].payload, b"change-2");
+        assert_eq!(page.next_since, second);
+        assert_eq!(
+            alice.pull(&project_id, 0, Some(1)).await.unwrap().messages[0].payload,
+            b"change-1"
+        );
+
+        let page = alice
+            .pull(&project_id, page.next_since, None)
+            .await
+            .unwrap();
+        assert!(page.messages.is_empty());
+        assert_eq!(page.next_since, second);
+    }
+}
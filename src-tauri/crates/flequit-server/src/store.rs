@@ -0,0 +1,902 @@
+//! サーバーの永続化層
+//!
+//! サーバー専用のSQLiteデータベースにサーバー固有のテーブルを保持する。
+//! アプリ本体のテーブルは作成せず、スキーマは [`MIGRATIONS`] で管理する。
+//!
+//! - `server_accounts`: 接続アカウントとAPIトークン（SHA-256ハッシュのみ保存）
+//! - `server_project_access`: アカウントごとにアクセスできるプロジェクト
+//! - `server_resources`: REST経由で保存されたエンティティ（パスのキー単位でJSONを保持）。
+//!   プロジェクトに属さないリソースは最初に書き込んだアカウントが所有する
+//! - `server_sync_messages`: プロジェクトごとに中継するAutomerge同期メッセージ
+
+use crate::error::ServerError;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DbBackend, QueryResult,
+    Statement, TransactionTrait, Value,
+};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// プロジェクトに属さないリソースのスコープ
+pub const GLOBAL_SCOPE: &str = "";
+
+/// APIトークンの接頭辞
+const TOKEN_PREFIX: &str = "flq_";
+
+/// スキーマのマイグレーション
+///
+/// 適用済みのバージョン（配列の先頭からの件数）は `PRAGMA user_version` に記録する。
+/// 適用済みのマイグレーションは変更せず、末尾に追加すること。
+pub const MIGRATIONS: &[&[&str]] = &[&[
+    "CREATE TABLE server_accounts (
+        id TEXT PRIMARY KEY NOT NULL,
+        name TEXT NOT NULL UNIQUE,
+        token_hash TEXT NOT NULL UNIQUE,
+        created_at TEXT NOT NULL
+    )",
+    "CREATE TABLE server_project_access (
+        project_id TEXT NOT NULL,
+        account_id TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        PRIMARY KEY (project_id, account_id)
+    )",
+    "CREATE TABLE server_resources (
+        project_id TEXT NOT NULL,
+        collection TEXT NOT NULL,
+        resource_key TEXT NOT NULL,
+        child_key TEXT NOT NULL,
+        body TEXT NOT NULL,
+        owner_account_id TEXT,
+        updated_by TEXT,
+        updated_at TEXT NOT NULL,
+        PRIMARY KEY (project_id, collection, resource_key, child_key)
+    )",
+    "CREATE TABLE server_sync_messages (
+        seq INTEGER PRIMARY KEY AUTOINCREMENT,
+        project_id TEXT NOT NULL,
+        account_id TEXT NOT NULL,
+        payload BLOB NOT NULL,
+        created_at TEXT NOT NULL
+    )",
+    "CREATE INDEX idx_server_sync_messages_project
+        ON server_sync_messages (project_id, seq)",
+]];
+
+/// サーバーに接続できるアカウント
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ServerAccount {
+    pub id: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 中継される同期メッセージ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncMessage {
+    /// サーバー全体で単調増加する連番（取得時のカーソル）
+    pub seq: i64,
+    /// 送信したアカウントのID
+    pub account_id: String,
+    /// Automergeの同期メッセージ（サーバーは内容を解釈しない）
+    pub payload: Vec<u8>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 保存先リソースのキー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceKey {
+    /// プロジェクトID（プロジェクトに属さない場合は [`GLOBAL_SCOPE`]）
+    pub project_id: String,
+    pub collection: &'static str,
+    /// エンティティID、関連付けでは親ID
+    pub key: String,
+    /// 関連付けの子ID（関連付け以外は空文字）
+    pub child: String,
+    /// 所有するアカウントのID（プロジェクトに属さないリソースのみ）
+    pub owner: Option<String>,
+}
+
+/// リソースの検索・削除条件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceQuery {
+    pub project_ids: Vec<String>,
+    pub collection: &'static str,
+    pub key: Option<String>,
+    pub child: Option<String>,
+    /// 所有するアカウントによる絞り込み
+    pub owner: Option<String>,
+    /// 本文のフィールドによる絞り込み（完全一致）
+    pub filters: Vec<(String, String)>,
+}
+
+impl ResourceQuery {
+    pub fn new(project_id: impl Into<String>, collection: &'static str) -> Self {
+        Self::in_projects(vec![project_id.into()], collection)
+    }
+
+    /// 複数のプロジェクトを対象とする条件
+    pub fn in_projects(project_ids: Vec<String>, collection: &'static str) -> Self {
+        Self {
+            project_ids,
+            collection,
+            key: None,
+            child: None,
+            owner: None,
+            filters: Vec::new(),
+        }
+    }
+
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    pub fn child(mut self, child: impl Into<String>) -> Self {
+        self.child = Some(child.into());
+        self
+    }
+
+    pub fn owned_by(mut self, account_id: impl Into<String>) -> Self {
+        self.owner = Some(account_id.into());
+        self
+    }
+
+    /// 所有者を問わない同じ条件
+    pub fn any_owner(mut self) -> Self {
+        self.owner = None;
+        self
+    }
+
+    pub fn filters(mut self, filters: Vec<(String, String)>) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    fn where_clause(&self) -> Result<(String, Vec<Value>), ServerError> {
+        if self.project_ids.is_empty() {
+            return Ok(("0".to_string(), Vec::new()));
+        }
+        let placeholders = vec!["?"; self.project_ids.len()].join(", ");
+        let mut conditions = vec![
+            format!("project_id IN ({placeholders})"),
+            "collection = ?".to_string(),
+        ];
+        let mut values: Vec<Value> = self.project_ids.iter().map(|p| p.clone().into()).collect();
+        values.push(self.collection.into());
+        if let Some(key) = &self.key {
+            conditions.push("resource_key = ?".to_string());
+            values.push(key.clone().into());
+        }
+        if let Some(child) = &self.child {
+            conditions.push("child_key = ?".to_string());
+            values.push(child.clone().into());
+        }
+        if let Some(owner) = &self.owner {
+            conditions.push("owner_account_id = ?".to_string());
+            values.push(owner.clone().into());
+        }
+        for (field, expected) in &self.filters {
+            if field.is_empty()
+                || !field
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            {
+                return Err(ServerError::BadRequest(format!(
+                    "Invalid filter field: {field}"
+                )));
+            }
+            conditions.push("json_extract(body, ?) = ?".to_string());
+            values.push(format!("$.{field}").into());
+            values.push(expected.clone().into());
+        }
+        Ok((conditions.join(" AND "), values))
+    }
+}
+
+/// 新しいAPIトークンを生成
+fn generate_token() -> String {
+    format!(
+        "{TOKEN_PREFIX}{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, ServerError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| ServerError::Database(format!("Invalid timestamp: {e}")))
+}
+
+fn account_from_row(row: &QueryResult) -> Result<ServerAccount, ServerError> {
+    Ok(ServerAccount {
+        id: row.try_get("", "id")?,
+        name: row.try_get("", "name")?,
+        created_at: parse_timestamp(&row.try_get::<String>("", "created_at")?)?,
+    })
+}
+
+/// サーバーのデータストア
+///
+/// 内部の接続はコネクションプールを共有するため、`clone` は安価。
+#[derive(Debug, Clone)]
+pub struct ServerStore {
+    db: DatabaseConnection,
+}
+
+impl ServerStore {
+    /// 指定パスのデータベースを開き（存在しない場合は作成）、未適用のマイグレーションを適用する
+    pub async fn open(database_path: impl AsRef<str>) -> Result<Self, ServerError> {
+        let database_path = database_path.as_ref();
+        if let Some(parent) = Path::new(database_path).parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let mut options = ConnectOptions::new(format!("sqlite://{database_path}?mode=rwc"));
+        options.max_connections(8).sqlx_logging(false);
+        let store = Self {
+            db: Database::connect(options).await?,
+        };
+        store.migrate().await?;
+        Ok(store)
+    }
+
+    /// 適用済みのスキーマバージョン
+    pub async fn schema_version(&self) -> Result<usize, ServerError> {
+        let rows = self.query("PRAGMA user_version", Vec::new()).await?;
+        let version: i64 = match rows.first() {
+            Some(row) => row.try_get("", "user_version")?,
+            None => 0,
+        };
+        Ok(version as usize)
+    }
+
+    /// 未適用のマイグレーションを1件ずつトランザクション内で適用
+    async fn migrate(&self) -> Result<(), ServerError> {
+        let current = self.schema_version().await?;
+        if current > MIGRATIONS.len() {
+            return Err(ServerError::Configuration(format!(
+                "Database schema version {current} is newer than this server supports ({})",
+                MIGRATIONS.len()
+            )));
+        }
+        for (index, statements) in MIGRATIONS.iter().enumerate().skip(current) {
+            let txn = self.db.begin().await?;
+            for statement in *statements {
+                txn.execute(Statement::from_string(DbBackend::Sqlite, *statement))
+                    .await?;
+            }
+            txn.execute(Statement::from_string(
+                DbBackend::Sqlite,
+                format!("PRAGMA user_version = {}", index + 1),
+            ))
+            .await?;
+            txn.commit().await?;
+            tracing::info!("Applied server schema migration {}", index + 1);
+        }
+        Ok(())
+    }
+
+    async fn execute(&self, sql: &str, values: Vec<Value>) -> Result<u64, ServerError> {
+        let result = self
+            .db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Sqlite,
+                sql,
+                values,
+            ))
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn query(&self, sql: &str, values: Vec<Value>) -> Result<Vec<QueryResult>, ServerError> {
+        Ok(self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Sqlite,
+                sql,
+                values,
+            ))
+            .await?)
+    }
+
+    // ---------------------------------------------------------------------
+    // アカウント
+    // ---------------------------------------------------------------------
+
+    /// アカウントを作成し、APIトークンを返す（トークンはこの時だけ取得できる）
+    pub async fn create_account(&self, name: &str) -> Result<(ServerAccount, String), ServerError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ServerError::BadRequest(
+                "Account name must not be empty".to_string(),
+            ));
+        }
+        if self.find_account(name).await?.is_some() {
+            return Err(ServerError::Conflict(format!(
+                "Account already exists: {name}"
+            )));
+        }
+
+        let account = ServerAccount {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            created_at: Utc::now(),
+        };
+        let token = generate_token();
+        self.execute(
+            "INSERT INTO server_accounts (id, name, token_hash, created_at) VALUES (?, ?, ?, ?)",
+            vec![
+                account.id.clone().into(),
+                account.name.clone().into(),
+                hash_token(&token).into(),
+                account.created_at.to_rfc3339().into(),
+            ],
+        )
+        .await?;
+        Ok((account, token))
+    }
+
+    /// 名前でアカウントを取得
+    pub async fn find_account(&self, name: &str) -> Result<Option<ServerAccount>, ServerError> {
+        self.query(
+            "SELECT id, name, created_at FROM server_accounts WHERE name = ?",
+            vec![name.into()],
+        )
+        .await?
+        .first()
+        .map(account_from_row)
+        .transpose()
+    }
+
+    async fn require_account(&self, name: &str) -> Result<ServerAccount, ServerError> {
+        self.find_account(name)
+            .await?
+            .ok_or_else(|| ServerError::NotFound(format!("Account not found: {name}")))
+    }
+
+    pub async fn list_accounts(&self) -> Result<Vec<ServerAccount>, ServerError> {
+        self.query(
+            "SELECT id, name, created_at FROM server_accounts ORDER BY name",
+            Vec::new(),
+        )
+        .await?
+        .iter()
+        .map(account_from_row)
+        .collect()
+    }
+
+    /// APIトークンを再発行（以前のトークンは無効になる）
+    pub async fn rotate_token(&self, name: &str) -> Result<String, ServerError> {
+        let account = self.require_account(name).await?;
+        let token = generate_token();
+        self.execute(
+            "UPDATE server_accounts SET token_hash = ? WHERE id = ?",
+            vec![hash_token(&token).into(), account.id.into()],
+        )
+        .await?;
+        Ok(token)
+    }
+
+    /// アカウントとそのアクセス権を削除
+    pub async fn remove_account(&self, name: &str) -> Result<(), ServerError> {
+        let account = self.require_account(name).await?;
+        self.execute(
+            "DELETE FROM server_project_access WHERE account_id = ?",
+            vec![account.id.clone().into()],
+        )
+        .await?;
+        self.execute(
+            "DELETE FROM server_accounts WHERE id = ?",
+            vec![account.id.into()],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// APIトークンからアカウントを特定
+    pub async fn authenticate(&self, token: &str) -> Result<Option<ServerAccount>, ServerError> {
+        if !token.starts_with(TOKEN_PREFIX) {
+            return Ok(None);
+        }
+        self.query(
+            "SELECT id, name, created_at FROM server_accounts WHERE token_hash = ?",
+            vec![hash_token(token).into()],
+        )
+        .await?
+        .first()
+        .map(account_from_row)
+        .transpose()
+    }
+
+    // ---------------------------------------------------------------------
+    // プロジェクトのアクセス権
+    // ---------------------------------------------------------------------
+
+    /// 名前で指定したアカウントにプロジェクトへのアクセスを許可
+    pub async fn grant_access(&self, project_id: &str, name: &str) -> Result<(), ServerError> {
+        let account = self.require_account(name).await?;
+        self.execute(
+            "INSERT OR IGNORE INTO server_project_access (project_id, account_id, created_at)
+             VALUES (?, ?, ?)",
+            vec![
+                project_id.into(),
+                account.id.into(),
+                Utc::now().to_rfc3339().into(),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// 名前で指定したアカウントのプロジェクトへのアクセスを取り消し
+    pub async fn revoke_access(&self, project_id: &str, name: &str) -> Result<(), ServerError> {
+        let account = self.require_account(name).await?;
+        self.execute(
+            "DELETE FROM server_project_access WHERE project_id = ? AND account_id = ?",
+            vec![project_id.into(), account.id.into()],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// アカウントがアクセスできるプロジェクトID
+    pub async fn accessible_projects(&self, account_id: &str) -> Result<Vec<String>, ServerError> {
+        self.query(
+            "SELECT project_id FROM server_project_access WHERE account_id = ? ORDER BY project_id",
+            vec![account_id.into()],
+        )
+        .await?
+        .iter()
+        .map(|row| Ok(row.try_get("", "project_id")?))
+        .collect()
+    }
+
+    pub async fn has_access(
+        &self,
+        project_id: &str,
+        account_id: &str,
+    ) -> Result<bool, ServerError> {
+        Ok(!self
+            .query(
+                "SELECT 1 FROM server_project_access WHERE project_id = ? AND account_id = ?",
+                vec![project_id.into(), account_id.into()],
+            )
+            .await?
+            .is_empty())
+    }
+
+    /// いずれかのアカウントに割り当て済みのプロジェクトか
+    pub async fn is_claimed(&self, project_id: &str) -> Result<bool, ServerError> {
+        Ok(!self
+            .query(
+                "SELECT 1 FROM server_project_access WHERE project_id = ? LIMIT 1",
+                vec![project_id.into()],
+            )
+            .await?
+            .is_empty())
+    }
+
+    /// どのアカウントにも割り当てられていないプロジェクトを指定アカウントのものにする
+    ///
+    /// 既に他のアカウントが割り当て済みの場合は何もせず `false` を返す。
+    pub async fn claim_project(
+        &self,
+        project_id: &str,
+        account_id: &str,
+    ) -> Result<bool, ServerError> {
+        let affected = self
+            .execute(
+                "INSERT INTO server_project_access (project_id, account_id, created_at)
+                 SELECT ?, ?, ?
+                 WHERE NOT EXISTS (SELECT 1 FROM server_project_access WHERE project_id = ?)",
+                vec![
+                    project_id.into(),
+                    account_id.into(),
+                    Utc::now().to_rfc3339().into(),
+                    project_id.into(),
+                ],
+            )
+            .await?;
+        Ok(affected > 0)
+    }
+
+    /// プロジェクトと配下のリソース・同期メッセージ・アクセス権を全て削除
+    pub async fn delete_project(&self, project_id: &str) -> Result<u64, ServerError> {
+        let mut deleted = self
+            .execute(
+                "DELETE FROM server_resources
+                 WHERE project_id = ? OR (project_id = ? AND collection = ? AND resource_key = ?)",
+                vec![
+                    project_id.into(),
+                    GLOBAL_SCOPE.into(),
+                    crate::collections::PROJECTS.into(),
+                    project_id.into(),
+                ],
+            )
+            .await?;
+        deleted += self
+            .execute(
+                "DELETE FROM server_sync_messages WHERE project_id = ?",
+                vec![project_id.into()],
+            )
+            .await?;
+        self.execute(
+            "DELETE FROM server_project_access WHERE project_id = ?",
+            vec![project_id.into()],
+        )
+        .await?;
+        Ok(deleted)
+    }
+
+    // ---------------------------------------------------------------------
+    // リソース
+    // ---------------------------------------------------------------------
+
+    /// リソースを作成または置換
+    ///
+    /// 既存のリソースの所有者が `key.owner` と異なる場合は何もせず `false` を返す。
+    pub async fn put_resource(
+        &self,
+        key: &ResourceKey,
+        body: &serde_json::Value,
+        updated_by: Option<&str>,
+        updated_at: Option<DateTime<Utc>>,
+    ) -> Result<bool, ServerError> {
+        let affected = self
+            .execute(
+                "INSERT INTO server_resources
+                    (project_id, collection, resource_key, child_key, body, owner_account_id,
+                     updated_by, updated_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                 ON CONFLICT (project_id, collection, resource_key, child_key)
+                 DO UPDATE SET body = excluded.body,
+                               updated_by = excluded.updated_by,
+                               updated_at = excluded.updated_at
+                 WHERE server_resources.owner_account_id IS excluded.owner_account_id",
+                vec![
+                    key.project_id.clone().into(),
+                    key.collection.into(),
+                    key.key.clone().into(),
+                    key.child.clone().into(),
+                    body.to_string().into(),
+                    key.owner.clone().into(),
+                    updated_by.map(str::to_string).into(),
+                    updated_at.unwrap_or_else(Utc::now).to_rfc3339().into(),
+                ],
+            )
+            .await?;
+        Ok(affected > 0)
+    }
+
+    /// 条件に一致するリソースの本文（キー順）
+    pub async fn find_resources(
+        &self,
+        query: &ResourceQuery,
+    ) -> Result<Vec<serde_json::Value>, ServerError> {
+        let (condition, values) = query.where_clause()?;
+        self.query(
+            &format!(
+                "SELECT body FROM server_resources WHERE {condition}
+                 ORDER BY project_id, resource_key, child_key"
+            ),
+            values,
+        )
+        .await?
+        .iter()
+        .map(|row| {
+            let body: String = row.try_get("", "body")?;
+            serde_json::from_str(&body)
+                .map_err(|e| ServerError::Database(format!("Corrupted resource body: {e}")))
+        })
+        .collect()
+    }
+
+    /// 条件に一致するリソースのプロジェクトID
+    pub async fn find_resource_projects(
+        &self,
+        query: &ResourceQuery,
+    ) -> Result<Vec<String>, ServerError> {
+        let (condition, values) = query.where_clause()?;
+        self.query(
+            &format!(
+                "SELECT DISTINCT project_id FROM server_resources WHERE {condition}
+                 ORDER BY project_id"
+            ),
+            values,
+        )
+        .await?
+        .iter()
+        .map(|row| Ok(row.try_get("", "project_id")?))
+        .collect()
+    }
+
+    /// 条件に一致するリソースを削除し、削除件数を返す
+    pub async fn delete_resources(&self, query: &ResourceQuery) -> Result<u64, ServerError> {
+        let (condition, values) = query.where_clause()?;
+        self.execute(
+            &format!("DELETE FROM server_resources WHERE {condition}"),
+            values,
+        )
+        .await
+    }
+
+    // ---------------------------------------------------------------------
+    // 同期メッセージ
+    // ---------------------------------------------------------------------
+
+    /// 同期メッセージを追加し、割り当てた連番を返す
+    pub async fn append_sync_message(
+        &self,
+        project_id: &str,
+        account_id: &str,
+        payload: &[u8],
+    ) -> Result<i64, ServerError> {
+        let rows = self
+            .query(
+                "INSERT INTO server_sync_messages (project_id, account_id, payload, created_at)
+                 VALUES (?, ?, ?, ?) RETURNING seq",
+                vec![
+                    project_id.into(),
+                    account_id.into(),
+                    payload.to_vec().into(),
+                    Utc::now().to_rfc3339().into(),
+                ],
+            )
+            .await?;
+        let row = rows
+            .first()
+            .ok_or_else(|| ServerError::Database("Failed to append sync message".to_string()))?;
+        Ok(row.try_get("", "seq")?)
+    }
+
+    /// `since` より後の同期メッセージを連番順に最大 `limit` 件取得
+    pub async fn sync_messages_since(
+        &self,
+        project_id: &str,
+        since: i64,
+        limit: u64,
+    ) -> Result<Vec<SyncMessage>, ServerError> {
+        self.query(
+            "SELECT seq, account_id, payload, created_at FROM server_sync_messages
+             WHERE project_id = ? AND seq > ? ORDER BY seq LIMIT ?",
+            vec![project_id.into(), since.into(), (limit as i64).into()],
+        )
+        .await?
+        .iter()
+        .map(|row| {
+            Ok(SyncMessage {
+                seq: row.try_get("", "seq")?,
+                account_id: row.try_get("", "account_id")?,
+                payload: row.try_get("", "payload")?,
+                created_at: parse_timestamp(&row.try_get::<String>("", "created_at")?)?,
+            })
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    async fn store() -> (ServerStore, tempfile::TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("server.db");
+        let store = ServerStore::open(path.to_string_lossy().to_string())
+            .await
+            .unwrap();
+        (store, dir)
+    }
+
+    #[tokio::test]
+    async fn test_open_applies_only_server_migrations() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("server.db");
+        let path = path.to_string_lossy().to_string();
+        let store = ServerStore::open(&path).await.unwrap();
+        assert_eq!(store.schema_version().await.unwrap(), MIGRATIONS.len());
+
+        let tables: Vec<String> = store
+            .query(
+                "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'
+                 ORDER BY name",
+                Vec::new(),
+            )
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row.try_get("", "name").unwrap())
+            .collect();
+        assert_eq!(
+            tables,
+            [
+                "server_accounts",
+                "server_project_access",
+                "server_resources",
+                "server_sync_messages"
+            ]
+        );
+
+        // 再度開いても適用済みのマイグレーションは実行しない
+        store.create_account("alice").await.unwrap();
+        let reopened = ServerStore::open(&path).await.unwrap();
+        assert_eq!(reopened.list_accounts().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_account_tokens() {
+        let (store, _dir) = store().await;
+        let (alice, token) = store.create_account("alice").await.unwrap();
+        assert!(matches!(
+            store.create_account("alice").await,
+            Err(ServerError::Conflict(_))
+        ));
+
+        assert_eq!(store.authenticate(&token).await.unwrap(), Some(alice));
+        assert_eq!(store.authenticate("flq_unknown").await.unwrap(), None);
+
+        let rotated = store.rotate_token("alice").await.unwrap();
+        assert_eq!(store.authenticate(&token).await.unwrap(), None);
+        assert!(store.authenticate(&rotated).await.unwrap().is_some());
+
+        store.remove_account("alice").await.unwrap();
+        assert_eq!(store.authenticate(&rotated).await.unwrap(), None);
+        assert!(store.list_accounts().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_claim_and_grant_project() {
+        let (store, _dir) = store().await;
+        let (alice, _) = store.create_account("alice").await.unwrap();
+        let (bob, _) = store.create_account("bob").await.unwrap();
+
+        assert!(store.claim_project("p1", &alice.id).await.unwrap());
+        assert!(!store.claim_project("p1", &bob.id).await.unwrap());
+        assert!(!store.has_access("p1", &bob.id).await.unwrap());
+
+        store.grant_access("p1", "bob").await.unwrap();
+        assert_eq!(store.accessible_projects(&bob.id).await.unwrap(), ["p1"]);
+        store.revoke_access("p1", "bob").await.unwrap();
+        assert!(store.accessible_projects(&bob.id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resource_queries() {
+        let (store, _dir) = store().await;
+        let relation = |parent: &str, child: &str| ResourceKey {
+            project_id: "p1".to_string(),
+            collection: "task_recurrences",
+            key: parent.to_string(),
+            child: child.to_string(),
+            owner: None,
+        };
+        for (parent, child) in [("t1", "r1"), ("t2", "r1"), ("t2", "r2")] {
+            store
+                .put_resource(
+                    &relation(parent, child),
+                    &json!({ "task_id": parent, "recurrence_rule_id": child }),
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        let all = ResourceQuery::new("p1", "task_recurrences");
+        assert_eq!(store.find_resources(&all).await.unwrap().len(), 3);
+        let by_parent = all.clone().key("t2");
+        assert_eq!(store.find_resources(&by_parent).await.unwrap().len(), 2);
+        let by_field = all
+            .clone()
+            .filters(vec![("recurrence_rule_id".to_string(), "r1".to_string())]);
+        assert_eq!(store.find_resources(&by_field).await.unwrap().len(), 2);
+        let invalid = all
+            .clone()
+            .filters(vec![("body') --".to_string(), "x".to_string())]);
+        assert!(matches!(
+            store.find_resources(&invalid).await,
+            Err(ServerError::BadRequest(_))
+        ));
+
+        assert_eq!(
+            store
+                .delete_resources(&all.clone().child("r1"))
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(store.find_resources(&all).await.unwrap().len(), 1);
+        assert!(
+            store
+                .find_resources(&ResourceQuery::new("p2", "task_recurrences"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resources_keep_their_owner() {
+        let (store, _dir) = store().await;
+        let key = |owner: &str| ResourceKey {
+            project_id: GLOBAL_SCOPE.to_string(),
+            collection: "users",
+            key: "u1".to_string(),
+            child: String::new(),
+            owner: Some(owner.to_string()),
+        };
+        assert!(
+            store
+                .put_resource(&key("alice"), &json!({ "name": "A" }), None, None)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !store
+                .put_resource(&key("bob"), &json!({ "name": "B" }), None, None)
+                .await
+                .unwrap()
+        );
+
+        let users = ResourceQuery::new(GLOBAL_SCOPE, "users");
+        let found = store
+            .find_resources(&users.clone().owned_by("alice"))
+            .await
+            .unwrap();
+        assert_eq!(found, [json!({ "name": "A" })]);
+        assert!(
+            store
+                .find_resources(&users.clone().owned_by("bob"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            store
+                .delete_resources(&users.owned_by("bob"))
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_messages_are_ordered_per_project() {
+        let (store, _dir) = store().await;
+        let first = store.append_sync_message("p1", "a", b"one").await.unwrap();
+        store
+            .append_sync_message("p2", "a", b"other")
+            .await
+            .unwrap();
+        let second = store.append_sync_message("p1", "b", b"two").await.unwrap();
+        assert!(second > first);
+
+        let messages = store.sync_messages_since("p1", 0, 10).await.unwrap();
+        let payloads: Vec<&[u8]> = messages.iter().map(|m| m.payload.as_slice()).collect();
+        assert_eq!(payloads, [b"one".as_slice(), b"two".as_slice()]);
+
+        let after = store.sync_messages_since("p1", first, 10).await.unwrap();
+        assert_eq!(after.len(), 1);
+        assert_eq!(after[0].account_id, "b");
+        assert_eq!(
+            store.sync_messages_since("p1", 0, 1).await.unwrap().len(),
+            1
+        );
+
+        assert_eq!(store.delete_project("p1").await.unwrap(), 2);
+        assert!(
+            store
+                .sync_messages_since("p1", 0, 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
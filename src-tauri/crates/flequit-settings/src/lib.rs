@@ -54,6 +54,8 @@ pub mod validation;
 // 公開API
 pub use errors::{SettingsError, SettingsResult};
 pub use manager::SettingsManager;
+pub use models::backup::BackupSettings;
+pub use models::calendar_feed::{CalendarFeed, CalendarFeedSettings};
 pub use models::datetime_format::DateTimeFormat;
 pub use models::due_date_buttons::DueDateButtons;
 pub use models::settings::{PartialSettings, Settings};
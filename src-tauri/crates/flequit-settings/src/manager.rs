@@ -210,6 +210,16 @@ impl SettingsManager {
         if let Some(view_items) = &partial.view_items {
             target.view_items = view_items.clone();
         }
+
+        // 連携設定
+        if let Some(calendar_feed) = &partial.calendar_feed {
+            target.calendar_feed = calendar_feed.clone();
+        }
+
+        // バックアップ設定
+        if let Some(backup) = &partial.backup {
+            target.backup = backup.clone();
+        }
     }
 }
 
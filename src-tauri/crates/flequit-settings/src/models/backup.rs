@@ -0,0 +1,36 @@
+//! 定期バックアップ設定モデル
+//!
+//! 自動バックアップの実行間隔・保存先・保持数を管理する構造体を定義します。
+
+use serde::{Deserialize, Serialize};
+
+/// 定期バックアップのデフォルト実行間隔（時間）
+pub const DEFAULT_BACKUP_INTERVAL_HOURS: u32 = 24;
+
+/// 定期バックアップのデフォルト保持数
+pub const DEFAULT_BACKUP_KEEP: u32 = 7;
+
+/// 定期バックアップの設定
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupSettings {
+    /// 定期バックアップを行うか
+    pub enabled: bool,
+    /// 実行間隔（時間）
+    pub interval_hours: u32,
+    /// 保存先ディレクトリ（未指定の場合はデータフォルダ内のbackups）
+    pub directory: Option<String>,
+    /// 保持するバックアップ数（超えた分は古いものから削除）
+    pub keep: u32,
+}
+
+impl Default for BackupSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_hours: DEFAULT_BACKUP_INTERVAL_HOURS,
+            directory: None,
+            keep: DEFAULT_BACKUP_KEEP,
+        }
+    }
+}
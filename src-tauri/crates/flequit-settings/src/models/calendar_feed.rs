@@ -0,0 +1,75 @@
+//! カレンダー購読フィード設定モデル
+//!
+//! ローカルHTTPサーバーで配信するiCalendarフィードの設定を管理する構造体を定義します。
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// フィード配信サーバーのデフォルトポート
+pub const DEFAULT_CALENDAR_FEED_PORT: u16 = 47321;
+
+/// カレンダー購読フィードの設定
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalendarFeedSettings {
+    /// ローカルHTTPサーバーを起動するか
+    pub enabled: bool,
+    /// 待ち受けポート（127.0.0.1のみで待ち受ける）
+    pub port: u16,
+    /// 配信するフィード
+    pub feeds: Vec<CalendarFeed>,
+}
+
+impl Default for CalendarFeedSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_CALENDAR_FEED_PORT,
+            feeds: vec![],
+        }
+    }
+}
+
+impl CalendarFeedSettings {
+    /// トークンに対応するフィードを取得
+    pub fn find_by_token(&self, token: &str) -> Option<&CalendarFeed> {
+        self.feeds.iter().find(|feed| feed.token == token)
+    }
+}
+
+/// 個別のフィード
+///
+/// URLにはトークンのみを含めるため、トークンを再発行・削除すると以前のURLは無効になります。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarFeed {
+    /// フィードID
+    pub id: String,
+    /// 表示名（カレンダー名として配信）
+    pub name: String,
+    /// URLに含めるランダムなトークン
+    pub token: String,
+    /// 対象プロジェクトID（空の場合は全プロジェクト）
+    #[serde(default)]
+    pub project_ids: Vec<String>,
+    /// 対象タスクリストID（空の場合は全て）
+    #[serde(default)]
+    pub task_list_ids: Vec<String>,
+    /// いずれかのタグが付いたタスクのみ（空の場合は全て）
+    #[serde(default)]
+    pub tag_ids: Vec<String>,
+    /// 対象ステータス（空の場合は全て）
+    #[serde(default)]
+    pub statuses: Vec<String>,
+    /// サブタスクを含めるか
+    #[serde(default = "default_true")]
+    pub include_subtasks: bool,
+    /// 指定日数より前に終わった予定を除外する
+    #[serde(default)]
+    pub days_back: Option<u32>,
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+}
+
+fn default_true() -> bool {
+    true
+}
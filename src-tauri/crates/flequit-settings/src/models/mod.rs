@@ -1,5 +1,7 @@
 //! 設定値モデル定義
 
+pub mod backup;
+pub mod calendar_feed;
 pub mod datetime_format;
 pub mod due_date_buttons;
 pub mod settings;
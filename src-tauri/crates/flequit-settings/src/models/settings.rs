@@ -5,6 +5,8 @@
 use partially::Partial;
 use serde::{Deserialize, Serialize};
 
+use super::backup::BackupSettings;
+use super::calendar_feed::CalendarFeedSettings;
 use super::datetime_format::DateTimeFormat;
 use super::due_date_buttons::DueDateButtons;
 use super::time_label::TimeLabel;
@@ -50,6 +52,16 @@ pub struct Settings {
     pub due_date_buttons: Vec<DueDateButtons>,
     /// ビューアイテム設定
     pub view_items: Vec<ViewItem>,
+
+    // 連携設定
+    /// カレンダー購読フィード
+    #[serde(default)]
+    pub calendar_feed: CalendarFeedSettings,
+
+    // バックアップ設定
+    /// 定期バックアップ
+    #[serde(default)]
+    pub backup: BackupSettings,
 }
 
 impl Default for Settings {
@@ -69,6 +81,8 @@ impl Default for Settings {
             time_labels: vec![],
             due_date_buttons: vec![],
             view_items: vec![],
+            calendar_feed: CalendarFeedSettings::default(),
+            backup: BackupSettings::default(),
         }
     }
 }
@@ -3,7 +3,10 @@
 //! このモジュールは設定値の妥当性を検証します。
 
 use crate::errors::{SettingsError, SettingsResult};
+use crate::models::backup::BackupSettings;
+use crate::models::calendar_feed::CalendarFeedSettings;
 use crate::models::settings::Settings;
+use std::collections::HashSet;
 
 /// 設定値検証器
 pub struct SettingsValidator;
@@ -17,6 +20,8 @@ impl SettingsValidator {
         Self::validate_week_start(&settings.week_start)?;
         Self::validate_timezone(&settings.timezone)?;
         Self::validate_custom_due_days(&settings.custom_due_days)?;
+        Self::validate_calendar_feed(&settings.calendar_feed)?;
+        Self::validate_backup(&settings.backup)?;
 
         Ok(())
     }
@@ -126,4 +131,60 @@ impl SettingsValidator {
 
         Ok(())
     }
+
+    /// カレンダー購読フィード設定の検証
+    fn validate_calendar_feed(calendar_feed: &CalendarFeedSettings) -> SettingsResult<()> {
+        if calendar_feed.port < 1024 {
+            return Err(SettingsError::ValidationError {
+                message: format!(
+                    "フィード配信ポートは1024以上である必要があります: {}",
+                    calendar_feed.port
+                ),
+            });
+        }
+
+        let mut ids = HashSet::new();
+        let mut tokens = HashSet::new();
+        for feed in &calendar_feed.feeds {
+            if feed.token.len() < 32 || !feed.token.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(SettingsError::ValidationError {
+                    message: format!("フィードのトークンが不正です: {}", feed.name),
+                });
+            }
+            if !ids.insert(feed.id.as_str()) || !tokens.insert(feed.token.as_str()) {
+                return Err(SettingsError::ValidationError {
+                    message: format!("フィードのIDまたはトークンが重複しています: {}", feed.name),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 定期バックアップ設定の検証
+    fn validate_backup(backup: &BackupSettings) -> SettingsResult<()> {
+        if backup.interval_hours == 0 {
+            return Err(SettingsError::ValidationError {
+                message: "バックアップの実行間隔は1時間以上である必要があります".to_string(),
+            });
+        }
+
+        if backup.keep == 0 {
+            return Err(SettingsError::ValidationError {
+                message: "バックアップの保持数は1以上である必要があります".to_string(),
+            });
+        }
+
+        if backup
+            .directory
+            .as_deref()
+            .is_some_and(|directory| directory.trim().is_empty())
+        {
+            return Err(SettingsError::ValidationError {
+                message: "バックアップの保存先が空です".to_string(),
+            });
+        }
+
+        Ok(())
+    }
 }
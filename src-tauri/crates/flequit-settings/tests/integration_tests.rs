@@ -10,7 +10,8 @@ fn test_config_manager_creation() {
     // プロジェクトルール準拠のテストディレクトリを作成
     let test_dir = TestPathGenerator::generate_test_dir(file!(), "test_config_manager_creation");
     std::fs::create_dir_all(&test_dir).unwrap();
-    env::set_var("HOME", test_dir);
+    // edition 2024ではset_varはunsafe（このテスト以外はHOMEを参照しない）
+    unsafe { env::set_var("HOME", test_dir) };
 
     let config_manager = SettingsManager::new();
     assert!(config_manager.is_ok());
@@ -43,6 +44,27 @@ fn test_settings_serialization() {
     assert_eq!(deserialized.language, settings.language);
 }
 
+#[test]
+fn test_settings_without_calendar_feed() {
+    // カレンダーフィード設定が追加される前の設定ファイルも読み込める
+    let mut yaml: serde_yaml::Value = serde_yaml::to_value(Settings::default()).unwrap();
+    yaml.as_mapping_mut().unwrap().remove("calendar_feed");
+
+    let settings: Settings = serde_yaml::from_value(yaml).unwrap();
+    assert!(!settings.calendar_feed.enabled);
+    assert!(settings.calendar_feed.feeds.is_empty());
+}
+
+#[test]
+fn test_settings_without_backup() {
+    // バックアップ設定が追加される前の設定ファイルは既定の定期バックアップ設定になる
+    let mut yaml: serde_yaml::Value = serde_yaml::to_value(Settings::default()).unwrap();
+    yaml.as_mapping_mut().unwrap().remove("backup");
+
+    let settings: Settings = serde_yaml::from_value(yaml).unwrap();
+    assert_eq!(settings.backup, flequit_settings::BackupSettings::default());
+}
+
 #[tokio::test]
 async fn test_auto_create_config_file() {
     // プロジェクトルール準拠のテストディレクトリを作成
@@ -244,8 +266,10 @@ mod validation_tests {
 
     #[test]
     fn test_invalid_theme() {
-        let mut settings = Settings::default();
-        settings.theme = "invalid_theme".to_string();
+        let settings = Settings {
+            theme: "invalid_theme".to_string(),
+            ..Settings::default()
+        };
 
         let result = SettingsValidator::validate(&settings);
         assert!(result.is_err());
@@ -253,8 +277,10 @@ mod validation_tests {
 
     #[test]
     fn test_invalid_font_size() {
-        let mut settings = Settings::default();
-        settings.font_size = 100; // 範囲外
+        let settings = Settings {
+            font_size: 100, // 範囲外
+            ..Settings::default()
+        };
 
         let result = SettingsValidator::validate(&settings);
         assert!(result.is_err());
@@ -262,10 +288,70 @@ mod validation_tests {
 
     #[test]
     fn test_invalid_week_start() {
-        let mut settings = Settings::default();
-        settings.week_start = "invalid_day".to_string();
+        let settings = Settings {
+            week_start: "invalid_day".to_string(),
+            ..Settings::default()
+        };
 
         let result = SettingsValidator::validate(&settings);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_invalid_calendar_feed() {
+        use flequit_settings::CalendarFeed;
+
+        let feed = CalendarFeed {
+            id: "feed-1".to_string(),
+            name: "Work".to_string(),
+            token: "a".repeat(32),
+            project_ids: vec![],
+            task_list_ids: vec![],
+            tag_ids: vec![],
+            statuses: vec![],
+            include_subtasks: true,
+            days_back: None,
+            created_at: chrono::Utc::now(),
+        };
+        let mut settings = Settings::default();
+        settings.calendar_feed.feeds.push(feed.clone());
+        assert!(SettingsValidator::validate(&settings).is_ok());
+
+        // トークンの重複
+        let mut duplicated = feed.clone();
+        duplicated.id = "feed-2".to_string();
+        settings.calendar_feed.feeds.push(duplicated);
+        assert!(SettingsValidator::validate(&settings).is_err());
+
+        // 短すぎるトークン
+        settings.calendar_feed.feeds.truncate(1);
+        settings.calendar_feed.feeds[0].token = "short".to_string();
+        assert!(SettingsValidator::validate(&settings).is_err());
+
+        // 特権ポート
+        settings.calendar_feed.feeds.clear();
+        settings.calendar_feed.port = 80;
+        assert!(SettingsValidator::validate(&settings).is_err());
+    }
+
+    #[test]
+    fn test_invalid_backup() {
+        let mut settings = Settings::default();
+        settings.backup.directory = Some("/tmp/flequit-backups".to_string());
+        assert!(SettingsValidator::validate(&settings).is_ok());
+
+        // 空の保存先
+        settings.backup.directory = Some(" ".to_string());
+        assert!(SettingsValidator::validate(&settings).is_err());
+
+        // 実行間隔が0
+        settings.backup.directory = None;
+        settings.backup.interval_hours = 0;
+        assert!(SettingsValidator::validate(&settings).is_err());
+
+        // 保持数が0
+        settings.backup.interval_hours = 24;
+        settings.backup.keep = 0;
+        assert!(SettingsValidator::validate(&settings).is_err());
+    }
 }
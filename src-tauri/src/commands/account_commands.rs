@@ -1,3 +1,8 @@
+//! アカウント関連のTauriコマンド
+//!
+//! アカウントはプロジェクトに属さない端末ローカルの認証情報であり、取り消し履歴は
+//! プロジェクト内の変更だけを対象とするため、これらのコマンドは`undoable`で包まない。
+
 use crate::models::account::AccountCommandModel;
 use crate::models::CommandModelConverter;
 use crate::state::AppState;
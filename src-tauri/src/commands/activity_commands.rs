@@ -0,0 +1,74 @@
+//! アクティビティログ（エンティティの変更履歴）関連のTauriコマンド
+
+use crate::models::activity::ActivityEntryCommandModel;
+use crate::state::AppState;
+use flequit_model::types::id_types::{ProjectId, TaskId};
+use tauri::State;
+use tracing::instrument;
+
+/// プロジェクトフィードの1回あたりの既定の取得件数
+const DEFAULT_FEED_LIMIT: u64 = 50;
+
+/// タスクのタイムライン（サブタスクの変更を含む）を古い順に取得します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn get_task_activity(
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: String,
+) -> Result<Vec<ActivityEntryCommandModel>, String> {
+    let repositories = state.repositories.read().await;
+    let entries = repositories
+        .task_activity(&ProjectId::from(project_id), &TaskId::from(task_id))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::activity", command = "get_task_activity", error = %e);
+            format!("タスクの変更履歴の取得に失敗: {}", e)
+        })?;
+    Ok(entries.into_iter().map(Into::into).collect())
+}
+
+/// プロジェクト全体の変更履歴を新しい順に取得します。
+///
+/// 続きを取得する場合は、前回取得した最後のエントリの `seq` を `before_seq` に指定します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn get_project_activity_feed(
+    state: State<'_, AppState>,
+    project_id: String,
+    before_seq: Option<i64>,
+    limit: Option<u64>,
+) -> Result<Vec<ActivityEntryCommandModel>, String> {
+    let repositories = state.repositories.read().await;
+    let entries = repositories
+        .project_activity_feed(
+            &ProjectId::from(project_id),
+            before_seq,
+            limit.unwrap_or(DEFAULT_FEED_LIMIT),
+        )
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::activity", command = "get_project_activity_feed", error = %e);
+            format!("プロジェクトの変更履歴の取得に失敗: {}", e)
+        })?;
+    Ok(entries.into_iter().map(Into::into).collect())
+}
+
+/// プロジェクトの変更履歴をAutomergeの変更履歴から作り直します。
+///
+/// 再構築したエントリ数を返します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn rebuild_project_activity(
+    state: State<'_, AppState>,
+    project_id: String,
+) -> Result<usize, String> {
+    let repositories = state.repositories.read().await;
+    repositories
+        .rebuild_project_activity(&ProjectId::from(project_id))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::activity", command = "rebuild_project_activity", error = %e);
+            format!("プロジェクトの変更履歴の再構築に失敗: {}", e)
+        })
+}
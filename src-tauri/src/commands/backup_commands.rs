@@ -0,0 +1,137 @@
+//! バックアップ・リストア関連のTauriコマンド
+
+use crate::models::backup::{
+    BackupFileCommandModel, BackupManifestCommandModel, BackupSettingsCommandModel,
+};
+use crate::state::AppState;
+use flequit_infrastructure::backup::{
+    BackupManager, backup_file_name, list_backups, resolve_backup_dir,
+};
+use std::path::PathBuf;
+use tauri::State;
+use tracing::instrument;
+
+/// 設定されたバックアップ保存先を取得
+async fn backup_dir(state: &AppState) -> Result<PathBuf, String> {
+    resolve_backup_dir(&state.settings.read().await.backup)
+        .ok_or_else(|| "バックアップ保存先を取得できません".to_string())
+}
+
+/// バックアップを作成します。
+///
+/// `path` を省略した場合は設定されたバックアップディレクトリに保存します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn create_backup(
+    state: State<'_, AppState>,
+    path: Option<String>,
+) -> Result<BackupFileCommandModel, String> {
+    let dest = match path {
+        Some(path) => PathBuf::from(path),
+        None => backup_dir(&state)
+            .await?
+            .join(backup_file_name(chrono::Utc::now())),
+    };
+
+    let repositories = state.repositories.read().await;
+    let manifest = repositories.create_backup(&dest).await.map_err(|e| {
+        tracing::error!(target: "commands::backup", command = "create_backup", error = %e);
+        format!("バックアップの作成に失敗: {}", e)
+    })?;
+
+    Ok(BackupFileCommandModel {
+        path: dest.to_string_lossy().to_string(),
+        size: std::fs::metadata(&dest).map(|m| m.len()).unwrap_or(0),
+        created_at: manifest.created_at,
+    })
+}
+
+/// 設定されたバックアップディレクトリ内のバックアップを新しい順に取得します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn list_backup_files(
+    state: State<'_, AppState>,
+) -> Result<Vec<BackupFileCommandModel>, String> {
+    let backups = list_backups(&backup_dir(&state).await?).map_err(|e| e.to_string())?;
+    Ok(backups.iter().map(BackupFileCommandModel::from).collect())
+}
+
+/// バックアップアーカイブを検証します。
+#[instrument(level = "info")]
+#[tauri::command]
+pub async fn verify_backup(path: String) -> Result<BackupManifestCommandModel, String> {
+    let manifest = BackupManager::verify_backup(&PathBuf::from(path)).map_err(|e| {
+        tracing::error!(target: "commands::backup", command = "verify_backup", error = %e);
+        format!("バックアップの検証に失敗: {}", e)
+    })?;
+    Ok(BackupManifestCommandModel::from(&manifest))
+}
+
+/// バックアップアーカイブからリストアします。
+///
+/// リストア中はリポジトリの書き込みロックを保持し、完了後に設定を読み込み直します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn restore_backup(
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<BackupManifestCommandModel, String> {
+    let manifest = {
+        let mut repositories = state.repositories.write().await;
+        repositories
+            .restore_backup(&PathBuf::from(path))
+            .await
+            .map_err(|e| {
+                tracing::error!(target: "commands::backup", command = "restore_backup", error = %e);
+                format!("バックアップのリストアに失敗: {}", e)
+            })?
+    };
+
+    // リストアした設定ファイルを読み込み直す
+    let settings = state
+        .settings_manager
+        .load_settings()
+        .await
+        .map_err(|e| format!("設定の再読み込みに失敗: {}", e))?;
+    *state.settings.write().await = settings;
+    // リストアした設定のバックアップ間隔・保存先で定期バックアップをやり直す
+    state.restart_backup_scheduler().await;
+
+    Ok(BackupManifestCommandModel::from(&manifest))
+}
+
+/// 定期バックアップの設定を取得します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn get_backup_settings(
+    state: State<'_, AppState>,
+) -> Result<BackupSettingsCommandModel, String> {
+    Ok(BackupSettingsCommandModel::from(
+        &state.settings.read().await.backup,
+    ))
+}
+
+/// 定期バックアップの設定を更新し、定期バックアップを再起動します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn update_backup_settings(
+    state: State<'_, AppState>,
+    backup: BackupSettingsCommandModel,
+) -> Result<BackupSettingsCommandModel, String> {
+    let mut settings = state.settings.read().await.clone();
+    settings.backup = backup.into();
+
+    state
+        .settings_manager
+        .save_settings(&settings)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::backup", command = "update_backup_settings", error = %e);
+            format!("設定の保存に失敗: {}", e)
+        })?;
+    let backup = BackupSettingsCommandModel::from(&settings.backup);
+    *state.settings.write().await = settings;
+    state.restart_backup_scheduler().await;
+
+    Ok(backup)
+}
@@ -0,0 +1,67 @@
+//! プロジェクトバンドル（.flequitファイル）関連のTauriコマンド
+
+use crate::models::bundle::{ProjectBundleCommandModel, ProjectBundleImportCommandModel};
+use crate::state::AppState;
+use flequit_infrastructure::bundle::{BundleImportMode, read_bundle};
+use flequit_model::types::id_types::ProjectId;
+use std::path::PathBuf;
+use tauri::State;
+use tracing::instrument;
+
+/// プロジェクトを変更履歴ごとバンドルファイルに書き出します。
+#[instrument(level = "info", skip(state), fields(project_id = %project_id))]
+#[tauri::command]
+pub async fn export_project_bundle(
+    state: State<'_, AppState>,
+    project_id: String,
+    path: String,
+) -> Result<ProjectBundleCommandModel, String> {
+    let repositories = state.repositories.read().await;
+    let manifest = repositories
+        .export_project_bundle(&ProjectId::from(project_id), &PathBuf::from(path))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::bundle", command = "export_project_bundle", error = %e);
+            format!("プロジェクトのエクスポートに失敗: {}", e)
+        })?;
+    Ok(ProjectBundleCommandModel::from(&manifest))
+}
+
+/// バンドルファイルを検証し、含まれるプロジェクトの概要を取得します。
+#[instrument(level = "info")]
+#[tauri::command]
+pub async fn inspect_project_bundle(path: String) -> Result<ProjectBundleCommandModel, String> {
+    let (manifest, _) = read_bundle(&PathBuf::from(path)).map_err(|e| {
+        tracing::error!(target: "commands::bundle", command = "inspect_project_bundle", error = %e);
+        format!("バンドルの読み込みに失敗: {}", e)
+    })?;
+    Ok(ProjectBundleCommandModel::from(&manifest))
+}
+
+/// バンドルファイルからプロジェクトを取り込みます。
+///
+/// `keep_ids` が true の場合はIDを保持して履歴をマージし、
+/// false の場合はIDを振り直したコピーとして取り込みます。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn import_project_bundle(
+    state: State<'_, AppState>,
+    path: String,
+    keep_ids: bool,
+) -> Result<ProjectBundleImportCommandModel, String> {
+    let mode = if keep_ids {
+        BundleImportMode::KeepIds
+    } else {
+        BundleImportMode::RemapIds
+    };
+
+    let repositories = state.repositories.read().await;
+    let result = repositories
+        .import_project_bundle(&PathBuf::from(path), mode)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::bundle", command = "import_project_bundle", error = %e);
+            format!("プロジェクトのインポートに失敗: {}", e)
+        })?;
+    Ok(ProjectBundleImportCommandModel::from(&result))
+}
@@ -0,0 +1,161 @@
+//! CalDAV同期関連のTauriコマンド
+//!
+//! パスワードは保存せず、サーバーにアクセスするコマンドの呼び出しごとに受け取ります。
+
+use crate::models::caldav::{
+    CalDavBindingCommandModel, CalDavCalendarCommandModel, CalDavConnectionCommandModel,
+    CalDavSyncReportCommandModel,
+};
+use crate::state::AppState;
+use flequit_core::services::task_list_service;
+use flequit_infrastructure::caldav::{
+    self, CalDavClient, CalDavCredentials, CalDavSyncState, CalendarBinding,
+    get_default_caldav_state_path,
+};
+use flequit_model::types::id_types::{ProjectId, TaskListId, UserId};
+use std::path::PathBuf;
+use tauri::State;
+use tokio::sync::Mutex;
+use tracing::instrument;
+
+/// 同期状態ファイルの読み書きと同期処理を直列化する
+static CALDAV_LOCK: Mutex<()> = Mutex::const_new(());
+
+fn state_path() -> Result<PathBuf, String> {
+    get_default_caldav_state_path()
+        .ok_or_else(|| "CalDAV同期状態の保存先を取得できません".to_string())
+}
+
+fn load_state() -> Result<CalDavSyncState, String> {
+    CalDavSyncState::load(&state_path()?).map_err(|e| {
+        tracing::error!(target: "commands::caldav", command = "load_state", error = %e);
+        format!("CalDAV同期状態の読み込みに失敗: {}", e)
+    })
+}
+
+fn save_state(sync_state: &CalDavSyncState) -> Result<(), String> {
+    sync_state.save(&state_path()?).map_err(|e| {
+        tracing::error!(target: "commands::caldav", command = "save_state", error = %e);
+        format!("CalDAV同期状態の保存に失敗: {}", e)
+    })
+}
+
+fn client(connection: &CalDavConnectionCommandModel) -> Result<CalDavClient, String> {
+    let credentials = connection
+        .username
+        .as_ref()
+        .map(|username| CalDavCredentials {
+            username: username.clone(),
+            password: connection.password.clone().unwrap_or_default(),
+        });
+    CalDavClient::new(&connection.url, credentials).map_err(|e| e.to_string())
+}
+
+/// サーバー上のVTODOに対応したカレンダーを取得します。
+#[instrument(level = "info", skip(connection))]
+#[tauri::command]
+pub async fn list_caldav_calendars(
+    connection: CalDavConnectionCommandModel,
+) -> Result<Vec<CalDavCalendarCommandModel>, String> {
+    let calendars = client(&connection)?.list_calendars().await.map_err(|e| {
+        tracing::error!(target: "commands::caldav", command = "list_caldav_calendars", error = %e);
+        format!("カレンダー一覧の取得に失敗: {}", e)
+    })?;
+    Ok(calendars.into_iter().map(Into::into).collect())
+}
+
+/// タスクリストとカレンダーの対応を取得します。
+#[instrument(level = "info")]
+#[tauri::command]
+pub async fn list_caldav_bindings() -> Result<Vec<CalDavBindingCommandModel>, String> {
+    let _guard = CALDAV_LOCK.lock().await;
+    Ok(load_state()?.bindings.iter().map(Into::into).collect())
+}
+
+/// タスクリストを既存のカレンダーに対応付けます。
+///
+/// `calendar_href` を省略した場合は、タスクリスト名のカレンダーをサーバー上に作成します。
+#[instrument(level = "info", skip(state, connection))]
+#[tauri::command]
+pub async fn bind_caldav_calendar(
+    state: State<'_, AppState>,
+    connection: CalDavConnectionCommandModel,
+    project_id: String,
+    task_list_id: String,
+    calendar_href: Option<String>,
+) -> Result<CalDavBindingCommandModel, String> {
+    let project_id = ProjectId::from(project_id);
+    let task_list_id = TaskListId::from(task_list_id);
+    let task_list = {
+        let repositories = state.repositories.read().await;
+        task_list_service::get_task_list(&*repositories, &project_id, &task_list_id)
+            .await
+            .map_err(|e| format!("Failed to get task list: {:?}", e))?
+            .ok_or_else(|| format!("Task list not found: {}", task_list_id))?
+    };
+
+    let client = client(&connection)?;
+    let (calendar_href, display_name) = match calendar_href {
+        Some(href) => (href, None),
+        None => {
+            let href = format!("{}{}/", client.base_url().path(), task_list_id);
+            client
+                .create_calendar(&href, &task_list.name)
+                .await
+                .map_err(|e| {
+                    tracing::error!(target: "commands::caldav", command = "bind_caldav_calendar", error = %e);
+                    format!("カレンダーの作成に失敗: {}", e)
+                })?;
+            (href, Some(task_list.name.clone()))
+        }
+    };
+
+    let _guard = CALDAV_LOCK.lock().await;
+    let mut sync_state = load_state()?;
+    let binding = CalendarBinding::new(project_id, task_list_id, &calendar_href, display_name);
+    let result = CalDavBindingCommandModel::from(&binding);
+    sync_state.bind(binding).map_err(|e| e.to_string())?;
+    sync_state.server_url = Some(connection.url.clone());
+    sync_state.username = connection.username.clone();
+    save_state(&sync_state)?;
+
+    Ok(result)
+}
+
+/// タスクリストとカレンダーの対応を解除します（タスク・カレンダーは削除しません）。
+#[instrument(level = "info")]
+#[tauri::command]
+pub async fn unbind_caldav_calendar(task_list_id: String) -> Result<bool, String> {
+    let _guard = CALDAV_LOCK.lock().await;
+    let mut sync_state = load_state()?;
+    let removed = sync_state.unbind(&TaskListId::from(task_list_id));
+    if removed {
+        save_state(&sync_state)?;
+    }
+    Ok(removed)
+}
+
+/// 対応付けられた全てのタスクリストを同期します。
+#[instrument(level = "info", skip(state, connection))]
+#[tauri::command]
+pub async fn sync_caldav(
+    state: State<'_, AppState>,
+    connection: CalDavConnectionCommandModel,
+    user_id: String,
+) -> Result<CalDavSyncReportCommandModel, String> {
+    let client = client(&connection)?;
+    let user_id = UserId::from(user_id);
+
+    let _guard = CALDAV_LOCK.lock().await;
+    let mut sync_state = load_state()?;
+    let report = {
+        let repositories = state.repositories.read().await;
+        caldav::sync_all(&*repositories, &client, &mut sync_state, &user_id).await
+    };
+    save_state(&sync_state)?;
+
+    for error in &report.errors {
+        tracing::warn!(target: "commands::caldav", command = "sync_caldav", error = %error);
+    }
+    Ok(report.into())
+}
@@ -0,0 +1,47 @@
+//! iCalendarエクスポート関連のTauriコマンド
+
+use crate::models::calendar_export::CalendarExportFilterCommandModel;
+use crate::state::AppState;
+use flequit_core::facades::calendar_export_facades;
+use flequit_model::types::id_types::ProjectId;
+use tauri::State;
+use tracing::instrument;
+
+/// タスクをiCalendar（.ics）ファイルに書き出します。
+///
+/// `project_ids` が空の場合は全プロジェクトを対象とします。
+/// 書き出したファイルのバイト数を返します。
+#[instrument(level = "info", skip(state, filter))]
+#[tauri::command]
+pub async fn export_calendar(
+    state: State<'_, AppState>,
+    project_ids: Vec<String>,
+    filter: Option<CalendarExportFilterCommandModel>,
+    path: String,
+) -> Result<usize, String> {
+    let project_ids: Vec<ProjectId> = project_ids.into_iter().map(ProjectId::from).collect();
+    let filter = filter.unwrap_or_default().into();
+
+    let repositories = state.repositories.read().await;
+    let ics = if let [project_id] = project_ids.as_slice() {
+        calendar_export_facades::export_project_calendar(&*repositories, project_id, &filter).await
+    } else {
+        calendar_export_facades::export_calendar(
+            &*repositories,
+            &project_ids,
+            &filter,
+            Some("Flequit".to_string()),
+        )
+        .await
+    }
+    .map_err(|e| {
+        tracing::error!(target: "commands::calendar_export", command = "export_calendar", error = %e);
+        e
+    })?;
+
+    std::fs::write(&path, ics.as_bytes()).map_err(|e| {
+        tracing::error!(target: "commands::calendar_export", command = "export_calendar", error = %e);
+        format!("カレンダーファイルの書き込みに失敗: {}", e)
+    })?;
+    Ok(ics.len())
+}
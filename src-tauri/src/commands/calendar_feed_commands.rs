@@ -0,0 +1,153 @@
+//! カレンダー購読フィード関連のTauriコマンド
+//!
+//! フィード定義は設定ファイルに保存し、配信サーバーはリクエストのたびに設定を参照するため、
+//! フィードの追加・トークン再発行・削除はサーバーを再起動せずに反映されます。
+
+use crate::models::calendar_feed::{
+    CalendarFeedCommandModel, CalendarFeedRequestCommandModel, CalendarFeedStatusCommandModel,
+};
+use crate::state::AppState;
+use flequit_infrastructure::calendar_feed::{CalendarFeedServer, generate_feed_token};
+use flequit_settings::CalendarFeedSettings;
+use tauri::State;
+use tracing::instrument;
+
+/// フィード設定を保存（保存時に検証されます）し、stateにも反映します。
+async fn save_calendar_feed_settings(
+    state: &AppState,
+    calendar_feed: CalendarFeedSettings,
+) -> Result<(), String> {
+    let mut settings = state.settings.read().await.clone();
+    settings.calendar_feed = calendar_feed;
+
+    state
+        .settings_manager
+        .save_settings(&settings)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::calendar_feed", command = "save_calendar_feed_settings", error = %e);
+            format!("設定の保存に失敗: {}", e)
+        })?;
+    *state.settings.write().await = settings;
+    Ok(())
+}
+
+/// 配信サーバーの状態を取得します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn get_calendar_feed_status(
+    state: State<'_, AppState>,
+) -> Result<CalendarFeedStatusCommandModel, String> {
+    let settings = state.settings.read().await;
+    Ok(CalendarFeedStatusCommandModel {
+        enabled: settings.calendar_feed.enabled,
+        port: settings.calendar_feed.port,
+        running: state.calendar_feed_server.lock().await.is_some(),
+    })
+}
+
+/// 配信サーバーの有効・無効とポートを更新し、サーバーを再起動または停止します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn update_calendar_feed_server(
+    state: State<'_, AppState>,
+    enabled: bool,
+    port: Option<u16>,
+) -> Result<CalendarFeedStatusCommandModel, String> {
+    let mut calendar_feed = state.settings.read().await.calendar_feed.clone();
+    calendar_feed.enabled = enabled;
+    if let Some(port) = port {
+        calendar_feed.port = port;
+    }
+    let port = calendar_feed.port;
+    save_calendar_feed_settings(&state, calendar_feed).await?;
+
+    let mut server = state.calendar_feed_server.lock().await;
+    if let Some(handle) = server.take() {
+        handle.shutdown();
+    }
+    if enabled {
+        let handle =
+            CalendarFeedServer::start(state.repositories.clone(), state.settings.clone(), port)
+                .await
+                .map_err(|e| {
+                    tracing::error!(target: "commands::calendar_feed", command = "update_calendar_feed_server", error = %e);
+                    format!("カレンダーフィードの配信開始に失敗: {}", e)
+                })?;
+        *server = Some(handle);
+    }
+
+    Ok(CalendarFeedStatusCommandModel {
+        enabled,
+        port,
+        running: server.is_some(),
+    })
+}
+
+/// 登録済みのフィードを取得します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn list_calendar_feeds(
+    state: State<'_, AppState>,
+) -> Result<Vec<CalendarFeedCommandModel>, String> {
+    let settings = state.settings.read().await;
+    let port = settings.calendar_feed.port;
+    Ok(settings
+        .calendar_feed
+        .feeds
+        .iter()
+        .map(|feed| CalendarFeedCommandModel::from_feed(feed, port))
+        .collect())
+}
+
+/// 新しいフィードを作成します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn create_calendar_feed(
+    state: State<'_, AppState>,
+    feed: CalendarFeedRequestCommandModel,
+) -> Result<CalendarFeedCommandModel, String> {
+    let feed = feed.into_feed(uuid::Uuid::new_v4().to_string(), generate_feed_token());
+    let mut calendar_feed = state.settings.read().await.calendar_feed.clone();
+    calendar_feed.feeds.push(feed.clone());
+    let port = calendar_feed.port;
+    save_calendar_feed_settings(&state, calendar_feed).await?;
+
+    Ok(CalendarFeedCommandModel::from_feed(&feed, port))
+}
+
+/// フィードのトークンを再発行します。以前のURLは無効になります。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn regenerate_calendar_feed_token(
+    state: State<'_, AppState>,
+    id: String,
+) -> Result<CalendarFeedCommandModel, String> {
+    let mut calendar_feed = state.settings.read().await.calendar_feed.clone();
+    let port = calendar_feed.port;
+    let feed = calendar_feed
+        .feeds
+        .iter_mut()
+        .find(|feed| feed.id == id)
+        .ok_or_else(|| format!("Calendar feed not found: {}", id))?;
+    feed.token = generate_feed_token();
+    let result = CalendarFeedCommandModel::from_feed(feed, port);
+    save_calendar_feed_settings(&state, calendar_feed).await?;
+
+    Ok(result)
+}
+
+/// フィードを削除します。URLは即座に無効になります。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn revoke_calendar_feed(state: State<'_, AppState>, id: String) -> Result<bool, String> {
+    let mut calendar_feed = state.settings.read().await.calendar_feed.clone();
+    let before = calendar_feed.feeds.len();
+    calendar_feed.feeds.retain(|feed| feed.id != id);
+    if calendar_feed.feeds.len() == before {
+        return Ok(false);
+    }
+    save_calendar_feed_settings(&state, calendar_feed).await?;
+
+    Ok(true)
+}
@@ -0,0 +1,131 @@
+//! コメント関連のTauriコマンド
+
+use crate::commands::undo_commands::undoable;
+use crate::models::CommandModelConverter;
+use crate::models::comment::CommentCommandModel;
+use crate::state::AppState;
+use flequit_core::facades::comment_facades;
+use flequit_model::models::task_projects::comment::Comment;
+use flequit_model::types::id_types::{CommentId, ProjectId, SubTaskId, TaskId, UserId};
+use tauri::State;
+use tracing::instrument;
+
+async fn to_command_models(comments: Vec<Comment>) -> Result<Vec<CommentCommandModel>, String> {
+    let mut command_models = Vec::with_capacity(comments.len());
+    for comment in comments {
+        command_models.push(comment.to_command_model().await?);
+    }
+    Ok(command_models)
+}
+
+/// タスクのコメントを投稿順に取得します。
+///
+/// `subtask_id`を指定した場合はそのサブタスクのコメントだけを、
+/// 指定しない場合はサブタスクへのものを含むタスクのすべてのコメントを返します。
+#[instrument(level = "info", skip(state), fields(project_id = %project_id, task_id = %task_id))]
+#[tauri::command]
+pub async fn list_comments(
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: String,
+    subtask_id: Option<String>,
+) -> Result<Vec<CommentCommandModel>, String> {
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id = TaskId::try_from_str(&task_id).map_err(|e| e.to_string())?;
+    let subtask_id = match subtask_id {
+        Some(id) => Some(SubTaskId::try_from_str(&id).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let repositories = state.repositories.read().await;
+
+    let comments = comment_facades::list_comments(&*repositories, &project_id, Some(&task_id), subtask_id.as_ref())
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::comment", command = "list_comments", project_id = %project_id, task_id = %task_id, error = %e);
+            e
+        })?;
+    to_command_models(comments).await
+}
+
+/// タスク（`subtask_id`を指定した場合はサブタスク）にコメントを投稿します。
+///
+/// 本文中の`@ハンドル`は言及したユーザーとして記録します。
+#[instrument(level = "info", skip(window, state, body), fields(project_id = %project_id, task_id = %task_id))]
+#[tauri::command]
+pub async fn add_comment(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: String,
+    subtask_id: Option<String>,
+    body: String,
+    user_id: String,
+) -> Result<CommentCommandModel, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id = TaskId::try_from_str(&task_id).map_err(|e| e.to_string())?;
+    let subtask_id = match subtask_id {
+        Some(id) => Some(SubTaskId::try_from_str(&id).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let repositories = state.repositories.read().await;
+
+    let comment = undoable(&window, comment_facades::add_comment(&*repositories, &project_id, &task_id, subtask_id.as_ref(), &body, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::comment", command = "add_comment", project_id = %project_id, task_id = %task_id, error = %e);
+            e
+        })?;
+    comment.to_command_model().await
+}
+
+/// コメントの本文を編集します。投稿者本人のみが編集できます。
+///
+/// コメントが存在しない場合は`false`を返します。
+#[instrument(level = "info", skip(window, state, body), fields(project_id = %project_id, comment_id = %comment_id))]
+#[tauri::command]
+pub async fn edit_comment(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    comment_id: String,
+    body: String,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let comment_id = CommentId::try_from_str(&comment_id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, comment_facades::edit_comment(&*repositories, &project_id, &comment_id, &body, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::comment", command = "edit_comment", project_id = %project_id, comment_id = %comment_id, error = %e);
+            e
+        })
+}
+
+/// コメントを削除します。投稿者本人のみが削除でき、取り消しで元に戻せます。
+///
+/// コメントが存在しない場合は`false`を返します。
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, comment_id = %comment_id))]
+#[tauri::command]
+pub async fn delete_comment(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    comment_id: String,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let comment_id = CommentId::try_from_str(&comment_id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, comment_facades::delete_comment(&*repositories, &project_id, &comment_id, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::comment", command = "delete_comment", project_id = %project_id, comment_id = %comment_id, error = %e);
+            e
+        })
+}
@@ -0,0 +1,93 @@
+//! Automergeドキュメント暗号化関連のTauriコマンド
+//!
+//! プロファイル鍵が存在する場合、起動直後はロック状態となり、
+//! `unlock_document_encryption` を呼び出すまで暗号化済みドキュメントは読み書きできません。
+
+use crate::models::encryption::DocumentEncryptionStatusCommandModel;
+use crate::state::AppState;
+use tauri::State;
+use tracing::instrument;
+
+/// Automergeドキュメント暗号化の状態を取得します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn get_document_encryption_status(
+    state: State<'_, AppState>,
+) -> Result<DocumentEncryptionStatusCommandModel, String> {
+    let repositories = state.repositories.read().await;
+    let status = repositories.document_encryption_status().map_err(|e| {
+        tracing::error!(target: "commands::encryption", command = "get_document_encryption_status", error = %e);
+        format!("暗号化状態の取得に失敗: {}", e)
+    })?;
+    Ok(DocumentEncryptionStatusCommandModel::from(status))
+}
+
+/// Automergeドキュメント暗号化を有効化します。
+///
+/// 既存のドキュメントは指定したパスフレーズで保護された鍵で暗号化されます。
+#[instrument(level = "info", skip(state, passphrase))]
+#[tauri::command]
+pub async fn enable_document_encryption(
+    state: State<'_, AppState>,
+    passphrase: String,
+) -> Result<(), String> {
+    let mut repositories = state.repositories.write().await;
+    repositories
+        .enable_document_encryption(&passphrase)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::encryption", command = "enable_document_encryption", error = %e);
+            format!("暗号化の有効化に失敗: {}", e)
+        })
+}
+
+/// パスフレーズで鍵をアンロックし、暗号化済みドキュメントを読み書き可能にします。
+#[instrument(level = "info", skip(state, passphrase))]
+#[tauri::command]
+pub async fn unlock_document_encryption(
+    state: State<'_, AppState>,
+    passphrase: String,
+) -> Result<(), String> {
+    let mut repositories = state.repositories.write().await;
+    repositories
+        .unlock_document_encryption(&passphrase)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::encryption", command = "unlock_document_encryption", error = %e);
+            format!("ロック解除に失敗: {}", e)
+        })
+}
+
+/// Automergeドキュメント暗号化を無効化し、ドキュメントを平文に戻します。
+#[instrument(level = "info", skip(state, passphrase))]
+#[tauri::command]
+pub async fn disable_document_encryption(
+    state: State<'_, AppState>,
+    passphrase: String,
+) -> Result<(), String> {
+    let mut repositories = state.repositories.write().await;
+    repositories
+        .disable_document_encryption(&passphrase)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::encryption", command = "disable_document_encryption", error = %e);
+            format!("暗号化の無効化に失敗: {}", e)
+        })
+}
+
+/// 暗号化鍵のパスフレーズを変更します。
+#[instrument(level = "info", skip(state, old_passphrase, new_passphrase))]
+#[tauri::command]
+pub async fn change_document_encryption_passphrase(
+    state: State<'_, AppState>,
+    old_passphrase: String,
+    new_passphrase: String,
+) -> Result<(), String> {
+    let repositories = state.repositories.read().await;
+    repositories
+        .change_document_passphrase(&old_passphrase, &new_passphrase)
+        .map_err(|e| {
+            tracing::error!(target: "commands::encryption", command = "change_document_encryption_passphrase", error = %e);
+            format!("パスフレーズの変更に失敗: {}", e)
+        })
+}
@@ -0,0 +1,54 @@
+//! 外部ツールからのタスクインポート関連のTauriコマンド
+
+use crate::models::import::{TaskImportCommandModel, TaskImportResultCommandModel};
+use crate::state::AppState;
+use flequit_core::facades::import_facades;
+use flequit_model::types::id_types::UserId;
+use tauri::State;
+use tracing::instrument;
+
+/// インポート内容を解析し、保存せずに結果をプレビューします。
+#[instrument(level = "info", skip(request), fields(format = %request.format))]
+#[tauri::command]
+pub async fn preview_task_import(
+    request: TaskImportCommandModel,
+) -> Result<TaskImportResultCommandModel, String> {
+    let format = request.to_format()?;
+    let result = import_facades::preview_import(&format, &request.content, &request.to_options())
+        .map_err(|e| {
+        tracing::error!(target: "commands::import", command = "preview_task_import", error = %e);
+        e
+    })?;
+    Ok(result.into())
+}
+
+/// 外部ツールのエクスポートファイルからプロジェクト・タスクを取り込みます。
+///
+/// `dry_run` が true の場合は保存せずに結果のみを返します。
+#[instrument(level = "info", skip(state, request), fields(format = %request.format))]
+#[tauri::command]
+pub async fn import_tasks(
+    state: State<'_, AppState>,
+    request: TaskImportCommandModel,
+    user_id: String,
+    dry_run: bool,
+) -> Result<TaskImportResultCommandModel, String> {
+    let format = request.to_format()?;
+    let user_id = UserId::from(user_id);
+
+    let repositories = state.repositories.read().await;
+    let result = import_facades::import_tasks(
+        &*repositories,
+        &format,
+        &request.content,
+        &request.to_options(),
+        &user_id,
+        dry_run,
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!(target: "commands::import", command = "import_tasks", error = %e);
+        format!("タスクのインポートに失敗: {}", e)
+    })?;
+    Ok(result.into())
+}
@@ -1,16 +1,34 @@
 pub mod account_commands;
+pub mod activity_commands;
+pub mod backup_commands;
+pub mod bundle_commands;
+pub mod caldav_commands;
+pub mod calendar_export_commands;
+pub mod calendar_feed_commands;
+pub mod comment_commands;
+pub mod encryption_commands;
+pub mod import_commands;
 pub mod initialization_commands;
+pub mod outbox_commands;
 pub mod project_commands;
+pub mod reminder_commands;
+pub mod schedule_commands;
 pub mod settings_commands;
+pub mod snooze_commands;
+pub mod status_transition_commands;
 pub mod subtask_assignment_commands;
 pub mod subtask_commands;
 pub mod tag_commands;
 pub mod tagging_commands;
 pub mod task_assignment_commands;
 pub mod task_commands;
+pub mod task_dependency_commands;
 pub mod task_list_commands;
+pub mod time_entry_commands;
+pub mod undo_commands;
 pub mod user_commands;
 pub mod user_preferences_commands;
+pub mod workflow_status_commands;
 
 #[macro_export]
 macro_rules! generate_app_handler {
@@ -26,11 +44,62 @@ macro_rules! generate_app_handler {
             account_commands::get_account,
             account_commands::update_account,
             account_commands::delete_account,
+            // Backup commands
+            backup_commands::create_backup,
+            backup_commands::list_backup_files,
+            backup_commands::verify_backup,
+            backup_commands::restore_backup,
+            backup_commands::get_backup_settings,
+            backup_commands::update_backup_settings,
+            // Document encryption commands
+            encryption_commands::get_document_encryption_status,
+            encryption_commands::enable_document_encryption,
+            encryption_commands::unlock_document_encryption,
+            encryption_commands::disable_document_encryption,
+            encryption_commands::change_document_encryption_passphrase,
+            // Project bundle commands
+            bundle_commands::export_project_bundle,
+            bundle_commands::inspect_project_bundle,
+            bundle_commands::import_project_bundle,
+            // Calendar export commands
+            calendar_export_commands::export_calendar,
+            // CalDAV sync commands
+            caldav_commands::list_caldav_calendars,
+            caldav_commands::list_caldav_bindings,
+            caldav_commands::bind_caldav_calendar,
+            caldav_commands::unbind_caldav_calendar,
+            caldav_commands::sync_caldav,
+            // Calendar feed commands
+            calendar_feed_commands::get_calendar_feed_status,
+            calendar_feed_commands::update_calendar_feed_server,
+            calendar_feed_commands::list_calendar_feeds,
+            calendar_feed_commands::create_calendar_feed,
+            calendar_feed_commands::regenerate_calendar_feed_token,
+            calendar_feed_commands::revoke_calendar_feed,
+            // Outbox commands
+            outbox_commands::get_outbox_summary,
+            outbox_commands::list_outbox_operations,
+            outbox_commands::flush_outbox,
+            outbox_commands::retry_outbox_operation,
+            outbox_commands::discard_outbox_operation,
+            // Activity log commands
+            activity_commands::get_task_activity,
+            activity_commands::get_project_activity_feed,
+            activity_commands::rebuild_project_activity,
+            // Undo/redo commands
+            undo_commands::undo,
+            undo_commands::redo,
+            undo_commands::get_undo_status,
+            // Task import commands
+            import_commands::preview_task_import,
+            import_commands::import_tasks,
             // Task management commands
             task_commands::create_task,
             task_commands::get_task,
             task_commands::search_tasks,
             task_commands::update_task,
+            task_commands::update_task_status,
+            task_commands::move_task_to_workflow_status,
             task_commands::delete_task,
             task_commands::restore_task,
             // Task recurrence commands
@@ -87,6 +156,7 @@ macro_rules! generate_app_handler {
             subtask_commands::get_sub_task,
             subtask_commands::search_sub_tasks,
             subtask_commands::update_sub_task,
+            subtask_commands::update_sub_task_status,
             subtask_commands::delete_sub_task,
             // Subtask recurrence commands
             subtask_commands::create_subtask_recurrence,
@@ -99,6 +169,46 @@ macro_rules! generate_app_handler {
             tag_commands::update_tag,
             tag_commands::delete_tag,
             tag_commands::restore_tag,
+            // Time entry commands
+            time_entry_commands::start_timer,
+            time_entry_commands::stop_timer,
+            time_entry_commands::get_running_timer,
+            time_entry_commands::create_time_entry,
+            time_entry_commands::get_time_entry,
+            time_entry_commands::list_time_entries,
+            time_entry_commands::update_time_entry,
+            time_entry_commands::delete_time_entry,
+            time_entry_commands::summarize_time,
+            // Reminder commands
+            reminder_commands::list_reminders,
+            reminder_commands::create_reminder,
+            reminder_commands::update_reminder,
+            reminder_commands::delete_reminder,
+            reminder_commands::carry_over_task_reminders,
+            reminder_commands::carry_over_subtask_reminders,
+            // Snooze commands
+            snooze_commands::list_snoozes,
+            snooze_commands::snooze_task,
+            snooze_commands::snooze_subtask,
+            snooze_commands::snooze_reminder,
+            snooze_commands::cancel_deferral,
+            // Comment commands
+            comment_commands::list_comments,
+            comment_commands::add_comment,
+            comment_commands::edit_comment,
+            comment_commands::delete_comment,
+            // Schedule commands
+            schedule_commands::compute_schedule,
+            // Status transition rule commands
+            status_transition_commands::list_status_transition_rules,
+            status_transition_commands::create_status_transition_rule,
+            status_transition_commands::update_status_transition_rule,
+            status_transition_commands::delete_status_transition_rule,
+            // Workflow status commands
+            workflow_status_commands::list_workflow_statuses,
+            workflow_status_commands::create_workflow_status,
+            workflow_status_commands::update_workflow_status,
+            workflow_status_commands::delete_workflow_status,
             // Tag Bookmark commands (User Preferences)
             user_preferences_commands::create_tag_bookmark,
             user_preferences_commands::list_tag_bookmarks_by_project,
@@ -133,6 +243,11 @@ macro_rules! generate_app_handler {
             task_assignment_commands::delete_task_assignment,
             subtask_assignment_commands::create_subtask_assignment,
             subtask_assignment_commands::delete_subtask_assignment,
+            // Task dependency commands
+            task_dependency_commands::create_task_dependency,
+            task_dependency_commands::delete_task_dependency,
+            task_dependency_commands::get_blocking_task_ids,
+            task_dependency_commands::get_dependent_task_ids,
             // Task recurrence management commands
             task_commands::create_recurrence_rule,
             task_commands::get_recurrence_rule,
@@ -0,0 +1,95 @@
+//! アウトボックス（オフライン時に送信できなかった書き込み）関連のTauriコマンド
+//!
+//! 再送は定期的にバックグラウンドで行われ、競合・デッドレターが発生した場合は
+//! `outbox-attention` イベントで再送結果を通知します。
+
+use crate::models::outbox::{
+    OutboxFlushReportCommandModel, OutboxOperationCommandModel, OutboxSummaryCommandModel,
+};
+use crate::state::AppState;
+use flequit_model::models::sync::outbox_operation::OutboxStatus;
+use tauri::State;
+use tracing::instrument;
+
+/// 競合・デッドレターの発生を通知するイベント名
+pub const OUTBOX_ATTENTION_EVENT: &str = "outbox-attention";
+
+/// アウトボックス内の操作数を取得します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn get_outbox_summary(
+    state: State<'_, AppState>,
+) -> Result<OutboxSummaryCommandModel, String> {
+    let repositories = state.repositories.read().await;
+    let summary = repositories.outbox_summary().await.map_err(|e| {
+        tracing::error!(target: "commands::outbox", command = "get_outbox_summary", error = %e);
+        format!("アウトボックスの取得に失敗: {}", e)
+    })?;
+    Ok(summary.into())
+}
+
+/// アウトボックス内の操作を記録順に取得します。
+///
+/// `status`（`pending` / `conflict` / `dead_letter`）を省略した場合は全件を返します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn list_outbox_operations(
+    state: State<'_, AppState>,
+    status: Option<String>,
+) -> Result<Vec<OutboxOperationCommandModel>, String> {
+    let status = status
+        .map(|status| status.parse::<OutboxStatus>())
+        .transpose()?;
+    let repositories = state.repositories.read().await;
+    let operations = repositories
+        .list_outbox_operations(status)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::outbox", command = "list_outbox_operations", error = %e);
+            format!("アウトボックスの取得に失敗: {}", e)
+        })?;
+    Ok(operations.into_iter().map(Into::into).collect())
+}
+
+/// 再送日時を待たずにアウトボックスを再送します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn flush_outbox(
+    state: State<'_, AppState>,
+) -> Result<OutboxFlushReportCommandModel, String> {
+    let repositories = state.repositories.read().await;
+    let report = repositories.flush_outbox().await.map_err(|e| {
+        tracing::error!(target: "commands::outbox", command = "flush_outbox", error = %e);
+        format!("アウトボックスの再送に失敗: {}", e)
+    })?;
+    Ok(report.into())
+}
+
+/// 競合・デッドレターの操作を再送キューに戻します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn retry_outbox_operation(
+    state: State<'_, AppState>,
+    seq: i64,
+) -> Result<OutboxOperationCommandModel, String> {
+    let repositories = state.repositories.read().await;
+    let operation = repositories.retry_outbox_operation(seq).await.map_err(|e| {
+        tracing::error!(target: "commands::outbox", command = "retry_outbox_operation", error = %e);
+        format!("アウトボックスの操作の再送に失敗: {}", e)
+    })?;
+    Ok(operation.into())
+}
+
+/// 操作を送信せずに破棄します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn discard_outbox_operation(
+    state: State<'_, AppState>,
+    seq: i64,
+) -> Result<bool, String> {
+    let repositories = state.repositories.read().await;
+    repositories.discard_outbox_operation(seq).await.map_err(|e| {
+        tracing::error!(target: "commands::outbox", command = "discard_outbox_operation", error = %e);
+        format!("アウトボックスの操作の破棄に失敗: {}", e)
+    })
+}
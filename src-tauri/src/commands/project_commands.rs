@@ -1,18 +1,20 @@
+use crate::commands::undo_commands::undoable;
+use crate::models::CommandModelConverter;
 use crate::models::project::{ProjectCommandModel, ProjectTreeCommandModel};
 use crate::models::project_search_request::ProjectSearchRequest;
-use crate::models::CommandModelConverter;
 use crate::state::AppState;
 use chrono::Utc;
 use flequit_core::facades::project_facades;
 use flequit_core::services::{tag_service, task_list_service};
-use flequit_model::models::{task_projects::project::PartialProject, ModelConverter};
+use flequit_model::models::{ModelConverter, task_projects::project::PartialProject};
 use flequit_model::types::id_types::{ProjectId, UserId};
 use tauri::State;
 use tracing::instrument;
 
-#[instrument(level = "info", skip(state, project), fields(project_id = %project.id))]
+#[instrument(level = "info", skip(window, state, project), fields(project_id = %project.id))]
 #[tauri::command]
 pub async fn create_project(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project: ProjectCommandModel,
     user_id: String,
@@ -21,12 +23,15 @@ pub async fn create_project(
     let repositories = state.repositories.read().await;
     let internal_project = project.to_model().await?;
 
-    project_facades::create_project(&*repositories, &internal_project, &user_id_typed)
-        .await
-        .map_err(|e| {
-            tracing::error!(target: "commands::project", command = "create_project", error = %e);
-            e
-        })
+    undoable(
+        &window,
+        project_facades::create_project(&*repositories, &internal_project, &user_id_typed),
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!(target: "commands::project", command = "create_project", error = %e);
+        e
+    })
 }
 
 #[instrument(level = "info", skip(state), fields(project_id = %id))]
@@ -80,9 +85,10 @@ pub async fn search_projects(
     Ok(result)
 }
 
-#[instrument(level = "info", skip(state, patch), fields(project_id = %id))]
+#[instrument(level = "info", skip(window, state, patch), fields(project_id = %id))]
 #[tauri::command]
 pub async fn update_project(
+    window: tauri::Window,
     state: State<'_, AppState>,
     id: String,
     patch: PartialProject,
@@ -91,7 +97,7 @@ pub async fn update_project(
     let user_id_typed = UserId::from(user_id);
     let repositories = state.repositories.read().await;
     let project_id = ProjectId::from(id);
-    project_facades::update_project(&*repositories, &project_id, &patch, &user_id_typed)
+    undoable(&window, project_facades::update_project(&*repositories, &project_id, &patch, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::project", command = "update_project", project_id = %project_id, error = %e);
@@ -99,9 +105,10 @@ pub async fn update_project(
         })
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %id))]
 #[tauri::command]
 pub async fn delete_project(
+    window: tauri::Window,
     state: State<'_, AppState>,
     id: String,
     user_id: String,
@@ -110,7 +117,7 @@ pub async fn delete_project(
     let repositories = state.repositories.read().await;
     let project_id = ProjectId::from(id);
     let user_id_typed = UserId::from(user_id);
-    project_facades::delete_project(&*repositories, &project_id, &user_id_typed, &timestamp)
+    undoable(&window, project_facades::delete_project(&*repositories, &project_id, &user_id_typed, &timestamp))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::project", command = "delete_project", project_id = %project_id, error = %e);
@@ -118,9 +125,10 @@ pub async fn delete_project(
         })
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %id))]
 #[tauri::command]
 pub async fn restore_project(
+    window: tauri::Window,
     state: State<'_, AppState>,
     id: String,
     user_id: String,
@@ -129,7 +137,7 @@ pub async fn restore_project(
     let repositories = state.repositories.read().await;
     let project_id = ProjectId::from(id);
     let user_id_typed = UserId::from(user_id);
-    project_facades::restore_project(&*repositories, &project_id, &user_id_typed, &timestamp)
+    undoable(&window, project_facades::restore_project(&*repositories, &project_id, &user_id_typed, &timestamp))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::project", command = "restore_project", project_id = %project_id, error = %e);
@@ -0,0 +1,177 @@
+//! リマインダー関連のTauriコマンド
+
+use crate::commands::undo_commands::undoable;
+use crate::models::CommandModelConverter;
+use crate::models::reminder::ReminderCommandModel;
+use crate::state::AppState;
+use flequit_core::facades::reminder_facades;
+use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::reminder::{PartialReminder, Reminder};
+use flequit_model::types::id_types::{ProjectId, ReminderId, SubTaskId, TaskId, UserId};
+use tauri::State;
+use tracing::instrument;
+
+async fn to_command_models(reminders: Vec<Reminder>) -> Result<Vec<ReminderCommandModel>, String> {
+    let mut command_models = Vec::with_capacity(reminders.len());
+    for reminder in reminders {
+        command_models.push(reminder.to_command_model().await?);
+    }
+    Ok(command_models)
+}
+
+/// プロジェクトのリマインダーを作成日時順に取得します。
+///
+/// `task_id`を指定した場合はそのタスク（サブタスクへのものを含む）のリマインダーだけを、
+/// `subtask_id`を指定した場合はそのサブタスクのリマインダーだけを返します。
+#[instrument(level = "info", skip(state), fields(project_id = %project_id))]
+#[tauri::command]
+pub async fn list_reminders(
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: Option<String>,
+    subtask_id: Option<String>,
+) -> Result<Vec<ReminderCommandModel>, String> {
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id = match task_id {
+        Some(id) => Some(TaskId::try_from_str(&id).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let subtask_id = match subtask_id {
+        Some(id) => Some(SubTaskId::try_from_str(&id).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let repositories = state.repositories.read().await;
+
+    let reminders = reminder_facades::list_reminders(&*repositories, &project_id, task_id.as_ref(), subtask_id.as_ref())
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::reminder", command = "list_reminders", project_id = %project_id, error = %e);
+            e
+        })?;
+    to_command_models(reminders).await
+}
+
+/// タスク（`subtaskId`を指定した場合はサブタスク）にリマインダーを追加します。
+///
+/// `anchor`が`absolute`の場合は`remindAt`の日時に、`plan_start` / `plan_end`の場合は
+/// 予定開始日時・予定終了日時から`offsetMinutes`分ずらした日時に通知します。
+#[instrument(level = "info", skip(window, state, reminder), fields(project_id = %project_id, reminder_id = %reminder.id))]
+#[tauri::command]
+pub async fn create_reminder(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    reminder: ReminderCommandModel,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let internal_reminder = reminder.to_model().await?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, reminder_facades::create_reminder(&*repositories, &project_id, &internal_reminder, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::reminder", command = "create_reminder", project_id = %project_id, error = %e);
+            e
+        })
+}
+
+#[instrument(level = "info", skip(window, state, patch), fields(project_id = %project_id, reminder_id = %id))]
+#[tauri::command]
+pub async fn update_reminder(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+    patch: PartialReminder,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let reminder_id = ReminderId::try_from_str(&id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, reminder_facades::update_reminder(&*repositories, &project_id, &reminder_id, &patch, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::reminder", command = "update_reminder", project_id = %project_id, reminder_id = %reminder_id, error = %e);
+            e
+        })
+}
+
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, reminder_id = %id))]
+#[tauri::command]
+pub async fn delete_reminder(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let reminder_id = ReminderId::try_from_str(&id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, reminder_facades::delete_reminder(&*repositories, &project_id, &reminder_id, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::reminder", command = "delete_reminder", project_id = %project_id, reminder_id = %reminder_id, error = %e);
+            e
+        })
+}
+
+/// 繰り返しタスクの次の回を作成した後、前の回のリマインダーを次の回へ引き継ぎます。
+///
+/// 日時指定のリマインダーは予定日時が進んだ分だけ通知日時をずらします。
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, from_task_id = %from_task_id, to_task_id = %to_task_id))]
+#[tauri::command]
+pub async fn carry_over_task_reminders(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    from_task_id: String,
+    to_task_id: String,
+    user_id: String,
+) -> Result<Vec<ReminderCommandModel>, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let from_task_id = TaskId::try_from_str(&from_task_id).map_err(|e| e.to_string())?;
+    let to_task_id = TaskId::try_from_str(&to_task_id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    let reminders = undoable(&window, reminder_facades::carry_over_task_reminders(&*repositories, &project_id, &from_task_id, &to_task_id, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::reminder", command = "carry_over_task_reminders", project_id = %project_id, error = %e);
+            e
+        })?;
+    to_command_models(reminders).await
+}
+
+/// 繰り返しサブタスクの次の回を作成した後、前の回のリマインダーを次の回へ引き継ぎます。
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, from_subtask_id = %from_subtask_id, to_subtask_id = %to_subtask_id))]
+#[tauri::command]
+pub async fn carry_over_subtask_reminders(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    from_subtask_id: String,
+    to_subtask_id: String,
+    user_id: String,
+) -> Result<Vec<ReminderCommandModel>, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let from_subtask_id = SubTaskId::try_from_str(&from_subtask_id).map_err(|e| e.to_string())?;
+    let to_subtask_id = SubTaskId::try_from_str(&to_subtask_id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    let reminders = undoable(&window, reminder_facades::carry_over_subtask_reminders(&*repositories, &project_id, &from_subtask_id, &to_subtask_id, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::reminder", command = "carry_over_subtask_reminders", project_id = %project_id, error = %e);
+            e
+        })?;
+    to_command_models(reminders).await
+}
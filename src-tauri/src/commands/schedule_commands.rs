@@ -0,0 +1,36 @@
+//! スケジュール（クリティカルパス）計算関連のTauriコマンド
+
+use crate::models::schedule::ScheduleCommandModel;
+use crate::state::AppState;
+use flequit_core::facades::schedule_facades;
+use flequit_model::types::id_types::{ProjectId, TaskListId};
+use tauri::State;
+use tracing::instrument;
+
+/// プロジェクト（`task_list_id`を指定した場合はそのタスクリスト）のスケジュールを計算します。
+///
+/// 依存関係と予定日時から最早・最遅の開始／終了日時と余裕時間を求め、
+/// クリティカルパスと、予定開始日時が先行タスクの終了より前になっている食い違いを返します。
+#[instrument(level = "info", skip(state), fields(project_id = %project_id))]
+#[tauri::command]
+pub async fn compute_schedule(
+    state: State<'_, AppState>,
+    project_id: String,
+    task_list_id: Option<String>,
+) -> Result<ScheduleCommandModel, String> {
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_list_id = match task_list_id {
+        Some(id) => Some(TaskListId::try_from_str(&id).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let repositories = state.repositories.read().await;
+
+    let schedule =
+        schedule_facades::compute_schedule(&*repositories, &project_id, task_list_id.as_ref())
+            .await
+            .map_err(|e| {
+                tracing::error!(target: "commands::schedule", command = "compute_schedule", project_id = %project_id, error = %e);
+                e
+            })?;
+    Ok(schedule.into())
+}
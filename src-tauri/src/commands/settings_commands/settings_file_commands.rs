@@ -1,5 +1,5 @@
-use crate::models::settings::{PartialSettingsCommandModel, SettingsCommandModel};
 use crate::models::CommandModelConverter;
+use crate::models::settings::{PartialSettingsCommandModel, SettingsCommandModel};
 use crate::state::AppState;
 use flequit_model::models::ModelConverter;
 use flequit_settings::{PartialSettings, Settings};
@@ -23,11 +23,13 @@ pub async fn save_settings(
     state: State<'_, AppState>,
     settings: SettingsCommandModel,
 ) -> Result<(), String> {
-    let settings_model = settings.to_model().await?;
+    let mut settings_model = settings.to_model().await?;
 
-    // stateの設定を更新
+    // stateの設定を更新（カレンダーフィード・バックアップは画面の設定に含まれないため引き継ぐ）
     {
         let mut state_settings = state.settings.write().await;
+        settings_model.calendar_feed = state_settings.calendar_feed.clone();
+        settings_model.backup = state_settings.backup.clone();
         *state_settings = settings_model.clone();
     }
 
@@ -80,6 +82,9 @@ pub async fn update_settings_partially(
         let mut state_settings = state.settings.write().await;
         *state_settings = updated_settings.clone();
     }
+    if partial_model.backup.is_some() {
+        state.restart_backup_scheduler().await;
+    }
 
     // 更新された設定をコマンドモデルとして返す
     updated_settings
@@ -106,6 +111,7 @@ pub async fn initialize_settings_with_defaults(state: State<'_, AppState>) -> Re
         let mut state_settings = state.settings.write().await;
         *state_settings = Settings::default();
     }
+    state.restart_backup_scheduler().await;
 
     Ok(())
 }
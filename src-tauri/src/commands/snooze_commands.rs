@@ -0,0 +1,164 @@
+//! スヌーズ関連のTauriコマンド
+
+use crate::commands::undo_commands::undoable;
+use crate::models::CommandModelConverter;
+use crate::models::snooze::SnoozeCommandModel;
+use crate::state::AppState;
+use chrono::{Local, Utc};
+use flequit_core::facades::snooze_facades;
+use flequit_core::services::snooze_service::{SnoozeClock, SnoozeOption};
+use flequit_model::models::task_projects::snooze::Snooze;
+use flequit_model::types::id_types::{ProjectId, ReminderId, SubTaskId, TaskId, UserId};
+use tauri::State;
+use tracing::instrument;
+
+async fn to_command_models(snoozes: Vec<Snooze>) -> Result<Vec<SnoozeCommandModel>, String> {
+    let mut command_models = Vec::with_capacity(snoozes.len());
+    for snooze in snoozes {
+        command_models.push(snooze.to_command_model().await?);
+    }
+    Ok(command_models)
+}
+
+/// 現在時刻と端末のタイムゾーン、時間ラベルの設定から「明日の朝」を決める時計を作る
+async fn snooze_clock(state: &AppState) -> SnoozeClock {
+    let settings = state.settings.read().await;
+    SnoozeClock::new(Utc::now(), *Local::now().offset(), &settings.time_labels)
+}
+
+/// プロジェクトのスヌーズの記録を作成日時順に取得します。
+///
+/// `task_id`を指定した場合はそのタスク（サブタスク・リマインダーのものを含む）の記録だけを返します。
+#[instrument(level = "info", skip(state), fields(project_id = %project_id))]
+#[tauri::command]
+pub async fn list_snoozes(
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: Option<String>,
+) -> Result<Vec<SnoozeCommandModel>, String> {
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id = match task_id {
+        Some(id) => Some(TaskId::try_from_str(&id).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let repositories = state.repositories.read().await;
+
+    let snoozes = snooze_facades::list_snoozes(&*repositories, &project_id, task_id.as_ref())
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::snooze", command = "list_snoozes", project_id = %project_id, error = %e);
+            e
+        })?;
+    to_command_models(snoozes).await
+}
+
+/// タスクの予定日時を先送りし、先送り先の日時までスマートリストから隠します。
+///
+/// `option`は`{ kind: "preset", preset: "one_hour" }`、`{ kind: "custom", minutes: 90 }`、
+/// `{ kind: "tomorrow_morning" }`のいずれかです。「明日の朝」は時間ラベルのうち最も早い時刻です。
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, task_id = %task_id))]
+#[tauri::command]
+pub async fn snooze_task(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: String,
+    option: SnoozeOption,
+    user_id: String,
+) -> Result<SnoozeCommandModel, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id = TaskId::try_from_str(&task_id).map_err(|e| e.to_string())?;
+    let clock = snooze_clock(&state).await;
+    let repositories = state.repositories.read().await;
+
+    let snooze = undoable(&window, snooze_facades::snooze_task(&*repositories, &project_id, &task_id, &option, &clock, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::snooze", command = "snooze_task", project_id = %project_id, task_id = %task_id, error = %e);
+            e
+        })?;
+    snooze.to_command_model().await
+}
+
+/// サブタスクの予定日時を先送りし、先送り先の日時までスマートリストから隠します。
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, subtask_id = %subtask_id))]
+#[tauri::command]
+pub async fn snooze_subtask(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    subtask_id: String,
+    option: SnoozeOption,
+    user_id: String,
+) -> Result<SnoozeCommandModel, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let subtask_id = SubTaskId::try_from_str(&subtask_id).map_err(|e| e.to_string())?;
+    let clock = snooze_clock(&state).await;
+    let repositories = state.repositories.read().await;
+
+    let snooze = undoable(&window, snooze_facades::snooze_subtask(&*repositories, &project_id, &subtask_id, &option, &clock, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::snooze", command = "snooze_subtask", project_id = %project_id, subtask_id = %subtask_id, error = %e);
+            e
+        })?;
+    snooze.to_command_model().await
+}
+
+/// リマインダーの通知を見送り、先送り先の日時に改めて通知します。
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, reminder_id = %reminder_id))]
+#[tauri::command]
+pub async fn snooze_reminder(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    reminder_id: String,
+    option: SnoozeOption,
+    user_id: String,
+) -> Result<SnoozeCommandModel, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let reminder_id = ReminderId::try_from_str(&reminder_id).map_err(|e| e.to_string())?;
+    let clock = snooze_clock(&state).await;
+    let repositories = state.repositories.read().await;
+
+    let snooze = undoable(&window, snooze_facades::snooze_reminder(&*repositories, &project_id, &reminder_id, &option, &clock, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::snooze", command = "snooze_reminder", project_id = %project_id, reminder_id = %reminder_id, error = %e);
+            e
+        })?;
+    snooze.to_command_model().await
+}
+
+/// タスク（`subtaskId`を指定した場合はサブタスク）の先送りを取りやめ、すぐにスマートリストへ戻します。
+///
+/// ずらした予定日時は戻しません。先送り中でない場合は`false`を返します。
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, task_id = %task_id))]
+#[tauri::command]
+pub async fn cancel_deferral(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: String,
+    subtask_id: Option<String>,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id = TaskId::try_from_str(&task_id).map_err(|e| e.to_string())?;
+    let subtask_id = match subtask_id {
+        Some(id) => Some(SubTaskId::try_from_str(&id).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, snooze_facades::cancel_deferral(&*repositories, &project_id, &task_id, subtask_id.as_ref(), &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::snooze", command = "cancel_deferral", project_id = %project_id, task_id = %task_id, error = %e);
+            e
+        })
+}
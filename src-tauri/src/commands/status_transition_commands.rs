@@ -0,0 +1,103 @@
+//! ステータス遷移ルール（プロジェクトの遷移ポリシー）関連のTauriコマンド
+
+use crate::commands::undo_commands::undoable;
+use crate::models::CommandModelConverter;
+use crate::models::status_transition_rule::StatusTransitionRuleCommandModel;
+use crate::state::AppState;
+use flequit_core::facades::status_transition_facades;
+use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::status_transition_rule::PartialStatusTransitionRule;
+use flequit_model::types::id_types::{ProjectId, StatusTransitionRuleId, UserId};
+use tauri::State;
+use tracing::instrument;
+
+/// プロジェクトのステータス遷移ルールを作成順に取得します。
+#[instrument(level = "info", skip(state), fields(project_id = %project_id))]
+#[tauri::command]
+pub async fn list_status_transition_rules(
+    state: State<'_, AppState>,
+    project_id: String,
+) -> Result<Vec<StatusTransitionRuleCommandModel>, String> {
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    let rules = status_transition_facades::list_status_transition_rules(&*repositories, &project_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::status_transition", command = "list_status_transition_rules", project_id = %project_id, error = %e);
+            e
+        })?;
+    let mut command_models = Vec::with_capacity(rules.len());
+    for rule in rules {
+        command_models.push(rule.to_command_model().await?);
+    }
+    Ok(command_models)
+}
+
+/// ステータス遷移ルールを作成します。同じ遷移に対するルールは1つまでです。
+#[instrument(level = "info", skip(window, state, rule), fields(project_id = %project_id, rule_id = %rule.id))]
+#[tauri::command]
+pub async fn create_status_transition_rule(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    rule: StatusTransitionRuleCommandModel,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let internal_rule = rule.to_model().await?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, status_transition_facades::create_status_transition_rule(&*repositories, &project_id, &internal_rule, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::status_transition", command = "create_status_transition_rule", project_id = %project_id, error = %e);
+            e
+        })
+}
+
+#[instrument(level = "info", skip(window, state, patch), fields(project_id = %project_id, rule_id = %id))]
+#[tauri::command]
+pub async fn update_status_transition_rule(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+    patch: PartialStatusTransitionRule,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let rule_id = StatusTransitionRuleId::try_from_str(&id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, status_transition_facades::update_status_transition_rule(&*repositories, &project_id, &rule_id, &patch, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::status_transition", command = "update_status_transition_rule", project_id = %project_id, rule_id = %rule_id, error = %e);
+            e
+        })
+}
+
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, rule_id = %id))]
+#[tauri::command]
+pub async fn delete_status_transition_rule(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let rule_id = StatusTransitionRuleId::try_from_str(&id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, status_transition_facades::delete_status_transition_rule(&*repositories, &project_id, &rule_id, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::status_transition", command = "delete_status_transition_rule", project_id = %project_id, rule_id = %rule_id, error = %e);
+            e
+        })
+}
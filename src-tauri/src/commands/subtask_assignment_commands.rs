@@ -1,3 +1,4 @@
+use crate::commands::undo_commands::undoable;
 use crate::models::subtask_assignment::SubtaskAssignmentCommandModel;
 use crate::state::AppState;
 use flequit_core::facades::subtask_assignment_facades as facades;
@@ -5,9 +6,10 @@ use flequit_model::types::id_types::{ProjectId, SubTaskId, UserId};
 use tauri::State;
 use tracing::instrument;
 
-#[instrument(level = "info", skip(state, subtask_assignment), fields(project_id = %project_id))]
+#[instrument(level = "info", skip(window, state, subtask_assignment), fields(project_id = %project_id))]
 #[tauri::command]
 pub async fn create_subtask_assignment(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     subtask_assignment: SubtaskAssignmentCommandModel,
@@ -21,7 +23,7 @@ pub async fn create_subtask_assignment(
     let subtask_id = SubTaskId::from(subtask_assignment.subtask_id);
     let assigned_user_id = UserId::from(subtask_assignment.user_id);
     let repositories = state.repositories.read().await;
-    facades::add(&*repositories, &project_id_typed, &subtask_id, &assigned_user_id, &user_id_typed)
+    undoable(&window, facades::add(&*repositories, &project_id_typed, &subtask_id, &assigned_user_id, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::subtask_assignment", command = "create_subtask_assignment", project_id = %project_id_typed, subtask_id = %subtask_id, user_id = %assigned_user_id, error = %e);
@@ -29,9 +31,10 @@ pub async fn create_subtask_assignment(
         })
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, subtask_id = %subtask_id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, subtask_id = %subtask_id))]
 #[tauri::command]
 pub async fn delete_subtask_assignment(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     subtask_id: String,
@@ -44,7 +47,7 @@ pub async fn delete_subtask_assignment(
     let subtask_id_typed = SubTaskId::from(subtask_id);
     let user_id_typed = UserId::from(user_id);
     let repositories = state.repositories.read().await;
-    facades::remove(&*repositories, &project_id_typed, &subtask_id_typed, &user_id_typed)
+    undoable(&window, facades::remove(&*repositories, &project_id_typed, &subtask_id_typed, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::subtask_assignment", command = "delete_subtask_assignment", project_id = %project_id_typed, subtask_id = %subtask_id_typed, user_id = %user_id_typed, error = %e);
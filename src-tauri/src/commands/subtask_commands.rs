@@ -1,21 +1,24 @@
+use crate::commands::undo_commands::undoable;
 use crate::models::{
+    CommandModelConverter, subtask::SubtaskCommandModel,
+    subtask_recurrence::SubtaskRecurrenceCommandModel,
     subtask_search_request::SubTaskSearchRequest,
-    subtask::SubtaskCommandModel, subtask_recurrence::SubtaskRecurrenceCommandModel,
-    CommandModelConverter,
 };
 use crate::state::AppState;
 use flequit_core::facades::{recurrence_facades, subtask_facades};
 use flequit_core::services::subtask_service::SubtaskSearchCondition;
-use flequit_model::models::{task_projects::subtask::PartialSubTask, ModelConverter};
+use flequit_model::models::{ModelConverter, task_projects::subtask::PartialSubTask};
 use flequit_model::types::id_types::{ProjectId, RecurrenceRuleId, SubTaskId, UserId};
+use flequit_model::types::task_types::TaskStatus;
 use tauri::State;
 use tracing::instrument;
 
 // Frontend compatibility aliases only
 
-#[instrument(level = "info", skip(state, sub_task), fields(project_id = %project_id, subtask_id = %sub_task.id))]
+#[instrument(level = "info", skip(window, state, sub_task), fields(project_id = %project_id, subtask_id = %sub_task.id))]
 #[tauri::command]
 pub async fn create_sub_task(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     sub_task: SubtaskCommandModel,
@@ -29,7 +32,7 @@ pub async fn create_sub_task(
     let subtask_param = sub_task.to_model().await?;
     let repositories = state.repositories.read().await;
 
-    subtask_facades::create_sub_task(&*repositories, &project_id, &subtask_param, &user_id_typed)
+    undoable(&window, subtask_facades::create_sub_task(&*repositories, &project_id, &subtask_param, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::subtask", command = "create_sub_task", project_id = %project_id, error = %e);
@@ -81,6 +84,7 @@ pub async fn search_sub_tasks(
         title: condition.title,
         status: condition.status,
         priority: condition.priority,
+        is_deferred: condition.is_deferred,
         limit: condition.limit,
         offset: condition.offset,
     };
@@ -104,9 +108,10 @@ pub async fn search_sub_tasks(
     Ok(result)
 }
 
-#[instrument(level = "info", skip(state, patch), fields(project_id = %project_id, subtask_id = %id))]
+#[instrument(level = "info", skip(window, state, patch), fields(project_id = %project_id, subtask_id = %id))]
 #[tauri::command]
 pub async fn update_sub_task(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     id: String,
@@ -124,7 +129,7 @@ pub async fn update_sub_task(
     };
     let repositories = state.repositories.read().await;
 
-    subtask_facades::update_sub_task(&*repositories, &project_id, &subtask_id, &patch, &user_id_typed)
+    undoable(&window, subtask_facades::update_sub_task(&*repositories, &project_id, &subtask_id, &patch, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::subtask", command = "update_sub_task", project_id = %project_id, subtask_id = %subtask_id, error = %e);
@@ -132,9 +137,44 @@ pub async fn update_sub_task(
         })
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, subtask_id = %id))]
+/// サブタスクのステータスを変更します。
+///
+/// プロジェクトのステータス遷移ルールで理由が必須の遷移は`reason`を指定します。
+/// 作業開始日時・作業終了日時と完了フラグはステータスに合わせて自動で更新されます。
+#[instrument(level = "info", skip(window, state, reason), fields(project_id = %project_id, subtask_id = %id))]
+#[tauri::command]
+pub async fn update_sub_task_status(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+    status: TaskStatus,
+    reason: Option<String>,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = match ProjectId::try_from_str(&project_id) {
+        Ok(id) => id,
+        Err(err) => return Err(err.to_string()),
+    };
+    let subtask_id = match SubTaskId::try_from_str(&id) {
+        Ok(id) => id,
+        Err(err) => return Err(err.to_string()),
+    };
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, subtask_facades::update_sub_task_status(&*repositories, &project_id, &subtask_id, &status, reason.as_deref(), &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::subtask", command = "update_sub_task_status", project_id = %project_id, subtask_id = %subtask_id, error = %e);
+            e
+        })
+}
+
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, subtask_id = %id))]
 #[tauri::command]
 pub async fn delete_sub_task(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     id: String,
@@ -149,7 +189,7 @@ pub async fn delete_sub_task(
     };
     let repositories = state.repositories.read().await;
 
-    subtask_facades::delete_sub_task(&*repositories, &project_id, &subtask_id)
+    undoable(&window, subtask_facades::delete_sub_task(&*repositories, &project_id, &subtask_id))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::subtask", command = "delete_sub_task", project_id = %project_id, subtask_id = %subtask_id, error = %e);
@@ -162,9 +202,10 @@ pub async fn delete_sub_task(
 // =============================================================================
 
 /// サブタスクに繰り返しルールを関連付けます。
-#[instrument(level = "info", skip(state, subtask_recurrence), fields(project_id = %project_id, subtask_id = %subtask_recurrence.subtask_id, recurrence_rule_id = %subtask_recurrence.recurrence_rule_id))]
+#[instrument(level = "info", skip(window, state, subtask_recurrence), fields(project_id = %project_id, subtask_id = %subtask_recurrence.subtask_id, recurrence_rule_id = %subtask_recurrence.recurrence_rule_id))]
 #[tauri::command]
 pub async fn create_subtask_recurrence(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     subtask_recurrence: SubtaskRecurrenceCommandModel,
@@ -177,12 +218,12 @@ pub async fn create_subtask_recurrence(
     let subtask_id = SubTaskId::from(subtask_recurrence.subtask_id);
     let recurrence_rule_id = RecurrenceRuleId::from(subtask_recurrence.recurrence_rule_id);
 
-    recurrence_facades::create_subtask_recurrence(
+    undoable(&window, recurrence_facades::create_subtask_recurrence(
         &*repositories,
         &project_id,
         &subtask_id,
         &recurrence_rule_id,
-    )
+    ))
     .await
     .map_err(|e| {
         tracing::error!(target: "commands::subtask", command = "create_subtask_recurrence", project_id = %project_id, subtask_id = %subtask_id, recurrence_rule_id = %recurrence_rule_id, error = %e);
@@ -219,9 +260,10 @@ pub async fn get_subtask_recurrence_by_subtask_id(
 }
 
 /// サブタスクの繰り返し関連付けを削除します。
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, subtask_id = %subtask_id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, subtask_id = %subtask_id))]
 #[tauri::command]
 pub async fn delete_subtask_recurrence(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     subtask_id: String,
@@ -233,7 +275,7 @@ pub async fn delete_subtask_recurrence(
     };
     let subtask_id_typed = SubTaskId::from(subtask_id);
 
-    recurrence_facades::delete_subtask_recurrence(&*repositories, &project_id, &subtask_id_typed)
+    undoable(&window, recurrence_facades::delete_subtask_recurrence(&*repositories, &project_id, &subtask_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::subtask", command = "delete_subtask_recurrence", project_id = %project_id, subtask_id = %subtask_id_typed, error = %e);
@@ -1,18 +1,20 @@
+use crate::commands::undo_commands::undoable;
+use crate::models::CommandModelConverter;
 use crate::models::tag::TagCommandModel;
 use crate::models::tag_search_request::TagSearchRequest;
-use crate::models::CommandModelConverter;
 use crate::state::AppState;
 use chrono::Utc;
 use flequit_core::facades::tag_facades;
-use flequit_model::models::task_projects::tag::PartialTag;
 use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::tag::PartialTag;
 use flequit_model::types::id_types::{ProjectId, TagId, UserId};
 use tauri::State;
 use tracing::instrument;
 
-#[instrument(level = "info", skip(state, tag), fields(project_id = %project_id, tag_id = %tag.id))]
+#[instrument(level = "info", skip(window, state, tag), fields(project_id = %project_id, tag_id = %tag.id))]
 #[tauri::command]
 pub async fn create_tag(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     tag: TagCommandModel,
@@ -26,7 +28,7 @@ pub async fn create_tag(
     let internal_tag = tag.to_model().await?;
     let repositories = state.repositories.read().await;
 
-    tag_facades::create_tag(&*repositories, &project_id, &internal_tag, &user_id_typed)
+    undoable(&window, tag_facades::create_tag(&*repositories, &project_id, &internal_tag, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::tag", command = "create_tag", project_id = %project_id, error = %e);
@@ -63,9 +65,10 @@ pub async fn get_tag(
     }
 }
 
-#[instrument(level = "info", skip(state, patch), fields(project_id = %project_id, tag_id = %tag_id))]
+#[instrument(level = "info", skip(window, state, patch), fields(project_id = %project_id, tag_id = %tag_id))]
 #[tauri::command]
 pub async fn update_tag(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     tag_id: String,
@@ -83,7 +86,7 @@ pub async fn update_tag(
     };
     let repositories = state.repositories.read().await;
 
-    tag_facades::update_tag(&*repositories, &project_id, &tag_id, &patch, &user_id_typed)
+    undoable(&window, tag_facades::update_tag(&*repositories, &project_id, &tag_id, &patch, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::tag", command = "update_tag", project_id = %project_id, tag_id = %tag_id, error = %e);
@@ -91,9 +94,10 @@ pub async fn update_tag(
         })
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, tag_id = %id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, tag_id = %id))]
 #[tauri::command]
 pub async fn delete_tag(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     id: String,
@@ -111,7 +115,7 @@ pub async fn delete_tag(
     };
     let repositories = state.repositories.read().await;
 
-    tag_facades::delete_tag(&*repositories, &project_id, &tag_id, &user_id_typed, &timestamp)
+    undoable(&window, tag_facades::delete_tag(&*repositories, &project_id, &tag_id, &user_id_typed, &timestamp))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::tag", command = "delete_tag", project_id = %project_id, tag_id = %tag_id, error = %e);
@@ -119,9 +123,10 @@ pub async fn delete_tag(
         })
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, tag_id = %id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, tag_id = %id))]
 #[tauri::command]
 pub async fn restore_tag(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     id: String,
@@ -139,7 +144,7 @@ pub async fn restore_tag(
     };
     let repositories = state.repositories.read().await;
 
-    tag_facades::restore_tag(&*repositories, &project_id, &tag_id, &user_id_typed, &timestamp)
+    undoable(&window, tag_facades::restore_tag(&*repositories, &project_id, &tag_id, &user_id_typed, &timestamp))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::tag", command = "restore_tag", project_id = %project_id, tag_id = %tag_id, error = %e);
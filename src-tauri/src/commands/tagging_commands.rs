@@ -1,5 +1,6 @@
-use crate::models::tag::TagCommandModel;
+use crate::commands::undo_commands::undoable;
 use crate::models::CommandModelConverter;
+use crate::models::tag::TagCommandModel;
 use crate::state::AppState;
 use flequit_core::facades::{subtask_facades, task_facades};
 use flequit_model::types::id_types::{ProjectId, SubTaskId, TagId, TaskId, UserId};
@@ -8,9 +9,10 @@ use tracing::instrument;
 
 // TaskTag関連コマンド（CRUD）
 
-#[instrument(level = "info", skip(state, tag_name), fields(project_id = %project_id, task_id = %task_id))]
+#[instrument(level = "info", skip(window, state, tag_name), fields(project_id = %project_id, task_id = %task_id))]
 #[tauri::command]
 pub async fn create_task_tag(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     task_id: String,
@@ -25,12 +27,15 @@ pub async fn create_task_tag(
     let task_id = TaskId::from(task_id);
     let repositories = state.repositories.read().await;
 
-    let result = task_facades::add_task_tag(
-        &*repositories,
-        &project_id,
-        &task_id,
-        &tag_name,
-        &user_id_typed,
+    let result = undoable(
+        &window,
+        task_facades::add_task_tag(
+            &*repositories,
+            &project_id,
+            &task_id,
+            &tag_name,
+            &user_id_typed,
+        ),
     )
     .await;
     if let Err(e) = result {
@@ -40,9 +45,10 @@ pub async fn create_task_tag(
     result.unwrap().to_command_model().await
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, task_id = %task_id, tag_id = %tag_id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, task_id = %task_id, tag_id = %tag_id))]
 #[tauri::command]
 pub async fn delete_task_tag(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     task_id: String,
@@ -56,7 +62,7 @@ pub async fn delete_task_tag(
     let tag_id_typed = TagId::from(tag_id);
     let repositories = state.repositories.read().await;
 
-    task_facades::remove_task_tag_relation(&*repositories, &project_id, &task_id_typed, &tag_id_typed)
+    undoable(&window, task_facades::remove_task_tag_relation(&*repositories, &project_id, &task_id_typed, &tag_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::tagging", command = "delete_task_tag", project_id = %project_id, task_id = %task_id_typed, tag_id = %tag_id_typed, error = %e);
@@ -66,9 +72,10 @@ pub async fn delete_task_tag(
 
 // SubtaskTag関連コマンド（CRUD）
 
-#[instrument(level = "info", skip(state, tag_name), fields(project_id = %project_id, task_id = %task_id))]
+#[instrument(level = "info", skip(window, state, tag_name), fields(project_id = %project_id, task_id = %task_id))]
 #[tauri::command]
 pub async fn create_task_tag_by_name(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     task_id: String,
@@ -82,12 +89,15 @@ pub async fn create_task_tag_by_name(
     };
     let task_id = TaskId::from(task_id);
     let repositories = state.repositories.read().await;
-    let result = task_facades::add_task_tag(
-        &*repositories,
-        &project_id,
-        &task_id,
-        &tag_name,
-        &user_id_typed,
+    let result = undoable(
+        &window,
+        task_facades::add_task_tag(
+            &*repositories,
+            &project_id,
+            &task_id,
+            &tag_name,
+            &user_id_typed,
+        ),
     )
     .await;
     if let Err(e) = result {
@@ -97,9 +107,10 @@ pub async fn create_task_tag_by_name(
     result.unwrap().to_command_model().await
 }
 
-#[instrument(level = "info", skip(state, tag_name), fields(project_id = %project_id, subtask_id = %subtask_id))]
+#[instrument(level = "info", skip(window, state, tag_name), fields(project_id = %project_id, subtask_id = %subtask_id))]
 #[tauri::command]
 pub async fn create_subtask_tag_by_name(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     subtask_id: String,
@@ -113,12 +124,15 @@ pub async fn create_subtask_tag_by_name(
     };
     let subtask_id = SubTaskId::from(subtask_id);
     let repositories = state.repositories.read().await;
-    let result = subtask_facades::add_subtask_tag(
-        &*repositories,
-        &project_id,
-        &subtask_id,
-        &tag_name,
-        &user_id_typed,
+    let result = undoable(
+        &window,
+        subtask_facades::add_subtask_tag(
+            &*repositories,
+            &project_id,
+            &subtask_id,
+            &tag_name,
+            &user_id_typed,
+        ),
     )
     .await;
     if let Err(e) = result {
@@ -130,9 +144,10 @@ pub async fn create_subtask_tag_by_name(
 
 // 名前指定の削除は行わず、削除はID厳格指定の既存コマンドを使用する
 
-#[instrument(level = "info", skip(state, tag_name), fields(project_id = %project_id, subtask_id = %subtask_id))]
+#[instrument(level = "info", skip(window, state, tag_name), fields(project_id = %project_id, subtask_id = %subtask_id))]
 #[tauri::command]
 pub async fn create_subtask_tag(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     subtask_id: String,
@@ -147,12 +162,15 @@ pub async fn create_subtask_tag(
     let subtask_id = SubTaskId::from(subtask_id);
     let repositories = state.repositories.read().await;
 
-    let result = subtask_facades::add_subtask_tag(
-        &*repositories,
-        &project_id,
-        &subtask_id,
-        &tag_name,
-        &user_id_typed,
+    let result = undoable(
+        &window,
+        subtask_facades::add_subtask_tag(
+            &*repositories,
+            &project_id,
+            &subtask_id,
+            &tag_name,
+            &user_id_typed,
+        ),
     )
     .await;
     if let Err(e) = result {
@@ -162,9 +180,10 @@ pub async fn create_subtask_tag(
     result.unwrap().to_command_model().await
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, subtask_id = %subtask_id, tag_id = %tag_id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, subtask_id = %subtask_id, tag_id = %tag_id))]
 #[tauri::command]
 pub async fn delete_subtask_tag(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     subtask_id: String,
@@ -178,7 +197,7 @@ pub async fn delete_subtask_tag(
     let tag_id_typed = TagId::from(tag_id);
     let repositories = state.repositories.read().await;
 
-    subtask_facades::remove_subtask_tag_relation(&*repositories, &project_id, &subtask_id_typed, &tag_id_typed)
+    undoable(&window, subtask_facades::remove_subtask_tag_relation(&*repositories, &project_id, &subtask_id_typed, &tag_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::tagging", command = "delete_subtask_tag", project_id = %project_id, subtask_id = %subtask_id_typed, tag_id = %tag_id_typed, error = %e);
@@ -1,3 +1,4 @@
+use crate::commands::undo_commands::undoable;
 use crate::models::task_assignment::TaskAssignmentCommandModel;
 use crate::state::AppState;
 use flequit_core::facades::task_assignment_facades as facades;
@@ -5,9 +6,10 @@ use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
 use tauri::State;
 use tracing::instrument;
 
-#[instrument(level = "info", skip(state, task_assignment), fields(project_id = %project_id))]
+#[instrument(level = "info", skip(window, state, task_assignment), fields(project_id = %project_id))]
 #[tauri::command]
 pub async fn create_task_assignment(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     task_assignment: TaskAssignmentCommandModel,
@@ -21,7 +23,7 @@ pub async fn create_task_assignment(
     let task_id = TaskId::from(task_assignment.task_id);
     let assigned_user_id = UserId::from(task_assignment.user_id);
     let repositories = state.repositories.read().await;
-    facades::add(&*repositories, &project_id, &task_id, &assigned_user_id, &user_id_typed)
+    undoable(&window, facades::add(&*repositories, &project_id, &task_id, &assigned_user_id, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task_assignment", command = "create_task_assignment", project_id = %project_id, task_id = %task_id, user_id = %assigned_user_id, error = %e);
@@ -29,9 +31,10 @@ pub async fn create_task_assignment(
         })
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, task_id = %task_id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, task_id = %task_id))]
 #[tauri::command]
 pub async fn delete_task_assignment(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     task_id: String,
@@ -44,7 +47,7 @@ pub async fn delete_task_assignment(
     let task_id_typed = TaskId::from(task_id);
     let user_id_typed = UserId::from(user_id);
     let repositories = state.repositories.read().await;
-    facades::remove(&*repositories, &project_id_typed, &task_id_typed, &user_id_typed)
+    undoable(&window, facades::remove(&*repositories, &project_id_typed, &task_id_typed, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task_assignment", command = "delete_task_assignment", project_id = %project_id_typed, task_id = %task_id_typed, user_id = %user_id_typed, error = %e);
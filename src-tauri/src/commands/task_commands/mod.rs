@@ -15,7 +15,10 @@ pub use recurrence::{
     get_recurrence_adjustments_by_rule_id, get_recurrence_details_by_rule_id, get_recurrence_rule,
     get_task_recurrence_by_task_id, update_recurrence_details, update_recurrence_rule,
 };
-pub use write::{create_task, delete_task, restore_task, update_task};
+pub use write::{
+    create_task, delete_task, move_task_to_workflow_status, restore_task, update_task,
+    update_task_status,
+};
 
 // Tauri generate_handler! 用の補助シンボルの再エクスポート
 pub use read::{__cmd__get_task, __cmd__search_tasks};
@@ -28,14 +31,16 @@ pub use recurrence::{
     __cmd__get_recurrence_rule, __cmd__get_task_recurrence_by_task_id,
     __cmd__update_recurrence_details, __cmd__update_recurrence_rule,
 };
-pub use write::{__cmd__create_task, __cmd__delete_task, __cmd__restore_task, __cmd__update_task};
+pub use write::{
+    __cmd__create_task, __cmd__delete_task, __cmd__move_task_to_workflow_status,
+    __cmd__restore_task, __cmd__update_task, __cmd__update_task_status,
+};
 
 pub use read::{__tauri_command_name_get_task, __tauri_command_name_search_tasks};
 pub use recurrence::{
     __tauri_command_name_create_recurrence_adjustment,
     __tauri_command_name_create_recurrence_details, __tauri_command_name_create_recurrence_rule,
-    __tauri_command_name_create_task_recurrence,
-    __tauri_command_name_delete_recurrence_adjustment,
+    __tauri_command_name_create_task_recurrence, __tauri_command_name_delete_recurrence_adjustment,
     __tauri_command_name_delete_recurrence_details, __tauri_command_name_delete_recurrence_rule,
     __tauri_command_name_delete_task_recurrence, __tauri_command_name_get_all_recurrence_rules,
     __tauri_command_name_get_recurrence_adjustments_by_rule_id,
@@ -45,5 +50,6 @@ pub use recurrence::{
 };
 pub use write::{
     __tauri_command_name_create_task, __tauri_command_name_delete_task,
-    __tauri_command_name_restore_task, __tauri_command_name_update_task,
+    __tauri_command_name_move_task_to_workflow_status, __tauri_command_name_restore_task,
+    __tauri_command_name_update_task, __tauri_command_name_update_task_status,
 };
@@ -2,12 +2,13 @@
 //!
 //! タスクの繰り返しルール・関連付け・調整・詳細の管理コマンドを提供する
 
+use crate::commands::undo_commands::undoable;
 use crate::models::{
+    CommandModelConverter,
     recurrence_adjustment::RecurrenceAdjustmentCommandModel,
     recurrence_details::RecurrenceDetailsCommandModel,
     recurrence_rule::{PartialRecurrenceRuleCommandModel, RecurrenceRuleCommandModel},
     task_recurrence::TaskRecurrenceCommandModel,
-    CommandModelConverter,
 };
 use crate::state::AppState;
 use flequit_core::facades::recurrence_facades;
@@ -21,9 +22,10 @@ use tracing::instrument;
 // =============================================================================
 
 /// タスクに繰り返しルールを関連付けます。
-#[instrument(level = "info", skip(state, task_recurrence), fields(project_id = %project_id, task_id = %task_recurrence.task_id, recurrence_rule_id = %task_recurrence.recurrence_rule_id))]
+#[instrument(level = "info", skip(window, state, task_recurrence), fields(project_id = %project_id, task_id = %task_recurrence.task_id, recurrence_rule_id = %task_recurrence.recurrence_rule_id))]
 #[tauri::command]
 pub async fn create_task_recurrence(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     task_recurrence: TaskRecurrenceCommandModel,
@@ -35,12 +37,12 @@ pub async fn create_task_recurrence(
     let task_id = TaskId::from(task_recurrence.task_id);
     let repositories = state.repositories.read().await;
     let recurrence_rule_id = RecurrenceRuleId::from(task_recurrence.recurrence_rule_id);
-    recurrence_facades::create_task_recurrence(
+    undoable(&window, recurrence_facades::create_task_recurrence(
         &*repositories,
         &project_id,
         &task_id,
         &recurrence_rule_id,
-    )
+    ))
     .await
     .map_err(|e| {
         tracing::error!(target: "commands::task", command = "create_task_recurrence", project_id = %project_id, task_id = %task_id, recurrence_rule_id = %recurrence_rule_id, error = %e);
@@ -76,9 +78,10 @@ pub async fn get_task_recurrence_by_task_id(
 }
 
 /// タスクの繰り返し関連付けを削除します。
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, task_id = %task_id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, task_id = %task_id))]
 #[tauri::command]
 pub async fn delete_task_recurrence(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     task_id: String,
@@ -89,7 +92,7 @@ pub async fn delete_task_recurrence(
         Err(err) => return Err(err.to_string()),
     };
     let task_id_typed = TaskId::from(task_id);
-    recurrence_facades::delete_task_recurrence(&*repositories, &project_id, &task_id_typed)
+    undoable(&window, recurrence_facades::delete_task_recurrence(&*repositories, &project_id, &task_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task", command = "delete_task_recurrence", project_id = %project_id, task_id = %task_id_typed, error = %e);
@@ -102,9 +105,10 @@ pub async fn delete_task_recurrence(
 // =============================================================================
 
 /// 繰り返しルールを作成します。
-#[instrument(level = "info", skip(state, rule), fields(project_id = %project_id, rule_id = %rule.id))]
+#[instrument(level = "info", skip(window, state, rule), fields(project_id = %project_id, rule_id = %rule.id))]
 #[tauri::command]
 pub async fn create_recurrence_rule(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     rule: RecurrenceRuleCommandModel,
@@ -117,7 +121,7 @@ pub async fn create_recurrence_rule(
     };
     let internal_rule = rule.to_model().await?;
     let repositories = state.repositories.read().await;
-    recurrence_facades::create_recurrence_rule(&*repositories, &project_id, internal_rule, &user_id_typed)
+    undoable(&window, recurrence_facades::create_recurrence_rule(&*repositories, &project_id, internal_rule, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task", command = "create_recurrence_rule", project_id = %project_id, error = %e);
@@ -176,9 +180,10 @@ pub async fn get_all_recurrence_rules(
 }
 
 /// 繰り返しルールを更新します。
-#[instrument(level = "info", skip(state, patch), fields(project_id = %project_id, rule_id = ?patch.id))]
+#[instrument(level = "info", skip(window, state, patch), fields(project_id = %project_id, rule_id = ?patch.id))]
 #[tauri::command]
 pub async fn update_recurrence_rule(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     patch: PartialRecurrenceRuleCommandModel,
@@ -198,7 +203,7 @@ pub async fn update_recurrence_rule(
 
     let internal_patch = patch.to_model().await?;
     let repositories = state.repositories.read().await;
-    recurrence_facades::update_recurrence_rule(&*repositories, &project_id, &rule_id, &internal_patch, &user_id_typed)
+    undoable(&window, recurrence_facades::update_recurrence_rule(&*repositories, &project_id, &rule_id, &internal_patch, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task", command = "update_recurrence_rule", project_id = %project_id, rule_id = %rule_id, error = %e);
@@ -207,9 +212,10 @@ pub async fn update_recurrence_rule(
 }
 
 /// 繰り返しルールを削除します。
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, rule_id = %rule_id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, rule_id = %rule_id))]
 #[tauri::command]
 pub async fn delete_recurrence_rule(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     rule_id: String,
@@ -219,7 +225,7 @@ pub async fn delete_recurrence_rule(
         Ok(id) => id,
         Err(err) => return Err(err.to_string()),
     };
-    recurrence_facades::delete_recurrence_rule(&*repositories, &project_id, rule_id)
+    undoable(&window, recurrence_facades::delete_recurrence_rule(&*repositories, &project_id, rule_id))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task", command = "delete_recurrence_rule", project_id = %project_id, error = %e);
@@ -230,6 +236,8 @@ pub async fn delete_recurrence_rule(
 // =============================================================================
 // 繰り返し調整関連コマンド
 // =============================================================================
+// 調整は繰り返しルールの`adjustment`として保存され、その変更は`update_recurrence_rule`の
+// 取り消し履歴に記録される。これらのコマンドは何も保存しないため`undoable`で包まない。
 
 /// 繰り返し調整を作成します。
 #[instrument(level = "info", skip(state, adjustment), fields(project_id = %project_id))]
@@ -304,6 +312,8 @@ pub async fn delete_recurrence_adjustment(
 // =============================================================================
 // 繰り返し詳細関連コマンド
 // =============================================================================
+// 詳細は繰り返しルールの`details`として保存され、その変更は`update_recurrence_rule`の
+// 取り消し履歴に記録される。これらのコマンドは何も保存しないため`undoable`で包まない。
 
 /// 繰り返し詳細を作成します。
 #[instrument(level = "info", skip(state, details), fields(project_id = %project_id))]
@@ -2,19 +2,22 @@
 //!
 //! タスクの作成・更新・削除・復元コマンドを提供する
 
+use crate::commands::undo_commands::undoable;
 use crate::models::task::TaskCommandModel;
 use crate::state::AppState;
 use chrono::Utc;
 use flequit_core::facades::task_facades;
-use flequit_model::models::task_projects::task::PartialTask;
 use flequit_model::models::ModelConverter;
-use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
+use flequit_model::models::task_projects::task::PartialTask;
+use flequit_model::types::id_types::{ProjectId, TaskId, UserId, WorkflowStatusId};
+use flequit_model::types::task_types::TaskStatus;
 use tauri::State;
 use tracing::instrument;
 
-#[instrument(level = "info", skip(state, task), fields(project_id = %task.project_id, task_id = %task.id))]
+#[instrument(level = "info", skip(window, state, task), fields(project_id = %task.project_id, task_id = %task.id))]
 #[tauri::command]
 pub async fn create_task(
+    window: tauri::Window,
     state: State<'_, AppState>,
     task: TaskCommandModel,
     user_id: String,
@@ -26,7 +29,7 @@ pub async fn create_task(
     };
     let internal_task = task.to_model().await?;
     let repositories = state.repositories.read().await;
-    task_facades::create_task(&*repositories, &project_id, &internal_task, &user_id_typed)
+    undoable(&window, task_facades::create_task(&*repositories, &project_id, &internal_task, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task", command = "create_task", project_id = %project_id, error = %e);
@@ -34,9 +37,10 @@ pub async fn create_task(
         })
 }
 
-#[instrument(level = "info", skip(state, patch), fields(project_id = %project_id, task_id = ?patch.id))]
+#[instrument(level = "info", skip(window, state, patch), fields(project_id = %project_id, task_id = ?patch.id))]
 #[tauri::command]
 pub async fn update_task(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     patch: PartialTask,
@@ -55,7 +59,7 @@ pub async fn update_task(
     };
 
     let repositories = state.repositories.read().await;
-    task_facades::update_task(&*repositories, &project_id, &task_id, &patch, &user_id_typed)
+    undoable(&window, task_facades::update_task(&*repositories, &project_id, &task_id, &patch, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task", command = "update_task", project_id = %project_id, task_id = %task_id, error = %e);
@@ -63,9 +67,74 @@ pub async fn update_task(
         })
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, task_id = %id))]
+/// タスクのステータスを変更します。
+///
+/// プロジェクトのステータス遷移ルールで理由が必須の遷移は`reason`を指定します。
+/// 作業開始日時・作業終了日時はステータスに合わせて自動で設定されます。
+#[instrument(level = "info", skip(window, state, reason), fields(project_id = %project_id, task_id = %id))]
+#[tauri::command]
+pub async fn update_task_status(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+    status: TaskStatus,
+    reason: Option<String>,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = match ProjectId::try_from_str(&project_id) {
+        Ok(id) => id,
+        Err(err) => return Err(err.to_string()),
+    };
+    let task_id = match TaskId::try_from_str(&id) {
+        Ok(t) => t,
+        Err(e) => return Err(e.to_string()),
+    };
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, task_facades::update_task_status(&*repositories, &project_id, &task_id, &status, reason.as_deref(), &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::task", command = "update_task_status", project_id = %project_id, task_id = %task_id, error = %e);
+            e
+        })
+}
+
+/// タスクをプロジェクトのワークフローステータスへ移します。
+///
+/// タスクのステータスは移動先の分類に変わり、ステータス遷移ルールと作業日時の連動は
+/// `update_task_status`と同じく分類に対して適用されます。
+#[instrument(level = "info", skip(window, state, reason), fields(project_id = %project_id, task_id = %id, workflow_status_id = %workflow_status_id))]
+#[tauri::command]
+pub async fn move_task_to_workflow_status(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+    workflow_status_id: String,
+    reason: Option<String>,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id = TaskId::try_from_str(&id).map_err(|e| e.to_string())?;
+    let workflow_status_id =
+        WorkflowStatusId::try_from_str(&workflow_status_id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, task_facades::move_task_to_workflow_status(&*repositories, &project_id, &task_id, &workflow_status_id, reason.as_deref(), &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::task", command = "move_task_to_workflow_status", project_id = %project_id, task_id = %task_id, workflow_status_id = %workflow_status_id, error = %e);
+            e
+        })
+}
+
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, task_id = %id))]
 #[tauri::command]
 pub async fn delete_task(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     id: String,
@@ -82,7 +151,7 @@ pub async fn delete_task(
         Err(err) => return Err(err.to_string()),
     };
     let repositories = state.repositories.read().await;
-    task_facades::delete_task(&*repositories, &project_id, &task_id, &user_id_typed, &timestamp)
+    undoable(&window, task_facades::delete_task(&*repositories, &project_id, &task_id, &user_id_typed, &timestamp))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task", command = "delete_task", project_id = %project_id, task_id = %task_id, error = %e);
@@ -90,9 +159,10 @@ pub async fn delete_task(
         })
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, task_id = %id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, task_id = %id))]
 #[tauri::command]
 pub async fn restore_task(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     id: String,
@@ -109,7 +179,7 @@ pub async fn restore_task(
         Err(err) => return Err(err.to_string()),
     };
     let repositories = state.repositories.read().await;
-    task_facades::restore_task(&*repositories, &project_id, &task_id, &user_id_typed, &timestamp)
+    undoable(&window, task_facades::restore_task(&*repositories, &project_id, &task_id, &user_id_typed, &timestamp))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task", command = "restore_task", project_id = %project_id, task_id = %task_id, error = %e);
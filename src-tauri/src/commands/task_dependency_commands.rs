@@ -0,0 +1,93 @@
+//! タスク依存関係（先行タスクの完了待ち）関連のTauriコマンド
+
+use crate::commands::undo_commands::undoable;
+use crate::models::task_dependency::TaskDependencyCommandModel;
+use crate::state::AppState;
+use flequit_core::facades::task_dependency_facades as facades;
+use flequit_model::types::id_types::{ProjectId, TaskId, UserId};
+use tauri::State;
+use tracing::instrument;
+
+/// 依存関係を追加します。循環する依存関係は作成できません。
+#[instrument(level = "info", skip(window, state, task_dependency), fields(project_id = %project_id))]
+#[tauri::command]
+pub async fn create_task_dependency(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    task_dependency: TaskDependencyCommandModel,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id = TaskId::from(task_dependency.task_id);
+    let depends_on_task_id = TaskId::from(task_dependency.depends_on_task_id);
+    let repositories = state.repositories.read().await;
+    undoable(&window, facades::add(&*repositories, &project_id, &task_id, &depends_on_task_id, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::task_dependency", command = "create_task_dependency", project_id = %project_id, task_id = %task_id, depends_on_task_id = %depends_on_task_id, error = %e);
+            e
+        })
+}
+
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, task_id = %task_id))]
+#[tauri::command]
+pub async fn delete_task_dependency(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: String,
+    depends_on_task_id: String,
+) -> Result<bool, String> {
+    let project_id_typed = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id_typed = TaskId::from(task_id);
+    let depends_on_task_id_typed = TaskId::from(depends_on_task_id);
+    let repositories = state.repositories.read().await;
+    undoable(&window, facades::remove(&*repositories, &project_id_typed, &task_id_typed, &depends_on_task_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::task_dependency", command = "delete_task_dependency", project_id = %project_id_typed, task_id = %task_id_typed, depends_on_task_id = %depends_on_task_id_typed, error = %e);
+            e
+        })
+}
+
+/// タスクが待っている先行タスクのIDを取得します。
+#[instrument(level = "info", skip(state), fields(project_id = %project_id, task_id = %task_id))]
+#[tauri::command]
+pub async fn get_blocking_task_ids(
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: String,
+) -> Result<Vec<String>, String> {
+    let project_id_typed = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id_typed = TaskId::from(task_id);
+    let repositories = state.repositories.read().await;
+    let task_ids = facades::get_blocking_task_ids(&*repositories, &project_id_typed, &task_id_typed)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::task_dependency", command = "get_blocking_task_ids", project_id = %project_id_typed, task_id = %task_id_typed, error = %e);
+            e
+        })?;
+    Ok(task_ids.iter().map(ToString::to_string).collect())
+}
+
+/// タスクの完了を待っている後続タスクのIDを取得します。
+#[instrument(level = "info", skip(state), fields(project_id = %project_id, task_id = %task_id))]
+#[tauri::command]
+pub async fn get_dependent_task_ids(
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: String,
+) -> Result<Vec<String>, String> {
+    let project_id_typed = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id_typed = TaskId::from(task_id);
+    let repositories = state.repositories.read().await;
+    let task_ids = facades::get_dependent_task_ids(&*repositories, &project_id_typed, &task_id_typed)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::task_dependency", command = "get_dependent_task_ids", project_id = %project_id_typed, task_id = %task_id_typed, error = %e);
+            e
+        })?;
+    Ok(task_ids.iter().map(ToString::to_string).collect())
+}
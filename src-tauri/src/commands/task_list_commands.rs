@@ -1,19 +1,21 @@
+use crate::commands::undo_commands::undoable;
+use crate::models::CommandModelConverter;
 use crate::models::task_list::{TaskListCommandModel, TaskListTreeCommandModel};
 use crate::models::task_list_search_request::TaskListSearchRequest;
-use crate::models::CommandModelConverter;
 use crate::state::AppState;
 use chrono::Utc;
 use flequit_core::facades::task_list_facades;
 use flequit_core::services::task_list_service;
-use flequit_model::models::task_projects::task_list::PartialTaskList;
 use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::task_list::PartialTaskList;
 use flequit_model::types::id_types::{ProjectId, TaskListId, UserId};
 use tauri::State;
 use tracing::instrument;
 
-#[instrument(level = "info", skip(state, task_list), fields(project_id = %task_list.project_id))]
+#[instrument(level = "info", skip(window, state, task_list), fields(project_id = %task_list.project_id))]
 #[tauri::command]
 pub async fn create_task_list(
+    window: tauri::Window,
     state: State<'_, AppState>,
     task_list: TaskListCommandModel,
     user_id: String,
@@ -26,7 +28,7 @@ pub async fn create_task_list(
     let internal_task_list = task_list.to_model().await?;
     let repositories = state.repositories.read().await;
 
-    task_list_facades::create_task_list(&*repositories, &project_id, &internal_task_list, &user_id_typed)
+    undoable(&window, task_list_facades::create_task_list(&*repositories, &project_id, &internal_task_list, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task_list", command = "create_task_list", project_id = %project_id, error = %e);
@@ -64,9 +66,10 @@ pub async fn get_task_list(
     }
 }
 
-#[instrument(level = "info", skip(state, patch), fields(project_id = %project_id, task_list_id = %id))]
+#[instrument(level = "info", skip(window, state, patch), fields(project_id = %project_id, task_list_id = %id))]
 #[tauri::command]
 pub async fn update_task_list(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     id: String,
@@ -84,7 +87,7 @@ pub async fn update_task_list(
     };
     let repositories = state.repositories.read().await;
 
-    task_list_facades::update_task_list(&*repositories, &project_id, &task_list_id, &patch, &user_id_typed)
+    undoable(&window, task_list_facades::update_task_list(&*repositories, &project_id, &task_list_id, &patch, &user_id_typed))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task_list", command = "update_task_list", project_id = %project_id, task_list_id = %task_list_id, error = %e);
@@ -92,9 +95,10 @@ pub async fn update_task_list(
         })
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, task_list_id = %id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, task_list_id = %id))]
 #[tauri::command]
 pub async fn delete_task_list(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     id: String,
@@ -112,7 +116,7 @@ pub async fn delete_task_list(
     };
     let repositories = state.repositories.read().await;
 
-    task_list_facades::delete_task_list(&*repositories, &project_id, &task_list_id, &user_id_typed, &timestamp)
+    undoable(&window, task_list_facades::delete_task_list(&*repositories, &project_id, &task_list_id, &user_id_typed, &timestamp))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task_list", command = "delete_task_list", project_id = %project_id, task_list_id = %task_list_id, error = %e);
@@ -120,9 +124,10 @@ pub async fn delete_task_list(
         })
 }
 
-#[instrument(level = "info", skip(state), fields(project_id = %project_id, task_list_id = %id))]
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, task_list_id = %id))]
 #[tauri::command]
 pub async fn restore_task_list(
+    window: tauri::Window,
     state: State<'_, AppState>,
     project_id: String,
     id: String,
@@ -140,7 +145,7 @@ pub async fn restore_task_list(
     };
     let repositories = state.repositories.read().await;
 
-    task_list_facades::restore_task_list(&*repositories, &project_id, &task_list_id, &user_id_typed, &timestamp)
+    undoable(&window, task_list_facades::restore_task_list(&*repositories, &project_id, &task_list_id, &user_id_typed, &timestamp))
         .await
         .map_err(|e| {
             tracing::error!(target: "commands::task_list", command = "restore_task_list", project_id = %project_id, task_list_id = %task_list_id, error = %e);
@@ -0,0 +1,261 @@
+//! タイムエントリ（作業時間の記録）関連のTauriコマンド
+
+use crate::commands::undo_commands::undoable;
+use crate::models::CommandModelConverter;
+use crate::models::time_entry::{TimeEntryCommandModel, TimeTotalCommandModel};
+use crate::state::AppState;
+use chrono::{DateTime, Utc};
+use flequit_core::facades::time_entry_facades;
+use flequit_core::services::time_entry_service::{TimeGrouping, TimeSummaryCondition};
+use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::time_entry::PartialTimeEntry;
+use flequit_model::types::id_types::{ProjectId, SubTaskId, TaskId, TimeEntryId, UserId};
+use tauri::State;
+use tracing::instrument;
+
+/// タスク（`subtask_id`を指定した場合はサブタスク）のタイマーを開始します。
+///
+/// 同じユーザーのタイマーが既に計測中の場合はエラーになります。
+#[instrument(level = "info", skip(window, state, note), fields(project_id = %project_id, task_id = %task_id))]
+#[tauri::command]
+pub async fn start_timer(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: String,
+    subtask_id: Option<String>,
+    note: Option<String>,
+    user_id: String,
+) -> Result<TimeEntryCommandModel, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id = TaskId::try_from_str(&task_id).map_err(|e| e.to_string())?;
+    let subtask_id = match subtask_id {
+        Some(id) => Some(SubTaskId::try_from_str(&id).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let repositories = state.repositories.read().await;
+
+    let entry = undoable(
+        &window,
+        time_entry_facades::start_timer(
+            &*repositories,
+            &project_id,
+            &task_id,
+            subtask_id.as_ref(),
+            note,
+            &user_id_typed,
+        ),
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!(target: "commands::time_entry", command = "start_timer", project_id = %project_id, task_id = %task_id, error = %e);
+        e
+    })?;
+    entry.to_command_model().await
+}
+
+/// 計測中のタイマーを停止します。計測中のタイマーがない場合は`None`を返します。
+#[instrument(level = "info", skip(window, state))]
+#[tauri::command]
+pub async fn stop_timer(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    user_id: String,
+) -> Result<Option<TimeEntryCommandModel>, String> {
+    let user_id_typed = UserId::from(user_id);
+    let repositories = state.repositories.read().await;
+
+    let result = undoable(
+        &window,
+        time_entry_facades::stop_timer(&*repositories, &user_id_typed),
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!(target: "commands::time_entry", command = "stop_timer", error = %e);
+        e
+    })?;
+    match result {
+        Some(entry) => Ok(Some(entry.to_command_model().await?)),
+        None => Ok(None),
+    }
+}
+
+/// ユーザーの計測中のタイマーを取得します。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn get_running_timer(
+    state: State<'_, AppState>,
+    user_id: String,
+) -> Result<Option<TimeEntryCommandModel>, String> {
+    let user_id_typed = UserId::from(user_id);
+    let repositories = state.repositories.read().await;
+
+    let result = time_entry_facades::get_running_time_entry(&*repositories, &user_id_typed)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::time_entry", command = "get_running_timer", error = %e);
+            e
+        })?;
+    match result {
+        Some(entry) => Ok(Some(entry.to_command_model().await?)),
+        None => Ok(None),
+    }
+}
+
+/// 終了済みの作業時間を手動で記録します。
+#[instrument(level = "info", skip(window, state, time_entry), fields(project_id = %project_id, time_entry_id = %time_entry.id))]
+#[tauri::command]
+pub async fn create_time_entry(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    time_entry: TimeEntryCommandModel,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let internal_entry = time_entry.to_model().await?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, time_entry_facades::create_time_entry(&*repositories, &project_id, &internal_entry, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::time_entry", command = "create_time_entry", project_id = %project_id, error = %e);
+            e
+        })
+}
+
+#[instrument(level = "info", skip(state), fields(project_id = %project_id, time_entry_id = %id))]
+#[tauri::command]
+pub async fn get_time_entry(
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+) -> Result<Option<TimeEntryCommandModel>, String> {
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let entry_id = TimeEntryId::try_from_str(&id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    let result = time_entry_facades::get_time_entry(&*repositories, &project_id, &entry_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::time_entry", command = "get_time_entry", project_id = %project_id, time_entry_id = %entry_id, error = %e);
+            e
+        })?;
+    match result {
+        Some(entry) => Ok(Some(entry.to_command_model().await?)),
+        None => Ok(None),
+    }
+}
+
+/// プロジェクトの作業時間の記録を開始日時順に取得します。
+///
+/// `task_id`を指定した場合はそのタスク（サブタスクを含む）の記録だけを返します。
+#[instrument(level = "info", skip(state), fields(project_id = %project_id))]
+#[tauri::command]
+pub async fn list_time_entries(
+    state: State<'_, AppState>,
+    project_id: String,
+    task_id: Option<String>,
+) -> Result<Vec<TimeEntryCommandModel>, String> {
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let task_id = match task_id {
+        Some(id) => Some(TaskId::try_from_str(&id).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let repositories = state.repositories.read().await;
+
+    let entries = time_entry_facades::list_time_entries(&*repositories, &project_id, task_id.as_ref())
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::time_entry", command = "list_time_entries", project_id = %project_id, error = %e);
+            e
+        })?;
+    let mut command_models = Vec::with_capacity(entries.len());
+    for entry in entries {
+        command_models.push(entry.to_command_model().await?);
+    }
+    Ok(command_models)
+}
+
+#[instrument(level = "info", skip(window, state, patch), fields(project_id = %project_id, time_entry_id = %id))]
+#[tauri::command]
+pub async fn update_time_entry(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+    patch: PartialTimeEntry,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let entry_id = TimeEntryId::try_from_str(&id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, time_entry_facades::update_time_entry(&*repositories, &project_id, &entry_id, &patch, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::time_entry", command = "update_time_entry", project_id = %project_id, time_entry_id = %entry_id, error = %e);
+            e
+        })
+}
+
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, time_entry_id = %id))]
+#[tauri::command]
+pub async fn delete_time_entry(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let entry_id = TimeEntryId::try_from_str(&id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, time_entry_facades::delete_time_entry(&*repositories, &project_id, &entry_id, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::time_entry", command = "delete_time_entry", project_id = %project_id, time_entry_id = %entry_id, error = %e);
+            e
+        })
+}
+
+/// 期間内の作業時間を集計します。
+///
+/// `group_by`には`task` / `task_list` / `project` / `tag` / `user`を指定します。
+/// 期間は`from`を含み`to`を含みません。
+#[instrument(level = "info", skip(state))]
+#[tauri::command]
+pub async fn summarize_time(
+    state: State<'_, AppState>,
+    group_by: TimeGrouping,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    project_id: Option<String>,
+    user_id: Option<String>,
+) -> Result<Vec<TimeTotalCommandModel>, String> {
+    let project_id = match project_id {
+        Some(id) => Some(ProjectId::try_from_str(&id).map_err(|e| e.to_string())?),
+        None => None,
+    };
+    let condition = TimeSummaryCondition {
+        group_by,
+        from,
+        to,
+        project_id,
+        user_id: user_id.map(UserId::from),
+    };
+    let repositories = state.repositories.read().await;
+
+    let totals = time_entry_facades::summarize_time(&*repositories, &condition)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::time_entry", command = "summarize_time", error = %e);
+            e
+        })?;
+    Ok(totals.into_iter().map(Into::into).collect())
+}
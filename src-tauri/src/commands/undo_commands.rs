@@ -0,0 +1,65 @@
+//! 取り消し・やり直し（Undo/Redo）関連のTauriコマンド
+//!
+//! 履歴はウィンドウごとに分かれており、`undoable` で包んだコマンドの書き込みだけが
+//! 呼び出し元ウィンドウの履歴に記録される。
+
+use crate::models::undo::{UndoStatusCommandModel, UndoStepSummaryCommandModel};
+use crate::state::AppState;
+use flequit_core::facades::undo_facades;
+use flequit_core::undo;
+use flequit_model::types::id_types::UserId;
+use std::future::Future;
+use tauri::State;
+use tracing::instrument;
+
+/// `work` の書き込みを呼び出し元ウィンドウの取り消し履歴に記録する
+pub(crate) async fn undoable<F: Future>(window: &tauri::Window, work: F) -> F::Output {
+    undo::in_session(window.label(), work).await
+}
+
+/// ウィンドウで最後に行った操作を取り消します。
+///
+/// 取り消す操作がない場合は `null` を返します。
+#[instrument(level = "info", skip(state, window), fields(window = %window.label()))]
+#[tauri::command]
+pub async fn undo(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    user_id: String,
+) -> Result<Option<UndoStepSummaryCommandModel>, String> {
+    let repositories = state.repositories.read().await;
+    let summary = undo_facades::undo(&*repositories, window.label(), &UserId::from(user_id))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::undo", command = "undo", error = %e);
+            format!("操作の取り消しに失敗: {}", e)
+        })?;
+    Ok(summary.map(Into::into))
+}
+
+/// ウィンドウで最後に取り消した操作をやり直します。
+///
+/// やり直す操作がない場合は `null` を返します。
+#[instrument(level = "info", skip(state, window), fields(window = %window.label()))]
+#[tauri::command]
+pub async fn redo(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    user_id: String,
+) -> Result<Option<UndoStepSummaryCommandModel>, String> {
+    let repositories = state.repositories.read().await;
+    let summary = undo_facades::redo(&*repositories, window.label(), &UserId::from(user_id))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::undo", command = "redo", error = %e);
+            format!("操作のやり直しに失敗: {}", e)
+        })?;
+    Ok(summary.map(Into::into))
+}
+
+/// ウィンドウで次に取り消し・やり直しされる操作を取得します。
+#[instrument(level = "info", skip(window), fields(window = %window.label()))]
+#[tauri::command]
+pub async fn get_undo_status(window: tauri::Window) -> Result<UndoStatusCommandModel, String> {
+    Ok(undo_facades::undo_status(window.label()).into())
+}
@@ -1,3 +1,8 @@
+//! ユーザー関連のTauriコマンド
+//!
+//! ユーザー（プロフィール）はプロジェクトに属さず、同期先の他の端末とも共有されるため、
+//! ウィンドウ単位の取り消し履歴には記録しない（これらのコマンドは`undoable`で包まない）。
+
 use crate::models::user::{PartialUserCommandModel, UserCommandModel};
 use crate::models::CommandModelConverter;
 use crate::state::AppState;
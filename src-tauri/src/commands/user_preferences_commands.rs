@@ -1,3 +1,8 @@
+//! ユーザー設定（タグのブックマーク）関連のTauriコマンド
+//!
+//! ブックマークは作業内容ではなく個人の表示設定のため、取り消し履歴には記録しない
+//! （これらのコマンドは`undoable`で包まない）。
+
 use crate::models::user_preferences::TagBookmarkCommandModel;
 use crate::state::AppState;
 use flequit_core::services::tag_bookmark_service;
@@ -0,0 +1,104 @@
+//! ワークフローステータス（プロジェクト独自のステータス）関連のTauriコマンド
+
+use crate::commands::undo_commands::undoable;
+use crate::models::CommandModelConverter;
+use crate::models::workflow_status::WorkflowStatusCommandModel;
+use crate::state::AppState;
+use flequit_core::facades::workflow_status_facades;
+use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::workflow_status::PartialWorkflowStatus;
+use flequit_model::types::id_types::{ProjectId, UserId, WorkflowStatusId};
+use tauri::State;
+use tracing::instrument;
+
+/// プロジェクトのワークフローステータスを表示順に取得します。
+#[instrument(level = "info", skip(state), fields(project_id = %project_id))]
+#[tauri::command]
+pub async fn list_workflow_statuses(
+    state: State<'_, AppState>,
+    project_id: String,
+) -> Result<Vec<WorkflowStatusCommandModel>, String> {
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    let statuses = workflow_status_facades::list_workflow_statuses(&*repositories, &project_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::workflow_status", command = "list_workflow_statuses", project_id = %project_id, error = %e);
+            e
+        })?;
+    let mut command_models = Vec::with_capacity(statuses.len());
+    for workflow_status in statuses {
+        command_models.push(workflow_status.to_command_model().await?);
+    }
+    Ok(command_models)
+}
+
+/// ワークフローステータスを作成します。名前はプロジェクト内で重複できません。
+#[instrument(level = "info", skip(window, state, workflow_status), fields(project_id = %project_id, workflow_status_id = %workflow_status.id))]
+#[tauri::command]
+pub async fn create_workflow_status(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    workflow_status: WorkflowStatusCommandModel,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let internal_workflow_status = workflow_status.to_model().await?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, workflow_status_facades::create_workflow_status(&*repositories, &project_id, &internal_workflow_status, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::workflow_status", command = "create_workflow_status", project_id = %project_id, error = %e);
+            e
+        })
+}
+
+#[instrument(level = "info", skip(window, state, patch), fields(project_id = %project_id, workflow_status_id = %id))]
+#[tauri::command]
+pub async fn update_workflow_status(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+    patch: PartialWorkflowStatus,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let workflow_status_id = WorkflowStatusId::try_from_str(&id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, workflow_status_facades::update_workflow_status(&*repositories, &project_id, &workflow_status_id, &patch, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::workflow_status", command = "update_workflow_status", project_id = %project_id, workflow_status_id = %workflow_status_id, error = %e);
+            e
+        })
+}
+
+/// ワークフローステータスを削除します。タスクが参照している間は削除できません。
+#[instrument(level = "info", skip(window, state), fields(project_id = %project_id, workflow_status_id = %id))]
+#[tauri::command]
+pub async fn delete_workflow_status(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+    project_id: String,
+    id: String,
+    user_id: String,
+) -> Result<bool, String> {
+    let user_id_typed = UserId::from(user_id);
+    let project_id = ProjectId::try_from_str(&project_id).map_err(|e| e.to_string())?;
+    let workflow_status_id = WorkflowStatusId::try_from_str(&id).map_err(|e| e.to_string())?;
+    let repositories = state.repositories.read().await;
+
+    undoable(&window, workflow_status_facades::delete_workflow_status(&*repositories, &project_id, &workflow_status_id, &user_id_typed))
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "commands::workflow_status", command = "delete_workflow_status", project_id = %project_id, workflow_status_id = %workflow_status_id, error = %e);
+            e
+        })
+}
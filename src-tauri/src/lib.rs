@@ -21,7 +21,107 @@ pub fn run() {
             .await
             .expect("Failed to create app state");
 
+        // マイグレーションで予約されたデータ補完を実行
+        if let Err(e) = app_state
+            .repositories
+            .read()
+            .await
+            .run_pending_backfills()
+            .await
+        {
+            tracing::error!("Failed to run data backfills: {}", e);
+        }
+
+        // 定期バックアップを開始
+        app_state.restart_backup_scheduler().await;
+
+        // カレンダー購読フィードの配信を開始
+        let calendar_feed = app_state.settings.read().await.calendar_feed.clone();
+        if calendar_feed.enabled {
+            match flequit_infrastructure::calendar_feed::CalendarFeedServer::start(
+                app_state.repositories.clone(),
+                app_state.settings.clone(),
+                calendar_feed.port,
+            )
+            .await
+            {
+                Ok(handle) => *app_state.calendar_feed_server.lock().await = Some(handle),
+                Err(e) => tracing::error!("Failed to start calendar feed server: {}", e),
+            }
+        }
+
+        let repositories = app_state.repositories.clone();
         tauri::Builder::default()
+            .setup(move |app| {
+                // 通知時刻を迎えたリマインダーをフロントエンドへ通知する
+                // （停止中に過ぎたリマインダーは起動直後にまとめて通知される）
+                let app_handle = app.handle().clone();
+                flequit_infrastructure::reminder::ReminderScheduler::spawn(
+                    repositories.clone(),
+                    move |fired| {
+                        use tauri::Emitter;
+                        let payload =
+                            crate::models::reminder::ReminderFiredCommandModel::from(fired);
+                        if let Err(e) =
+                            app_handle.emit(crate::models::reminder::REMINDER_FIRED_EVENT, payload)
+                        {
+                            tracing::error!("Failed to emit reminder event: {}", e);
+                        }
+                    },
+                );
+
+                // オフライン中に記録した書き込みを定期的に再送する
+                let app_handle = app.handle().clone();
+                flequit_infrastructure::web::OutboxFlusher::spawn(
+                    repositories,
+                    std::time::Duration::from_secs(30),
+                    move |report| {
+                        use tauri::Emitter;
+                        let payload = crate::models::outbox::OutboxFlushReportCommandModel::from(
+                            report.clone(),
+                        );
+                        if let Err(e) = app_handle
+                            .emit(commands::outbox_commands::OUTBOX_ATTENTION_EVENT, payload)
+                        {
+                            tracing::error!("Failed to emit outbox event: {}", e);
+                        }
+                    },
+                );
+
+                // エンティティの変更をフロントエンドへ通知する
+                let app_handle = app.handle().clone();
+                let mut domain_events = flequit_core::events::EventBus::global().subscribe();
+                tauri::async_runtime::spawn(async move {
+                    use tauri::Emitter;
+                    use tokio::sync::broadcast::error::RecvError;
+                    loop {
+                        match domain_events.recv().await {
+                            Ok(event) => {
+                                let payload =
+                                    crate::models::domain_event::DomainEventCommandModel::from(
+                                        event,
+                                    );
+                                if let Err(e) = app_handle
+                                    .emit(crate::models::domain_event::DOMAIN_EVENT, payload)
+                                {
+                                    tracing::error!("Failed to emit domain event: {}", e);
+                                }
+                            }
+                            Err(RecvError::Lagged(skipped)) => {
+                                tracing::warn!("Skipped {} domain events", skipped);
+                            }
+                            Err(RecvError::Closed) => break,
+                        }
+                    }
+                });
+                Ok(())
+            })
+            .on_window_event(|window, event| {
+                // 閉じたウィンドウの取り消し履歴は不要になるため破棄する
+                if let tauri::WindowEvent::Destroyed = event {
+                    flequit_core::undo::UndoJournal::global().clear_session(window.label());
+                }
+            })
             .manage(app_state)
             .plugin(tauri_plugin_opener::init())
             .invoke_handler(crate::generate_app_handler!())
@@ -0,0 +1,64 @@
+//! アクティビティログ（エンティティの変更履歴）コマンドモデル
+
+use chrono::{DateTime, Utc};
+use flequit_model::models::activity::activity_entry::{ActivityEntry, FieldChange};
+use serde::{Deserialize, Serialize};
+
+/// フィールド単位の変更内容（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChangeCommandModel {
+    pub field: String,
+    /// 変更前の値（不明な場合は `null`）
+    pub from: serde_json::Value,
+    /// 変更後の値（不明な場合は `null`）
+    pub to: serde_json::Value,
+}
+
+impl From<FieldChange> for FieldChangeCommandModel {
+    fn from(change: FieldChange) -> Self {
+        Self {
+            field: change.field,
+            from: change.from,
+            to: change.to,
+        }
+    }
+}
+
+/// アクティビティログのエントリ（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityEntryCommandModel {
+    pub seq: i64,
+    pub project_id: String,
+    /// 対象エンティティの種類（`task` / `sub_task` / `task_tag` など）
+    pub entity: String,
+    pub entity_id: String,
+    /// 関連先・親エンティティのID
+    pub related_id: Option<String>,
+    /// `created` / `updated` / `deleted` / `restored`
+    pub action: String,
+    pub changes: Vec<FieldChangeCommandModel>,
+    /// 変更を行ったユーザーID
+    pub actor: Option<String>,
+    /// `local` / `sync` / `history`
+    pub source: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl From<ActivityEntry> for ActivityEntryCommandModel {
+    fn from(entry: ActivityEntry) -> Self {
+        Self {
+            seq: entry.seq,
+            project_id: entry.project_id.to_string(),
+            entity: entry.entity,
+            entity_id: entry.entity_id,
+            related_id: entry.related_id,
+            action: entry.action.to_string(),
+            changes: entry.changes.into_iter().map(Into::into).collect(),
+            actor: entry.actor.map(|id| id.to_string()),
+            source: entry.source.to_string(),
+            occurred_at: entry.occurred_at,
+        }
+    }
+}
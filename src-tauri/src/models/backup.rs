@@ -0,0 +1,84 @@
+//! バックアップコマンドモデル
+
+use chrono::{DateTime, Utc};
+use flequit_infrastructure::backup::{BackupFileInfo, BackupManifest};
+use flequit_settings::BackupSettings;
+use serde::{Deserialize, Serialize};
+
+/// バックアップマニフェスト（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupManifestCommandModel {
+    pub format_version: u32,
+    pub app_version: String,
+    pub created_at: DateTime<Utc>,
+    pub database_encrypted: bool,
+    pub automerge_encrypted: bool,
+    pub file_count: usize,
+    pub total_size: u64,
+}
+
+impl From<&BackupManifest> for BackupManifestCommandModel {
+    fn from(manifest: &BackupManifest) -> Self {
+        Self {
+            format_version: manifest.format_version,
+            app_version: manifest.app_version.clone(),
+            created_at: manifest.created_at,
+            database_encrypted: manifest.database_encrypted,
+            automerge_encrypted: manifest.automerge_encrypted,
+            file_count: manifest.entries.len(),
+            total_size: manifest.total_size(),
+        }
+    }
+}
+
+/// バックアップファイル情報（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupFileCommandModel {
+    pub path: String,
+    pub size: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&BackupFileInfo> for BackupFileCommandModel {
+    fn from(info: &BackupFileInfo) -> Self {
+        Self {
+            path: info.path.to_string_lossy().to_string(),
+            size: info.size,
+            created_at: info.created_at,
+        }
+    }
+}
+
+/// 定期バックアップ設定（Tauriコマンド引数・戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupSettingsCommandModel {
+    pub enabled: bool,
+    pub interval_hours: u32,
+    pub directory: Option<String>,
+    pub keep: u32,
+}
+
+impl From<&BackupSettings> for BackupSettingsCommandModel {
+    fn from(settings: &BackupSettings) -> Self {
+        Self {
+            enabled: settings.enabled,
+            interval_hours: settings.interval_hours,
+            directory: settings.directory.clone(),
+            keep: settings.keep,
+        }
+    }
+}
+
+impl From<BackupSettingsCommandModel> for BackupSettings {
+    fn from(model: BackupSettingsCommandModel) -> Self {
+        Self {
+            enabled: model.enabled,
+            interval_hours: model.interval_hours,
+            directory: model.directory,
+            keep: model.keep,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! プロジェクトバンドルコマンドモデル
+
+use chrono::{DateTime, Utc};
+use flequit_infrastructure::bundle::{BundleImportMode, BundleImportResult, BundleManifest};
+use serde::{Deserialize, Serialize};
+
+/// プロジェクトバンドルのマニフェスト（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectBundleCommandModel {
+    pub format_version: u32,
+    pub app_version: String,
+    pub exported_at: DateTime<Utc>,
+    pub project_id: String,
+    pub project_name: String,
+    pub task_list_count: usize,
+    pub task_count: usize,
+    pub subtask_count: usize,
+    pub tag_count: usize,
+    pub member_count: usize,
+    pub recurrence_rule_count: usize,
+}
+
+impl From<&BundleManifest> for ProjectBundleCommandModel {
+    fn from(manifest: &BundleManifest) -> Self {
+        Self {
+            format_version: manifest.format_version,
+            app_version: manifest.app_version.clone(),
+            exported_at: manifest.exported_at,
+            project_id: manifest.project_id.clone(),
+            project_name: manifest.project_name.clone(),
+            task_list_count: manifest.counts.task_lists,
+            task_count: manifest.counts.tasks,
+            subtask_count: manifest.counts.subtasks,
+            tag_count: manifest.counts.tags,
+            member_count: manifest.counts.members,
+            recurrence_rule_count: manifest.counts.recurrence_rules,
+        }
+    }
+}
+
+/// プロジェクトバンドルのインポート結果（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectBundleImportCommandModel {
+    /// 取り込み先のプロジェクトID
+    pub project_id: String,
+    /// IDを保持して取り込んだか
+    pub keep_ids: bool,
+    /// 既存のプロジェクトにマージしたか
+    pub merged: bool,
+    pub remapped_ids: usize,
+    pub bundle: ProjectBundleCommandModel,
+}
+
+impl From<&BundleImportResult> for ProjectBundleImportCommandModel {
+    fn from(result: &BundleImportResult) -> Self {
+        Self {
+            project_id: result.project_id.clone(),
+            keep_ids: result.mode == BundleImportMode::KeepIds,
+            merged: result.merged,
+            remapped_ids: result.remapped_ids,
+            bundle: ProjectBundleCommandModel::from(&result.manifest),
+        }
+    }
+}
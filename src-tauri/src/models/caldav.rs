@@ -0,0 +1,86 @@
+//! CalDAV同期コマンドモデル
+
+use chrono::{DateTime, Utc};
+use flequit_infrastructure::caldav::{CalendarBinding, RemoteCalendar, SyncReport};
+use serde::{Deserialize, Serialize};
+
+/// CalDAVサーバーへの接続情報（Tauriコマンド引数用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalDavConnectionCommandModel {
+    /// カレンダーホームのURL
+    pub url: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+/// サーバー上のカレンダー（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalDavCalendarCommandModel {
+    pub href: String,
+    pub display_name: Option<String>,
+}
+
+impl From<RemoteCalendar> for CalDavCalendarCommandModel {
+    fn from(calendar: RemoteCalendar) -> Self {
+        Self {
+            href: calendar.href,
+            display_name: calendar.display_name,
+        }
+    }
+}
+
+/// タスクリストとカレンダーの対応（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalDavBindingCommandModel {
+    pub project_id: String,
+    pub task_list_id: String,
+    pub calendar_href: String,
+    pub display_name: Option<String>,
+    pub item_count: usize,
+    pub last_synced_at: Option<DateTime<Utc>>,
+}
+
+impl From<&CalendarBinding> for CalDavBindingCommandModel {
+    fn from(binding: &CalendarBinding) -> Self {
+        Self {
+            project_id: binding.project_id.to_string(),
+            task_list_id: binding.task_list_id.to_string(),
+            calendar_href: binding.calendar_href.clone(),
+            display_name: binding.display_name.clone(),
+            item_count: binding.items.len(),
+            last_synced_at: binding.last_synced_at,
+        }
+    }
+}
+
+/// 同期結果（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalDavSyncReportCommandModel {
+    pub pulled_created: usize,
+    pub pulled_updated: usize,
+    pub pulled_deleted: usize,
+    pub pushed_created: usize,
+    pub pushed_updated: usize,
+    pub pushed_deleted: usize,
+    pub conflicts: usize,
+    pub errors: Vec<String>,
+}
+
+impl From<SyncReport> for CalDavSyncReportCommandModel {
+    fn from(report: SyncReport) -> Self {
+        Self {
+            pulled_created: report.pulled_created,
+            pulled_updated: report.pulled_updated,
+            pulled_deleted: report.pulled_deleted,
+            pushed_created: report.pushed_created,
+            pushed_updated: report.pushed_updated,
+            pushed_deleted: report.pushed_deleted,
+            conflicts: report.conflicts,
+            errors: report.errors,
+        }
+    }
+}
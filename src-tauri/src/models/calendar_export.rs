@@ -0,0 +1,41 @@
+//! カレンダーエクスポートコマンドモデル
+
+use chrono::{DateTime, Utc};
+use flequit_core::exporters::ical::CalendarExportFilter;
+use flequit_model::types::id_types::{TagId, TaskListId};
+use flequit_model::types::task_types::TaskStatus;
+use serde::{Deserialize, Serialize};
+
+/// エクスポート対象の絞り込み条件（Tauriコマンド引数用）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarExportFilterCommandModel {
+    pub task_list_ids: Option<Vec<String>>,
+    pub tag_ids: Option<Vec<String>>,
+    pub statuses: Option<Vec<TaskStatus>>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub include_subtasks: Option<bool>,
+    pub include_archived: Option<bool>,
+    pub include_undated: Option<bool>,
+}
+
+impl From<CalendarExportFilterCommandModel> for CalendarExportFilter {
+    fn from(model: CalendarExportFilterCommandModel) -> Self {
+        let defaults = CalendarExportFilter::default();
+        Self {
+            task_list_ids: model
+                .task_list_ids
+                .map(|ids| ids.into_iter().map(TaskListId::from).collect()),
+            tag_ids: model
+                .tag_ids
+                .map(|ids| ids.into_iter().map(TagId::from).collect()),
+            statuses: model.statuses,
+            from: model.from,
+            to: model.to,
+            include_subtasks: model.include_subtasks.unwrap_or(defaults.include_subtasks),
+            include_archived: model.include_archived.unwrap_or(defaults.include_archived),
+            include_undated: model.include_undated.unwrap_or(defaults.include_undated),
+        }
+    }
+}
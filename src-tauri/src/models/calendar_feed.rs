@@ -0,0 +1,89 @@
+//! カレンダー購読フィードコマンドモデル
+
+use chrono::{DateTime, Utc};
+use flequit_infrastructure::calendar_feed::feed_url;
+use flequit_model::types::task_types::TaskStatus;
+use flequit_settings::CalendarFeed;
+use serde::{Deserialize, Serialize};
+
+/// フィード配信サーバーの状態（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarFeedStatusCommandModel {
+    pub enabled: bool,
+    pub port: u16,
+    /// 実際に待ち受けているか（ポートが使用中の場合などはfalse）
+    pub running: bool,
+}
+
+/// フィードの作成条件（Tauriコマンド引数用）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarFeedRequestCommandModel {
+    pub name: String,
+    pub project_ids: Option<Vec<String>>,
+    pub task_list_ids: Option<Vec<String>>,
+    pub tag_ids: Option<Vec<String>>,
+    pub statuses: Option<Vec<TaskStatus>>,
+    pub include_subtasks: Option<bool>,
+    pub days_back: Option<u32>,
+}
+
+impl CalendarFeedRequestCommandModel {
+    /// トークンとIDを付与してフィード設定へ変換
+    pub fn into_feed(self, id: String, token: String) -> CalendarFeed {
+        let statuses = self
+            .statuses
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|status| serde_json::to_value(status).ok())
+            .filter_map(|value| value.as_str().map(str::to_string))
+            .collect();
+        CalendarFeed {
+            id,
+            name: self.name,
+            token,
+            project_ids: self.project_ids.unwrap_or_default(),
+            task_list_ids: self.task_list_ids.unwrap_or_default(),
+            tag_ids: self.tag_ids.unwrap_or_default(),
+            statuses,
+            include_subtasks: self.include_subtasks.unwrap_or(true),
+            days_back: self.days_back,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// フィード情報（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarFeedCommandModel {
+    pub id: String,
+    pub name: String,
+    /// 購読用URL（トークンを含む）
+    pub url: String,
+    pub project_ids: Vec<String>,
+    pub task_list_ids: Vec<String>,
+    pub tag_ids: Vec<String>,
+    pub statuses: Vec<String>,
+    pub include_subtasks: bool,
+    pub days_back: Option<u32>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CalendarFeedCommandModel {
+    pub fn from_feed(feed: &CalendarFeed, port: u16) -> Self {
+        Self {
+            id: feed.id.clone(),
+            name: feed.name.clone(),
+            url: feed_url(port, &feed.token),
+            project_ids: feed.project_ids.clone(),
+            task_list_ids: feed.task_list_ids.clone(),
+            tag_ids: feed.tag_ids.clone(),
+            statuses: feed.statuses.clone(),
+            include_subtasks: feed.include_subtasks,
+            days_back: feed.days_back,
+            created_at: feed.created_at,
+        }
+    }
+}
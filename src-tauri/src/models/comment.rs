@@ -0,0 +1,91 @@
+//! コメントコマンドモデル
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::comment::Comment;
+use flequit_model::types::id_types::{CommentId, ProjectId, SubTaskId, TaskId, UserId};
+use serde::{Deserialize, Serialize};
+
+use crate::models::CommandModelConverter;
+
+/// Tauriコマンド引数用のComment構造体（日時はString）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentCommandModel {
+    pub id: String,
+    pub project_id: String,
+    pub task_id: String,
+    /// サブタスクへのコメントの場合のみ
+    pub subtask_id: Option<String>,
+    pub author_id: String,
+    pub body: String,
+    /// 本文で言及したユーザーのID
+    pub mentioned_user_ids: Vec<String>,
+    pub created_at: String,
+    /// 編集していない場合は`None`
+    pub edited_at: Option<String>,
+    pub updated_at: String,
+    pub deleted: bool,
+    pub updated_by: String,
+}
+
+fn parse_datetime(field: &str, value: &str) -> Result<DateTime<Utc>, String> {
+    value
+        .parse::<DateTime<Utc>>()
+        .map_err(|e| format!("Invalid {} format: {}", field, e))
+}
+
+#[async_trait]
+impl ModelConverter<Comment> for CommentCommandModel {
+    /// コマンド引数用（CommentCommand）から内部モデル（Comment）に変換
+    async fn to_model(&self) -> Result<Comment, String> {
+        Ok(Comment {
+            id: CommentId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            task_id: TaskId::from(self.task_id.clone()),
+            subtask_id: self.subtask_id.clone().map(SubTaskId::from),
+            author_id: UserId::from(self.author_id.clone()),
+            body: self.body.clone(),
+            mentioned_user_ids: self
+                .mentioned_user_ids
+                .iter()
+                .cloned()
+                .map(UserId::from)
+                .collect(),
+            created_at: parse_datetime("created_at", &self.created_at)?,
+            edited_at: self
+                .edited_at
+                .as_deref()
+                .map(|value| parse_datetime("edited_at", value))
+                .transpose()?,
+            updated_at: parse_datetime("updated_at", &self.updated_at)?,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+#[async_trait]
+impl CommandModelConverter<CommentCommandModel> for Comment {
+    async fn to_command_model(&self) -> Result<CommentCommandModel, String> {
+        Ok(CommentCommandModel {
+            id: self.id.to_string(),
+            project_id: self.project_id.to_string(),
+            task_id: self.task_id.to_string(),
+            subtask_id: self.subtask_id.map(|id| id.to_string()),
+            author_id: self.author_id.to_string(),
+            body: self.body.clone(),
+            mentioned_user_ids: self
+                .mentioned_user_ids
+                .iter()
+                .map(|id| id.to_string())
+                .collect(),
+            created_at: self.created_at.to_rfc3339(),
+            edited_at: self.edited_at.map(|at| at.to_rfc3339()),
+            updated_at: self.updated_at.to_rfc3339(),
+            deleted: self.deleted,
+            updated_by: self.updated_by.to_string(),
+        })
+    }
+}
@@ -0,0 +1,42 @@
+//! ドメインイベント（エンティティの変更通知）モデル
+
+use chrono::{DateTime, Utc};
+use flequit_core::events::DomainEvent;
+use serde::{Deserialize, Serialize};
+
+/// ドメインイベントを通知するアプリイベント名
+pub const DOMAIN_EVENT: &str = "domain-event";
+
+/// エンティティの変更通知（Tauriイベント用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainEventCommandModel {
+    /// `task` / `task_list` / `task_tag` など
+    pub entity: String,
+    /// `created` / `updated` / `deleted` / `restored`
+    pub change: String,
+    pub project_id: Option<String>,
+    pub entity_id: String,
+    pub related_id: Option<String>,
+    pub changed_fields: Vec<String>,
+    pub actor: Option<String>,
+    /// `local` / `sync`
+    pub origin: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl From<DomainEvent> for DomainEventCommandModel {
+    fn from(event: DomainEvent) -> Self {
+        Self {
+            entity: event.entity.to_string(),
+            change: event.change.to_string(),
+            project_id: event.project_id.map(|id| id.to_string()),
+            entity_id: event.entity_id,
+            related_id: event.related_id,
+            changed_fields: event.changed_fields,
+            actor: event.actor.map(|id| id.to_string()),
+            origin: event.origin.to_string(),
+            occurred_at: event.occurred_at,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! Automergeドキュメント暗号化コマンドモデル
+
+use flequit_infrastructure::infrastructure_repositories::DocumentEncryptionStatus;
+use serde::{Deserialize, Serialize};
+
+/// Automergeドキュメント暗号化の状態（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentEncryptionStatusCommandModel {
+    /// ドキュメントが暗号化されているか
+    pub enabled: bool,
+    /// 鍵がアンロックされているか（暗号化有効時にfalseの場合はアンロックが必要）
+    pub unlocked: bool,
+}
+
+impl From<DocumentEncryptionStatus> for DocumentEncryptionStatusCommandModel {
+    fn from(status: DocumentEncryptionStatus) -> Self {
+        Self {
+            enabled: status.enabled,
+            unlocked: status.unlocked,
+        }
+    }
+}
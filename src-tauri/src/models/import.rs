@@ -0,0 +1,136 @@
+//! タスクインポートコマンドモデル
+
+use flequit_core::facades::import_facades::ImportResult;
+use flequit_core::importers::{CsvColumnMapping, ImportFormat, ImportOptions};
+use serde::{Deserialize, Serialize};
+
+/// 汎用CSVの列対応（値は列名）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvColumnMappingCommandModel {
+    pub title: String,
+    pub description: Option<String>,
+    pub project: Option<String>,
+    pub task_list: Option<String>,
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    pub start_date: Option<String>,
+    pub due_date: Option<String>,
+    pub tags: Option<String>,
+    pub tag_separator: Option<String>,
+    pub parent: Option<String>,
+    pub recurrence: Option<String>,
+}
+
+impl From<CsvColumnMappingCommandModel> for CsvColumnMapping {
+    fn from(model: CsvColumnMappingCommandModel) -> Self {
+        let defaults = CsvColumnMapping::with_title(model.title);
+        Self {
+            description: model.description,
+            project: model.project,
+            task_list: model.task_list,
+            status: model.status,
+            priority: model.priority,
+            start_date: model.start_date,
+            due_date: model.due_date,
+            tags: model.tags,
+            tag_separator: model
+                .tag_separator
+                .filter(|s| !s.is_empty())
+                .unwrap_or(defaults.tag_separator.clone()),
+            parent: model.parent,
+            recurrence: model.recurrence,
+            ..defaults
+        }
+    }
+}
+
+/// インポート要求（Tauriコマンド引数用）
+///
+/// `format` は `todoist_csv` / `todoist_json` / `microsoft_todo_json` /
+/// `taskwarrior_json` / `generic_csv` のいずれか。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskImportCommandModel {
+    pub format: String,
+    /// ファイル内容
+    pub content: String,
+    pub project_name: Option<String>,
+    /// `generic_csv` の場合のみ必須
+    pub column_mapping: Option<CsvColumnMappingCommandModel>,
+}
+
+impl TaskImportCommandModel {
+    pub fn to_format(&self) -> Result<ImportFormat, String> {
+        match self.format.as_str() {
+            "todoist_csv" => Ok(ImportFormat::TodoistCsv),
+            "todoist_json" => Ok(ImportFormat::TodoistJson),
+            "microsoft_todo_json" => Ok(ImportFormat::MicrosoftTodoJson),
+            "taskwarrior_json" => Ok(ImportFormat::TaskwarriorJson),
+            "generic_csv" => self
+                .column_mapping
+                .clone()
+                .map(|mapping| ImportFormat::GenericCsv(Box::new(mapping.into())))
+                .ok_or_else(|| "汎用CSVには列の対応付けが必要です".to_string()),
+            other => Err(format!("未対応のインポート形式です: {}", other)),
+        }
+    }
+
+    pub fn to_options(&self) -> ImportOptions {
+        ImportOptions {
+            project_name: self.project_name.clone(),
+        }
+    }
+}
+
+/// 取り込めなかったフィールド（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmappedFieldCommandModel {
+    pub field: String,
+    pub reason: String,
+    pub occurrences: usize,
+    pub sample: Option<String>,
+}
+
+/// インポート結果（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskImportResultCommandModel {
+    pub dry_run: bool,
+    pub project_count: usize,
+    pub task_list_count: usize,
+    pub task_count: usize,
+    pub subtask_count: usize,
+    pub tag_count: usize,
+    pub recurrence_rule_count: usize,
+    pub unmapped_fields: Vec<UnmappedFieldCommandModel>,
+    pub warnings: Vec<String>,
+    pub project_ids: Vec<String>,
+}
+
+impl From<ImportResult> for TaskImportResultCommandModel {
+    fn from(result: ImportResult) -> Self {
+        Self {
+            dry_run: result.dry_run,
+            project_count: result.counts.projects,
+            task_list_count: result.counts.task_lists,
+            task_count: result.counts.tasks,
+            subtask_count: result.counts.subtasks,
+            tag_count: result.counts.tags,
+            recurrence_rule_count: result.counts.recurrence_rules,
+            unmapped_fields: result
+                .unmapped_fields
+                .into_iter()
+                .map(|f| UnmappedFieldCommandModel {
+                    field: f.field,
+                    reason: f.reason,
+                    occurrences: f.occurrences,
+                    sample: f.sample,
+                })
+                .collect(),
+            warnings: result.warnings,
+            project_ids: result.project_ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+}
@@ -2,42 +2,61 @@ use async_trait::async_trait;
 
 // 1構造体1ファイルに分割されたモジュール
 pub mod account;
+pub mod activity;
+pub mod backup;
+pub mod bundle;
+pub mod caldav;
+pub mod calendar_export;
+pub mod calendar_feed;
+pub mod comment;
 pub mod date_condition;
 pub mod datetime;
 pub mod datetime_format;
+pub mod domain_event;
 pub mod due_date_buttons;
+pub mod encryption;
+pub mod import;
 pub mod individual;
 pub mod initialize;
 pub mod initialized_data;
 pub mod member;
+pub mod outbox;
 pub mod project;
 pub mod project_search_request;
 pub mod recurrence;
 pub mod recurrence_adjustment;
 pub mod recurrence_details;
 pub mod recurrence_rule;
+pub mod reminder;
+pub mod snooze;
+pub mod schedule;
 pub mod search;
 pub mod setting_response;
 pub mod settings;
+pub mod status_transition_rule;
 pub mod subtask;
 pub mod subtask_assignment;
-pub mod subtask_search_request;
 pub mod subtask_recurrence;
+pub mod subtask_search_request;
 pub mod subtask_tag;
 pub mod tag;
 pub mod tag_search_request;
 pub mod task;
 pub mod task_assignment;
+pub mod task_dependency;
 pub mod task_list;
 pub mod task_list_search_request;
 pub mod task_recurrence;
 pub mod task_search_request;
 pub mod task_tag;
+pub mod time_entry;
 pub mod time_label;
+pub mod undo;
 pub mod user;
 pub mod user_preferences;
 pub mod view_item;
 pub mod weekday_condition;
+pub mod workflow_status;
 
 /// 内部ドメインモデルからTauriコマンド用モデルへの変換を提供するトレイト
 ///
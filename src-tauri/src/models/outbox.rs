@@ -0,0 +1,87 @@
+//! アウトボックス（未送信のリモート書き込み）コマンドモデル
+
+use chrono::{DateTime, Utc};
+use flequit_infrastructure::web::{OutboxFlushReport, OutboxSummary};
+use flequit_model::models::sync::outbox_operation::OutboxOperation;
+use serde::{Deserialize, Serialize};
+
+/// アウトボックス内の操作数（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxSummaryCommandModel {
+    pub pending: u64,
+    pub conflicts: u64,
+    pub dead_letters: u64,
+}
+
+impl From<OutboxSummary> for OutboxSummaryCommandModel {
+    fn from(summary: OutboxSummary) -> Self {
+        Self {
+            pending: summary.pending,
+            conflicts: summary.conflicts,
+            dead_letters: summary.dead_letters,
+        }
+    }
+}
+
+/// アウトボックス内の操作（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxOperationCommandModel {
+    pub seq: i64,
+    /// 対象エンティティのコレクション名（`tasks` など）
+    pub entity: String,
+    /// `put` / `delete`
+    pub operation: String,
+    pub project_id: Option<String>,
+    /// `pending` / `conflict` / `dead_letter`
+    pub status: String,
+    pub attempts: u32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    /// 送信内容（JSON）
+    pub payload: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<OutboxOperation> for OutboxOperationCommandModel {
+    fn from(operation: OutboxOperation) -> Self {
+        Self {
+            seq: operation.seq,
+            entity: operation.entity,
+            operation: operation.operation.to_string(),
+            project_id: operation.project_id.map(|id| id.to_string()),
+            status: operation.status.to_string(),
+            attempts: operation.attempts,
+            next_attempt_at: operation.next_attempt_at,
+            last_error: operation.last_error,
+            payload: operation.payload,
+            created_at: operation.created_at,
+            updated_at: operation.updated_at,
+        }
+    }
+}
+
+/// 再送結果（Tauriコマンド戻り値・イベント用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxFlushReportCommandModel {
+    pub sent: usize,
+    pub conflicts: Vec<OutboxOperationCommandModel>,
+    pub dead_letters: Vec<OutboxOperationCommandModel>,
+    pub pending: u64,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+}
+
+impl From<OutboxFlushReport> for OutboxFlushReportCommandModel {
+    fn from(report: OutboxFlushReport) -> Self {
+        Self {
+            sent: report.sent,
+            conflicts: report.conflicts.into_iter().map(Into::into).collect(),
+            dead_letters: report.dead_letters.into_iter().map(Into::into).collect(),
+            pending: report.pending,
+            next_attempt_at: report.next_attempt_at,
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! リマインダーコマンドモデル
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_core::services::reminder_service::FiredReminder;
+use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::reminder::Reminder;
+use flequit_model::types::id_types::{ProjectId, ReminderId, SubTaskId, TaskId, UserId};
+use flequit_model::types::task_types::ReminderAnchor;
+use serde::{Deserialize, Serialize};
+
+use crate::models::CommandModelConverter;
+
+/// リマインダーの通知を知らせるアプリイベント名
+pub const REMINDER_FIRED_EVENT: &str = "reminder-fired";
+
+/// Tauriコマンド引数用のReminder構造体（日時はString）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderCommandModel {
+    pub id: String,
+    pub project_id: String,
+    pub task_id: String,
+    pub subtask_id: Option<String>,
+    pub anchor: ReminderAnchor,
+    /// `anchor`が`absolute`の場合のみ
+    pub remind_at: Option<String>,
+    pub offset_minutes: i64,
+    pub repeat_interval_minutes: Option<i64>,
+    pub repeat_count: Option<i32>,
+    pub last_fired_at: Option<String>,
+    /// スヌーズ中の場合、改めて通知する日時
+    #[serde(default)]
+    pub snoozed_until: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub deleted: bool,
+    pub updated_by: String,
+}
+
+fn parse_datetime(field: &str, value: &str) -> Result<DateTime<Utc>, String> {
+    value
+        .parse::<DateTime<Utc>>()
+        .map_err(|e| format!("Invalid {} format: {}", field, e))
+}
+
+#[async_trait]
+impl ModelConverter<Reminder> for ReminderCommandModel {
+    /// コマンド引数用（ReminderCommand）から内部モデル（Reminder）に変換
+    async fn to_model(&self) -> Result<Reminder, String> {
+        let remind_at = match &self.remind_at {
+            Some(value) => Some(parse_datetime("remind_at", value)?),
+            None => None,
+        };
+        let last_fired_at = match &self.last_fired_at {
+            Some(value) => Some(parse_datetime("last_fired_at", value)?),
+            None => None,
+        };
+        let snoozed_until = match &self.snoozed_until {
+            Some(value) => Some(parse_datetime("snoozed_until", value)?),
+            None => None,
+        };
+
+        Ok(Reminder {
+            id: ReminderId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            task_id: TaskId::from(self.task_id.clone()),
+            subtask_id: self.subtask_id.clone().map(SubTaskId::from),
+            anchor: self.anchor,
+            remind_at,
+            offset_minutes: self.offset_minutes,
+            repeat_interval_minutes: self.repeat_interval_minutes,
+            repeat_count: self.repeat_count,
+            last_fired_at,
+            snoozed_until,
+            created_at: parse_datetime("created_at", &self.created_at)?,
+            updated_at: parse_datetime("updated_at", &self.updated_at)?,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+#[async_trait]
+impl CommandModelConverter<ReminderCommandModel> for Reminder {
+    async fn to_command_model(&self) -> Result<ReminderCommandModel, String> {
+        Ok(ReminderCommandModel {
+            id: self.id.to_string(),
+            project_id: self.project_id.to_string(),
+            task_id: self.task_id.to_string(),
+            subtask_id: self.subtask_id.map(|id| id.to_string()),
+            anchor: self.anchor,
+            remind_at: self.remind_at.map(|at| at.to_rfc3339()),
+            offset_minutes: self.offset_minutes,
+            repeat_interval_minutes: self.repeat_interval_minutes,
+            repeat_count: self.repeat_count,
+            last_fired_at: self.last_fired_at.map(|at| at.to_rfc3339()),
+            snoozed_until: self.snoozed_until.map(|at| at.to_rfc3339()),
+            created_at: self.created_at.to_rfc3339(),
+            updated_at: self.updated_at.to_rfc3339(),
+            deleted: self.deleted,
+            updated_by: self.updated_by.to_string(),
+        })
+    }
+}
+
+/// リマインダーの通知（Tauriイベント用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderFiredCommandModel {
+    pub reminder_id: String,
+    pub project_id: String,
+    pub task_id: String,
+    pub subtask_id: Option<String>,
+    /// 通知対象（タスクまたはサブタスク）のタイトル
+    pub title: String,
+    pub scheduled_at: DateTime<Utc>,
+    /// アプリケーションの停止中などで通知予定時刻を過ぎてから通知したかどうか
+    pub missed: bool,
+}
+
+impl From<&FiredReminder> for ReminderFiredCommandModel {
+    fn from(fired: &FiredReminder) -> Self {
+        Self {
+            reminder_id: fired.reminder.id.to_string(),
+            project_id: fired.reminder.project_id.to_string(),
+            task_id: fired.reminder.task_id.to_string(),
+            subtask_id: fired.reminder.subtask_id.map(|id| id.to_string()),
+            title: fired.title.clone(),
+            scheduled_at: fired.scheduled_at,
+            missed: fired.missed,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! スケジュール（クリティカルパス）計算結果のコマンドモデル
+
+use flequit_core::services::schedule_service::{Schedule, ScheduleConflict, ScheduledTask};
+use serde::{Deserialize, Serialize};
+
+/// スケジュールの計算結果（Tauriコマンド戻り値用、日時はRFC3339文字列）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleCommandModel {
+    pub start: String,
+    pub finish: String,
+    /// 最早開始日時の順に並べたタスク
+    pub tasks: Vec<ScheduledTaskCommandModel>,
+    /// 余裕時間のないタスクIDを開始順に並べたもの
+    pub critical_path: Vec<String>,
+    pub conflicts: Vec<ScheduleConflictCommandModel>,
+}
+
+/// タスクごとの計算結果（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledTaskCommandModel {
+    pub task_id: String,
+    pub list_id: String,
+    pub duration_seconds: i64,
+    pub earliest_start: String,
+    pub earliest_finish: String,
+    pub latest_start: String,
+    pub latest_finish: String,
+    pub slack_seconds: i64,
+    pub is_critical: bool,
+}
+
+/// 予定日時と依存関係の食い違い（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleConflictCommandModel {
+    pub task_id: String,
+    pub depends_on_task_id: String,
+    pub plan_start_date: String,
+    pub earliest_start: String,
+}
+
+impl From<Schedule> for ScheduleCommandModel {
+    fn from(schedule: Schedule) -> Self {
+        Self {
+            start: schedule.start.to_rfc3339(),
+            finish: schedule.finish.to_rfc3339(),
+            tasks: schedule.tasks.into_iter().map(Into::into).collect(),
+            critical_path: schedule
+                .critical_path
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            conflicts: schedule.conflicts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<ScheduledTask> for ScheduledTaskCommandModel {
+    fn from(task: ScheduledTask) -> Self {
+        Self {
+            task_id: task.task_id.to_string(),
+            list_id: task.list_id.to_string(),
+            duration_seconds: task.duration.num_seconds(),
+            earliest_start: task.earliest_start.to_rfc3339(),
+            earliest_finish: task.earliest_finish.to_rfc3339(),
+            latest_start: task.latest_start.to_rfc3339(),
+            latest_finish: task.latest_finish.to_rfc3339(),
+            slack_seconds: task.slack.num_seconds(),
+            is_critical: task.is_critical,
+        }
+    }
+}
+
+impl From<ScheduleConflict> for ScheduleConflictCommandModel {
+    fn from(conflict: ScheduleConflict) -> Self {
+        Self {
+            task_id: conflict.task_id.to_string(),
+            depends_on_task_id: conflict.depends_on_task_id.to_string(),
+            plan_start_date: conflict.plan_start_date.to_rfc3339(),
+            earliest_start: conflict.earliest_start.to_rfc3339(),
+        }
+    }
+}
@@ -60,6 +60,10 @@ impl ModelConverter<Settings> for SettingsCommandModel {
             time_labels: self.time_labels.clone(),
             due_date_buttons: self.due_date_buttons.clone(),
             view_items: self.view_items.clone(),
+            // フィードのトークンは専用コマンドでのみ扱う（保存時は既存の値を引き継ぐ）
+            calendar_feed: Default::default(),
+            // バックアップ設定は画面の設定に含まれない（保存時は既存の値を引き継ぐ）
+            backup: Default::default(),
         })
     }
 }
@@ -137,6 +141,8 @@ impl ModelConverter<PartialSettings> for PartialSettingsCommandModel {
             time_labels: self.time_labels.clone(),
             due_date_buttons: self.due_date_buttons.clone(),
             view_items: self.view_items.clone(),
+            calendar_feed: None,
+            backup: None,
         })
     }
 }
@@ -0,0 +1,98 @@
+//! スヌーズコマンドモデル
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::snooze::Snooze;
+use flequit_model::types::id_types::{ProjectId, ReminderId, SnoozeId, SubTaskId, TaskId, UserId};
+use serde::{Deserialize, Serialize};
+
+use crate::models::CommandModelConverter;
+
+/// Tauriコマンド引数用のSnooze構造体（日時はString）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnoozeCommandModel {
+    pub id: String,
+    pub project_id: String,
+    pub task_id: String,
+    pub subtask_id: Option<String>,
+    /// リマインダーのスヌーズの場合のみ
+    pub reminder_id: Option<String>,
+    pub original_plan_start_date: Option<String>,
+    pub original_plan_end_date: Option<String>,
+    pub original_remind_at: Option<String>,
+    pub snoozed_until: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub deleted: bool,
+    pub updated_by: String,
+}
+
+fn parse_datetime(field: &str, value: &str) -> Result<DateTime<Utc>, String> {
+    value
+        .parse::<DateTime<Utc>>()
+        .map_err(|e| format!("Invalid {} format: {}", field, e))
+}
+
+fn parse_optional_datetime(
+    field: &str,
+    value: &Option<String>,
+) -> Result<Option<DateTime<Utc>>, String> {
+    value
+        .as_deref()
+        .map(|value| parse_datetime(field, value))
+        .transpose()
+}
+
+#[async_trait]
+impl ModelConverter<Snooze> for SnoozeCommandModel {
+    /// コマンド引数用（SnoozeCommand）から内部モデル（Snooze）に変換
+    async fn to_model(&self) -> Result<Snooze, String> {
+        Ok(Snooze {
+            id: SnoozeId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            task_id: TaskId::from(self.task_id.clone()),
+            subtask_id: self.subtask_id.clone().map(SubTaskId::from),
+            reminder_id: self.reminder_id.clone().map(ReminderId::from),
+            original_plan_start_date: parse_optional_datetime(
+                "original_plan_start_date",
+                &self.original_plan_start_date,
+            )?,
+            original_plan_end_date: parse_optional_datetime(
+                "original_plan_end_date",
+                &self.original_plan_end_date,
+            )?,
+            original_remind_at: parse_optional_datetime(
+                "original_remind_at",
+                &self.original_remind_at,
+            )?,
+            snoozed_until: parse_datetime("snoozed_until", &self.snoozed_until)?,
+            created_at: parse_datetime("created_at", &self.created_at)?,
+            updated_at: parse_datetime("updated_at", &self.updated_at)?,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+#[async_trait]
+impl CommandModelConverter<SnoozeCommandModel> for Snooze {
+    async fn to_command_model(&self) -> Result<SnoozeCommandModel, String> {
+        Ok(SnoozeCommandModel {
+            id: self.id.to_string(),
+            project_id: self.project_id.to_string(),
+            task_id: self.task_id.to_string(),
+            subtask_id: self.subtask_id.map(|id| id.to_string()),
+            reminder_id: self.reminder_id.map(|id| id.to_string()),
+            original_plan_start_date: self.original_plan_start_date.map(|at| at.to_rfc3339()),
+            original_plan_end_date: self.original_plan_end_date.map(|at| at.to_rfc3339()),
+            original_remind_at: self.original_remind_at.map(|at| at.to_rfc3339()),
+            snoozed_until: self.snoozed_until.to_rfc3339(),
+            created_at: self.created_at.to_rfc3339(),
+            updated_at: self.updated_at.to_rfc3339(),
+            deleted: self.deleted,
+            updated_by: self.updated_by.to_string(),
+        })
+    }
+}
@@ -0,0 +1,71 @@
+//! ステータス遷移ルールコマンドモデル
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::status_transition_rule::StatusTransitionRule;
+use flequit_model::types::id_types::{ProjectId, StatusTransitionRuleId, UserId};
+use flequit_model::types::task_types::{StatusTransitionRequirement, TaskStatus};
+use serde::{Deserialize, Serialize};
+
+use crate::models::CommandModelConverter;
+
+/// Tauriコマンド引数用のStatusTransitionRule構造体（created_at/updated_atはString）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusTransitionRuleCommandModel {
+    pub id: String,
+    pub project_id: String,
+    pub from_status: TaskStatus,
+    pub to_status: TaskStatus,
+    /// `forbidden` / `reason_required`
+    pub requirement: StatusTransitionRequirement,
+    pub created_at: String,
+    pub updated_at: String,
+    pub deleted: bool,
+    pub updated_by: String,
+}
+
+#[async_trait]
+impl ModelConverter<StatusTransitionRule> for StatusTransitionRuleCommandModel {
+    /// コマンド引数用（StatusTransitionRuleCommand）から内部モデル（StatusTransitionRule）に変換
+    async fn to_model(&self) -> Result<StatusTransitionRule, String> {
+        let created_at = self
+            .created_at
+            .parse::<DateTime<Utc>>()
+            .map_err(|e| format!("Invalid created_at format: {}", e))?;
+        let updated_at = self
+            .updated_at
+            .parse::<DateTime<Utc>>()
+            .map_err(|e| format!("Invalid updated_at format: {}", e))?;
+
+        Ok(StatusTransitionRule {
+            id: StatusTransitionRuleId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            from_status: self.from_status.clone(),
+            to_status: self.to_status.clone(),
+            requirement: self.requirement,
+            created_at,
+            updated_at,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+#[async_trait]
+impl CommandModelConverter<StatusTransitionRuleCommandModel> for StatusTransitionRule {
+    async fn to_command_model(&self) -> Result<StatusTransitionRuleCommandModel, String> {
+        Ok(StatusTransitionRuleCommandModel {
+            id: self.id.to_string(),
+            project_id: self.project_id.to_string(),
+            from_status: self.from_status.clone(),
+            to_status: self.to_status.clone(),
+            requirement: self.requirement,
+            created_at: self.created_at.to_rfc3339(),
+            updated_at: self.updated_at.to_rfc3339(),
+            deleted: self.deleted,
+            updated_by: self.updated_by.to_string(),
+        })
+    }
+}
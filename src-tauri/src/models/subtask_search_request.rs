@@ -9,6 +9,8 @@ pub struct SubTaskSearchRequest {
     pub title: Option<String>,
     pub status: Option<TaskStatus>,
     pub priority: Option<i32>,
+    /// スヌーズで先送り中のサブタスクに絞り込む（`false`でスマートリストから除外する）
+    pub is_deferred: Option<bool>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
 }
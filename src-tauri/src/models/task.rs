@@ -2,13 +2,15 @@ use crate::models::recurrence_rule::RecurrenceRuleCommandModel;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use flequit_model::models::task_projects::task::TaskTree;
-use flequit_model::types::id_types::{ProjectId, TagId, TaskId, TaskListId, UserId};
+use flequit_model::types::id_types::{
+    ProjectId, TagId, TaskId, TaskListId, UserId, WorkflowStatusId,
+};
 use flequit_model::types::task_types::TaskStatus;
 use serde::{Deserialize, Serialize};
 
 use crate::models::CommandModelConverter;
-use flequit_model::models::task_projects::task::Task;
 use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::task::Task;
 // TagのCommandModelConverter実装をimport
 
 /// Tauriコマンド引数用のTask構造体（日時フィールドはString）
@@ -22,6 +24,8 @@ pub struct TaskCommandModel {
     pub title: String,
     pub description: Option<String>,
     pub status: TaskStatus,
+    #[serde(default)]
+    pub workflow_status_id: Option<String>,
     pub priority: i32,
     pub plan_start_date: Option<String>,
     pub plan_end_date: Option<String>,
@@ -99,6 +103,7 @@ impl ModelConverter<Task> for TaskCommandModel {
             title: self.title.clone(),
             description: self.description.clone(),
             status: self.status.clone(),
+            workflow_status_id: self.workflow_status_id.clone().map(WorkflowStatusId::from),
             priority: self.priority,
             plan_start_date,
             plan_end_date,
@@ -141,6 +146,8 @@ pub struct TaskTreeCommandModel {
     pub title: String,
     pub description: Option<String>,
     pub status: TaskStatus,
+    #[serde(default)]
+    pub workflow_status_id: Option<String>,
     pub priority: i32,
     pub plan_start_date: Option<String>,
     pub plan_end_date: Option<String>,
@@ -224,6 +231,7 @@ impl ModelConverter<TaskTree> for TaskTreeCommandModel {
             title: self.title.clone(),
             description: self.description.clone(),
             status: self.status.clone(),
+            workflow_status_id: self.workflow_status_id.clone().map(WorkflowStatusId::from),
             priority: self.priority,
             plan_start_date,
             plan_end_date,
@@ -273,6 +281,7 @@ impl CommandModelConverter<TaskTreeCommandModel> for TaskTree {
             title: self.title.clone(),
             description: self.description.clone(),
             status: self.status.clone(),
+            workflow_status_id: self.workflow_status_id.map(|id| id.to_string()),
             priority: self.priority,
             plan_start_date: self.plan_start_date.as_ref().map(|d| d.to_rfc3339()),
             plan_end_date: self.plan_end_date.as_ref().map(|d| d.to_rfc3339()),
@@ -313,6 +322,7 @@ impl CommandModelConverter<TaskCommandModel> for Task {
             title: self.title.clone(),
             description: self.description.clone(),
             status: self.status.clone(),
+            workflow_status_id: self.workflow_status_id.map(|id| id.to_string()),
             priority: self.priority,
             plan_start_date: self.plan_start_date.as_ref().map(|d| d.to_rfc3339()),
             plan_end_date: self.plan_end_date.as_ref().map(|d| d.to_rfc3339()),
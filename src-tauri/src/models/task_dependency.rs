@@ -0,0 +1,62 @@
+use crate::models::CommandModelConverter;
+use async_trait::async_trait;
+use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::task_dependency::TaskDependency;
+use flequit_model::types::id_types::{TaskId, UserId};
+use serde::{Deserialize, Serialize};
+
+/// Tauriコマンド引数用のTaskDependency構造体
+///
+/// `task_id` のタスクは `depends_on_task_id` のタスク（先行タスク）が完了するまでブロックされる。
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskDependencyCommandModel {
+    pub task_id: String,
+    pub depends_on_task_id: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub deleted: bool,
+    pub updated_by: String,
+}
+
+#[async_trait]
+impl ModelConverter<TaskDependency> for TaskDependencyCommandModel {
+    /// コマンド引数用（TaskDependencyCommand）から内部モデル（TaskDependency）に変換
+    async fn to_model(&self) -> Result<TaskDependency, String> {
+        use chrono::{DateTime, Utc};
+
+        let created_at = self
+            .created_at
+            .parse::<DateTime<Utc>>()
+            .map_err(|e| format!("Invalid created_at format: {}", e))?;
+
+        let updated_at = self
+            .updated_at
+            .parse::<DateTime<Utc>>()
+            .map_err(|e| format!("Invalid updated_at format: {}", e))?;
+
+        Ok(TaskDependency {
+            task_id: TaskId::from(self.task_id.clone()),
+            depends_on_task_id: TaskId::from(self.depends_on_task_id.clone()),
+            created_at,
+            updated_at,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+#[async_trait]
+impl CommandModelConverter<TaskDependencyCommandModel> for TaskDependency {
+    /// ドメインモデル（TaskDependency）からコマンドモデル（TaskDependencyCommand）に変換
+    async fn to_command_model(&self) -> Result<TaskDependencyCommandModel, String> {
+        Ok(TaskDependencyCommandModel {
+            task_id: self.task_id.to_string(),
+            depends_on_task_id: self.depends_on_task_id.to_string(),
+            created_at: self.created_at.to_rfc3339(),
+            updated_at: self.updated_at.to_rfc3339(),
+            deleted: self.deleted,
+            updated_by: self.updated_by.to_string(),
+        })
+    }
+}
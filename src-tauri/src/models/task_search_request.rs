@@ -12,6 +12,10 @@ pub struct TaskSearchRequest {
     pub tag_id: Option<String>,
     pub title: Option<String>,
     pub is_archived: Option<bool>,
+    /// 未完了の先行タスクを持つ（ブロック中の）タスクに絞り込む
+    pub is_blocked: Option<bool>,
+    /// スヌーズで先送り中のタスクに絞り込む（`false`でスマートリストから除外する）
+    pub is_deferred: Option<bool>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
 }
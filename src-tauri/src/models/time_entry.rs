@@ -0,0 +1,102 @@
+//! タイムエントリ（作業時間の記録）コマンドモデル
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_core::services::time_entry_service::TimeTotal;
+use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::time_entry::TimeEntry;
+use flequit_model::types::id_types::{ProjectId, SubTaskId, TaskId, TimeEntryId, UserId};
+use serde::{Deserialize, Serialize};
+
+use crate::models::CommandModelConverter;
+
+/// Tauriコマンド引数用のTimeEntry構造体（日時はString）
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntryCommandModel {
+    pub id: String,
+    pub project_id: String,
+    pub task_id: String,
+    pub subtask_id: Option<String>,
+    pub user_id: String,
+    pub started_at: String,
+    /// タイマー計測中は`None`
+    pub ended_at: Option<String>,
+    pub note: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub deleted: bool,
+    pub updated_by: String,
+}
+
+fn parse_datetime(field: &str, value: &str) -> Result<DateTime<Utc>, String> {
+    value
+        .parse::<DateTime<Utc>>()
+        .map_err(|e| format!("Invalid {} format: {}", field, e))
+}
+
+#[async_trait]
+impl ModelConverter<TimeEntry> for TimeEntryCommandModel {
+    /// コマンド引数用（TimeEntryCommand）から内部モデル（TimeEntry）に変換
+    async fn to_model(&self) -> Result<TimeEntry, String> {
+        let ended_at = match &self.ended_at {
+            Some(value) => Some(parse_datetime("ended_at", value)?),
+            None => None,
+        };
+
+        Ok(TimeEntry {
+            id: TimeEntryId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            task_id: TaskId::from(self.task_id.clone()),
+            subtask_id: self.subtask_id.clone().map(SubTaskId::from),
+            user_id: UserId::from(self.user_id.clone()),
+            started_at: parse_datetime("started_at", &self.started_at)?,
+            ended_at,
+            note: self.note.clone(),
+            created_at: parse_datetime("created_at", &self.created_at)?,
+            updated_at: parse_datetime("updated_at", &self.updated_at)?,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+#[async_trait]
+impl CommandModelConverter<TimeEntryCommandModel> for TimeEntry {
+    async fn to_command_model(&self) -> Result<TimeEntryCommandModel, String> {
+        Ok(TimeEntryCommandModel {
+            id: self.id.to_string(),
+            project_id: self.project_id.to_string(),
+            task_id: self.task_id.to_string(),
+            subtask_id: self.subtask_id.map(|id| id.to_string()),
+            user_id: self.user_id.to_string(),
+            started_at: self.started_at.to_rfc3339(),
+            ended_at: self.ended_at.map(|at| at.to_rfc3339()),
+            note: self.note.clone(),
+            created_at: self.created_at.to_rfc3339(),
+            updated_at: self.updated_at.to_rfc3339(),
+            deleted: self.deleted,
+            updated_by: self.updated_by.to_string(),
+        })
+    }
+}
+
+/// 集計単位ごとの作業時間（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeTotalCommandModel {
+    /// 集計単位のID（タスクID・タスクリストID・プロジェクトID・タグID・ユーザーID）
+    pub key: String,
+    pub total_seconds: i64,
+    pub entry_count: u32,
+}
+
+impl From<TimeTotal> for TimeTotalCommandModel {
+    fn from(total: TimeTotal) -> Self {
+        Self {
+            key: total.key,
+            total_seconds: total.total_seconds,
+            entry_count: total.entry_count,
+        }
+    }
+}
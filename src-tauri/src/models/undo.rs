@@ -0,0 +1,46 @@
+//! 取り消し・やり直しコマンドモデル
+
+use chrono::{DateTime, Utc};
+use flequit_core::undo::{UndoStatus, UndoStepSummary};
+use serde::{Deserialize, Serialize};
+
+/// 取り消し・やり直しされる操作の概要（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoStepSummaryCommandModel {
+    /// 操作の起点となったエンティティの種類（`task` / `task_list` など）
+    pub entity: String,
+    /// `created` / `updated` / `deleted` / `restored`
+    pub change: String,
+    /// まとめて戻される変更の数
+    pub operation_count: usize,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl From<UndoStepSummary> for UndoStepSummaryCommandModel {
+    fn from(summary: UndoStepSummary) -> Self {
+        Self {
+            entity: summary.entity.to_string(),
+            change: summary.change.to_string(),
+            operation_count: summary.operation_count,
+            recorded_at: summary.recorded_at,
+        }
+    }
+}
+
+/// ウィンドウで次に取り消し・やり直しされる操作（Tauriコマンド戻り値用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoStatusCommandModel {
+    pub undo: Option<UndoStepSummaryCommandModel>,
+    pub redo: Option<UndoStepSummaryCommandModel>,
+}
+
+impl From<UndoStatus> for UndoStatusCommandModel {
+    fn from(status: UndoStatus) -> Self {
+        Self {
+            undo: status.undo.map(Into::into),
+            redo: status.redo.map(Into::into),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! ワークフローステータスコマンドモデル
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flequit_model::models::ModelConverter;
+use flequit_model::models::task_projects::workflow_status::WorkflowStatus;
+use flequit_model::types::id_types::{ProjectId, UserId, WorkflowStatusId};
+use flequit_model::types::task_types::TaskStatus;
+use serde::{Deserialize, Serialize};
+
+use crate::models::CommandModelConverter;
+
+/// Tauriコマンド引数用のWorkflowStatus構造体（created_at/updated_atはString）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStatusCommandModel {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub color: Option<String>,
+    pub order_index: i32,
+    /// 集計に使う組み込みのステータス
+    pub category: TaskStatus,
+    pub created_at: String,
+    pub updated_at: String,
+    pub deleted: bool,
+    pub updated_by: String,
+}
+
+#[async_trait]
+impl ModelConverter<WorkflowStatus> for WorkflowStatusCommandModel {
+    /// コマンド引数用（WorkflowStatusCommand）から内部モデル（WorkflowStatus）に変換
+    async fn to_model(&self) -> Result<WorkflowStatus, String> {
+        let created_at = self
+            .created_at
+            .parse::<DateTime<Utc>>()
+            .map_err(|e| format!("Invalid created_at format: {}", e))?;
+        let updated_at = self
+            .updated_at
+            .parse::<DateTime<Utc>>()
+            .map_err(|e| format!("Invalid updated_at format: {}", e))?;
+
+        Ok(WorkflowStatus {
+            id: WorkflowStatusId::from(self.id.clone()),
+            project_id: ProjectId::from(self.project_id.clone()),
+            name: self.name.clone(),
+            color: self.color.clone(),
+            order_index: self.order_index,
+            category: self.category.clone(),
+            created_at,
+            updated_at,
+            deleted: self.deleted,
+            updated_by: UserId::from(self.updated_by.clone()),
+        })
+    }
+}
+
+#[async_trait]
+impl CommandModelConverter<WorkflowStatusCommandModel> for WorkflowStatus {
+    async fn to_command_model(&self) -> Result<WorkflowStatusCommandModel, String> {
+        Ok(WorkflowStatusCommandModel {
+            id: self.id.to_string(),
+            project_id: self.project_id.to_string(),
+            name: self.name.clone(),
+            color: self.color.clone(),
+            order_index: self.order_index,
+            category: self.category.clone(),
+            created_at: self.created_at.to_rfc3339(),
+            updated_at: self.updated_at.to_rfc3339(),
+            deleted: self.deleted,
+            updated_by: self.updated_by.to_string(),
+        })
+    }
+}
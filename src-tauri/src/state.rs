@@ -1,8 +1,11 @@
 use flequit_core::InfrastructureRepositoriesTrait;
+use flequit_infrastructure::backup::{BackupScheduleConfig, BackupScheduler};
+use flequit_infrastructure::calendar_feed::CalendarFeedHandle;
 use flequit_infrastructure::{InfrastructureConfig, InfrastructureRepositories};
 use flequit_settings::{Settings, SettingsManager};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
+use tokio::task::JoinHandle;
 
 /// アプリケーション全体で共有される状態
 #[derive(Clone, Debug)]
@@ -13,6 +16,10 @@ where
     pub repositories: Arc<RwLock<R>>,
     pub settings: Arc<RwLock<Settings>>,
     pub settings_manager: Arc<SettingsManager>,
+    /// 起動中のカレンダーフィード配信サーバー
+    pub calendar_feed_server: Arc<Mutex<Option<CalendarFeedHandle>>>,
+    /// 実行中の定期バックアップタスク
+    pub backup_scheduler: Arc<Mutex<Option<JoinHandle<()>>>>,
 }
 
 impl AppState<InfrastructureRepositories> {
@@ -30,6 +37,7 @@ impl AppState<InfrastructureRepositories> {
             sqlite_search_enabled: true,
             sqlite_storage_enabled: true,
             automerge_storage_enabled: true,
+            ..InfrastructureConfig::default()
         };
 
         let repositories =
@@ -41,8 +49,26 @@ impl AppState<InfrastructureRepositories> {
             repositories: Arc::new(RwLock::new(repositories)),
             settings: Arc::new(RwLock::new(settings)),
             settings_manager: Arc::new(settings_manager),
+            calendar_feed_server: Arc::new(Mutex::new(None)),
+            backup_scheduler: Arc::new(Mutex::new(None)),
         })
     }
+
+    /// 現在の設定で定期バックアップタスクを再起動する
+    ///
+    /// 実行中のタスクを停止し、定期バックアップが有効な場合は新しい設定で開始する。
+    /// 起動時と、バックアップ設定が変わり得る操作（設定の更新・初期化、リストア）の後に呼び出す。
+    pub async fn restart_backup_scheduler(&self) {
+        let mut scheduler = self.backup_scheduler.lock().await;
+        if let Some(handle) = scheduler.take() {
+            handle.abort();
+        }
+
+        let backup = self.settings.read().await.backup.clone();
+        if let Some(config) = BackupScheduleConfig::from_settings(&backup) {
+            *scheduler = Some(BackupScheduler::spawn(self.repositories.clone(), config));
+        }
+    }
 }
 
 impl<R> AppState<R>
@@ -59,6 +85,8 @@ where
             repositories: Arc::new(RwLock::new(repositories)),
             settings: Arc::new(RwLock::new(settings)),
             settings_manager: Arc::new(settings_manager),
+            calendar_feed_server: Arc::new(Mutex::new(None)),
+            backup_scheduler: Arc::new(Mutex::new(None)),
         }
     }
 }